@@ -170,6 +170,11 @@ impl pallet_collective_content::Config<AmbassadorContentInstance> for Runtime {
 
 pub type AmbassadorCoreInstance = pallet_core_fellowship::Instance2;
 
+parameter_types! {
+	pub const AmbassadorReviewApproval: Perbill = Perbill::from_percent(50);
+	pub const AmbassadorReviewSupport: Perbill = Perbill::from_percent(50);
+}
+
 impl pallet_core_fellowship::Config<AmbassadorCoreInstance> for Runtime {
 	type WeightInfo = weights::pallet_core_fellowship_ambassador_core::WeightInfo<Runtime>;
 	type RuntimeEvent = RuntimeEvent;
@@ -208,6 +213,12 @@ impl pallet_core_fellowship::Config<AmbassadorCoreInstance> for Runtime {
 	type ApproveOrigin = PromoteOrigin;
 	type PromoteOrigin = PromoteOrigin;
 	type EvidenceSize = ConstU32<65536>;
+	type Tally = pallet_ranked_collective::TallyOf<Runtime, AmbassadorCollectiveInstance>;
+	// The Ambassador Program's polls are its referenda, so evidence is judged by the origins
+	// above.
+	type ReviewPeriod = ConstU32<0>;
+	type ReviewApproval = AmbassadorReviewApproval;
+	type ReviewSupport = AmbassadorReviewSupport;
 }
 
 pub type AmbassadorSalaryInstance = pallet_salary::Instance2;
@@ -233,6 +244,7 @@ pub type AmbassadorSalaryPaymaster = PayOverXcm<
 impl pallet_salary::Config<AmbassadorSalaryInstance> for Runtime {
 	type WeightInfo = weights::pallet_salary_ambassador_salary::WeightInfo<Runtime>;
 	type RuntimeEvent = RuntimeEvent;
+	type AssetKind = ();
 
 	#[cfg(not(feature = "runtime-benchmarks"))]
 	type Paymaster = AmbassadorSalaryPaymaster;
@@ -241,6 +253,10 @@ impl pallet_salary::Config<AmbassadorSalaryInstance> for Runtime {
 		AmbassadorSalaryPaymaster,
 		crate::impls::benchmarks::OpenHrmpChannel<ConstU32<1000>>,
 	>;
+	// Payments are only made in the single asset of the `Paymaster`.
+	type DefaultAsset = ();
+	type PayoutAssets = frame_support::traits::Nothing;
+	type BalanceConverter = frame_support::traits::tokens::UnityAssetBalanceConversion;
 	type Members = pallet_ranked_collective::Pallet<Runtime, AmbassadorCollectiveInstance>;
 
 	#[cfg(not(feature = "runtime-benchmarks"))]
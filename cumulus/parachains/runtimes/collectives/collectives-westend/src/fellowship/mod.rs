@@ -45,7 +45,7 @@ use parachains_common::{
 use polkadot_runtime_common::impls::{
 	LocatableAssetConverter, VersionedLocatableAsset, VersionedLocationConverter,
 };
-use sp_arithmetic::Permill;
+use sp_arithmetic::{Perbill, Permill};
 use sp_core::{ConstU128, ConstU32};
 use sp_runtime::traits::{ConstU16, ConvertToValue, IdentityLookup, Replace, TakeFirst};
 use westend_runtime_constants::time::HOURS;
@@ -143,6 +143,11 @@ impl pallet_ranked_collective::Config<FellowshipCollectiveInstance> for Runtime
 
 pub type FellowshipCoreInstance = pallet_core_fellowship::Instance1;
 
+parameter_types! {
+	pub const FellowshipReviewApproval: Perbill = Perbill::from_percent(50);
+	pub const FellowshipReviewSupport: Perbill = Perbill::from_percent(50);
+}
+
 impl pallet_core_fellowship::Config<FellowshipCoreInstance> for Runtime {
 	type WeightInfo = weights::pallet_core_fellowship_fellowship_core::WeightInfo<Runtime>;
 	type RuntimeEvent = RuntimeEvent;
@@ -195,6 +200,11 @@ impl pallet_core_fellowship::Config<FellowshipCoreInstance> for Runtime {
 		EnsureCanPromoteTo,
 	>;
 	type EvidenceSize = ConstU32<65536>;
+	type Tally = pallet_ranked_collective::TallyOf<Runtime, FellowshipCollectiveInstance>;
+	// The Fellowship's polls are its referenda, so evidence is judged by the origins above.
+	type ReviewPeriod = ConstU32<0>;
+	type ReviewApproval = FellowshipReviewApproval;
+	type ReviewSupport = FellowshipReviewSupport;
 }
 
 pub type FellowshipSalaryInstance = pallet_salary::Instance1;
@@ -222,11 +232,16 @@ pub type FellowshipSalaryPaymaster = PayOverXcm<
 impl pallet_salary::Config<FellowshipSalaryInstance> for Runtime {
 	type WeightInfo = weights::pallet_salary_fellowship_salary::WeightInfo<Runtime>;
 	type RuntimeEvent = RuntimeEvent;
+	type AssetKind = ();
 
 	#[cfg(not(feature = "runtime-benchmarks"))]
 	type Paymaster = FellowshipSalaryPaymaster;
 	#[cfg(feature = "runtime-benchmarks")]
 	type Paymaster = PayWithEnsure<FellowshipSalaryPaymaster, OpenHrmpChannel<ConstU32<1000>>>;
+	// Payments are only made in the single asset of the `Paymaster`.
+	type DefaultAsset = ();
+	type PayoutAssets = frame_support::traits::Nothing;
+	type BalanceConverter = frame_support::traits::tokens::UnityAssetBalanceConversion;
 	type Members = pallet_ranked_collective::Pallet<Runtime, FellowshipCollectiveInstance>;
 
 	#[cfg(not(feature = "runtime-benchmarks"))]
@@ -335,6 +350,7 @@ impl pallet_treasury::Config<FellowshipTreasuryInstance> for Runtime {
 	type Paymaster = PayWithEnsure<FellowshipTreasuryPaymaster, OpenHrmpChannel<ConstU32<1000>>>;
 	type BalanceConverter = AssetRate;
 	type PayoutPeriod = ConstU32<{ 30 * DAYS }>;
+	type MaxPaymentBatch = ConstU32<20>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = polkadot_runtime_common::impls::benchmarks::TreasuryArguments<
 		sp_core::ConstU8<1>,
@@ -167,6 +167,12 @@ pub mod benchmarks {
 		fn check_payment(id: Self::Id) -> PaymentStatus {
 			O::check_payment(id)
 		}
+		fn pay_batch(
+			who: &Self::Beneficiary,
+			payments: Vec<(Self::AssetKind, Self::Balance)>,
+		) -> Option<Result<Self::Id, Self::Error>> {
+			O::pay_batch(who, payments)
+		}
 		fn ensure_successful(
 			who: &Self::Beneficiary,
 			asset_kind: Self::AssetKind,
@@ -722,6 +722,8 @@ type Migrations = (
 	pallet_collator_selection::migration::v1::MigrateToV1<Runtime>,
 	// unreleased
 	cumulus_pallet_xcmp_queue::migration::v4::MigrationToV4<Runtime>,
+	// unreleased
+	pallet_treasury::migration::MigrateV0ToV1<Runtime, fellowship::FellowshipTreasuryInstance>,
 );
 
 /// Executive: handles dispatch to the various modules.
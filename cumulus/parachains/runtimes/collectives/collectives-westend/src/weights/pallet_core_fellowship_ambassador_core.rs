@@ -70,15 +70,16 @@ impl<T: frame_system::Config> pallet_core_fellowship::WeightInfo for WeightInfo<
 	/// Proof: `AmbassadorCollective::IdToIndex` (`max_values`: None, `max_size`: Some(54), added: 2529, mode: `MaxEncodedLen`)
 	/// Storage: `AmbassadorCore::MemberEvidence` (r:1 w:1)
 	/// Proof: `AmbassadorCore::MemberEvidence` (`max_values`: None, `max_size`: Some(65581), added: 68056, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorCore::MemberReview` (r:1 w:1)
+	/// Proof: `AmbassadorCore::MemberReview` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorCore::Reviews` (r:0 w:1)
+	/// Proof: `AmbassadorCore::Reviews` (`max_values`: None, `max_size`: Some(62), added: 2537, mode: `MaxEncodedLen`)
 	fn bump_offboard() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `66011`
-		//  Estimated: `69046`
-		// Minimum execution time: 96_000_000 picoseconds.
+		// TODO: run benchmarks
 		Weight::from_parts(111_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 69046))
-			.saturating_add(T::DbWeight::get().reads(6))
-			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(6))
 	}
 	/// Storage: `AmbassadorCore::Member` (r:1 w:1)
 	/// Proof: `AmbassadorCore::Member` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
@@ -92,15 +93,16 @@ impl<T: frame_system::Config> pallet_core_fellowship::WeightInfo for WeightInfo<
 	/// Proof: `AmbassadorCollective::IdToIndex` (`max_values`: None, `max_size`: Some(54), added: 2529, mode: `MaxEncodedLen`)
 	/// Storage: `AmbassadorCore::MemberEvidence` (r:1 w:1)
 	/// Proof: `AmbassadorCore::MemberEvidence` (`max_values`: None, `max_size`: Some(65581), added: 68056, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorCore::MemberReview` (r:1 w:1)
+	/// Proof: `AmbassadorCore::MemberReview` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorCore::Reviews` (r:0 w:1)
+	/// Proof: `AmbassadorCore::Reviews` (`max_values`: None, `max_size`: Some(62), added: 2537, mode: `MaxEncodedLen`)
 	fn bump_demote() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `66121`
-		//  Estimated: `69046`
-		// Minimum execution time: 99_000_000 picoseconds.
+		// TODO: run benchmarks
 		Weight::from_parts(116_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 69046))
-			.saturating_add(T::DbWeight::get().reads(6))
-			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(6))
 	}
 	/// Storage: `AmbassadorCollective::Members` (r:1 w:0)
 	/// Proof: `AmbassadorCollective::Members` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
@@ -150,15 +152,16 @@ impl<T: frame_system::Config> pallet_core_fellowship::WeightInfo for WeightInfo<
 	/// Proof: `AmbassadorCollective::IndexToId` (`max_values`: None, `max_size`: Some(54), added: 2529, mode: `MaxEncodedLen`)
 	/// Storage: `AmbassadorCollective::IdToIndex` (r:0 w:1)
 	/// Proof: `AmbassadorCollective::IdToIndex` (`max_values`: None, `max_size`: Some(54), added: 2529, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorCore::MemberReview` (r:1 w:1)
+	/// Proof: `AmbassadorCore::MemberReview` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorCore::Reviews` (r:0 w:1)
+	/// Proof: `AmbassadorCore::Reviews` (`max_values`: None, `max_size`: Some(62), added: 2537, mode: `MaxEncodedLen`)
 	fn promote() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `65989`
-		//  Estimated: `69046`
-		// Minimum execution time: 95_000_000 picoseconds.
+		// TODO: run benchmarks
 		Weight::from_parts(110_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 69046))
-			.saturating_add(T::DbWeight::get().reads(5))
-			.saturating_add(T::DbWeight::get().writes(6))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(8))
 	}
 	/// Storage: `AmbassadorCollective::Members` (r:1 w:0)
 	/// Proof: `AmbassadorCollective::Members` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
@@ -166,15 +169,18 @@ impl<T: frame_system::Config> pallet_core_fellowship::WeightInfo for WeightInfo<
 	/// Proof: `AmbassadorCore::Member` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	/// Storage: `AmbassadorCore::MemberEvidence` (r:0 w:1)
 	/// Proof: `AmbassadorCore::MemberEvidence` (`max_values`: None, `max_size`: Some(65581), added: 68056, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorCore::MemberReview` (r:1 w:1)
+	/// Proof: `AmbassadorCore::MemberReview` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorCore::Reviews` (r:0 w:1)
+	/// Proof: `AmbassadorCore::Reviews` (`max_values`: None, `max_size`: Some(62), added: 2537, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorCore::ReviewCooldown` (r:0 w:1)
+	/// Proof: `AmbassadorCore::ReviewCooldown` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
 	fn offboard() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `331`
-		//  Estimated: `3514`
-		// Minimum execution time: 21_000_000 picoseconds.
-		Weight::from_parts(22_000_000, 0)
+		// TODO: run benchmarks
+		Weight::from_parts(24_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 3514))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(5))
 	}
 	/// Storage: `AmbassadorCore::Member` (r:1 w:1)
 	/// Proof: `AmbassadorCore::Member` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
@@ -196,28 +202,61 @@ impl<T: frame_system::Config> pallet_core_fellowship::WeightInfo for WeightInfo<
 	/// Proof: `AmbassadorCore::Member` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	/// Storage: `AmbassadorCore::MemberEvidence` (r:1 w:1)
 	/// Proof: `AmbassadorCore::MemberEvidence` (`max_values`: None, `max_size`: Some(65581), added: 68056, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorCore::MemberReview` (r:1 w:1)
+	/// Proof: `AmbassadorCore::MemberReview` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorCore::Reviews` (r:0 w:1)
+	/// Proof: `AmbassadorCore::Reviews` (`max_values`: None, `max_size`: Some(62), added: 2537, mode: `MaxEncodedLen`)
 	fn approve() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `65967`
-		//  Estimated: `69046`
-		// Minimum execution time: 78_000_000 picoseconds.
+		// TODO: run benchmarks
 		Weight::from_parts(104_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 69046))
-			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `AmbassadorCore::Member` (r:1 w:0)
 	/// Proof: `AmbassadorCore::Member` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	/// Storage: `AmbassadorCore::MemberEvidence` (r:1 w:1)
 	/// Proof: `AmbassadorCore::MemberEvidence` (`max_values`: None, `max_size`: Some(65581), added: 68056, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorCore::MemberReview` (r:1 w:1)
+	/// Proof: `AmbassadorCore::MemberReview` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorCore::Reviews` (r:0 w:1)
+	/// Proof: `AmbassadorCore::Reviews` (`max_values`: None, `max_size`: Some(62), added: 2537, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorCore::ReviewCount` (r:1 w:1)
+	/// Proof: `AmbassadorCore::ReviewCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorCore::ReviewCooldown` (r:1 w:1)
+	/// Proof: `AmbassadorCore::ReviewCooldown` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
 	fn submit_evidence() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `151`
-		//  Estimated: `69046`
-		// Minimum execution time: 43_000_000 picoseconds.
-		Weight::from_parts(44_000_000, 0)
+		// TODO: run benchmarks
+		Weight::from_parts(48_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 69046))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `AmbassadorCollective::Members` (r:1 w:1)
+	/// Proof: `AmbassadorCollective::Members` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorCore::Member` (r:1 w:1)
+	/// Proof: `AmbassadorCore::Member` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorCore::Params` (r:1 w:0)
+	/// Proof: `AmbassadorCore::Params` (`max_values`: Some(1), `max_size`: Some(364), added: 859, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorCollective::MemberCount` (r:1 w:1)
+	/// Proof: `AmbassadorCollective::MemberCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorCore::MemberEvidence` (r:1 w:1)
+	/// Proof: `AmbassadorCore::MemberEvidence` (`max_values`: None, `max_size`: Some(65581), added: 68056, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorCollective::IndexToId` (r:0 w:1)
+	/// Proof: `AmbassadorCollective::IndexToId` (`max_values`: None, `max_size`: Some(54), added: 2529, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorCollective::IdToIndex` (r:0 w:1)
+	/// Proof: `AmbassadorCollective::IdToIndex` (`max_values`: None, `max_size`: Some(54), added: 2529, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorCore::MemberReview` (r:1 w:1)
+	/// Proof: `AmbassadorCore::MemberReview` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorCore::Reviews` (r:1 w:1)
+	/// Proof: `AmbassadorCore::Reviews` (`max_values`: None, `max_size`: Some(62), added: 2537, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorCore::ReviewCooldown` (r:0 w:1)
+	/// Proof: `AmbassadorCore::ReviewCooldown` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	fn conclude_review() -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(113_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 69046))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(9))
 	}
 }
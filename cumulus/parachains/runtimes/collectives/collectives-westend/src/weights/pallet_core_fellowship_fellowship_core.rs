@@ -69,15 +69,16 @@ impl<T: frame_system::Config> pallet_core_fellowship::WeightInfo for WeightInfo<
 	/// Proof: `FellowshipCollective::IdToIndex` (`max_values`: None, `max_size`: Some(54), added: 2529, mode: `MaxEncodedLen`)
 	/// Storage: `FellowshipCore::MemberEvidence` (r:1 w:1)
 	/// Proof: `FellowshipCore::MemberEvidence` (`max_values`: None, `max_size`: Some(65581), added: 68056, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipCore::MemberReview` (r:1 w:1)
+	/// Proof: `FellowshipCore::MemberReview` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipCore::Reviews` (r:0 w:1)
+	/// Proof: `FellowshipCore::Reviews` (`max_values`: None, `max_size`: Some(62), added: 2537, mode: `MaxEncodedLen`)
 	fn bump_offboard() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `66144`
-		//  Estimated: `69046`
-		// Minimum execution time: 109_000_000 picoseconds.
+		// TODO: run benchmarks
 		Weight::from_parts(125_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 69046))
-			.saturating_add(T::DbWeight::get().reads(6))
-			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(6))
 	}
 	/// Storage: `FellowshipCore::Member` (r:1 w:1)
 	/// Proof: `FellowshipCore::Member` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
@@ -91,15 +92,16 @@ impl<T: frame_system::Config> pallet_core_fellowship::WeightInfo for WeightInfo<
 	/// Proof: `FellowshipCollective::IdToIndex` (`max_values`: None, `max_size`: Some(54), added: 2529, mode: `MaxEncodedLen`)
 	/// Storage: `FellowshipCore::MemberEvidence` (r:1 w:1)
 	/// Proof: `FellowshipCore::MemberEvidence` (`max_values`: None, `max_size`: Some(65581), added: 68056, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipCore::MemberReview` (r:1 w:1)
+	/// Proof: `FellowshipCore::MemberReview` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipCore::Reviews` (r:0 w:1)
+	/// Proof: `FellowshipCore::Reviews` (`max_values`: None, `max_size`: Some(62), added: 2537, mode: `MaxEncodedLen`)
 	fn bump_demote() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `66254`
-		//  Estimated: `69046`
-		// Minimum execution time: 112_000_000 picoseconds.
+		// TODO: run benchmarks
 		Weight::from_parts(114_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 69046))
-			.saturating_add(T::DbWeight::get().reads(6))
-			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(6))
 	}
 	/// Storage: `FellowshipCollective::Members` (r:1 w:0)
 	/// Proof: `FellowshipCollective::Members` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
@@ -149,15 +151,16 @@ impl<T: frame_system::Config> pallet_core_fellowship::WeightInfo for WeightInfo<
 	/// Proof: `FellowshipCollective::IndexToId` (`max_values`: None, `max_size`: Some(54), added: 2529, mode: `MaxEncodedLen`)
 	/// Storage: `FellowshipCollective::IdToIndex` (r:0 w:1)
 	/// Proof: `FellowshipCollective::IdToIndex` (`max_values`: None, `max_size`: Some(54), added: 2529, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipCore::MemberReview` (r:1 w:1)
+	/// Proof: `FellowshipCore::MemberReview` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipCore::Reviews` (r:0 w:1)
+	/// Proof: `FellowshipCore::Reviews` (`max_values`: None, `max_size`: Some(62), added: 2537, mode: `MaxEncodedLen`)
 	fn promote() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `66122`
-		//  Estimated: `69046`
-		// Minimum execution time: 97_000_000 picoseconds.
+		// TODO: run benchmarks
 		Weight::from_parts(129_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 69046))
-			.saturating_add(T::DbWeight::get().reads(5))
-			.saturating_add(T::DbWeight::get().writes(6))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(8))
 	}
 	/// Storage: `FellowshipCollective::Members` (r:1 w:0)
 	/// Proof: `FellowshipCollective::Members` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
@@ -165,15 +168,18 @@ impl<T: frame_system::Config> pallet_core_fellowship::WeightInfo for WeightInfo<
 	/// Proof: `FellowshipCore::Member` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	/// Storage: `FellowshipCore::MemberEvidence` (r:0 w:1)
 	/// Proof: `FellowshipCore::MemberEvidence` (`max_values`: None, `max_size`: Some(65581), added: 68056, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipCore::MemberReview` (r:1 w:1)
+	/// Proof: `FellowshipCore::MemberReview` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipCore::Reviews` (r:0 w:1)
+	/// Proof: `FellowshipCore::Reviews` (`max_values`: None, `max_size`: Some(62), added: 2537, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipCore::ReviewCooldown` (r:0 w:1)
+	/// Proof: `FellowshipCore::ReviewCooldown` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
 	fn offboard() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `464`
-		//  Estimated: `3514`
-		// Minimum execution time: 22_000_000 picoseconds.
-		Weight::from_parts(22_000_000, 0)
+		// TODO: run benchmarks
+		Weight::from_parts(24_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 3514))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(5))
 	}
 	/// Storage: `FellowshipCore::Member` (r:1 w:1)
 	/// Proof: `FellowshipCore::Member` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
@@ -195,28 +201,61 @@ impl<T: frame_system::Config> pallet_core_fellowship::WeightInfo for WeightInfo<
 	/// Proof: `FellowshipCore::Member` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	/// Storage: `FellowshipCore::MemberEvidence` (r:1 w:1)
 	/// Proof: `FellowshipCore::MemberEvidence` (`max_values`: None, `max_size`: Some(65581), added: 68056, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipCore::MemberReview` (r:1 w:1)
+	/// Proof: `FellowshipCore::MemberReview` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipCore::Reviews` (r:0 w:1)
+	/// Proof: `FellowshipCore::Reviews` (`max_values`: None, `max_size`: Some(62), added: 2537, mode: `MaxEncodedLen`)
 	fn approve() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `66100`
-		//  Estimated: `69046`
-		// Minimum execution time: 89_000_000 picoseconds.
+		// TODO: run benchmarks
 		Weight::from_parts(119_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 69046))
-			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `FellowshipCore::Member` (r:1 w:0)
 	/// Proof: `FellowshipCore::Member` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	/// Storage: `FellowshipCore::MemberEvidence` (r:1 w:1)
 	/// Proof: `FellowshipCore::MemberEvidence` (`max_values`: None, `max_size`: Some(65581), added: 68056, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipCore::MemberReview` (r:1 w:1)
+	/// Proof: `FellowshipCore::MemberReview` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipCore::Reviews` (r:0 w:1)
+	/// Proof: `FellowshipCore::Reviews` (`max_values`: None, `max_size`: Some(62), added: 2537, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipCore::ReviewCount` (r:1 w:1)
+	/// Proof: `FellowshipCore::ReviewCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipCore::ReviewCooldown` (r:1 w:1)
+	/// Proof: `FellowshipCore::ReviewCooldown` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
 	fn submit_evidence() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `184`
-		//  Estimated: `69046`
-		// Minimum execution time: 43_000_000 picoseconds.
-		Weight::from_parts(52_000_000, 0)
+		// TODO: run benchmarks
+		Weight::from_parts(56_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 69046))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `FellowshipCollective::Members` (r:1 w:1)
+	/// Proof: `FellowshipCollective::Members` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipCore::Member` (r:1 w:1)
+	/// Proof: `FellowshipCore::Member` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipCore::Params` (r:1 w:0)
+	/// Proof: `FellowshipCore::Params` (`max_values`: Some(1), `max_size`: Some(364), added: 859, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipCollective::MemberCount` (r:1 w:1)
+	/// Proof: `FellowshipCollective::MemberCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipCore::MemberEvidence` (r:1 w:1)
+	/// Proof: `FellowshipCore::MemberEvidence` (`max_values`: None, `max_size`: Some(65581), added: 68056, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipCollective::IndexToId` (r:0 w:1)
+	/// Proof: `FellowshipCollective::IndexToId` (`max_values`: None, `max_size`: Some(54), added: 2529, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipCollective::IdToIndex` (r:0 w:1)
+	/// Proof: `FellowshipCollective::IdToIndex` (`max_values`: None, `max_size`: Some(54), added: 2529, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipCore::MemberReview` (r:1 w:1)
+	/// Proof: `FellowshipCore::MemberReview` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipCore::Reviews` (r:1 w:1)
+	/// Proof: `FellowshipCore::Reviews` (`max_values`: None, `max_size`: Some(62), added: 2537, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipCore::ReviewCooldown` (r:0 w:1)
+	/// Proof: `FellowshipCore::ReviewCooldown` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	fn conclude_review() -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(132_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 69046))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(9))
 	}
 }
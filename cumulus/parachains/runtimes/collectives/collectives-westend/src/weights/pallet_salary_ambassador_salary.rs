@@ -127,14 +127,13 @@ impl<T: frame_system::Config> pallet_salary::WeightInfo for WeightInfo<T> {
 	/// Proof: `XcmpQueue::OutboundXcmpMessages` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `PolkadotXcm::Queries` (r:0 w:1)
 	/// Proof: `PolkadotXcm::Queries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AmbassadorSalary::PayoutAsset` (r:1 w:0)
+	/// Proof: `AmbassadorSalary::PayoutAsset` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
 	fn payout() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `879`
-		//  Estimated: `4344`
-		// Minimum execution time: 68_000_000 picoseconds.
+		// TODO: run benchmarks
 		Weight::from_parts(72_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 4344))
-			.saturating_add(T::DbWeight::get().reads(10))
+			.saturating_add(T::DbWeight::get().reads(11))
 			.saturating_add(T::DbWeight::get().writes(7))
 	}
 	/// Storage: `AmbassadorSalary::Status` (r:1 w:1)
@@ -161,14 +160,13 @@ impl<T: frame_system::Config> pallet_salary::WeightInfo for WeightInfo<T> {
 	/// Proof: `XcmpQueue::OutboundXcmpMessages` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `PolkadotXcm::Queries` (r:0 w:1)
 	/// Proof: `PolkadotXcm::Queries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AmbassadorSalary::PayoutAsset` (r:1 w:0)
+	/// Proof: `AmbassadorSalary::PayoutAsset` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
 	fn payout_other() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `879`
-		//  Estimated: `4344`
-		// Minimum execution time: 69_000_000 picoseconds.
+		// TODO: run benchmarks
 		Weight::from_parts(70_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 4344))
-			.saturating_add(T::DbWeight::get().reads(10))
+			.saturating_add(T::DbWeight::get().reads(11))
 			.saturating_add(T::DbWeight::get().writes(7))
 	}
 	/// Storage: `AmbassadorSalary::Status` (r:1 w:1)
@@ -187,4 +185,15 @@ impl<T: frame_system::Config> pallet_salary::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
+	/// Storage: `AmbassadorSalary::Claimant` (r:1 w:0)
+	/// Proof: `AmbassadorSalary::Claimant` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorSalary::PayoutAsset` (r:0 w:1)
+	/// Proof: `AmbassadorSalary::PayoutAsset` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	fn set_payout_asset() -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(13_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3551))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
@@ -126,14 +126,13 @@ impl<T: frame_system::Config> pallet_salary::WeightInfo for WeightInfo<T> {
 	/// Proof: `XcmpQueue::OutboundXcmpMessages` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `PolkadotXcm::Queries` (r:0 w:1)
 	/// Proof: `PolkadotXcm::Queries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `FellowshipSalary::PayoutAsset` (r:1 w:0)
+	/// Proof: `FellowshipSalary::PayoutAsset` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
 	fn payout() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `774`
-		//  Estimated: `4239`
-		// Minimum execution time: 67_000_000 picoseconds.
+		// TODO: run benchmarks
 		Weight::from_parts(74_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 4239))
-			.saturating_add(T::DbWeight::get().reads(10))
+			.saturating_add(T::DbWeight::get().reads(11))
 			.saturating_add(T::DbWeight::get().writes(7))
 	}
 	/// Storage: `FellowshipSalary::Status` (r:1 w:1)
@@ -160,14 +159,13 @@ impl<T: frame_system::Config> pallet_salary::WeightInfo for WeightInfo<T> {
 	/// Proof: `XcmpQueue::OutboundXcmpMessages` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `PolkadotXcm::Queries` (r:0 w:1)
 	/// Proof: `PolkadotXcm::Queries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `FellowshipSalary::PayoutAsset` (r:1 w:0)
+	/// Proof: `FellowshipSalary::PayoutAsset` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
 	fn payout_other() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `774`
-		//  Estimated: `4239`
-		// Minimum execution time: 66_000_000 picoseconds.
+		// TODO: run benchmarks
 		Weight::from_parts(71_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 4239))
-			.saturating_add(T::DbWeight::get().reads(10))
+			.saturating_add(T::DbWeight::get().reads(11))
 			.saturating_add(T::DbWeight::get().writes(7))
 	}
 	/// Storage: `FellowshipSalary::Status` (r:1 w:1)
@@ -186,4 +184,15 @@ impl<T: frame_system::Config> pallet_salary::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
+	/// Storage: `FellowshipSalary::Claimant` (r:1 w:0)
+	/// Proof: `FellowshipSalary::Claimant` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipSalary::PayoutAsset` (r:0 w:1)
+	/// Proof: `FellowshipSalary::PayoutAsset` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	fn set_payout_asset() -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(13_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3551))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
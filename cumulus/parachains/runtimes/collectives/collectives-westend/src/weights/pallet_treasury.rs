@@ -175,29 +175,27 @@ impl<T: frame_system::Config> pallet_treasury::WeightInfo for WeightInfo<T> {
 	/// Proof: `XcmpQueue::OutboundXcmpMessages` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `PolkadotXcm::Queries` (r:0 w:1)
 	/// Proof: `PolkadotXcm::Queries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `FellowshipTreasury::PaymentSpends` (r:0 w:1)
+	/// Proof: `FellowshipTreasury::PaymentSpends` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
 	fn payout() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `629`
-		//  Estimated: `5318`
-		// Minimum execution time: 472_000_000 picoseconds.
+		// TODO: run benchmarks
 		Weight::from_parts(492_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 5318))
 			.saturating_add(T::DbWeight::get().reads(9))
-			.saturating_add(T::DbWeight::get().writes(6))
+			.saturating_add(T::DbWeight::get().writes(7))
 	}
 	/// Storage: `FellowshipTreasury::Spends` (r:1 w:1)
 	/// Proof: `FellowshipTreasury::Spends` (`max_values`: None, `max_size`: Some(1853), added: 4328, mode: `MaxEncodedLen`)
 	/// Storage: `PolkadotXcm::Queries` (r:1 w:1)
 	/// Proof: `PolkadotXcm::Queries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `FellowshipTreasury::PaymentSpends` (r:1 w:1)
+	/// Proof: `FellowshipTreasury::PaymentSpends` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
 	fn check_status() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `383`
-		//  Estimated: `5318`
-		// Minimum execution time: 211_000_000 picoseconds.
+		// TODO: run benchmarks
 		Weight::from_parts(215_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 5318))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `FellowshipTreasury::Spends` (r:1 w:1)
 	/// Proof: `FellowshipTreasury::Spends` (`max_values`: None, `max_size`: Some(1853), added: 4328, mode: `MaxEncodedLen`)
@@ -211,4 +209,44 @@ impl<T: frame_system::Config> pallet_treasury::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `FellowshipTreasury::Spends` (r:100 w:100)
+	/// Proof: `FellowshipTreasury::Spends` (`max_values`: None, `max_size`: Some(1853), added: 4328, mode: `MaxEncodedLen`)
+	/// Storage: `PolkadotXcm::QueryCounter` (r:1 w:1)
+	/// Proof: `PolkadotXcm::QueryCounter` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `PolkadotXcm::Queries` (r:0 w:1)
+	/// Proof: `PolkadotXcm::Queries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `FellowshipTreasury::PaymentSpends` (r:0 w:100)
+	/// Proof: `FellowshipTreasury::PaymentSpends` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 100]`.
+	fn payout_batch(n: u32, ) -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(617_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 5318))
+			.saturating_add(Weight::from_parts(114_208_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(5))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 4328).saturating_mul(n.into()))
+	}
+	/// Storage: `FellowshipTreasury::PollCursor` (r:1 w:1)
+	/// Proof: `FellowshipTreasury::PollCursor` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipTreasury::PaymentSpends` (r:101 w:100)
+	/// Proof: `FellowshipTreasury::PaymentSpends` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `PolkadotXcm::Queries` (r:1 w:1)
+	/// Proof: `PolkadotXcm::Queries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `FellowshipTreasury::Spends` (r:100 w:100)
+	/// Proof: `FellowshipTreasury::Spends` (`max_values`: None, `max_size`: Some(1853), added: 4328, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 100]`.
+	fn poll_payment(n: u32, ) -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(223_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 5825))
+			.saturating_add(Weight::from_parts(109_862_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 4328).saturating_mul(n.into()))
+	}
 }
@@ -15,7 +15,7 @@
 
 use crate::{
 	Balance, Balances, RandomnessCollectiveFlip, Runtime, RuntimeCall, RuntimeEvent,
-	RuntimeHoldReason, Timestamp,
+	RuntimeHoldReason, Timestamp, MINUTES,
 };
 use frame_support::{
 	parameter_types,
@@ -68,9 +68,10 @@ impl Config for Runtime {
 	type MaxDebugBufferLen = ConstU32<{ 2 * 1024 * 1024 }>;
 	type MaxDelegateDependencies = ConstU32<32>;
 	type CodeHashLockupDepositPercent = CodeHashLockupDepositPercent;
+	type CodeUpgradeDelay = ConstU32<{ 10 * MINUTES }>;
 	type Migrations = ();
 	type RuntimeHoldReason = RuntimeHoldReason;
-	type Debug = ();
+	type Debug = pallet_contracts::debug::CallTracer;
 	type Environment = ();
 	type Xcm = pallet_xcm::Pallet<Self>;
 }
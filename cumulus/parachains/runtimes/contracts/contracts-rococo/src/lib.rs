@@ -595,6 +595,46 @@ impl_runtime_apis! {
 			)
 		}
 
+		fn trace_call(
+			origin: AccountId,
+			dest: AccountId,
+			value: Balance,
+			gas_limit: Option<Weight>,
+			storage_deposit_limit: Option<Balance>,
+			input_data: Vec<u8>,
+		) -> pallet_contracts::ContractTraceResult<Balance, EventRecord> {
+			let gas_limit = gas_limit.unwrap_or(RuntimeBlockWeights::get().max_block);
+			Contracts::bare_trace_call(
+				origin,
+				dest,
+				value,
+				gas_limit,
+				storage_deposit_limit,
+				input_data,
+				pallet_contracts::Determinism::Enforced,
+			)
+		}
+
+		fn estimate_call(
+			origin: AccountId,
+			dest: AccountId,
+			value: Balance,
+			gas_limit: Option<Weight>,
+			storage_deposit_limit: Option<Balance>,
+			input_data: Vec<u8>,
+		) -> pallet_contracts::ContractEstimateResult<Balance, EventRecord> {
+			let gas_limit = gas_limit.unwrap_or(RuntimeBlockWeights::get().max_block);
+			Contracts::bare_estimate_call(
+				origin,
+				dest,
+				value,
+				gas_limit,
+				storage_deposit_limit,
+				input_data,
+				pallet_contracts::Determinism::Enforced,
+			)
+		}
+
 		fn instantiate(
 			origin: AccountId,
 			value: Balance,
@@ -638,6 +678,12 @@ impl_runtime_apis! {
 		) -> pallet_contracts::GetStorageResult {
 			Contracts::get_storage(address, key)
 		}
+
+		fn pending_code_upgrade(
+			address: AccountId,
+		) -> Option<pallet_contracts::CodeUpgrade<Hash, BlockNumber>> {
+			Contracts::pending_code_upgrade(address)
+		}
 	}
 
 	#[cfg(feature = "try-runtime")]
@@ -234,4 +234,5 @@ impl pallet_broker::Config for Runtime {
 	type PalletId = BrokerPalletId;
 	type AdminOrigin = EnsureRoot<AccountId>;
 	type PriceAdapter = pallet_broker::Linear;
+	type RenewalPriceCap = ();
 }
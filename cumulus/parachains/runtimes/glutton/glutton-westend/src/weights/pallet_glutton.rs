@@ -175,4 +175,89 @@ impl<T: frame_system::Config> pallet_glutton::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(0, 0))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `Glutton::ChildTrashDataCount` (r:1 w:1)
+	/// Proof: `Glutton::ChildTrashDataCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Glutton::ChildTrashDataDepth` (r:1 w:1)
+	/// Proof: `Glutton::ChildTrashDataDepth` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: UNKNOWN KEY `0x3a6368696c645f73746f726167653a64656661756c743a676c7574746f6e` (r:1 w:1)
+	/// Proof: UNKNOWN KEY `0x3a6368696c645f73746f726167653a64656661756c743a676c7574746f6e` (r:1 w:1)
+	/// The range of component `n` is `[0, 1000]`.
+	fn initialize_child_trie_grow(n: u32, ) -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(12_305_000, 0)
+			.saturating_add(Weight::from_parts(0, 1489))
+			.saturating_add(Weight::from_parts(9_614_338, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `Glutton::ChildTrashDataCount` (r:1 w:1)
+	/// Proof: `Glutton::ChildTrashDataCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Glutton::ChildTrashDataDepth` (r:1 w:1)
+	/// Proof: `Glutton::ChildTrashDataDepth` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: UNKNOWN KEY `0x3a6368696c645f73746f726167653a64656661756c743a676c7574746f6e` (r:1 w:1)
+	/// Proof: UNKNOWN KEY `0x3a6368696c645f73746f726167653a64656661756c743a676c7574746f6e` (r:1 w:1)
+	/// The range of component `n` is `[0, 1000]`.
+	fn initialize_child_trie_shrink(n: u32, ) -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(12_203_871, 0)
+			.saturating_add(Weight::from_parts(0, 1489))
+			.saturating_add(Weight::from_parts(1_187_263, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 41).saturating_mul(n.into()))
+	}
+	/// Storage: `Glutton::TrashData` (r:0 w:5000)
+	/// Proof: `Glutton::TrashData` (`max_values`: Some(65000), `max_size`: Some(1036), added: 3016, mode: `MaxEncodedLen`)
+	/// The range of component `i` is `[0, 5000]`.
+	fn waste_proof_size_write(i: u32, ) -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(776_000, 0)
+			.saturating_add(Weight::from_parts(0, 990))
+			.saturating_add(Weight::from_parts(7_271_904, 0).saturating_mul(i.into()))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(i.into())))
+			.saturating_add(Weight::from_parts(0, 3016).saturating_mul(i.into()))
+	}
+	/// Storage: UNKNOWN KEY `0x3a6368696c645f73746f726167653a64656661756c743a676c7574746f6e` (r:1 w:0)
+	/// Proof: UNKNOWN KEY `0x3a6368696c645f73746f726167653a64656661756c743a676c7574746f6e` (r:1 w:0)
+	/// The range of component `i` is `[0, 5000]`.
+	fn waste_child_proof_size_some(i: u32, ) -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(694_000, 0)
+			.saturating_add(Weight::from_parts(0, 1163))
+			.saturating_add(Weight::from_parts(6_011_582, 0).saturating_mul(i.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(Weight::from_parts(0, 1061).saturating_mul(i.into()))
+	}
+	/// Storage: UNKNOWN KEY `0x3a6368696c645f73746f726167653a64656661756c743a676c7574746f6e` (r:1 w:1)
+	/// Proof: UNKNOWN KEY `0x3a6368696c645f73746f726167653a64656661756c743a676c7574746f6e` (r:1 w:1)
+	/// The range of component `i` is `[0, 5000]`.
+	fn waste_child_proof_size_write(i: u32, ) -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(781_000, 0)
+			.saturating_add(Weight::from_parts(0, 1163))
+			.saturating_add(Weight::from_parts(7_543_226, 0).saturating_mul(i.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(Weight::from_parts(0, 1061).saturating_mul(i.into()))
+	}
+	/// Storage: UNKNOWN KEY `0x3a6368696c645f73746f726167653a64656661756c743a676c7574746f6e` (r:1 w:0)
+	/// Proof: UNKNOWN KEY `0x3a6368696c645f73746f726167653a64656661756c743a676c7574746f6e` (r:1 w:0)
+	/// The range of component `d` is `[0, 16]`.
+	fn waste_child_trie_depth(d: u32, ) -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(4_301_457, 0)
+			.saturating_add(Weight::from_parts(0, 1196))
+			.saturating_add(Weight::from_parts(3_154_018, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(Weight::from_parts(0, 74).saturating_mul(d.into()))
+	}
+	/// Storage: `Glutton::Pattern` (r:0 w:1)
+	/// Proof: `Glutton::Pattern` (`max_values`: Some(1), `max_size`: Some(6), added: 501, mode: `MaxEncodedLen`)
+	fn set_pov_pattern() -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(8_402_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
@@ -406,4 +406,32 @@ impl<T: frame_system::Config> pallet_identity::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `Identity::Registrars` (r:1 w:0)
+	/// Proof: `Identity::Registrars` (`max_values`: Some(1), `max_size`: Some(1141), added: 1636, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::IdentityOf` (r:1 w:0)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(7572), added: 10047, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::AttestationsOf` (r:1 w:1)
+	/// Proof: `Identity::AttestationsOf` (`max_values`: None, `max_size`: Some(7219), added: 9694, mode: `MaxEncodedLen`)
+	/// The range of component `a` is `[0, 64]`.
+	fn attest(a: u32, ) -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(40_127_509, 0)
+			.saturating_add(Weight::from_parts(0, 11037))
+			.saturating_add(Weight::from_parts(163_724, 0).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Identity::Registrars` (r:1 w:0)
+	/// Proof: `Identity::Registrars` (`max_values`: Some(1), `max_size`: Some(1141), added: 1636, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::AttestationsOf` (r:1 w:1)
+	/// Proof: `Identity::AttestationsOf` (`max_values`: None, `max_size`: Some(7219), added: 9694, mode: `MaxEncodedLen`)
+	/// The range of component `a` is `[1, 64]`.
+	fn revoke_attestation(a: u32, ) -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(21_042_187, 0)
+			.saturating_add(Weight::from_parts(0, 10684))
+			.saturating_add(Weight::from_parts(102_311, 0).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
@@ -293,6 +293,7 @@ impl pallet_identity::Config for Test {
 	type MaxSubAccounts = ConstU32<2>;
 	type IdentityInformation = IdentityInfo<ConstU32<2>>;
 	type MaxRegistrars = ConstU32<20>;
+	type MaxAttestations = ConstU32<64>;
 	type RegistrarOrigin = EnsureRoot<AccountId>;
 	type ForceOrigin = EnsureRoot<AccountId>;
 	type OffchainSignature = MultiSignature;
@@ -192,4 +192,64 @@ impl<T: frame_system::Config> pallet_conviction_voting::WeightInfo for WeightInf
 			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
+	/// Storage: `ConvictionVoting::VotingFor` (r:2 w:1)
+	/// Proof: `ConvictionVoting::VotingFor` (`max_values`: None, `max_size`: Some(27241), added: 29716, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::PartialDelegationsFor` (r:1 w:1)
+	/// Proof: `ConvictionVoting::PartialDelegationsFor` (`max_values`: None, `max_size`: Some(1099), added: 3574, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::ReferendumInfoFor` (r:512 w:512)
+	/// Proof: `Referenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(936), added: 3411, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Agenda` (r:2 w:2)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::ClassLocksFor` (r:1 w:1)
+	/// Proof: `ConvictionVoting::ClassLocksFor` (`max_values`: None, `max_size`: Some(311), added: 2786, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::RetractedDelegations` (r:512 w:512)
+	/// Proof: `ConvictionVoting::RetractedDelegations` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[0, 512]`.
+	/// The range of component `s` is `[0, 512]`.
+	fn delegate_partial(r: u32, s: u32, ) -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(2_060_112_774, 0)
+			.saturating_add(Weight::from_parts(0, 83866))
+			.saturating_add(Weight::from_parts(44_102_519, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes(7))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 3411).saturating_mul(r.into()))
+			.saturating_add(Weight::from_parts(21_406_000, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(s.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 5970).saturating_mul(s.into()))
+	}
+	/// Storage: `ConvictionVoting::PartialDelegationsFor` (r:1 w:1)
+	/// Proof: `ConvictionVoting::PartialDelegationsFor` (`max_values`: None, `max_size`: Some(1099), added: 3574, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::VotingFor` (r:2 w:2)
+	/// Proof: `ConvictionVoting::VotingFor` (`max_values`: None, `max_size`: Some(27241), added: 29716, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::ReferendumInfoFor` (r:512 w:512)
+	/// Proof: `Referenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(936), added: 3411, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Agenda` (r:2 w:2)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::RetractedDelegations` (r:512 w:512)
+	/// Proof: `ConvictionVoting::RetractedDelegations` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[0, 512]`.
+	/// The range of component `s` is `[0, 512]`.
+	fn undelegate_partial(r: u32, s: u32, ) -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(1_921_005_380, 0)
+			.saturating_add(Weight::from_parts(0, 83866))
+			.saturating_add(Weight::from_parts(43_398_120, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes(5))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 3411).saturating_mul(r.into()))
+			.saturating_add(Weight::from_parts(20_873_000, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(s.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 5970).saturating_mul(s.into()))
+	}
 }
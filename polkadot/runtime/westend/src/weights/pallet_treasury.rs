@@ -184,29 +184,27 @@ impl<T: frame_system::Config> pallet_treasury::WeightInfo for WeightInfo<T> {
 	/// Proof Skipped: Dmp DownwardMessageQueueHeads (max_values: None, max_size: None, mode: Measured)
 	/// Storage: XcmPallet Queries (r:0 w:1)
 	/// Proof Skipped: XcmPallet Queries (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Treasury PaymentSpends (r:0 w:1)
+	/// Proof: Treasury PaymentSpends (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
 	fn payout() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `737`
-		//  Estimated: `5313`
-		// Minimum execution time: 551_000_000 picoseconds.
+		// TODO: run benchmarks
 		Weight::from_parts(569_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 5313))
 			.saturating_add(T::DbWeight::get().reads(9))
-			.saturating_add(T::DbWeight::get().writes(6))
+			.saturating_add(T::DbWeight::get().writes(7))
 	}
 	/// Storage: Treasury Spends (r:1 w:1)
 	/// Proof: Treasury Spends (max_values: None, max_size: Some(1848), added: 4323, mode: MaxEncodedLen)
 	/// Storage: XcmPallet Queries (r:1 w:1)
 	/// Proof Skipped: XcmPallet Queries (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Treasury PaymentSpends (r:1 w:1)
+	/// Proof: Treasury PaymentSpends (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
 	fn check_status() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `442`
-		//  Estimated: `5313`
-		// Minimum execution time: 245_000_000 picoseconds.
+		// TODO: run benchmarks
 		Weight::from_parts(281_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 5313))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: Treasury Spends (r:1 w:1)
 	/// Proof: Treasury Spends (max_values: None, max_size: Some(1848), added: 4323, mode: MaxEncodedLen)
@@ -220,4 +218,44 @@ impl<T: frame_system::Config> pallet_treasury::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: Treasury Spends (r:100 w:100)
+	/// Proof: Treasury Spends (max_values: None, max_size: Some(1848), added: 4323, mode: MaxEncodedLen)
+	/// Storage: XcmPallet QueryCounter (r:1 w:1)
+	/// Proof Skipped: XcmPallet QueryCounter (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: XcmPallet Queries (r:0 w:1)
+	/// Proof Skipped: XcmPallet Queries (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Treasury PaymentSpends (r:0 w:100)
+	/// Proof: Treasury PaymentSpends (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 100]`.
+	fn payout_batch(n: u32, ) -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(716_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 5313))
+			.saturating_add(Weight::from_parts(131_844_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(5))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 4323).saturating_mul(n.into()))
+	}
+	/// Storage: Treasury PollCursor (r:1 w:1)
+	/// Proof: Treasury PollCursor (max_values: Some(1), max_size: Some(12), added: 507, mode: MaxEncodedLen)
+	/// Storage: Treasury PaymentSpends (r:101 w:100)
+	/// Proof: Treasury PaymentSpends (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: XcmPallet Queries (r:1 w:1)
+	/// Proof Skipped: XcmPallet Queries (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Treasury Spends (r:100 w:100)
+	/// Proof: Treasury Spends (max_values: None, max_size: Some(1848), added: 4323, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 100]`.
+	fn poll_payment(n: u32, ) -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(262_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 5820))
+			.saturating_add(Weight::from_parts(126_415_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 4323).saturating_mul(n.into()))
+	}
 }
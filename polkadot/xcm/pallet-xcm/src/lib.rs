@@ -1443,6 +1443,14 @@ impl<T: Config> QueryHandler for Pallet<T> {
 		}
 	}
 
+	fn block_number() -> Self::BlockNumber {
+		frame_system::Pallet::<T>::block_number()
+	}
+
+	fn remove_query(query_id: Self::QueryId) {
+		Queries::<T>::remove(query_id);
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	fn expect_response(id: Self::QueryId, response: Response) {
 		let response = response.into();
@@ -20,8 +20,8 @@ use frame_support::traits::{
 	tokens::{Pay, PaymentStatus},
 	Get,
 };
-use sp_runtime::traits::TryConvert;
-use sp_std::{marker::PhantomData, vec};
+use sp_runtime::traits::{Saturating, TryConvert};
+use sp_std::{marker::PhantomData, vec, vec::Vec};
 use xcm::{opaque::lts::Weight, prelude::*};
 use xcm_executor::traits::{QueryHandler, QueryResponseStatus};
 
@@ -39,7 +39,13 @@ use xcm_executor::traits::{QueryHandler, QueryResponseStatus};
 /// `TransferAsset`.
 ///
 /// `PayOverXcm::pay` is asynchronous, and returns a `QueryId` which can then be used in
-/// `check_payment` to check the status of the XCM transaction.
+/// `check_payment` to check the status of the XCM transaction. `PayOverXcm::pay_batch` transfers
+/// assets of several kinds in one message under a single `QueryId`, as long as they all reside at
+/// the same location.
+///
+/// A response to the payment is expected within `Timeout` blocks of it being sent. Once that has
+/// passed without one, `check_payment` removes the query and reports the status as `Unknown`, since
+/// the transfer might still have been executed.
 ///
 /// See also `PayAccountId32OverXcm` which is similar to this except that `BeneficiaryRefToLocation`
 /// need not be supplied and `Beneficiary` must implement `Into<[u8; 32]>`.
@@ -84,6 +90,8 @@ impl<
 		AssetKindToLocatableAsset,
 		BeneficiaryRefToLocation,
 	>
+where
+	Querier::BlockNumber: Saturating + PartialOrd,
 {
 	type Beneficiary = Beneficiary;
 	type AssetKind = AssetKind;
@@ -99,34 +107,32 @@ impl<
 		let locatable = AssetKindToLocatableAsset::try_convert(asset_kind)
 			.map_err(|_| xcm::latest::Error::InvalidLocation)?;
 		let LocatableAssetId { asset_id, location: asset_location } = locatable;
-		let destination = Querier::UniversalLocation::get()
-			.invert_target(&asset_location)
-			.map_err(|()| Self::Error::LocationNotInvertible)?;
-		let beneficiary = BeneficiaryRefToLocation::try_convert(&who)
-			.map_err(|_| xcm::latest::Error::InvalidLocation)?;
-
-		let query_id = Querier::new_query(asset_location.clone(), Timeout::get(), Interior::get());
-
-		let message = Xcm(vec![
-			DescendOrigin(Interior::get()),
-			UnpaidExecution { weight_limit: Unlimited, check_origin: None },
-			SetAppendix(Xcm(vec![
-				SetFeesMode { jit_withdraw: true },
-				ReportError(QueryResponseInfo {
-					destination,
-					query_id,
-					max_weight: Weight::zero(),
-				}),
-			])),
-			TransferAsset {
-				beneficiary,
-				assets: vec![Asset { id: asset_id, fun: Fungibility::Fungible(amount) }].into(),
-			},
-		]);
+		Self::transfer(
+			who,
+			asset_location,
+			vec![Asset { id: asset_id, fun: Fungibility::Fungible(amount) }],
+		)
+	}
 
-		let (ticket, _) = Router::validate(&mut Some(asset_location), &mut Some(message))?;
-		Router::deliver(ticket)?;
-		Ok(query_id.into())
+	fn pay_batch(
+		who: &Self::Beneficiary,
+		payments: Vec<(Self::AssetKind, Self::Balance)>,
+	) -> Option<Result<Self::Id, Self::Error>> {
+		let mut asset_location = None;
+		let mut assets = Vec::with_capacity(payments.len());
+		for (asset_kind, amount) in payments {
+			let Ok(locatable) = AssetKindToLocatableAsset::try_convert(asset_kind) else {
+				return Some(Err(xcm::latest::Error::InvalidLocation))
+			};
+			let LocatableAssetId { asset_id, location } = locatable;
+			// All the assets must be transferred by the same message.
+			match &asset_location {
+				Some(asset_location) if *asset_location != location => return None,
+				_ => asset_location = Some(location),
+			}
+			assets.push(Asset { id: asset_id, fun: Fungibility::Fungible(amount) });
+		}
+		asset_location.map(|asset_location| Self::transfer(who, asset_location, assets))
 	}
 
 	fn check_payment(id: Self::Id) -> PaymentStatus {
@@ -137,6 +143,11 @@ impl<
 				Response::ExecutionResult(Some(_)) => PaymentStatus::Failure,
 				_ => PaymentStatus::Unknown,
 			},
+			Pending { timeout } if timeout < Querier::block_number() => {
+				// The response is not awaited any longer.
+				Querier::remove_query(id);
+				PaymentStatus::Unknown
+			},
 			Pending { .. } => PaymentStatus::InProgress,
 			NotFound | UnexpectedVersion => PaymentStatus::Unknown,
 		}
@@ -155,6 +166,65 @@ impl<
 	}
 }
 
+impl<
+		Interior: Get<InteriorLocation>,
+		Router: SendXcm,
+		Querier: QueryHandler,
+		Timeout: Get<Querier::BlockNumber>,
+		Beneficiary: Clone,
+		AssetKind,
+		AssetKindToLocatableAsset: TryConvert<AssetKind, LocatableAssetId>,
+		BeneficiaryRefToLocation: for<'a> TryConvert<&'a Beneficiary, Location>,
+	>
+	PayOverXcm<
+		Interior,
+		Router,
+		Querier,
+		Timeout,
+		Beneficiary,
+		AssetKind,
+		AssetKindToLocatableAsset,
+		BeneficiaryRefToLocation,
+	>
+where
+	Querier::BlockNumber: Saturating + PartialOrd,
+{
+	/// Send a message transferring `assets`, all residing at `asset_location`, to `who`, with the
+	/// outcome of the transfer reported back under the returned query id.
+	fn transfer(
+		who: &Beneficiary,
+		asset_location: Location,
+		assets: Vec<Asset>,
+	) -> Result<Querier::QueryId, xcm::latest::Error> {
+		let destination = Querier::UniversalLocation::get()
+			.invert_target(&asset_location)
+			.map_err(|()| xcm::latest::Error::LocationNotInvertible)?;
+		let beneficiary = BeneficiaryRefToLocation::try_convert(&who)
+			.map_err(|_| xcm::latest::Error::InvalidLocation)?;
+
+		let timeout = Querier::block_number().saturating_add(Timeout::get());
+		let query_id = Querier::new_query(asset_location.clone(), timeout, Interior::get());
+
+		let message = Xcm(vec![
+			DescendOrigin(Interior::get()),
+			UnpaidExecution { weight_limit: Unlimited, check_origin: None },
+			SetAppendix(Xcm(vec![
+				SetFeesMode { jit_withdraw: true },
+				ReportError(QueryResponseInfo {
+					destination,
+					query_id,
+					max_weight: Weight::zero(),
+				}),
+			])),
+			TransferAsset { beneficiary, assets: assets.into() },
+		]);
+
+		let (ticket, _) = Router::validate(&mut Some(asset_location), &mut Some(message))?;
+		Router::deliver(ticket)?;
+		Ok(query_id.into())
+	}
+}
+
 /// Specialization of the [`PayOverXcm`] trait to allow `[u8; 32]`-based `AccountId` values to be
 /// paid on a remote chain.
 ///
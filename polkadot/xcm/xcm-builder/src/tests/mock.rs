@@ -459,6 +459,14 @@ impl<T: Config, BlockNumber: sp_runtime::traits::Zero + Encode> QueryHandler
 			.unwrap_or(QueryResponseStatus::NotFound)
 	}
 
+	fn block_number() -> Self::BlockNumber {
+		Self::BlockNumber::zero()
+	}
+
+	fn remove_query(query_id: Self::QueryId) {
+		QUERIES.with(|q| q.borrow_mut().remove(&query_id));
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	fn expect_response(_id: Self::QueryId, _response: xcm::latest::Response) {
 		// Unnecessary since it's only a test implementation
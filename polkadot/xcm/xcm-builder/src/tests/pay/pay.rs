@@ -18,7 +18,10 @@
 //! correct destination
 
 use super::{mock::*, *};
-use frame_support::{assert_ok, traits::tokens::Pay};
+use frame_support::{
+	assert_ok,
+	traits::tokens::{Pay, PaymentStatus},
+};
 
 /// Type representing both a location and an asset that is held at that location.
 /// The id of the held asset is relative to the location where it is being held.
@@ -174,3 +177,128 @@ fn pay_over_xcm_governance_body() {
 		assert_eq!(mock::Assets::balance(relay_asset_index, &recipient), amount);
 	});
 }
+
+/// Scenario:
+/// A pluralistic body, a Treasury, on the local chain, parachain 42, controls funds of both the
+/// relay's token and a local token of parachain 2 on parachain 2. [`PayOverXcm::pay_batch`] pays
+/// both to account #7 on parachain 2 with a single message, and refuses to batch payments of
+/// assets residing on different chains.
+#[test]
+fn pay_batch_over_xcm_works() {
+	type Payer = PayOverXcm<
+		InteriorBody,
+		TestMessageSender,
+		TestQueryHandler<TestConfig, BlockNumber>,
+		Timeout,
+		AccountId,
+		AssetKind,
+		LocatableAssetKindConverter,
+		AliasesIntoAccountId32<AnyNetwork, AccountId>,
+	>;
+
+	let recipient = AccountId::new([7u8; 32]);
+	let relay_asset_kind =
+		AssetKind { destination: (Parent, Parachain(2)).into(), asset_id: Parent.into() };
+	let local_asset_kind = AssetKind {
+		destination: (Parent, Parachain(2)).into(),
+		asset_id: (PalletInstance(1), GeneralIndex(100)).into(),
+	};
+	let amount = 10 * UNITS;
+
+	new_test_ext().execute_with(|| {
+		// Assets residing on different chains cannot be paid with one message.
+		let remote_asset_kind =
+			AssetKind { destination: (Parent, Parachain(3)).into(), asset_id: Parent.into() };
+		assert!(Payer::pay_batch(
+			&recipient,
+			vec![(relay_asset_kind.clone(), amount), (remote_asset_kind, amount)]
+		)
+		.is_none());
+		assert!(Payer::pay_batch(&recipient, vec![]).is_none());
+		assert_eq!(sent_xcm(), vec![]);
+
+		assert_ok!(Payer::pay_batch(
+			&recipient,
+			vec![(relay_asset_kind, amount), (local_asset_kind, amount)]
+		)
+		.expect("assets reside on the same chain"));
+
+		let expected_message = Xcm(vec![
+			DescendOrigin(Plurality { id: BodyId::Treasury, part: BodyPart::Voice }.into()),
+			UnpaidExecution { weight_limit: Unlimited, check_origin: None },
+			SetAppendix(Xcm(vec![
+				SetFeesMode { jit_withdraw: true },
+				ReportError(QueryResponseInfo {
+					destination: (Parent, Parachain(42)).into(),
+					query_id: 1,
+					max_weight: Weight::zero(),
+				}),
+			])),
+			TransferAsset {
+				assets: vec![
+					(Parent, amount).into(),
+					((PalletInstance(1), GeneralIndex(100)), amount).into(),
+				]
+				.into(),
+				beneficiary: AccountId32 { id: recipient.clone().into(), network: None }.into(),
+			},
+		]);
+		let expected_hash = fake_message_hash(&expected_message);
+		assert_eq!(
+			sent_xcm(),
+			vec![((Parent, Parachain(2)).into(), expected_message, expected_hash)]
+		);
+
+		let (_, message, mut hash) = sent_xcm()[0].clone();
+		let message =
+			Xcm::<<XcmConfig as xcm_executor::Config>::RuntimeCall>::from(message.clone());
+
+		// Execute message in parachain 2 with parachain 42's origin
+		let origin = (Parent, Parachain(42));
+		XcmExecutor::<XcmConfig>::prepare_and_execute(
+			origin,
+			message,
+			&mut hash,
+			Weight::MAX,
+			Weight::zero(),
+		);
+		assert_eq!(mock::Assets::balance(1, &recipient), amount);
+		assert_eq!(mock::Assets::balance(100, &recipient), amount);
+	});
+}
+
+/// Scenario:
+/// A payment made by [`PayOverXcm::pay`] never gets a response. Its status is `InProgress` until
+/// the query times out `Timeout` blocks after the payment, and `Unknown` from then on, the query
+/// being removed.
+#[test]
+fn pay_over_xcm_times_out() {
+	type Payer = PayOverXcm<
+		InteriorBody,
+		TestMessageSender,
+		XcmPallet,
+		Timeout,
+		AccountId,
+		AssetKind,
+		LocatableAssetKindConverter,
+		AliasesIntoAccountId32<AnyNetwork, AccountId>,
+	>;
+
+	let recipient = AccountId::new([7u8; 32]);
+	let asset_kind =
+		AssetKind { destination: (Parent, Parachain(2)).into(), asset_id: Parent.into() };
+
+	new_test_ext().execute_with(|| {
+		let id = Payer::pay(&recipient, asset_kind, 10 * UNITS).unwrap();
+		assert_eq!(Payer::check_payment(id), PaymentStatus::InProgress);
+
+		run_to(1 + Timeout::get());
+		assert_eq!(Payer::check_payment(id), PaymentStatus::InProgress);
+
+		next_block();
+		assert!(XcmPallet::query(id).is_some());
+		assert_eq!(Payer::check_payment(id), PaymentStatus::Unknown);
+		assert!(XcmPallet::query(id).is_none());
+		assert_eq!(Payer::check_payment(id), PaymentStatus::Unknown);
+	});
+}
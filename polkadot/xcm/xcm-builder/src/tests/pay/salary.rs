@@ -20,7 +20,10 @@ use super::{mock::*, *};
 
 use frame_support::{
 	assert_ok,
-	traits::{tokens::GetSalary, RankedMembers},
+	traits::{
+		tokens::{GetSalary, UnityAssetBalanceConversion},
+		Nothing, RankedMembers,
+	},
 };
 use sp_runtime::{traits::ConvertToValue, DispatchResult};
 
@@ -107,7 +110,11 @@ impl GetSalary<Rank, AccountId, Balance> for FixedSalary {
 impl pallet_salary::Config for Test {
 	type WeightInfo = ();
 	type RuntimeEvent = RuntimeEvent;
+	type AssetKind = ();
 	type Paymaster = SalaryPayOverXcm;
+	type DefaultAsset = ();
+	type PayoutAssets = Nothing;
+	type BalanceConverter = UnityAssetBalanceConversion;
 	type Members = TestClub;
 	type Salary = FixedSalary;
 	type RegistrationPeriod = RegistrationPeriod;
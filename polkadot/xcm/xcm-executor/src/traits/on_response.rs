@@ -156,6 +156,13 @@ pub trait QueryHandler {
 	/// Attempt to remove and return the response of query with ID `query_id`.
 	fn take_response(id: Self::QueryId) -> QueryResponseStatus<Self::BlockNumber>;
 
+	/// The current block number, against which the `timeout` of a query is to be checked.
+	fn block_number() -> Self::BlockNumber;
+
+	/// Remove the query with ID `id`, whether its response arrived or not. A response arriving
+	/// afterwards is no longer expected.
+	fn remove_query(id: Self::QueryId);
+
 	/// Makes sure to expect a response with the given id.
 	#[cfg(feature = "runtime-benchmarks")]
 	fn expect_response(id: Self::QueryId, response: Response);
@@ -190,6 +197,12 @@ impl QueryHandler for () {
 		Err(())
 	}
 
+	fn block_number() -> Self::BlockNumber {
+		0
+	}
+
+	fn remove_query(_id: Self::QueryId) {}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	fn expect_response(_id: Self::QueryId, _response: crate::Response) {}
 }
@@ -119,6 +119,7 @@ pub fn create_extrinsic(
 			)),
 			frame_system::CheckNonce::<kitchensink_runtime::Runtime>::from(nonce),
 			frame_system::CheckWeight::<kitchensink_runtime::Runtime>::new(),
+			pallet_tx_pause::CheckPauseRules::<kitchensink_runtime::Runtime>::new(),
 			pallet_skip_feeless_payment::SkipCheckIfFeeless::from(
 				pallet_asset_conversion_tx_payment::ChargeAssetTxPayment::<
 					kitchensink_runtime::Runtime,
@@ -138,6 +139,7 @@ pub fn create_extrinsic(
 			(),
 			(),
 			(),
+			(),
 		),
 	);
 	let signature = raw_payload.using_encoded(|e| sender.sign(e));
@@ -990,6 +992,7 @@ mod tests {
 				let check_era = frame_system::CheckEra::from(Era::Immortal);
 				let check_nonce = frame_system::CheckNonce::from(index);
 				let check_weight = frame_system::CheckWeight::new();
+				let check_pause_rules = pallet_tx_pause::CheckPauseRules::new();
 				let tx_payment = pallet_skip_feeless_payment::SkipCheckIfFeeless::from(
 					pallet_asset_conversion_tx_payment::ChargeAssetTxPayment::from(0, None),
 				);
@@ -1001,12 +1004,23 @@ mod tests {
 					check_era,
 					check_nonce,
 					check_weight,
+					check_pause_rules,
 					tx_payment,
 				);
 				let raw_payload = SignedPayload::from_raw(
 					function,
 					extra,
-					((), spec_version, transaction_version, genesis_hash, genesis_hash, (), (), ()),
+					(
+						(),
+						spec_version,
+						transaction_version,
+						genesis_hash,
+						genesis_hash,
+						(),
+						(),
+						(),
+						(),
+					),
 				);
 				let signature = raw_payload.using_encoded(|payload| signer.sign(payload));
 				let (function, extra, _) = raw_payload.deconstruct();
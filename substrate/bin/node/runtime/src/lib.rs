@@ -41,12 +41,11 @@ use frame_support::{
 		},
 		tokens::{
 			imbalance::ResolveAssetTo, nonfungibles_v2::Inspect, pay::PayAssetFromAccount,
-			GetSalary, PayFromAccount,
+			ConversionToAssetBalance, GetSalary,
 		},
 		AsEnsureOriginWithArg, ConstBool, ConstU128, ConstU16, ConstU32, Contains, Currency,
 		EitherOfDiverse, EqualPrivilegeOnly, Imbalance, InsideBoth, InstanceFilter,
-		KeyOwnerProofSystem, LinearStoragePrice, LockIdentifier, Nothing, OnUnbalanced,
-		WithdrawReasons,
+		KeyOwnerProofSystem, LinearStoragePrice, LockIdentifier, OnUnbalanced, WithdrawReasons,
 	},
 	weights::{
 		constants::{
@@ -256,6 +255,27 @@ impl Contains<RuntimeCallNameOf<Runtime>> for TxPauseWhitelistedCalls {
 	}
 }
 
+/// Calls nested in batches, proxy and multisig calls, which keep the kind of their caller.
+pub struct TxPauseInnerCalls;
+impl pallet_tx_pause::InnerCalls<RuntimeCall> for TxPauseInnerCalls {
+	fn inner_calls(call: &RuntimeCall) -> Vec<&RuntimeCall> {
+		match call {
+			RuntimeCall::Utility(pallet_utility::Call::batch { calls }) |
+			RuntimeCall::Utility(pallet_utility::Call::batch_all { calls }) |
+			RuntimeCall::Utility(pallet_utility::Call::force_batch { calls }) =>
+				calls.iter().collect(),
+			RuntimeCall::Utility(pallet_utility::Call::as_derivative { call, .. }) |
+			RuntimeCall::Utility(pallet_utility::Call::with_weight { call, .. }) |
+			RuntimeCall::Proxy(pallet_proxy::Call::proxy { call, .. }) |
+			RuntimeCall::Proxy(pallet_proxy::Call::proxy_announced { call, .. }) |
+			RuntimeCall::Multisig(pallet_multisig::Call::as_multi { call, .. }) |
+			RuntimeCall::Multisig(pallet_multisig::Call::as_multi_threshold_1 { call, .. }) =>
+				vec![call],
+			_ => Vec::new(),
+		}
+	}
+}
+
 impl pallet_tx_pause::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
@@ -263,6 +283,9 @@ impl pallet_tx_pause::Config for Runtime {
 	type UnpauseOrigin = EnsureRoot<AccountId>;
 	type WhitelistedCalls = TxPauseWhitelistedCalls;
 	type MaxNameLen = ConstU32<256>;
+	type MaxRulesPerCall = ConstU32<16>;
+	type MaxArgsPrefixLen = ConstU32<64>;
+	type InnerCalls = TxPauseInnerCalls;
 	type WeightInfo = pallet_tx_pause::weights::SubstrateWeight<Runtime>;
 }
 
@@ -289,6 +312,10 @@ impl pallet_safe_mode::Config for Runtime {
 	type ForceDepositOrigin = EnsureRoot<AccountId>;
 	type ReleaseDelay = ReleaseDelay;
 	type Notify = ();
+	type ExemptionOrigin = EnsureRoot<AccountId>;
+	type MaxNameLen = ConstU32<256>;
+	type MaxExemptionsPerCall = ConstU32<16>;
+	type MaxArgsPrefixLen = ConstU32<64>;
 	type WeightInfo = pallet_safe_mode::weights::SubstrateWeight<Runtime>;
 }
 
@@ -680,6 +707,7 @@ impl pallet_staking::Config for Runtime {
 	type EraPayout = pallet_staking::ConvertCurve<RewardCurve>;
 	type NextNewSession = Session;
 	type MaxExposurePageSize = ConstU32<256>;
+	type MaxAutoPayoutsPerBlock = ConstU32<8>;
 	type OffendingValidatorsThreshold = OffendingValidatorsThreshold;
 	type ElectionProvider = ElectionProviderMultiPhase;
 	type GenesisElectionProvider = onchain::OnChainExecution<OnChainSeqPhragmen>;
@@ -833,6 +861,7 @@ impl pallet_election_provider_multi_phase::Config for Runtime {
 	type EstimateCallFee = TransactionPayment;
 	type SignedPhase = SignedPhase;
 	type UnsignedPhase = UnsignedPhase;
+	type Pages = ConstU32<1>;
 	type BetterSignedThreshold = ();
 	type OffchainRepeat = OffchainRepeat;
 	type MinerTxPriority = MultiPhaseUnsignedPriority;
@@ -902,6 +931,7 @@ impl pallet_nomination_pools::Config for Runtime {
 	type BalanceToU256 = BalanceToU256;
 	type U256ToBalance = U256ToBalance;
 	type Staking = Staking;
+	type StakeAdapter = pallet_nomination_pools::adapter::TransferStake<Self, Staking>;
 	type PostUnbondingPoolsWindow = PostUnbondPoolsWindow;
 	type MaxMetadataLen = ConstU32<256>;
 	type MaxUnbonding = ConstU32<8>;
@@ -921,6 +951,7 @@ impl pallet_conviction_voting::Config for Runtime {
 	type MaxVotes = ConstU32<512>;
 	type MaxTurnout = frame_support::traits::TotalIssuanceOf<Balances, Self::AccountId>;
 	type Polls = Referenda;
+	type MaxDelegations = ConstU32<16>;
 }
 
 parameter_types! {
@@ -991,6 +1022,42 @@ impl pallet_referenda::Config for Runtime {
 	type Preimages = Preimage;
 }
 
+/// Exposes the calls wrapped by batches, scheduled tasks and whitelisted dispatches, so that
+/// referendum proposals can be previewed as call trees.
+pub struct ProposalCallWrappers;
+impl pallet_referenda::CallWrappers<RuntimeCall, Hash> for ProposalCallWrappers {
+	fn inner_calls(call: &RuntimeCall) -> Vec<pallet_referenda::InnerCall<RuntimeCall, Hash>> {
+		use pallet_referenda::InnerCall::{Inline, Lookup};
+		match call {
+			RuntimeCall::Utility(
+				pallet_utility::Call::batch { calls } |
+				pallet_utility::Call::batch_all { calls } |
+				pallet_utility::Call::force_batch { calls },
+			) => calls.iter().cloned().map(Inline).collect(),
+			RuntimeCall::Utility(
+				pallet_utility::Call::as_derivative { call, .. } |
+				pallet_utility::Call::dispatch_as { call, .. } |
+				pallet_utility::Call::with_weight { call, .. },
+			) |
+			RuntimeCall::Scheduler(
+				pallet_scheduler::Call::schedule { call, .. } |
+				pallet_scheduler::Call::schedule_named { call, .. } |
+				pallet_scheduler::Call::schedule_after { call, .. } |
+				pallet_scheduler::Call::schedule_named_after { call, .. },
+			) |
+			RuntimeCall::Whitelist(
+				pallet_whitelist::Call::dispatch_whitelisted_call_with_preimage { call },
+			) => vec![Inline((**call).clone())],
+			RuntimeCall::Whitelist(pallet_whitelist::Call::dispatch_whitelisted_call {
+				call_hash,
+				call_encoded_len,
+				..
+			}) => vec![Lookup { hash: *call_hash, len: Some(*call_encoded_len) }],
+			_ => Vec::new(),
+		}
+	}
+}
+
 impl pallet_referenda::Config<pallet_referenda::Instance2> for Runtime {
 	type WeightInfo = pallet_referenda::weights::SubstrateWeight<Self>;
 	type RuntimeCall = RuntimeCall;
@@ -1021,6 +1088,19 @@ impl pallet_ranked_collective::Config for Runtime {
 	type VoteWeight = pallet_ranked_collective::Geometric;
 }
 
+/// The collective whose members are tracked by `CoreFellowship` and paid by `Salary`, voting on
+/// the reviews of the evidence they submit.
+pub type CoreCollectiveInstance = pallet_ranked_collective::Instance2;
+impl pallet_ranked_collective::Config<CoreCollectiveInstance> for Runtime {
+	type WeightInfo = pallet_ranked_collective::weights::SubstrateWeight<Self>;
+	type RuntimeEvent = RuntimeEvent;
+	type PromoteOrigin = EnsureRootWithSuccess<AccountId, ConstU16<65535>>;
+	type DemoteOrigin = EnsureRootWithSuccess<AccountId, ConstU16<65535>>;
+	type Polls = CoreFellowship;
+	type MinRankOfClass = traits::Identity;
+	type VoteWeight = pallet_ranked_collective::Geometric;
+}
+
 impl pallet_remark::Config for Runtime {
 	type WeightInfo = pallet_remark::weights::SubstrateWeight<Self>;
 	type RuntimeEvent = RuntimeEvent;
@@ -1235,6 +1315,7 @@ impl pallet_treasury::Config for Runtime {
 	type Paymaster = PayAssetFromAccount<Assets, TreasuryAccount>;
 	type BalanceConverter = AssetRate;
 	type PayoutPeriod = SpendPayoutPeriod;
+	type MaxPaymentBatch = ConstU32<20>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
 }
@@ -1328,19 +1409,33 @@ parameter_types! {
 	pub CodeHashLockupDepositPercent: Perbill = Perbill::from_percent(30);
 }
 
+/// The dispatchables contracts are allowed to call: only those managing their own code upgrades.
+pub struct ContractsCallFilter;
+impl Contains<RuntimeCall> for ContractsCallFilter {
+	fn contains(call: &RuntimeCall) -> bool {
+		matches!(
+			call,
+			RuntimeCall::Contracts(
+				pallet_contracts::Call::schedule_code_upgrade { .. } |
+					pallet_contracts::Call::cancel_code_upgrade { .. }
+			)
+		)
+	}
+}
+
 impl pallet_contracts::Config for Runtime {
 	type Time = Timestamp;
 	type Randomness = RandomnessCollectiveFlip;
 	type Currency = Balances;
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
-	/// The safest default is to allow no calls at all.
+	/// Only the dispatchables contracts need to upgrade their own code are allowed.
 	///
 	/// Runtimes should whitelist dispatchables that are allowed to be called from contracts
 	/// and make sure they are stable. Dispatchables exposed to contracts are not allowed to
 	/// change because that would break already deployed contracts. The `Call` structure itself
 	/// is not allowed to change the indices of existing pallets, too.
-	type CallFilter = Nothing;
+	type CallFilter = ContractsCallFilter;
 	type DepositPerItem = DepositPerItem;
 	type DepositPerByte = DepositPerByte;
 	type DefaultDepositLimit = DefaultDepositLimit;
@@ -1361,7 +1456,8 @@ impl pallet_contracts::Config for Runtime {
 	type Migrations = pallet_contracts::migration::codegen::BenchMigrations;
 	type MaxDelegateDependencies = ConstU32<32>;
 	type CodeHashLockupDepositPercent = CodeHashLockupDepositPercent;
-	type Debug = ();
+	type CodeUpgradeDelay = ConstU32<{ 2 * DAYS }>;
+	type Debug = pallet_contracts::debug::CallTracer;
 	type Environment = ();
 	type Xcm = ();
 }
@@ -1409,6 +1505,7 @@ where
 			frame_system::CheckEra::<Runtime>::from(era),
 			frame_system::CheckNonce::<Runtime>::from(nonce),
 			frame_system::CheckWeight::<Runtime>::new(),
+			pallet_tx_pause::CheckPauseRules::<Runtime>::new(),
 			pallet_skip_feeless_payment::SkipCheckIfFeeless::from(
 				pallet_asset_conversion_tx_payment::ChargeAssetTxPayment::<Runtime>::from(
 					tip, None,
@@ -1486,6 +1583,7 @@ parameter_types! {
 	pub const MaxSubAccounts: u32 = 100;
 	pub const MaxAdditionalFields: u32 = 100;
 	pub const MaxRegistrars: u32 = 20;
+	pub const MaxAttestations: u32 = 64;
 }
 
 impl pallet_identity::Config for Runtime {
@@ -1497,6 +1595,7 @@ impl pallet_identity::Config for Runtime {
 	type MaxSubAccounts = MaxSubAccounts;
 	type IdentityInformation = IdentityInfo<MaxAdditionalFields>;
 	type MaxRegistrars = MaxRegistrars;
+	type MaxAttestations = MaxAttestations;
 	type Slashed = Treasury;
 	type ForceOrigin = EnsureRootOrHalfCouncil;
 	type RegistrarOrigin = EnsureRootOrHalfCouncil;
@@ -1639,6 +1738,11 @@ impl pallet_assets::Config<Instance1> for Runtime {
 	type Freezer = ();
 	type Extra = ();
 	type CallbackHandle = ();
+	type TransferHook = ();
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type MaxHolds = ConstU32<4>;
+	type FreezeIdentifier = RuntimeFreezeReason;
+	type MaxFreezes = ConstU32<4>;
 	type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
 	type RemoveItemsLimit = ConstU32<1000>;
 	#[cfg(feature = "runtime-benchmarks")]
@@ -1668,6 +1772,11 @@ impl pallet_assets::Config<Instance2> for Runtime {
 	type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
 	type RemoveItemsLimit = ConstU32<1000>;
 	type CallbackHandle = ();
+	type TransferHook = ();
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type MaxHolds = ConstU32<4>;
+	type FreezeIdentifier = RuntimeFreezeReason;
+	type MaxFreezes = ConstU32<4>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
 }
@@ -1701,6 +1810,7 @@ impl pallet_asset_conversion::Config for Runtime {
 	type LiquidityWithdrawalFee = LiquidityWithdrawalFee;
 	type WeightInfo = pallet_asset_conversion::weights::SubstrateWeight<Runtime>;
 	type MaxSwapPathLength = ConstU32<4>;
+	type MaxPriceObservations = ConstU32<24>;
 	type MintMinLiquidity = MintMinLiquidity;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
@@ -1786,27 +1896,72 @@ impl GetSalary<u16, AccountId, Balance> for SalaryForRank {
 	}
 }
 
+/// The assets salaries may be paid in: the native one and those with a rate in `AssetRate`.
+pub struct SalaryPayoutAssets;
+impl Contains<NativeOrWithId<u32>> for SalaryPayoutAssets {
+	fn contains(asset_kind: &NativeOrWithId<u32>) -> bool {
+		match asset_kind {
+			NativeOrWithId::Native => true,
+			NativeOrWithId::WithId(id) =>
+				pallet_asset_rate::ConversionRateToNative::<Runtime>::contains_key(id),
+		}
+	}
+}
+
+/// Converts native salaries into the chosen payout asset at its rate in `AssetRate`.
+pub struct SalaryBalanceConverter;
+impl ConversionToAssetBalance<Balance, NativeOrWithId<u32>, Balance> for SalaryBalanceConverter {
+	type Error = pallet_asset_rate::Error<Runtime>;
+
+	fn to_asset_balance(
+		balance: Balance,
+		asset_kind: NativeOrWithId<u32>,
+	) -> Result<Balance, Self::Error> {
+		match asset_kind {
+			NativeOrWithId::Native => Ok(balance),
+			NativeOrWithId::WithId(id) => AssetRate::to_asset_balance(balance, id),
+		}
+	}
+}
+
 impl pallet_salary::Config for Runtime {
 	type WeightInfo = ();
 	type RuntimeEvent = RuntimeEvent;
-	type Paymaster = PayFromAccount<Balances, TreasuryAccount>;
-	type Members = RankedCollective;
+	type AssetKind = NativeOrWithId<u32>;
+	type Paymaster = PayAssetFromAccount<
+		UnionOf<Balances, Assets, NativeFromLeft, NativeOrWithId<u32>, AccountId>,
+		TreasuryAccount,
+	>;
+	type DefaultAsset = Native;
+	type PayoutAssets = SalaryPayoutAssets;
+	type BalanceConverter = SalaryBalanceConverter;
+	type Members = CoreCollective;
 	type Salary = SalaryForRank;
 	type RegistrationPeriod = ConstU32<200>;
 	type PayoutPeriod = ConstU32<200>;
 	type Budget = Budget;
 }
 
+parameter_types! {
+	pub const ReviewPeriod: BlockNumber = 7 * DAYS;
+	pub const ReviewApproval: Perbill = Perbill::from_percent(50);
+	pub const ReviewSupport: Perbill = Perbill::from_percent(50);
+}
+
 impl pallet_core_fellowship::Config for Runtime {
 	type WeightInfo = ();
 	type RuntimeEvent = RuntimeEvent;
-	type Members = RankedCollective;
+	type Members = CoreCollective;
 	type Balance = Balance;
 	type ParamsOrigin = frame_system::EnsureRoot<AccountId>;
 	type InductOrigin = pallet_core_fellowship::EnsureInducted<Runtime, (), 1>;
 	type ApproveOrigin = EnsureRootWithSuccess<AccountId, ConstU16<9>>;
 	type PromoteOrigin = EnsureRootWithSuccess<AccountId, ConstU16<9>>;
 	type EvidenceSize = ConstU32<16_384>;
+	type Tally = pallet_ranked_collective::TallyOf<Runtime, CoreCollectiveInstance>;
+	type ReviewPeriod = ReviewPeriod;
+	type ReviewApproval = ReviewApproval;
+	type ReviewSupport = ReviewSupport;
 }
 
 parameter_types! {
@@ -1842,6 +1997,7 @@ parameter_types! {
 
 impl pallet_nfts::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
 	type CollectionId = u32;
 	type ItemId = u32;
 	type Currency = Balances;
@@ -1857,6 +2013,8 @@ impl pallet_nfts::Config for Runtime {
 	type ApprovalsLimit = ApprovalsLimit;
 	type ItemAttributesApprovalsLimit = ItemAttributesApprovalsLimit;
 	type MaxTips = MaxTips;
+	type MaxRoyaltyRecipients = ConstU32<10>;
+	type MaxNestingDepth = ConstU32<5>;
 	type MaxDeadlineDuration = MaxDeadlineDuration;
 	type MaxAttributesPerCall = MaxAttributesPerCall;
 	type Features = Features;
@@ -2004,6 +2162,9 @@ impl pallet_statement::Config for Runtime {
 
 parameter_types! {
 	pub const BrokerPalletId: PalletId = PalletId(*b"py/broke");
+	pub const BrokerDemandSmoothing: Perbill = Perbill::from_percent(20);
+	pub const RenewalPriceCap: Option<sp_runtime::FixedU64> =
+		Some(sp_runtime::FixedU64::from_u32(2));
 }
 
 pub struct IntoAuthor;
@@ -2057,7 +2218,8 @@ impl pallet_broker::Config for Runtime {
 	type WeightInfo = ();
 	type PalletId = BrokerPalletId;
 	type AdminOrigin = EnsureRoot<AccountId>;
-	type PriceAdapter = pallet_broker::Linear;
+	type PriceAdapter = pallet_broker::MovingAverage<BrokerDemandSmoothing>;
+	type RenewalPriceCap = RenewalPriceCap;
 }
 
 parameter_types! {
@@ -2165,6 +2327,7 @@ construct_runtime!(
 		TasksExample: pallet_example_tasks,
 		Mixnet: pallet_mixnet,
 		SkipFeelessPayment: pallet_skip_feeless_payment,
+		CoreCollective: pallet_ranked_collective::<Instance2>,
 	}
 );
 
@@ -2191,6 +2354,7 @@ pub type SignedExtra = (
 	frame_system::CheckEra<Runtime>,
 	frame_system::CheckNonce<Runtime>,
 	frame_system::CheckWeight<Runtime>,
+	pallet_tx_pause::CheckPauseRules<Runtime>,
 	pallet_skip_feeless_payment::SkipCheckIfFeeless<
 		Runtime,
 		pallet_asset_conversion_tx_payment::ChargeAssetTxPayment<Runtime>,
@@ -2225,6 +2389,7 @@ type Migrations = (
 	pallet_alliance::migration::Migration<Runtime>,
 	pallet_contracts::Migration<Runtime>,
 	pallet_identity::migration::versioned::V0ToV1<Runtime, IDENTITY_MIGRATION_KEY_LIMIT>,
+	pallet_treasury::migration::MigrateV0ToV1<Runtime, ()>,
 );
 
 type EventRecord = frame_system::EventRecord<
@@ -2447,6 +2612,14 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_referenda_runtime_api::ReferendaApi<Block, u16, BlockNumber, Hash> for Runtime {
+		fn referendum_preview(
+			index: pallet_referenda::ReferendumIndex,
+		) -> Option<pallet_referenda::ReferendumPreview<u16, BlockNumber, Hash>> {
+			Referenda::referendum_preview::<ProposalCallWrappers>(index)
+		}
+	}
+
 	impl pallet_staking_runtime_api::StakingApi<Block, Balance, AccountId> for Runtime {
 		fn nominations_quota(balance: Balance) -> u32 {
 			Staking::api_nominations_quota(balance)
@@ -2554,6 +2727,46 @@ impl_runtime_apis! {
 			)
 		}
 
+		fn trace_call(
+			origin: AccountId,
+			dest: AccountId,
+			value: Balance,
+			gas_limit: Option<Weight>,
+			storage_deposit_limit: Option<Balance>,
+			input_data: Vec<u8>,
+		) -> pallet_contracts::ContractTraceResult<Balance, EventRecord> {
+			let gas_limit = gas_limit.unwrap_or(RuntimeBlockWeights::get().max_block);
+			Contracts::bare_trace_call(
+				origin,
+				dest,
+				value,
+				gas_limit,
+				storage_deposit_limit,
+				input_data,
+				pallet_contracts::Determinism::Enforced,
+			)
+		}
+
+		fn estimate_call(
+			origin: AccountId,
+			dest: AccountId,
+			value: Balance,
+			gas_limit: Option<Weight>,
+			storage_deposit_limit: Option<Balance>,
+			input_data: Vec<u8>,
+		) -> pallet_contracts::ContractEstimateResult<Balance, EventRecord> {
+			let gas_limit = gas_limit.unwrap_or(RuntimeBlockWeights::get().max_block);
+			Contracts::bare_estimate_call(
+				origin,
+				dest,
+				value,
+				gas_limit,
+				storage_deposit_limit,
+				input_data,
+				pallet_contracts::Determinism::Enforced,
+			)
+		}
+
 		fn instantiate(
 			origin: AccountId,
 			value: Balance,
@@ -2602,6 +2815,12 @@ impl_runtime_apis! {
 				key
 			)
 		}
+
+		fn pending_code_upgrade(
+			address: AccountId,
+		) -> Option<pallet_contracts::CodeUpgrade<Hash, BlockNumber>> {
+			Contracts::pending_code_upgrade(address)
+		}
 	}
 
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<
@@ -2622,6 +2841,7 @@ impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(2)]
 	impl pallet_asset_conversion::AssetConversionApi<
 		Block,
 		Balance,
@@ -2639,6 +2859,10 @@ impl_runtime_apis! {
 		fn get_reserves(asset1: NativeOrWithId<u32>, asset2: NativeOrWithId<u32>) -> Option<(Balance, Balance)> {
 			AssetConversion::get_reserves(asset1, asset2).ok()
 		}
+
+		fn average_price(asset1: NativeOrWithId<u32>, asset2: NativeOrWithId<u32>, window: u32) -> Option<FixedU128> {
+			AssetConversion::average_price(&asset1, &asset2, window)
+		}
 	}
 
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentCallApi<Block, Balance, RuntimeCall>
@@ -299,4 +299,29 @@ pub trait StateApi<Hash> {
 		storage_keys: Option<String>,
 		methods: Option<String>,
 	) -> Result<sp_rpc::tracing::TraceBlockResponse, Error>;
+
+	/// The `traceBlockStorageAccess` RPC re-executes a single block and reports, for the block
+	/// initialization, every extrinsic and the block finalization, the storage keys that were
+	/// read and written together with the number of bytes each key added to the storage proof.
+	///
+	/// Keys belonging to a pallet storage item are annotated with the pallet and item name
+	/// found in the runtime metadata, which makes it easy to spot the storage items responsible
+	/// for the bulk of the proof size of a block.
+	///
+	/// Note: requires the node to run with `--rpc-methods=Unsafe`.
+	///
+	/// ### `curl` example
+	///
+	/// ```text
+	/// curl \
+	/// 	-H "Content-Type: application/json" \
+	/// 	-d '{"id":1, "jsonrpc":"2.0", "method": "state_traceBlockStorageAccess", \
+	/// 		"params": ["0xb246acf1adea1f801ce15c77a5fa7d8f2eb8fed466978bcee172cc02cf64e264"]}' \
+	/// 	http://localhost:9933/
+	/// ```
+	#[method(name = "state_traceBlockStorageAccess", blocking)]
+	fn trace_block_storage_access(
+		&self,
+		block: Hash,
+	) -> Result<sp_rpc::tracing::TraceStorageAccessResponse, Error>;
 }
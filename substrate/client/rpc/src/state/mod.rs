@@ -148,6 +148,12 @@ where
 		methods: Option<String>,
 	) -> Result<sp_rpc::tracing::TraceBlockResponse, Error>;
 
+	/// Trace storage accesses and their proof size for every phase of a block
+	fn trace_block_storage_access(
+		&self,
+		block: Block::Hash,
+	) -> Result<sp_rpc::tracing::TraceStorageAccessResponse, Error>;
+
 	/// New runtime version subscription
 	fn subscribe_runtime_version(&self, pending: PendingSubscriptionSink);
 
@@ -323,6 +329,18 @@ where
 			.map_err(Into::into)
 	}
 
+	/// Re-execute the given block and report the storage keys accessed, and the proof size
+	/// they account for, during each phase of its execution.
+	///
+	/// Note: requires the node to run with `--rpc-methods=Unsafe`.
+	fn trace_block_storage_access(
+		&self,
+		block: Block::Hash,
+	) -> Result<sp_rpc::tracing::TraceStorageAccessResponse, Error> {
+		self.deny_unsafe.check_if_safe()?;
+		self.backend.trace_block_storage_access(block).map_err(Into::into)
+	}
+
 	fn subscribe_runtime_version(&self, pending: PendingSubscriptionSink) {
 		self.backend.subscribe_runtime_version(pending)
 	}
@@ -477,6 +477,15 @@ where
 		.trace_block()
 		.map_err(|e| invalid_block::<Block>(block, None, e.to_string()))
 	}
+
+	fn trace_block_storage_access(
+		&self,
+		block: Block::Hash,
+	) -> std::result::Result<sp_rpc::tracing::TraceStorageAccessResponse, Error> {
+		sc_tracing::block::BlockExecutor::new(self.client.clone(), block, None, None, None)
+			.trace_storage_access()
+			.map_err(|e| invalid_block::<Block>(block, None, e.to_string()))
+	}
 }
 
 impl<BE, Block, Client> ChildStateBackend<Block, Client> for FullState<BE, Block, Client>
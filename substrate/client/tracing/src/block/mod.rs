@@ -35,15 +35,20 @@ use tracing::{
 
 use crate::{SpanDatum, TraceEvent, Values};
 use sc_client_api::BlockBackend;
-use sp_api::{Core, Metadata, ProvideRuntimeApi};
+use sp_api::{ApiExt, Core, Metadata, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
 use sp_core::hexdisplay::HexDisplay;
-use sp_rpc::tracing::{BlockTrace, Span, TraceBlockResponse};
+use sp_rpc::tracing::{
+	BlockStorageAccess, BlockTrace, Span, TraceBlockResponse, TraceStorageAccessResponse,
+};
 use sp_runtime::{
 	generic::BlockId,
 	traits::{Block as BlockT, Header},
 };
 use sp_tracing::{WASM_NAME_KEY, WASM_TARGET_KEY, WASM_TRACE_IDENTIFIER};
+use storage_access::{StorageAccessSubscriber, StorageItems};
+
+mod storage_access;
 
 // Default to only pallet, frame support and state related traces
 const DEFAULT_TARGETS: &str = "pallet,frame,state";
@@ -195,23 +200,7 @@ where
 	/// prefixes in `Self::storage_keys`.
 	pub fn trace_block(&self) -> TraceBlockResult<TraceBlockResponse> {
 		tracing::debug!(target: "state_tracing", "Tracing block: {}", self.block);
-		// Prepare the block
-		let mut header = self
-			.client
-			.header(self.block)
-			.map_err(Error::InvalidBlockId)?
-			.ok_or_else(|| Error::MissingBlockComponent("Header not found".to_string()))?;
-		let extrinsics = self
-			.client
-			.block_body(self.block)
-			.map_err(Error::InvalidBlockId)?
-			.ok_or_else(|| Error::MissingBlockComponent("Extrinsics not found".to_string()))?;
-		tracing::debug!(target: "state_tracing", "Found {} extrinsics", extrinsics.len());
-		let parent_hash = *header.parent_hash();
-		// Remove all `Seal`s as they are added by the consensus engines after building the block.
-		// On import they are normally removed by the consensus engine.
-		header.digest_mut().logs.retain(|d| d.as_seal().is_none());
-		let block = Block::new(header, extrinsics);
+		let (block, parent_hash) = self.prepare_block()?;
 
 		let targets = if let Some(t) = &self.targets { t } else { DEFAULT_TARGETS };
 		let block_subscriber = BlockSubscriber::new(targets);
@@ -278,6 +267,78 @@ where
 			events,
 		}))
 	}
+
+	/// Execute block and report the storage keys read and written during each execution phase,
+	/// together with the number of bytes they added to the storage proof.
+	///
+	/// Storage keys are mapped to the pallet storage items declared in the runtime metadata.
+	pub fn trace_storage_access(&self) -> TraceBlockResult<TraceStorageAccessResponse> {
+		tracing::debug!(target: "state_tracing", "Tracing storage access of block: {}", self.block);
+		let (block, parent_hash) = self.prepare_block()?;
+		let extrinsics_len = block.extrinsics().len();
+
+		let metadata = self
+			.client
+			.runtime_api()
+			.metadata(parent_hash)
+			.map_err(|e| Error::Dispatch(format!("Failed to fetch runtime metadata: {}", e)))?;
+		let items = StorageItems::from_metadata(&metadata);
+
+		let mut runtime_api = self.client.runtime_api();
+		runtime_api.record_proof();
+		let proof_recorder = runtime_api
+			.proof_recorder()
+			.ok_or_else(|| Error::Dispatch("Failed to enable proof recording".to_string()))?;
+		let recorder = proof_recorder.clone();
+		let dispatch =
+			Dispatch::new(StorageAccessSubscriber::new(move || recorder.estimate_encoded_size()));
+
+		if let Err(e) =
+			dispatcher::with_default(&dispatch, || runtime_api.execute_block(parent_hash, block))
+		{
+			return Err(Error::Dispatch(format!(
+				"Failed to collect storage accesses and execute block: {}",
+				e
+			)))
+		}
+
+		let subscriber = dispatch.downcast_ref::<StorageAccessSubscriber>().ok_or_else(|| {
+			Error::Dispatch(
+				"Cannot downcast Dispatch to StorageAccessSubscriber after tracing block"
+					.to_string(),
+			)
+		})?;
+		let phases = subscriber.phases(&items, extrinsics_len);
+		tracing::debug!(target: "state_tracing", "Captured storage accesses of {} phases", phases.len());
+
+		Ok(TraceStorageAccessResponse::StorageAccess(BlockStorageAccess {
+			block_hash: block_id_as_string(BlockId::<Block>::Hash(self.block)),
+			parent_hash: block_id_as_string(BlockId::<Block>::Hash(parent_hash)),
+			proof_size: proof_recorder.estimate_encoded_size() as u64,
+			phases,
+		}))
+	}
+
+	/// Fetch the block to execute, returning it together with the hash of its parent.
+	fn prepare_block(&self) -> TraceBlockResult<(Block, Block::Hash)> {
+		let mut header = self
+			.client
+			.header(self.block)
+			.map_err(Error::InvalidBlockId)?
+			.ok_or_else(|| Error::MissingBlockComponent("Header not found".to_string()))?;
+		let extrinsics = self
+			.client
+			.block_body(self.block)
+			.map_err(Error::InvalidBlockId)?
+			.ok_or_else(|| Error::MissingBlockComponent("Extrinsics not found".to_string()))?;
+		tracing::debug!(target: "state_tracing", "Found {} extrinsics", extrinsics.len());
+		let parent_hash = *header.parent_hash();
+		// Remove all `Seal`s as they are added by the consensus engines after building the block.
+		// On import they are normally removed by the consensus engine.
+		header.digest_mut().logs.retain(|d| d.as_seal().is_none());
+		let block = Block::new(header, extrinsics);
+		Ok((block, parent_hash))
+	}
 }
 
 fn event_values_filter(event: &TraceEvent, filter_kind: &str, values: &str) -> bool {
@@ -0,0 +1,347 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Substrate.
+
+// Substrate is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Substrate is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Substrate.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Recording of the storage accesses made while executing a block.
+
+use std::{
+	collections::HashMap,
+	sync::atomic::{AtomicU64, Ordering},
+};
+
+use codec::Decode;
+use frame_metadata::{RuntimeMetadata, RuntimeMetadataPrefixed};
+use parking_lot::Mutex;
+use sp_core::{bytes::from_hex, hashing::twox_128, hexdisplay::HexDisplay};
+use sp_rpc::tracing::{ExecutionPhase, PhaseStorageAccess, StorageAccess};
+use tracing::{
+	span::{Attributes, Id, Record},
+	Subscriber,
+};
+
+use crate::Values;
+
+// Target of the events emitted by the state machine for every storage access.
+const STATE_TARGET: &str = "state";
+// The name of a field required for all events.
+const REQUIRED_EVENT_FIELD: &str = "method";
+// Methods of the state machine events which read from storage.
+const READ_METHODS: &[&str] = &["Get", "Hash", "Exists", "ChildGet", "ChildHash", "ChildExists"];
+// Methods of the state machine events which write to storage.
+const WRITE_METHODS: &[&str] =
+	&["Put", "ChildPut", "Append", "ClearPrefix", "ChildClearPrefix", "ChildKill"];
+
+/// Maps the hex encoded prefixes of storage items to the names of their pallet and item.
+pub(super) struct StorageItems(HashMap<String, (String, String)>);
+
+impl StorageItems {
+	/// Collect all storage items declared in the encoded runtime `metadata`.
+	///
+	/// Unknown metadata versions result in no storage items being known.
+	pub(super) fn from_metadata(mut metadata: &[u8]) -> Self {
+		let items = match RuntimeMetadataPrefixed::decode(&mut metadata).map(|m| m.1) {
+			Ok(RuntimeMetadata::V14(m)) => m
+				.pallets
+				.into_iter()
+				.filter_map(|p| p.storage)
+				.flat_map(|s| {
+					let prefix = s.prefix;
+					s.entries.into_iter().map(move |e| (prefix.clone(), e.name))
+				})
+				.collect(),
+			Ok(RuntimeMetadata::V15(m)) => m
+				.pallets
+				.into_iter()
+				.filter_map(|p| p.storage)
+				.flat_map(|s| {
+					let prefix = s.prefix;
+					s.entries.into_iter().map(move |e| (prefix.clone(), e.name))
+				})
+				.collect(),
+			_ => Vec::new(),
+		};
+
+		StorageItems(
+			items
+				.into_iter()
+				.map(|(pallet, item)| {
+					let key = [twox_128(pallet.as_bytes()), twox_128(item.as_bytes())].concat();
+					(HexDisplay::from(&key).to_string(), (pallet, item))
+				})
+				.collect(),
+		)
+	}
+
+	/// Returns the pallet and item name of the storage item `key` belongs to, if known.
+	fn lookup(&self, key: &str) -> Option<&(String, String)> {
+		key.get(..64).and_then(|prefix| self.0.get(prefix))
+	}
+}
+
+/// An event emitted by the state machine, together with the size of the storage proof at the
+/// time it was emitted.
+struct StateEvent {
+	values: Values,
+	proof_size: usize,
+}
+
+/// Records all events emitted by the state machine while executing a block.
+pub(super) struct StorageAccessSubscriber {
+	next_id: AtomicU64,
+	proof_size: Box<dyn Fn() -> usize + Send + Sync>,
+	events: Mutex<Vec<StateEvent>>,
+}
+
+impl StorageAccessSubscriber {
+	/// Create a new subscriber which queries the current size of the storage proof using
+	/// `proof_size`.
+	pub(super) fn new(proof_size: impl Fn() -> usize + Send + Sync + 'static) -> Self {
+		StorageAccessSubscriber {
+			next_id: AtomicU64::new(1),
+			proof_size: Box::new(proof_size),
+			events: Mutex::new(Vec::new()),
+		}
+	}
+
+	/// Group the recorded storage accesses by the execution phase they were made in.
+	///
+	/// Phases are delimited by the writes of `frame_system` to its `ExecutionPhase` storage item.
+	/// Accesses made after the last extrinsic, but before the finalization started, are accounted
+	/// to the last extrinsic.
+	pub(super) fn phases(
+		&self,
+		items: &StorageItems,
+		extrinsics_len: usize,
+	) -> Vec<PhaseStorageAccess> {
+		let phase_key = [twox_128(b"System"), twox_128(b"ExecutionPhase")].concat();
+		let phase_key = HexDisplay::from(&phase_key).to_string();
+
+		let mut phases = vec![PhaseBuilder::new(ExecutionPhase::Initialization)];
+		let mut last_proof_size = 0;
+		for event in self.events.lock().drain(..) {
+			let proof_size = event.proof_size.saturating_sub(last_proof_size) as u64;
+			last_proof_size = last_proof_size.max(event.proof_size);
+
+			let current = phases.last_mut().expect("There is always at least one phase; qed");
+			current.access.proof_size += proof_size;
+
+			let values = &event.values.string_values;
+			let (Some(method), Some(key)) = (values.get("method"), values.get("key")) else {
+				continue
+			};
+			let child_info = values.get("child_info");
+			if READ_METHODS.contains(&method.as_str()) {
+				current.read(key, child_info, proof_size, items);
+			} else if WRITE_METHODS.contains(&method.as_str()) {
+				current.write(key, child_info, proof_size, items);
+			}
+
+			if method != "Put" || *key != phase_key {
+				continue
+			}
+			let Some(phase) = values.get("value").and_then(|v| decode_phase(v)) else { continue };
+			let next_extrinsic = match phase {
+				ExecutionPhase::ApplyExtrinsic(i) => (i as usize) < extrinsics_len,
+				_ => true,
+			};
+			if phase != current.access.phase && next_extrinsic {
+				phases.push(PhaseBuilder::new(phase));
+			}
+		}
+
+		phases.into_iter().map(|p| p.access).collect()
+	}
+}
+
+impl Subscriber for StorageAccessSubscriber {
+	fn enabled(&self, metadata: &tracing::Metadata<'_>) -> bool {
+		metadata.is_event() &&
+			metadata.target() == STATE_TARGET &&
+			metadata.fields().field(REQUIRED_EVENT_FIELD).is_some()
+	}
+
+	fn new_span(&self, _attrs: &Attributes<'_>) -> Id {
+		Id::from_u64(self.next_id.fetch_add(1, Ordering::Relaxed))
+	}
+
+	fn record(&self, _span: &Id, _values: &Record<'_>) {}
+
+	fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+	fn event(&self, event: &tracing::Event<'_>) {
+		let mut values = Values::default();
+		event.record(&mut values);
+		let proof_size = (self.proof_size)();
+		self.events.lock().push(StateEvent { values, proof_size });
+	}
+
+	fn enter(&self, _id: &Id) {}
+
+	fn exit(&self, _span: &Id) {}
+}
+
+/// Accumulates the storage accesses of a single execution phase.
+struct PhaseBuilder {
+	access: PhaseStorageAccess,
+	reads: HashMap<(String, Option<String>), usize>,
+	writes: HashMap<(String, Option<String>), usize>,
+}
+
+impl PhaseBuilder {
+	fn new(phase: ExecutionPhase) -> Self {
+		PhaseBuilder {
+			access: PhaseStorageAccess {
+				phase,
+				proof_size: 0,
+				reads: Vec::new(),
+				writes: Vec::new(),
+			},
+			reads: HashMap::new(),
+			writes: HashMap::new(),
+		}
+	}
+
+	fn read(
+		&mut self,
+		key: &str,
+		child_info: Option<&String>,
+		proof_size: u64,
+		items: &StorageItems,
+	) {
+		record(&mut self.access.reads, &mut self.reads, key, child_info, proof_size, items)
+	}
+
+	fn write(
+		&mut self,
+		key: &str,
+		child_info: Option<&String>,
+		proof_size: u64,
+		items: &StorageItems,
+	) {
+		record(&mut self.access.writes, &mut self.writes, key, child_info, proof_size, items)
+	}
+}
+
+/// Account an access to `key` to the matching entry of `accesses`, adding one if needed.
+fn record(
+	accesses: &mut Vec<StorageAccess>,
+	index: &mut HashMap<(String, Option<String>), usize>,
+	key: &str,
+	child_info: Option<&String>,
+	proof_size: u64,
+	items: &StorageItems,
+) {
+	let position = *index.entry((key.to_owned(), child_info.cloned())).or_insert_with(|| {
+		// Only keys of the main trie are declared in the metadata.
+		let item = child_info.is_none().then(|| items.lookup(key)).flatten();
+		accesses.push(StorageAccess {
+			key: key.to_owned(),
+			child_info: child_info.cloned(),
+			pallet: item.map(|(pallet, _)| pallet.clone()),
+			storage_item: item.map(|(_, item)| item.clone()),
+			..Default::default()
+		});
+		accesses.len() - 1
+	});
+	let access = &mut accesses[position];
+	access.count += 1;
+	access.proof_size += proof_size;
+}
+
+/// Decode the `frame_system` execution phase from the recorded `value` of a `Put` event.
+fn decode_phase(value: &str) -> Option<ExecutionPhase> {
+	let value = value.strip_prefix("Some(")?.strip_suffix(')')?;
+	let bytes = from_hex(value).ok()?;
+	match bytes.split_first()? {
+		(&0, mut index) => u32::decode(&mut index).ok().map(ExecutionPhase::ApplyExtrinsic),
+		(&1, []) => Some(ExecutionPhase::Finalization),
+		(&2, []) => Some(ExecutionPhase::Initialization),
+		_ => None,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use codec::Encode;
+	use std::sync::{
+		atomic::{AtomicUsize, Ordering},
+		Arc,
+	};
+	use tracing::{dispatcher, Dispatch};
+
+	fn put_phase(phase_key: &str, phase: Vec<u8>) {
+		tracing::trace!(
+			target: "state",
+			method = "Put",
+			key = %phase_key,
+			value = ?Some(HexDisplay::from(&phase)),
+		);
+	}
+
+	#[test]
+	fn decode_phase_works() {
+		let phase = |bytes: Vec<u8>| decode_phase(&format!("Some({})", HexDisplay::from(&bytes)));
+		assert_eq!(phase((0u8, 7u32).encode()), Some(ExecutionPhase::ApplyExtrinsic(7)));
+		assert_eq!(phase(vec![1]), Some(ExecutionPhase::Finalization));
+		assert_eq!(phase(vec![2]), Some(ExecutionPhase::Initialization));
+		assert_eq!(phase(vec![3]), None);
+		assert_eq!(decode_phase("None"), None);
+	}
+
+	#[test]
+	fn storage_accesses_are_grouped_by_phase() {
+		let phase_key = [twox_128(b"System"), twox_128(b"ExecutionPhase")].concat();
+		let phase_key = HexDisplay::from(&phase_key).to_string();
+		let items = StorageItems(
+			[(phase_key.clone(), ("System".to_string(), "ExecutionPhase".to_string()))].into(),
+		);
+
+		let proof_size = Arc::new(AtomicUsize::new(0));
+		let size = proof_size.clone();
+		let dispatch =
+			Dispatch::new(StorageAccessSubscriber::new(move || size.load(Ordering::Relaxed)));
+		dispatcher::with_default(&dispatch, || {
+			proof_size.store(10, Ordering::Relaxed);
+			tracing::trace!(target: "state", method = "Get", key = "aa", result = "None");
+			put_phase(&phase_key, (0u8, 0u32).encode());
+			proof_size.store(25, Ordering::Relaxed);
+			tracing::trace!(target: "state", method = "Get", key = "bb", result = "None");
+			tracing::trace!(target: "state", method = "Get", key = "bb", result = "None");
+			// An index past the end of the block body marks no new extrinsic.
+			put_phase(&phase_key, (0u8, 1u32).encode());
+			put_phase(&phase_key, vec![1]);
+			tracing::trace!(target: "state", method = "Put", key = "cc", value = "None");
+		});
+
+		let subscriber = dispatch.downcast_ref::<StorageAccessSubscriber>().unwrap();
+		let phases = subscriber.phases(&items, 1);
+		assert_eq!(
+			phases.iter().map(|p| (p.phase, p.proof_size)).collect::<Vec<_>>(),
+			vec![
+				(ExecutionPhase::Initialization, 10),
+				(ExecutionPhase::ApplyExtrinsic(0), 15),
+				(ExecutionPhase::Finalization, 0),
+			],
+		);
+		assert_eq!(phases[0].reads[0].key, "aa");
+		assert_eq!(phases[0].writes[0].pallet.as_deref(), Some("System"));
+		assert_eq!(phases[1].reads[0].count, 2);
+		assert_eq!(phases[1].reads[0].proof_size, 15);
+		assert_eq!(phases[1].writes.len(), 1);
+		assert_eq!(phases[1].writes[0].count, 2);
+		assert_eq!(phases[2].writes[0].key, "cc");
+	}
+}
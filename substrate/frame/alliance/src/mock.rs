@@ -104,6 +104,7 @@ parameter_types! {
 	pub const MaxSubAccounts: u32 = 2;
 	pub const MaxAdditionalFields: u32 = 2;
 	pub const MaxRegistrars: u32 = 20;
+	pub const MaxAttestations: u32 = 64;
 	pub const PendingUsernameExpiration: u64 = 100;
 }
 ord_parameter_types! {
@@ -125,6 +126,7 @@ impl pallet_identity::Config for Test {
 	type MaxSubAccounts = MaxSubAccounts;
 	type IdentityInformation = IdentityInfo<MaxAdditionalFields>;
 	type MaxRegistrars = MaxRegistrars;
+	type MaxAttestations = MaxAttestations;
 	type Slashed = ();
 	type RegistrarOrigin = EnsureOneOrRoot;
 	type ForceOrigin = EnsureTwoOrRoot;
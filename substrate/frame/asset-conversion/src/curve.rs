@@ -0,0 +1,194 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The curves a pool can price its swaps with.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_core::U256;
+use sp_runtime::RuntimeDebug;
+
+/// The maximum amplification coefficient of a [`PoolCurve::StableSwap`] pool.
+pub const MAX_AMPLIFICATION: u32 = 1_000_000;
+
+/// The curve of a pool, relating its reserves before and after a swap.
+///
+/// Liquidity is added and removed in proportion to the reserves whatever the curve, so the lp
+/// tokens of all pools work the same way.
+#[derive(
+	Clone, Copy, Decode, Encode, Default, PartialEq, Eq, MaxEncodedLen, TypeInfo, RuntimeDebug,
+)]
+pub enum PoolCurve {
+	/// The constant product `x * y = k` of Uniswap V2, suited to assets of any relative value.
+	#[default]
+	ConstantProduct,
+	/// The amplified invariant of StableSwap, suited to assets of the same value.
+	///
+	/// Around balanced reserves, the curve is close to the constant sum `x + y = k`, giving a
+	/// much lower slippage than the constant product. The higher the `amplification`, the
+	/// flatter the curve, and the further from balanced the reserves can get before the price
+	/// moves away from one to one.
+	StableSwap {
+		/// The amplification coefficient, between 1 and [`MAX_AMPLIFICATION`].
+		amplification: u32,
+	},
+}
+
+impl PoolCurve {
+	/// Whether the parameters of the curve are valid.
+	pub fn is_valid(&self) -> bool {
+		match self {
+			PoolCurve::ConstantProduct => true,
+			PoolCurve::StableSwap { amplification } =>
+				(1..=MAX_AMPLIFICATION).contains(amplification),
+		}
+	}
+}
+
+/// The math of the StableSwap invariant `D` of a pool of two assets with reserves `x` and `y`:
+///
+/// `4A(x + y) + D = 4AD + D^3 / 4xy`
+///
+/// where `A` is the amplification coefficient. Fees are expressed in tenths of a percent, like
+/// [`crate::Config::LPFee`].
+pub mod stable_swap {
+	use super::*;
+
+	/// The maximum number of iterations of Newton's method.
+	const MAX_ITERATIONS: u32 = 255;
+
+	fn converged(a: U256, b: U256) -> bool {
+		let diff = if a > b { a - b } else { b - a };
+		diff <= U256::one()
+	}
+
+	/// The invariant `D` of a pool with reserves `x` and `y`.
+	pub fn invariant(x: U256, y: U256, amplification: u32) -> Option<U256> {
+		let sum = x.checked_add(y)?;
+		if sum.is_zero() {
+			return Some(U256::zero())
+		}
+		if x.is_zero() || y.is_zero() {
+			return None
+		}
+
+		let ann = U256::from(amplification).checked_mul(4.into())?;
+		let mut d = sum;
+		for _ in 0..MAX_ITERATIONS {
+			// D^3 / 4xy
+			let d_p = d
+				.checked_mul(d)?
+				.checked_div(x.checked_mul(2.into())?)?
+				.checked_mul(d)?
+				.checked_div(y.checked_mul(2.into())?)?;
+			let previous = d;
+			// D = (4A(x + y) + 2 D_P) D / ((4A - 1) D + 3 D_P)
+			let numerator =
+				ann.checked_mul(sum)?.checked_add(d_p.checked_mul(2.into())?)?.checked_mul(d)?;
+			let denominator = ann
+				.checked_sub(1.into())?
+				.checked_mul(d)?
+				.checked_add(d_p.checked_mul(3.into())?)?;
+			d = numerator.checked_div(denominator)?;
+			if converged(d, previous) {
+				return Some(d)
+			}
+		}
+		None
+	}
+
+	/// The reserve of one asset that keeps the invariant `d`, given the reserve `x` of the other.
+	pub fn reserve_for(x: U256, d: U256, amplification: u32) -> Option<U256> {
+		if x.is_zero() {
+			return None
+		}
+
+		let ann = U256::from(amplification).checked_mul(4.into())?;
+		// D^3 / (4x * 4A)
+		let c = d
+			.checked_mul(d)?
+			.checked_div(x.checked_mul(2.into())?)?
+			.checked_mul(d)?
+			.checked_div(ann.checked_mul(2.into())?)?;
+		let b = x.checked_add(d.checked_div(ann)?)?;
+
+		let mut y = d;
+		for _ in 0..MAX_ITERATIONS {
+			let previous = y;
+			// y = (y^2 + c) / (2y + b - D)
+			let numerator = y.checked_mul(y)?.checked_add(c)?;
+			let denominator = y.checked_mul(2.into())?.checked_add(b)?.checked_sub(d)?;
+			y = numerator.checked_div(denominator)?;
+			if converged(y, previous) {
+				return Some(y)
+			}
+		}
+		None
+	}
+
+	/// Given an input amount and the reserves, the output amount after a `fee`.
+	///
+	/// Rounds down, in favour of the pool.
+	pub fn amount_out(
+		amount_in: u128,
+		reserve_in: u128,
+		reserve_out: u128,
+		amplification: u32,
+		fee: u32,
+	) -> Option<u128> {
+		let reserve_in = U256::from(reserve_in);
+		let reserve_out = U256::from(reserve_out);
+		let amount_in_after_fee = U256::from(amount_in)
+			.checked_mul(U256::from(1000u32.checked_sub(fee)?))?
+			.checked_div(1000.into())?;
+
+		let d = invariant(reserve_in, reserve_out, amplification)?;
+		let new_reserve_out =
+			reserve_for(reserve_in.checked_add(amount_in_after_fee)?, d, amplification)?;
+		let amount_out = reserve_out
+			.checked_sub(new_reserve_out)
+			.unwrap_or_default()
+			.saturating_sub(1.into());
+
+		amount_out.try_into().ok()
+	}
+
+	/// Given an output amount and the reserves, the input amount needed, including a `fee`.
+	///
+	/// Rounds up, in favour of the pool.
+	pub fn amount_in(
+		amount_out: u128,
+		reserve_in: u128,
+		reserve_out: u128,
+		amplification: u32,
+		fee: u32,
+	) -> Option<u128> {
+		let reserve_in = U256::from(reserve_in);
+		let reserve_out = U256::from(reserve_out);
+
+		let d = invariant(reserve_in, reserve_out, amplification)?;
+		let new_reserve_in =
+			reserve_for(reserve_out.checked_sub(amount_out.into())?, d, amplification)?;
+		let amount_in_after_fee = new_reserve_in.checked_sub(reserve_in)?.checked_add(1.into())?;
+		let amount_in = amount_in_after_fee
+			.checked_mul(1000.into())?
+			.checked_div(U256::from(1000u32.checked_sub(fee)?))?
+			.checked_add(1.into())?;
+
+		amount_in.try_into().ok()
+	}
+}
@@ -23,7 +23,8 @@
 //!
 //! This pallet allows you to:
 //!
-//!  - [create a liquidity pool](`Pallet::create_pool()`) for 2 assets
+//!  - [create a liquidity pool](`Pallet::create_pool()`) for 2 assets, possibly with [a curve
+//!    suited to assets of the same value](`Pallet::create_pool_with_curve()`)
 //!  - [provide the liquidity](`Pallet::add_liquidity()`) and receive back an LP token
 //!  - [exchange the LP token back to assets](`Pallet::remove_liquidity()`)
 //!  - [swap a specific amount of assets for another](`Pallet::swap_exact_tokens_for_tokens()`) if
@@ -34,6 +35,9 @@
 //!    a runtime call endpoint
 //!  - [query the size of a liquidity pool](`AssetConversionApi::get_reserves`) via a runtime api
 //!    endpoint.
+//!  - [query a time-weighted average price](`AssetConversionApi::average_price`), which is much
+//!    harder to manipulate than the spot price, via a runtime api endpoint or
+//!    [on chain](`Pallet::average_price()`).
 //!
 //! The `quote_price_exact_tokens_for_tokens` and `quote_price_tokens_for_exact_tokens` functions
 //! both take a path parameter of the route to take. If you want to swap from native asset to
@@ -56,6 +60,7 @@
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+mod curve;
 #[cfg(test)]
 mod mock;
 mod swap;
@@ -65,6 +70,7 @@ mod types;
 pub mod weights;
 #[cfg(feature = "runtime-benchmarks")]
 pub use benchmarking::{BenchmarkHelper, NativeOrWithIdFactory};
+pub use curve::*;
 pub use pallet::*;
 pub use swap::*;
 pub use types::*;
@@ -85,13 +91,14 @@ use frame_support::{
 	},
 	PalletId,
 };
-use sp_core::Get;
+use sp_core::{Get, U256};
 use sp_runtime::{
 	traits::{
-		CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Ensure, IntegerSquareRoot, MaybeDisplay,
-		One, TrailingZeroInput, Zero,
+		Bounded, CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Ensure, IntegerSquareRoot,
+		MaybeDisplay, One, TrailingZeroInput, Zero,
 	},
-	DispatchError, Saturating, TokenError, TransactionOutcome,
+	DispatchError, FixedPointNumber, FixedU128, SaturatedConversion, Saturating, TokenError,
+	TransactionOutcome,
 };
 use sp_std::{boxed::Box, collections::btree_set::BTreeSet, vec::Vec};
 
@@ -182,6 +189,12 @@ pub mod pallet {
 		#[pallet::constant]
 		type PalletId: Get<PalletId>;
 
+		/// The max number of price observations kept for each pool, at most one per block.
+		///
+		/// Bounds the window of [`Pallet::average_price`]. Zero disables the price oracles.
+		#[pallet::constant]
+		type MaxPriceObservations: Get<u32>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 
@@ -196,6 +209,17 @@ pub mod pallet {
 	pub type Pools<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::PoolId, PoolInfo<T::PoolAssetId>, OptionQuery>;
 
+	/// The curve of each pool that does not use the default [`PoolCurve::ConstantProduct`].
+	#[pallet::storage]
+	pub type PoolCurves<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::PoolId, PoolCurve, ValueQuery>;
+
+	/// The price oracle of each pool, started by the first change to its reserves once it has
+	/// liquidity.
+	#[pallet::storage]
+	pub type PriceOracles<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::PoolId, PriceOracleOf<T>, OptionQuery>;
+
 	/// Stores the `PoolAssetId` that is going to be used for the next lp token.
 	/// This gets incremented whenever a new lp pool is created.
 	#[pallet::storage]
@@ -334,6 +358,8 @@ pub mod pallet {
 		IncorrectPoolAssetId,
 		/// The destination account cannot exist with the swapped funds.
 		BelowMinimum,
+		/// The parameters of the pool curve are invalid.
+		InvalidCurve,
 	}
 
 	#[pallet::hooks]
@@ -361,49 +387,7 @@ pub mod pallet {
 			asset2: Box<T::AssetKind>,
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
-			ensure!(asset1 != asset2, Error::<T>::InvalidAssetPair);
-
-			// prepare pool_id
-			let pool_id = T::PoolLocator::pool_id(&asset1, &asset2)
-				.map_err(|_| Error::<T>::InvalidAssetPair)?;
-			ensure!(!Pools::<T>::contains_key(&pool_id), Error::<T>::PoolExists);
-
-			let pool_account =
-				T::PoolLocator::address(&pool_id).map_err(|_| Error::<T>::InvalidAssetPair)?;
-
-			// pay the setup fee
-			let fee =
-				Self::withdraw(T::PoolSetupFeeAsset::get(), &sender, T::PoolSetupFee::get(), true)?;
-			T::PoolSetupFeeTarget::on_unbalanced(fee);
-
-			if T::Assets::should_touch(*asset1.clone(), &pool_account) {
-				T::Assets::touch(*asset1, &pool_account, &sender)?
-			};
-
-			if T::Assets::should_touch(*asset2.clone(), &pool_account) {
-				T::Assets::touch(*asset2, &pool_account, &sender)?
-			};
-
-			let lp_token = NextPoolAssetId::<T>::get()
-				.or(T::PoolAssetId::initial_value())
-				.ok_or(Error::<T>::IncorrectPoolAssetId)?;
-			let next_lp_token_id = lp_token.increment().ok_or(Error::<T>::IncorrectPoolAssetId)?;
-			NextPoolAssetId::<T>::set(Some(next_lp_token_id));
-
-			T::PoolAssets::create(lp_token.clone(), pool_account.clone(), false, 1u32.into())?;
-			T::PoolAssets::touch(lp_token.clone(), &pool_account, &sender)?;
-
-			let pool_info = PoolInfo { lp_token: lp_token.clone() };
-			Pools::<T>::insert(pool_id.clone(), pool_info);
-
-			Self::deposit_event(Event::PoolCreated {
-				creator: sender,
-				pool_id,
-				pool_account,
-				lp_token,
-			});
-
-			Ok(())
+			Self::do_create_pool(sender, asset1, asset2, PoolCurve::ConstantProduct)
 		}
 
 		/// Provide liquidity into the pool of `asset1` and `asset2`.
@@ -416,7 +400,9 @@ pub mod pallet {
 		/// Once liquidity is added, someone may successfully call
 		/// [`Pallet::swap_exact_tokens_for_tokens`] successfully.
 		#[pallet::call_index(1)]
-		#[pallet::weight(T::WeightInfo::add_liquidity())]
+		#[pallet::weight(
+			T::WeightInfo::add_liquidity().saturating_add(Pallet::<T>::price_oracle_weight(2))
+		)]
 		pub fn add_liquidity(
 			origin: OriginFor<T>,
 			asset1: Box<T::AssetKind>,
@@ -443,6 +429,7 @@ pub mod pallet {
 
 			let reserve1 = Self::get_balance(&pool_account, *asset1.clone());
 			let reserve2 = Self::get_balance(&pool_account, *asset2.clone());
+			Self::update_price_oracle(&pool_id, &asset1, &asset2, reserve1, reserve2);
 
 			let amount1: T::Balance;
 			let amount2: T::Balance;
@@ -509,6 +496,15 @@ pub mod pallet {
 
 			T::PoolAssets::mint_into(pool.lp_token.clone(), &mint_to, lp_token_amount)?;
 
+			// start the oracle of a pool that just got liquidity.
+			Self::update_price_oracle(
+				&pool_id,
+				&asset1,
+				&asset2,
+				reserve1.saturating_add(amount1),
+				reserve2.saturating_add(amount2),
+			);
+
 			Self::deposit_event(Event::LiquidityAdded {
 				who: sender,
 				mint_to,
@@ -526,7 +522,9 @@ pub mod pallet {
 		/// burned in the process. With the usage of `amount1_min_receive`/`amount2_min_receive`
 		/// it's possible to control the min amount of returned tokens you're happy with.
 		#[pallet::call_index(2)]
-		#[pallet::weight(T::WeightInfo::remove_liquidity())]
+		#[pallet::weight(
+			T::WeightInfo::remove_liquidity().saturating_add(Pallet::<T>::price_oracle_weight(1))
+		)]
 		pub fn remove_liquidity(
 			origin: OriginFor<T>,
 			asset1: Box<T::AssetKind>,
@@ -549,6 +547,7 @@ pub mod pallet {
 				T::PoolLocator::address(&pool_id).map_err(|_| Error::<T>::InvalidAssetPair)?;
 			let reserve1 = Self::get_balance(&pool_account, *asset1.clone());
 			let reserve2 = Self::get_balance(&pool_account, *asset2.clone());
+			Self::update_price_oracle(&pool_id, &asset1, &asset2, reserve1, reserve2);
 
 			let total_supply = T::PoolAssets::total_issuance(pool.lp_token.clone());
 			let withdrawal_fee_amount = T::LiquidityWithdrawalFee::get() * lp_token_burn;
@@ -603,7 +602,11 @@ pub mod pallet {
 		/// [`AssetConversionApi::quote_price_exact_tokens_for_tokens`] runtime call can be called
 		/// for a quote.
 		#[pallet::call_index(3)]
-		#[pallet::weight(T::WeightInfo::swap_exact_tokens_for_tokens(path.len() as u32))]
+		#[pallet::weight(
+			T::WeightInfo::swap_exact_tokens_for_tokens(path.len() as u32).saturating_add(
+				Pallet::<T>::price_oracle_weight((path.len() as u32).saturating_sub(1))
+			)
+		)]
 		pub fn swap_exact_tokens_for_tokens(
 			origin: OriginFor<T>,
 			path: Vec<Box<T::AssetKind>>,
@@ -631,7 +634,11 @@ pub mod pallet {
 		/// [`AssetConversionApi::quote_price_tokens_for_exact_tokens`] runtime call can be called
 		/// for a quote.
 		#[pallet::call_index(4)]
-		#[pallet::weight(T::WeightInfo::swap_tokens_for_exact_tokens(path.len() as u32))]
+		#[pallet::weight(
+			T::WeightInfo::swap_tokens_for_exact_tokens(path.len() as u32).saturating_add(
+				Pallet::<T>::price_oracle_weight((path.len() as u32).saturating_sub(1))
+			)
+		)]
 		pub fn swap_tokens_for_exact_tokens(
 			origin: OriginFor<T>,
 			path: Vec<Box<T::AssetKind>>,
@@ -651,9 +658,84 @@ pub mod pallet {
 			)?;
 			Ok(())
 		}
+
+		/// Creates an empty liquidity pool like [`Pallet::create_pool`], whose swaps are priced
+		/// along the given `curve`.
+		///
+		/// A [`PoolCurve::StableSwap`] pool gives a much lower slippage than the default
+		/// [`PoolCurve::ConstantProduct`] for assets of the same value, like two stablecoins of
+		/// the same currency, or an asset and its bridged counterpart.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::create_pool().saturating_add(T::DbWeight::get().writes(1)))]
+		pub fn create_pool_with_curve(
+			origin: OriginFor<T>,
+			asset1: Box<T::AssetKind>,
+			asset2: Box<T::AssetKind>,
+			curve: PoolCurve,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::do_create_pool(sender, asset1, asset2, curve)
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
+		/// Create a pool of `asset1` and `asset2` pricing its swaps with `curve`, the setup fee
+		/// being paid by `sender`.
+		fn do_create_pool(
+			sender: T::AccountId,
+			asset1: Box<T::AssetKind>,
+			asset2: Box<T::AssetKind>,
+			curve: PoolCurve,
+		) -> DispatchResult {
+			ensure!(asset1 != asset2, Error::<T>::InvalidAssetPair);
+			ensure!(curve.is_valid(), Error::<T>::InvalidCurve);
+
+			// prepare pool_id
+			let pool_id = T::PoolLocator::pool_id(&asset1, &asset2)
+				.map_err(|_| Error::<T>::InvalidAssetPair)?;
+			ensure!(!Pools::<T>::contains_key(&pool_id), Error::<T>::PoolExists);
+
+			let pool_account =
+				T::PoolLocator::address(&pool_id).map_err(|_| Error::<T>::InvalidAssetPair)?;
+
+			// pay the setup fee
+			let fee =
+				Self::withdraw(T::PoolSetupFeeAsset::get(), &sender, T::PoolSetupFee::get(), true)?;
+			T::PoolSetupFeeTarget::on_unbalanced(fee);
+
+			if T::Assets::should_touch(*asset1.clone(), &pool_account) {
+				T::Assets::touch(*asset1, &pool_account, &sender)?
+			};
+
+			if T::Assets::should_touch(*asset2.clone(), &pool_account) {
+				T::Assets::touch(*asset2, &pool_account, &sender)?
+			};
+
+			let lp_token = NextPoolAssetId::<T>::get()
+				.or(T::PoolAssetId::initial_value())
+				.ok_or(Error::<T>::IncorrectPoolAssetId)?;
+			let next_lp_token_id = lp_token.increment().ok_or(Error::<T>::IncorrectPoolAssetId)?;
+			NextPoolAssetId::<T>::set(Some(next_lp_token_id));
+
+			T::PoolAssets::create(lp_token.clone(), pool_account.clone(), false, 1u32.into())?;
+			T::PoolAssets::touch(lp_token.clone(), &pool_account, &sender)?;
+
+			let pool_info = PoolInfo { lp_token: lp_token.clone() };
+			Pools::<T>::insert(pool_id.clone(), pool_info);
+			if curve != PoolCurve::default() {
+				PoolCurves::<T>::insert(pool_id.clone(), curve);
+			}
+
+			Self::deposit_event(Event::PoolCreated {
+				creator: sender,
+				pool_id,
+				pool_account,
+				lp_token,
+			});
+
+			Ok(())
+		}
+
 		/// Swap exactly `amount_in` of asset `path[0]` for asset `path[1]`.
 		/// If an `amount_out_min` is specified, it will return an error if it is unable to acquire
 		/// the amount desired.
@@ -885,6 +967,13 @@ pub mod pallet {
 			path: &BalancePath<T>,
 		) -> Result<CreditOf<T>, (CreditOf<T>, DispatchError)> {
 			let resolve_path = || -> Result<CreditOf<T>, DispatchError> {
+				for window in path.windows(2) {
+					let [(asset1, _), (asset2, _)] = window else { continue };
+					let pool_id = T::PoolLocator::pool_id(asset1, asset2)
+						.map_err(|_| Error::<T>::InvalidAssetPair)?;
+					let (reserve1, reserve2) = Self::get_reserves(asset1.clone(), asset2.clone())?;
+					Self::update_price_oracle(&pool_id, asset1, asset2, reserve1, reserve2);
+				}
 				for pos in 0..=path.len() {
 					if let Some([(asset1, _), (asset2, amount_out)]) = path.get(pos..=pos + 1) {
 						let pool_from = T::PoolLocator::pool_address(asset1, asset2)
@@ -994,8 +1083,10 @@ pub mod pallet {
 					},
 				};
 				let (reserve_in, reserve_out) = Self::get_reserves(asset1.clone(), asset2.clone())?;
+				let curve = Self::pool_curve(asset1, &asset2);
 				balance_path.push((asset2, amount_in));
-				amount_in = Self::get_amount_in(&amount_in, &reserve_in, &reserve_out)?;
+				amount_in =
+					Self::get_amount_in_on_curve(curve, &amount_in, &reserve_in, &reserve_out)?;
 			}
 			balance_path.reverse();
 
@@ -1020,8 +1111,10 @@ pub mod pallet {
 					},
 				};
 				let (reserve_in, reserve_out) = Self::get_reserves(asset1.clone(), asset2.clone())?;
+				let curve = Self::pool_curve(&asset1, asset2);
 				balance_path.push((asset1, amount_out));
-				amount_out = Self::get_amount_out(&amount_out, &reserve_in, &reserve_out)?;
+				amount_out =
+					Self::get_amount_out_on_curve(curve, &amount_out, &reserve_in, &reserve_out)?;
 			}
 			Ok(balance_path)
 		}
@@ -1034,14 +1127,25 @@ pub mod pallet {
 			include_fee: bool,
 		) -> Option<T::Balance> {
 			let pool_account = T::PoolLocator::pool_address(&asset1, &asset2).ok()?;
+			let curve = Self::pool_curve(&asset1, &asset2);
 
 			let balance1 = Self::get_balance(&pool_account, asset1);
 			let balance2 = Self::get_balance(&pool_account, asset2);
 			if !balance1.is_zero() {
-				if include_fee {
-					Self::get_amount_out(&amount, &balance1, &balance2).ok()
-				} else {
-					Self::quote(&amount, &balance1, &balance2).ok()
+				match (curve, include_fee) {
+					(_, true) =>
+						Self::get_amount_out_on_curve(curve, &amount, &balance1, &balance2).ok(),
+					(PoolCurve::ConstantProduct, false) =>
+						Self::quote(&amount, &balance1, &balance2).ok(),
+					(PoolCurve::StableSwap { amplification }, false) => Self::stable_swap_amount(
+						stable_swap::amount_out,
+						amount,
+						balance1,
+						balance2,
+						amplification,
+						0,
+					)
+					.ok(),
 				}
 			} else {
 				None
@@ -1056,14 +1160,25 @@ pub mod pallet {
 			include_fee: bool,
 		) -> Option<T::Balance> {
 			let pool_account = T::PoolLocator::pool_address(&asset1, &asset2).ok()?;
+			let curve = Self::pool_curve(&asset1, &asset2);
 
 			let balance1 = Self::get_balance(&pool_account, asset1);
 			let balance2 = Self::get_balance(&pool_account, asset2);
 			if !balance1.is_zero() {
-				if include_fee {
-					Self::get_amount_in(&amount, &balance1, &balance2).ok()
-				} else {
-					Self::quote(&amount, &balance2, &balance1).ok()
+				match (curve, include_fee) {
+					(_, true) =>
+						Self::get_amount_in_on_curve(curve, &amount, &balance1, &balance2).ok(),
+					(PoolCurve::ConstantProduct, false) =>
+						Self::quote(&amount, &balance2, &balance1).ok(),
+					(PoolCurve::StableSwap { amplification }, false) => Self::stable_swap_amount(
+						stable_swap::amount_in,
+						amount,
+						balance1,
+						balance2,
+						amplification,
+						0,
+					)
+					.ok(),
 				}
 			} else {
 				None
@@ -1188,6 +1303,243 @@ pub mod pallet {
 			result.try_into().map_err(|_| Error::<T>::Overflow)
 		}
 
+		/// The curve of the pool of `asset1` and `asset2`.
+		pub(crate) fn pool_curve(asset1: &T::AssetKind, asset2: &T::AssetKind) -> PoolCurve {
+			T::PoolLocator::pool_id(asset1, asset2)
+				.map(PoolCurves::<T>::get)
+				.unwrap_or_default()
+		}
+
+		/// Calculates amount out along `curve`.
+		///
+		/// Like [`Self::get_amount_out`], which it defers to for [`PoolCurve::ConstantProduct`].
+		pub fn get_amount_out_on_curve(
+			curve: PoolCurve,
+			amount_in: &T::Balance,
+			reserve_in: &T::Balance,
+			reserve_out: &T::Balance,
+		) -> Result<T::Balance, Error<T>> {
+			match curve {
+				PoolCurve::ConstantProduct =>
+					Self::get_amount_out(amount_in, reserve_in, reserve_out),
+				PoolCurve::StableSwap { amplification } => {
+					if reserve_in.is_zero() || reserve_out.is_zero() {
+						return Err(Error::<T>::ZeroLiquidity)
+					}
+					Self::stable_swap_amount(
+						stable_swap::amount_out,
+						*amount_in,
+						*reserve_in,
+						*reserve_out,
+						amplification,
+						T::LPFee::get(),
+					)
+				},
+			}
+		}
+
+		/// Calculates amount in along `curve`.
+		///
+		/// Like [`Self::get_amount_in`], which it defers to for [`PoolCurve::ConstantProduct`].
+		pub fn get_amount_in_on_curve(
+			curve: PoolCurve,
+			amount_out: &T::Balance,
+			reserve_in: &T::Balance,
+			reserve_out: &T::Balance,
+		) -> Result<T::Balance, Error<T>> {
+			match curve {
+				PoolCurve::ConstantProduct =>
+					Self::get_amount_in(amount_out, reserve_in, reserve_out),
+				PoolCurve::StableSwap { amplification } => {
+					if reserve_in.is_zero() || reserve_out.is_zero() {
+						return Err(Error::<T>::ZeroLiquidity)
+					}
+					if amount_out >= reserve_out {
+						return Err(Error::<T>::AmountOutTooHigh)
+					}
+					Self::stable_swap_amount(
+						stable_swap::amount_in,
+						*amount_out,
+						*reserve_in,
+						*reserve_out,
+						amplification,
+						T::LPFee::get(),
+					)
+				},
+			}
+		}
+
+		/// Convert the balances of a [`stable_swap`] calculation.
+		fn stable_swap_amount(
+			calculate: fn(u128, u128, u128, u32, u32) -> Option<u128>,
+			amount: T::Balance,
+			reserve_in: T::Balance,
+			reserve_out: T::Balance,
+			amplification: u32,
+			fee: u32,
+		) -> Result<T::Balance, Error<T>> {
+			let to_u128 = |balance: T::Balance| -> Result<u128, Error<T>> {
+				balance.try_into().map_err(|_| Error::<T>::Overflow)
+			};
+			let result = calculate(
+				to_u128(amount)?,
+				to_u128(reserve_in)?,
+				to_u128(reserve_out)?,
+				amplification,
+				fee,
+			)
+			.ok_or(Error::<T>::Overflow)?;
+			result.try_into().map_err(|_| Error::<T>::Overflow)
+		}
+
+		/// The weight of updating the price oracles of `pools`, on top of the benchmarked weight of
+		/// the call updating them.
+		///
+		/// Each update reads the reserves of the pool and reads and writes its oracle, whose
+		/// largest encoding bounds the proof size.
+		pub fn price_oracle_weight(pools: u32) -> Weight {
+			if T::MaxPriceObservations::get().is_zero() {
+				return Weight::zero()
+			}
+			let proof_size = 16u64
+				.saturating_add(T::PoolId::max_encoded_len() as u64)
+				.saturating_add(PriceOracleOf::<T>::max_encoded_len() as u64);
+			T::DbWeight::get()
+				.reads_writes(3, 1)
+				.saturating_add(Weight::from_parts(0, proof_size))
+				.saturating_mul(pools.into())
+		}
+
+		/// Record the prices of a pool in its oracle, given its `reserve1` of `asset1` and
+		/// `reserve2` of `asset2` before any change to them in this block.
+		///
+		/// Only the first call in a block accumulates prices, so the reserves of a pool can not be
+		/// moved within a block to manipulate its average price.
+		fn update_price_oracle(
+			pool_id: &T::PoolId,
+			asset1: &T::AssetKind,
+			asset2: &T::AssetKind,
+			reserve1: T::Balance,
+			reserve2: T::Balance,
+		) {
+			if T::MaxPriceObservations::get().is_zero() || reserve1.is_zero() || reserve2.is_zero()
+			{
+				return
+			}
+			let now = frame_system::Pallet::<T>::block_number();
+
+			PriceOracles::<T>::mutate(pool_id, |maybe_oracle| {
+				let oracle = maybe_oracle.get_or_insert_with(|| PriceOracle {
+					base: asset1.clone(),
+					observations: Default::default(),
+				});
+				let latest = match oracle.observations.last() {
+					Some(latest) if latest.block == now => return,
+					Some(latest) => Self::next_price_observation(
+						latest,
+						now,
+						*asset1 == oracle.base,
+						reserve1,
+						reserve2,
+					),
+					None => PriceObservation { block: now, ..Default::default() },
+				};
+				if oracle.observations.is_full() {
+					oracle.observations.remove(0);
+				}
+				let _ = oracle.observations.try_push(latest);
+			});
+		}
+
+		/// The observation at block `now` that follows `latest`, given the reserves of the pool
+		/// since then, with `reserve1` of the base asset if `base_first`.
+		fn next_price_observation(
+			latest: &PriceObservation<BlockNumberFor<T>>,
+			now: BlockNumberFor<T>,
+			base_first: bool,
+			reserve1: T::Balance,
+			reserve2: T::Balance,
+		) -> PriceObservation<BlockNumberFor<T>> {
+			let (base_reserve, quote_reserve) =
+				if base_first { (reserve1, reserve2) } else { (reserve2, reserve1) };
+			let elapsed = U256::from(now.saturating_sub(latest.block).saturated_into::<u128>());
+			let accumulate = |cumulative: U256, numerator: T::Balance, denominator: T::Balance| {
+				let price = FixedU128::checked_from_rational(
+					numerator.saturated_into::<u128>(),
+					denominator.saturated_into::<u128>(),
+				)
+				.unwrap_or_else(Bounded::max_value);
+				cumulative
+					.overflowing_add(U256::from(price.into_inner()).saturating_mul(elapsed))
+					.0
+			};
+			PriceObservation {
+				block: now,
+				base_price_cumulative: accumulate(
+					latest.base_price_cumulative,
+					quote_reserve,
+					base_reserve,
+				),
+				quote_price_cumulative: accumulate(
+					latest.quote_price_cumulative,
+					base_reserve,
+					quote_reserve,
+				),
+			}
+		}
+
+		/// The price of `asset1` in units of `asset2`, averaged over the last `window` blocks.
+		///
+		/// Unlike the spot price of [`Self::quote_price_exact_tokens_for_tokens`], moving the
+		/// reserves of a pool for a few blocks barely moves this average, making it suitable for
+		/// pricing assets on chain. Returns `None` if the pool's oracle does not cover the whole
+		/// `window`, or if the window is empty.
+		pub fn average_price(
+			asset1: &T::AssetKind,
+			asset2: &T::AssetKind,
+			window: BlockNumberFor<T>,
+		) -> Option<FixedU128> {
+			let pool_id = T::PoolLocator::pool_id(asset1, asset2).ok()?;
+			let oracle = PriceOracles::<T>::get(&pool_id)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			let start = now.checked_sub(&window).filter(|_| !window.is_zero())?;
+			let base_first = *asset1 == oracle.base;
+			let cumulative = |observation: &PriceObservation<BlockNumberFor<T>>| {
+				if base_first {
+					observation.base_price_cumulative
+				} else {
+					observation.quote_price_cumulative
+				}
+			};
+
+			let latest = oracle.observations.last()?;
+			let current = if latest.block == now {
+				*latest
+			} else {
+				let (reserve1, reserve2) =
+					Self::get_reserves(asset1.clone(), asset2.clone()).ok()?;
+				Self::next_price_observation(latest, now, base_first, reserve1, reserve2)
+			};
+
+			// the price is constant between two observations, so the cumulative price at `start`
+			// is interpolated from the observations around it.
+			let index = oracle.observations.iter().rposition(|o| o.block <= start)?;
+			let before = &oracle.observations[index];
+			let after = oracle.observations.get(index + 1).unwrap_or(&current);
+			let span = after.block.saturating_sub(before.block).saturated_into::<u128>();
+			let offset = start.saturating_sub(before.block).saturated_into::<u128>();
+			let at_start = if span.is_zero() {
+				cumulative(before)
+			} else {
+				let price = cumulative(after).overflowing_sub(cumulative(before)).0 / span;
+				cumulative(before).overflowing_add(price.saturating_mul(offset.into())).0
+			};
+
+			let sum = cumulative(&current).overflowing_sub(at_start).0;
+			let average = sum / U256::from(window.saturated_into::<u128>());
+			Some(FixedU128::from_inner(average.try_into().ok()?))
+		}
+
 		/// Ensure that a path is valid.
 		fn validate_swap_path(path: &Vec<T::AssetKind>) -> Result<(), DispatchError> {
 			ensure!(path.len() >= 2, Error::<T>::InvalidPath);
@@ -1251,6 +1603,11 @@ sp_api::decl_runtime_apis! {
 
 		/// Returns the size of the liquidity pool for the given asset pair.
 		fn get_reserves(asset1: AssetId, asset2: AssetId) -> Option<(Balance, Balance)>;
+
+		/// Returns the price of `asset1` in units of `asset2`, averaged over the last `window`
+		/// blocks. See [`Pallet::average_price`].
+		#[api_version(2)]
+		fn average_price(asset1: AssetId, asset2: AssetId, window: u32) -> Option<FixedU128>;
 	}
 }
 
@@ -18,6 +18,7 @@
 //! Traits and implementations for swap between the various asset classes.
 
 use super::*;
+use frame_support::weights::Weight;
 
 /// Trait for providing methods to swap between the various asset classes.
 pub trait Swap<AccountId> {
@@ -29,6 +30,12 @@ pub trait Swap<AccountId> {
 	/// Returns the upper limit on the length of the swap path.
 	fn max_path_len() -> u32;
 
+	/// Returns the weight a swap along a path of `path_len` assets adds on top of the transfers
+	/// it makes, which callers outside of a benchmarked call must account for.
+	fn swap_overhead_weight(_path_len: u32) -> Weight {
+		Weight::zero()
+	}
+
 	/// Swap exactly `amount_in` of asset `path[0]` for asset `path[last]`.
 	/// If an `amount_out_min` is specified, it will return an error if it is unable to acquire
 	/// the amount desired.
@@ -81,6 +88,12 @@ pub trait SwapCredit<AccountId> {
 	/// Returns the upper limit on the length of the swap path.
 	fn max_path_len() -> u32;
 
+	/// Returns the weight a swap along a path of `path_len` assets adds on top of the transfers
+	/// it makes, which callers outside of a benchmarked call must account for.
+	fn swap_overhead_weight(_path_len: u32) -> Weight {
+		Weight::zero()
+	}
+
 	/// Swap exactly `credit_in` of asset `path[0]` for asset `path[last]`.  If `amount_out_min` is
 	/// provided and the swap can't achieve at least this amount, an error is returned.
 	///
@@ -120,6 +133,10 @@ impl<T: Config> Swap<T::AccountId> for Pallet<T> {
 		T::MaxSwapPathLength::get()
 	}
 
+	fn swap_overhead_weight(path_len: u32) -> Weight {
+		Self::price_oracle_weight(path_len.saturating_sub(1))
+	}
+
 	fn swap_exact_tokens_for_tokens(
 		sender: T::AccountId,
 		path: Vec<Self::AssetKind>,
@@ -172,6 +189,10 @@ impl<T: Config> SwapCredit<T::AccountId> for Pallet<T> {
 		T::MaxSwapPathLength::get()
 	}
 
+	fn swap_overhead_weight(path_len: u32) -> Weight {
+		Self::price_oracle_weight(path_len.saturating_sub(1))
+	}
+
 	fn swap_exact_tokens_for_tokens(
 		path: Vec<Self::AssetKind>,
 		credit_in: Self::Credit,
@@ -18,6 +18,7 @@
 use crate::{mock::*, *};
 use frame_support::{
 	assert_noop, assert_ok, assert_storage_noop,
+	dispatch::GetDispatchInfo,
 	instances::Instance1,
 	traits::{
 		fungible,
@@ -27,7 +28,7 @@ use frame_support::{
 		Get,
 	},
 };
-use sp_arithmetic::Permill;
+use sp_arithmetic::{FixedPointNumber, FixedU128, Permill};
 use sp_runtime::{DispatchError, TokenError};
 
 fn events() -> Vec<Event<Test>> {
@@ -2493,3 +2494,411 @@ fn swap_credit_invalid_path() {
 		assert_eq!(error, (expected_credit_in, Error::<Test>::InvalidPath.into()));
 	});
 }
+
+fn create_stable_pool(user: u128, amplification: u32, liquidity: u128) {
+	let token_2 = NativeOrWithId::WithId(2);
+	let token_3 = NativeOrWithId::WithId(3);
+	create_tokens(user, vec![token_2.clone(), token_3.clone()]);
+	assert_ok!(AssetConversion::create_pool_with_curve(
+		RuntimeOrigin::signed(user),
+		Box::new(token_2.clone()),
+		Box::new(token_3.clone()),
+		PoolCurve::StableSwap { amplification },
+	));
+	assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 2, user, 10 * liquidity));
+	assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 3, user, 10 * liquidity));
+	assert_ok!(AssetConversion::add_liquidity(
+		RuntimeOrigin::signed(user),
+		Box::new(token_2),
+		Box::new(token_3),
+		liquidity,
+		liquidity,
+		1,
+		1,
+		user,
+	));
+}
+
+#[test]
+fn can_create_pool_with_curve() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let token_2 = NativeOrWithId::WithId(2);
+		let token_3 = NativeOrWithId::WithId(3);
+		let pool_id = (token_2.clone(), token_3.clone());
+		let curve = PoolCurve::StableSwap { amplification: 100 };
+
+		create_stable_pool(user, 100, 10_000);
+
+		assert_eq!(pools(), vec![pool_id.clone()]);
+		assert_eq!(PoolCurves::<Test>::get(&pool_id), curve);
+		assert_eq!(AssetConversion::pool_curve(&token_3, &token_2), curve);
+
+		// pools created with `create_pool` use the constant product.
+		create_tokens(user, vec![NativeOrWithId::WithId(4)]);
+		assert_ok!(AssetConversion::create_pool(
+			RuntimeOrigin::signed(user),
+			Box::new(NativeOrWithId::Native),
+			Box::new(NativeOrWithId::WithId(4)),
+		));
+		let pool_id = (NativeOrWithId::Native, NativeOrWithId::WithId(4));
+		assert!(!PoolCurves::<Test>::contains_key(&pool_id));
+		assert_eq!(PoolCurves::<Test>::get(&pool_id), PoolCurve::ConstantProduct);
+	});
+}
+
+#[test]
+fn create_pool_with_invalid_curve_should_fail() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let token_2 = NativeOrWithId::WithId(2);
+		let token_3 = NativeOrWithId::WithId(3);
+		create_tokens(user, vec![token_2.clone(), token_3.clone()]);
+
+		for amplification in [0, MAX_AMPLIFICATION + 1] {
+			assert_noop!(
+				AssetConversion::create_pool_with_curve(
+					RuntimeOrigin::signed(user),
+					Box::new(token_2.clone()),
+					Box::new(token_3.clone()),
+					PoolCurve::StableSwap { amplification },
+				),
+				Error::<Test>::InvalidCurve
+			);
+		}
+	});
+}
+
+#[test]
+fn stable_swap_has_lower_slippage() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let token_2 = NativeOrWithId::WithId(2);
+		let token_3 = NativeOrWithId::WithId(3);
+		let liquidity = 100_000;
+		create_stable_pool(user, 100, liquidity);
+
+		let input_amount = 10_000;
+		let constant_product =
+			AssetConversion::get_amount_out(&input_amount, &liquidity, &liquidity).unwrap();
+		let expect_receive = AssetConversion::quote_price_exact_tokens_for_tokens(
+			token_2.clone(),
+			token_3.clone(),
+			input_amount,
+			true,
+		)
+		.unwrap();
+		// the fee is 0.3%, the rest is almost all received at a one to one price.
+		assert!(expect_receive > constant_product);
+		assert!(expect_receive < input_amount * 997 / 1000);
+		assert!(expect_receive > input_amount * 990 / 1000);
+
+		let before = balance(user, token_3.clone());
+		assert_ok!(AssetConversion::swap_exact_tokens_for_tokens(
+			RuntimeOrigin::signed(user),
+			bvec![token_2.clone(), token_3.clone()],
+			input_amount,
+			expect_receive,
+			user,
+			false,
+		));
+		assert_eq!(balance(user, token_3.clone()) - before, expect_receive);
+
+		// buying exact tokens back costs what was quoted.
+		let output_amount = 5_000;
+		let expect_pay = AssetConversion::quote_price_tokens_for_exact_tokens(
+			token_3.clone(),
+			token_2.clone(),
+			output_amount,
+			true,
+		)
+		.unwrap();
+		let before = balance(user, token_2.clone());
+		assert_ok!(AssetConversion::swap_tokens_for_exact_tokens(
+			RuntimeOrigin::signed(user),
+			bvec![token_3.clone(), token_2.clone()],
+			output_amount,
+			expect_pay,
+			user,
+			false,
+		));
+		assert_eq!(balance(user, token_2.clone()) - before, output_amount);
+	});
+}
+
+#[test]
+fn can_swap_through_pools_of_different_curves() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let token_1 = NativeOrWithId::Native;
+		let token_2 = NativeOrWithId::WithId(2);
+		let token_3 = NativeOrWithId::WithId(3);
+		create_stable_pool(user, 1_000, 100_000);
+
+		assert_ok!(AssetConversion::create_pool(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_2.clone()),
+		));
+		let ed = get_native_ed();
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), user, 100_000 + ed));
+		assert_ok!(AssetConversion::add_liquidity(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_2.clone()),
+			50_000,
+			100_000,
+			1,
+			1,
+			user,
+		));
+
+		let input_amount = 1_000;
+		let path = vec![token_1.clone(), token_2.clone(), token_3.clone()];
+		let amounts = AssetConversion::balance_path_from_amount_in(input_amount, path).unwrap();
+		let via_token_2 =
+			AssetConversion::get_amount_out(&input_amount, &50_000, &100_000).unwrap();
+		let expect_receive = AssetConversion::get_amount_out_on_curve(
+			PoolCurve::StableSwap { amplification: 1_000 },
+			&via_token_2,
+			&100_000,
+			&100_000,
+		)
+		.unwrap();
+		assert_eq!(
+			amounts,
+			vec![
+				(token_1.clone(), input_amount),
+				(token_2.clone(), via_token_2),
+				(token_3.clone(), expect_receive)
+			]
+		);
+
+		let before = balance(user, token_3.clone());
+		assert_ok!(AssetConversion::swap_exact_tokens_for_tokens(
+			RuntimeOrigin::signed(user),
+			bvec![token_1, token_2, token_3.clone()],
+			input_amount,
+			expect_receive,
+			user,
+			false,
+		));
+		assert_eq!(balance(user, token_3) - before, expect_receive);
+	});
+}
+
+#[test]
+fn stable_swap_math_works() {
+	use stable_swap::*;
+
+	// balanced reserves have an invariant of their sum.
+	let d = invariant(1_000_000.into(), 1_000_000.into(), 100).unwrap();
+	assert_eq!(d, 2_000_000.into());
+
+	// the reserve keeping the invariant is the other one.
+	let y = reserve_for(1_000_000.into(), d, 100).unwrap();
+	assert!(y >= 999_999.into() && y <= 1_000_001.into());
+
+	// amounts in and out round in favour of the pool.
+	let out = amount_out(1_000, 1_000_000, 1_000_000, 100, 0).unwrap();
+	assert!(out < 1_000 && out >= 997);
+	let amount = amount_in(out, 1_000_000, 1_000_000, 100, 0).unwrap();
+	assert!(amount >= 1_000);
+
+	// a higher amplification flattens the curve.
+	let flat = amount_out(100_000, 1_000_000, 1_000_000, 10_000, 0).unwrap();
+	let curved = amount_out(100_000, 1_000_000, 1_000_000, 1, 0).unwrap();
+	assert!(flat > curved);
+
+	// cannot take the whole reserve.
+	assert_eq!(amount_in(1_000_000, 1_000_000, 1_000_000, 100, 0), None);
+}
+
+fn create_pool_with_price_oracle(user: u128) -> (NativeOrWithId<u32>, NativeOrWithId<u32>) {
+	let token_1 = NativeOrWithId::Native;
+	let token_2 = NativeOrWithId::WithId(2);
+	create_tokens(user, vec![token_2.clone()]);
+	assert_ok!(AssetConversion::create_pool(
+		RuntimeOrigin::signed(user),
+		Box::new(token_1.clone()),
+		Box::new(token_2.clone())
+	));
+	let ed = get_native_ed();
+	assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), user, 100_000 + ed));
+	assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 2, user, 100_000));
+	assert_ok!(AssetConversion::add_liquidity(
+		RuntimeOrigin::signed(user),
+		Box::new(token_1.clone()),
+		Box::new(token_2.clone()),
+		10_000,
+		20_000,
+		1,
+		1,
+		user,
+	));
+	(token_1, token_2)
+}
+
+fn spot_price(asset1: &NativeOrWithId<u32>, asset2: &NativeOrWithId<u32>) -> FixedU128 {
+	let (reserve1, reserve2) =
+		AssetConversion::get_reserves(asset1.clone(), asset2.clone()).unwrap();
+	FixedU128::checked_from_rational(reserve2, reserve1).unwrap()
+}
+
+#[test]
+fn average_price_follows_the_price_over_time() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let (token_1, token_2) = create_pool_with_price_oracle(user);
+		let pool_id = (token_1.clone(), token_2.clone());
+		assert_eq!(PriceOracles::<Test>::get(&pool_id).unwrap().observations.len(), 1);
+
+		// the oracle does not cover any block yet.
+		assert_eq!(AssetConversion::average_price(&token_1, &token_2, 1), None);
+
+		System::set_block_number(11);
+		assert_eq!(
+			AssetConversion::average_price(&token_1, &token_2, 10),
+			Some(FixedU128::from_u32(2))
+		);
+		assert_eq!(
+			AssetConversion::average_price(&token_2, &token_1, 10),
+			Some(FixedU128::from_rational(1, 2))
+		);
+		// the window must be covered and not empty.
+		assert_eq!(AssetConversion::average_price(&token_1, &token_2, 11), None);
+		assert_eq!(AssetConversion::average_price(&token_1, &token_2, 0), None);
+
+		assert_ok!(AssetConversion::swap_exact_tokens_for_tokens(
+			RuntimeOrigin::signed(user),
+			bvec![token_1.clone(), token_2.clone()],
+			1_000,
+			1,
+			user,
+			false,
+		));
+		let price = spot_price(&token_1, &token_2);
+		assert!(price < FixedU128::from_u32(2));
+
+		// the swap only counts from the next block on.
+		assert_eq!(
+			AssetConversion::average_price(&token_1, &token_2, 10),
+			Some(FixedU128::from_u32(2))
+		);
+
+		System::set_block_number(21);
+		assert_eq!(AssetConversion::average_price(&token_1, &token_2, 10), Some(price));
+		assert_eq!(AssetConversion::average_price(&token_1, &token_2, 5), Some(price));
+		assert_eq!(
+			AssetConversion::average_price(&token_1, &token_2, 20),
+			Some(FixedU128::from_inner((2 * FixedU128::accuracy() + price.into_inner()) / 2))
+		);
+		// interpolated between the observations at blocks 1 and 11.
+		assert_eq!(
+			AssetConversion::average_price(&token_1, &token_2, 15),
+			Some(FixedU128::from_inner(
+				(5 * 2 * FixedU128::accuracy() + 10 * price.into_inner()) / 15
+			))
+		);
+	});
+}
+
+#[test]
+fn average_price_resists_manipulation_within_a_block() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let (token_1, token_2) = create_pool_with_price_oracle(user);
+
+		System::set_block_number(11);
+		// move the price a lot, and back, within a block.
+		assert_ok!(AssetConversion::swap_exact_tokens_for_tokens(
+			RuntimeOrigin::signed(user),
+			bvec![token_1.clone(), token_2.clone()],
+			50_000,
+			1,
+			user,
+			false,
+		));
+		assert!(spot_price(&token_1, &token_2) < FixedU128::from_rational(1, 10));
+		assert_eq!(
+			AssetConversion::average_price(&token_1, &token_2, 10),
+			Some(FixedU128::from_u32(2))
+		);
+		assert_ok!(AssetConversion::swap_tokens_for_exact_tokens(
+			RuntimeOrigin::signed(user),
+			bvec![token_2.clone(), token_1.clone()],
+			50_000,
+			u128::MAX,
+			user,
+			false,
+		));
+
+		System::set_block_number(12);
+		let price = spot_price(&token_1, &token_2);
+		assert_eq!(
+			AssetConversion::average_price(&token_1, &token_2, 11),
+			Some(FixedU128::from_inner((20 * FixedU128::accuracy() + price.into_inner()) / 11))
+		);
+	});
+}
+
+#[test]
+fn price_oracle_keeps_a_bounded_history() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let (token_1, token_2) = create_pool_with_price_oracle(user);
+		let pool_id = (token_1.clone(), token_2.clone());
+
+		for block in 2..=6 {
+			System::set_block_number(block);
+			assert_ok!(AssetConversion::swap_exact_tokens_for_tokens(
+				RuntimeOrigin::signed(user),
+				bvec![token_1.clone(), token_2.clone()],
+				10,
+				1,
+				user,
+				false,
+			));
+		}
+
+		let blocks: Vec<_> = PriceOracles::<Test>::get(&pool_id)
+			.unwrap()
+			.observations
+			.iter()
+			.map(|o| o.block)
+			.collect();
+		assert_eq!(blocks, vec![3, 4, 5, 6]);
+
+		System::set_block_number(10);
+		assert!(AssetConversion::average_price(&token_1, &token_2, 7).is_some());
+		assert_eq!(AssetConversion::average_price(&token_1, &token_2, 8), None);
+	});
+}
+
+#[test]
+fn swap_weight_accounts_for_the_price_oracle_of_every_hop() {
+	new_test_ext().execute_with(|| {
+		let token_1 = NativeOrWithId::Native;
+		let token_2 = NativeOrWithId::WithId(2);
+		let token_3 = NativeOrWithId::WithId(3);
+		let per_pool = AssetConversion::price_oracle_weight(1);
+		assert!(per_pool.proof_size() > 0);
+
+		let call = crate::Call::<Test>::swap_exact_tokens_for_tokens {
+			path: bvec![token_1, token_2, token_3],
+			amount_in: 10,
+			amount_out_min: 1,
+			send_to: 1,
+			keep_alive: false,
+		};
+		assert_eq!(
+			call.get_dispatch_info().weight,
+			<() as WeightInfo>::swap_exact_tokens_for_tokens(3)
+				.saturating_add(per_pool.saturating_mul(2))
+		);
+		assert_eq!(
+			<AssetConversion as Swap<u128>>::swap_overhead_weight(3),
+			AssetConversion::price_oracle_weight(2)
+		);
+	});
+}
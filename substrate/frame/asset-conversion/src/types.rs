@@ -18,6 +18,8 @@
 use super::*;
 use codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
+use sp_core::U256;
+use sp_runtime::{BoundedVec, RuntimeDebug};
 use sp_std::marker::PhantomData;
 
 /// Represents a swap path with associated asset amounts indicating how much of the asset needs to
@@ -40,6 +42,41 @@ pub struct PoolInfo<PoolAssetId> {
 	pub lp_token: PoolAssetId,
 }
 
+/// The cumulative prices of a pool at some block.
+///
+/// Each cumulative price is the sum, over every block since the pool's oracle started, of the
+/// price at the start of the block, as a [`FixedU128`](sp_runtime::FixedU128) inner value. The
+/// sums wrap around on overflow, which keeps the difference between two observations correct.
+#[derive(
+	Clone, Copy, Decode, Encode, Default, PartialEq, Eq, MaxEncodedLen, TypeInfo, RuntimeDebug,
+)]
+pub struct PriceObservation<BlockNumber> {
+	/// The block of the observation.
+	pub block: BlockNumber,
+	/// The cumulative price of the base asset, in units of the other asset.
+	pub base_price_cumulative: U256,
+	/// The cumulative price of the other asset, in units of the base asset.
+	pub quote_price_cumulative: U256,
+}
+
+/// The price oracle of a pool, from which time-weighted average prices are read.
+#[derive(Decode, Encode, MaxEncodedLen, TypeInfo)]
+#[scale_info(skip_type_params(MaxObservations))]
+#[codec(mel_bound(AssetKind: MaxEncodedLen, BlockNumber: MaxEncodedLen))]
+pub struct PriceOracle<AssetKind, BlockNumber, MaxObservations: Get<u32>> {
+	/// The asset whose price is [`PriceObservation::base_price_cumulative`].
+	pub base: AssetKind,
+	/// The latest observations, oldest first, at most one per block.
+	pub observations: BoundedVec<PriceObservation<BlockNumber>, MaxObservations>,
+}
+
+/// The [`PriceOracle`] of a pool.
+pub type PriceOracleOf<T> = PriceOracle<
+	<T as Config>::AssetKind,
+	frame_system::pallet_prelude::BlockNumberFor<T>,
+	<T as Config>::MaxPriceObservations,
+>;
+
 /// Provides means to resolve the `PoolId` and `AccountId` from a pair of assets.
 ///
 /// Resulting `PoolId` remains consistent whether the asset pair is presented as (asset1, asset2)
@@ -59,7 +59,10 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use frame_support::traits::{fungible::Inspect, tokens::ConversionFromAssetBalance};
+use frame_support::traits::{
+	fungible::Inspect,
+	tokens::{ConversionFromAssetBalance, ConversionToAssetBalance},
+};
 use sp_runtime::{traits::Zero, FixedPointNumber, FixedU128};
 use sp_std::boxed::Box;
 
@@ -144,6 +147,8 @@ pub mod pallet {
 		UnknownAssetKind,
 		/// The given asset ID already has an assigned conversion rate and cannot be re-created.
 		AlreadyExists,
+		/// The conversion rate of the given asset cannot be inverted.
+		ZeroRate,
 	}
 
 	#[pallet::call]
@@ -246,3 +251,21 @@ where
 		pallet::ConversionRateToNative::<T>::set(asset_id.clone(), Some(1.into()));
 	}
 }
+
+/// Exposes conversion of an arbitrary native balance to the balance of an asset.
+impl<T> ConversionToAssetBalance<BalanceOf<T>, AssetKindOf<T>, BalanceOf<T>> for Pallet<T>
+where
+	T: Config,
+{
+	type Error = pallet::Error<T>;
+
+	fn to_asset_balance(
+		balance: BalanceOf<T>,
+		asset_kind: AssetKindOf<T>,
+	) -> Result<BalanceOf<T>, pallet::Error<T>> {
+		let rate = pallet::ConversionRateToNative::<T>::get(asset_kind)
+			.ok_or(pallet::Error::<T>::UnknownAssetKind)?;
+		let inverse = rate.reciprocal().ok_or(pallet::Error::<T>::ZeroRate)?;
+		Ok(inverse.saturating_mul_int(balance))
+	}
+}
@@ -151,3 +151,38 @@ fn convert_unknown_throws() {
 		assert!(conversion.is_err());
 	});
 }
+
+#[test]
+fn convert_to_asset_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AssetRate::create(
+			RuntimeOrigin::root(),
+			Box::new(ASSET_ID),
+			FixedU128::from_float(2.5)
+		));
+
+		let conversion = <AssetRate as ConversionToAssetBalance<
+			BalanceOf<Test>,
+			<Test as pallet_asset_rate::Config>::AssetKind,
+			BalanceOf<Test>,
+		>>::to_asset_balance(25, ASSET_ID);
+		assert_eq!(conversion.expect("Conversion rate exists for asset"), 10);
+	});
+}
+
+#[test]
+fn convert_to_asset_unknown_or_zero_rate_throws() {
+	new_test_ext().execute_with(|| {
+		let convert = || {
+			<AssetRate as ConversionToAssetBalance<
+				BalanceOf<Test>,
+				<Test as pallet_asset_rate::Config>::AssetKind,
+				BalanceOf<Test>,
+			>>::to_asset_balance(10, ASSET_ID)
+		};
+		assert_eq!(convert(), Err(Error::<Test>::UnknownAssetKind));
+
+		assert_ok!(AssetRate::create(RuntimeOrigin::root(), Box::new(ASSET_ID), 0.into()));
+		assert_eq!(convert(), Err(Error::<Test>::ZeroRate));
+	});
+}
@@ -553,5 +553,38 @@ benchmarks_instance_pallet! {
 		assert_last_event::<T, I>(Event::Blocked { asset_id: asset_id.into(), who: caller }.into());
 	}
 
+	poke_deposit {
+		let (asset_id, caller, _) = create_default_asset::<T, I>(false);
+		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
+		let origin = SystemOrigin::Signed(caller.clone());
+		assert!(Assets::<T, I>::touch(origin.clone().into(), asset_id.clone()).is_ok());
+		let name = vec![0u8; T::StringLimit::get() as usize];
+		assert!(Assets::<T, I>::set_metadata(origin.into(), asset_id.clone(), name.clone(), name, 12).is_ok());
+		// Make the held deposits outdated, so that all of them need to be topped up.
+		let id: T::AssetId = asset_id.clone().into();
+		Account::<T, I>::mutate(&id, &caller, |maybe_account| {
+			maybe_account.as_mut().unwrap().reason = ExistenceReason::DepositHeld(Zero::zero());
+		});
+		Asset::<T, I>::mutate(&id, |maybe_asset| maybe_asset.as_mut().unwrap().deposit = 1u32.into());
+		Metadata::<T, I>::mutate(&id, |metadata| metadata.deposit = 1u32.into());
+	}: _(SystemOrigin::Signed(caller), asset_id)
+	verify {
+		assert_eq!(Asset::<T, I>::get(&id).unwrap().deposit, T::AssetDeposit::get());
+	}
+
+	// Worst case: the tax goes to a new account, and `caller` is kept alive for the rest.
+	transfer_hook {
+		let amount = T::Balance::from(100u32);
+		let (asset_id, caller, _) = create_default_minted_asset::<T, I>(true, amount);
+		let beneficiary: T::AccountId = account("beneficiary", 0, SEED);
+		let tax = TransferTax { beneficiary: beneficiary.clone(), amount: T::Balance::from(10u32) };
+		let flags = TransferFlags { keep_alive: false, best_effort: false, burn_dust: false };
+	}: {
+		Assets::<T, I>::transfer_tax(asset_id.clone().into(), &caller, tax, amount, flags)?;
+	}
+	verify {
+		assert_eq!(Assets::<T, I>::balance(asset_id.into(), beneficiary), T::Balance::from(10u32));
+	}
+
 	impl_benchmark_test_suite!(Assets, crate::mock::new_test_ext(), crate::mock::Test)
 }
@@ -18,7 +18,7 @@
 //! Functions for the Assets pallet.
 
 use super::*;
-use frame_support::{defensive, traits::Get, BoundedVec};
+use frame_support::{defensive, traits::Get, weights::Weight, BoundedVec};
 
 #[must_use]
 pub(super) enum DeadConsequence {
@@ -186,7 +186,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			return Frozen
 		}
 		if let Some(rest) = account.balance.checked_sub(&amount) {
-			if let Some(frozen) = T::Freezer::frozen_balance(id.clone(), who) {
+			if let Some(frozen) = Self::frozen_balance(id.clone(), who) {
 				match frozen.checked_add(&details.min_balance) {
 					Some(required) if rest < required => return Frozen,
 					None => return Overflow,
@@ -208,6 +208,43 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		}
 	}
 
+	/// Returns the balance of `who` which must stay free, on top of the asset's minimum balance,
+	/// or `None` if the account may die.
+	///
+	/// This is the largest of the balance frozen by [`Config::Freezer`] and of the freezes on the
+	/// account, the latter being covered by any balance on hold too. An account with freezes or a
+	/// balance on hold can not die.
+	///
+	/// Holds and freezes are not read if [`Config::MaxHolds`] and [`Config::MaxFreezes`] allow
+	/// none.
+	pub(super) fn frozen_balance(id: T::AssetId, who: &T::AccountId) -> Option<T::Balance> {
+		let held = if T::MaxHolds::get().is_zero() {
+			Zero::zero()
+		} else {
+			Holds::<T, I>::get(&id, who)
+				.iter()
+				.fold(T::Balance::zero(), |total, hold| total.saturating_add(hold.amount))
+		};
+		let frozen = if T::MaxFreezes::get().is_zero() {
+			None
+		} else {
+			Freezes::<T, I>::get(&id, who)
+				.iter()
+				.map(|freeze| freeze.amount)
+				.max()
+				.map(|frozen| frozen.saturating_sub(held))
+		};
+		let frozen = match (T::Freezer::frozen_balance(id, who), frozen) {
+			(Some(a), Some(b)) => Some(a.max(b)),
+			(a, b) => a.or(b),
+		};
+		if held.is_zero() {
+			frozen
+		} else {
+			Some(frozen.unwrap_or_default())
+		}
+	}
+
 	// Maximum `amount` that can be passed into `can_withdraw` to result in a `WithdrawConsequence`
 	// of `Success`.
 	pub(super) fn reducible_balance(
@@ -221,7 +258,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		let account = Account::<T, I>::get(&id, who).ok_or(Error::<T, I>::NoAccount)?;
 		ensure!(!account.status.is_frozen(), Error::<T, I>::Frozen);
 
-		let amount = if let Some(frozen) = T::Freezer::frozen_balance(id, who) {
+		let amount = if let Some(frozen) = Self::frozen_balance(id, who) {
 			// Frozen balance: account CANNOT be deleted
 			let required =
 				frozen.checked_add(&details.min_balance).ok_or(ArithmeticError::Overflow)?;
@@ -349,6 +386,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		let mut details = Asset::<T, I>::get(&id).ok_or(Error::<T, I>::Unknown)?;
 		ensure!(matches!(details.status, Live | Frozen), Error::<T, I>::IncorrectStatus);
 		ensure!(account.balance.is_zero() || allow_burn, Error::<T, I>::WouldBurn);
+		ensure!(!Holds::<T, I>::contains_key(&id, &who), Error::<T, I>::ContainsHolds);
+		ensure!(!Freezes::<T, I>::contains_key(&id, &who), Error::<T, I>::ContainsFreezes);
 
 		if let Some(deposit) = account.reason.take_deposit() {
 			T::Currency::unreserve(&who, deposit);
@@ -382,6 +421,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		ensure!(!account.status.is_frozen(), Error::<T, I>::Frozen);
 		ensure!(caller == &depositor || caller == &details.admin, Error::<T, I>::NoPermission);
 		ensure!(account.balance.is_zero(), Error::<T, I>::WouldBurn);
+		ensure!(!Holds::<T, I>::contains_key(&id, &who), Error::<T, I>::ContainsHolds);
+		ensure!(!Freezes::<T, I>::contains_key(&id, &who), Error::<T, I>::ContainsFreezes);
 
 		T::Currency::unreserve(&depositor, deposit);
 
@@ -584,6 +625,11 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		maybe_need_admin: Option<T::AccountId>,
 		f: TransferFlags,
 	) -> Result<T::Balance, DispatchError> {
+		// Privileged transfers are not hooked.
+		let amount = match maybe_need_admin {
+			Some(_) => amount,
+			None => Self::pay_transfer_tax(id.clone(), source, dest, amount, f)?,
+		};
 		let (balance, died) =
 			Self::transfer_and_die(id.clone(), source, dest, amount, maybe_need_admin, f)?;
 		if let Some(Remove) = died {
@@ -592,6 +638,62 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Ok(balance)
 	}
 
+	/// The worst case weight of running the [`Config::TransferHook`] on a transfer, including the
+	/// transfer of a tax.
+	pub(super) fn transfer_hook_weight() -> Weight {
+		T::WeightInfo::transfer_hook().saturating_add(T::TransferHook::weight())
+	}
+
+	/// Runs the [`Config::TransferHook`] on a transfer of `amount` of asset `id` from `source` to
+	/// `dest`, and transfers any tax it asks for from `source` to its beneficiary.
+	///
+	/// Returns the amount left to transfer to `dest`.
+	pub(super) fn pay_transfer_tax(
+		id: T::AssetId,
+		source: &T::AccountId,
+		dest: &T::AccountId,
+		amount: T::Balance,
+		f: TransferFlags,
+	) -> Result<T::Balance, DispatchError> {
+		if amount.is_zero() || source == dest {
+			return Ok(amount)
+		}
+		match T::TransferHook::on_transfer(&id, source, dest, amount)? {
+			Some(tax) => Self::transfer_tax(id, source, tax, amount, f),
+			None => Ok(amount),
+		}
+	}
+
+	/// Transfers `tax` on a transfer of `amount` of asset `id` from `source` to its beneficiary,
+	/// keeping `source` alive if anything is left to transfer.
+	///
+	/// Returns the amount left to transfer.
+	pub(super) fn transfer_tax(
+		id: T::AssetId,
+		source: &T::AccountId,
+		tax: TransferTax<T::AccountId, T::Balance>,
+		amount: T::Balance,
+		f: TransferFlags,
+	) -> Result<T::Balance, DispatchError> {
+		let tax_amount = tax.amount.min(amount);
+		let rest = amount.saturating_sub(tax_amount);
+
+		// `source` must stay alive for the rest of the transfer.
+		let tax_flags = if rest.is_zero() { f } else { TransferFlags { keep_alive: true, ..f } };
+		let (_, died) = Self::transfer_and_die(
+			id.clone(),
+			source,
+			&tax.beneficiary,
+			tax_amount,
+			None,
+			tax_flags,
+		)?;
+		if let Some(Remove) = died {
+			T::Freezer::died(id, source);
+		}
+		Ok(rest)
+	}
+
 	/// Same as `do_transfer` but it does not execute the `FrozenBalance::died` hook and
 	/// instead returns whether and how the `source` account died in this operation.
 	fn transfer_and_die(
@@ -769,6 +871,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 					}
 					if let Remove = Self::dead_account(&who, &mut details, &v.reason, false) {
 						Account::<T, I>::remove(&id, &who);
+						Holds::<T, I>::remove(&id, &who);
+						Freezes::<T, I>::remove(&id, &who);
 						dead_accounts.push(who);
 					} else {
 						// deposit may have been released, need to update `Account`
@@ -925,8 +1029,9 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 					approved.amount.checked_sub(&amount).ok_or(Error::<T, I>::Unapproved)?;
 
 				let f = TransferFlags { keep_alive: false, best_effort: false, burn_dust: false };
+				let rest = Self::pay_transfer_tax(id.clone(), owner, destination, amount, f)?;
 				owner_died =
-					Self::transfer_and_die(id.clone(), owner, destination, amount, None, f)?.1;
+					Self::transfer_and_die(id.clone(), owner, destination, rest, None, f)?.1;
 
 				if remaining.is_zero() {
 					T::Currency::unreserve(owner, approved.deposit);
@@ -1005,6 +1110,85 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			.saturating_add(T::MetadataDepositBase::get())
 	}
 
+	/// Re-adjust the deposits held by `who` for the asset `id` to the current requirements.
+	///
+	/// This covers the deposit `who` placed for its own asset-account and, if `who` is the Owner
+	/// of the asset, the asset and metadata deposits. Deposits that were waived because the asset
+	/// or its metadata were set by a force origin remain waived.
+	///
+	/// Returns whether any deposit was changed.
+	pub(super) fn do_poke_deposit(
+		id: T::AssetId,
+		who: &T::AccountId,
+	) -> Result<bool, DispatchError> {
+		use AssetStatus::*;
+		let mut details = Asset::<T, I>::get(&id).ok_or(Error::<T, I>::Unknown)?;
+		ensure!(matches!(details.status, Live | Frozen), Error::<T, I>::IncorrectStatus);
+
+		let mut found = false;
+		let mut old_deposit = DepositBalanceOf::<T, I>::zero();
+		let mut new_deposit = DepositBalanceOf::<T, I>::zero();
+
+		if let Some(mut account) = Account::<T, I>::get(&id, who) {
+			if let ExistenceReason::DepositHeld(deposit) = account.reason {
+				let new = T::AssetAccountDeposit::get();
+				Self::rejig_deposit(who, deposit, new)?;
+				found = true;
+				old_deposit.saturating_accrue(deposit);
+				new_deposit.saturating_accrue(new);
+				account.reason = ExistenceReason::DepositHeld(new);
+				Account::<T, I>::insert(&id, who, account);
+			}
+		}
+		if &details.owner == who {
+			if !details.deposit.is_zero() {
+				let new = T::AssetDeposit::get();
+				Self::rejig_deposit(who, details.deposit, new)?;
+				found = true;
+				old_deposit.saturating_accrue(details.deposit);
+				new_deposit.saturating_accrue(new);
+				details.deposit = new;
+				Asset::<T, I>::insert(&id, &details);
+			}
+			let mut metadata = Metadata::<T, I>::get(&id);
+			if !metadata.deposit.is_zero() {
+				let new = Self::calc_metadata_deposit(&metadata.name, &metadata.symbol);
+				Self::rejig_deposit(who, metadata.deposit, new)?;
+				found = true;
+				old_deposit.saturating_accrue(metadata.deposit);
+				new_deposit.saturating_accrue(new);
+				metadata.deposit = new;
+				Metadata::<T, I>::insert(&id, metadata);
+			}
+		}
+		ensure!(found, Error::<T, I>::NoDeposit);
+
+		let changed = old_deposit != new_deposit;
+		if changed {
+			Self::deposit_event(Event::DepositPoked {
+				asset_id: id,
+				who: who.clone(),
+				old_deposit,
+				new_deposit,
+			});
+		}
+		Ok(changed)
+	}
+
+	/// Reserves or unreserves the difference between the `old` and `new` deposits of `who`.
+	fn rejig_deposit(
+		who: &T::AccountId,
+		old: DepositBalanceOf<T, I>,
+		new: DepositBalanceOf<T, I>,
+	) -> DispatchResult {
+		if new > old {
+			T::Currency::reserve(who, new - old)?;
+		} else if new < old {
+			T::Currency::unreserve(who, old - new);
+		}
+		Ok(())
+	}
+
 	/// Returns all the non-zero balances for all assets of the given `account`.
 	pub fn account_balances(account: T::AccountId) -> Vec<(T::AssetId, T::Balance)> {
 		Asset::<T, I>::iter_keys()
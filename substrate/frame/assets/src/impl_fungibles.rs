@@ -19,12 +19,14 @@
 
 use frame_support::{
 	defensive,
+	dispatch::DispatchClass,
 	traits::tokens::{
 		Fortitude,
 		Precision::{self, BestEffort},
-		Preservation::{self, Expendable},
-		Provenance::{self, Minted},
+		Preservation::{self, Expendable, Preserve},
+		Provenance::{self, Extant, Minted},
 	},
+	weights::Weight,
 };
 
 use super::*;
@@ -46,7 +48,9 @@ impl<T: Config<I>, I: 'static> fungibles::Inspect<<T as SystemConfig>::AccountId
 	}
 
 	fn total_balance(asset: Self::AssetId, who: &<T as SystemConfig>::AccountId) -> Self::Balance {
-		Pallet::<T, I>::balance(asset, who)
+		Pallet::<T, I>::balance(asset.clone(), who).saturating_add(
+			<Self as fungibles::hold::Inspect<_>>::total_balance_on_hold(asset, who),
+		)
 	}
 
 	fn reducible_balance(
@@ -82,6 +86,45 @@ impl<T: Config<I>, I: 'static> fungibles::Inspect<<T as SystemConfig>::AccountId
 }
 
 impl<T: Config<I>, I: 'static> fungibles::Mutate<<T as SystemConfig>::AccountId> for Pallet<T, I> {
+	fn transfer(
+		asset: Self::AssetId,
+		source: &<T as SystemConfig>::AccountId,
+		dest: &<T as SystemConfig>::AccountId,
+		amount: Self::Balance,
+		preservation: Preservation,
+	) -> Result<Self::Balance, DispatchError> {
+		// Not a call of this pallet, so the weight of the hook is registered as it runs.
+		let maybe_tax = if amount.is_zero() || source == dest {
+			None
+		} else {
+			Self::register_hook_weight(T::TransferHook::weight());
+			T::TransferHook::on_transfer(&asset, source, dest, amount)?
+		};
+		let Some(tax) = maybe_tax else {
+			return Self::transfer_unhooked(asset, source, dest, amount, preservation)
+		};
+		Self::register_hook_weight(T::WeightInfo::transfer_hook());
+		let tax_amount = tax.amount.min(amount);
+		let rest = amount.saturating_sub(tax_amount);
+
+		// `source` must stay alive for the rest of the transfer.
+		let tax_preservation = match preservation {
+			Expendable if !rest.is_zero() => Preserve,
+			preservation => preservation,
+		};
+		Self::transfer_unhooked(
+			asset.clone(),
+			source,
+			&tax.beneficiary,
+			tax_amount,
+			tax_preservation,
+		)?;
+		if !rest.is_zero() {
+			Self::transfer_unhooked(asset, source, dest, rest, preservation)?;
+		}
+		Ok(amount)
+	}
+
 	fn done_mint_into(
 		asset_id: Self::AssetId,
 		beneficiary: &<T as SystemConfig>::AccountId,
@@ -113,6 +156,45 @@ impl<T: Config<I>, I: 'static> fungibles::Mutate<<T as SystemConfig>::AccountId>
 	}
 }
 
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Adds `weight` to the weight of the current block.
+	fn register_hook_weight(weight: Weight) {
+		frame_system::Pallet::<T>::register_extra_weight_unchecked(weight, DispatchClass::Normal);
+	}
+
+	/// The default [`fungibles::Mutate::transfer`], without the [`Config::TransferHook`].
+	fn transfer_unhooked(
+		asset: T::AssetId,
+		source: &T::AccountId,
+		dest: &T::AccountId,
+		amount: T::Balance,
+		preservation: Preservation,
+	) -> Result<T::Balance, DispatchError> {
+		use fungibles::{Inspect, Mutate, Unbalanced};
+
+		let _extra = <Self as Inspect<_>>::can_withdraw(asset.clone(), source, amount)
+			.into_result(preservation != Expendable)?;
+		<Self as Inspect<_>>::can_deposit(asset.clone(), dest, amount, Extant).into_result()?;
+		if source == dest {
+			return Ok(amount)
+		}
+
+		<Self as Unbalanced<_>>::decrease_balance(
+			asset.clone(),
+			source,
+			amount,
+			BestEffort,
+			preservation,
+			Fortitude::Polite,
+		)?;
+		// This should never fail as we checked `can_deposit` earlier. But we do a best-effort
+		// anyway.
+		let _ = <Self as Unbalanced<_>>::increase_balance(asset.clone(), dest, amount, BestEffort);
+		<Self as Mutate<_>>::done_transfer(asset, source, dest, amount);
+		Ok(amount)
+	}
+}
+
 impl<T: Config<I>, I: 'static> fungibles::Balanced<<T as SystemConfig>::AccountId>
 	for Pallet<T, I>
 {
@@ -308,3 +390,173 @@ impl<T: Config<I>, I: 'static> fungibles::InspectEnumerable<T::AccountId> for Pa
 		Asset::<T, I>::iter_keys()
 	}
 }
+
+impl<T: Config<I>, I: 'static> fungibles::hold::Inspect<T::AccountId> for Pallet<T, I> {
+	type Reason = T::RuntimeHoldReason;
+
+	fn total_balance_on_hold(asset: T::AssetId, who: &T::AccountId) -> T::Balance {
+		Holds::<T, I>::get(asset, who)
+			.iter()
+			.fold(Zero::zero(), |total, hold| total.saturating_add(hold.amount))
+	}
+
+	fn balance_on_hold(asset: T::AssetId, reason: &Self::Reason, who: &T::AccountId) -> T::Balance {
+		Holds::<T, I>::get(asset, who)
+			.iter()
+			.find(|hold| &hold.id == reason)
+			.map_or_else(Zero::zero, |hold| hold.amount)
+	}
+
+	fn hold_available(asset: T::AssetId, reason: &Self::Reason, who: &T::AccountId) -> bool {
+		if !Account::<T, I>::contains_key(&asset, who) {
+			return false
+		}
+		let holds = Holds::<T, I>::get(asset, who);
+		!holds.is_full() || holds.iter().any(|hold| &hold.id == reason)
+	}
+}
+
+impl<T: Config<I>, I: 'static> fungibles::hold::Unbalanced<T::AccountId> for Pallet<T, I> {
+	fn set_balance_on_hold(
+		asset: T::AssetId,
+		reason: &Self::Reason,
+		who: &T::AccountId,
+		amount: T::Balance,
+	) -> DispatchResult {
+		let mut holds = Holds::<T, I>::get(&asset, who);
+		if let Some(hold) = holds.iter_mut().find(|hold| &hold.id == reason) {
+			hold.amount = amount;
+		} else if !amount.is_zero() {
+			ensure!(Account::<T, I>::contains_key(&asset, who), Error::<T, I>::NoAccount);
+			holds
+				.try_push(IdAmount { id: *reason, amount })
+				.map_err(|_| Error::<T, I>::TooManyHolds)?;
+		}
+		holds.retain(|hold| !hold.amount.is_zero());
+
+		if holds.is_empty() {
+			Holds::<T, I>::remove(asset, who);
+		} else {
+			Holds::<T, I>::insert(asset, who, holds);
+		}
+		Ok(())
+	}
+}
+
+impl<T: Config<I>, I: 'static> fungibles::hold::Balanced<T::AccountId> for Pallet<T, I> {}
+
+impl<T: Config<I>, I: 'static> fungibles::hold::Mutate<T::AccountId> for Pallet<T, I> {
+	fn done_hold(
+		asset_id: T::AssetId,
+		reason: &Self::Reason,
+		who: &T::AccountId,
+		amount: T::Balance,
+	) {
+		Self::deposit_event(Event::Held { asset_id, who: who.clone(), reason: *reason, amount });
+	}
+
+	fn done_release(
+		asset_id: T::AssetId,
+		reason: &Self::Reason,
+		who: &T::AccountId,
+		amount: T::Balance,
+	) {
+		Self::deposit_event(Event::Released {
+			asset_id,
+			who: who.clone(),
+			reason: *reason,
+			amount,
+		});
+	}
+
+	fn done_burn_held(
+		asset_id: T::AssetId,
+		reason: &Self::Reason,
+		who: &T::AccountId,
+		amount: T::Balance,
+	) {
+		Self::deposit_event(Event::BurnedHeld {
+			asset_id,
+			who: who.clone(),
+			reason: *reason,
+			amount,
+		});
+	}
+}
+
+impl<T: Config<I>, I: 'static> fungibles::freeze::Inspect<T::AccountId> for Pallet<T, I> {
+	type Id = T::FreezeIdentifier;
+
+	fn balance_frozen(asset: T::AssetId, id: &Self::Id, who: &T::AccountId) -> T::Balance {
+		Freezes::<T, I>::get(asset, who)
+			.iter()
+			.find(|freeze| &freeze.id == id)
+			.map_or_else(Zero::zero, |freeze| freeze.amount)
+	}
+
+	fn can_freeze(asset: T::AssetId, id: &Self::Id, who: &T::AccountId) -> bool {
+		let freezes = Freezes::<T, I>::get(asset, who);
+		!freezes.is_full() || freezes.iter().any(|freeze| &freeze.id == id)
+	}
+}
+
+impl<T: Config<I>, I: 'static> fungibles::freeze::Mutate<T::AccountId> for Pallet<T, I> {
+	fn set_freeze(
+		asset: T::AssetId,
+		id: &Self::Id,
+		who: &T::AccountId,
+		amount: T::Balance,
+	) -> DispatchResult {
+		if amount.is_zero() {
+			return Self::thaw(asset, id, who)
+		}
+		Self::update_freeze(asset, id, who, |_| amount)
+	}
+
+	fn extend_freeze(
+		asset: T::AssetId,
+		id: &Self::Id,
+		who: &T::AccountId,
+		amount: T::Balance,
+	) -> DispatchResult {
+		if amount.is_zero() {
+			return Ok(())
+		}
+		Self::update_freeze(asset, id, who, |frozen| frozen.max(amount))
+	}
+
+	fn thaw(asset: T::AssetId, id: &Self::Id, who: &T::AccountId) -> DispatchResult {
+		Freezes::<T, I>::mutate_exists(asset, who, |maybe_freezes| {
+			if let Some(freezes) = maybe_freezes {
+				freezes.retain(|freeze| &freeze.id != id);
+				if freezes.is_empty() {
+					*maybe_freezes = None;
+				}
+			}
+		});
+		Ok(())
+	}
+}
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Sets the freeze `id` on the account of `who` to `amount` of its current amount, which is
+	/// zero for a new freeze.
+	fn update_freeze(
+		asset: T::AssetId,
+		id: &T::FreezeIdentifier,
+		who: &T::AccountId,
+		amount: impl FnOnce(T::Balance) -> T::Balance,
+	) -> DispatchResult {
+		let mut freezes = Freezes::<T, I>::get(&asset, who);
+		if let Some(freeze) = freezes.iter_mut().find(|freeze| &freeze.id == id) {
+			freeze.amount = amount(freeze.amount);
+		} else {
+			ensure!(Account::<T, I>::contains_key(&asset, who), Error::<T, I>::NoAccount);
+			freezes
+				.try_push(IdAmount { id: *id, amount: amount(Zero::zero()) })
+				.map_err(|_| Error::<T, I>::TooManyFreezes)?;
+		}
+		Freezes::<T, I>::insert(asset, who, freezes);
+		Ok(())
+	}
+}
@@ -204,7 +204,7 @@ pub mod pallet {
 	use super::*;
 	use frame_support::{
 		pallet_prelude::*,
-		traits::{AccountTouch, ContainsPair},
+		traits::{AccountTouch, ContainsPair, PokeDeposit},
 	};
 	use frame_system::pallet_prelude::*;
 
@@ -312,6 +312,28 @@ pub mod pallet {
 		/// Callback methods for asset state change (e.g. asset created or destroyed)
 		type CallbackHandle: AssetsCallback<Self::AssetId, Self::AccountId>;
 
+		/// A hook to allow the issuer of an asset to veto or tax its transfers.
+		///
+		/// Only permissionless transfers are hooked, see [`TransferHook`] for the exemptions. The
+		/// transfer of a tax is benchmarked as [`WeightInfo::transfer_hook`], the hook itself is
+		/// weighed by [`TransferHook::weight`].
+		type TransferHook: TransferHook<Self::AssetId, Self::AccountId, Self::Balance>;
+
+		/// The overarching hold reason.
+		type RuntimeHoldReason: Parameter + Member + MaxEncodedLen + Copy;
+
+		/// The maximum number of holds that can exist on an account of an asset at any time.
+		#[pallet::constant]
+		type MaxHolds: Get<u32>;
+
+		/// The ID type for freezes.
+		type FreezeIdentifier: Parameter + Member + MaxEncodedLen + Copy;
+
+		/// The maximum number of individual freeze locks that can exist on an account of an asset
+		/// at any time.
+		#[pallet::constant]
+		type MaxFreezes: Get<u32>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 
@@ -340,6 +362,30 @@ pub mod pallet {
 		AssetAccountOf<T, I>,
 	>;
 
+	#[pallet::storage]
+	/// Holds on the balance of an account for a specific asset.
+	pub(super) type Holds<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<IdAmount<T::RuntimeHoldReason, T::Balance>, T::MaxHolds>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	/// Freeze locks on the balance of an account for a specific asset.
+	pub(super) type Freezes<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<IdAmount<T::FreezeIdentifier, T::Balance>, T::MaxFreezes>,
+		ValueQuery,
+	>;
+
 	#[pallet::storage]
 	/// Approved balance transfers. First balance is the amount approved for transfer. Second
 	/// is the amount of `T::Currency` reserved for storing this.
@@ -520,6 +566,35 @@ pub mod pallet {
 		Touched { asset_id: T::AssetId, who: T::AccountId, depositor: T::AccountId },
 		/// Some account `who` was blocked.
 		Blocked { asset_id: T::AssetId, who: T::AccountId },
+		/// The deposits held by `who` for an asset class have been updated to the current
+		/// requirements.
+		DepositPoked {
+			asset_id: T::AssetId,
+			who: T::AccountId,
+			old_deposit: DepositBalanceOf<T, I>,
+			new_deposit: DepositBalanceOf<T, I>,
+		},
+		/// Some balance of `who` was put on hold for `reason`.
+		Held {
+			asset_id: T::AssetId,
+			who: T::AccountId,
+			reason: T::RuntimeHoldReason,
+			amount: T::Balance,
+		},
+		/// Some balance of `who` on hold for `reason` was released.
+		Released {
+			asset_id: T::AssetId,
+			who: T::AccountId,
+			reason: T::RuntimeHoldReason,
+			amount: T::Balance,
+		},
+		/// Some balance of `who` on hold for `reason` was burned.
+		BurnedHeld {
+			asset_id: T::AssetId,
+			who: T::AccountId,
+			reason: T::RuntimeHoldReason,
+			amount: T::Balance,
+		},
 	}
 
 	#[pallet::error]
@@ -567,6 +642,14 @@ pub mod pallet {
 		NotFrozen,
 		/// Callback action resulted in error
 		CallbackFailed,
+		/// Number of holds exceed `MaxHolds`.
+		TooManyHolds,
+		/// Number of freezes exceed `MaxFreezes`.
+		TooManyFreezes,
+		/// The account still has a balance on hold.
+		ContainsHolds,
+		/// The account still has freezes.
+		ContainsFreezes,
 	}
 
 	#[pallet::call(weight(<T as Config<I>>::WeightInfo))]
@@ -829,6 +912,9 @@ pub mod pallet {
 		/// Modes: Pre-existence of `target`; Post-existence of sender; Account pre-existence of
 		/// `target`.
 		#[pallet::call_index(8)]
+		#[pallet::weight(
+			T::WeightInfo::transfer().saturating_add(Pallet::<T, I>::transfer_hook_weight())
+		)]
 		pub fn transfer(
 			origin: OriginFor<T>,
 			id: T::AssetIdParameter,
@@ -862,6 +948,10 @@ pub mod pallet {
 		/// Modes: Pre-existence of `target`; Post-existence of sender; Account pre-existence of
 		/// `target`.
 		#[pallet::call_index(9)]
+		#[pallet::weight(
+			T::WeightInfo::transfer_keep_alive()
+				.saturating_add(Pallet::<T, I>::transfer_hook_weight())
+		)]
 		pub fn transfer_keep_alive(
 			origin: OriginFor<T>,
 			id: T::AssetIdParameter,
@@ -1452,6 +1542,10 @@ pub mod pallet {
 		///
 		/// Weight: `O(1)`
 		#[pallet::call_index(25)]
+		#[pallet::weight(
+			T::WeightInfo::transfer_approved()
+				.saturating_add(Pallet::<T, I>::transfer_hook_weight())
+		)]
 		pub fn transfer_approved(
 			origin: OriginFor<T>,
 			id: T::AssetIdParameter,
@@ -1632,6 +1726,30 @@ pub mod pallet {
 			Self::deposit_event(Event::<T, I>::Blocked { asset_id: id, who });
 			Ok(())
 		}
+
+		/// Re-adjust the deposits held by the sender for the asset `id` to the current
+		/// requirements.
+		///
+		/// Origin must be Signed and the sender should hold a deposit for its own asset-account,
+		/// or be the Owner of the asset `id` with a non-zero asset or metadata deposit.
+		///
+		/// - `id`: The identifier of the asset.
+		///
+		/// The sender pays no fee if any deposit was updated.
+		///
+		/// Emits `DepositPoked` if any deposit was updated.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(32)]
+		pub fn poke_deposit(
+			origin: OriginFor<T>,
+			id: T::AssetIdParameter,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let id: T::AssetId = id.into();
+			let changed = Self::do_poke_deposit(id, &who)?;
+			Ok(if changed { Pays::No } else { Pays::Yes }.into())
+		}
 	}
 
 	/// Implements [`AccountTouch`] trait.
@@ -1661,6 +1779,15 @@ pub mod pallet {
 		}
 	}
 
+	/// Implements [`PokeDeposit`] trait for the deposits an account holds for an asset class.
+	impl<T: Config<I>, I: 'static> PokeDeposit<T::AccountId> for Pallet<T, I> {
+		type Key = T::AssetId;
+
+		fn poke_deposit(who: &T::AccountId, id: T::AssetId) -> Result<bool, DispatchError> {
+			Self::do_poke_deposit(id, who)
+		}
+	}
+
 	/// Implements [`ContainsPair`] trait for a pair of asset and account IDs.
 	impl<T: Config<I>, I: 'static> ContainsPair<T::AssetId, T::AccountId> for Pallet<T, I> {
 		/// Check if an account with the given asset ID and account address exists.
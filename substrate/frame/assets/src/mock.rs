@@ -20,16 +20,17 @@
 use super::*;
 use crate as pallet_assets;
 
-use codec::Encode;
+use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::{
-	construct_runtime, derive_impl, parameter_types,
+	construct_runtime, derive_impl, ensure, parameter_types,
 	traits::{AsEnsureOriginWithArg, ConstU32, ConstU64},
+	weights::Weight,
 };
 use sp_core::H256;
 use sp_io::storage;
 use sp_runtime::{
 	traits::{BlakeTwo256, IdentityLookup},
-	BuildStorage,
+	BuildStorage, DispatchError,
 };
 
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -133,6 +134,15 @@ impl AssetsCallbackHandle {
 	}
 }
 
+#[derive(
+	Encode, Decode, Copy, Clone, Eq, PartialEq, MaxEncodedLen, scale_info::TypeInfo, Debug,
+)]
+pub enum TestId {
+	Foo,
+	Bar,
+	Baz,
+}
+
 impl Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Balance = u64;
@@ -150,6 +160,11 @@ impl Config for Test {
 	type Freezer = TestFreezer;
 	type WeightInfo = ();
 	type CallbackHandle = AssetsCallbackHandle;
+	type TransferHook = TestTransferHook;
+	type RuntimeHoldReason = TestId;
+	type MaxHolds = ConstU32<2>;
+	type FreezeIdentifier = TestId;
+	type MaxFreezes = ConstU32<2>;
 	type Extra = ();
 	type RemoveItemsLimit = ConstU32<5>;
 	#[cfg(feature = "runtime-benchmarks")]
@@ -181,6 +196,56 @@ impl FrozenBalance<u32, u64, u64> for TestFreezer {
 	}
 }
 
+/// The compliance rules of an asset, enforced by [`TestTransferHook`].
+#[derive(Clone, Default)]
+pub struct TransferRules {
+	/// Only these accounts may receive the asset, if set.
+	pub allowlist: Option<Vec<u64>>,
+	/// The maximum balance of the asset an account may hold, if set.
+	pub max_holding: Option<u64>,
+	/// The beneficiary of a tax on transfers, and the tax in percent.
+	pub tax: Option<(u64, u64)>,
+}
+
+parameter_types! {
+	static Rules: HashMap<u32, TransferRules> = Default::default();
+}
+
+pub struct TestTransferHook;
+impl TransferHook<u32, u64, u64> for TestTransferHook {
+	fn weight() -> Weight {
+		Weight::from_parts(1_000, 0)
+	}
+
+	fn on_transfer(
+		id: &u32,
+		_source: &u64,
+		dest: &u64,
+		amount: u64,
+	) -> Result<Option<TransferTax<u64, u64>>, DispatchError> {
+		let Some(rules) = Rules::get().get(id).cloned() else { return Ok(None) };
+		if let Some(allowlist) = rules.allowlist {
+			ensure!(allowlist.contains(dest), DispatchError::Other("NotAllowed"));
+		}
+		if let Some(max_holding) = rules.max_holding {
+			ensure!(
+				Assets::balance(*id, dest).saturating_add(amount) <= max_holding,
+				DispatchError::Other("MaxHolding")
+			);
+		}
+		Ok(rules.tax.map(|(beneficiary, percent)| TransferTax {
+			beneficiary,
+			amount: amount * percent / 100,
+		}))
+	}
+}
+
+pub(crate) fn set_transfer_rules(asset: u32, rules: TransferRules) {
+	Rules::mutate(|v| {
+		v.insert(asset, rules);
+	});
+}
+
 pub(crate) fn set_frozen_balance(asset: u32, who: u64, amount: u64) {
 	Frozen::mutate(|v| {
 		v.insert((asset, who), amount);
@@ -21,7 +21,7 @@ use super::*;
 use crate::{mock::*, Error};
 use frame_support::{
 	assert_noop, assert_ok,
-	dispatch::GetDispatchInfo,
+	dispatch::{GetDispatchInfo, Pays},
 	traits::{fungibles::InspectEnumerable, tokens::Preservation::Protect, Currency},
 };
 use pallet_balances::Error as BalancesError;
@@ -303,6 +303,53 @@ fn refunding_frozen_with_deposit_works() {
 	});
 }
 
+#[test]
+fn poke_deposit_works() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(1), 0, 1, 1));
+		assert_ok!(Assets::set_metadata(
+			RuntimeOrigin::signed(1),
+			0,
+			vec![0u8; 10],
+			vec![0u8; 5],
+			12
+		));
+		assert_ok!(Assets::touch(RuntimeOrigin::signed(1), 0));
+		assert_eq!(Balances::reserved_balance(&1), 1 + 16 + 10);
+
+		// Nothing changes while the deposits are up to date.
+		let res = Assets::poke_deposit(RuntimeOrigin::signed(1), 0).unwrap();
+		assert_eq!(res.pays_fee, Pays::Yes);
+
+		assert_noop!(Assets::poke_deposit(RuntimeOrigin::signed(2), 0), Error::<Test>::NoDeposit);
+		assert_noop!(Assets::poke_deposit(RuntimeOrigin::signed(1), 1), Error::<Test>::Unknown);
+
+		// Simulate deposits taken under an older pricing.
+		Asset::<Test>::mutate(0, |asset| asset.as_mut().unwrap().deposit = 3);
+		assert_ok!(Balances::reserve(&1, 2));
+		Metadata::<Test>::mutate(0, |metadata| metadata.deposit = 6);
+		Balances::unreserve(&1, 10);
+		Account::<Test>::mutate(0, 1, |account| {
+			account.as_mut().unwrap().reason = ExistenceReason::DepositHeld(5)
+		});
+		Balances::unreserve(&1, 5);
+
+		let res = Assets::poke_deposit(RuntimeOrigin::signed(1), 0).unwrap();
+		assert_eq!(res.pays_fee, Pays::No);
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::DepositPoked {
+			asset_id: 0,
+			who: 1,
+			old_deposit: 14,
+			new_deposit: 27,
+		}));
+		assert_eq!(Balances::reserved_balance(&1), 1 + 16 + 10);
+		assert_eq!(Asset::<Test>::get(0).unwrap().deposit, 1);
+		assert_eq!(Metadata::<Test>::get(0).deposit, 16);
+		assert_eq!(Account::<Test>::get(0, 1).unwrap().reason, ExistenceReason::DepositHeld(10));
+	});
+}
+
 #[test]
 fn approval_lifecycle_works() {
 	new_test_ext().execute_with(|| {
@@ -1777,3 +1824,226 @@ fn asset_destroy_refund_existence_deposit() {
 		assert_eq!(Balances::reserved_balance(&admin), 0);
 	});
 }
+
+#[test]
+fn transfer_hook_can_veto_transfers() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		set_transfer_rules(
+			0,
+			TransferRules { allowlist: Some(vec![1, 2]), max_holding: Some(50), tax: None },
+		);
+
+		// not on the allowlist.
+		assert_noop!(
+			Assets::transfer(RuntimeOrigin::signed(1), 0, 3, 10),
+			DispatchError::Other("NotAllowed")
+		);
+		assert_noop!(
+			Assets::transfer_keep_alive(RuntimeOrigin::signed(1), 0, 3, 10),
+			DispatchError::Other("NotAllowed")
+		);
+		assert_noop!(
+			<Assets as fungibles::Mutate<_>>::transfer(0, &1, &3, 10, Protect),
+			DispatchError::Other("NotAllowed")
+		);
+
+		// above the maximum holding.
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 40));
+		assert_noop!(
+			Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 11),
+			DispatchError::Other("MaxHolding")
+		);
+		assert_ok!(<Assets as fungibles::Mutate<_>>::transfer(0, &1, &2, 10, Protect));
+		assert_eq!(Assets::balance(0, 2), 50);
+
+		// approved transfers are hooked as well.
+		Balances::make_free_balance_be(&1, 2);
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), 0, 2, 10));
+		assert_noop!(
+			Assets::transfer_approved(RuntimeOrigin::signed(2), 0, 1, 3, 10),
+			DispatchError::Other("NotAllowed")
+		);
+
+		// privileged transfers and changes of the supply are not.
+		assert_ok!(Assets::force_transfer(RuntimeOrigin::signed(1), 0, 1, 3, 10));
+		assert_ok!(<Assets as fungibles::Mutate<_>>::mint_into(0, &3, 10));
+		assert_eq!(Assets::balance(0, 3), 20);
+	});
+}
+
+#[test]
+fn transfer_hook_can_tax_transfers() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 1000));
+		set_transfer_rules(0, TransferRules { tax: Some((9, 10)), ..Default::default() });
+
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 100));
+		assert_eq!(Assets::balance(0, 1), 900);
+		assert_eq!(Assets::balance(0, 2), 90);
+		assert_eq!(Assets::balance(0, 9), 10);
+		System::assert_has_event(RuntimeEvent::Assets(crate::Event::Transferred {
+			asset_id: 0,
+			from: 1,
+			to: 9,
+			amount: 10,
+		}));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::Transferred {
+			asset_id: 0,
+			from: 1,
+			to: 2,
+			amount: 90,
+		}));
+
+		assert_ok!(<Assets as fungibles::Mutate<_>>::transfer(0, &1, &2, 100, Protect));
+		assert_eq!(Assets::balance(0, 1), 800);
+		assert_eq!(Assets::balance(0, 2), 180);
+		assert_eq!(Assets::balance(0, 9), 20);
+
+		// the full approved amount is spent, tax included.
+		Balances::make_free_balance_be(&1, 2);
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), 0, 2, 100));
+		assert_ok!(Assets::transfer_approved(RuntimeOrigin::signed(2), 0, 1, 3, 100));
+		assert_eq!(Assets::balance(0, 1), 700);
+		assert_eq!(Assets::balance(0, 3), 90);
+		assert_eq!(Assets::balance(0, 9), 30);
+		assert_eq!(Approvals::<Test>::get((0, 1, 2)), None);
+
+		// the whole balance can still be transferred.
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(3), 0, 4, 90));
+		assert_eq!(Assets::balance(0, 3), 0);
+		assert_eq!(Assets::balance(0, 4), 81);
+		assert_eq!(Assets::balance(0, 9), 39);
+	});
+}
+
+#[test]
+fn fungibles_transfer_registers_the_transfer_hook_weight() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 1000));
+		let block_weight = || System::block_weight().total();
+
+		// untaxed transfers only weigh the hook.
+		let before = block_weight();
+		assert_ok!(<Assets as fungibles::Mutate<_>>::transfer(0, &1, &2, 100, Protect));
+		assert_eq!(block_weight(), before + TestTransferHook::weight());
+
+		// taxed transfers weigh the transfer of the tax as well.
+		set_transfer_rules(0, TransferRules { tax: Some((9, 10)), ..Default::default() });
+		let before = block_weight();
+		assert_ok!(<Assets as fungibles::Mutate<_>>::transfer(0, &1, &2, 100, Protect));
+		assert_eq!(block_weight(), before + Assets::transfer_hook_weight());
+		assert!(Assets::transfer_hook_weight().any_gt(TestTransferHook::weight()));
+
+		// and so do the calls.
+		let call = Call::<Test>::transfer { id: 0, target: 2, amount: 100 };
+		assert_eq!(
+			call.get_dispatch_info().weight,
+			<Test as Config>::WeightInfo::transfer().saturating_add(Assets::transfer_hook_weight())
+		);
+	});
+}
+
+#[test]
+fn holds_work() {
+	use frame_support::traits::{
+		fungibles::{Inspect, InspectHold, MutateHold},
+		tokens::{Fortitude::Polite, Precision::Exact, Restriction::Free},
+	};
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+
+		assert_ok!(Assets::hold(0, &TestId::Foo, &1, 30));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::Held {
+			asset_id: 0,
+			who: 1,
+			reason: TestId::Foo,
+			amount: 30,
+		}));
+		assert_eq!(Assets::balance(0, 1), 70);
+		assert_eq!(Assets::balance_on_hold(0, &TestId::Foo, &1), 30);
+		assert_eq!(<Assets as Inspect<_>>::total_balance(0, &1), 100);
+		assert_eq!(Assets::total_supply(0), 100);
+
+		// the account can not die while it has a balance on hold.
+		assert_noop!(
+			Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 70),
+			Error::<Test>::BalanceLow
+		);
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 69));
+		assert_eq!(Assets::balance(0, 1), 1);
+
+		assert_eq!(Assets::release(0, &TestId::Foo, &1, 10, Exact), Ok(10));
+		assert_eq!(Assets::balance(0, 1), 11);
+		assert_eq!(Assets::balance_on_hold(0, &TestId::Foo, &1), 20);
+
+		assert_eq!(Assets::burn_held(0, &TestId::Foo, &1, 5, Exact, Polite), Ok(5));
+		assert_eq!(Assets::balance_on_hold(0, &TestId::Foo, &1), 15);
+		assert_eq!(Assets::total_supply(0), 95);
+
+		assert_eq!(
+			Assets::transfer_on_hold(0, &TestId::Foo, &1, &3, 15, Exact, Free, Polite),
+			Ok(15)
+		);
+		assert_eq!(Assets::balance(0, 3), 15);
+		assert_eq!(Assets::total_balance_on_hold(0, &1), 0);
+		assert!(!Holds::<Test>::contains_key(0, 1));
+
+		// holds are bounded, and only on existing accounts.
+		assert_ok!(Assets::hold(0, &TestId::Foo, &3, 1));
+		assert_ok!(Assets::hold(0, &TestId::Bar, &3, 1));
+		assert_ok!(Assets::hold(0, &TestId::Foo, &3, 1));
+		assert_noop!(Assets::hold(0, &TestId::Baz, &3, 1), TokenError::CannotCreateHold);
+		assert_noop!(Assets::hold(0, &TestId::Foo, &4, 1), TokenError::CannotCreateHold);
+	});
+}
+
+#[test]
+fn freezes_work() {
+	use frame_support::traits::{
+		fungibles::{Inspect, InspectFreeze, Mutate, MutateFreeze, MutateHold},
+		tokens::{Fortitude::Polite, Preservation::Expendable},
+	};
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+
+		assert_ok!(Assets::set_freeze(0, &TestId::Foo, &1, 50));
+		assert_eq!(Assets::balance_frozen(0, &TestId::Foo, &1), 50);
+		assert_eq!(<Assets as Inspect<_>>::reducible_balance(0, &1, Expendable, Polite), 49);
+		assert_noop!(
+			Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 50),
+			Error::<Test>::BalanceLow
+		);
+
+		// the largest freeze applies.
+		assert_ok!(Assets::extend_freeze(0, &TestId::Bar, &1, 60));
+		assert_eq!(<Assets as Inspect<_>>::reducible_balance(0, &1, Expendable, Polite), 39);
+		assert_ok!(<Assets as MutateFreeze<_>>::thaw(0, &TestId::Bar, &1));
+		assert_eq!(<Assets as Inspect<_>>::reducible_balance(0, &1, Expendable, Polite), 49);
+
+		// the balance on hold is frozen too.
+		assert_ok!(Assets::mint_into(0, &1, 100));
+		assert_ok!(Assets::hold(0, &TestId::Foo, &1, 100));
+		assert_eq!(<Assets as Inspect<_>>::reducible_balance(0, &1, Expendable, Polite), 99);
+
+		// an account with freezes can not be refunded.
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 1, 1, false, 1));
+		Balances::make_free_balance_be(&2, 100);
+		assert_ok!(Assets::touch(RuntimeOrigin::signed(2), 1));
+		assert_ok!(Assets::set_freeze(1, &TestId::Foo, &2, 10));
+		assert_noop!(
+			Assets::refund(RuntimeOrigin::signed(2), 1, true),
+			Error::<Test>::ContainsFreezes
+		);
+		assert_ok!(Assets::set_freeze(1, &TestId::Foo, &2, 0));
+		assert!(!Freezes::<Test>::contains_key(1, 2));
+		assert_ok!(Assets::refund(RuntimeOrigin::signed(2), 1, true));
+	});
+}
@@ -232,6 +232,76 @@ impl<AssetId, AccountId, Balance> FrozenBalance<AssetId, AccountId, Balance> for
 	fn died(_: AssetId, _: &AccountId) {}
 }
 
+/// An identifier and balance.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct IdAmount<Id, Balance> {
+	/// An identifier for this item.
+	pub id: Id,
+	/// Some amount for this item.
+	pub amount: Balance,
+}
+
+/// A part of a transfer diverted to another account by a [`TransferHook`].
+#[derive(Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct TransferTax<AccountId, Balance> {
+	/// The account receiving the tax.
+	pub beneficiary: AccountId,
+	/// The amount of the tax, at most the amount transferred.
+	pub amount: Balance,
+}
+
+/// Trait for allowing an asset's issuer to restrict or tax the transfers of the asset, to enforce
+/// rules such as allowlists, maximum holdings or jurisdictions.
+///
+/// Called on `transfer`, `transfer_keep_alive`, `transfer_approved` and
+/// [`fungibles::Mutate::transfer`](frame_support::traits::fungibles::Mutate::transfer).
+///
+/// The following are deliberately not hooked, so the rules of an asset must not rely on them:
+/// - `force_transfer`, which is reserved to the admin of the asset, who can move its balances
+///   anyway.
+/// - `mint`, `burn` and [`fungibles::Mutate::mint_into`] and [`fungibles::Mutate::burn_from`],
+///   which change the supply rather than move balances between two accounts. Their callers are the
+///   issuer, the admin or other pallets acting on their behalf.
+/// - [`fungibles::Balanced`], whose withdrawals and deposits are made to and from imbalances rather
+///   than accounts, for example to pay transaction fees or to settle swaps. A transfer made of a
+///   withdrawal and a deposit has no single source and destination to hook.
+///
+/// [`fungibles::Mutate::mint_into`]: frame_support::traits::fungibles::Mutate::mint_into
+/// [`fungibles::Mutate::burn_from`]: frame_support::traits::fungibles::Mutate::burn_from
+/// [`fungibles::Balanced`]: frame_support::traits::fungibles::Balanced
+pub trait TransferHook<AssetId, AccountId, Balance> {
+	/// The worst case weight of [`Self::on_transfer`].
+	///
+	/// The transfer of a [`TransferTax`] is weighed by the pallet and must not be included.
+	fn weight() -> Weight;
+
+	/// Called before `amount` of asset `id` is transferred from `source` to `dest`.
+	///
+	/// Return an error to veto the transfer, or a [`TransferTax`] to transfer part of `amount`
+	/// to its beneficiary rather than to `dest`.
+	fn on_transfer(
+		id: &AssetId,
+		source: &AccountId,
+		dest: &AccountId,
+		amount: Balance,
+	) -> Result<Option<TransferTax<AccountId, Balance>>, DispatchError>;
+}
+
+impl<AssetId, AccountId, Balance> TransferHook<AssetId, AccountId, Balance> for () {
+	fn weight() -> Weight {
+		Weight::zero()
+	}
+
+	fn on_transfer(
+		_: &AssetId,
+		_: &AccountId,
+		_: &AccountId,
+		_: Balance,
+	) -> Result<Option<TransferTax<AccountId, Balance>>, DispatchError> {
+		Ok(None)
+	}
+}
+
 #[derive(Copy, Clone, PartialEq, Eq)]
 pub(super) struct TransferFlags {
 	/// The debited account must stay alive at the end of the operation; an error is returned if
@@ -84,6 +84,8 @@ pub trait WeightInfo {
 	fn refund() -> Weight;
 	fn refund_other() -> Weight;
 	fn block() -> Weight;
+	fn poke_deposit() -> Weight;
+	fn transfer_hook() -> Weight;
 }
 
 /// Weights for pallet_assets using the Substrate node and recommended hardware.
@@ -194,58 +196,62 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
 	/// Storage: Assets Account (r:1 w:1)
 	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: Assets Holds (r:1 w:0)
+	/// Proof: Assets Holds (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: Assets Freezes (r:1 w:0)
+	/// Proof: Assets Freezes (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
 	fn burn() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `459`
-		//  Estimated: `3675`
-		// Minimum execution time: 33_625_000 picoseconds.
-		Weight::from_parts(34_474_000, 3675)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
+		// TODO: run benchmarks
+		Weight::from_parts(34_474_000, 8771)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:1)
 	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
 	/// Storage: Assets Account (r:2 w:2)
 	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: Assets Holds (r:1 w:0)
+	/// Proof: Assets Holds (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: Assets Freezes (r:1 w:0)
+	/// Proof: Assets Freezes (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	fn transfer() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `498`
-		//  Estimated: `6208`
-		// Minimum execution time: 47_609_000 picoseconds.
-		Weight::from_parts(48_476_000, 6208)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
+		// TODO: run benchmarks
+		Weight::from_parts(48_476_000, 11304)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:1)
 	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
 	/// Storage: Assets Account (r:2 w:2)
 	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: Assets Holds (r:1 w:0)
+	/// Proof: Assets Holds (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: Assets Freezes (r:1 w:0)
+	/// Proof: Assets Freezes (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	fn transfer_keep_alive() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `498`
-		//  Estimated: `6208`
-		// Minimum execution time: 41_625_000 picoseconds.
-		Weight::from_parts(43_030_000, 6208)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
+		// TODO: run benchmarks
+		Weight::from_parts(43_030_000, 11304)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:1)
 	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
 	/// Storage: Assets Account (r:2 w:2)
 	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: Assets Holds (r:1 w:0)
+	/// Proof: Assets Holds (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: Assets Freezes (r:1 w:0)
+	/// Proof: Assets Freezes (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	fn force_transfer() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `498`
-		//  Estimated: `6208`
-		// Minimum execution time: 47_661_000 picoseconds.
-		Weight::from_parts(48_469_000, 6208)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
+		// TODO: run benchmarks
+		Weight::from_parts(48_469_000, 11304)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:0)
@@ -408,15 +414,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: Assets Approvals (max_values: None, max_size: Some(148), added: 2623, mode: MaxEncodedLen)
 	/// Storage: Assets Account (r:2 w:2)
 	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: Assets Holds (r:1 w:0)
+	/// Proof: Assets Holds (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: Assets Freezes (r:1 w:0)
+	/// Proof: Assets Freezes (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	fn transfer_approved() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `668`
-		//  Estimated: `6208`
-		// Minimum execution time: 67_712_000 picoseconds.
-		Weight::from_parts(69_946_000, 6208)
-			.saturating_add(T::DbWeight::get().reads(5_u64))
+		// TODO: run benchmarks
+		Weight::from_parts(69_946_000, 11304)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
 			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:1)
@@ -525,6 +532,36 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:1 w:1)
+	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: Assets Metadata (r:1 w:1)
+	/// Proof: Assets Metadata (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn poke_deposit() -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(47_038_000, 3675)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:2 w:2)
+	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: Assets Holds (r:1 w:0)
+	/// Proof: Assets Holds (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: Assets Freezes (r:1 w:0)
+	/// Proof: Assets Freezes (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn transfer_hook() -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(48_476_000, 11304)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -634,58 +671,62 @@ impl WeightInfo for () {
 	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
 	/// Storage: Assets Account (r:1 w:1)
 	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: Assets Holds (r:1 w:0)
+	/// Proof: Assets Holds (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: Assets Freezes (r:1 w:0)
+	/// Proof: Assets Freezes (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
 	fn burn() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `459`
-		//  Estimated: `3675`
-		// Minimum execution time: 33_625_000 picoseconds.
-		Weight::from_parts(34_474_000, 3675)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
+		// TODO: run benchmarks
+		Weight::from_parts(34_474_000, 8771)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:1)
 	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
 	/// Storage: Assets Account (r:2 w:2)
 	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: Assets Holds (r:1 w:0)
+	/// Proof: Assets Holds (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: Assets Freezes (r:1 w:0)
+	/// Proof: Assets Freezes (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	fn transfer() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `498`
-		//  Estimated: `6208`
-		// Minimum execution time: 47_609_000 picoseconds.
-		Weight::from_parts(48_476_000, 6208)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
+		// TODO: run benchmarks
+		Weight::from_parts(48_476_000, 11304)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:1)
 	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
 	/// Storage: Assets Account (r:2 w:2)
 	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: Assets Holds (r:1 w:0)
+	/// Proof: Assets Holds (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: Assets Freezes (r:1 w:0)
+	/// Proof: Assets Freezes (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	fn transfer_keep_alive() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `498`
-		//  Estimated: `6208`
-		// Minimum execution time: 41_625_000 picoseconds.
-		Weight::from_parts(43_030_000, 6208)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
+		// TODO: run benchmarks
+		Weight::from_parts(43_030_000, 11304)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:1)
 	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
 	/// Storage: Assets Account (r:2 w:2)
 	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: Assets Holds (r:1 w:0)
+	/// Proof: Assets Holds (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: Assets Freezes (r:1 w:0)
+	/// Proof: Assets Freezes (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	fn force_transfer() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `498`
-		//  Estimated: `6208`
-		// Minimum execution time: 47_661_000 picoseconds.
-		Weight::from_parts(48_469_000, 6208)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
+		// TODO: run benchmarks
+		Weight::from_parts(48_469_000, 11304)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:0)
@@ -848,15 +889,16 @@ impl WeightInfo for () {
 	/// Proof: Assets Approvals (max_values: None, max_size: Some(148), added: 2623, mode: MaxEncodedLen)
 	/// Storage: Assets Account (r:2 w:2)
 	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: Assets Holds (r:1 w:0)
+	/// Proof: Assets Holds (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: Assets Freezes (r:1 w:0)
+	/// Proof: Assets Freezes (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	fn transfer_approved() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `668`
-		//  Estimated: `6208`
-		// Minimum execution time: 67_712_000 picoseconds.
-		Weight::from_parts(69_946_000, 6208)
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
+		// TODO: run benchmarks
+		Weight::from_parts(69_946_000, 11304)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
 			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:1)
@@ -965,4 +1007,34 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:1 w:1)
+	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: Assets Metadata (r:1 w:1)
+	/// Proof: Assets Metadata (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn poke_deposit() -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(47_038_000, 3675)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:2 w:2)
+	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: Assets Holds (r:1 w:0)
+	/// Proof: Assets Holds (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: Assets Freezes (r:1 w:0)
+	/// Proof: Assets Freezes (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn transfer_hook() -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(48_476_000, 11304)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
 }
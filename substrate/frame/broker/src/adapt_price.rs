@@ -18,7 +18,9 @@
 #![deny(missing_docs)]
 
 use crate::CoreIndex;
-use sp_arithmetic::{traits::One, FixedU64};
+use sp_arithmetic::{traits::One, FixedU64, Perbill};
+use sp_core::Get;
+use sp_std::marker::PhantomData;
 
 /// Type for determining how to set price.
 pub trait AdaptPrice {
@@ -33,6 +35,24 @@ pub trait AdaptPrice {
 	/// - `target`: The target number of cores to be sold (must be larger than zero).
 	/// - `limit`: The maximum number of cores to be sold.
 	fn adapt_price(sold: CoreIndex, target: CoreIndex, limit: CoreIndex) -> FixedU64;
+	/// Return the correction factor by which the regular price must be multiplied based on market
+	/// performance across previous sales.
+	///
+	/// By default this ignores the history and defers to `adapt_price`.
+	///
+	/// - `sold`: The number of cores sold.
+	/// - `target`: The target number of cores to be sold.
+	/// - `limit`: The maximum number of cores to be sold.
+	/// - `demand`: The record of demand which this function left after the previous sale, or `None`
+	///   if there is none yet. It is kept by the pallet and may be updated here.
+	fn adapt_price_with_demand(
+		sold: CoreIndex,
+		target: CoreIndex,
+		limit: CoreIndex,
+		_demand: &mut Option<FixedU64>,
+	) -> FixedU64 {
+		Self::adapt_price(sold, target, limit)
+	}
 }
 
 impl AdaptPrice for () {
@@ -61,10 +81,59 @@ impl AdaptPrice for Linear {
 	}
 }
 
+/// Implementation of `AdaptPrice` which reacts to demand across multiple sales rather than just the
+/// last one.
+///
+/// The ratio of cores sold to the target is averaged exponentially, with the latest sale weighted
+/// by `Smoothing` and the history starting out on target. The average is then turned into a price
+/// factor in the same way as `Linear` does for a single sale.
+pub struct MovingAverage<Smoothing>(PhantomData<Smoothing>);
+impl<Smoothing: Get<Perbill>> AdaptPrice for MovingAverage<Smoothing> {
+	fn leadin_factor_at(when: FixedU64) -> FixedU64 {
+		Linear::leadin_factor_at(when)
+	}
+	fn adapt_price(sold: CoreIndex, target: CoreIndex, limit: CoreIndex) -> FixedU64 {
+		Self::adapt_price_with_demand(sold, target, limit, &mut None)
+	}
+	fn adapt_price_with_demand(
+		sold: CoreIndex,
+		target: CoreIndex,
+		limit: CoreIndex,
+		demand: &mut Option<FixedU64>,
+	) -> FixedU64 {
+		let latest = if target == 0 {
+			FixedU64::one()
+		} else {
+			FixedU64::from_rational(sold.into(), target.into())
+		};
+		let smoothing = Smoothing::get();
+		let previous = demand.unwrap_or_else(FixedU64::one);
+		let average = FixedU64::from_perbill(smoothing) * latest +
+			FixedU64::from_perbill(smoothing.left_from_one()) * previous;
+		*demand = Some(average);
+
+		if average <= FixedU64::one() {
+			average
+		} else if limit > target {
+			FixedU64::one() +
+				(average - FixedU64::one()) *
+					FixedU64::from_rational(target.into(), (limit - target).into())
+		} else {
+			FixedU64::one()
+		}
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
 
+	frame_support::parameter_types! {
+		pub const Nothing: Perbill = Perbill::from_percent(0);
+		pub const Tenth: Perbill = Perbill::from_percent(10);
+		pub const Half: Perbill = Perbill::from_percent(50);
+	}
+
 	#[test]
 	fn linear_no_panic() {
 		for limit in 0..10 {
@@ -81,4 +150,47 @@ mod tests {
 			}
 		}
 	}
+
+	#[test]
+	fn moving_average_follows_demand() {
+		type HalfAverage = MovingAverage<Half>;
+
+		// With full smoothing the history never moves from the target.
+		let mut demand = None;
+		assert_eq!(
+			MovingAverage::<Nothing>::adapt_price_with_demand(0, 5, 10, &mut demand),
+			FixedU64::one()
+		);
+		assert_eq!(demand, Some(FixedU64::one()));
+
+		// Without history a sale is only half-believed.
+		let mut demand = None;
+		let price = HalfAverage::adapt_price_with_demand(0, 5, 10, &mut demand);
+		assert_eq!(price, FixedU64::from_rational(1, 2));
+		// A second sale of nothing pulls the average further down.
+		let price = HalfAverage::adapt_price_with_demand(0, 5, 10, &mut demand);
+		assert_eq!(price, FixedU64::from_rational(1, 4));
+		// Selling out twice brings it back above target.
+		HalfAverage::adapt_price_with_demand(10, 5, 10, &mut demand);
+		let price = HalfAverage::adapt_price_with_demand(10, 5, 10, &mut demand);
+		assert_eq!(demand, Some(FixedU64::from_rational(25, 16)));
+		assert_eq!(price, FixedU64::from_rational(25, 16));
+	}
+
+	#[test]
+	fn moving_average_no_panic() {
+		for limit in 0..10 {
+			for target in 0..10 {
+				let mut demand = Some(FixedU64::from(3));
+				for sold in 0..=limit {
+					MovingAverage::<Tenth>::adapt_price_with_demand(
+						sold,
+						target,
+						limit,
+						&mut demand,
+					);
+				}
+			}
+		}
+	}
 }
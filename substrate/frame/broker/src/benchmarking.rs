@@ -29,7 +29,10 @@ use frame_support::{
 	},
 };
 use frame_system::{Pallet as System, RawOrigin};
-use sp_arithmetic::{traits::Zero, Perbill};
+use sp_arithmetic::{
+	traits::{One, Zero},
+	FixedU64, Perbill,
+};
 use sp_core::Get;
 use sp_runtime::{traits::BlockNumberProvider, Saturating};
 use sp_std::{vec, vec::Vec};
@@ -778,13 +781,13 @@ mod benches {
 			sale_start: now,
 			leadin_length: Zero::zero(),
 			price,
-			sellout_price: None,
+			sellout_price: Some(price),
 			region_begin: commit_timeslice,
 			region_end: commit_timeslice.saturating_add(config.region_length),
 			first_core: 0,
-			ideal_cores_sold: 0,
-			cores_offered: 0,
-			cores_sold: 0,
+			ideal_cores_sold: 1,
+			cores_offered: 1,
+			cores_sold: 1,
 		};
 
 		let status = StatusRecord {
@@ -801,6 +804,10 @@ mod benches {
 		// Assume Leases to be filled for worst case
 		setup_leases::<T>(T::MaxLeasedCores::get(), 1, 10);
 
+		// Assume the previous sale sold out on target so the price is adapted against the recorded
+		// demand.
+		SaleDemand::<T>::put(FixedU64::one());
+
 		#[block]
 		{
 			Broker::<T>::rotate_sale(sale.clone(), &config, &status);
@@ -918,6 +925,107 @@ mod benches {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn list_region() -> Result<(), BenchmarkError> {
+		setup_and_start_sale::<T>()?;
+
+		advance_to::<T>(2);
+
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::set_balance(
+			&caller.clone(),
+			T::Currency::minimum_balance().saturating_add(10u32.into()),
+		);
+
+		let region = Broker::<T>::do_purchase(caller.clone(), 10u32.into())
+			.map_err(|_| BenchmarkError::Weightless)?;
+		let price = ListingPrice::Dutch {
+			start_price: 10u32.into(),
+			end_price: 1u32.into(),
+			duration: 10u32.into(),
+		};
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), region, price);
+
+		assert_last_event::<T>(Event::Listed { region_id: region, seller: caller, price }.into());
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn purchase_listed() -> Result<(), BenchmarkError> {
+		setup_and_start_sale::<T>()?;
+
+		advance_to::<T>(2);
+
+		let seller: T::AccountId = account("seller", 0, SEED);
+		T::Currency::set_balance(
+			&seller.clone(),
+			T::Currency::minimum_balance().saturating_add(10u32.into()),
+		);
+
+		let region = Broker::<T>::do_purchase(seller.clone(), 10u32.into())
+			.map_err(|_| BenchmarkError::Weightless)?;
+		Broker::<T>::do_list_region(region, seller.clone(), ListingPrice::Fixed(10u32.into()))
+			.map_err(|_| BenchmarkError::Weightless)?;
+
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::set_balance(
+			&caller.clone(),
+			T::Currency::minimum_balance().saturating_add(20u32.into()),
+		);
+
+		// Buying only the middle part of the Region is the worst case as it requires two
+		// partitions: one of its first timeslice, which is committed, and one of the remainder.
+		Status::<T>::mutate(|status| {
+			if let Some(status) = status.as_mut() {
+				status.last_committed_timeslice = region.begin;
+			}
+		});
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), region, 1, 10u32.into());
+
+		assert_last_event::<T>(
+			Event::ListingSold {
+				region_id: RegionId { begin: region.begin + 1, ..region },
+				seller,
+				buyer: caller,
+				price: 10u32.into(),
+				duration: 1,
+			}
+			.into(),
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn cancel_listing() -> Result<(), BenchmarkError> {
+		setup_and_start_sale::<T>()?;
+
+		advance_to::<T>(2);
+
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::set_balance(
+			&caller.clone(),
+			T::Currency::minimum_balance().saturating_add(10u32.into()),
+		);
+
+		let region = Broker::<T>::do_purchase(caller.clone(), 10u32.into())
+			.map_err(|_| BenchmarkError::Weightless)?;
+		Broker::<T>::do_list_region(region, caller.clone(), ListingPrice::Fixed(10u32.into()))
+			.map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), region);
+
+		assert_last_event::<T>(Event::ListingCancelled { region_id: region }.into());
+
+		Ok(())
+	}
+
 	// Implements a test for each benchmark. Execute with:
 	// `cargo test -p pallet-broker --features runtime-benchmarks`.
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
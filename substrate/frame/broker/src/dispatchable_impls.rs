@@ -141,12 +141,13 @@ impl<T: Config> Pallet<T> {
 
 		let old_core = core;
 		let core = sale.first_core.saturating_add(sale.cores_sold);
-		Self::charge(&who, record.price)?;
+		let price = Self::renewal_price(&sale, record.price);
+		Self::charge(&who, price)?;
 		Self::deposit_event(Event::Renewed {
 			who,
 			old_core,
 			core,
-			price: record.price,
+			price,
 			begin: sale.region_begin,
 			duration: sale.region_end.saturating_sub(sale.region_begin),
 			workload: workload.clone(),
@@ -157,7 +158,7 @@ impl<T: Config> Pallet<T> {
 		Workplan::<T>::insert((sale.region_begin, core), &workload);
 
 		let begin = sale.region_end;
-		let price_cap = record.price + config.renewal_bump * record.price;
+		let price_cap = price + config.renewal_bump * price;
 		let now = frame_system::Pallet::<T>::block_number();
 		let price = Self::sale_price(&sale, now).min(price_cap);
 		let new_record = AllowedRenewalRecord { price, completion: Complete(workload) };
@@ -184,6 +185,8 @@ impl<T: Config> Pallet<T> {
 		let old_owner = region.owner;
 		region.owner = new_owner;
 		Regions::<T>::insert(&region_id, &region);
+		// A listing is an offer of its seller only.
+		Listings::<T>::remove(&region_id);
 		let duration = region.end.saturating_sub(region_id.begin);
 		Self::deposit_event(Event::Transferred {
 			region_id,
@@ -195,6 +198,76 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	pub(crate) fn do_list_region(
+		region_id: RegionId,
+		seller: T::AccountId,
+		price: ListingPriceOf<T>,
+	) -> DispatchResult {
+		let region = Regions::<T>::get(&region_id).ok_or(Error::<T>::UnknownRegion)?;
+		ensure!(seller == region.owner, Error::<T>::NotOwner);
+		price.validate().map_err(|()| Error::<T>::InvalidListingPrice)?;
+
+		let listed_at = frame_system::Pallet::<T>::block_number();
+		Listings::<T>::insert(
+			&region_id,
+			ListingRecord { seller: seller.clone(), price, listed_at },
+		);
+		Self::deposit_event(Event::Listed { region_id, seller, price });
+		Ok(())
+	}
+
+	pub(crate) fn do_purchase_listed(
+		who: T::AccountId,
+		region_id: RegionId,
+		duration: Timeslice,
+		price_limit: BalanceOf<T>,
+	) -> Result<RegionId, DispatchError> {
+		let status = Status::<T>::get().ok_or(Error::<T>::Uninitialized)?;
+		let listing = Listings::<T>::get(&region_id).ok_or(Error::<T>::NotListed)?;
+		let region = Regions::<T>::get(&region_id).ok_or(Error::<T>::UnknownRegion)?;
+		ensure!(listing.seller == region.owner, Error::<T>::NotOwner);
+		// Only the timeslices which are not yet committed are of any use to the buyer.
+		let begin = region_id.begin.max(status.last_committed_timeslice.saturating_add(1));
+		ensure!(begin < region.end, Error::<T>::ListingExpired);
+		ensure!(duration <= region.end.saturating_sub(begin), Error::<T>::PivotTooLate);
+
+		let now = frame_system::Pallet::<T>::block_number();
+		let price = Self::listing_price(&listing, now).saturating_mul(duration.into());
+		ensure!(price_limit >= price, Error::<T>::Overpriced);
+		T::Currency::transfer(&who, &listing.seller, price, Expendable)?;
+
+		// The committed part of the Region stays with the seller.
+		let mut region_id = region_id;
+		if region_id.begin < begin {
+			(_, region_id) = Self::do_partition(region_id, None, begin - region_id.begin)?;
+		}
+		if begin.saturating_add(duration) < region.end {
+			let (_, remainder) = Self::do_partition(region_id, None, duration)?;
+			Listings::<T>::insert(&remainder, &listing);
+		}
+		Self::do_transfer(region_id, None, who.clone())?;
+		Self::deposit_event(Event::ListingSold {
+			region_id,
+			seller: listing.seller,
+			buyer: who,
+			price,
+			duration,
+		});
+		Ok(region_id)
+	}
+
+	pub(crate) fn do_cancel_listing(region_id: RegionId, who: T::AccountId) -> DispatchResult {
+		let listing = Listings::<T>::get(&region_id).ok_or(Error::<T>::NotListed)?;
+		// Anyone may clear out a listing which the seller is no longer able to honour.
+		if Regions::<T>::get(&region_id).map_or(false, |r| r.owner == listing.seller) {
+			ensure!(who == listing.seller, Error::<T>::NotOwner);
+		}
+
+		Listings::<T>::remove(&region_id);
+		Self::deposit_event(Event::ListingCancelled { region_id });
+		Ok(())
+	}
+
 	pub(crate) fn do_partition(
 		region_id: RegionId,
 		maybe_check_owner: Option<T::AccountId>,
@@ -214,6 +287,8 @@ impl<T: Config> Pallet<T> {
 
 		Regions::<T>::insert(&new_region_ids.0, &RegionRecord { end: pivot, ..region.clone() });
 		Regions::<T>::insert(&new_region_ids.1, &region);
+		// The listing was for the whole of the Region.
+		Listings::<T>::remove(&region_id);
 		Self::deposit_event(Event::Partitioned { old_region_id: region_id, new_region_ids });
 
 		Ok(new_region_ids)
@@ -56,6 +56,7 @@ pub mod pallet {
 		PalletId,
 	};
 	use frame_system::pallet_prelude::*;
+	use sp_arithmetic::FixedU64;
 	use sp_runtime::traits::{Convert, ConvertBack};
 	use sp_std::vec::Vec;
 
@@ -85,6 +86,11 @@ pub mod pallet {
 		/// The algorithm to determine the next price on the basis of market performance.
 		type PriceAdapter: AdaptPrice;
 
+		/// The most a renewal may cost, as a multiple of the regular price of the sale in which it
+		/// is made. If `None`, renewal prices are only limited by the configured `renewal_bump`.
+		#[pallet::constant]
+		type RenewalPriceCap: Get<Option<FixedU64>>;
+
 		/// Reversible conversion from local balance to Relay-chain balance. This will typically be
 		/// the `Identity`, but provided just in case the chains use different representations.
 		type ConvertBalance: Convert<BalanceOf<Self>, RelayBalanceOf<Self>>
@@ -163,6 +169,15 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type CoreCountInbox<T> = StorageValue<_, CoreIndex, OptionQuery>;
 
+	/// The record of demand across previous sales kept on behalf of the `PriceAdapter`.
+	#[pallet::storage]
+	pub type SaleDemand<T> = StorageValue<_, FixedU64, OptionQuery>;
+
+	/// Regions listed for sale on the secondary market.
+	#[pallet::storage]
+	pub type Listings<T> =
+		StorageMap<_, Blake2_128Concat, RegionId, ListingRecordOf<T>, OptionQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -410,6 +425,33 @@ pub mod pallet {
 			/// The core whose workload is no longer available to be renewed for `when`.
 			core: CoreIndex,
 		},
+		/// A Region has been listed for sale on the secondary market.
+		Listed {
+			/// The Region which has been listed.
+			region_id: RegionId,
+			/// The owner of the Region who is selling it.
+			seller: T::AccountId,
+			/// The asking price per timeslice.
+			price: ListingPriceOf<T>,
+		},
+		/// A listed Region (or a leading part of it) has been sold on the secondary market.
+		ListingSold {
+			/// The Region which has been sold.
+			region_id: RegionId,
+			/// The previous owner of the Region.
+			seller: T::AccountId,
+			/// The new owner of the Region.
+			buyer: T::AccountId,
+			/// The total price paid to the seller.
+			price: BalanceOf<T>,
+			/// The duration of the Region sold.
+			duration: Timeslice,
+		},
+		/// A listing has been removed from the secondary market.
+		ListingCancelled {
+			/// The Region which is no longer listed.
+			region_id: RegionId,
+		},
 	}
 
 	#[pallet::error]
@@ -474,6 +516,12 @@ pub mod pallet {
 		AlreadyExpired,
 		/// The configuration could not be applied because it is invalid.
 		InvalidConfig,
+		/// The Region is not listed on the secondary market.
+		NotListed,
+		/// The listing price is invalid.
+		InvalidListingPrice,
+		/// The listed Region has no timeslices left which are not yet committed.
+		ListingExpired,
 	}
 
 	#[pallet::hooks]
@@ -786,5 +834,59 @@ pub mod pallet {
 			Self::do_notify_core_count(core_count)?;
 			Ok(())
 		}
+
+		/// List a Bulk Coretime Region for sale on the secondary market, replacing any existing
+		/// listing of it.
+		///
+		/// - `origin`: Must be a Signed origin of the account which owns the Region `region_id`.
+		/// - `region_id`: The Region which should be listed.
+		/// - `price`: The asking price per timeslice of the Region.
+		#[pallet::call_index(20)]
+		pub fn list_region(
+			origin: OriginFor<T>,
+			region_id: RegionId,
+			price: ListingPriceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_list_region(region_id, who, price)?;
+			Ok(())
+		}
+
+		/// Purchase a listed Bulk Coretime Region, or a leading part of it, from the secondary
+		/// market.
+		///
+		/// If only part of the Region is bought, it is partitioned and the remainder stays listed
+		/// under its new identity at the same price. Timeslices of the Region which are already
+		/// committed are neither sold nor paid for: they are partitioned off and left with the
+		/// seller.
+		///
+		/// - `origin`: Must be a Signed origin with at least enough funds to pay the asking price.
+		/// - `region_id`: The listed Region which should be bought.
+		/// - `duration`: The number of timeslices to buy, from the beginning of the Region or from
+		///   the first timeslice not yet committed, whichever is later.
+		/// - `price_limit`: An amount no more than which should be paid.
+		#[pallet::call_index(21)]
+		pub fn purchase_listed(
+			origin: OriginFor<T>,
+			region_id: RegionId,
+			duration: Timeslice,
+			price_limit: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_purchase_listed(who, region_id, duration, price_limit)?;
+			Ok(())
+		}
+
+		/// Remove a Bulk Coretime Region from the secondary market.
+		///
+		/// - `origin`: Must be a Signed origin of the seller, or of any account if the seller no
+		///   longer owns the Region.
+		/// - `region_id`: The Region whose listing should be removed.
+		#[pallet::call_index(22)]
+		pub fn cancel_listing(origin: OriginFor<T>, region_id: RegionId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_cancel_listing(region_id, who)?;
+			Ok(())
+		}
 	}
 }
@@ -28,7 +28,7 @@ use frame_support::{
 	PalletId,
 };
 use frame_system::{EnsureRoot, EnsureSignedBy};
-use sp_arithmetic::Perbill;
+use sp_arithmetic::{FixedU64, Perbill};
 use sp_core::{ConstU32, ConstU64};
 use sp_runtime::{
 	traits::{BlockNumberProvider, Identity},
@@ -71,6 +71,7 @@ parameter_types! {
 	pub static CoretimeUsage: BTreeMap<CoreIndex, Vec<(CoreAssignment, PartsOf57600)>> = Default::default();
 	pub static CoretimeInPool: CoreMaskBitCount = 0;
 	pub static NotifyRevenueInfo: Vec<(u32, u64)> = Default::default();
+	pub static RenewalPriceCap: Option<FixedU64> = None;
 }
 
 pub struct TestCoretimeProvider;
@@ -200,6 +201,7 @@ impl crate::Config for Test {
 	type PalletId = TestBrokerId;
 	type AdminOrigin = EnsureOneOrRoot;
 	type PriceAdapter = Linear;
+	type RenewalPriceCap = RenewalPriceCap;
 }
 
 pub fn advance_to(b: u64) {
@@ -24,6 +24,7 @@ use frame_support::{
 	BoundedVec,
 };
 use frame_system::RawOrigin::Root;
+use sp_arithmetic::FixedU64;
 use sp_runtime::traits::Get;
 use CoreAssignment::*;
 use CoretimeTraceItem::*;
@@ -316,6 +317,25 @@ fn migration_works() {
 	});
 }
 
+#[test]
+fn renewal_price_cap_works() {
+	TestExt::new().endow(1, 1000).execute_with(|| {
+		RenewalPriceCap::set(Some(FixedU64::from_rational(1, 4)));
+		assert_ok!(Broker::do_start_sales(100, 1));
+		advance_to(2);
+		let region = Broker::do_purchase(1, u64::max_value()).unwrap();
+		assert_eq!(balance(1), 900);
+		assert_ok!(Broker::do_assign(region, None, 1001, Final));
+		advance_to(6);
+		// The regular price is now 200, so renewing costs no more than 50.
+		assert_eq!(SaleInfo::<Test>::get().unwrap().price, 200);
+		assert_ok!(Broker::do_renew(1, region.core));
+		assert_eq!(balance(1), 850);
+		// The next renewal is bumped from the price actually paid.
+		assert_eq!(AllowedRenewals::<Test>::iter_values().next().unwrap().price, 55);
+	});
+}
+
 #[test]
 fn renewal_works() {
 	TestExt::new().endow(1, 1000).execute_with(|| {
@@ -1044,3 +1064,142 @@ fn config_works() {
 		assert_noop!(Broker::configure(Root.into(), cfg), Error::<Test>::InvalidConfig);
 	});
 }
+
+#[test]
+fn listed_region_can_be_bought_in_parts() {
+	TestExt::new().endow(1, 1000).endow(2, 1000).execute_with(|| {
+		assert_ok!(Broker::do_start_sales(100, 1));
+		advance_to(2);
+		let region = Broker::do_purchase(1, u64::max_value()).unwrap();
+		assert_noop!(
+			Broker::do_list_region(region, 2, ListingPrice::Fixed(10)),
+			Error::<Test>::NotOwner
+		);
+		let bad = ListingPrice::Dutch { start_price: 10, end_price: 20, duration: 5 };
+		assert_noop!(Broker::do_list_region(region, 1, bad), Error::<Test>::InvalidListingPrice);
+		assert_ok!(Broker::do_list_region(region, 1, ListingPrice::Fixed(10)));
+
+		// Two of the three timeslices cost 20.
+		assert_noop!(Broker::do_purchase_listed(2, region, 2, 19), Error::<Test>::Overpriced);
+		assert_noop!(Broker::do_purchase_listed(2, region, 4, 40), Error::<Test>::PivotTooLate);
+		assert_ok!(Broker::do_purchase_listed(2, region, 2, 20));
+		assert_eq!(balance(1), 920);
+		assert_eq!(balance(2), 980);
+		assert_eq!(
+			Regions::<Test>::get(region).unwrap(),
+			RegionRecord { end: 6, owner: 2, paid: None }
+		);
+
+		// The remainder stays with the seller and remains listed.
+		let remainder = RegionId { begin: 6, ..region };
+		assert_eq!(Regions::<Test>::get(remainder).unwrap().owner, 1);
+		assert!(!Listings::<Test>::contains_key(region));
+		assert_ok!(Broker::do_purchase_listed(2, remainder, 1, 10));
+		assert_eq!(Regions::<Test>::get(remainder).unwrap().owner, 2);
+		assert_eq!(Listings::<Test>::iter().count(), 0);
+		assert_eq!(balance(1), 930);
+		assert_eq!(balance(2), 970);
+	});
+}
+
+#[test]
+fn dutch_listing_price_falls() {
+	TestExt::new().endow(1, 1000).endow(2, 1000).execute_with(|| {
+		assert_ok!(Broker::do_start_sales(100, 1));
+		advance_to(2);
+		let region = Broker::do_purchase(1, u64::max_value()).unwrap();
+		let price = ListingPrice::Dutch { start_price: 100, end_price: 20, duration: 4 };
+		assert_ok!(Broker::do_list_region(region, 1, price));
+		let listing = Listings::<Test>::get(region).unwrap();
+		assert_eq!(Broker::listing_price(&listing, 2), 100);
+		assert_eq!(Broker::listing_price(&listing, 4), 60);
+		assert_eq!(Broker::listing_price(&listing, 6), 20);
+		assert_eq!(Broker::listing_price(&listing, 100), 20);
+
+		advance_to(4);
+		assert_noop!(Broker::do_purchase_listed(2, region, 3, 179), Error::<Test>::Overpriced);
+		assert_ok!(Broker::do_purchase_listed(2, region, 3, 180));
+		assert_eq!(Regions::<Test>::get(region).unwrap().owner, 2);
+		assert_eq!(balance(1), 1080);
+	});
+}
+
+#[test]
+fn cancel_listing_works() {
+	TestExt::new().endow(1, 1000).endow(2, 1000).execute_with(|| {
+		assert_ok!(Broker::do_start_sales(100, 1));
+		advance_to(2);
+		let region = Broker::do_purchase(1, u64::max_value()).unwrap();
+		assert_noop!(Broker::do_cancel_listing(region, 1), Error::<Test>::NotListed);
+		assert_ok!(Broker::do_list_region(region, 1, ListingPrice::Fixed(10)));
+		assert_noop!(Broker::do_cancel_listing(region, 2), Error::<Test>::NotOwner);
+		assert_ok!(Broker::do_cancel_listing(region, 1));
+		assert_noop!(Broker::do_purchase_listed(2, region, 3, 30), Error::<Test>::NotListed);
+
+		// A listing of a Region which no longer exists cannot be bought and may be cleared by
+		// anyone.
+		assert_ok!(Broker::do_list_region(region, 1, ListingPrice::Fixed(10)));
+		assert_ok!(Broker::do_interlace(region, Some(1), CoreMask::from_chunk(0, 40)));
+		assert_noop!(Broker::do_purchase_listed(2, region, 3, 30), Error::<Test>::UnknownRegion);
+		assert_ok!(Broker::do_cancel_listing(region, 2));
+		assert!(!Listings::<Test>::contains_key(region));
+	});
+}
+
+#[test]
+fn transfer_and_partition_remove_listings() {
+	TestExt::new().endow(1, 1000).endow(2, 1000).execute_with(|| {
+		assert_ok!(Broker::do_start_sales(100, 1));
+		advance_to(2);
+		let region = Broker::do_purchase(1, u64::max_value()).unwrap();
+
+		assert_ok!(Broker::do_list_region(region, 1, ListingPrice::Fixed(10)));
+		assert_ok!(Broker::do_transfer(region, Some(1), 3));
+		assert!(!Listings::<Test>::contains_key(region));
+		assert_noop!(Broker::do_purchase_listed(2, region, 3, 30), Error::<Test>::NotListed);
+
+		assert_ok!(Broker::do_list_region(region, 3, ListingPrice::Fixed(10)));
+		let (first, second) = Broker::do_partition(region, Some(3), 1).unwrap();
+		assert_eq!(Listings::<Test>::iter().count(), 0);
+		assert_noop!(Broker::do_purchase_listed(2, first, 1, 10), Error::<Test>::NotListed);
+		assert_noop!(Broker::do_purchase_listed(2, second, 2, 20), Error::<Test>::NotListed);
+	});
+}
+
+#[test]
+fn committed_part_of_listed_region_is_not_sold() {
+	TestExt::new().endow(1, 1000).endow(2, 1000).execute_with(|| {
+		assert_ok!(Broker::do_start_sales(100, 1));
+		advance_to(2);
+		let region = Broker::do_purchase(1, u64::max_value()).unwrap();
+		assert_eq!(region.begin, 4);
+		assert_ok!(Broker::do_list_region(region, 1, ListingPrice::Fixed(10)));
+
+		// Timeslice 4 is committed: only two timeslices are left to sell.
+		advance_to(6);
+		assert_eq!(Status::<Test>::get().unwrap().last_committed_timeslice, 4);
+		assert_noop!(Broker::do_purchase_listed(2, region, 3, 30), Error::<Test>::PivotTooLate);
+		assert_ok!(Broker::do_purchase_listed(2, region, 1, 10));
+		assert_eq!(balance(2), 990);
+
+		// The committed timeslice stays with the seller, unlisted.
+		assert_eq!(
+			Regions::<Test>::get(region).unwrap(),
+			RegionRecord { end: 5, owner: 1, paid: None }
+		);
+		let bought = RegionId { begin: 5, ..region };
+		assert_eq!(
+			Regions::<Test>::get(bought).unwrap(),
+			RegionRecord { end: 6, owner: 2, paid: None }
+		);
+		let remainder = RegionId { begin: 6, ..region };
+		assert_eq!(Listings::<Test>::iter_keys().collect::<Vec<_>>(), vec![remainder]);
+
+		// Once all of it is committed, the listing can no longer be bought.
+		advance_to(12);
+		assert_noop!(
+			Broker::do_purchase_listed(2, remainder, 1, 10),
+			Error::<Test>::ListingExpired
+		);
+	});
+}
@@ -182,8 +182,15 @@ impl<T: Config> Pallet<T> {
 				Some(old_sale.price)
 			};
 			if let Some(purchase_price) = maybe_purchase_price {
-				T::PriceAdapter::adapt_price(sold.min(offered), ideal, offered)
-					.saturating_mul_int(purchase_price)
+				SaleDemand::<T>::mutate(|demand| {
+					T::PriceAdapter::adapt_price_with_demand(
+						sold.min(offered),
+						ideal,
+						offered,
+						demand,
+					)
+				})
+				.saturating_mul_int(purchase_price)
 			} else {
 				old_sale.price
 			}
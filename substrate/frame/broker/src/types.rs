@@ -23,7 +23,7 @@ use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::traits::fungible::Inspect;
 use frame_system::{pallet_prelude::BlockNumberFor, Config as SConfig};
 use scale_info::TypeInfo;
-use sp_arithmetic::Perbill;
+use sp_arithmetic::{traits::Zero, Perbill};
 use sp_core::{ConstU32, RuntimeDebug};
 use sp_runtime::BoundedVec;
 
@@ -90,6 +90,47 @@ pub struct RegionRecord<AccountId, Balance> {
 }
 pub type RegionRecordOf<T> = RegionRecord<<T as SConfig>::AccountId, BalanceOf<T>>;
 
+/// The asking price of a Region listed on the secondary market. Prices are quoted per timeslice so
+/// that any leading part of the Region may be bought on its own.
+#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum ListingPrice<Balance, BlockNumber> {
+	/// A constant price per timeslice.
+	Fixed(Balance),
+	/// A price per timeslice which falls linearly from `start_price` to `end_price` over the
+	/// `duration` blocks following the listing, remaining at `end_price` thereafter.
+	Dutch { start_price: Balance, end_price: Balance, duration: BlockNumber },
+}
+pub type ListingPriceOf<T> = ListingPrice<BalanceOf<T>, BlockNumberFor<T>>;
+
+impl<Balance: PartialOrd, BlockNumber: Zero> ListingPrice<Balance, BlockNumber> {
+	/// Check the price for internal consistency; a Dutch auction must run for a non-zero number of
+	/// blocks and may not rise.
+	pub(crate) fn validate(&self) -> Result<(), ()> {
+		match self {
+			Self::Fixed(_) => Ok(()),
+			Self::Dutch { start_price, end_price, duration } =>
+				if duration.is_zero() || start_price < end_price {
+					Err(())
+				} else {
+					Ok(())
+				},
+		}
+	}
+}
+
+/// A Region which has been listed for sale on the secondary market.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ListingRecord<AccountId, Balance, BlockNumber> {
+	/// The account selling the Region. The listing is only valid while it owns the Region.
+	pub seller: AccountId,
+	/// The asking price.
+	pub price: ListingPrice<Balance, BlockNumber>,
+	/// The local block number at which the Region was listed.
+	pub listed_at: BlockNumber,
+}
+pub type ListingRecordOf<T> =
+	ListingRecord<<T as SConfig>::AccountId, BalanceOf<T>, BlockNumberFor<T>>;
+
 /// An distinct item which can be scheduled on a Polkadot Core.
 #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub struct ScheduleItem {
@@ -66,6 +66,26 @@ impl<T: Config> Pallet<T> {
 		T::PriceAdapter::leadin_factor_at(through).saturating_mul_int(sale.price)
 	}
 
+	/// The price at which a renewal recorded at `price` may be made in `sale`.
+	pub fn renewal_price(sale: &SaleInfoRecordOf<T>, price: BalanceOf<T>) -> BalanceOf<T> {
+		match T::RenewalPriceCap::get() {
+			Some(cap) => price.min(cap.saturating_mul_int(sale.price)),
+			None => price,
+		}
+	}
+
+	pub fn listing_price(listing: &ListingRecordOf<T>, now: BlockNumberFor<T>) -> BalanceOf<T> {
+		match listing.price {
+			ListingPrice::Fixed(price) => price,
+			ListingPrice::Dutch { start_price, end_price, duration } => {
+				let num = now.saturating_sub(listing.listed_at).min(duration).saturated_into();
+				let through = FixedU64::from_rational(num, duration.saturated_into());
+				let discount = through.saturating_mul_int(start_price.saturating_sub(end_price));
+				start_price.saturating_sub(discount)
+			},
+		}
+	}
+
 	pub(crate) fn charge(who: &T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
 		let credit = T::Currency::withdraw(&who, amount, Exact, Expendable, Polite)?;
 		T::OnRevenue::on_unbalanced(credit);
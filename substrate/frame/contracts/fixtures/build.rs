@@ -225,7 +225,7 @@ fn post_process_wasm(input_path: &Path, output_path: &Path) -> Result<()> {
 	if let Some(section) = module.export_section_mut() {
 		section.entries_mut().retain(|entry| {
 			matches!(entry.internal(), Internal::Function(_)) &&
-				(entry.field() == "call" || entry.field() == "deploy")
+				matches!(entry.field(), "call" | "deploy" | "migrate")
 		});
 	}
 
@@ -74,6 +74,7 @@ impl pallet_contracts::Config for Runtime {
 	type CallStack = [pallet_contracts::Frame<Self>; 5];
 	type ChainExtension = ();
 	type CodeHashLockupDepositPercent = CodeHashLockupDepositPercent;
+	type CodeUpgradeDelay = ConstU32<10>;
 	type Currency = Balances;
 	type DefaultDepositLimit = DefaultDepositLimit;
 	type DepositPerByte = DepositPerByte;
@@ -534,6 +534,62 @@ benchmarks! {
 		assert_eq!(instance.info()?.code_hash, hash);
 	}
 
+	// The worst case is replacing an upgrade the contract already has pending.
+	schedule_code_upgrade {
+		let instance = <Contract<T>>::with_caller(
+			whitelisted_caller(), WasmModule::dummy(), vec![],
+		)?;
+		let WasmModule { code, hash: replaced, .. } = <WasmModule<T>>::dummy_with_bytes(64);
+		<Contracts<T>>::store_code_raw(code, instance.caller.clone())?;
+		let WasmModule { code, hash, .. } = <WasmModule<T>>::dummy_with_bytes(128);
+		<Contracts<T>>::store_code_raw(code, instance.caller.clone())?;
+		let origin = RawOrigin::Signed(instance.account_id.clone());
+		let gas_limit = <Contracts<T>>::max_migrate_gas_limit();
+		<Contracts<T>>::schedule_code_upgrade(origin.clone().into(), replaced, gas_limit)?;
+	}: _(origin, hash, gas_limit)
+	verify {
+		assert_eq!(
+			<Contracts<T>>::pending_code_upgrade(instance.account_id).map(|u| u.code_hash),
+			Some(hash),
+		);
+	}
+
+	cancel_code_upgrade {
+		let instance = <Contract<T>>::with_caller(
+			whitelisted_caller(), WasmModule::dummy(), vec![],
+		)?;
+		let WasmModule { code, hash, .. } = <WasmModule<T>>::dummy_with_bytes(128);
+		<Contracts<T>>::store_code_raw(code, instance.caller.clone())?;
+		let origin = RawOrigin::Signed(instance.account_id.clone());
+		let gas_limit = <Contracts<T>>::max_migrate_gas_limit();
+		<Contracts<T>>::schedule_code_upgrade(origin.clone().into(), hash, gas_limit)?;
+	}: _(origin)
+	verify {
+		assert!(<Contracts<T>>::pending_code_upgrade(instance.account_id).is_none());
+	}
+
+	// The new code has no `migrate` function, whose execution is accounted for by its gas limit.
+	apply_code_upgrade {
+		let instance = <Contract<T>>::with_caller(
+			whitelisted_caller(), WasmModule::dummy(), vec![],
+		)?;
+		let WasmModule { code, hash, .. } = <WasmModule<T>>::dummy_with_bytes(128);
+		<Contracts<T>>::store_code_raw(code, instance.caller.clone())?;
+		let gas_limit = <Contracts<T>>::max_migrate_gas_limit();
+		<Contracts<T>>::schedule_code_upgrade(
+			RawOrigin::Signed(instance.account_id.clone()).into(),
+			hash,
+			gas_limit,
+		)?;
+		frame_system::Pallet::<T>::set_block_number(
+			frame_system::Pallet::<T>::block_number() + T::CodeUpgradeDelay::get(),
+		);
+		let origin = RawOrigin::Signed(instance.caller.clone());
+	}: _(origin, instance.addr.clone(), gas_limit, None)
+	verify {
+		assert_eq!(instance.info()?.code_hash, hash);
+	}
+
 	#[pov_mode = Measured]
 	seal_caller {
 		let r in 0 .. API_BENCHMARK_RUNS;
@@ -15,11 +15,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod call_tracer;
+
 pub use crate::{
 	exec::{ExecResult, ExportedFunction},
 	primitives::ExecReturnValue,
 };
 use crate::{Config, LOG_TARGET};
+pub use call_tracer::{CallTracer, CallTracerSpan};
+use frame_support::weights::Weight;
 
 /// Umbrella trait for all interfaces that serves for debugging.
 pub trait Debugger<T: Config>: Tracing<T> + CallInterceptor<T> {}
@@ -47,6 +51,41 @@ pub trait Tracing<T: Config> {
 		entry_point: ExportedFunction,
 		input_data: &[u8],
 	) -> Self::CallSpan;
+
+	/// Called when a contract reads a value from its storage.
+	///
+	/// # Arguments
+	///
+	/// * `contract_address` - The address of the contract reading from its storage.
+	/// * `key` - The key of the value, as given by the contract.
+	/// * `value` - The value read, `None` if there was none.
+	fn on_storage_read(_contract_address: &T::AccountId, _key: &[u8], _value: Option<&[u8]>) {}
+
+	/// Called just before a contract writes a value to its storage.
+	///
+	/// # Arguments
+	///
+	/// * `contract_address` - The address of the contract writing to its storage.
+	/// * `key` - The key of the value, as given by the contract.
+	/// * `value` - The value to be written, `None` if the value is to be removed.
+	/// * `previous` - Reads the value about to be overwritten. This comes at the cost of an
+	///   additional storage read, so it should only be used off-chain.
+	fn on_storage_write(
+		_contract_address: &T::AccountId,
+		_key: &[u8],
+		_value: Option<&[u8]>,
+		_previous: impl FnOnce() -> Option<Vec<u8>>,
+	) {
+	}
+
+	/// Called when a contract emits an event.
+	///
+	/// # Arguments
+	///
+	/// * `contract_address` - The address of the contract emitting the event.
+	/// * `topics` - The topics of the event.
+	/// * `data` - The data of the event.
+	fn on_event(_contract_address: &T::AccountId, _topics: &[T::Hash], _data: &[u8]) {}
 }
 
 /// Defines a span of execution for a contract call.
@@ -57,6 +96,23 @@ pub trait CallSpan {
 	///
 	/// * `output` - The raw output of the call.
 	fn after_call(self, output: &ExecReturnValue);
+
+	/// Called just after the execution of a contract, whether or not it succeeded.
+	///
+	/// By default, this forwards the output of successful executions to [`Self::after_call`].
+	///
+	/// # Arguments
+	///
+	/// * `result` - The result of the call.
+	/// * `gas_consumed` - The gas consumed by the call, including the one of the calls it made.
+	fn after_call_with_result(self, result: &ExecResult, _gas_consumed: Weight)
+	where
+		Self: Sized,
+	{
+		if let Ok(output) = result {
+			self.after_call(output)
+		}
+	}
 }
 
 impl<T: Config> Tracing<T> for () {
@@ -0,0 +1,159 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A [`Tracing`] implementation which records contract executions as [`CallTrace`]s.
+
+use super::{CallInterceptor, CallSpan, ExecResult, ExecReturnValue, ExportedFunction, Tracing};
+use crate::{
+	primitives::{CallTrace, CallType, EventTrace, StorageAccess},
+	Config,
+};
+use codec::Encode;
+use frame_support::weights::Weight;
+use sp_std::prelude::*;
+
+/// The traces being recorded by [`CallTracer::trace`].
+#[derive(Default)]
+struct Traces {
+	/// The executions in progress, the innermost one last.
+	stack: Vec<CallTrace>,
+	/// The top level executions which finished, in order.
+	finished: Vec<CallTrace>,
+}
+
+environmental::environmental!(traces: Traces);
+
+/// Records the contract executions happening within [`CallTracer::trace`] as [`CallTrace`]s.
+///
+/// Outside of [`CallTracer::trace`], this does nothing, which makes it cheap enough to be used as
+/// the `Debug` type of a runtime, so that off-chain callers can trace the executions of a dry run.
+pub struct CallTracer;
+
+impl CallTracer {
+	/// Runs `f`, recording the contract executions it leads to.
+	///
+	/// Returns the result of `f` together with the traces of the top level executions, in order.
+	/// The `from` account of those is left empty, since it isn't known to the tracer.
+	pub fn trace<R>(f: impl FnOnce() -> R) -> (R, Vec<CallTrace>) {
+		let mut recorded = Traces::default();
+		let result = traces::using(&mut recorded, f);
+		(result, recorded.finished)
+	}
+
+	/// Calls `f` with the trace of the innermost execution in progress, if any is recorded.
+	fn with_current(f: impl FnOnce(&mut CallTrace)) {
+		traces::with(|traces| traces.stack.last_mut().map(f));
+	}
+}
+
+/// The [`CallSpan`] of [`CallTracer`].
+pub struct CallTracerSpan;
+
+impl<T: Config> Tracing<T> for CallTracer {
+	type CallSpan = CallTracerSpan;
+
+	fn new_call_span(
+		contract_address: &T::AccountId,
+		entry_point: ExportedFunction,
+		input_data: &[u8],
+	) -> CallTracerSpan {
+		traces::with(|traces| {
+			let from = traces.stack.last().map(|caller| caller.to.clone()).unwrap_or_default();
+			traces.stack.push(CallTrace {
+				call_type: match entry_point {
+					ExportedFunction::Constructor => CallType::Create,
+					ExportedFunction::Call => CallType::Call,
+					ExportedFunction::Migrate => CallType::Migrate,
+				},
+				from,
+				to: contract_address.encode(),
+				input: input_data.to_vec(),
+				..Default::default()
+			});
+		});
+		CallTracerSpan
+	}
+
+	fn on_storage_read(_contract_address: &T::AccountId, key: &[u8], value: Option<&[u8]>) {
+		Self::with_current(|trace| {
+			trace
+				.storage
+				.push(StorageAccess::Read { key: key.to_vec(), value: value.map(|v| v.to_vec()) })
+		});
+	}
+
+	fn on_storage_write(
+		_contract_address: &T::AccountId,
+		key: &[u8],
+		value: Option<&[u8]>,
+		previous: impl FnOnce() -> Option<Vec<u8>>,
+	) {
+		Self::with_current(|trace| {
+			trace.storage.push(StorageAccess::Write {
+				key: key.to_vec(),
+				previous: previous(),
+				value: value.map(|v| v.to_vec()),
+			})
+		});
+	}
+
+	fn on_event(_contract_address: &T::AccountId, topics: &[T::Hash], data: &[u8]) {
+		Self::with_current(|trace| {
+			trace.logs.push(EventTrace {
+				topics: topics.iter().map(Encode::encode).collect(),
+				data: data.to_vec(),
+			})
+		});
+	}
+}
+
+impl<T: Config> CallInterceptor<T> for CallTracer {
+	fn intercept_call(
+		_contract_address: &T::AccountId,
+		_entry_point: &ExportedFunction,
+		_input_data: &[u8],
+	) -> Option<ExecResult> {
+		None
+	}
+}
+
+impl CallSpan for CallTracerSpan {
+	fn after_call(self, output: &ExecReturnValue) {
+		self.after_call_with_result(&Ok(output.clone()), Weight::zero())
+	}
+
+	fn after_call_with_result(self, result: &ExecResult, gas_consumed: Weight) {
+		traces::with(|traces| {
+			let Some(mut trace) = traces.stack.pop() else { return };
+			trace.gas_used = gas_consumed;
+			match result {
+				Ok(output) => {
+					trace.output = output.data.clone();
+					trace.reverted = output.did_revert();
+				},
+				Err(err) => {
+					trace.reverted = true;
+					trace.error = Some(err.error);
+				},
+			}
+			match traces.stack.last_mut() {
+				Some(caller) => caller.calls.push(trace),
+				None => traces.finished.push(trace),
+			}
+		});
+	}
+}
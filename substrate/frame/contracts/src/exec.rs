@@ -21,9 +21,10 @@ use crate::{
 	primitives::{ExecReturnValue, StorageDeposit},
 	storage::{self, meter::Diff, WriteOutcome},
 	BalanceOf, CodeHash, CodeInfo, CodeInfoOf, Config, ContractInfo, ContractInfoOf,
-	DebugBufferVec, Determinism, Error, Event, Nonce, Origin, Pallet as Contracts, Schedule,
-	LOG_TARGET,
+	DebugBufferVec, Determinism, Error, Event, Nonce, Origin, Pallet as Contracts,
+	PendingCodeUpgradeOf, Schedule, LOG_TARGET,
 };
+use codec::Encode;
 use frame_support::{
 	crypto::ecdsa::ECDSAExt,
 	dispatch::{DispatchResult, DispatchResultWithPostInfo},
@@ -381,6 +382,9 @@ pub enum ExportedFunction {
 	Constructor,
 	/// The function which is executed when a contract is called.
 	Call,
+	/// The function which is executed once when the code of a contract is upgraded, to migrate
+	/// its storage to the new code.
+	Migrate,
 }
 
 /// A trait that represents something that can be executed.
@@ -533,6 +537,12 @@ enum FrameArgs<'a, T: Config, E> {
 		/// The input data is used in the contract address deriviation of the new contract.
 		input_data: &'a [u8],
 	},
+	Migrate {
+		/// The account id of the contract whose code is upgraded.
+		dest: T::AccountId,
+		/// The executable of the new code, whose `migrate` function is run.
+		executable: E,
+	},
 }
 
 /// Describes the different states of a contract as contained in a `Frame`.
@@ -730,6 +740,39 @@ where
 		stack.run(executable, input_data).map(|ret| (account_id, ret))
 	}
 
+	/// Create and run a new call stack by upgrading the code of a contract to the one of
+	/// `executable` and running its `migrate` function.
+	///
+	/// The migration gets the previous code hash of the contract as input. If it fails, the code
+	/// of the contract is left untouched.
+	///
+	/// # Note
+	///
+	/// `debug_message` should only ever be set to `Some` when executing as an RPC because
+	/// it adds allocations and could be abused to drive the runtime into an OOM panic.
+	pub fn run_migrate(
+		origin: T::AccountId,
+		dest: T::AccountId,
+		executable: E,
+		gas_meter: &'a mut GasMeter<T>,
+		storage_meter: &'a mut storage::meter::Meter<T>,
+		schedule: &'a Schedule<T>,
+		debug_message: Option<&'a mut DebugBufferVec<T>>,
+	) -> Result<ExecReturnValue, ExecError> {
+		let (mut stack, executable) = Self::new(
+			FrameArgs::Migrate { dest, executable },
+			Origin::from_account_id(origin),
+			gas_meter,
+			storage_meter,
+			schedule,
+			BalanceOf::<T>::zero(),
+			debug_message,
+			Determinism::Enforced,
+		)?;
+		let input_data = stack.own_code_hash().encode();
+		stack.run(executable, input_data)
+	}
+
 	/// Create a new call stack.
 	fn new(
 		args: FrameArgs<T, E>,
@@ -817,6 +860,11 @@ where
 						Some(nonce),
 					)
 				},
+				FrameArgs::Migrate { dest, executable } => {
+					let contract =
+						<ContractInfoOf<T>>::get(&dest).ok_or(<Error<T>>::ContractNotFound)?;
+					(dest, contract, executable, None, ExportedFunction::Migrate, None)
+				},
 			};
 
 		// `Relaxed` will only be ever set in case of off-chain execution.
@@ -859,8 +907,10 @@ where
 		// We do not store on instantiate because we do not allow to call into a contract
 		// from its own constructor.
 		let frame = self.top_frame();
-		if let (CachedContract::Cached(contract), ExportedFunction::Call) =
-			(&frame.contract_info, frame.entry_point)
+		if let (
+			CachedContract::Cached(contract),
+			ExportedFunction::Call | ExportedFunction::Migrate,
+		) = (&frame.contract_info, frame.entry_point)
 		{
 			<ContractInfoOf<T>>::insert(frame.account_id.clone(), contract.clone());
 		}
@@ -905,6 +955,12 @@ where
 				)?;
 			}
 
+			// The code is only swapped just before the migration runs, so that both are rolled
+			// back together if the migration fails.
+			if entry_point == ExportedFunction::Migrate {
+				self.set_code_hash(*executable.code_hash())?;
+			}
+
 			// Every non delegate call or instantiate also optionally transfers the balance.
 			self.initial_transfer()?;
 
@@ -917,9 +973,10 @@ where
 					executable
 						.execute(self, &entry_point, input_data)
 						.map_err(|e| ExecError { error: e.error, origin: ErrorOrigin::Callee })
-				})?;
+				});
 
-			call_span.after_call(&output);
+			call_span.after_call_with_result(&output, top_frame!(self).nested_gas.gas_consumed());
+			let output = output?;
 
 			// Avoid useless work that would be reverted anyways.
 			if output.did_revert() {
@@ -980,6 +1037,10 @@ where
 						Event::Called { caller: caller.clone(), contract: account_id.clone() },
 					);
 				},
+				(ExportedFunction::Migrate, _) => {
+					// The upgrade is reported by the caller of the migration, which only ever
+					// runs in the root frame.
+				},
 			}
 
 			Ok(output)
@@ -1205,7 +1266,10 @@ where
 			// constructor: We disallow calling not fully constructed contracts.
 			let cached_info = self
 				.frames()
-				.find(|f| f.entry_point == ExportedFunction::Call && f.account_id == to)
+				.find(|f| {
+					matches!(f.entry_point, ExportedFunction::Call | ExportedFunction::Migrate) &&
+						f.account_id == to
+				})
 				.and_then(|f| match &f.contract_info {
 					CachedContract::Cached(contract) => Some(contract.clone()),
 					_ => None,
@@ -1289,6 +1353,9 @@ where
 		info.queue_trie_for_deletion();
 		ContractInfoOf::<T>::remove(&frame.account_id);
 		Self::decrement_refcount(info.code_hash);
+		if let Some(upgrade) = PendingCodeUpgradeOf::<T>::take(&frame.account_id) {
+			Self::decrement_refcount(upgrade.code_hash);
+		}
 
 		for (code_hash, deposit) in info.delegate_dependencies() {
 			Self::decrement_refcount(*code_hash);
@@ -1312,7 +1379,9 @@ where
 	}
 
 	fn get_storage(&mut self, key: &Key<T>) -> Option<Vec<u8>> {
-		self.top_frame_mut().contract_info().read(key)
+		let value = self.top_frame_mut().contract_info().read(key);
+		T::Debug::on_storage_read(&self.top_frame().account_id, &key.to_vec(), value.as_deref());
+		value
 	}
 
 	fn get_storage_size(&mut self, key: &Key<T>) -> Option<u32> {
@@ -1326,6 +1395,9 @@ where
 		take_old: bool,
 	) -> Result<WriteOutcome, DispatchError> {
 		let frame = self.top_frame_mut();
+		T::Debug::on_storage_write(&frame.account_id, &key.to_vec(), value.as_deref(), || {
+			frame.contract_info.get(&frame.account_id).read(key)
+		});
 		frame.contract_info.get(&frame.account_id).write(
 			key.into(),
 			value,
@@ -1395,6 +1467,7 @@ where
 	}
 
 	fn deposit_event(&mut self, topics: Vec<T::Hash>, data: Vec<u8>) {
+		T::Debug::on_event(&self.top_frame().account_id, &topics, &data);
 		Contracts::<Self::T>::deposit_event(
 			topics,
 			Event::ContractEmitted { contract: self.top_frame().account_id.clone(), data },
@@ -116,9 +116,12 @@ use crate::{
 use codec::{Codec, Decode, Encode, HasCompact, MaxEncodedLen};
 use environmental::*;
 use frame_support::{
-	dispatch::{GetDispatchInfo, Pays, PostDispatchInfo, RawOrigin, WithPostDispatchInfo},
+	dispatch::{
+		DispatchClass, GetDispatchInfo, Pays, PostDispatchInfo, RawOrigin, WithPostDispatchInfo,
+	},
 	ensure,
 	error::BadOrigin,
+	storage::{with_transaction, TransactionOutcome},
 	traits::{
 		fungible::{Inspect, Mutate, MutateHold},
 		ConstU32, Contains, Get, Randomness, Time,
@@ -134,10 +137,12 @@ use frame_system::{
 use scale_info::TypeInfo;
 use smallvec::Array;
 use sp_runtime::{
-	traits::{Convert, Dispatchable, Hash, Saturating, StaticLookup, Zero},
+	traits::{
+		AtLeast32BitUnsigned, Convert, Dispatchable, Hash, One, Saturating, StaticLookup, Zero,
+	},
 	DispatchError, RuntimeDebug,
 };
-use sp_std::{fmt::Debug, prelude::*};
+use sp_std::{cell::Cell, fmt::Debug, prelude::*};
 
 pub use crate::{
 	address::{AddressGenerator, DefaultAddressGenerator},
@@ -184,6 +189,12 @@ const SENTINEL: u32 = u32::MAX;
 /// Example: `RUST_LOG=runtime::contracts=debug my_code --dev`
 const LOG_TARGET: &str = "runtime::contracts";
 
+/// The limits estimated by [`Pallet::bare_estimate_call`] exceed the smallest ones the call
+/// succeeds with by at most a `1 / ESTIMATE_PRECISION` fraction of them.
+///
+/// This bounds the number of times the call is executed to find them.
+const ESTIMATE_PRECISION: u32 = 64;
+
 /// Wrapper around `PhantomData` to prevent it being filtered by `scale-info`.
 ///
 /// `scale-info` filters out `PhantomData` fields because usually we are only interested
@@ -351,6 +362,14 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxDelegateDependencies: Get<u32>;
 
+		/// The number of blocks after which a code upgrade scheduled by a contract with
+		/// [`Pallet::schedule_code_upgrade`] can be applied.
+		///
+		/// This gives the callers of the contract the time to notice the upgrade and react to it
+		/// before it takes effect.
+		#[pallet::constant]
+		type CodeUpgradeDelay: Get<BlockNumberFor<Self>>;
+
 		/// Make contract callable functions marked as `#[unstable]` available.
 		///
 		/// Contracts that use `#[unstable]` functions won't be able to be uploaded unless
@@ -875,6 +894,136 @@ pub mod pallet {
 				},
 			}
 		}
+
+		/// Schedule an upgrade of the code of the calling contract to `code_hash`.
+		///
+		/// This is meant to be dispatched by the contract itself through `seal_call_runtime`, which
+		/// requires [`Config::CallFilter`] to allow it. Once
+		/// [`Config::CodeUpgradeDelay`] has passed, anyone can apply the upgrade with
+		/// [`Self::apply_code_upgrade`], which runs the `migrate` function of the new code once
+		/// with `migrate_gas_limit` as its gas limit. Until then, the pending upgrade can be
+		/// queried with [`Pallet::pending_code_upgrade`].
+		///
+		/// `migrate_gas_limit` is bounded by [`Pallet::max_migrate_gas_limit`], as an upgrade
+		/// which can't be applied in an extrinsic would stay pending forever.
+		///
+		/// This replaces any upgrade the contract has pending, restarting the delay.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::schedule_code_upgrade())]
+		pub fn schedule_code_upgrade(
+			origin: OriginFor<T>,
+			code_hash: CodeHash<T>,
+			migrate_gas_limit: Weight,
+		) -> DispatchResult {
+			Migration::<T>::ensure_migrated()?;
+			let contract = ensure_signed(origin)?;
+			ensure!(<ContractInfoOf<T>>::contains_key(&contract), <Error<T>>::ContractNotFound);
+			let code_info = <CodeInfoOf<T>>::get(code_hash).ok_or(<Error<T>>::CodeNotFound)?;
+			ensure!(code_info.determinism() == Determinism::Enforced, <Error<T>>::Indeterministic);
+			ensure!(
+				Self::max_migrate_gas_limit().all_gte(migrate_gas_limit),
+				<Error<T>>::CodeUpgradeGasLimitTooHigh
+			);
+
+			// The code is protected from removal until the upgrade is applied or cancelled.
+			<ExecStack<T, WasmBlob<T>>>::increment_refcount(code_hash)?;
+			if let Some(replaced) = <PendingCodeUpgradeOf<T>>::take(&contract) {
+				<ExecStack<T, WasmBlob<T>>>::decrement_refcount(replaced.code_hash);
+			}
+			let due = <frame_system::Pallet<T>>::block_number()
+				.saturating_add(T::CodeUpgradeDelay::get());
+			<PendingCodeUpgradeOf<T>>::insert(
+				&contract,
+				CodeUpgrade { code_hash, migrate_gas_limit, due },
+			);
+			Self::deposit_event(
+				vec![T::Hashing::hash_of(&contract), code_hash],
+				Event::CodeUpgradeScheduled { contract, code_hash, due },
+			);
+			Ok(())
+		}
+
+		/// Cancel the code upgrade the calling contract has pending.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::cancel_code_upgrade())]
+		pub fn cancel_code_upgrade(origin: OriginFor<T>) -> DispatchResult {
+			Migration::<T>::ensure_migrated()?;
+			let contract = ensure_signed(origin)?;
+			let CodeUpgrade { code_hash, .. } = <PendingCodeUpgradeOf<T>>::take(&contract)
+				.ok_or(<Error<T>>::NoCodeUpgradePending)?;
+			<ExecStack<T, WasmBlob<T>>>::decrement_refcount(code_hash);
+			Self::deposit_event(
+				vec![T::Hashing::hash_of(&contract), code_hash],
+				Event::CodeUpgradeCancelled { contract, code_hash },
+			);
+			Ok(())
+		}
+
+		/// Apply the code upgrade `dest` has pending, once it is due.
+		///
+		/// The origin pays for the `migrate` function of the new code, which runs with the gas
+		/// limit the upgrade was scheduled with, so `gas_limit` must be at least that one. The
+		/// new code doesn't need to export `migrate`, in which case nothing is run.
+		///
+		/// If the migration fails or reverts, the code of the contract is left untouched and
+		/// [`Event::CodeUpgradeFailed`] is emitted. The upgrade is then kept pending, so that it
+		/// can be applied again or cancelled by the contract: as anyone can apply it with limits
+		/// of their choosing, a failure isn't a reason to give up on it. Failures which aren't
+		/// caused by the migration itself, like an insufficient `storage_deposit_limit`, fail this
+		/// dispatchable instead.
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::apply_code_upgrade().saturating_add(*gas_limit))]
+		pub fn apply_code_upgrade(
+			origin: OriginFor<T>,
+			dest: AccountIdLookupOf<T>,
+			gas_limit: Weight,
+			storage_deposit_limit: Option<<BalanceOf<T> as codec::HasCompact>::Type>,
+		) -> DispatchResultWithPostInfo {
+			Migration::<T>::ensure_migrated()?;
+			let origin = Origin::from_runtime_origin(origin)?;
+			let dest = T::Lookup::lookup(dest)?;
+			let CodeUpgrade { code_hash, migrate_gas_limit, due } =
+				<PendingCodeUpgradeOf<T>>::get(&dest).ok_or(<Error<T>>::NoCodeUpgradePending)?;
+			ensure!(
+				due <= <frame_system::Pallet<T>>::block_number(),
+				<Error<T>>::CodeUpgradeNotDue
+			);
+			ensure!(gas_limit.all_gte(migrate_gas_limit), <Error<T>>::CodeUpgradeGasLimitTooLow);
+			<PendingCodeUpgradeOf<T>>::remove(&dest);
+
+			let common = CommonInput {
+				origin,
+				value: Zero::zero(),
+				data: Vec::new(),
+				gas_limit: migrate_gas_limit,
+				storage_deposit_limit: storage_deposit_limit.map(Into::into),
+				debug_message: None,
+			};
+			let output = MigrateInput::<T> { dest: dest.clone(), code_hash }.run_guarded(common);
+			let result = match output.result {
+				Err(err @ ExecError { origin: ErrorOrigin::Caller, .. }) => Err(err),
+				Ok(retval) if !retval.did_revert() => {
+					<ExecStack<T, WasmBlob<T>>>::decrement_refcount(code_hash);
+					Ok(())
+				},
+				_ => {
+					// The upgrade was only taken out while migrating, so that the migration can't
+					// apply it again.
+					<PendingCodeUpgradeOf<T>>::insert(
+						&dest,
+						CodeUpgrade { code_hash, migrate_gas_limit, due },
+					);
+					Self::deposit_event(
+						vec![T::Hashing::hash_of(&dest), code_hash],
+						Event::CodeUpgradeFailed { contract: dest, code_hash },
+					);
+					Ok(())
+				},
+			};
+			output
+				.gas_meter
+				.into_dispatch_result(result, T::WeightInfo::apply_code_upgrade())
+		}
 	}
 
 	#[pallet::event]
@@ -962,6 +1111,38 @@ pub mod pallet {
 			to: T::AccountId,
 			amount: BalanceOf<T>,
 		},
+
+		/// A contract scheduled an upgrade of its code.
+		CodeUpgradeScheduled {
+			/// The contract whose code is to be upgraded.
+			contract: T::AccountId,
+			/// The code hash the contract is to be upgraded to.
+			code_hash: CodeHash<T>,
+			/// The block from which the upgrade can be applied.
+			due: BlockNumberFor<T>,
+		},
+
+		/// A contract cancelled the upgrade of its code.
+		CodeUpgradeCancelled {
+			/// The contract whose code was to be upgraded.
+			contract: T::AccountId,
+			/// The code hash the contract was to be upgraded to.
+			code_hash: CodeHash<T>,
+		},
+
+		/// The migration of a contract to new code failed, so its code was left untouched.
+		///
+		/// The upgrade is kept pending, to be applied again or cancelled.
+		///
+		/// # Note
+		///
+		/// A successful upgrade emits [`Event::ContractCodeUpdated`] instead.
+		CodeUpgradeFailed {
+			/// The contract whose code was to be upgraded.
+			contract: T::AccountId,
+			/// The code hash the contract was to be upgraded to.
+			code_hash: CodeHash<T>,
+		},
 	}
 
 	#[pallet::error]
@@ -1057,6 +1238,14 @@ pub mod pallet {
 		DelegateDependencyAlreadyExists,
 		/// Can not add a delegate dependency to the code hash of the contract itself.
 		CannotAddSelfAsDelegateDependency,
+		/// The contract has no code upgrade pending.
+		NoCodeUpgradePending,
+		/// The code upgrade can't be applied before its delay has passed.
+		CodeUpgradeNotDue,
+		/// The gas limit is lower than the one the migration of the code upgrade runs with.
+		CodeUpgradeGasLimitTooLow,
+		/// The gas limit is higher than any code upgrade can be scheduled with.
+		CodeUpgradeGasLimitTooHigh,
 	}
 
 	/// A reason for the pallet contracts placing a hold on funds.
@@ -1126,6 +1315,13 @@ pub mod pallet {
 	#[pallet::storage]
 	pub(crate) type MigrationInProgress<T: Config> =
 		StorageValue<_, migration::Cursor, OptionQuery>;
+
+	/// The code upgrades scheduled by contracts, which can be applied once they are due.
+	///
+	/// TWOX-NOTE: SAFE since `AccountId` is a secure hash.
+	#[pallet::storage]
+	pub(crate) type PendingCodeUpgradeOf<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, CodeUpgrade<CodeHash<T>, BlockNumberFor<T>>>;
 }
 
 /// The type of origins supported by the contracts pallet.
@@ -1185,6 +1381,12 @@ struct InstantiateInput<T: Config> {
 	salt: Vec<u8>,
 }
 
+/// Input specific to a contract code upgrade invocation.
+struct MigrateInput<T: Config> {
+	dest: T::AccountId,
+	code_hash: CodeHash<T>,
+}
+
 /// Determines whether events should be collected during execution.
 #[derive(
 	Copy, Clone, PartialEq, Eq, RuntimeDebug, Decode, Encode, MaxEncodedLen, scale_info::TypeInfo,
@@ -1241,7 +1443,8 @@ trait Invokable<T: Config>: Sized {
 	/// Downstream execution flow is branched by implementations of [`Invokable`] trait:
 	///
 	/// - [`InstantiateInput::run`] runs contract instantiation,
-	/// - [`CallInput::run`] runs contract call.
+	/// - [`CallInput::run`] runs contract call,
+	/// - [`MigrateInput::run`] runs contract code upgrade.
 	///
 	/// We enforce a re-entrancy guard here by initializing and checking a boolean flag through a
 	/// global reference.
@@ -1397,6 +1600,46 @@ impl<T: Config> Invokable<T> for InstantiateInput<T> {
 	}
 }
 
+impl<T: Config> Invokable<T> for MigrateInput<T> {
+	type Output = ExecReturnValue;
+
+	fn run(
+		self,
+		common: CommonInput<T>,
+		mut gas_meter: GasMeter<T>,
+	) -> InternalOutput<T, Self::Output> {
+		let mut storage_deposit = Default::default();
+		let try_exec = || {
+			let schedule = T::Schedule::get();
+			let MigrateInput { dest, code_hash } = self;
+			let CommonInput { origin, storage_deposit_limit, debug_message, .. } = common;
+			let executable = WasmBlob::from_storage(code_hash, &mut gas_meter)?;
+			let mut storage_meter =
+				StorageMeter::new(&origin, storage_deposit_limit, Zero::zero())?;
+			let result = ExecStack::<T, WasmBlob<T>>::run_migrate(
+				origin.account_id()?.clone(),
+				dest,
+				executable,
+				&mut gas_meter,
+				&mut storage_meter,
+				&schedule,
+				debug_message,
+			);
+
+			storage_deposit = storage_meter.try_into_deposit(&origin)?;
+			result
+		};
+		InternalOutput { result: try_exec(), gas_meter, storage_deposit }
+	}
+
+	fn ensure_origin(&self, origin: Origin<T>) -> Result<(), DispatchError> {
+		match origin {
+			Origin::Signed(_) => Ok(()),
+			Origin::Root => Err(DispatchError::RootNotAllowed),
+		}
+	}
+}
+
 macro_rules! ensure_no_migration_in_progress {
 	() => {
 		if Migration::<T>::in_progress() {
@@ -1469,6 +1712,177 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Perform a call to a specified contract while tracing the executions it leads to.
+	///
+	/// This function is similar to [`Self::bare_call`], with the additional human readable
+	/// debugging information, but also returns the trace of the call as recorded by
+	/// [`debug::CallTracer`].
+	///
+	/// # Note
+	///
+	/// The trace is only recorded if [`debug::CallTracer`] is the [`Config::Debug`] of the
+	/// runtime, or is used by it. Tracing reads the values overwritten by the contracts, which
+	/// isn't accounted for in their gas, so this must not be called on-chain.
+	pub fn bare_trace_call(
+		origin: T::AccountId,
+		dest: T::AccountId,
+		value: BalanceOf<T>,
+		gas_limit: Weight,
+		storage_deposit_limit: Option<BalanceOf<T>>,
+		data: Vec<u8>,
+		determinism: Determinism,
+	) -> ContractTraceResult<BalanceOf<T>, EventRecordOf<T>> {
+		let from = origin.encode();
+		let (result, mut traces) = debug::CallTracer::trace(|| {
+			Self::bare_call(
+				origin,
+				dest,
+				value,
+				gas_limit,
+				storage_deposit_limit,
+				data,
+				DebugInfo::UnsafeDebug,
+				CollectEvents::Skip,
+				determinism,
+			)
+		});
+		let trace = traces.pop().map(|trace| CallTrace { from, ..trace });
+		ContractTraceResult { result, trace }
+	}
+
+	/// Estimate the smallest limits a call to a specified contract succeeds with.
+	///
+	/// The `gas_required` returned by [`Self::bare_call`] isn't enough for contracts which
+	/// behave differently depending on the gas they are left with. Instead, this binary searches
+	/// the gas limit, and then the storage deposit limit, between what the call consumes and the
+	/// given limits, executing the call for each candidate and rolling back its changes.
+	///
+	/// A `storage_deposit_limit` of `None` stands for the limit [`Self::bare_call`] would apply.
+	///
+	/// # Note
+	///
+	/// The executions aren't accounted for, so this must not be called on-chain.
+	pub fn bare_estimate_call(
+		origin: T::AccountId,
+		dest: T::AccountId,
+		value: BalanceOf<T>,
+		gas_limit: Weight,
+		storage_deposit_limit: Option<BalanceOf<T>>,
+		data: Vec<u8>,
+		determinism: Determinism,
+	) -> ContractEstimateResult<BalanceOf<T>, EventRecordOf<T>> {
+		let iterations = Cell::new(0u32);
+		let dry_run = |gas_limit, storage_deposit_limit| {
+			iterations.set(iterations.get().saturating_add(1));
+			let result = with_transaction(|| -> TransactionOutcome<Result<_, DispatchError>> {
+				TransactionOutcome::Rollback(Ok(Self::bare_call(
+					origin.clone(),
+					dest.clone(),
+					value,
+					gas_limit,
+					storage_deposit_limit,
+					data.clone(),
+					DebugInfo::UnsafeDebug,
+					CollectEvents::Skip,
+					determinism,
+				)))
+			});
+			result.unwrap_or_else(|error| ContractExecResult {
+				result: Err(error),
+				gas_consumed: Weight::zero(),
+				gas_required: Weight::zero(),
+				storage_deposit: Default::default(),
+				debug_message: Vec::new(),
+				events: None,
+			})
+		};
+		let succeeded = |result: &ContractExecResult<BalanceOf<T>, EventRecordOf<T>>| {
+			result.result.as_ref().map_or(false, |output| !output.did_revert())
+		};
+
+		let first = dry_run(gas_limit, storage_deposit_limit);
+		if !succeeded(&first) {
+			return ContractEstimateResult { result: first, estimate: None, iterations: 1 }
+		}
+		let storage_deposit_limit = storage_deposit_limit.unwrap_or_else(|| {
+			StorageMeter::<T>::new(&Origin::from_account_id(origin.clone()), None, value)
+				.map_or(Zero::zero(), |meter| meter.limit())
+		});
+		let (consumed, required, deposit) =
+			(first.gas_consumed, first.gas_required, first.storage_deposit.charge_or_zero());
+
+		// The result of the latest successful execution, which is always the one with the limits
+		// estimated so far.
+		let mut best = first;
+		let mut try_limits = |gas_limit, storage_deposit_limit| {
+			let result = dry_run(gas_limit, Some(storage_deposit_limit));
+			let success = succeeded(&result);
+			if success {
+				best = result;
+			}
+			success
+		};
+		let ref_time = Self::search_min_limit(
+			consumed.ref_time(),
+			required.ref_time(),
+			gas_limit.ref_time(),
+			|r| try_limits(Weight::from_parts(r, gas_limit.proof_size()), storage_deposit_limit),
+		);
+		let proof_size = Self::search_min_limit(
+			consumed.proof_size(),
+			required.proof_size(),
+			gas_limit.proof_size(),
+			|p| try_limits(Weight::from_parts(ref_time, p), storage_deposit_limit),
+		);
+		let gas_limit = Weight::from_parts(ref_time, proof_size);
+		let storage_deposit_limit =
+			Self::search_min_limit(deposit, deposit, storage_deposit_limit, |d| {
+				try_limits(gas_limit, d)
+			});
+
+		ContractEstimateResult {
+			result: best,
+			estimate: Some(CallEstimate { gas_limit, storage_deposit_limit }),
+			iterations: iterations.get(),
+		}
+	}
+
+	/// Search the smallest limit within `lo..=hi` that `succeeds`, given that it succeeds with
+	/// `hi` and with any limit above one it succeeds with.
+	///
+	/// `lo` and then `hint` are tried first, since they are the most likely to succeed. The limit
+	/// is then searched to within [`ESTIMATE_PRECISION`] of it.
+	fn search_min_limit<N: AtLeast32BitUnsigned + Copy>(
+		lo: N,
+		hint: N,
+		mut hi: N,
+		mut succeeds: impl FnMut(N) -> bool,
+	) -> N {
+		if lo >= hi {
+			return hi
+		}
+		if succeeds(lo) {
+			return lo
+		}
+		let mut lo = lo.saturating_add(One::one());
+		if hint > lo && hint < hi {
+			if succeeds(hint) {
+				hi = hint;
+			} else {
+				lo = hint.saturating_add(One::one());
+			}
+		}
+		while lo < hi && hi - lo > hi / ESTIMATE_PRECISION.into() {
+			let mid = lo + (hi - lo) / 2u32.into();
+			if succeeds(mid) {
+				hi = mid;
+			} else {
+				lo = mid.saturating_add(One::one());
+			}
+		}
+		hi
+	}
+
 	/// Instantiate a new contract.
 	///
 	/// This function is similar to [`Self::instantiate`], but doesn't perform any address lookups
@@ -1603,6 +2017,22 @@ impl<T: Config> Pallet<T> {
 		Ok((module, deposit))
 	}
 
+	/// Query the code upgrade a specified contract has pending, if any.
+	pub fn pending_code_upgrade(
+		address: T::AccountId,
+	) -> Option<CodeUpgrade<CodeHash<T>, BlockNumberFor<T>>> {
+		<PendingCodeUpgradeOf<T>>::get(address)
+	}
+
+	/// The largest gas limit a code upgrade can be scheduled with, so that
+	/// [`Self::apply_code_upgrade`] fits in an extrinsic.
+	pub fn max_migrate_gas_limit() -> Weight {
+		let weights = <T as frame_system::Config>::BlockWeights::get();
+		let max_extrinsic =
+			weights.get(DispatchClass::Normal).max_extrinsic.unwrap_or(weights.max_block);
+		max_extrinsic.saturating_sub(T::WeightInfo::apply_code_upgrade())
+	}
+
 	/// Query storage of a specified contract under a specified key.
 	pub fn get_storage(address: T::AccountId, key: Vec<u8>) -> GetStorageResult {
 		if Migration::<T>::in_progress() {
@@ -1672,7 +2102,7 @@ impl<T: Config> Pallet<T> {
 
 sp_api::decl_runtime_apis! {
 	/// The API used to dry-run contract interactions.
-	#[api_version(2)]
+	#[api_version(3)]
 	pub trait ContractsApi<AccountId, Balance, BlockNumber, Hash, EventRecord> where
 		AccountId: Codec,
 		Balance: Codec,
@@ -1724,5 +2154,39 @@ sp_api::decl_runtime_apis! {
 			address: AccountId,
 			key: Vec<u8>,
 		) -> GetStorageResult;
+
+		/// Perform a call from a specified account to a given contract, tracing the executions it
+		/// leads to.
+		///
+		/// See [`crate::Pallet::bare_trace_call`].
+		#[api_version(3)]
+		fn trace_call(
+			origin: AccountId,
+			dest: AccountId,
+			value: Balance,
+			gas_limit: Option<Weight>,
+			storage_deposit_limit: Option<Balance>,
+			input_data: Vec<u8>,
+		) -> ContractTraceResult<Balance, EventRecord>;
+
+		/// Estimate the smallest limits a call from a specified account to a given contract
+		/// succeeds with, searching them up to the given ones.
+		///
+		/// See [`crate::Pallet::bare_estimate_call`].
+		#[api_version(3)]
+		fn estimate_call(
+			origin: AccountId,
+			dest: AccountId,
+			value: Balance,
+			gas_limit: Option<Weight>,
+			storage_deposit_limit: Option<Balance>,
+			input_data: Vec<u8>,
+		) -> ContractEstimateResult<Balance, EventRecord>;
+
+		/// Query the code upgrade a given contract has pending.
+		///
+		/// Returns `None` if the contract has no upgrade pending.
+		#[api_version(3)]
+		fn pending_code_upgrade(address: AccountId) -> Option<CodeUpgrade<Hash, BlockNumber>>;
 	}
 }
@@ -96,6 +96,51 @@ pub type CodeUploadResult<CodeHash, Balance> =
 /// Result type of a `get_storage` call.
 pub type GetStorageResult = Result<Option<Vec<u8>>, ContractAccessError>;
 
+/// Result type of a `bare_trace_call` call as well as `ContractsApi::trace_call`.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct ContractTraceResult<Balance, EventRecord> {
+	/// The result of the call, as returned by `bare_call`.
+	pub result: ContractExecResult<Balance, EventRecord>,
+	/// The trace of the call and of all the calls it made in turn.
+	///
+	/// `None` if no contract was executed, or if the runtime doesn't trace contract executions.
+	pub trace: Option<CallTrace>,
+}
+
+/// Result type of a `bare_estimate_call` call as well as `ContractsApi::estimate_call`.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct ContractEstimateResult<Balance, EventRecord> {
+	/// The result of the call made with the estimated limits, or with the given limits if the
+	/// call didn't succeed with those.
+	pub result: ContractExecResult<Balance, EventRecord>,
+	/// The smallest limits found for the call to succeed with.
+	///
+	/// `None` if the call didn't succeed with the given limits.
+	pub estimate: Option<CallEstimate<Balance>>,
+	/// How many times the call was executed to find the estimate.
+	pub iterations: u32,
+}
+
+/// The limits estimated for a call to succeed with.
+#[derive(Copy, Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct CallEstimate<Balance> {
+	/// The gas limit to call with.
+	pub gas_limit: Weight,
+	/// The storage deposit limit to call with.
+	pub storage_deposit_limit: Balance,
+}
+
+/// A code upgrade scheduled by a contract, as returned by `ContractsApi::pending_code_upgrade`.
+#[derive(Copy, Clone, Eq, PartialEq, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub struct CodeUpgrade<CodeHash, BlockNumber> {
+	/// The code hash the contract is upgraded to.
+	pub code_hash: CodeHash,
+	/// The gas limit the `migrate` function of the new code runs with.
+	pub migrate_gas_limit: Weight,
+	/// The block from which the upgrade can be applied.
+	pub due: BlockNumber,
+}
+
 /// The possible errors that can happen querying the storage of a contract.
 #[derive(Copy, Clone, Eq, PartialEq, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo)]
 pub enum ContractAccessError {
@@ -250,3 +295,143 @@ where
 		}
 	}
 }
+
+/// Whether a traced contract execution was a call, an instantiation or a migration.
+#[derive(Copy, Clone, Default, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "UPPERCASE"))]
+pub enum CallType {
+	/// A call to an existing contract.
+	#[default]
+	Call,
+	/// The instantiation of a new contract.
+	Create,
+	/// The migration of a contract to new code.
+	Migrate,
+}
+
+/// A contract execution and the executions it led to, as recorded by
+/// [`CallTracer`](crate::debug::CallTracer).
+///
+/// Accounts are SCALE encoded. When serialized, the layout follows the one of the `callTracer` of
+/// geth, with the events under `logs` and the storage accesses of the contract under `storage`.
+#[derive(Clone, Default, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct CallTrace {
+	/// Whether this was a call, an instantiation or a migration.
+	#[cfg_attr(feature = "std", serde(rename = "type"))]
+	pub call_type: CallType,
+	/// The account which made the call.
+	#[cfg_attr(feature = "std", serde(with = "sp_core::bytes"))]
+	pub from: Vec<u8>,
+	/// The account of the contract which was executed.
+	#[cfg_attr(feature = "std", serde(with = "sp_core::bytes"))]
+	pub to: Vec<u8>,
+	/// The input data of the execution.
+	#[cfg_attr(feature = "std", serde(with = "sp_core::bytes"))]
+	pub input: Vec<u8>,
+	/// The output data of the execution. Empty if it failed.
+	#[cfg_attr(feature = "std", serde(with = "sp_core::bytes"))]
+	pub output: Vec<u8>,
+	/// The gas consumed by the execution, including the one of the calls it made.
+	pub gas_used: Weight,
+	/// Whether the contract reverted its changes.
+	pub reverted: bool,
+	/// The error the execution failed with, if any.
+	pub error: Option<DispatchError>,
+	/// The events emitted by the contract, in order.
+	pub logs: Vec<EventTrace>,
+	/// The accesses of the contract to its storage, in order.
+	pub storage: Vec<StorageAccess>,
+	/// The executions this one led to, in order.
+	pub calls: Vec<CallTrace>,
+}
+
+/// An event emitted by a traced contract execution.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct EventTrace {
+	/// The SCALE encoded topics of the event.
+	#[cfg_attr(feature = "std", serde(with = "serde_hex::seq"))]
+	pub topics: Vec<Vec<u8>>,
+	/// The data of the event.
+	#[cfg_attr(feature = "std", serde(with = "sp_core::bytes"))]
+	pub data: Vec<u8>,
+}
+
+/// An access of a traced contract execution to the storage of the contract.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "std", serde(tag = "kind", rename_all = "camelCase"))]
+pub enum StorageAccess {
+	/// A value was read.
+	Read {
+		/// The key, as given by the contract.
+		#[cfg_attr(feature = "std", serde(with = "sp_core::bytes"))]
+		key: Vec<u8>,
+		/// The value read, `None` if there was none.
+		#[cfg_attr(feature = "std", serde(with = "serde_hex::option"))]
+		value: Option<Vec<u8>>,
+	},
+	/// A value was written.
+	Write {
+		/// The key, as given by the contract.
+		#[cfg_attr(feature = "std", serde(with = "sp_core::bytes"))]
+		key: Vec<u8>,
+		/// The value before the write, `None` if there was none.
+		#[cfg_attr(feature = "std", serde(with = "serde_hex::option"))]
+		previous: Option<Vec<u8>>,
+		/// The value written, `None` if the value was removed.
+		#[cfg_attr(feature = "std", serde(with = "serde_hex::option"))]
+		value: Option<Vec<u8>>,
+	},
+}
+
+/// Hex serialization of the byte containers of traces, which `sp_core::bytes` doesn't cover.
+#[cfg(feature = "std")]
+mod serde_hex {
+	pub mod option {
+		use serde::{Deserialize, Deserializer, Serializer};
+
+		pub fn serialize<S: Serializer>(
+			value: &Option<Vec<u8>>,
+			serializer: S,
+		) -> Result<S::Ok, S::Error> {
+			match value {
+				Some(bytes) => sp_core::bytes::serialize(bytes, serializer),
+				None => serializer.serialize_none(),
+			}
+		}
+
+		pub fn deserialize<'de, D: Deserializer<'de>>(
+			deserializer: D,
+		) -> Result<Option<Vec<u8>>, D::Error> {
+			Ok(Option::<sp_core::Bytes>::deserialize(deserializer)?.map(|bytes| bytes.0))
+		}
+	}
+
+	pub mod seq {
+		use serde::{ser::SerializeSeq, Deserialize, Deserializer, Serializer};
+
+		pub fn serialize<S: Serializer>(
+			value: &Vec<Vec<u8>>,
+			serializer: S,
+		) -> Result<S::Ok, S::Error> {
+			let mut seq = serializer.serialize_seq(Some(value.len()))?;
+			for bytes in value {
+				seq.serialize_element(&sp_core::bytes::to_hex(bytes, false))?;
+			}
+			seq.end()
+		}
+
+		pub fn deserialize<'de, D: Deserializer<'de>>(
+			deserializer: D,
+		) -> Result<Vec<Vec<u8>>, D::Error> {
+			Ok(Vec::<sp_core::Bytes>::deserialize(deserializer)?
+				.into_iter()
+				.map(|bytes| bytes.0)
+				.collect())
+		}
+	}
+}
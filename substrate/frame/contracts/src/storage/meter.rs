@@ -379,6 +379,11 @@ where
 		}
 	}
 
+	/// The limit the meter was created with, as checked against the origin.
+	pub fn limit(&self) -> BalanceOf<T> {
+		self.limit
+	}
+
 	/// The total amount of deposit that should change hands as result of the execution
 	/// that this meter was passed into. This will also perform all the charges accumulated
 	/// in the whole contract stack.
@@ -30,7 +30,7 @@ use crate::{
 	},
 	exec::{Frame, Key},
 	migration::codegen::LATEST_MIGRATION_VERSION,
-	primitives::CodeUploadReturnValue,
+	primitives::{CodeUpgrade, CodeUploadReturnValue, StorageDeposit},
 	storage::DeletionQueueManager,
 	tests::test_utils::{get_contract, get_contract_checked},
 	wasm::{Determinism, ReturnErrorCode as RuntimeReturnCode},
@@ -485,6 +485,7 @@ impl Config for Test {
 	type Migrations = crate::migration::codegen::BenchMigrations;
 	type CodeHashLockupDepositPercent = CodeHashLockupDepositPercent;
 	type MaxDelegateDependencies = MaxDelegateDependencies;
+	type CodeUpgradeDelay = ConstU64<10>;
 	type Debug = TestDebug;
 	type Environment = ();
 	type Xcm = ();
@@ -3098,6 +3099,95 @@ fn gas_estimation_call_runtime() {
 	});
 }
 
+#[test]
+fn gas_estimation_binary_search() {
+	let (caller_code, _caller_hash) = compile_module::<Test>("call").unwrap();
+	let (callee_code, _callee_hash) = compile_module::<Test>("store_call").unwrap();
+	ExtBuilder::default().existential_deposit(50).build().execute_with(|| {
+		let min_balance = Contracts::min_balance();
+		let _ = <Test as Config>::Currency::set_balance(&ALICE, 1000 * min_balance);
+
+		let deploy = |code, salt| {
+			Contracts::bare_instantiate(
+				ALICE,
+				min_balance * 100,
+				GAS_LIMIT,
+				None,
+				Code::Upload(code),
+				vec![],
+				salt,
+				DebugInfo::Skip,
+				CollectEvents::Skip,
+			)
+			.result
+			.unwrap()
+			.account_id
+		};
+		let addr_caller = deploy(caller_code, vec![0]);
+		let addr_callee = deploy(callee_code, vec![1]);
+
+		// The callee stores 100 bytes, which requires a storage deposit.
+		let input = (100u32, &addr_callee).encode();
+		let estimated = Contracts::bare_estimate_call(
+			ALICE,
+			addr_caller.clone(),
+			0,
+			GAS_LIMIT,
+			None,
+			input.clone(),
+			Determinism::Enforced,
+		);
+		let estimate = estimated.estimate.unwrap();
+		assert!(estimated.iterations > 1);
+		assert_ok!(&estimated.result.result);
+		assert_eq!(
+			estimated.result.storage_deposit,
+			StorageDeposit::Charge(estimate.storage_deposit_limit)
+		);
+		assert!(estimate.gas_limit.all_gte(estimated.result.gas_consumed));
+		assert!(estimate.gas_limit.all_lt(GAS_LIMIT));
+
+		// The estimation didn't change any state.
+		let mut key = [0u8; 32];
+		key[0] = 1;
+		assert_eq!(get_contract(&addr_callee).read(&Key::Fix(key)), None);
+
+		// The estimated limits are enough, but no less storage deposit is.
+		let call = |gas_limit, storage_deposit_limit| {
+			Contracts::bare_call(
+				ALICE,
+				addr_caller.clone(),
+				0,
+				gas_limit,
+				Some(storage_deposit_limit),
+				input.clone(),
+				DebugInfo::Skip,
+				CollectEvents::Skip,
+				Determinism::Enforced,
+			)
+			.result
+		};
+		assert_err!(
+			call(estimate.gas_limit, estimate.storage_deposit_limit - 1),
+			<Error<Test>>::StorageDepositLimitExhausted,
+		);
+		assert_ok!(call(estimate.gas_limit, estimate.storage_deposit_limit));
+
+		// Nothing is estimated when the call doesn't succeed with the given limits.
+		let estimated = Contracts::bare_estimate_call(
+			ALICE,
+			addr_caller,
+			0,
+			GAS_LIMIT,
+			Some(1),
+			input,
+			Determinism::Enforced,
+		);
+		assert_eq!(estimated.estimate, None);
+		assert_eq!(estimated.iterations, 1);
+	});
+}
+
 #[test]
 fn call_runtime_reentrancy_guarded() {
 	let (caller_code, _caller_hash) = compile_module::<Test>("call_runtime").unwrap();
@@ -4222,6 +4312,195 @@ fn set_code_extrinsic() {
 	});
 }
 
+#[test]
+fn code_upgrade_works() {
+	let (wasm, code_hash) = compile_module::<Test>("call_runtime").unwrap();
+	let (new_wasm, new_code_hash) = compile_module::<Test>("code_upgrade_migrate").unwrap();
+	let migrate_gas_limit = GAS_LIMIT / 2;
+
+	ExtBuilder::default().existential_deposit(50).build().execute_with(|| {
+		let min_balance = Contracts::min_balance();
+		let _ = <Test as Config>::Currency::set_balance(&ALICE, 1000 * min_balance);
+
+		let addr = Contracts::bare_instantiate(
+			ALICE,
+			min_balance * 100,
+			GAS_LIMIT,
+			None,
+			Code::Upload(wasm),
+			vec![],
+			vec![],
+			DebugInfo::Skip,
+			CollectEvents::Skip,
+		)
+		.result
+		.unwrap()
+		.account_id;
+		assert_ok!(Contracts::upload_code(
+			RuntimeOrigin::signed(ALICE),
+			new_wasm,
+			None,
+			Determinism::Enforced
+		));
+
+		let schedule = |code_hash| {
+			let call = RuntimeCall::Contracts(crate::Call::schedule_code_upgrade {
+				code_hash,
+				migrate_gas_limit,
+			});
+			let result = Contracts::bare_call(
+				ALICE,
+				addr.clone(),
+				0,
+				GAS_LIMIT,
+				None,
+				call.encode(),
+				DebugInfo::Skip,
+				CollectEvents::Skip,
+				Determinism::Enforced,
+			)
+			.result
+			.unwrap();
+			assert_return_code!(result, RuntimeReturnCode::Success);
+		};
+
+		// Upgrades which couldn't be applied in an extrinsic can't be scheduled.
+		assert_noop!(
+			Contracts::schedule_code_upgrade(
+				RuntimeOrigin::signed(addr.clone()),
+				new_code_hash,
+				Contracts::max_migrate_gas_limit().saturating_add(Weight::from_parts(1, 0)),
+			),
+			<Error<Test>>::CodeUpgradeGasLimitTooHigh,
+		);
+
+		// The contract schedules the upgrade itself.
+		initialize_block(2);
+		schedule(new_code_hash);
+		let due = 2 + <Test as Config>::CodeUpgradeDelay::get();
+		assert_eq!(
+			Contracts::pending_code_upgrade(addr.clone()),
+			Some(CodeUpgrade { code_hash: new_code_hash, migrate_gas_limit, due }),
+		);
+		assert_refcount!(&new_code_hash, 1);
+		assert!(System::events().contains(&EventRecord {
+			phase: Phase::Initialization,
+			event: RuntimeEvent::Contracts(crate::Event::CodeUpgradeScheduled {
+				contract: addr.clone(),
+				code_hash: new_code_hash,
+				due,
+			}),
+			topics: vec![hash(&addr), new_code_hash],
+		}));
+
+		// The upgrade can't be applied before it is due, or with less gas than the migration.
+		assert_noop!(
+			Contracts::apply_code_upgrade(
+				RuntimeOrigin::signed(ALICE),
+				addr.clone(),
+				GAS_LIMIT,
+				None
+			),
+			<Error<Test>>::CodeUpgradeNotDue,
+		);
+		initialize_block(due);
+		assert_noop!(
+			Contracts::apply_code_upgrade(
+				RuntimeOrigin::signed(ALICE),
+				addr.clone(),
+				migrate_gas_limit / 2,
+				None
+			),
+			<Error<Test>>::CodeUpgradeGasLimitTooLow,
+		);
+
+		// Applying it runs the migration, which stores the previous code hash.
+		assert_ok!(Contracts::apply_code_upgrade(
+			RuntimeOrigin::signed(ALICE),
+			addr.clone(),
+			GAS_LIMIT,
+			None
+		));
+		assert_eq!(get_contract(&addr).code_hash, new_code_hash);
+		assert_eq!(
+			Contracts::get_storage(addr.clone(), vec![1u8; 32]),
+			Ok(Some(code_hash.encode())),
+		);
+		assert_eq!(Contracts::pending_code_upgrade(addr.clone()), None);
+		assert_refcount!(&code_hash, 0);
+		assert_refcount!(&new_code_hash, 1);
+		assert!(System::events().contains(&EventRecord {
+			phase: Phase::Initialization,
+			event: RuntimeEvent::Contracts(crate::Event::ContractCodeUpdated {
+				contract: addr.clone(),
+				new_code_hash,
+				old_code_hash: code_hash,
+			}),
+			topics: vec![hash(&addr), new_code_hash, code_hash],
+		}));
+
+		// The migration only runs once.
+		assert_noop!(
+			Contracts::apply_code_upgrade(
+				RuntimeOrigin::signed(ALICE),
+				addr.clone(),
+				GAS_LIMIT,
+				None
+			),
+			<Error<Test>>::NoCodeUpgradePending,
+		);
+
+		// A migration which reverts leaves the contract untouched and keeps the upgrade pending.
+		// The migration of the new code reverts when it was run before.
+		schedule(new_code_hash);
+		assert_refcount!(&new_code_hash, 2);
+		let due = due + <Test as Config>::CodeUpgradeDelay::get();
+		initialize_block(due);
+		assert_ok!(Contracts::apply_code_upgrade(
+			RuntimeOrigin::signed(ALICE),
+			addr.clone(),
+			GAS_LIMIT,
+			None
+		));
+		assert_eq!(get_contract(&addr).code_hash, new_code_hash);
+		assert_eq!(
+			Contracts::pending_code_upgrade(addr.clone()),
+			Some(CodeUpgrade { code_hash: new_code_hash, migrate_gas_limit, due }),
+		);
+		assert_refcount!(&new_code_hash, 2);
+		assert_eq!(
+			System::events(),
+			vec![EventRecord {
+				phase: Phase::Initialization,
+				event: RuntimeEvent::Contracts(crate::Event::CodeUpgradeFailed {
+					contract: addr.clone(),
+					code_hash: new_code_hash,
+				}),
+				topics: vec![hash(&addr), new_code_hash],
+			}],
+		);
+
+		// The contract can still cancel it.
+		let call = RuntimeCall::Contracts(crate::Call::cancel_code_upgrade {});
+		let result = Contracts::bare_call(
+			ALICE,
+			addr.clone(),
+			0,
+			GAS_LIMIT,
+			None,
+			call.encode(),
+			DebugInfo::Skip,
+			CollectEvents::Skip,
+			Determinism::Enforced,
+		)
+		.result
+		.unwrap();
+		assert_return_code!(result, RuntimeReturnCode::Success);
+		assert_eq!(Contracts::pending_code_upgrade(addr.clone()), None);
+		assert_refcount!(&new_code_hash, 1);
+	});
+}
+
 #[test]
 fn slash_cannot_kill_account() {
 	let (wasm, _code_hash) = compile_module::<Test>("dummy").unwrap();
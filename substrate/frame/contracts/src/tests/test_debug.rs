@@ -17,8 +17,11 @@
 
 use super::*;
 use crate::{
-	debug::{CallInterceptor, CallSpan, ExecResult, ExportedFunction, Tracing},
-	primitives::ExecReturnValue,
+	debug::{
+		CallInterceptor, CallSpan, CallTracer, CallTracerSpan, ExecResult, ExportedFunction,
+		Tracing,
+	},
+	primitives::{CallTrace, CallType, ExecReturnValue, StorageAccess},
 	AccountIdOf,
 };
 use frame_support::traits::Currency;
@@ -38,11 +41,13 @@ thread_local! {
 	static INTERCEPTED_ADDRESS: RefCell<Option<AccountId32>> = RefCell::new(None);
 }
 
+/// Records the executions as [`DebugFrame`]s, and forwards them to [`CallTracer`].
 pub struct TestDebug;
 pub struct TestCallSpan {
 	contract_account: AccountId32,
 	call: ExportedFunction,
 	input: Vec<u8>,
+	tracer_span: CallTracerSpan,
 }
 
 impl Tracing<Test> for TestDebug {
@@ -65,8 +70,30 @@ impl Tracing<Test> for TestDebug {
 			contract_account: contract_account.clone(),
 			call: entry_point,
 			input: input_data.to_vec(),
+			tracer_span: <CallTracer as Tracing<Test>>::new_call_span(
+				contract_account,
+				entry_point,
+				input_data,
+			),
 		}
 	}
+
+	fn on_storage_read(contract_account: &AccountIdOf<Test>, key: &[u8], value: Option<&[u8]>) {
+		<CallTracer as Tracing<Test>>::on_storage_read(contract_account, key, value)
+	}
+
+	fn on_storage_write(
+		contract_account: &AccountIdOf<Test>,
+		key: &[u8],
+		value: Option<&[u8]>,
+		previous: impl FnOnce() -> Option<Vec<u8>>,
+	) {
+		<CallTracer as Tracing<Test>>::on_storage_write(contract_account, key, value, previous)
+	}
+
+	fn on_event(contract_account: &AccountIdOf<Test>, topics: &[H256], data: &[u8]) {
+		<CallTracer as Tracing<Test>>::on_event(contract_account, topics, data)
+	}
 }
 
 impl CallInterceptor<Test> for TestDebug {
@@ -87,14 +114,21 @@ impl CallInterceptor<Test> for TestDebug {
 
 impl CallSpan for TestCallSpan {
 	fn after_call(self, output: &ExecReturnValue) {
-		DEBUG_EXECUTION_TRACE.with(|d| {
-			d.borrow_mut().push(DebugFrame {
-				contract_account: self.contract_account,
-				call: self.call,
-				input: self.input,
-				result: Some(output.data.clone()),
-			})
-		});
+		self.after_call_with_result(&Ok(output.clone()), Weight::zero())
+	}
+
+	fn after_call_with_result(self, result: &ExecResult, gas_consumed: Weight) {
+		self.tracer_span.after_call_with_result(result, gas_consumed);
+		if let Ok(output) = result {
+			DEBUG_EXECUTION_TRACE.with(|d| {
+				d.borrow_mut().push(DebugFrame {
+					contract_account: self.contract_account,
+					call: self.call,
+					input: self.input,
+					result: Some(output.data.clone()),
+				})
+			});
+		}
 	}
 }
 
@@ -227,3 +261,81 @@ fn call_interception_works() {
 		);
 	});
 }
+
+#[test]
+fn call_tracing_works() {
+	let (wasm_caller, _) = compile_module::<Test>("call").unwrap();
+	let (wasm_callee, _) = compile_module::<Test>("store_call").unwrap();
+
+	ExtBuilder::default().existential_deposit(200).build().execute_with(|| {
+		let _ = Balances::deposit_creating(&ALICE, 1_000_000);
+
+		let deploy = |wasm, salt| {
+			Contracts::bare_instantiate(
+				ALICE,
+				0,
+				GAS_LIMIT,
+				None,
+				Code::Upload(wasm),
+				vec![],
+				salt,
+				DebugInfo::Skip,
+				CollectEvents::Skip,
+			)
+			.result
+			.unwrap()
+			.account_id
+		};
+		let addr_caller = deploy(wasm_caller, vec![0x42]);
+		let addr_callee = deploy(wasm_callee, vec![0x42]);
+
+		// nothing is recorded outside of a trace
+		let (_, traces) = CallTracer::trace(|| ());
+		assert_eq!(traces, vec![]);
+
+		let main_args = (100u32, &addr_callee).encode();
+		let inner_args = 100u32.encode();
+		let traced = Contracts::bare_trace_call(
+			ALICE,
+			addr_caller.clone(),
+			0,
+			GAS_LIMIT,
+			None,
+			main_args.clone(),
+			Determinism::Enforced,
+		);
+		assert_ok!(&traced.result.result);
+		let trace = traced.trace.unwrap();
+
+		let mut key = vec![0u8; 32];
+		key[0] = 1;
+		let inner = CallTrace {
+			call_type: CallType::Call,
+			from: addr_caller.encode(),
+			to: addr_callee.encode(),
+			input: inner_args,
+			storage: vec![StorageAccess::Write {
+				key,
+				previous: None,
+				value: Some(vec![0u8; 100]),
+			}],
+			gas_used: trace.calls[0].gas_used,
+			..Default::default()
+		};
+		assert_eq!(
+			trace,
+			CallTrace {
+				call_type: CallType::Call,
+				from: ALICE.encode(),
+				to: addr_caller.encode(),
+				input: main_args,
+				gas_used: trace.gas_used,
+				calls: vec![inner],
+				..Default::default()
+			}
+		);
+		assert!(trace.calls[0].gas_used.any_gt(Weight::zero()));
+		assert!(trace.gas_used.all_gte(trace.calls[0].gas_used));
+		assert!(traced.result.gas_consumed.all_gte(trace.gas_used));
+	});
+}
@@ -125,6 +125,7 @@ impl ExportedFunction {
 		match self {
 			Self::Constructor => "deploy",
 			Self::Call => "call",
+			Self::Migrate => "migrate",
 		}
 	}
 }
@@ -327,6 +328,11 @@ impl<T: Config> CodeInfo<T> {
 	pub fn deposit(&self) -> BalanceOf<T> {
 		self.deposit
 	}
+
+	/// Returns the determinism of the module.
+	pub fn determinism(&self) -> Determinism {
+		self.determinism
+	}
 }
 
 impl<T: Config> Executable<T> for WasmBlob<T> {
@@ -358,7 +364,8 @@ impl<T: Config> Executable<T> for WasmBlob<T> {
 			StackLimits::default(),
 			match function {
 				ExportedFunction::Call => AllowDeprecatedInterface::Yes,
-				ExportedFunction::Constructor => AllowDeprecatedInterface::No,
+				ExportedFunction::Constructor | ExportedFunction::Migrate =>
+					AllowDeprecatedInterface::No,
 			},
 		)
 		.map_err(|msg| {
@@ -400,13 +407,17 @@ impl<T: Config> Executable<T> for WasmBlob<T> {
 		// from the whole contract execution.
 		match instance.start(&mut store) {
 			Ok(instance) => {
-				let exported_func = instance
+				let Some(exported_func) = instance
 					.get_export(&store, function.identifier())
 					.and_then(|export| export.into_func())
-					.ok_or_else(|| {
-						log::error!(target: LOG_TARGET, "failed to find entry point");
-						Error::<T>::CodeRejected
-					})?;
+				else {
+					// Code which doesn't export `migrate` needs no migration.
+					if let &ExportedFunction::Migrate = function {
+						return process_result(store, Ok(()))
+					}
+					log::error!(target: LOG_TARGET, "failed to find entry point");
+					return Err(Error::<T>::CodeRejected.into())
+				};
 
 				let result = exported_func.call(&mut store, &[], &mut []);
 				process_result(store, result)
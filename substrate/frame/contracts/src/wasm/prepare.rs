@@ -91,6 +91,7 @@ impl LoadedModule {
 	///
 	/// - 'call'
 	/// - 'deploy'
+	/// - 'migrate', which is optional
 	///
 	/// Any other exports are not allowed.
 	fn scan_exports(&self) -> Result<(), &'static str> {
@@ -105,13 +106,14 @@ impl LoadedModule {
 					match export.name() {
 						"call" => call_found = true,
 						"deploy" => deploy_found = true,
+						"migrate" => (),
 						_ =>
 							return Err(
-								"unknown function export: expecting only deploy and call functions",
+								"unknown function export: expecting only deploy, call and migrate functions",
 							),
 					}
 					// Check the signature.
-					// Both "call" and "deploy" have the () -> () function type.
+					// All of "call", "deploy" and "migrate" have the () -> () function type.
 					// We still support () -> (i32) for backwards compatibility.
 					if !(ft.params().is_empty() &&
 						(ft.results().is_empty() || ft.results() == [WasmiValueType::I32]))
@@ -769,7 +771,19 @@ mod tests {
 				(func (export "whatevs"))
 			)
 			"#,
-			Err("unknown function export: expecting only deploy and call functions")
+			Err("unknown function export: expecting only deploy, call and migrate functions")
+		);
+
+		prepare_test!(
+			migrate_export,
+			r#"
+			(module
+				(func (export "call"))
+				(func (export "deploy"))
+				(func (export "migrate"))
+			)
+			"#,
+			Ok(_)
 		);
 
 		prepare_test!(
@@ -244,6 +244,80 @@ benchmarks_instance_pallet! {
 		assert_matches!(VotingFor::<T, I>::get(&caller, &class), Voting::Casting(_));
 	}
 
+	delegate_partial {
+		let r in 0 .. T::MaxVotes::get().min(T::Polls::max_ongoing().1);
+		let s in 0 .. T::MaxVotes::get().min(T::Polls::max_ongoing().1);
+
+		let all_polls = fill_voting::<T, I>().1;
+		let class = T::Polls::max_ongoing().0;
+		let polls = &all_polls[&class];
+		let voter = funded_account::<T, I>("voter", 0);
+		let voter_lookup = T::Lookup::unlookup(voter.clone());
+		let caller = funded_account::<T, I>("caller", 0);
+		whitelist_account!(caller);
+
+		let delegated_balance: BalanceOf<T, I> = 1000u32.into();
+		let delegate_vote = account_vote::<T, I>(delegated_balance);
+
+		// We need to create existing delegations
+		for i in polls.iter().take(r as usize) {
+			ConvictionVoting::<T, I>::vote(RawOrigin::Signed(voter.clone()).into(), *i, delegate_vote)?;
+		}
+		assert_matches!(
+			VotingFor::<T, I>::get(&voter, &class),
+			Voting::Casting(Casting { votes, .. }) if votes.len() == r as usize
+		);
+
+		// Our own votes on the same polls, from which the delegation must be retracted.
+		for i in polls.iter().take(s as usize) {
+			ConvictionVoting::<T, I>::vote(RawOrigin::Signed(caller.clone()).into(), *i, delegate_vote)?;
+		}
+	}: _(RawOrigin::Signed(caller.clone()), class.clone(), voter_lookup, Conviction::Locked1x, delegated_balance)
+	verify {
+		assert_eq!(PartialDelegationsFor::<T, I>::get(&caller, &class).len(), 1);
+	}
+
+	undelegate_partial {
+		let r in 0 .. T::MaxVotes::get().min(T::Polls::max_ongoing().1);
+		let s in 0 .. T::MaxVotes::get().min(T::Polls::max_ongoing().1);
+
+		let all_polls = fill_voting::<T, I>().1;
+		let class = T::Polls::max_ongoing().0;
+		let polls = &all_polls[&class];
+		let voter = funded_account::<T, I>("voter", 0);
+		let voter_lookup = T::Lookup::unlookup(voter.clone());
+		let caller = funded_account::<T, I>("caller", 0);
+		whitelist_account!(caller);
+
+		let delegated_balance: BalanceOf<T, I> = 1000u32.into();
+		let delegate_vote = account_vote::<T, I>(delegated_balance);
+
+		ConvictionVoting::<T, I>::delegate_partial(
+			RawOrigin::Signed(caller.clone()).into(),
+			class.clone(),
+			voter_lookup.clone(),
+			Conviction::Locked1x,
+			delegated_balance,
+		)?;
+
+		// We need to create delegations
+		for i in polls.iter().take(r as usize) {
+			ConvictionVoting::<T, I>::vote(RawOrigin::Signed(voter.clone()).into(), *i, delegate_vote)?;
+		}
+		assert_matches!(
+			VotingFor::<T, I>::get(&voter, &class),
+			Voting::Casting(Casting { votes, .. }) if votes.len() == r as usize
+		);
+		// Our own votes on the same polls, on which the delegation must be reinstated.
+		for i in polls.iter().take(s as usize) {
+			ConvictionVoting::<T, I>::vote(RawOrigin::Signed(caller.clone()).into(), *i, delegate_vote)?;
+		}
+		assert_eq!(PartialDelegationsFor::<T, I>::get(&caller, &class).len(), 1);
+	}: _(RawOrigin::Signed(caller.clone()), class.clone(), voter_lookup)
+	verify {
+		assert!(PartialDelegationsFor::<T, I>::get(&caller, &class).is_empty());
+	}
+
 	unlock {
 		let caller = funded_account::<T, I>("caller", 0);
 		let caller_lookup = T::Lookup::unlookup(caller.clone());
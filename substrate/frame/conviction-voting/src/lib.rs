@@ -23,6 +23,12 @@
 //! ## Overview
 //!
 //! Pallet for managing actual voting in polls.
+//!
+//! An account may either vote directly or delegate all of its voting power for a class of polls
+//! to a single target with [`Call::delegate`]. Alternatively, it may keep voting directly while
+//! delegating parts of its balance to several targets with [`Call::delegate_partial`]. Such an
+//! account overrides its partial delegations on any poll it votes on directly: for that poll, its
+//! delegated voting power is retracted from the votes of its targets.
 
 #![recursion_limit = "256"]
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -32,8 +38,9 @@ use frame_support::{
 	ensure,
 	traits::{
 		fungible, Currency, Get, LockIdentifier, LockableCurrency, PollStatus, Polling,
-		ReservableCurrency, WithdrawReasons,
+		ReservableCurrency, StorageInfo, StorageInfoTrait, WithdrawReasons,
 	},
+	weights::Weight,
 };
 use frame_system::pallet_prelude::BlockNumberFor;
 use sp_runtime::{
@@ -51,7 +58,7 @@ pub use self::{
 	conviction::Conviction,
 	pallet::*,
 	types::{Delegations, Tally, UnvoteScope},
-	vote::{AccountVote, Casting, Delegating, Vote, Voting},
+	vote::{AccountVote, Casting, Delegating, PartialDelegation, Vote, Voting},
 	weights::WeightInfo,
 };
 
@@ -76,6 +83,8 @@ type VotingOf<T, I = ()> = Voting<
 #[allow(dead_code)]
 type DelegatingOf<T, I = ()> =
 	Delegating<BalanceOf<T, I>, <T as frame_system::Config>::AccountId, BlockNumberFor<T>>;
+type PartialDelegationOf<T, I = ()> =
+	PartialDelegation<<T as frame_system::Config>::AccountId, BalanceOf<T, I>>;
 pub type TallyOf<T, I = ()> = Tally<BalanceOf<T, I>, <T as Config<I>>::MaxTurnout>;
 pub type VotesOf<T, I = ()> = BalanceOf<T, I>;
 type PollIndexOf<T, I = ()> = <<T as Config<I>>::Polls as Polling<TallyOf<T, I>>>::Index;
@@ -88,7 +97,8 @@ pub mod pallet {
 	use super::*;
 	use frame_support::{
 		pallet_prelude::{
-			DispatchResultWithPostInfo, IsType, StorageDoubleMap, StorageMap, ValueQuery,
+			DispatchResultWithPostInfo, IsType, OptionQuery, StorageDoubleMap, StorageMap,
+			ValueQuery,
 		},
 		traits::ClassCountOf,
 		Twox64Concat,
@@ -136,6 +146,13 @@ pub mod pallet {
 		/// those successful voters are locked into the consequences that their votes entail.
 		#[pallet::constant]
 		type VoteLockingPeriod: Get<BlockNumberFor<Self>>;
+
+		/// The maximum number of partial delegations an account may have for a class of polls.
+		///
+		/// Also used to compute weight: voting and removing a vote are charged for retracting or
+		/// reinstating this many delegations.
+		#[pallet::constant]
+		type MaxDelegations: Get<u32>;
 	}
 
 	/// All voting for a particular voter in a particular voting class. We store the balance for the
@@ -163,6 +180,33 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// The partial delegations of a voter in a particular voting class. The voter continues to
+	/// cast votes, which override these delegations on the polls voted on.
+	#[pallet::storage]
+	pub type PartialDelegationsFor<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		ClassOf<T, I>,
+		BoundedVec<PartialDelegationOf<T, I>, T::MaxDelegations>,
+		ValueQuery,
+	>;
+
+	/// The delegations received by an account which are retracted from a particular poll, because
+	/// the delegators vote on it directly. These do not count towards the account's vote on the
+	/// poll.
+	#[pallet::storage]
+	pub type RetractedDelegations<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		PollIndexOf<T, I>,
+		Delegations<BalanceOf<T, I>>,
+		OptionQuery,
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config<I>, I: 'static = ()> {
@@ -170,6 +214,8 @@ pub mod pallet {
 		Delegated(T::AccountId, T::AccountId),
 		/// An \[account\] has cancelled a previous delegation operation.
 		Undelegated(T::AccountId),
+		/// An account has cancelled a partial delegation to another account. \[who, target\]
+		PartiallyUndelegated(T::AccountId, T::AccountId),
 	}
 
 	#[pallet::error]
@@ -199,6 +245,8 @@ pub mod pallet {
 		ClassNeeded,
 		/// The class ID supplied is invalid.
 		BadClass,
+		/// Maximum number of partial delegations reached.
+		MaxDelegationsReached,
 	}
 
 	#[pallet::call]
@@ -213,7 +261,11 @@ pub mod pallet {
 		///
 		/// Weight: `O(R)` where R is the number of polls the voter has voted on.
 		#[pallet::call_index(0)]
-		#[pallet::weight(T::WeightInfo::vote_new().max(T::WeightInfo::vote_existing()))]
+		#[pallet::weight(
+			T::WeightInfo::vote_new()
+				.max(T::WeightInfo::vote_existing())
+				.saturating_add(Pallet::<T, I>::retraction_weight())
+		)]
 		pub fn vote(
 			origin: OriginFor<T>,
 			#[pallet::compact] poll_index: PollIndexOf<T, I>,
@@ -229,10 +281,9 @@ pub mod pallet {
 		/// The balance delegated is locked for as long as it's delegated, and thereafter for the
 		/// time appropriate for the conviction's lock period.
 		///
-		/// The dispatch origin of this call must be _Signed_, and the signing account must either:
-		///   - be delegating already; or
-		///   - have no voting activity (if there is, then it will need to be removed/consolidated
-		///     through `reap_vote` or `unvote`).
+		/// The dispatch origin of this call must be _Signed_, and the signing account must have no
+		/// voting activity (if there is, then it will need to be removed/consolidated through
+		/// `reap_vote` or `unvote`) nor partial delegations in this class.
 		///
 		/// - `to`: The account whose voting the `target` account's voting power will follow.
 		/// - `class`: The class of polls to delegate. To delegate multiple classes, multiple calls
@@ -343,7 +394,9 @@ pub mod pallet {
 		/// Weight: `O(R + log R)` where R is the number of polls that `target` has voted on.
 		///   Weight is calculated for the maximum number of vote.
 		#[pallet::call_index(4)]
-		#[pallet::weight(T::WeightInfo::remove_vote())]
+		#[pallet::weight(
+			T::WeightInfo::remove_vote().saturating_add(Pallet::<T, I>::retraction_weight())
+		)]
 		pub fn remove_vote(
 			origin: OriginFor<T>,
 			class: Option<ClassOf<T, I>>,
@@ -370,7 +423,9 @@ pub mod pallet {
 		/// Weight: `O(R + log R)` where R is the number of polls that `target` has voted on.
 		///   Weight is calculated for the maximum number of vote.
 		#[pallet::call_index(5)]
-		#[pallet::weight(T::WeightInfo::remove_other_vote())]
+		#[pallet::weight(
+			T::WeightInfo::remove_other_vote().saturating_add(Pallet::<T, I>::retraction_weight())
+		)]
 		pub fn remove_other_vote(
 			origin: OriginFor<T>,
 			target: AccountIdLookupOf<T>,
@@ -383,6 +438,83 @@ pub mod pallet {
 			Self::try_remove_vote(&target, index, Some(class), scope)?;
 			Ok(())
 		}
+
+		/// Delegate some of the voting power (with some given conviction) of the sending account
+		/// for a particular class of polls, while it keeps voting directly.
+		///
+		/// An account may delegate different parts of its balance to different targets in the same
+		/// class. On any poll the account votes on directly, its vote overrides these delegations:
+		/// the delegated voting power is retracted from the votes of the targets on that poll until
+		/// the direct vote is removed.
+		///
+		/// The balance delegated is locked for as long as it's delegated, and thereafter for the
+		/// time appropriate for the conviction's lock period.
+		///
+		/// The dispatch origin of this call must be _Signed_, and the signing account must not be
+		/// delegating all of its voting power through `delegate`.
+		///
+		/// - `class`: The class of polls to delegate.
+		/// - `to`: The account whose voting the delegated voting power will follow. The sending
+		///   account must not already be delegating to it in this class.
+		/// - `conviction`: The conviction that will be attached to the delegated votes. When the
+		///   delegation is removed, the funds will be locked for the corresponding period.
+		/// - `balance`: The amount of the account's balance to be delegated. Together with the
+		///   account's other partial delegations in this class, this must not be more than the
+		///   account's current balance.
+		///
+		/// Emits `Delegated`.
+		///
+		/// Weight: `O(R + S)` where R is the number of polls the voter delegating to has voted on
+		///   and S the number of polls the sending account has voted on. Weight is initially
+		///   charged as if maximum votes, but is refunded later.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::delegate_partial(T::MaxVotes::get(), T::MaxVotes::get()))]
+		pub fn delegate_partial(
+			origin: OriginFor<T>,
+			class: ClassOf<T, I>,
+			to: AccountIdLookupOf<T>,
+			conviction: Conviction,
+			balance: BalanceOf<T, I>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let to = T::Lookup::lookup(to)?;
+			let (votes, own_votes) =
+				Self::try_delegate_partial(who, class, to, conviction, balance)?;
+
+			Ok(Some(T::WeightInfo::delegate_partial(votes, own_votes)).into())
+		}
+
+		/// Remove a partial delegation of the sending account for a particular class of polls.
+		///
+		/// Tokens may be unlocked following once an amount of time consistent with the lock period
+		/// of the conviction with which the delegation was issued has passed.
+		///
+		/// The dispatch origin of this call must be _Signed_ and the signing account must be
+		/// partially delegating to `target` in `class`.
+		///
+		/// - `class`: The class of polls to remove the delegation from.
+		/// - `target`: The account to which the delegation was made.
+		///
+		/// Emits `PartiallyUndelegated`.
+		///
+		/// Weight: `O(R + S)` where R is the number of polls the voter delegating to has voted on
+		///   and S the number of polls the sending account has voted on. Weight is initially
+		///   charged as if maximum votes, but is refunded later.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::undelegate_partial(
+			T::MaxVotes::get(),
+			T::MaxVotes::get()
+		))]
+		pub fn undelegate_partial(
+			origin: OriginFor<T>,
+			class: ClassOf<T, I>,
+			target: AccountIdLookupOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let target = T::Lookup::lookup(target)?;
+			let (votes, own_votes) = Self::try_undelegate_partial(who, class, target)?;
+			Ok(Some(T::WeightInfo::undelegate_partial(votes, own_votes)).into())
+		}
 	}
 }
 
@@ -401,12 +533,13 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			let (tally, class) = poll_status.ensure_ongoing().ok_or(Error::<T, I>::NotOngoing)?;
 			VotingFor::<T, I>::try_mutate(who, &class, |voting| {
 				if let Voting::Casting(Casting { ref mut votes, delegations, .. }) = voting {
+					let delegations = Self::effective_delegations(who, poll_index, *delegations);
 					match votes.binary_search_by_key(&poll_index, |i| i.0) {
 						Ok(i) => {
 							// Shouldn't be possible to fail, but we handle it gracefully.
 							tally.remove(votes[i].1).ok_or(ArithmeticError::Underflow)?;
 							if let Some(approve) = votes[i].1.as_standard() {
-								tally.reduce(approve, *delegations);
+								tally.reduce(approve, delegations);
 							}
 							votes[i].1 = vote;
 						},
@@ -414,12 +547,21 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 							votes
 								.try_insert(i, (poll_index, vote))
 								.map_err(|_| Error::<T, I>::MaxVotesReached)?;
+							// The direct vote overrides our partial delegations on this poll.
+							for d in PartialDelegationsFor::<T, I>::get(who, &class) {
+								let amount = d.conviction.votes(d.balance);
+								if let Some(approve) =
+									Self::retract_delegation(&d.target, &class, poll_index, amount)
+								{
+									tally.reduce(approve, amount);
+								}
+							}
 						},
 					}
 					// Shouldn't be possible to fail, but we handle it gracefully.
 					tally.add(vote).ok_or(ArithmeticError::Overflow)?;
 					if let Some(approve) = vote.as_standard() {
-						tally.increase(approve, *delegations);
+						tally.increase(approve, delegations);
 					}
 				} else {
 					return Err(Error::<T, I>::AlreadyDelegating.into())
@@ -447,7 +589,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		let class = class_hint
 			.or_else(|| Some(T::Polls::as_ongoing(poll_index)?.1))
 			.ok_or(Error::<T, I>::ClassNeeded)?;
-		VotingFor::<T, I>::try_mutate(who, class, |voting| {
+		let partial_delegations = PartialDelegationsFor::<T, I>::get(who, &class);
+		VotingFor::<T, I>::try_mutate(who, &class, |voting| {
 			if let Voting::Casting(Casting { ref mut votes, delegations, ref mut prior }) = voting {
 				let i = votes
 					.binary_search_by_key(&poll_index, |i| i.0)
@@ -460,7 +603,19 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 						// Shouldn't be possible to fail, but we handle it gracefully.
 						tally.remove(v.1).ok_or(ArithmeticError::Underflow)?;
 						if let Some(approve) = v.1.as_standard() {
-							tally.reduce(approve, *delegations);
+							tally.reduce(
+								approve,
+								Self::effective_delegations(who, poll_index, *delegations),
+							);
+						}
+						// Our partial delegations apply to this poll again.
+						for d in partial_delegations.iter() {
+							let amount = d.conviction.votes(d.balance);
+							if let Some(approve) =
+								Self::reinstate_delegation(&d.target, &class, poll_index, amount)
+							{
+								tally.increase(approve, amount);
+							}
 						}
 						Ok(())
 					},
@@ -478,9 +633,20 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 								prior.accumulate(unlock_at, balance)
 							}
 						}
+						for d in partial_delegations.iter() {
+							let amount = d.conviction.votes(d.balance);
+							Self::reinstate_delegation(&d.target, &class, poll_index, amount);
+						}
+						Ok(())
+					},
+					PollStatus::None => {
+						// Poll was cancelled.
+						for d in partial_delegations.iter() {
+							let amount = d.conviction.votes(d.balance);
+							Self::reinstate_delegation(&d.target, &class, poll_index, amount);
+						}
 						Ok(())
 					},
-					PollStatus::None => Ok(()), // Poll was cancelled.
 				})
 			} else {
 				Ok(())
@@ -557,6 +723,10 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		ensure!(who != target, Error::<T, I>::Nonsense);
 		T::Polls::classes().binary_search(&class).map_err(|_| Error::<T, I>::BadClass)?;
 		ensure!(balance <= T::Currency::total_balance(&who), Error::<T, I>::InsufficientFunds);
+		ensure!(
+			PartialDelegationsFor::<T, I>::get(&who, &class).is_empty(),
+			Error::<T, I>::AlreadyDelegating
+		);
 		let votes =
 			VotingFor::<T, I>::try_mutate(&who, &class, |voting| -> Result<u32, DispatchError> {
 				let old = sp_std::mem::replace(
@@ -629,6 +799,189 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Ok(votes)
 	}
 
+	/// Attempt to delegate `balance` times `conviction` of voting power from `who` to `target`,
+	/// alongside `who`'s direct votes and other partial delegations.
+	///
+	/// Return the upstream number of votes and the number of `who`'s own votes.
+	fn try_delegate_partial(
+		who: T::AccountId,
+		class: ClassOf<T, I>,
+		target: T::AccountId,
+		conviction: Conviction,
+		balance: BalanceOf<T, I>,
+	) -> Result<(u32, u32), DispatchError> {
+		ensure!(who != target, Error::<T, I>::Nonsense);
+		T::Polls::classes().binary_search(&class).map_err(|_| Error::<T, I>::BadClass)?;
+		let own_votes = match VotingFor::<T, I>::get(&who, &class) {
+			Voting::Casting(Casting { votes, .. }) => votes,
+			Voting::Delegating(_) => return Err(Error::<T, I>::AlreadyDelegating.into()),
+		};
+		let total = PartialDelegationsFor::<T, I>::try_mutate(
+			&who,
+			&class,
+			|delegations| -> Result<BalanceOf<T, I>, DispatchError> {
+				ensure!(
+					delegations.iter().all(|d| d.target != target),
+					Error::<T, I>::AlreadyDelegating
+				);
+				let total = delegations.iter().fold(balance, |a, d| a.saturating_add(d.balance));
+				ensure!(
+					total <= T::Currency::total_balance(&who),
+					Error::<T, I>::InsufficientFunds
+				);
+				delegations
+					.try_push(PartialDelegation { target: target.clone(), conviction, balance })
+					.map_err(|_| Error::<T, I>::MaxDelegationsReached)?;
+				Ok(total)
+			},
+		)?;
+
+		let amount = conviction.votes(balance);
+		let votes = Self::increase_upstream_delegation(&target, &class, amount);
+		// Our direct votes override the new delegation.
+		for &(poll_index, _) in own_votes.iter() {
+			if let Some(approve) = Self::retract_delegation(&target, &class, poll_index, amount) {
+				T::Polls::access_poll(poll_index, |poll_status| {
+					if let PollStatus::Ongoing(tally, _) = poll_status {
+						tally.reduce(approve, amount);
+					}
+				});
+			}
+		}
+		// Extend the lock to `total` (rather than setting it) since we don't know what other votes
+		// are in place.
+		Self::extend_lock(&who, &class, total);
+		Self::deposit_event(Event::<T, I>::Delegated(who, target));
+		Ok((votes, own_votes.len() as u32))
+	}
+
+	/// Attempt to end the partial delegation from `who` to `target`.
+	///
+	/// Return the number of votes of upstream and the number of `who`'s own votes.
+	fn try_undelegate_partial(
+		who: T::AccountId,
+		class: ClassOf<T, I>,
+		target: T::AccountId,
+	) -> Result<(u32, u32), DispatchError> {
+		let PartialDelegation { conviction, balance, .. } =
+			PartialDelegationsFor::<T, I>::try_mutate(&who, &class, |delegations| {
+				delegations
+					.iter()
+					.position(|d| d.target == target)
+					.map(|i| delegations.remove(i))
+					.ok_or(Error::<T, I>::NotDelegating)
+			})?;
+
+		let amount = conviction.votes(balance);
+		let own_votes = VotingFor::<T, I>::mutate(&who, &class, |voting| {
+			// Partial delegations are only made while casting.
+			if let Voting::Casting(Casting { votes, prior, .. }) = voting {
+				// Undo the retractions due to our direct votes.
+				for &(poll_index, _) in votes.iter() {
+					if let Some(approve) =
+						Self::reinstate_delegation(&target, &class, poll_index, amount)
+					{
+						T::Polls::access_poll(poll_index, |poll_status| {
+							if let PollStatus::Ongoing(tally, _) = poll_status {
+								tally.increase(approve, amount);
+							}
+						});
+					}
+				}
+				let now = frame_system::Pallet::<T>::block_number();
+				let lock_periods = conviction.lock_periods().into();
+				prior.accumulate(
+					now.saturating_add(T::VoteLockingPeriod::get().saturating_mul(lock_periods)),
+					balance,
+				);
+				votes.len() as u32
+			} else {
+				0
+			}
+		});
+		let votes = Self::reduce_upstream_delegation(&target, &class, amount);
+		Self::deposit_event(Event::<T, I>::PartiallyUndelegated(who, target));
+		Ok((votes, own_votes))
+	}
+
+	/// The delegations received by `who` which count towards its vote on `poll_index`.
+	fn effective_delegations(
+		who: &T::AccountId,
+		poll_index: PollIndexOf<T, I>,
+		delegations: Delegations<BalanceOf<T, I>>,
+	) -> Delegations<BalanceOf<T, I>> {
+		match RetractedDelegations::<T, I>::get(who, poll_index) {
+			Some(retracted) => delegations.saturating_sub(retracted),
+			None => delegations,
+		}
+	}
+
+	/// Retract `amount` of the delegations received by `target` from `poll_index`.
+	///
+	/// Return the direction of `target`'s standard vote on the poll, if any, whose tally must be
+	/// reduced by `amount`.
+	fn retract_delegation(
+		target: &T::AccountId,
+		class: &ClassOf<T, I>,
+		poll_index: PollIndexOf<T, I>,
+		amount: Delegations<BalanceOf<T, I>>,
+	) -> Option<bool> {
+		RetractedDelegations::<T, I>::mutate(target, poll_index, |retracted| {
+			*retracted = Some(retracted.unwrap_or_default().saturating_add(amount));
+		});
+		Self::standard_vote(target, class, poll_index)
+	}
+
+	/// Reinstate `amount` of the delegations received by `target` on `poll_index`.
+	///
+	/// Return the direction of `target`'s standard vote on the poll, if any, whose tally must be
+	/// increased by `amount`.
+	fn reinstate_delegation(
+		target: &T::AccountId,
+		class: &ClassOf<T, I>,
+		poll_index: PollIndexOf<T, I>,
+		amount: Delegations<BalanceOf<T, I>>,
+	) -> Option<bool> {
+		RetractedDelegations::<T, I>::mutate_exists(target, poll_index, |retracted| {
+			*retracted =
+				retracted.map(|r| r.saturating_sub(amount)).filter(|r| *r != Default::default());
+		});
+		Self::standard_vote(target, class, poll_index)
+	}
+
+	/// The direction of the standard vote of `who` on `poll_index`, if any.
+	fn standard_vote(
+		who: &T::AccountId,
+		class: &ClassOf<T, I>,
+		poll_index: PollIndexOf<T, I>,
+	) -> Option<bool> {
+		match VotingFor::<T, I>::get(who, class) {
+			Voting::Casting(Casting { votes, .. }) => votes
+				.binary_search_by_key(&poll_index, |i| i.0)
+				.ok()
+				.and_then(|i| votes[i].1.as_standard()),
+			Voting::Delegating(_) => None,
+		}
+	}
+
+	/// The weight of retracting or reinstating the partial delegations of a voter on a poll.
+	///
+	/// Each delegation reads the vote of its target and reads and writes the delegations retracted
+	/// from it, whose largest encodings bound the proof size.
+	fn retraction_weight() -> Weight {
+		let proof_size = |info: Vec<StorageInfo>| {
+			Weight::from_parts(0, info.iter().filter_map(|i| i.max_size).map(u64::from).sum())
+		};
+		let per_delegation = T::DbWeight::get()
+			.reads_writes(2, 1)
+			.saturating_add(proof_size(VotingFor::<T, I>::storage_info()))
+			.saturating_add(proof_size(RetractedDelegations::<T, I>::storage_info()));
+		T::DbWeight::get()
+			.reads(1)
+			.saturating_add(proof_size(PartialDelegationsFor::<T, I>::storage_info()))
+			.saturating_add(per_delegation.saturating_mul(T::MaxDelegations::get().into()))
+	}
+
 	fn extend_lock(who: &T::AccountId, class: &ClassOf<T, I>, amount: BalanceOf<T, I>) {
 		ClassLocksFor::<T, I>::mutate(who, |locks| {
 			match locks.iter().position(|x| &x.0 == class) {
@@ -655,9 +1008,12 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	/// Rejig the lock on an account. It will never get more stringent (since that would indicate
 	/// a security hole) but may be reduced from what they are currently.
 	fn update_lock(class: &ClassOf<T, I>, who: &T::AccountId) {
+		let delegated = PartialDelegationsFor::<T, I>::get(who, class)
+			.iter()
+			.fold(Zero::zero(), |a: BalanceOf<T, I>, d| a.saturating_add(d.balance));
 		let class_lock_needed = VotingFor::<T, I>::mutate(who, class, |voting| {
 			voting.rejig(frame_system::Pallet::<T>::block_number());
-			voting.locked_balance()
+			voting.locked_balance().max(delegated)
 		});
 		let lock_needed = ClassLocksFor::<T, I>::mutate(who, |locks| {
 			locks.retain(|x| &x.0 != class);
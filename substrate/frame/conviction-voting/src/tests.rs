@@ -190,6 +190,7 @@ impl Config for Test {
 	type WeightInfo = ();
 	type MaxTurnout = frame_support::traits::TotalIssuanceOf<Balances, Self::AccountId>;
 	type Polls = TestPolls;
+	type MaxDelegations = ConstU32<2>;
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
@@ -542,6 +543,134 @@ fn classwise_delegation_works() {
 	});
 }
 
+#[test]
+fn partial_delegation_works() {
+	new_test_ext().execute_with(|| {
+		Polls::set(
+			vec![(0, Ongoing(Tally::new(0), 0)), (1, Ongoing(Tally::new(0), 0))]
+				.into_iter()
+				.collect(),
+		);
+		assert_ok!(Voting::delegate_partial(
+			RuntimeOrigin::signed(1),
+			0,
+			2,
+			Conviction::Locked1x,
+			3
+		));
+		assert_ok!(Voting::delegate_partial(
+			RuntimeOrigin::signed(1),
+			0,
+			3,
+			Conviction::Locked2x,
+			5
+		));
+		assert_eq!(Balances::usable_balance(1), 2);
+		assert_noop!(
+			Voting::delegate_partial(RuntimeOrigin::signed(1), 0, 2, Conviction::Locked1x, 1),
+			Error::<Test>::AlreadyDelegating
+		);
+		assert_noop!(
+			Voting::delegate_partial(RuntimeOrigin::signed(1), 0, 4, Conviction::Locked1x, 2),
+			Error::<Test>::MaxDelegationsReached
+		);
+		assert_noop!(
+			Voting::delegate_partial(RuntimeOrigin::signed(1), 0, 4, Conviction::Locked1x, 3),
+			Error::<Test>::InsufficientFunds
+		);
+		assert_noop!(
+			Voting::delegate(RuntimeOrigin::signed(1), 0, 4, Conviction::Locked1x, 2),
+			Error::<Test>::AlreadyDelegating
+		);
+
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(2), 0, aye(10, 0)));
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(3), 0, nay(10, 0)));
+		assert_eq!(tally(0), Tally::from_parts(4, 11, 13));
+
+		// 1 votes directly on poll 0, overriding its delegations there.
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(1), 0, aye(10, 1)));
+		assert_eq!(tally(0), Tally::from_parts(11, 1, 20));
+
+		// 2 changing its vote does not bring back the retracted delegation.
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(2), 0, nay(10, 0)));
+		assert_eq!(tally(0), Tally::from_parts(10, 2, 10));
+
+		// The delegation still applies to other polls.
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(2), 1, aye(10, 0)));
+		assert_eq!(tally(1), Tally::from_parts(4, 0, 13));
+
+		// Removing the direct vote reinstates the delegations.
+		assert_ok!(Voting::remove_vote(RuntimeOrigin::signed(1), Some(0), 0));
+		assert_eq!(tally(0), Tally::from_parts(0, 15, 0));
+		assert_eq!(RetractedDelegations::<Test>::get(2, 0), None);
+		assert_eq!(RetractedDelegations::<Test>::get(3, 0), None);
+	});
+}
+
+#[test]
+fn partial_delegation_around_direct_vote_works() {
+	new_test_ext().execute_with(|| {
+		Polls::set(vec![(0, Ongoing(Tally::new(0), 0))].into_iter().collect());
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(2), 0, aye(10, 0)));
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(1), 0, aye(10, 0)));
+		assert_eq!(tally(0), Tally::from_parts(2, 0, 20));
+
+		// Delegating while voting directly leaves the poll voted on untouched.
+		assert_ok!(Voting::delegate_partial(
+			RuntimeOrigin::signed(1),
+			0,
+			2,
+			Conviction::Locked1x,
+			5
+		));
+		assert_eq!(tally(0), Tally::from_parts(2, 0, 20));
+		assert_eq!(
+			RetractedDelegations::<Test>::get(2, 0),
+			Some(Delegations { votes: 5, capital: 5 })
+		);
+
+		assert_ok!(Voting::undelegate_partial(RuntimeOrigin::signed(1), 0, 2));
+		System::assert_last_event(RuntimeEvent::Voting(Event::PartiallyUndelegated(1, 2)));
+		assert_eq!(tally(0), Tally::from_parts(2, 0, 20));
+		assert_eq!(RetractedDelegations::<Test>::get(2, 0), None);
+		assert_noop!(
+			Voting::undelegate_partial(RuntimeOrigin::signed(1), 0, 2),
+			Error::<Test>::NotDelegating
+		);
+
+		assert_ok!(Voting::remove_vote(RuntimeOrigin::signed(1), Some(0), 0));
+		assert_eq!(tally(0), Tally::from_parts(1, 0, 10));
+	});
+}
+
+#[test]
+fn partial_delegation_locks_balance() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Voting::delegate_partial(
+			RuntimeOrigin::signed(1),
+			0,
+			2,
+			Conviction::Locked1x,
+			5
+		));
+		assert_ok!(Voting::delegate_partial(RuntimeOrigin::signed(1), 0, 3, Conviction::None, 3));
+		assert_ok!(Voting::unlock(RuntimeOrigin::signed(1), 0, 1));
+		assert_eq!(Balances::usable_balance(1), 2);
+
+		assert_ok!(Voting::undelegate_partial(RuntimeOrigin::signed(1), 0, 3));
+		assert_ok!(Voting::unlock(RuntimeOrigin::signed(1), 0, 1));
+		assert_eq!(Balances::usable_balance(1), 5);
+
+		assert_ok!(Voting::undelegate_partial(RuntimeOrigin::signed(1), 0, 2));
+		assert_ok!(Voting::unlock(RuntimeOrigin::signed(1), 0, 1));
+		assert_eq!(Balances::usable_balance(1), 5);
+
+		run_to(4);
+		assert_ok!(Voting::unlock(RuntimeOrigin::signed(1), 0, 1));
+		assert_eq!(Balances::usable_balance(1), 10);
+	});
+}
+
 #[test]
 fn redelegation_after_vote_ending_should_keep_lock() {
 	new_test_ext().execute_with(|| {
@@ -165,6 +165,19 @@ pub struct Delegating<Balance, AccountId, BlockNumber> {
 	pub prior: PriorLock<BlockNumber, Balance>,
 }
 
+/// A delegation of some of an account's voting power to a single target, made while the account
+/// keeps casting votes.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PartialDelegation<AccountId, Balance> {
+	/// The account to which the voting power is delegated.
+	pub target: AccountId,
+	/// The conviction with which the voting power is delegated. When this gets undelegated, the
+	/// relevant lock begins.
+	pub conviction: Conviction,
+	/// The amount of balance delegated.
+	pub balance: Balance,
+}
+
 /// Information concerning the direct vote-casting of some voting power.
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 #[scale_info(skip_type_params(MaxVotes))]
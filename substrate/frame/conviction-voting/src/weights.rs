@@ -59,6 +59,8 @@ pub trait WeightInfo {
 	fn delegate(r: u32, ) -> Weight;
 	fn undelegate(r: u32, ) -> Weight;
 	fn unlock() -> Weight;
+	fn delegate_partial(r: u32, s: u32, ) -> Weight;
+	fn undelegate_partial(r: u32, s: u32, ) -> Weight;
 }
 
 /// Weights for pallet_conviction_voting using the Substrate node and recommended hardware.
@@ -199,6 +201,64 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
+	/// Storage: ConvictionVoting VotingFor (r:2 w:1)
+	/// Proof: ConvictionVoting VotingFor (max_values: None, max_size: Some(27241), added: 29716, mode: MaxEncodedLen)
+	/// Storage: ConvictionVoting PartialDelegationsFor (r:1 w:1)
+	/// Proof: ConvictionVoting PartialDelegationsFor (max_values: None, max_size: Some(1099), added: 3574, mode: MaxEncodedLen)
+	/// Storage: Referenda ReferendumInfoFor (r:1 w:1)
+	/// Proof: Referenda ReferendumInfoFor (max_values: None, max_size: Some(366), added: 2841, mode: MaxEncodedLen)
+	/// Storage: Scheduler Agenda (r:2 w:2)
+	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(107022), added: 109497, mode: MaxEncodedLen)
+	/// Storage: ConvictionVoting ClassLocksFor (r:1 w:1)
+	/// Proof: ConvictionVoting ClassLocksFor (max_values: None, max_size: Some(59), added: 2534, mode: MaxEncodedLen)
+	/// Storage: Balances Locks (r:1 w:1)
+	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	/// Storage: Balances Freezes (r:1 w:0)
+	/// Proof: Balances Freezes (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: ConvictionVoting RetractedDelegations (r:1 w:1)
+	/// Proof: ConvictionVoting RetractedDelegations (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// The range of component `r` is `[0, 1]`.
+	/// The range of component `s` is `[0, 1]`.
+	fn delegate_partial(r: u32, s: u32, ) -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(62_034_118, 109992)
+			.saturating_add(Weight::from_parts(45_118_302, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 109992).saturating_mul(r.into()))
+			.saturating_add(Weight::from_parts(21_406_000, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(s.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 5400).saturating_mul(s.into()))
+	}
+	/// Storage: ConvictionVoting PartialDelegationsFor (r:1 w:1)
+	/// Proof: ConvictionVoting PartialDelegationsFor (max_values: None, max_size: Some(1099), added: 3574, mode: MaxEncodedLen)
+	/// Storage: ConvictionVoting VotingFor (r:2 w:2)
+	/// Proof: ConvictionVoting VotingFor (max_values: None, max_size: Some(27241), added: 29716, mode: MaxEncodedLen)
+	/// Storage: Referenda ReferendumInfoFor (r:1 w:1)
+	/// Proof: Referenda ReferendumInfoFor (max_values: None, max_size: Some(366), added: 2841, mode: MaxEncodedLen)
+	/// Storage: Scheduler Agenda (r:2 w:2)
+	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(107022), added: 109497, mode: MaxEncodedLen)
+	/// Storage: ConvictionVoting RetractedDelegations (r:1 w:1)
+	/// Proof: ConvictionVoting RetractedDelegations (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// The range of component `r` is `[0, 1]`.
+	/// The range of component `s` is `[0, 1]`.
+	fn undelegate_partial(r: u32, s: u32, ) -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(32_660_915, 109992)
+			.saturating_add(Weight::from_parts(41_017_593, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 109992).saturating_mul(r.into()))
+			.saturating_add(Weight::from_parts(20_873_000, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(s.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 5400).saturating_mul(s.into()))
+	}
 }
 
 // For backwards compatibility and tests
@@ -338,4 +398,62 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
+	/// Storage: ConvictionVoting VotingFor (r:2 w:1)
+	/// Proof: ConvictionVoting VotingFor (max_values: None, max_size: Some(27241), added: 29716, mode: MaxEncodedLen)
+	/// Storage: ConvictionVoting PartialDelegationsFor (r:1 w:1)
+	/// Proof: ConvictionVoting PartialDelegationsFor (max_values: None, max_size: Some(1099), added: 3574, mode: MaxEncodedLen)
+	/// Storage: Referenda ReferendumInfoFor (r:1 w:1)
+	/// Proof: Referenda ReferendumInfoFor (max_values: None, max_size: Some(366), added: 2841, mode: MaxEncodedLen)
+	/// Storage: Scheduler Agenda (r:2 w:2)
+	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(107022), added: 109497, mode: MaxEncodedLen)
+	/// Storage: ConvictionVoting ClassLocksFor (r:1 w:1)
+	/// Proof: ConvictionVoting ClassLocksFor (max_values: None, max_size: Some(59), added: 2534, mode: MaxEncodedLen)
+	/// Storage: Balances Locks (r:1 w:1)
+	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	/// Storage: Balances Freezes (r:1 w:0)
+	/// Proof: Balances Freezes (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: ConvictionVoting RetractedDelegations (r:1 w:1)
+	/// Proof: ConvictionVoting RetractedDelegations (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// The range of component `r` is `[0, 1]`.
+	/// The range of component `s` is `[0, 1]`.
+	fn delegate_partial(r: u32, s: u32, ) -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(62_034_118, 109992)
+			.saturating_add(Weight::from_parts(45_118_302, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(r.into())))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 109992).saturating_mul(r.into()))
+			.saturating_add(Weight::from_parts(21_406_000, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(s.into())))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 5400).saturating_mul(s.into()))
+	}
+	/// Storage: ConvictionVoting PartialDelegationsFor (r:1 w:1)
+	/// Proof: ConvictionVoting PartialDelegationsFor (max_values: None, max_size: Some(1099), added: 3574, mode: MaxEncodedLen)
+	/// Storage: ConvictionVoting VotingFor (r:2 w:2)
+	/// Proof: ConvictionVoting VotingFor (max_values: None, max_size: Some(27241), added: 29716, mode: MaxEncodedLen)
+	/// Storage: Referenda ReferendumInfoFor (r:1 w:1)
+	/// Proof: Referenda ReferendumInfoFor (max_values: None, max_size: Some(366), added: 2841, mode: MaxEncodedLen)
+	/// Storage: Scheduler Agenda (r:2 w:2)
+	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(107022), added: 109497, mode: MaxEncodedLen)
+	/// Storage: ConvictionVoting RetractedDelegations (r:1 w:1)
+	/// Proof: ConvictionVoting RetractedDelegations (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// The range of component `r` is `[0, 1]`.
+	/// The range of component `s` is `[0, 1]`.
+	fn undelegate_partial(r: u32, s: u32, ) -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(32_660_915, 109992)
+			.saturating_add(Weight::from_parts(41_017_593, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(r.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 109992).saturating_mul(r.into()))
+			.saturating_add(Weight::from_parts(20_873_000, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(s.into())))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 5400).saturating_mul(s.into()))
+	}
 }
@@ -215,6 +215,9 @@ mod benchmarks {
 	fn submit_evidence() -> Result<(), BenchmarkError> {
 		let member = make_member::<T, I>(1)?;
 		let evidence = vec![0; Evidence::<T, I>::bound()].try_into().unwrap();
+		// An expired cooldown after a failed review has to be cleared.
+		let now = frame_system::Pallet::<T>::block_number();
+		ReviewCooldown::<T, I>::insert(&member, now);
 
 		assert!(!MemberEvidence::<T, I>::contains_key(&member));
 
@@ -222,12 +225,35 @@ mod benchmarks {
 		_(RawOrigin::Signed(member.clone()), Wish::Retention, evidence);
 
 		assert!(MemberEvidence::<T, I>::contains_key(&member));
+		assert!(!ReviewCooldown::<T, I>::contains_key(&member));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn conclude_review() -> Result<(), BenchmarkError> {
+		let member = make_member::<T, I>(1)?;
+		let evidence = vec![0; Evidence::<T, I>::bound()].try_into().unwrap();
+		let origin = RawOrigin::Signed(member.clone()).into();
+		CoreFellowship::<T, I>::submit_evidence(origin, Wish::Promotion, evidence)?;
+
+		T::Tally::setup(2, Perbill::from_percent(10));
+		let index = CoreFellowship::<T, I>::start_review(member.clone(), 2, 1u32.into());
+		<CoreFellowship<T, I> as Polling<T::Tally>>::end_ongoing(index, true).unwrap();
+		// Set it to the max value to ensure that any minimum promotion period has passed.
+		frame_system::Pallet::<T>::set_block_number(BlockNumberFor::<T>::max_value());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(member.clone()), member.clone());
+
+		assert_eq!(T::Members::rank_of(&member), Some(2));
+		assert!(!MemberEvidence::<T, I>::contains_key(&member));
+		assert!(!MemberReview::<T, I>::contains_key(&member));
 		Ok(())
 	}
 
 	impl_benchmark_test_suite! {
 		CoreFellowship,
-		crate::tests::new_test_ext(),
-		crate::tests::Test,
+		crate::tests::unit::new_test_ext(),
+		crate::tests::unit::Test,
 	}
 }
@@ -32,6 +32,13 @@
 //!   demotion and keep it at rank 1.
 //! - These last two steps continue until the candidate is ready to apply for a promotion, at which
 //!   point the previous two steps are repeated with a higher rank.
+//! - Instead of waiting on the `ApproveOrigin` or `PromoteOrigin`, submitted evidence may be
+//!   reviewed by the ranked members themselves: if a `ReviewPeriod` is configured, submitting
+//!   evidence opens a poll of the rank at stake which members of at least that rank vote on (e.g.
+//!   through `pallet-ranked-collective` using this pallet as its `Polls`). Once the period is over,
+//!   anyone may call `conclude_review`, which approves or promotes the member if the review passed
+//!   and otherwise disposes of the evidence, no new evidence being accepted from the member for
+//!   another `ReviewPeriod`.
 //! - If the member fails to get an approval within the `demotion_period` then anyone may call
 //!   `bump` to demote the candidate by one rank.
 //! - If a candidate fails to be promoted to a member within the `offboard_timeout` period, then
@@ -61,14 +68,15 @@
 use codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_arithmetic::traits::{Saturating, Zero};
-use sp_runtime::RuntimeDebug;
+use sp_runtime::{Perbill, RuntimeDebug};
 use sp_std::{marker::PhantomData, prelude::*};
 
 use frame_support::{
 	dispatch::DispatchResultWithPostInfo,
 	ensure, impl_ensure_origin_with_arg_ignoring_arg,
 	traits::{
-		tokens::Balance as BalanceTrait, EnsureOrigin, EnsureOriginWithArg, Get, RankedMembers,
+		tokens::Balance as BalanceTrait, EnsureOrigin, EnsureOriginWithArg, Get, PollStatus,
+		Polling, RankedMembers, VoteTally,
 	},
 	BoundedVec,
 };
@@ -138,6 +146,22 @@ pub struct MemberStatus<BlockNumber> {
 	last_proof: BlockNumber,
 }
 
+/// Index of a review of some submitted evidence.
+pub type PollIndex = u32;
+
+/// A review of some submitted evidence, voted on by the ranked members.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+pub struct ReviewStatus<AccountId, Rank, Tally, BlockNumber> {
+	/// The member/candidate whose evidence is reviewed.
+	who: AccountId,
+	/// The rank at stake. Only members of at least this rank may vote.
+	class: Rank,
+	/// The votes cast so far.
+	tally: Tally,
+	/// The block from which votes may no longer be cast and the review may be concluded.
+	end: BlockNumber,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -192,11 +216,35 @@ pub mod pallet {
 		/// The maximum size in bytes submitted evidence is allowed to be.
 		#[pallet::constant]
 		type EvidenceSize: Get<u32>;
+
+		/// The tally of the votes cast on a review of submitted evidence.
+		type Tally: VoteTally<u32, RankOf<Self, I>> + Parameter + MaxEncodedLen;
+
+		/// The number of blocks for which members may vote on a review of submitted evidence.
+		///
+		/// If zero, no reviews are opened and evidence is only judged by the `ApproveOrigin` and
+		/// the `PromoteOrigin`.
+		#[pallet::constant]
+		type ReviewPeriod: Get<BlockNumberFor<Self>>;
+
+		/// The minimum proportion of aye votes for a review to pass.
+		#[pallet::constant]
+		type ReviewApproval: Get<Perbill>;
+
+		/// The minimum proportion of the eligible members voting aye for a review to pass.
+		#[pallet::constant]
+		type ReviewSupport: Get<Perbill>;
 	}
 
 	pub type ParamsOf<T, I> = ParamsType<<T as Config<I>>::Balance, BlockNumberFor<T>, RANK_COUNT>;
 	pub type MemberStatusOf<T> = MemberStatus<BlockNumberFor<T>>;
 	pub type RankOf<T, I> = <<T as Config<I>>::Members as RankedMembers>::Rank;
+	pub type ReviewOf<T, I> = ReviewStatus<
+		<T as frame_system::Config>::AccountId,
+		RankOf<T, I>,
+		<T as Config<I>>::Tally,
+		BlockNumberFor<T>,
+	>;
 
 	/// The overall status of the system.
 	#[pallet::storage]
@@ -213,6 +261,26 @@ pub mod pallet {
 	pub(super) type MemberEvidence<T: Config<I>, I: 'static = ()> =
 		StorageMap<_, Twox64Concat, T::AccountId, (Wish, Evidence<T, I>), OptionQuery>;
 
+	/// The number of reviews which have been opened.
+	#[pallet::storage]
+	pub(super) type ReviewCount<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, PollIndex, ValueQuery>;
+
+	/// The reviews of submitted evidence which have not yet been concluded.
+	#[pallet::storage]
+	pub(super) type Reviews<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, PollIndex, ReviewOf<T, I>, OptionQuery>;
+
+	/// The review of the evidence submitted by a member, if any.
+	#[pallet::storage]
+	pub(super) type MemberReview<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, T::AccountId, PollIndex, OptionQuery>;
+
+	/// The block from which a member whose review failed may submit evidence again.
+	#[pallet::storage]
+	pub(super) type ReviewCooldown<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, T::AccountId, BlockNumberFor<T>, OptionQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config<I>, I: 'static = ()> {
@@ -234,7 +302,7 @@ pub mod pallet {
 		/// Member has stated evidence of their efforts their request for rank.
 		Requested { who: T::AccountId, wish: Wish },
 		/// Some submitted evidence was judged and removed. There may or may not have been a change
-		/// to the rank, but in any case, `last_proof` is reset.
+		/// to the rank and, unless the evidence failed its review, `last_proof` is reset.
 		EvidenceJudged {
 			/// The member/candidate.
 			who: T::AccountId,
@@ -249,6 +317,10 @@ pub mod pallet {
 		},
 		/// Pre-ranked account has been inducted at their current rank.
 		Imported { who: T::AccountId, rank: RankOf<T, I> },
+		/// Members of at least rank `class` may vote on the review of some submitted evidence.
+		ReviewStarted { who: T::AccountId, index: PollIndex, class: RankOf<T, I> },
+		/// The review of some submitted evidence was concluded.
+		ReviewConcluded { who: T::AccountId, index: PollIndex, approved: bool },
 	}
 
 	#[pallet::error]
@@ -273,6 +345,10 @@ pub mod pallet {
 		NotTracked,
 		/// Operation cannot be done yet since not enough time has passed.
 		TooSoon,
+		/// The member's evidence is not under review.
+		NoReview,
+		/// The member's evidence is under review, and may not be replaced until it is concluded.
+		UnderReview,
 	}
 
 	#[pallet::call]
@@ -381,15 +457,7 @@ pub mod pallet {
 			ensure!(at_rank > 0, Error::<T, I>::InvalidRank);
 			let rank = T::Members::rank_of(&who).ok_or(Error::<T, I>::Unranked)?;
 			ensure!(rank == at_rank, Error::<T, I>::UnexpectedRank);
-			let mut member = Member::<T, I>::get(&who).ok_or(Error::<T, I>::NotTracked)?;
-
-			member.last_proof = frame_system::Pallet::<T>::block_number();
-			Member::<T, I>::insert(&who, &member);
-
-			Self::dispose_evidence(who.clone(), at_rank, Some(at_rank));
-			Self::deposit_event(Event::<T, I>::Proven { who, at_rank });
-
-			Ok(())
+			Self::do_approve(who, at_rank)
 		}
 
 		/// Introduce a new and unranked candidate (rank zero).
@@ -438,28 +506,7 @@ pub mod pallet {
 				rank.checked_add(1).map_or(false, |i| i == to_rank),
 				Error::<T, I>::UnexpectedRank
 			);
-
-			let mut member = Member::<T, I>::get(&who).ok_or(Error::<T, I>::NotTracked)?;
-			let now = frame_system::Pallet::<T>::block_number();
-
-			let params = Params::<T, I>::get();
-			let rank_index = Self::rank_to_index(to_rank).ok_or(Error::<T, I>::InvalidRank)?;
-			let min_period = params.min_promotion_period[rank_index];
-			// Ensure enough time has passed.
-			ensure!(
-				member.last_promotion.saturating_add(min_period) <= now,
-				Error::<T, I>::TooSoon,
-			);
-
-			T::Members::promote(&who)?;
-			member.last_promotion = now;
-			member.last_proof = now;
-			Member::<T, I>::insert(&who, &member);
-			Self::dispose_evidence(who.clone(), rank, Some(to_rank));
-
-			Self::deposit_event(Event::<T, I>::Promoted { who, to_rank });
-
-			Ok(())
+			Self::do_promote(who, rank, to_rank)
 		}
 
 		/// Stop tracking a prior member who is now not a ranked member of the collective.
@@ -475,6 +522,8 @@ pub mod pallet {
 			ensure!(Member::<T, I>::contains_key(&who), Error::<T, I>::NotTracked);
 			Member::<T, I>::remove(&who);
 			MemberEvidence::<T, I>::remove(&who);
+			ReviewCooldown::<T, I>::remove(&who);
+			Self::close_review(&who);
 			Self::deposit_event(Event::<T, I>::Offboarded { who });
 			Ok(Pays::No.into())
 		}
@@ -484,6 +533,10 @@ pub mod pallet {
 		/// This is free as long as no evidence for the forthcoming judgement is already submitted.
 		/// Evidence is cleared after an outcome (either demotion, promotion of approval).
 		///
+		/// If reviews are enabled, this opens a review of the evidence for members of at least the
+		/// rank at stake to vote on. No evidence may be submitted while it runs, nor, after a
+		/// failed review, until `ReviewPeriod` blocks later.
+		///
 		/// - `origin`: A `Signed` origin of an inducted and ranked account.
 		/// - `wish`: The stated desire of the member.
 		/// - `evidence`: A dump of evidence to be considered. This should generally be either a
@@ -498,9 +551,16 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
 			ensure!(Member::<T, I>::contains_key(&who), Error::<T, I>::NotTracked);
+			ensure!(!MemberReview::<T, I>::contains_key(&who), Error::<T, I>::UnderReview);
+			if let Some(cooldown_end) = ReviewCooldown::<T, I>::get(&who) {
+				let now = frame_system::Pallet::<T>::block_number();
+				ensure!(now >= cooldown_end, Error::<T, I>::TooSoon);
+				ReviewCooldown::<T, I>::remove(&who);
+			}
 			let replaced = MemberEvidence::<T, I>::contains_key(&who);
 			MemberEvidence::<T, I>::insert(&who, (wish, evidence));
-			Self::deposit_event(Event::<T, I>::Requested { who, wish });
+			Self::deposit_event(Event::<T, I>::Requested { who: who.clone(), wish });
+			Self::open_review(who, wish);
 			Ok(if replaced { Pays::Yes } else { Pays::No }.into())
 		}
 
@@ -527,6 +587,49 @@ pub mod pallet {
 
 			Ok(Pays::No.into())
 		}
+
+		/// Conclude the review of a member's evidence once its voting period is over.
+		///
+		/// If the review passed, the member is proven at their rank or promoted according to their
+		/// wish, just as through `approve` or `promote`. Otherwise, or if the member's rank changed
+		/// in the meantime, the evidence is disposed of without further effect. A member whose
+		/// review failed may only submit evidence again `ReviewPeriod` blocks later.
+		///
+		/// - `origin`: A `Signed` origin of an account.
+		/// - `who`: The member whose evidence is under review.
+		#[pallet::weight(T::WeightInfo::conclude_review())]
+		#[pallet::call_index(9)]
+		pub fn conclude_review(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let _ = ensure_signed(origin)?;
+			let index = MemberReview::<T, I>::get(&who).ok_or(Error::<T, I>::NoReview)?;
+			let review = Reviews::<T, I>::get(index).ok_or(Error::<T, I>::NoReview)?;
+			let (wish, _) = MemberEvidence::<T, I>::get(&who).ok_or(Error::<T, I>::NoReview)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now >= review.end, Error::<T, I>::TooSoon);
+			let rank = T::Members::rank_of(&who).ok_or(Error::<T, I>::Unranked)?;
+
+			let class = review.class;
+			let approved = review.tally.approval(class) >= T::ReviewApproval::get() &&
+				review.tally.support(class) >= T::ReviewSupport::get();
+			match wish {
+				Wish::Retention if approved && class == rank =>
+					Self::do_approve(who.clone(), rank)?,
+				Wish::Promotion if approved && rank.checked_add(1) == Some(class) =>
+					Self::do_promote(who.clone(), rank, class)?,
+				_ => Self::dispose_evidence(who.clone(), rank, Some(rank)),
+			}
+			if !approved {
+				// Otherwise a failed review could be reopened right away.
+				let cooldown_end = now.saturating_add(T::ReviewPeriod::get());
+				ReviewCooldown::<T, I>::insert(&who, cooldown_end);
+			}
+
+			Self::deposit_event(Event::<T, I>::ReviewConcluded { who, index, approved });
+			Ok(Pays::No.into())
+		}
 	}
 
 	impl<T: Config<I>, I: 'static> Pallet<T, I> {
@@ -541,7 +644,101 @@ pub mod pallet {
 			}
 		}
 
+		/// Prove `who` at their current rank `at_rank`.
+		fn do_approve(who: T::AccountId, at_rank: RankOf<T, I>) -> DispatchResult {
+			let mut member = Member::<T, I>::get(&who).ok_or(Error::<T, I>::NotTracked)?;
+
+			member.last_proof = frame_system::Pallet::<T>::block_number();
+			Member::<T, I>::insert(&who, &member);
+
+			Self::dispose_evidence(who.clone(), at_rank, Some(at_rank));
+			Self::deposit_event(Event::<T, I>::Proven { who, at_rank });
+
+			Ok(())
+		}
+
+		/// Promote `who` from their current rank `rank` to `to_rank`.
+		fn do_promote(
+			who: T::AccountId,
+			rank: RankOf<T, I>,
+			to_rank: RankOf<T, I>,
+		) -> DispatchResult {
+			let mut member = Member::<T, I>::get(&who).ok_or(Error::<T, I>::NotTracked)?;
+			let now = frame_system::Pallet::<T>::block_number();
+
+			let params = Params::<T, I>::get();
+			let rank_index = Self::rank_to_index(to_rank).ok_or(Error::<T, I>::InvalidRank)?;
+			let min_period = params.min_promotion_period[rank_index];
+			// Ensure enough time has passed.
+			ensure!(
+				member.last_promotion.saturating_add(min_period) <= now,
+				Error::<T, I>::TooSoon,
+			);
+
+			T::Members::promote(&who)?;
+			member.last_promotion = now;
+			member.last_proof = now;
+			Member::<T, I>::insert(&who, &member);
+			Self::dispose_evidence(who.clone(), rank, Some(to_rank));
+
+			Self::deposit_event(Event::<T, I>::Promoted { who, to_rank });
+
+			Ok(())
+		}
+
+		/// Open a review of the evidence `who` submitted for `wish`, unless reviews are disabled
+		/// or there is no rank at stake which could be voted on.
+		fn open_review(who: T::AccountId, wish: Wish) {
+			let period = T::ReviewPeriod::get();
+			if period.is_zero() {
+				return
+			}
+			let Some(rank) = T::Members::rank_of(&who) else { return };
+			let class = match wish {
+				Wish::Retention => rank,
+				Wish::Promotion => rank.saturating_add(1),
+			};
+			if Self::rank_to_index(class).is_some() {
+				Self::start_review(who, class, period);
+			}
+		}
+
+		/// Start a review of `who`'s evidence by members of at least rank `class`, lasting
+		/// `period` blocks. Any previous review of theirs is closed.
+		pub(crate) fn start_review(
+			who: T::AccountId,
+			class: RankOf<T, I>,
+			period: BlockNumberFor<T>,
+		) -> PollIndex {
+			Self::close_review(&who);
+			let index = ReviewCount::<T, I>::mutate(|count| {
+				let index = *count;
+				count.saturating_inc();
+				index
+			});
+			let end = frame_system::Pallet::<T>::block_number().saturating_add(period);
+			let tally = T::Tally::new(class);
+			Reviews::<T, I>::insert(index, ReviewStatus { who: who.clone(), class, tally, end });
+			MemberReview::<T, I>::insert(&who, index);
+			Self::deposit_event(Event::<T, I>::ReviewStarted { who, index, class });
+			index
+		}
+
+		/// Remove the review of `who`'s evidence, if any.
+		fn close_review(who: &T::AccountId) {
+			if let Some(index) = MemberReview::<T, I>::take(who) {
+				Reviews::<T, I>::remove(index);
+			}
+		}
+
+		/// The review with the given `index` if it may still be voted on.
+		fn ongoing_review(index: PollIndex) -> Option<ReviewOf<T, I>> {
+			let now = frame_system::Pallet::<T>::block_number();
+			Reviews::<T, I>::get(index).filter(|review| now < review.end)
+		}
+
 		fn dispose_evidence(who: T::AccountId, old_rank: u16, new_rank: Option<u16>) {
+			Self::close_review(&who);
 			if let Some((wish, evidence)) = MemberEvidence::<T, I>::take(&who) {
 				let e = Event::<T, I>::EvidenceJudged { who, wish, evidence, old_rank, new_rank };
 				Self::deposit_event(e);
@@ -565,6 +762,76 @@ pub mod pallet {
 			salary[index]
 		}
 	}
+
+	impl<T: Config<I>, I: 'static> Polling<T::Tally> for Pallet<T, I> {
+		type Index = PollIndex;
+		type Votes = u32;
+		type Class = RankOf<T, I>;
+		type Moment = BlockNumberFor<T>;
+
+		fn classes() -> Vec<Self::Class> {
+			(1..=RANK_COUNT as u16).collect()
+		}
+
+		fn as_ongoing(index: Self::Index) -> Option<(T::Tally, Self::Class)> {
+			Self::ongoing_review(index).map(|review| (review.tally, review.class))
+		}
+
+		fn access_poll<R>(
+			index: Self::Index,
+			f: impl FnOnce(PollStatus<&mut T::Tally, Self::Moment, Self::Class>) -> R,
+		) -> R {
+			match Self::ongoing_review(index) {
+				Some(mut review) => {
+					let result = f(PollStatus::Ongoing(&mut review.tally, review.class));
+					Reviews::<T, I>::insert(index, review);
+					result
+				},
+				None => f(PollStatus::None),
+			}
+		}
+
+		fn try_access_poll<R>(
+			index: Self::Index,
+			f: impl FnOnce(
+				PollStatus<&mut T::Tally, Self::Moment, Self::Class>,
+			) -> Result<R, DispatchError>,
+		) -> Result<R, DispatchError> {
+			match Self::ongoing_review(index) {
+				Some(mut review) => {
+					let result = f(PollStatus::Ongoing(&mut review.tally, review.class))?;
+					Reviews::<T, I>::insert(index, review);
+					Ok(result)
+				},
+				None => f(PollStatus::None),
+			}
+		}
+
+		#[cfg(feature = "runtime-benchmarks")]
+		fn create_ongoing(class: Self::Class) -> Result<Self::Index, ()> {
+			Self::rank_to_index(class).ok_or(())?;
+			let who = frame_benchmarking::account::<T::AccountId>(
+				"review",
+				ReviewCount::<T, I>::get(),
+				0,
+			);
+			let period = T::ReviewPeriod::get().max(1u32.into());
+			Ok(Self::start_review(who, class, period))
+		}
+
+		#[cfg(feature = "runtime-benchmarks")]
+		fn end_ongoing(index: Self::Index, approved: bool) -> Result<(), ()> {
+			let mut review = Self::ongoing_review(index).ok_or(())?;
+			review.tally = if approved {
+				T::Tally::unanimity(review.class)
+			} else {
+				T::Tally::rejection(review.class)
+			};
+			review.end = frame_system::Pallet::<T>::block_number();
+			Reviews::<T, I>::insert(index, review);
+			Ok(())
+		}
+	}
 }
 
 /// Guard to ensure that the given origin is inducted into this pallet with a given minimum rank.
@@ -0,0 +1,259 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration tests for evidence reviews voted on through the ranked collective.
+
+use frame_support::{
+	assert_noop, assert_ok, derive_impl, parameter_types,
+	traits::{ConstU16, ConstU32, ConstU64, Everything},
+};
+use frame_system::{EnsureRoot, EnsureRootWithSuccess};
+use pallet_ranked_collective::Geometric;
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, Identity, IdentityLookup},
+	BuildStorage,
+};
+
+use crate as pallet_core_fellowship;
+use crate::*;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		CoreFellowship: pallet_core_fellowship,
+		Club: pallet_ranked_collective,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
+impl frame_system::Config for Test {
+	type BaseCallFilter = Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type Nonce = u64;
+	type RuntimeCall = RuntimeCall;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Block = Block;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+parameter_types! {
+	pub const ReviewApproval: Perbill = Perbill::from_percent(50);
+	pub const ReviewSupport: Perbill = Perbill::from_percent(50);
+}
+
+impl Config for Test {
+	type WeightInfo = ();
+	type RuntimeEvent = RuntimeEvent;
+	type Members = Club;
+	type Balance = u64;
+	type ParamsOrigin = EnsureRoot<u64>;
+	type InductOrigin = EnsureInducted<Test, (), 1>;
+	type ApproveOrigin = EnsureRootWithSuccess<u64, ConstU16<9>>;
+	type PromoteOrigin = EnsureRootWithSuccess<u64, ConstU16<9>>;
+	type EvidenceSize = ConstU32<1024>;
+	type Tally = pallet_ranked_collective::TallyOf<Test>;
+	type ReviewPeriod = ConstU64<3>;
+	type ReviewApproval = ReviewApproval;
+	type ReviewSupport = ReviewSupport;
+}
+
+impl pallet_ranked_collective::Config for Test {
+	type WeightInfo = ();
+	type RuntimeEvent = RuntimeEvent;
+	type PromoteOrigin = EnsureRootWithSuccess<u64, ConstU16<65535>>;
+	type DemoteOrigin = EnsureRootWithSuccess<u64, ConstU16<65535>>;
+	type Polls = CoreFellowship;
+	type MinRankOfClass = Identity;
+	type VoteWeight = Geometric;
+}
+
+fn set_rank(who: u64, rank: u16) {
+	assert_ok!(Club::add_member(RuntimeOrigin::root(), who));
+	for _ in 0..rank {
+		assert_ok!(Club::promote_member(RuntimeOrigin::root(), who));
+	}
+}
+
+/// Members 1, 2 and 3 have rank 2; member 10 has rank 1 and is tracked by the pallet.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		for who in 1..=3 {
+			set_rank(who, 2);
+		}
+		set_rank(10, 1);
+		assert_ok!(CoreFellowship::import(signed(10)));
+	});
+	ext
+}
+
+fn run_to(n: u64) {
+	while System::block_number() < n {
+		System::set_block_number(System::block_number() + 1);
+	}
+}
+
+fn signed(who: u64) -> RuntimeOrigin {
+	RuntimeOrigin::signed(who)
+}
+
+fn evidence() -> Evidence<Test, ()> {
+	Evidence::<Test, ()>::try_from(vec![0; 8]).unwrap()
+}
+
+#[test]
+fn approved_review_promotes() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CoreFellowship::submit_evidence(signed(10), Wish::Promotion, evidence()));
+		System::assert_last_event(
+			Event::<Test>::ReviewStarted { who: 10, index: 0, class: 2 }.into(),
+		);
+
+		// Only members of at least the rank at stake may vote.
+		assert_noop!(
+			Club::vote(signed(10), 0, true),
+			pallet_ranked_collective::Error::<Test>::RankTooLow
+		);
+		assert_ok!(Club::vote(signed(1), 0, true));
+		assert_ok!(Club::vote(signed(2), 0, true));
+		assert_ok!(Club::vote(signed(3), 0, false));
+		assert_noop!(CoreFellowship::conclude_review(signed(3), 10), Error::<Test>::TooSoon);
+
+		run_to(4);
+		assert_noop!(
+			Club::vote(signed(3), 0, true),
+			pallet_ranked_collective::Error::<Test>::NotPolling
+		);
+		assert_ok!(CoreFellowship::conclude_review(signed(3), 10));
+		System::assert_last_event(
+			Event::<Test>::ReviewConcluded { who: 10, index: 0, approved: true }.into(),
+		);
+		assert_eq!(Club::rank_of(&10), Some(2));
+		assert_eq!(Member::<Test>::get(10).unwrap().last_promotion, 4);
+		assert!(!MemberEvidence::<Test>::contains_key(10));
+		assert!(!MemberReview::<Test>::contains_key(10));
+		assert!(!Reviews::<Test>::contains_key(0));
+
+		// The votes of the concluded review can be cleaned up.
+		assert_ok!(Club::cleanup_poll(signed(3), 0, 10));
+		assert_noop!(CoreFellowship::conclude_review(signed(3), 10), Error::<Test>::NoReview);
+	});
+}
+
+#[test]
+fn rejected_review_disposes_evidence() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CoreFellowship::submit_evidence(signed(10), Wish::Retention, evidence()));
+		// Members of rank 1 may vote on retention at rank 1, including the member themselves.
+		assert_ok!(Club::vote(signed(10), 0, true));
+
+		// The evidence cannot be replaced while it is under review.
+		run_to(2);
+		assert_noop!(
+			CoreFellowship::submit_evidence(signed(10), Wish::Promotion, evidence()),
+			Error::<Test>::UnderReview
+		);
+		assert_eq!(MemberReview::<Test>::get(10), Some(0));
+		assert_eq!(ReviewCount::<Test>::get(), 1);
+		let review = Reviews::<Test>::get(0).unwrap();
+		assert_eq!(review.end, 4);
+		assert_eq!(review.tally, pallet_ranked_collective::Tally::from_parts(1, 1, 0));
+
+		assert_ok!(Club::vote(signed(1), 0, false));
+		assert_ok!(Club::vote(signed(2), 0, false));
+
+		run_to(4);
+		assert_ok!(CoreFellowship::conclude_review(signed(1), 10));
+		System::assert_last_event(
+			Event::<Test>::ReviewConcluded { who: 10, index: 0, approved: false }.into(),
+		);
+		assert_eq!(Club::rank_of(&10), Some(1));
+		assert_eq!(Member::<Test>::get(10).unwrap().last_proof, 1);
+		assert!(!MemberEvidence::<Test>::contains_key(10));
+		assert!(!MemberReview::<Test>::contains_key(10));
+
+		// The review cannot be reopened before another review period.
+		assert_noop!(
+			CoreFellowship::submit_evidence(signed(10), Wish::Retention, evidence()),
+			Error::<Test>::TooSoon
+		);
+		run_to(7);
+		assert_ok!(CoreFellowship::submit_evidence(signed(10), Wish::Retention, evidence()));
+		assert_eq!(MemberReview::<Test>::get(10), Some(1));
+		assert!(!ReviewCooldown::<Test>::contains_key(10));
+	});
+}
+
+#[test]
+fn evidence_under_review_cannot_be_replaced() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CoreFellowship::submit_evidence(signed(10), Wish::Promotion, evidence()));
+		assert_noop!(
+			CoreFellowship::submit_evidence(signed(10), Wish::Retention, evidence()),
+			Error::<Test>::UnderReview
+		);
+
+		// Once the evidence is judged, new evidence may be submitted.
+		assert_ok!(CoreFellowship::approve(RuntimeOrigin::root(), 10, 1));
+		assert_ok!(CoreFellowship::submit_evidence(signed(10), Wish::Retention, evidence()));
+		assert_eq!(MemberReview::<Test>::get(10), Some(1));
+	});
+}
+
+#[test]
+fn judging_evidence_closes_review() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CoreFellowship::submit_evidence(signed(10), Wish::Retention, evidence()));
+		assert_ok!(CoreFellowship::approve(RuntimeOrigin::root(), 10, 1));
+		assert!(!MemberReview::<Test>::contains_key(10));
+		assert!(!Reviews::<Test>::contains_key(0));
+		assert_noop!(
+			Club::vote(signed(1), 0, true),
+			pallet_ranked_collective::Error::<Test>::NotPolling
+		);
+		assert_noop!(CoreFellowship::conclude_review(signed(1), 10), Error::<Test>::NoReview);
+
+		// There is no rank at stake for a candidate to retain.
+		assert_ok!(CoreFellowship::induct(RuntimeOrigin::root(), 20));
+		assert_ok!(CoreFellowship::submit_evidence(signed(20), Wish::Retention, evidence()));
+		assert!(!MemberReview::<Test>::contains_key(20));
+		assert_ok!(CoreFellowship::submit_evidence(signed(20), Wish::Promotion, evidence()));
+		assert_eq!(MemberReview::<Test>::get(20), Some(1));
+	});
+}
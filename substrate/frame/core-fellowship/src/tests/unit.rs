@@ -15,7 +15,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-//! The crate's tests.
+//! Unit tests for the core-fellowship pallet.
 
 use std::collections::BTreeMap;
 
@@ -33,8 +33,8 @@ use sp_runtime::{
 };
 use sp_std::cell::RefCell;
 
-use super::*;
 use crate as pallet_core_fellowship;
+use crate::*;
 
 type Block = frame_system::mocking::MockBlock<Test>;
 
@@ -125,8 +125,48 @@ fn unrank(who: u64) {
 	CLUB.with(|club| club.borrow_mut().remove(&who));
 }
 
+/// A tally in which each vote counts once, out of ten members eligible to vote on any class.
+#[derive(Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, Encode, Decode, MaxEncodedLen)]
+pub struct TestTally {
+	ayes: u32,
+	nays: u32,
+}
+
+impl VoteTally<u32, u16> for TestTally {
+	fn new(_: u16) -> Self {
+		Self { ayes: 0, nays: 0 }
+	}
+	fn ayes(&self, _: u16) -> u32 {
+		self.ayes
+	}
+	fn support(&self, _: u16) -> Perbill {
+		Perbill::from_rational(self.ayes, 10)
+	}
+	fn approval(&self, _: u16) -> Perbill {
+		Perbill::from_rational(self.ayes, 1.max(self.ayes + self.nays))
+	}
+	#[cfg(feature = "runtime-benchmarks")]
+	fn unanimity(_: u16) -> Self {
+		Self { ayes: 10, nays: 0 }
+	}
+	#[cfg(feature = "runtime-benchmarks")]
+	fn rejection(_: u16) -> Self {
+		Self { ayes: 0, nays: 10 }
+	}
+	#[cfg(feature = "runtime-benchmarks")]
+	fn from_requirements(support: Perbill, approval: Perbill, _: u16) -> Self {
+		let ayes = support * 10u32;
+		let nays = ((ayes as u64) * 1_000_000_000u64 / approval.deconstruct() as u64) as u32 - ayes;
+		Self { ayes, nays }
+	}
+	#[cfg(feature = "runtime-benchmarks")]
+	fn setup(_: u16, _: Perbill) {}
+}
+
 parameter_types! {
 	pub ZeroToNine: Vec<u64> = vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+	pub const ReviewApproval: Perbill = Perbill::from_percent(50);
+	pub const ReviewSupport: Perbill = Perbill::from_percent(20);
 }
 ord_parameter_types! {
 	pub const One: u64 = 1;
@@ -142,6 +182,10 @@ impl Config for Test {
 	type ApproveOrigin = TryMapSuccess<EnsureSignedBy<IsInVec<ZeroToNine>, u64>, TryMorphInto<u16>>;
 	type PromoteOrigin = TryMapSuccess<EnsureSignedBy<IsInVec<ZeroToNine>, u64>, TryMorphInto<u16>>;
 	type EvidenceSize = ConstU32<1024>;
+	type Tally = TestTally;
+	type ReviewPeriod = ConstU64<0>;
+	type ReviewApproval = ReviewApproval;
+	type ReviewSupport = ReviewSupport;
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
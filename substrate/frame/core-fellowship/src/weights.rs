@@ -62,6 +62,7 @@ pub trait WeightInfo {
 	fn import() -> Weight;
 	fn approve() -> Weight;
 	fn submit_evidence() -> Weight;
+	fn conclude_review() -> Weight;
 }
 
 /// Weights for pallet_core_fellowship using the Substrate node and recommended hardware.
@@ -89,14 +90,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: RankedCollective IdToIndex (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
 	/// Storage: CoreFellowship MemberEvidence (r:1 w:1)
 	/// Proof: CoreFellowship MemberEvidence (max_values: None, max_size: Some(16429), added: 18904, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship MemberReview (r:1 w:1)
+	/// Proof: CoreFellowship MemberReview (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship Reviews (r:0 w:1)
+	/// Proof: CoreFellowship Reviews (max_values: None, max_size: Some(62), added: 2537, mode: MaxEncodedLen)
 	fn bump_offboard() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `16887`
-		//  Estimated: `19894`
-		// Minimum execution time: 58_489_000 picoseconds.
+		// TODO: run benchmarks
 		Weight::from_parts(60_202_000, 19894)
-			.saturating_add(T::DbWeight::get().reads(6_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: CoreFellowship Member (r:1 w:1)
 	/// Proof: CoreFellowship Member (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
@@ -110,14 +112,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: RankedCollective IdToIndex (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
 	/// Storage: CoreFellowship MemberEvidence (r:1 w:1)
 	/// Proof: CoreFellowship MemberEvidence (max_values: None, max_size: Some(16429), added: 18904, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship MemberReview (r:1 w:1)
+	/// Proof: CoreFellowship MemberReview (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship Reviews (r:0 w:1)
+	/// Proof: CoreFellowship Reviews (max_values: None, max_size: Some(62), added: 2537, mode: MaxEncodedLen)
 	fn bump_demote() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `16997`
-		//  Estimated: `19894`
-		// Minimum execution time: 60_605_000 picoseconds.
+		// TODO: run benchmarks
 		Weight::from_parts(63_957_000, 19894)
-			.saturating_add(T::DbWeight::get().reads(6_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: RankedCollective Members (r:1 w:0)
 	/// Proof: RankedCollective Members (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
@@ -165,14 +168,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: RankedCollective IndexToId (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
 	/// Storage: RankedCollective IdToIndex (r:0 w:1)
 	/// Proof: RankedCollective IdToIndex (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship MemberReview (r:1 w:1)
+	/// Proof: CoreFellowship MemberReview (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship Reviews (r:0 w:1)
+	/// Proof: CoreFellowship Reviews (max_values: None, max_size: Some(62), added: 2537, mode: MaxEncodedLen)
 	fn promote() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `16865`
-		//  Estimated: `19894`
-		// Minimum execution time: 56_642_000 picoseconds.
+		// TODO: run benchmarks
 		Weight::from_parts(59_353_000, 19894)
-			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
 	}
 	/// Storage: RankedCollective Members (r:1 w:0)
 	/// Proof: RankedCollective Members (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
@@ -180,14 +184,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: CoreFellowship Member (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
 	/// Storage: CoreFellowship MemberEvidence (r:0 w:1)
 	/// Proof: CoreFellowship MemberEvidence (max_values: None, max_size: Some(16429), added: 18904, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship MemberReview (r:1 w:1)
+	/// Proof: CoreFellowship MemberReview (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship Reviews (r:0 w:1)
+	/// Proof: CoreFellowship Reviews (max_values: None, max_size: Some(62), added: 2537, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship ReviewCooldown (r:0 w:1)
+	/// Proof: CoreFellowship ReviewCooldown (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
 	fn offboard() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `359`
-		//  Estimated: `3514`
-		// Minimum execution time: 17_459_000 picoseconds.
-		Weight::from_parts(18_033_000, 3514)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+		// TODO: run benchmarks
+		Weight::from_parts(19_759_000, 3514)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: CoreFellowship Member (r:1 w:1)
 	/// Proof: CoreFellowship Member (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
@@ -208,27 +215,59 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: CoreFellowship Member (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
 	/// Storage: CoreFellowship MemberEvidence (r:1 w:1)
 	/// Proof: CoreFellowship MemberEvidence (max_values: None, max_size: Some(16429), added: 18904, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship MemberReview (r:1 w:1)
+	/// Proof: CoreFellowship MemberReview (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship Reviews (r:0 w:1)
+	/// Proof: CoreFellowship Reviews (max_values: None, max_size: Some(62), added: 2537, mode: MaxEncodedLen)
 	fn approve() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `16843`
-		//  Estimated: `19894`
-		// Minimum execution time: 41_487_000 picoseconds.
+		// TODO: run benchmarks
 		Weight::from_parts(43_459_000, 19894)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: CoreFellowship Member (r:1 w:0)
 	/// Proof: CoreFellowship Member (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
 	/// Storage: CoreFellowship MemberEvidence (r:1 w:1)
 	/// Proof: CoreFellowship MemberEvidence (max_values: None, max_size: Some(16429), added: 18904, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship MemberReview (r:1 w:1)
+	/// Proof: CoreFellowship MemberReview (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship Reviews (r:0 w:1)
+	/// Proof: CoreFellowship Reviews (max_values: None, max_size: Some(62), added: 2537, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship ReviewCount (r:1 w:1)
+	/// Proof: CoreFellowship ReviewCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship ReviewCooldown (r:1 w:1)
+	/// Proof: CoreFellowship ReviewCooldown (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
 	fn submit_evidence() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `79`
-		//  Estimated: `19894`
-		// Minimum execution time: 26_033_000 picoseconds.
-		Weight::from_parts(26_612_000, 19894)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		// TODO: run benchmarks
+		Weight::from_parts(28_766_000, 19894)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: RankedCollective Members (r:1 w:1)
+	/// Proof: RankedCollective Members (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship Member (r:1 w:1)
+	/// Proof: CoreFellowship Member (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship Params (r:1 w:0)
+	/// Proof: CoreFellowship Params (max_values: Some(1), max_size: Some(364), added: 859, mode: MaxEncodedLen)
+	/// Storage: RankedCollective MemberCount (r:1 w:1)
+	/// Proof: RankedCollective MemberCount (max_values: None, max_size: Some(14), added: 2489, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship MemberEvidence (r:1 w:1)
+	/// Proof: CoreFellowship MemberEvidence (max_values: None, max_size: Some(16429), added: 18904, mode: MaxEncodedLen)
+	/// Storage: RankedCollective IndexToId (r:0 w:1)
+	/// Proof: RankedCollective IndexToId (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
+	/// Storage: RankedCollective IdToIndex (r:0 w:1)
+	/// Proof: RankedCollective IdToIndex (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship MemberReview (r:1 w:1)
+	/// Proof: CoreFellowship MemberReview (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship Reviews (r:1 w:1)
+	/// Proof: CoreFellowship Reviews (max_values: None, max_size: Some(62), added: 2537, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship ReviewCooldown (r:0 w:1)
+	/// Proof: CoreFellowship ReviewCooldown (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	fn conclude_review() -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(61_165_000, 19894)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
 	}
 }
 
@@ -256,14 +295,15 @@ impl WeightInfo for () {
 	/// Proof: RankedCollective IdToIndex (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
 	/// Storage: CoreFellowship MemberEvidence (r:1 w:1)
 	/// Proof: CoreFellowship MemberEvidence (max_values: None, max_size: Some(16429), added: 18904, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship MemberReview (r:1 w:1)
+	/// Proof: CoreFellowship MemberReview (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship Reviews (r:0 w:1)
+	/// Proof: CoreFellowship Reviews (max_values: None, max_size: Some(62), added: 2537, mode: MaxEncodedLen)
 	fn bump_offboard() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `16887`
-		//  Estimated: `19894`
-		// Minimum execution time: 58_489_000 picoseconds.
+		// TODO: run benchmarks
 		Weight::from_parts(60_202_000, 19894)
-			.saturating_add(RocksDbWeight::get().reads(6_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	/// Storage: CoreFellowship Member (r:1 w:1)
 	/// Proof: CoreFellowship Member (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
@@ -277,14 +317,15 @@ impl WeightInfo for () {
 	/// Proof: RankedCollective IdToIndex (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
 	/// Storage: CoreFellowship MemberEvidence (r:1 w:1)
 	/// Proof: CoreFellowship MemberEvidence (max_values: None, max_size: Some(16429), added: 18904, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship MemberReview (r:1 w:1)
+	/// Proof: CoreFellowship MemberReview (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship Reviews (r:0 w:1)
+	/// Proof: CoreFellowship Reviews (max_values: None, max_size: Some(62), added: 2537, mode: MaxEncodedLen)
 	fn bump_demote() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `16997`
-		//  Estimated: `19894`
-		// Minimum execution time: 60_605_000 picoseconds.
+		// TODO: run benchmarks
 		Weight::from_parts(63_957_000, 19894)
-			.saturating_add(RocksDbWeight::get().reads(6_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	/// Storage: RankedCollective Members (r:1 w:0)
 	/// Proof: RankedCollective Members (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
@@ -332,14 +373,15 @@ impl WeightInfo for () {
 	/// Proof: RankedCollective IndexToId (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
 	/// Storage: RankedCollective IdToIndex (r:0 w:1)
 	/// Proof: RankedCollective IdToIndex (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship MemberReview (r:1 w:1)
+	/// Proof: CoreFellowship MemberReview (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship Reviews (r:0 w:1)
+	/// Proof: CoreFellowship Reviews (max_values: None, max_size: Some(62), added: 2537, mode: MaxEncodedLen)
 	fn promote() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `16865`
-		//  Estimated: `19894`
-		// Minimum execution time: 56_642_000 picoseconds.
+		// TODO: run benchmarks
 		Weight::from_parts(59_353_000, 19894)
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
 	}
 	/// Storage: RankedCollective Members (r:1 w:0)
 	/// Proof: RankedCollective Members (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
@@ -347,14 +389,17 @@ impl WeightInfo for () {
 	/// Proof: CoreFellowship Member (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
 	/// Storage: CoreFellowship MemberEvidence (r:0 w:1)
 	/// Proof: CoreFellowship MemberEvidence (max_values: None, max_size: Some(16429), added: 18904, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship MemberReview (r:1 w:1)
+	/// Proof: CoreFellowship MemberReview (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship Reviews (r:0 w:1)
+	/// Proof: CoreFellowship Reviews (max_values: None, max_size: Some(62), added: 2537, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship ReviewCooldown (r:0 w:1)
+	/// Proof: CoreFellowship ReviewCooldown (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
 	fn offboard() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `359`
-		//  Estimated: `3514`
-		// Minimum execution time: 17_459_000 picoseconds.
-		Weight::from_parts(18_033_000, 3514)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+		// TODO: run benchmarks
+		Weight::from_parts(19_759_000, 3514)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: CoreFellowship Member (r:1 w:1)
 	/// Proof: CoreFellowship Member (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
@@ -375,26 +420,58 @@ impl WeightInfo for () {
 	/// Proof: CoreFellowship Member (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
 	/// Storage: CoreFellowship MemberEvidence (r:1 w:1)
 	/// Proof: CoreFellowship MemberEvidence (max_values: None, max_size: Some(16429), added: 18904, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship MemberReview (r:1 w:1)
+	/// Proof: CoreFellowship MemberReview (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship Reviews (r:0 w:1)
+	/// Proof: CoreFellowship Reviews (max_values: None, max_size: Some(62), added: 2537, mode: MaxEncodedLen)
 	fn approve() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `16843`
-		//  Estimated: `19894`
-		// Minimum execution time: 41_487_000 picoseconds.
+		// TODO: run benchmarks
 		Weight::from_parts(43_459_000, 19894)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: CoreFellowship Member (r:1 w:0)
 	/// Proof: CoreFellowship Member (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
 	/// Storage: CoreFellowship MemberEvidence (r:1 w:1)
 	/// Proof: CoreFellowship MemberEvidence (max_values: None, max_size: Some(16429), added: 18904, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship MemberReview (r:1 w:1)
+	/// Proof: CoreFellowship MemberReview (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship Reviews (r:0 w:1)
+	/// Proof: CoreFellowship Reviews (max_values: None, max_size: Some(62), added: 2537, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship ReviewCount (r:1 w:1)
+	/// Proof: CoreFellowship ReviewCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship ReviewCooldown (r:1 w:1)
+	/// Proof: CoreFellowship ReviewCooldown (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
 	fn submit_evidence() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `79`
-		//  Estimated: `19894`
-		// Minimum execution time: 26_033_000 picoseconds.
-		Weight::from_parts(26_612_000, 19894)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		// TODO: run benchmarks
+		Weight::from_parts(28_766_000, 19894)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: RankedCollective Members (r:1 w:1)
+	/// Proof: RankedCollective Members (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship Member (r:1 w:1)
+	/// Proof: CoreFellowship Member (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship Params (r:1 w:0)
+	/// Proof: CoreFellowship Params (max_values: Some(1), max_size: Some(364), added: 859, mode: MaxEncodedLen)
+	/// Storage: RankedCollective MemberCount (r:1 w:1)
+	/// Proof: RankedCollective MemberCount (max_values: None, max_size: Some(14), added: 2489, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship MemberEvidence (r:1 w:1)
+	/// Proof: CoreFellowship MemberEvidence (max_values: None, max_size: Some(16429), added: 18904, mode: MaxEncodedLen)
+	/// Storage: RankedCollective IndexToId (r:0 w:1)
+	/// Proof: RankedCollective IndexToId (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
+	/// Storage: RankedCollective IdToIndex (r:0 w:1)
+	/// Proof: RankedCollective IdToIndex (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship MemberReview (r:1 w:1)
+	/// Proof: CoreFellowship MemberReview (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship Reviews (r:1 w:1)
+	/// Proof: CoreFellowship Reviews (max_values: None, max_size: Some(62), added: 2537, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship ReviewCooldown (r:0 w:1)
+	/// Proof: CoreFellowship ReviewCooldown (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	fn conclude_review() -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(61_165_000, 19894)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
 	}
 }
@@ -151,6 +151,41 @@
 //! See the [`staking-miner`](https://github.com/paritytech/staking-miner-v2) docs for more
 //! information.
 //!
+//! ## Paged Elections
+//!
+//! If [`pallet::Config::Pages`] is greater than one, the voters of the snapshot are fetched from
+//! the data provider in pages, one per block, in the blocks right before the snapshot is created.
+//! Each page is subject to the voter bounds of [`pallet::Config::ElectionBounds`] and stored on its
+//! own, page `0` being part of [`Snapshot`] and the others in [`PagedVoterSnapshot`].
+//!
+//! The offchain miner computes the election over the voters of all pages, and submits one
+//! [`Call::submit_unsigned_page`] per page, which only assigns the voters of that page. Every page
+//! is checked for feasibility against its page of the snapshot, and its supports are staged on
+//! their own in [`StagedSolutionPages`]. The score that all pages claim is checked once all of them
+//! are submitted, against the backings of all pages together, see [`PagedSolution`]. Only then does
+//! the staged solution replace the one in [`QueuedPagedSolution`], which is always complete.
+//!
+//! In the signed phase, [`Call::submit_page`] submits the same pages, and checks them on the spot
+//! as well. A deposit is reserved for each page, and the score of a submission is checked once all
+//! of its pages are there. If it is wrong, the deposits are slashed. Otherwise, the submission
+//! replaces the queued solution if it is better, and its submitter is rewarded at the end of the
+//! signed phase. Submissions that are not complete by then are slashed.
+//!
+//! The result of the election is served in pages through [`ElectionProvider::elect_paged`], each
+//! page holding the backings of the voters of the same page of the snapshot, such that the data
+//! provider can process it over several blocks. If no complete paged solution is queued, the
+//! result of the emergency phase or of the fallback is served in its entirety as the first page.
+//!
+//! Paged elections have some limitations:
+//!
+//! - [`Call::submit`] and [`Call::submit_unsigned`] are not accepted, only [`Call::submit_page`]
+//!   and [`Call::submit_unsigned_page`].
+//! - The call fees of paged signed submissions are not refunded.
+//! - Paged solutions are not cached by the offchain worker, they are mined again whenever they are
+//!   resubmitted.
+//! - The unsigned phase is closed as soon as the first page of the result is requested, which is
+//!   earlier than the block of the election for a data provider that fetches one page per block.
+//!
 //! ## Feasible Solution (correct solution)
 //!
 //! All submissions must undergo a feasibility check. Signed solutions are checked one by one at the
@@ -232,23 +267,26 @@
 use codec::{Decode, Encode};
 use frame_election_provider_support::{
 	bounds::{CountBound, ElectionBounds, ElectionBoundsBuilder, SizeBound},
-	BoundedSupportsOf, DataProviderBounds, ElectionDataProvider, ElectionProvider,
-	ElectionProviderBase, InstantElectionProvider, NposSolution,
+	data_provider, BoundedSupportsOf, DataProviderBounds, ElectionDataProvider, ElectionProvider,
+	ElectionProviderBase, InstantElectionProvider, NposSolution, PageIndex,
 };
 use frame_support::{
 	dispatch::DispatchClass,
 	ensure,
-	traits::{Currency, DefensiveResult, Get, OnUnbalanced, ReservableCurrency},
+	traits::{Currency, Defensive, DefensiveResult, Get, OnUnbalanced, ReservableCurrency},
 	weights::Weight,
-	DefaultNoBound, EqNoBound, PartialEqNoBound,
+	BoundedVec, DefaultNoBound, EqNoBound, PartialEqNoBound,
 };
 use frame_system::{ensure_none, offchain::SendTransactionTypes, pallet_prelude::BlockNumberFor};
 use scale_info::TypeInfo;
 use sp_arithmetic::{
-	traits::{CheckedAdd, Zero},
+	traits::{CheckedAdd, Saturating, Zero},
 	UpperOf,
 };
-use sp_npos_elections::{BoundedSupports, ElectionScore, IdentifierT, Supports, VoteWeight};
+use sp_npos_elections::{
+	BoundedSupports, ElectionScore, EvaluateSupport, ExtendedBalance, IdentifierT, Support,
+	Supports, VoteWeight,
+};
 use sp_runtime::{
 	transaction_validity::{
 		InvalidTransaction, TransactionPriority, TransactionSource, TransactionValidity,
@@ -276,8 +314,9 @@ pub mod unsigned;
 pub mod weights;
 
 pub use signed::{
-	BalanceOf, GeometricDepositBase, NegativeImbalanceOf, PositiveImbalanceOf, SignedSubmission,
-	SignedSubmissionOf, SignedSubmissions, SubmissionIndicesOf,
+	BalanceOf, GeometricDepositBase, NegativeImbalanceOf, PositiveImbalanceOf,
+	SignedPagedSubmission, SignedPagedSubmissionOf, SignedSubmission, SignedSubmissionOf,
+	SignedSubmissions, SubmissionIndicesOf,
 };
 use unsigned::VoterOf;
 pub use unsigned::{Miner, MinerConfig};
@@ -450,6 +489,75 @@ where
 	pub compute: ElectionCompute,
 }
 
+/// The progress of a paged solution, see [`pallet::Config::Pages`].
+///
+/// The pages of a paged solution are submitted and checked one by one. The score claimed by all
+/// of them is only checked once every page is there, against the backings of all pages together.
+#[derive(
+	PartialEqNoBound,
+	EqNoBound,
+	Clone,
+	Encode,
+	Decode,
+	RuntimeDebug,
+	DefaultNoBound,
+	scale_info::TypeInfo,
+)]
+#[scale_info(skip_type_params(AccountId, MaxWinners))]
+pub struct PagedSolution<AccountId, MaxWinners>
+where
+	AccountId: IdentifierT,
+	MaxWinners: Get<u32>,
+{
+	/// The score claimed by every page of the solution.
+	pub score: ElectionScore,
+	/// How this election was computed.
+	pub compute: ElectionCompute,
+	/// The number of pages that have been checked so far.
+	pub checked_pages: PageIndex,
+	/// The total backing of each winner in the pages checked so far.
+	pub backings: BoundedVec<(AccountId, ExtendedBalance), MaxWinners>,
+	/// Whether a previously queued solution was ejected to make room for this one.
+	pub prev_ejected: bool,
+}
+
+impl<AccountId: IdentifierT, MaxWinners: Get<u32>> PagedSolution<AccountId, MaxWinners> {
+	/// Add the backings of a checked page of the solution.
+	///
+	/// Fails if the pages checked so far elect more than `desired_targets` winners.
+	pub fn add_page(
+		&mut self,
+		supports: &[(AccountId, Support<AccountId>)],
+		desired_targets: u32,
+	) -> Result<(), FeasibilityError> {
+		for (winner, support) in supports {
+			match self.backings.iter_mut().find(|(who, _)| who == winner) {
+				Some((_, backing)) => *backing = backing.saturating_add(support.total),
+				None => self
+					.backings
+					.try_push((winner.clone(), support.total))
+					.map_err(|_| FeasibilityError::WrongWinnerCount)?,
+			}
+		}
+		ensure!(self.backings.len() as u32 <= desired_targets, FeasibilityError::WrongWinnerCount);
+		self.checked_pages.saturating_inc();
+		Ok(())
+	}
+
+	/// Check the claimed score against the backings of all pages, once all of them are checked.
+	pub fn check_score(&self, desired_targets: u32) -> Result<(), FeasibilityError> {
+		ensure!(self.backings.len() as u32 == desired_targets, FeasibilityError::WrongWinnerCount);
+		let known_score = self
+			.backings
+			.iter()
+			.map(|(winner, total)| (winner.clone(), Support { total: *total, voters: vec![] }))
+			.collect::<Supports<_>>()
+			.evaluate();
+		ensure!(known_score == self.score, FeasibilityError::InvalidScore);
+		Ok(())
+	}
+}
+
 /// A snapshot of all the data that is needed for en entire round. They are provided by
 /// [`ElectionDataProvider`] and are kept around until the round is finished.
 ///
@@ -592,6 +700,19 @@ pub mod pallet {
 		#[pallet::constant]
 		type SignedPhase: Get<BlockNumberFor<Self>>;
 
+		/// The number of pages the voter snapshot, the unsigned solutions and the election result
+		/// are split into.
+		///
+		/// With more than one page, the pages of the voter snapshot are fetched from the data
+		/// provider in the `Pages - 1` blocks before the snapshot is created, each of them holding
+		/// up to the voter bounds of [`Config::ElectionBounds`]. Unsigned solutions are submitted
+		/// and checked page by page, and the election result is served in pages, the first of
+		/// which is requested `Pages - 1` blocks before the election is predicted. Hence, the
+		/// unsigned phase should be longer than `Pages`. Signed solutions are submitted page by
+		/// page as well.
+		#[pallet::constant]
+		type Pages: Get<PageIndex>;
+
 		/// The minimum amount of improvement to the solution score that defines a solution as
 		/// "better" in the Signed phase.
 		#[pallet::constant]
@@ -812,6 +933,8 @@ pub mod pallet {
 						T::WeightInfo::on_initialize_open_unsigned()
 					}
 				},
+				Phase::Off if T::Pages::get() > 1 => Self::fetch_voter_page(remaining)
+					.saturating_add(T::WeightInfo::on_initialize_nothing()),
 				_ => T::WeightInfo::on_initialize_nothing(),
 			}
 		}
@@ -1008,6 +1131,8 @@ pub mod pallet {
 		///
 		/// A deposit is reserved and recorded for the solution. Based on the outcome, the solution
 		/// might be rewarded, slashed, or get all or a part of the deposit back.
+		///
+		/// Not accepted if [`Config::Pages`] is greater than one, see [`Call::submit_page`].
 		#[pallet::call_index(3)]
 		#[pallet::weight(T::WeightInfo::submit())]
 		pub fn submit(
@@ -1020,6 +1145,9 @@ pub mod pallet {
 			ensure!(Self::current_phase().is_signed(), Error::<T>::PreDispatchEarlySubmission);
 			ensure!(raw_solution.round == Self::round(), Error::<T>::PreDispatchDifferentRound);
 
+			// paged elections only accept paged solutions.
+			ensure!(T::Pages::get() <= 1, Error::<T>::PreDispatchWrongPage);
+
 			// NOTE: this is the only case where having separate snapshot would have been better
 			// because could do just decode_len. But we can create abstractions to do this.
 
@@ -1126,6 +1254,185 @@ pub mod pallet {
 			<QueuedSolution<T>>::put(solution);
 			Ok(())
 		}
+
+		/// Submit a page of a paged solution for the unsigned phase.
+		///
+		/// The dispatch origin fo this call must be __none__.
+		///
+		/// Only accepted if [`Config::Pages`] is greater than one, in place of
+		/// [`Call::submit_unsigned`]. The solution of a page only assigns the voters of the same
+		/// page of the snapshot, and claims the score of all pages together. The pages are staged
+		/// until all of them are there, and only then replace the queued solution, which their
+		/// score must improve on. Pages of a solution with a different claimed score than the
+		/// staged one replace it, if the score is better.
+		///
+		/// Like [`Call::submit_unsigned`], every page is checked on the fly, and this transaction
+		/// will panic if the page is invalid in any way. So will the last page of a solution, if
+		/// the claimed score does not match the backings of all pages.
+		///
+		/// No deposit or reward is associated with this submission.
+		#[pallet::call_index(5)]
+		#[pallet::weight((
+			T::WeightInfo::submit_unsigned(
+				witness.voters,
+				witness.targets,
+				raw_solution.solution.voter_count() as u32,
+				raw_solution.solution.unique_targets().len() as u32
+			)
+			.saturating_add(T::DbWeight::get().reads_writes(4, 2))
+			.saturating_add(Pallet::<T>::queue_paged_solution_weight()),
+			DispatchClass::Operational,
+		))]
+		pub fn submit_unsigned_page(
+			origin: OriginFor<T>,
+			page: PageIndex,
+			raw_solution: Box<RawSolution<SolutionOf<T::MinerConfig>>>,
+			witness: SolutionOrSnapshotSize,
+		) -> DispatchResult {
+			ensure_none(origin)?;
+			let error_message = "Invalid unsigned submission must produce invalid block and \
+				 deprive validator from their authoring reward.";
+
+			// Check page, score being an improvement, phase, and desired targets.
+			Self::unsigned_paged_pre_dispatch_checks(page, &raw_solution).expect(error_message);
+
+			// Ensure witness was correct.
+			let snapshot = Self::snapshot_page(page).expect(error_message);
+
+			// NOTE: we are asserting, not `ensure`ing -- we want to panic here.
+			assert!(snapshot.voters.len() as u32 == witness.voters, "{}", error_message);
+			assert!(snapshot.targets.len() as u32 == witness.targets, "{}", error_message);
+
+			let score = raw_solution.score;
+			let supports =
+				Self::feasibility_check_page(*raw_solution, snapshot).expect(error_message);
+			let solution = Self::stage_solution_page(page, score, supports).expect(error_message);
+
+			// Store the newly received page.
+			log!(info, "staged page {} of unsigned solution with score {:?}", page, score);
+			if solution.checked_pages == T::Pages::get() {
+				Self::deposit_event(Event::SolutionStored {
+					compute: ElectionCompute::Unsigned,
+					origin: None,
+					prev_ejected: solution.prev_ejected,
+				});
+			}
+
+			Ok(())
+		}
+
+		/// Submit a page of a paged solution for the signed phase.
+		///
+		/// The dispatch origin fo this call must be __signed__.
+		///
+		/// Only accepted if [`Config::Pages`] is greater than one, in place of [`Call::submit`].
+		/// Like [`Call::submit_unsigned_page`], the solution of a page only assigns the voters of
+		/// the same page of the snapshot, and claims the score of all pages together. Every page
+		/// is checked on the spot, and a deposit is reserved for it.
+		///
+		/// Once all pages of a submission are there, its score is checked. If it is wrong, all
+		/// deposits of the submission are slashed. Otherwise, the submission replaces the queued
+		/// solution if it is better, or gets its deposits back if it is not anymore. The submitter
+		/// of the queued solution is rewarded at the end of the signed phase, while submissions
+		/// that are incomplete by then are slashed.
+		#[pallet::call_index(6)]
+		#[pallet::weight(
+			T::WeightInfo::submit()
+				.saturating_add(T::WeightInfo::submit_unsigned(
+					witness.voters,
+					witness.targets,
+					raw_solution.solution.voter_count() as u32,
+					raw_solution.solution.unique_targets().len() as u32
+				))
+				.saturating_add(T::DbWeight::get().reads_writes(4, 3))
+				.saturating_add(Pallet::<T>::queue_paged_solution_weight())
+		)]
+		pub fn submit_page(
+			origin: OriginFor<T>,
+			page: PageIndex,
+			raw_solution: Box<RawSolution<SolutionOf<T::MinerConfig>>>,
+			witness: SolutionOrSnapshotSize,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			// ensure solution is timely.
+			ensure!(Self::current_phase().is_signed(), Error::<T>::PreDispatchEarlySubmission);
+			ensure!(raw_solution.round == Self::round(), Error::<T>::PreDispatchDifferentRound);
+
+			// ensure the page exists.
+			let pages = T::Pages::get();
+			ensure!(pages > 1 && page < pages, Error::<T>::PreDispatchWrongPage);
+
+			// ensure the page is missing from the submission of `who`, and claims its score. A new
+			// submission must improve on the score of the queued solution.
+			let mut submission = match <SignedPagedSubmissions<T>>::get(&who) {
+				Some(submission) => {
+					ensure!(
+						submission.solution.score == raw_solution.score &&
+							!<SignedSubmissionPages<T>>::contains_key(&who, page),
+						Error::<T>::PreDispatchWrongPage,
+					);
+					submission
+				},
+				None => {
+					let threshold = T::BetterSignedThreshold::get();
+					ensure!(
+						<QueuedPagedSolution<T>>::get().map_or(true, |queued| {
+							raw_solution.score.strict_threshold_better(queued.score, threshold)
+						}),
+						Error::<T>::PreDispatchWeakSubmission,
+					);
+					ensure!(
+						<SignedPagedSubmissions<T>>::count() < T::SignedMaxSubmissions::get(),
+						Error::<T>::SignedQueueFull,
+					);
+					SignedPagedSubmission {
+						solution: PagedSolution {
+							score: raw_solution.score,
+							compute: ElectionCompute::Signed,
+							..Default::default()
+						},
+						deposit: Zero::zero(),
+					}
+				},
+			};
+
+			// unlikely to ever return an error: if phase is signed, snapshot will exist.
+			let snapshot = Self::snapshot_page(page).ok_or(Error::<T>::MissingSnapshotMetadata)?;
+			let desired_targets =
+				Self::desired_targets().ok_or(Error::<T>::MissingSnapshotMetadata)?;
+			ensure!(
+				snapshot.voters.len() as u32 == witness.voters &&
+					snapshot.targets.len() as u32 == witness.targets,
+				Error::<T>::SignedInvalidWitness,
+			);
+			ensure!(
+				Self::solution_weight_of(&raw_solution, witness).all_lt(T::SignedMaxWeight::get()),
+				Error::<T>::SignedTooMuchWeight,
+			);
+
+			let first_page = submission.solution.checked_pages.is_zero();
+			let deposit = Self::deposit_for_page(&raw_solution, witness, first_page);
+			let supports = Self::feasibility_check_page(*raw_solution, snapshot)
+				.map_err(|_| Error::<T>::SignedInvalidPage)?;
+			submission
+				.solution
+				.add_page(&supports, desired_targets)
+				.map_err(|_| Error::<T>::SignedInvalidPage)?;
+
+			// collect deposit. Thereafter, the function cannot fail.
+			T::Currency::reserve(&who, deposit).map_err(|_| Error::<T>::SignedCannotPayDeposit)?;
+			submission.deposit = submission.deposit.saturating_add(deposit);
+			<SignedSubmissionPages<T>>::insert(&who, page, supports);
+
+			if submission.solution.checked_pages < pages {
+				<SignedPagedSubmissions<T>>::insert(&who, submission);
+			} else {
+				<SignedPagedSubmissions<T>>::remove(&who);
+				Self::finalize_signed_paged_submission(who, submission, desired_targets);
+			}
+			Ok(())
+		}
 	}
 
 	#[pallet::event]
@@ -1194,53 +1501,71 @@ pub mod pallet {
 		TooManyWinners,
 		/// Sumission was prepared for a different round.
 		PreDispatchDifferentRound,
+		/// The page of a paged submission is out of bounds or already queued, or the submission
+		/// is not paged while the election is, or vice versa. The pages of a signed submission
+		/// must also claim the same score.
+		PreDispatchWrongPage,
+		/// The page of a paged signed submission is not feasible.
+		SignedInvalidPage,
 	}
 
 	#[pallet::validate_unsigned]
 	impl<T: Config> ValidateUnsigned for Pallet<T> {
 		type Call = Call<T>;
 		fn validate_unsigned(source: TransactionSource, call: &Self::Call) -> TransactionValidity {
-			if let Call::submit_unsigned { raw_solution, .. } = call {
-				// Discard solution not coming from the local OCW.
-				match source {
-					TransactionSource::Local | TransactionSource::InBlock => { /* allowed */ },
-					_ => return InvalidTransaction::Call.into(),
-				}
-
-				let _ = Self::unsigned_pre_dispatch_checks(raw_solution)
-					.map_err(|err| {
-						log!(debug, "unsigned transaction validation failed due to {:?}", err);
-						err
-					})
-					.map_err(dispatch_error_to_invalid)?;
+			let (raw_solution, maybe_page) = match call {
+				Call::submit_unsigned { raw_solution, .. } => (raw_solution, None),
+				Call::submit_unsigned_page { page, raw_solution, .. } =>
+					(raw_solution, Some(*page)),
+				_ => return InvalidTransaction::Call.into(),
+			};
 
-				ValidTransaction::with_tag_prefix("OffchainElection")
-					// The higher the score.minimal_stake, the better a solution is.
-					.priority(
-						T::MinerTxPriority::get()
-							.saturating_add(raw_solution.score.minimal_stake.saturated_into()),
-					)
-					// Used to deduplicate unsigned solutions: each validator should produce one
-					// solution per round at most, and solutions are not propagate.
-					.and_provides(raw_solution.round)
-					// Transaction should stay in the pool for the duration of the unsigned phase.
-					.longevity(T::UnsignedPhase::get().saturated_into::<u64>())
-					// We don't propagate this. This can never be validated at a remote node.
-					.propagate(false)
-					.build()
-			} else {
-				InvalidTransaction::Call.into()
+			// Discard solution not coming from the local OCW.
+			match source {
+				TransactionSource::Local | TransactionSource::InBlock => { /* allowed */ },
+				_ => return InvalidTransaction::Call.into(),
 			}
+
+			let _ = Self::unsigned_checks_of(raw_solution, maybe_page)
+				.map_err(|err| {
+					log!(debug, "unsigned transaction validation failed due to {:?}", err);
+					err
+				})
+				.map_err(dispatch_error_to_invalid)?;
+
+			let builder = ValidTransaction::with_tag_prefix("OffchainElection")
+				// The higher the score.minimal_stake, the better a solution is.
+				.priority(
+					T::MinerTxPriority::get()
+						.saturating_add(raw_solution.score.minimal_stake.saturated_into()),
+				);
+			let builder = match maybe_page {
+				// Used to deduplicate unsigned solutions: each validator should produce one
+				// solution per round at most, and solutions are not propagate.
+				None => builder.and_provides(raw_solution.round),
+				// Likewise, but for each page of a paged solution.
+				Some(page) => builder.and_provides((raw_solution.round, page)),
+			};
+
+			builder
+				// Transaction should stay in the pool for the duration of the unsigned phase.
+				.longevity(T::UnsignedPhase::get().saturated_into::<u64>())
+				// We don't propagate this. This can never be validated at a remote node.
+				.propagate(false)
+				.build()
 		}
 
 		fn pre_dispatch(call: &Self::Call) -> Result<(), TransactionValidityError> {
-			if let Call::submit_unsigned { raw_solution, .. } = call {
-				Self::unsigned_pre_dispatch_checks(raw_solution)
-					.map_err(dispatch_error_to_invalid)
-					.map_err(Into::into)
-			} else {
-				Err(InvalidTransaction::Call.into())
-			}
+			let (raw_solution, maybe_page) = match call {
+				Call::submit_unsigned { raw_solution, .. } => (raw_solution, None),
+				Call::submit_unsigned_page { page, raw_solution, .. } =>
+					(raw_solution, Some(*page)),
+				_ => return Err(InvalidTransaction::Call.into()),
+			};
+
+			Self::unsigned_checks_of(raw_solution, maybe_page)
+				.map_err(dispatch_error_to_invalid)
+				.map_err(Into::into)
 		}
 	}
 
@@ -1290,12 +1615,53 @@ pub mod pallet {
 
 	/// The metadata of the [`RoundSnapshot`]
 	///
+	/// With more than one page, `voters` only counts the voters of page `0`, which are the ones
+	/// in [`Snapshot`].
+	///
 	/// Only exists when [`Snapshot`] is present.
 	/// Note: This storage type must only be mutated through [`SnapshotWrapper`].
 	#[pallet::storage]
 	#[pallet::getter(fn snapshot_metadata)]
 	pub type SnapshotMetadata<T: Config> = StorageValue<_, SolutionOrSnapshotSize>;
 
+	/// The voters of the pages `1` to `T::Pages - 1` of the snapshot, page `0` being part of
+	/// [`Snapshot`].
+	///
+	/// Pages are fetched from `T::Pages - 1` downwards, partially ahead of the snapshot creation,
+	/// and are kept around until the round is finished, like [`Snapshot`].
+	#[pallet::storage]
+	pub type PagedVoterSnapshot<T: Config> =
+		StorageMap<_, Twox64Concat, PageIndex, Vec<VoterOf<T>>, OptionQuery>;
+
+	/// The best complete paged solution, whose pages are in [`QueuedSolutionPages`].
+	///
+	/// Only used if `T::Pages` is greater than one, in place of [`QueuedSolution`]. All pages of
+	/// this solution have been checked, and so has its score.
+	#[pallet::storage]
+	pub type QueuedPagedSolution<T: Config> =
+		StorageValue<_, PagedSolution<T::AccountId, T::MaxWinners>>;
+
+	/// The supports of each page of [`QueuedPagedSolution`].
+	///
+	/// Once the election result is served, the first page might also hold the entire result of
+	/// the emergency phase or of the fallback. Cleared when the round is finished.
+	#[pallet::storage]
+	pub type QueuedSolutionPages<T: Config> =
+		StorageMap<_, Twox64Concat, PageIndex, BoundedSupportsOf<Pallet<T>>, OptionQuery>;
+
+	/// The progress of the paged unsigned solution whose pages are in [`StagedSolutionPages`].
+	///
+	/// Once all of its pages are checked, and so is its score, it replaces
+	/// [`QueuedPagedSolution`].
+	#[pallet::storage]
+	pub type StagedPagedSolution<T: Config> =
+		StorageValue<_, PagedSolution<T::AccountId, T::MaxWinners>>;
+
+	/// The supports of each page of [`StagedPagedSolution`] that has been checked.
+	#[pallet::storage]
+	pub type StagedSolutionPages<T: Config> =
+		StorageMap<_, Twox64Concat, PageIndex, BoundedSupportsOf<Pallet<T>>, OptionQuery>;
+
 	// The following storage items collectively comprise `SignedSubmissions<T>`, and should never be
 	// accessed independently. Instead, get `Self::signed_submissions()`, modify it as desired, and
 	// then do `signed_submissions.put()` when you're done with it.
@@ -1335,6 +1701,34 @@ pub mod pallet {
 
 	// `SignedSubmissions` items end here.
 
+	/// The paged signed submissions that are not complete yet, by submitter.
+	///
+	/// Their pages are in [`SignedSubmissionPages`]. Bounded by `T::SignedMaxSubmissions`, and
+	/// drained at the end of the signed phase.
+	#[pallet::storage]
+	pub type SignedPagedSubmissions<T: Config> =
+		CountedStorageMap<_, Twox64Concat, T::AccountId, SignedPagedSubmissionOf<T>, OptionQuery>;
+
+	/// The supports of each page of the [`SignedPagedSubmissions`] that has been checked.
+	#[pallet::storage]
+	pub type SignedSubmissionPages<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		PageIndex,
+		BoundedSupportsOf<Pallet<T>>,
+		OptionQuery,
+	>;
+
+	/// The submitter of [`QueuedPagedSolution`] and their deposit, if it was submitted in the
+	/// signed phase.
+	///
+	/// They are rewarded at the end of the signed phase, or get their deposit back if a better
+	/// signed submission replaces theirs before that.
+	#[pallet::storage]
+	pub type QueuedPagedSubmitter<T: Config> = StorageValue<_, (T::AccountId, BalanceOf<T>)>;
+
 	/// The minimum score that each 'untrusted' solution must attain in order to be considered
 	/// feasible.
 	///
@@ -1364,6 +1758,7 @@ impl<T: Config> SnapshotWrapper<T> {
 		<Snapshot<T>>::kill();
 		<SnapshotMetadata<T>>::kill();
 		<DesiredTargets<T>>::kill();
+		let _ = <PagedVoterSnapshot<T>>::clear(u32::MAX, None);
 	}
 	/// Set all snapshot related storage items at the same time.
 	pub fn set(metadata: SolutionOrSnapshotSize, desired_targets: u32, buffer: &[u8]) {
@@ -1400,15 +1795,24 @@ impl<T: Config> Pallet<T> {
 					// This is executed at the beginning of each round. Any cache is now invalid.
 					// Clear it.
 					unsigned::kill_ocw_solution::<T>();
-					Self::mine_check_save_submit()
+					if T::Pages::get() > 1 {
+						Self::mine_check_submit_paged()
+					} else {
+						Self::mine_check_save_submit()
+					}
 				});
 				log!(debug, "initial offchain thread output: {:?}", initial_output);
 			},
 			Phase::Unsigned((true, opened)) if opened < now => {
 				// Try and resubmit the cached solution, and recompute ONLY if it is not
 				// feasible.
-				let resubmit_output = Self::ensure_offchain_repeat_frequency(now)
-					.and_then(|_| Self::restore_or_compute_then_maybe_submit());
+				let resubmit_output = Self::ensure_offchain_repeat_frequency(now).and_then(|_| {
+					if T::Pages::get() > 1 {
+						Self::mine_check_submit_paged()
+					} else {
+						Self::restore_or_compute_then_maybe_submit()
+					}
+				});
 				log!(debug, "resubmit offchain thread output: {:?}", resubmit_output);
 			},
 			_ => {},
@@ -1472,15 +1876,8 @@ impl<T: Config> Pallet<T> {
 			})
 			.map_err(ElectionError::DataProvider)?;
 
-		let voters = T::DataProvider::electing_voters(election_bounds.voters)
-			.and_then(|v| {
-				election_bounds.ensure_voters_limits(
-					CountBound(v.len() as u32),
-					SizeBound(v.encoded_size() as u32),
-				)?;
-				Ok(v)
-			})
-			.map_err(ElectionError::DataProvider)?;
+		let voters =
+			Self::snapshot_voters(&election_bounds).map_err(ElectionError::DataProvider)?;
 
 		let mut desired_targets = <Pallet<T> as ElectionProviderBase>::desired_targets_checked()
 			.map_err(|e| ElectionError::DataProvider(e))?;
@@ -1501,6 +1898,89 @@ impl<T: Config> Pallet<T> {
 		Ok((targets, voters, desired_targets))
 	}
 
+	/// Fetch the page of voters that is due in this block ahead of the snapshot creation, if any.
+	///
+	/// `remaining` is the number of blocks until the next election. Returns the consumed weight,
+	/// the data provider is self-weighing.
+	fn fetch_voter_page(remaining: BlockNumberFor<T>) -> Weight {
+		let pages = T::Pages::get();
+		let snapshot_deadline = if T::SignedPhase::get().is_zero() {
+			T::UnsignedPhase::get()
+		} else {
+			T::SignedPhase::get() + T::UnsignedPhase::get()
+		};
+
+		let blocks_before = remaining.saturating_sub(snapshot_deadline);
+		if blocks_before.is_zero() || blocks_before >= pages.into() {
+			return Weight::zero()
+		}
+
+		// only fetch a page if all previous pages are there, the data provider serves the voters
+		// in order.
+		let page: PageIndex = blocks_before.saturated_into();
+		let due = page == pages - 1 || PagedVoterSnapshot::<T>::contains_key(page + 1);
+		if !due || PagedVoterSnapshot::<T>::contains_key(page) {
+			return T::DbWeight::get().reads(2)
+		}
+
+		match Self::fetch_voters(&T::ElectionBounds::get(), page) {
+			Ok(voters) => PagedVoterSnapshot::<T>::insert(page, voters),
+			Err(why) => {
+				log!(warn, "failed to fetch voter page {} due to {:?}", page, why);
+				// start over, the missing pages are fetched when the snapshot is created.
+				let _ = PagedVoterSnapshot::<T>::clear(pages, None);
+			},
+		}
+
+		T::DbWeight::get().reads_writes(2, 1)
+	}
+
+	/// Fetch page `page` of the voters from the data provider, each page being subject to the
+	/// voter bounds of `election_bounds`.
+	fn fetch_voters(
+		election_bounds: &ElectionBounds,
+		page: PageIndex,
+	) -> data_provider::Result<Vec<VoterOf<T>>> {
+		let voters = if T::Pages::get() <= 1 {
+			T::DataProvider::electing_voters(election_bounds.voters)?
+		} else {
+			T::DataProvider::electing_voters_paged(election_bounds.voters, page)?
+		};
+
+		election_bounds.ensure_voters_limits(
+			CountBound(voters.len() as u32),
+			SizeBound(voters.encoded_size() as u32),
+		)?;
+		Ok(voters)
+	}
+
+	/// The voters of page `0` of the snapshot.
+	///
+	/// With more than one page, the other pages that were not fetched ahead of time are fetched
+	/// first, and stored in [`PagedVoterSnapshot`].
+	fn snapshot_voters(election_bounds: &ElectionBounds) -> data_provider::Result<Vec<VoterOf<T>>> {
+		for page in (1..T::Pages::get()).rev() {
+			if !PagedVoterSnapshot::<T>::contains_key(page) {
+				let voters = Self::fetch_voters(election_bounds, page)?;
+				PagedVoterSnapshot::<T>::insert(page, voters);
+				Self::register_weight(T::DbWeight::get().reads_writes(1, 1));
+			}
+		}
+
+		Self::fetch_voters(election_bounds, 0)
+	}
+
+	/// Page `page` of the snapshot, with the voters of that page and all targets.
+	pub fn snapshot_page(page: PageIndex) -> Option<RoundSnapshot<T::AccountId, VoterOf<T>>> {
+		let snapshot = Self::snapshot()?;
+		if page.is_zero() {
+			return Some(snapshot)
+		}
+
+		PagedVoterSnapshot::<T>::get(page)
+			.map(|voters| RoundSnapshot { voters, targets: snapshot.targets })
+	}
+
 	/// Creates the snapshot. Writes new data to:
 	///
 	/// 1. [`SnapshotMetadata`]
@@ -1513,7 +1993,12 @@ impl<T: Config> Pallet<T> {
 	/// [`DispatchClass::Mandatory`] with the system pallet.
 	pub fn create_snapshot() -> Result<(), ElectionError<T>> {
 		// this is self-weighing itself..
-		let (targets, voters, desired_targets) = Self::create_snapshot_external()?;
+		let (targets, voters, desired_targets) =
+			Self::create_snapshot_external().map_err(|why| {
+				// the pages fetched so far might be outdated by the next attempt.
+				let _ = PagedVoterSnapshot::<T>::clear(u32::MAX, None);
+				why
+			})?;
 
 		// ..therefore we only measure the weight of this and add it.
 		let internal_weight =
@@ -1555,11 +2040,89 @@ impl<T: Config> Pallet<T> {
 		)
 	}
 
+	/// Checks the feasibility of a page of a paged solution, against its page of the snapshot.
+	///
+	/// Returns the supports of the page.
+	pub fn feasibility_check_page(
+		raw_solution: RawSolution<SolutionOf<T::MinerConfig>>,
+		snapshot: RoundSnapshot<T::AccountId, VoterOf<T>>,
+	) -> Result<BoundedSupportsOf<Self>, FeasibilityError> {
+		let desired_targets =
+			Self::desired_targets().ok_or(FeasibilityError::SnapshotUnavailable)?;
+		let round = Self::round();
+		let minimum_untrusted_score = Self::minimum_untrusted_score();
+
+		Miner::<T::MinerConfig>::feasibility_check_page(
+			raw_solution,
+			desired_targets,
+			snapshot,
+			round,
+			minimum_untrusted_score,
+		)
+	}
+
+	/// Add a feasible page of a paged unsigned solution to [`StagedPagedSolution`], and store its
+	/// supports in [`StagedSolutionPages`].
+	///
+	/// A page with a different claimed score than the staged solution replaces it with a new one,
+	/// along with all of its pages. Once all pages are there, the claimed score is checked, and the
+	/// staged solution replaces the queued one. Until then, the queued solution is left untouched.
+	fn stage_solution_page(
+		page: PageIndex,
+		score: ElectionScore,
+		supports: BoundedSupportsOf<Self>,
+	) -> Result<PagedSolution<T::AccountId, T::MaxWinners>, FeasibilityError> {
+		let pages = T::Pages::get();
+		let desired_targets =
+			Self::desired_targets().ok_or(FeasibilityError::SnapshotUnavailable)?;
+
+		let mut solution = match <StagedPagedSolution<T>>::get() {
+			Some(staged) if staged.score == score => staged,
+			_ => {
+				let _ = <StagedSolutionPages<T>>::clear(pages, None);
+				PagedSolution { score, compute: ElectionCompute::Unsigned, ..Default::default() }
+			},
+		};
+
+		solution.add_page(&supports, desired_targets)?;
+		<StagedSolutionPages<T>>::insert(page, supports);
+		if solution.checked_pages < pages {
+			<StagedPagedSolution<T>>::put(&solution);
+			return Ok(solution)
+		}
+
+		solution.check_score(desired_targets)?;
+		<StagedPagedSolution<T>>::kill();
+		Ok(Self::queue_paged_solution(solution, <StagedSolutionPages<T>>::drain()))
+	}
+
+	/// Replace [`QueuedPagedSolution`] and its pages with the complete and checked `solution`,
+	/// whose supports are `pages`.
+	fn queue_paged_solution(
+		mut solution: PagedSolution<T::AccountId, T::MaxWinners>,
+		pages: impl Iterator<Item = (PageIndex, BoundedSupportsOf<Self>)>,
+	) -> PagedSolution<T::AccountId, T::MaxWinners> {
+		let _ = <QueuedSolutionPages<T>>::clear(T::Pages::get(), None);
+		pages.for_each(|(page, supports)| <QueuedSolutionPages<T>>::insert(page, supports));
+
+		solution.prev_ejected = <QueuedPagedSolution<T>>::exists();
+		<QueuedPagedSolution<T>>::put(&solution);
+		solution
+	}
+
+	/// The worst case weight of the storage accesses of [`Self::queue_paged_solution`], moving all
+	/// pages of a complete solution into [`QueuedSolutionPages`].
+	fn queue_paged_solution_weight() -> Weight {
+		let pages: u64 = T::Pages::get().into();
+		T::DbWeight::get().reads_writes(pages.saturating_add(1), pages.saturating_mul(3))
+	}
+
 	/// Perform the tasks to be done after a new `elect` has been triggered:
 	///
 	/// 1. Increment round.
 	/// 2. Change phase to [`Phase::Off`]
 	/// 3. Clear all snapshot data.
+	/// 4. Clear the queued and the staged paged solutions, if any.
 	fn rotate_round() {
 		// Inc round.
 		<Round<T>>::mutate(|r| *r += 1);
@@ -1569,9 +2132,16 @@ impl<T: Config> Pallet<T> {
 
 		// Kill snapshot and relevant metadata (everything created by [`SnapshotMetadata::set`]).
 		SnapshotWrapper::<T>::kill();
+
+		<QueuedPagedSolution<T>>::kill();
+		let _ = <QueuedSolutionPages<T>>::clear(u32::MAX, None);
+		<StagedPagedSolution<T>>::kill();
+		let _ = <StagedSolutionPages<T>>::clear(u32::MAX, None);
 	}
 
-	fn do_elect() -> Result<BoundedSupportsOf<Self>, ElectionError<T>> {
+	/// The queued solution, or the one of the fallback if there is none.
+	fn queued_or_fallback() -> Result<ReadySolution<T::AccountId, T::MaxWinners>, ElectionError<T>>
+	{
 		// We have to unconditionally try finalizing the signed phase here. There are only two
 		// possibilities:
 		//
@@ -1600,6 +2170,10 @@ impl<T: Config> Pallet<T> {
 					})
 				})
 			})
+	}
+
+	fn do_elect() -> Result<BoundedSupportsOf<Self>, ElectionError<T>> {
+		Self::queued_or_fallback()
 			.map(|ReadySolution { compute, score, supports }| {
 				Self::deposit_event(Event::ElectionFinalized { compute, score });
 				if Self::round() != 1 {
@@ -1616,6 +2190,53 @@ impl<T: Config> Pallet<T> {
 			})
 	}
 
+	/// Serve page `page` of a paged election result.
+	///
+	/// The first page that is requested closes the signed or unsigned phase, such that the result
+	/// can no longer change. If no complete paged solution is queued at that point, the solution of
+	/// the emergency phase or of the fallback is stored in its entirety as page `T::Pages - 1`.
+	/// Serving page `0` finishes the round.
+	fn do_elect_paged(page: PageIndex) -> Result<BoundedSupportsOf<Self>, ElectionError<T>> {
+		let pages = T::Pages::get();
+		// Like in `queued_or_fallback`, the signed phase might not be finalized yet. It queues the
+		// best paged signed submission, if any.
+		let _ = Self::finalize_signed_phase();
+		let solution = match <QueuedPagedSolution<T>>::get() {
+			Some(solution) => solution,
+			None => {
+				let ReadySolution { supports, score, compute } = Self::queued_or_fallback()?;
+				let _ = <QueuedSolutionPages<T>>::clear(pages, None);
+				<QueuedSolutionPages<T>>::insert(pages - 1, supports);
+				let solution =
+					PagedSolution { score, compute, checked_pages: pages, ..Default::default() };
+				<QueuedPagedSolution<T>>::put(&solution);
+				solution
+			},
+		};
+
+		match Self::current_phase() {
+			Phase::Signed => Self::phase_transition(Phase::Unsigned((
+				false,
+				<frame_system::Pallet<T>>::block_number(),
+			))),
+			Phase::Unsigned((true, opened)) =>
+				Self::phase_transition(Phase::Unsigned((false, opened))),
+			_ => {},
+		}
+
+		let supports = <QueuedSolutionPages<T>>::get(page).unwrap_or_default();
+		Self::weigh_supports(&supports);
+
+		if page.is_zero() {
+			let PagedSolution { compute, score, .. } = solution;
+			Self::deposit_event(Event::ElectionFinalized { compute, score });
+			log!(info, "Finalized paged election round with compute {:?}.", compute);
+			Self::rotate_round();
+		}
+
+		Ok(supports)
+	}
+
 	/// record the weight of the given `supports`.
 	fn weigh_supports(supports: &Supports<T::AccountId>) {
 		let active_voters = supports
@@ -1696,12 +2317,15 @@ impl<T: Config> Pallet<T> {
 
 	// [`Phase::Off`] state check. Invariants:
 	// - If phase is `Phase::Off`, [`Snapshot`] must be none.
+	// - If phase is `Phase::Off`, [`QueuedPagedSolution`] and [`StagedPagedSolution`] must be none.
 	fn try_state_phase_off() -> Result<(), TryRuntimeError> {
 		match Self::current_phase().is_off() {
 			false => Ok(()),
 			true =>
 				if <Snapshot<T>>::get().is_some() {
 					Err("Snapshot must be none when in Phase::Off".into())
+				} else if <QueuedPagedSolution<T>>::exists() || <StagedPagedSolution<T>>::exists() {
+					Err("Paged solution must be none when in Phase::Off".into())
 				} else {
 					Ok(())
 				},
@@ -1725,6 +2349,27 @@ impl<T: Config> ElectionProvider for Pallet<T> {
 		}
 	}
 
+	fn pages() -> PageIndex {
+		T::Pages::get().max(1)
+	}
+
+	fn elect_paged(page: PageIndex) -> Result<BoundedSupportsOf<Self>, Self::Error> {
+		let pages = <Self as ElectionProvider>::pages();
+		if pages == 1 {
+			return if page.is_zero() { Self::elect() } else { Ok(Default::default()) }
+		}
+
+		match Self::do_elect_paged(page) {
+			Ok(supports) => Ok(supports),
+			Err(why) => {
+				log!(error, "Entering emergency mode: {:?}", why);
+				Self::deposit_event(Event::ElectionFailed);
+				Self::phase_transition(Phase::Emergency);
+				Err(why)
+			},
+		}
+	}
+
 	fn elect() -> Result<BoundedSupportsOf<Self>, Self::Error> {
 		match Self::do_elect() {
 			Ok(supports) => {
@@ -2679,6 +3324,150 @@ mod tests {
 		})
 	}
 
+	#[test]
+	fn paged_voter_snapshot_is_fetched_ahead_of_time() {
+		ExtBuilder::default().pages(3).phases(0, 5).build_and_execute(|| {
+			roll_to(22);
+			assert_eq!(PagedVoterSnapshot::<Runtime>::iter().count(), 0);
+
+			// the two most significant pages are fetched in the two blocks before the snapshot.
+			roll_to(23);
+			assert!(PagedVoterSnapshot::<Runtime>::contains_key(2));
+			roll_to(24);
+			assert!(PagedVoterSnapshot::<Runtime>::contains_key(1));
+			assert!(MultiPhase::snapshot().is_none());
+
+			// the last page is fetched with the snapshot, the others are kept for the round.
+			roll_to_unsigned();
+			assert!(MultiPhase::current_phase().is_unsigned());
+			assert_eq!(PagedVoterSnapshot::<Runtime>::iter().count(), 2);
+			assert_eq!(MultiPhase::snapshot_metadata().unwrap().voters, 2);
+			let voters = (0..3)
+				.rev()
+				.flat_map(|page| MultiPhase::snapshot_page(page).unwrap().voters)
+				.collect::<Vec<_>>();
+			assert_eq!(voters, Voters::get());
+
+			// all pages are gone with the round.
+			assert_ok!(<MultiPhase as ElectionProvider>::elect_paged(2));
+			assert_ok!(<MultiPhase as ElectionProvider>::elect_paged(1));
+			assert_ok!(<MultiPhase as ElectionProvider>::elect_paged(0));
+			assert_eq!(PagedVoterSnapshot::<Runtime>::iter().count(), 0);
+		})
+	}
+
+	#[test]
+	fn elect_paged_serves_queued_pages() {
+		ExtBuilder::default().pages(2).phases(0, 5).build_and_execute(|| {
+			roll_to_unsigned();
+			assert_eq!(<MultiPhase as ElectionProvider>::pages(), 2);
+
+			for call in MultiPhase::mine_checked_paged_calls().unwrap() {
+				if let Call::submit_unsigned_page { page, raw_solution, witness } = call {
+					assert_ok!(MultiPhase::submit_unsigned_page(
+						RuntimeOrigin::none(),
+						page,
+						raw_solution,
+						witness
+					));
+				}
+			}
+			let solution = QueuedPagedSolution::<Runtime>::get().unwrap();
+			assert_eq!(solution.checked_pages, 2);
+			assert_eq!(QueuedSolutionPages::<Runtime>::iter().count(), 2);
+
+			// the first requested page closes the unsigned phase.
+			let mut supports =
+				<MultiPhase as ElectionProvider>::elect_paged(1).unwrap().into_inner();
+			assert_eq!(MultiPhase::current_phase(), Phase::Unsigned((false, 25)));
+			assert_eq!(MultiPhase::round(), 1);
+
+			let last_page = <MultiPhase as ElectionProvider>::elect_paged(0).unwrap().into_inner();
+			frame_election_provider_support::merge_supports(&mut supports, last_page);
+			assert!(MultiPhase::current_phase().is_off());
+			assert_eq!(MultiPhase::round(), 2);
+			assert!(QueuedPagedSolution::<Runtime>::get().is_none());
+			assert_eq!(QueuedSolutionPages::<Runtime>::iter().count(), 0);
+
+			// the pages together make up the queued solution.
+			assert_eq!(supports.len(), 2);
+			assert_eq!(supports.evaluate(), solution.score);
+			assert!(multi_phase_events().contains(&Event::ElectionFinalized {
+				compute: ElectionCompute::Unsigned,
+				score: solution.score
+			}));
+		})
+	}
+
+	#[test]
+	fn partial_better_solution_does_not_displace_the_queued_one() {
+		ExtBuilder::default().pages(2).phases(0, 5).build_and_execute(|| {
+			roll_to_unsigned();
+
+			let calls = MultiPhase::mine_checked_paged_calls().unwrap();
+			for call in calls.clone() {
+				if let Call::submit_unsigned_page { page, raw_solution, witness } = call {
+					assert_ok!(MultiPhase::submit_unsigned_page(
+						RuntimeOrigin::none(),
+						page,
+						raw_solution,
+						witness
+					));
+				}
+			}
+			let queued = QueuedPagedSolution::<Runtime>::get().unwrap();
+			assert_eq!(queued.checked_pages, 2);
+			assert!(StagedPagedSolution::<Runtime>::get().is_none());
+			assert_eq!(StagedSolutionPages::<Runtime>::iter().count(), 0);
+
+			// the first page of a solution that claims a better score is only staged.
+			let (page, mut raw_solution, witness) = match calls[0].clone() {
+				Call::submit_unsigned_page { page, raw_solution, witness } =>
+					(page, raw_solution, witness),
+				_ => unreachable!(),
+			};
+			raw_solution.score.minimal_stake += 1;
+			assert_ok!(MultiPhase::submit_unsigned_page(
+				RuntimeOrigin::none(),
+				page,
+				raw_solution,
+				witness
+			));
+			assert_eq!(StagedPagedSolution::<Runtime>::get().unwrap().checked_pages, 1);
+			assert_eq!(QueuedPagedSolution::<Runtime>::get(), Some(queued.clone()));
+			assert_eq!(QueuedSolutionPages::<Runtime>::iter().count(), 2);
+
+			// the complete solution is still the one that is served.
+			let mut supports =
+				<MultiPhase as ElectionProvider>::elect_paged(1).unwrap().into_inner();
+			let last_page = <MultiPhase as ElectionProvider>::elect_paged(0).unwrap().into_inner();
+			frame_election_provider_support::merge_supports(&mut supports, last_page);
+			assert_eq!(supports.evaluate(), queued.score);
+			assert!(StagedPagedSolution::<Runtime>::get().is_none());
+			assert_eq!(StagedSolutionPages::<Runtime>::iter().count(), 0);
+		})
+	}
+
+	#[test]
+	fn elect_paged_serves_fallback_in_the_last_page() {
+		ExtBuilder::default()
+			.pages(2)
+			.phases(0, 5)
+			.onchain_fallback(true)
+			.build_and_execute(|| {
+				roll_to_unsigned();
+
+				// no paged solution was queued, the entire fallback result is the first page.
+				let supports = <MultiPhase as ElectionProvider>::elect_paged(1).unwrap();
+				assert_eq!(
+					supports.iter().map(|(who, support)| (*who, support.total)).collect::<Vec<_>>(),
+					vec![(30, 40), (40, 60)]
+				);
+				assert!(<MultiPhase as ElectionProvider>::elect_paged(0).unwrap().is_empty());
+				assert!(MultiPhase::current_phase().is_off());
+			})
+	}
+
 	#[test]
 	fn number_of_voters_allowed_2sec_block() {
 		// Just a rough estimate with the substrate weights.
@@ -19,7 +19,7 @@ use super::*;
 use crate::{self as multi_phase, signed::GeometricDepositBase, unsigned::MinerConfig};
 use frame_election_provider_support::{
 	bounds::{DataProviderBounds, ElectionBounds},
-	data_provider, onchain, ElectionDataProvider, NposSolution, SequentialPhragmen,
+	data_provider, onchain, ElectionDataProvider, NposSolution, PageIndex, SequentialPhragmen,
 };
 pub use frame_support::derive_impl;
 use frame_support::{
@@ -287,6 +287,7 @@ parameter_types! {
 	pub static DesiredTargets: u32 = 2;
 	pub static SignedPhase: BlockNumber = 10;
 	pub static UnsignedPhase: BlockNumber = 5;
+	pub static Pages: PageIndex = 1;
 	pub static SignedMaxSubmissions: u32 = 5;
 	pub static SignedMaxRefunds: u32 = 1;
 	// for tests only. if `EnableVariableDepositBase` is true, the deposit base will be calculated
@@ -397,6 +398,7 @@ impl crate::Config for Runtime {
 	type EstimateCallFee = frame_support::traits::ConstU32<8>;
 	type SignedPhase = SignedPhase;
 	type UnsignedPhase = UnsignedPhase;
+	type Pages = Pages;
 	type BetterSignedThreshold = BetterSignedThreshold;
 	type OffchainRepeat = OffchainRepeat;
 	type MinerTxPriority = MinerTxPriority;
@@ -483,6 +485,27 @@ impl ElectionDataProvider for StakingMock {
 		Ok(voters)
 	}
 
+	fn electing_voters_paged(
+		bounds: DataProviderBounds,
+		page: PageIndex,
+	) -> data_provider::Result<Vec<VoterOf<Runtime>>> {
+		// the most significant page holds the first voters.
+		let pages = Pages::get() as usize;
+		let voters = Self::electing_voters(DataProviderBounds::default())?;
+		let chunk_size = (voters.len() + pages - 1) / pages;
+		let mut voters = voters
+			.chunks(chunk_size.max(1))
+			.nth(pages - 1 - page as usize)
+			.map(|chunk| chunk.to_vec())
+			.unwrap_or_default();
+
+		if let Some(max_len) = bounds.count {
+			voters.truncate(max_len.0 as usize)
+		}
+
+		Ok(voters)
+	}
+
 	fn desired_targets() -> data_provider::Result<u32> {
 		Ok(DesiredTargets::get())
 	}
@@ -536,6 +559,10 @@ impl ExtBuilder {
 		self
 	}
 
+	pub fn pages(self, pages: PageIndex) -> Self {
+		<Pages>::set(pages);
+		self
+	}
 	pub fn phases(self, signed: BlockNumber, unsigned: BlockNumber) -> Self {
 		<SignedPhase>::set(signed);
 		<UnsignedPhase>::set(unsigned);
@@ -20,9 +20,10 @@
 use core::marker::PhantomData;
 
 use crate::{
-	unsigned::MinerConfig, Config, ElectionCompute, Pallet, QueuedSolution, RawSolution,
-	ReadySolution, SignedSubmissionIndices, SignedSubmissionNextIndex, SignedSubmissionsMap,
-	SolutionOf, SolutionOrSnapshotSize, Weight, WeightInfo,
+	unsigned::MinerConfig, Config, ElectionCompute, PagedSolution, Pallet, QueuedPagedSolution,
+	QueuedPagedSubmitter, QueuedSolution, RawSolution, ReadySolution, SignedPagedSubmissions,
+	SignedSubmissionIndices, SignedSubmissionNextIndex, SignedSubmissionPages,
+	SignedSubmissionsMap, SolutionOf, SolutionOrSnapshotSize, Weight, WeightInfo,
 };
 use codec::{Decode, Encode, HasCompact};
 use frame_election_provider_support::NposSolution;
@@ -32,7 +33,7 @@ use frame_support::traits::{
 use frame_system::pallet_prelude::BlockNumberFor;
 use sp_arithmetic::traits::SaturatedConversion;
 use sp_core::bounded::BoundedVec;
-use sp_npos_elections::ElectionScore;
+use sp_npos_elections::{ElectionScore, IdentifierT};
 use sp_runtime::{
 	traits::{Convert, Saturating, Zero},
 	FixedPointNumber, FixedPointOperand, FixedU128, Percent, RuntimeDebug,
@@ -87,6 +88,23 @@ where
 	}
 }
 
+/// A paged signed submission, see [`crate::Call::submit_page`].
+///
+/// The pages of the submission are checked as they are submitted, and their supports are stored
+/// in [`SignedSubmissionPages`]. A deposit is reserved for every page.
+#[derive(Encode, Decode, RuntimeDebug, scale_info::TypeInfo)]
+#[scale_info(skip_type_params(MaxWinners))]
+pub struct SignedPagedSubmission<AccountId, Balance, MaxWinners>
+where
+	AccountId: IdentifierT,
+	MaxWinners: Get<u32>,
+{
+	/// The progress of the solution.
+	pub solution: PagedSolution<AccountId, MaxWinners>,
+	/// The deposit reserved for all pages submitted so far.
+	pub deposit: Balance,
+}
+
 pub type BalanceOf<T> =
 	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 pub type PositiveImbalanceOf<T> = <<T as Config>::Currency as Currency<
@@ -100,6 +118,11 @@ pub type SignedSubmissionOf<T> = SignedSubmission<
 	BalanceOf<T>,
 	<<T as crate::Config>::MinerConfig as MinerConfig>::Solution,
 >;
+pub type SignedPagedSubmissionOf<T> = SignedPagedSubmission<
+	<T as frame_system::Config>::AccountId,
+	BalanceOf<T>,
+	<T as crate::Config>::MaxWinners,
+>;
 
 /// Always sorted vector of a score, submitted at the given block number, which can be found at the
 /// given index (`u32`) of the `SignedSubmissionsMap`.
@@ -400,6 +423,10 @@ impl<T: Config> Pallet<T> {
 
 	/// The guts of [`finalized_signed_phase`], that does everything except registering its weight.
 	pub(crate) fn finalize_signed_phase_internal() -> (Weight, bool) {
+		if T::Pages::get() > 1 {
+			return Self::finalize_signed_paged_phase()
+		}
+
 		let mut all_submissions = Self::signed_submissions();
 		let mut found_solution = false;
 		let mut weight = T::DbWeight::get().reads(1);
@@ -483,6 +510,91 @@ impl<T: Config> Pallet<T> {
 
 		(weight, found_solution)
 	}
+
+	/// The paged counterpart of [`Self::finalize_signed_phase_internal`].
+	///
+	/// The paged signed submissions are checked as they are submitted, and the best one is queued
+	/// already. Its submitter is rewarded, and all submissions that are still incomplete are
+	/// slashed.
+	fn finalize_signed_paged_phase() -> (Weight, bool) {
+		let pages = T::Pages::get();
+		let mut weight = T::DbWeight::get().reads(2);
+
+		let found_solution = match <QueuedPagedSubmitter<T>>::take() {
+			Some((who, deposit)) => {
+				Self::reward_signed_submission(&who, deposit, Zero::zero());
+				weight =
+					weight.saturating_add(T::WeightInfo::finalize_signed_phase_accept_solution());
+				true
+			},
+			None => false,
+		};
+
+		let mut incomplete = 0;
+		for (who, submission) in <SignedPagedSubmissions<T>>::drain() {
+			log!(warn, "finalized_signed: incomplete paged submission found, slashing.");
+			let _ = <SignedSubmissionPages<T>>::clear_prefix(&who, pages, None);
+			Self::finalize_signed_phase_reject_solution(&who, submission.deposit);
+			weight = weight
+				.saturating_add(T::WeightInfo::finalize_signed_phase_reject_solution())
+				.saturating_add(T::DbWeight::get().reads_writes(1, pages.saturating_add(1).into()));
+			incomplete += 1;
+		}
+
+		log!(
+			debug,
+			"closed paged signed phase, found solution? {}, slashed {} incomplete",
+			found_solution,
+			incomplete
+		);
+
+		(weight, found_solution)
+	}
+
+	/// Finish the paged signed submission of `who`, once all of its pages are in
+	/// [`SignedSubmissionPages`].
+	///
+	/// The deposit is slashed if the claimed score is wrong. Otherwise, the submission replaces the
+	/// queued paged solution if it is still better, or gets its deposit back if not.
+	pub(crate) fn finalize_signed_paged_submission(
+		who: T::AccountId,
+		submission: SignedPagedSubmissionOf<T>,
+		desired_targets: u32,
+	) {
+		let SignedPagedSubmission { solution, deposit } = submission;
+		let threshold = T::BetterSignedThreshold::get();
+		let better = <QueuedPagedSolution<T>>::get()
+			.map_or(true, |queued| solution.score.strict_threshold_better(queued.score, threshold));
+
+		if solution.check_score(desired_targets).is_err() {
+			log!(warn, "paged signed submission with a wrong score found, slashing.");
+			let _ = <SignedSubmissionPages<T>>::clear_prefix(&who, T::Pages::get(), None);
+			Self::finalize_signed_phase_reject_solution(&who, deposit);
+		} else if !better {
+			let _ = <SignedSubmissionPages<T>>::clear_prefix(&who, T::Pages::get(), None);
+			let _remaining = T::Currency::unreserve(&who, deposit);
+			debug_assert!(_remaining.is_zero());
+		} else {
+			let solution = Self::queue_paged_solution(
+				solution,
+				<SignedSubmissionPages<T>>::drain_prefix(&who),
+			);
+
+			// the submitter of the solution that was queued before gets their deposit back.
+			if let Some((ejected, ejected_deposit)) = <QueuedPagedSubmitter<T>>::take() {
+				let _remaining = T::Currency::unreserve(&ejected, ejected_deposit);
+				debug_assert!(_remaining.is_zero());
+			}
+			<QueuedPagedSubmitter<T>>::put((who.clone(), deposit));
+
+			Self::deposit_event(crate::Event::SolutionStored {
+				compute: ElectionCompute::Signed,
+				origin: Some(who),
+				prev_ejected: solution.prev_ejected,
+			});
+		}
+	}
+
 	/// Helper function for the case where a solution is accepted in the signed phase.
 	///
 	/// Extracted to facilitate with weight calculation.
@@ -497,6 +609,11 @@ impl<T: Config> Pallet<T> {
 		// write this ready solution.
 		<QueuedSolution<T>>::put(ready_solution);
 
+		Self::reward_signed_submission(who, deposit, call_fee);
+	}
+
+	/// Reward `who` for an accepted signed submission, and give them their `deposit` back.
+	fn reward_signed_submission(who: &T::AccountId, deposit: BalanceOf<T>, call_fee: BalanceOf<T>) {
 		let reward = T::SignedRewardBase::get();
 		// emit reward event
 		Self::deposit_event(crate::Event::Rewarded { account: who.clone(), value: reward });
@@ -546,6 +663,33 @@ impl<T: Config> Pallet<T> {
 	pub fn deposit_for(
 		raw_solution: &RawSolution<SolutionOf<T::MinerConfig>>,
 		size: SolutionOrSnapshotSize,
+	) -> BalanceOf<T> {
+		T::SignedDepositBase::convert(Self::signed_submissions().len())
+			.saturating_add(Self::len_and_weight_deposit_of(raw_solution, size))
+	}
+
+	/// Collect a sufficient deposit to store page `raw_solution` of a paged signed submission.
+	///
+	/// Like [`Self::deposit_for`], except that the base deposit is only part of the deposit of the
+	/// first page of a submission, and depends on the number of [`SignedPagedSubmissions`].
+	pub fn deposit_for_page(
+		raw_solution: &RawSolution<SolutionOf<T::MinerConfig>>,
+		size: SolutionOrSnapshotSize,
+		first_page: bool,
+	) -> BalanceOf<T> {
+		let base_deposit = if first_page {
+			T::SignedDepositBase::convert(<SignedPagedSubmissions<T>>::count() as usize)
+		} else {
+			Zero::zero()
+		};
+
+		base_deposit.saturating_add(Self::len_and_weight_deposit_of(raw_solution, size))
+	}
+
+	/// The per-byte and the per-weight deposits of a solution.
+	fn len_and_weight_deposit_of(
+		raw_solution: &RawSolution<SolutionOf<T::MinerConfig>>,
+		size: SolutionOrSnapshotSize,
 	) -> BalanceOf<T> {
 		let encoded_len: u32 = raw_solution.encoded_size().saturated_into();
 		let encoded_len_balance: BalanceOf<T> = encoded_len.into();
@@ -555,9 +699,7 @@ impl<T: Config> Pallet<T> {
 		let weight_deposit = T::SignedDepositWeight::get()
 			.saturating_mul(feasibility_weight.ref_time().saturated_into());
 
-		T::SignedDepositBase::convert(Self::signed_submissions().len())
-			.saturating_add(len_deposit)
-			.saturating_add(weight_deposit)
+		len_deposit.saturating_add(weight_deposit)
 	}
 }
 
@@ -566,7 +708,7 @@ mod tests {
 	use super::*;
 	use crate::{
 		mock::*, ElectionBoundsBuilder, ElectionCompute, ElectionError, Error, Event, Perbill,
-		Phase,
+		Phase, QueuedPagedSolution,
 	};
 	use frame_support::{assert_noop, assert_ok, assert_storage_noop};
 	use sp_runtime::Percent;
@@ -768,6 +910,169 @@ mod tests {
 		})
 	}
 
+	#[test]
+	fn paged_solution_is_queued_once_complete_and_rewarded() {
+		ExtBuilder::default().pages(2).build_and_execute(|| {
+			roll_to_signed();
+			assert!(MultiPhase::current_phase().is_signed());
+
+			let pages = MultiPhase::mine_paged_solution().unwrap();
+			let (page, raw_solution, witness) = pages[0].clone();
+
+			// entire solutions are not accepted.
+			assert_noop!(
+				MultiPhase::submit(RuntimeOrigin::signed(99), Box::new(raw_solution.clone())),
+				Error::<Runtime>::PreDispatchWrongPage,
+			);
+
+			// the base deposit is reserved with the first page.
+			assert_ok!(MultiPhase::submit_page(
+				RuntimeOrigin::signed(99),
+				page,
+				Box::new(raw_solution.clone()),
+				witness
+			));
+			assert_eq!(balances(&99), (95, 5));
+			assert_noop!(
+				MultiPhase::submit_page(
+					RuntimeOrigin::signed(99),
+					page,
+					Box::new(raw_solution),
+					witness
+				),
+				Error::<Runtime>::PreDispatchWrongPage,
+			);
+			assert!(QueuedPagedSolution::<Runtime>::get().is_none());
+
+			// the last page queues the solution.
+			let (page, raw_solution, witness) = pages[1].clone();
+			assert_ok!(MultiPhase::submit_page(
+				RuntimeOrigin::signed(99),
+				page,
+				Box::new(raw_solution),
+				witness
+			));
+			let queued = QueuedPagedSolution::<Runtime>::get().unwrap();
+			assert_eq!((queued.compute, queued.checked_pages), (ElectionCompute::Signed, 2));
+			assert_eq!(SignedPagedSubmissions::<Runtime>::count(), 0);
+			assert_eq!(SignedSubmissionPages::<Runtime>::iter().count(), 0);
+
+			// the submitter is rewarded at the end of the signed phase, without a call fee refund.
+			assert!(MultiPhase::finalize_signed_phase());
+			assert_eq!(balances(&99), (100 + 7, 0));
+
+			assert_eq!(
+				multi_phase_events(),
+				vec![
+					Event::PhaseTransitioned { from: Phase::Off, to: Phase::Signed, round: 1 },
+					Event::SolutionStored {
+						compute: ElectionCompute::Signed,
+						origin: Some(99),
+						prev_ejected: false
+					},
+					Event::Rewarded { account: 99, value: 7 }
+				]
+			);
+		})
+	}
+
+	#[test]
+	fn wrong_or_incomplete_paged_solution_is_slashed() {
+		ExtBuilder::default().pages(2).build_and_execute(|| {
+			roll_to_signed();
+			assert!(MultiPhase::current_phase().is_signed());
+
+			let pages = MultiPhase::mine_paged_solution().unwrap();
+
+			// the score of all pages together is wrong.
+			for (page, mut raw_solution, witness) in pages.clone() {
+				raw_solution.score.minimal_stake += 1;
+				assert_ok!(MultiPhase::submit_page(
+					RuntimeOrigin::signed(99),
+					page,
+					Box::new(raw_solution),
+					witness
+				));
+			}
+			assert_eq!(balances(&99), (95, 0));
+
+			// the last page is never submitted.
+			let (page, raw_solution, witness) = pages[0].clone();
+			assert_ok!(MultiPhase::submit_page(
+				RuntimeOrigin::signed(999),
+				page,
+				Box::new(raw_solution),
+				witness
+			));
+			assert_eq!(balances(&999), (95, 5));
+
+			assert!(!MultiPhase::finalize_signed_phase());
+			assert_eq!(balances(&999), (95, 0));
+			assert!(QueuedPagedSolution::<Runtime>::get().is_none());
+			assert_eq!(SignedPagedSubmissions::<Runtime>::count(), 0);
+			assert_eq!(SignedSubmissionPages::<Runtime>::iter().count(), 0);
+
+			assert_eq!(
+				multi_phase_events(),
+				vec![
+					Event::PhaseTransitioned { from: Phase::Off, to: Phase::Signed, round: 1 },
+					Event::Slashed { account: 99, value: 5 },
+					Event::Slashed { account: 999, value: 5 }
+				]
+			);
+		})
+	}
+
+	#[test]
+	fn better_paged_solution_replaces_the_queued_one() {
+		ExtBuilder::default().pages(2).build_and_execute(|| {
+			roll_to_signed();
+			assert!(MultiPhase::current_phase().is_signed());
+
+			let pages = MultiPhase::mine_paged_solution().unwrap();
+			let submit_all = |who: AccountId| {
+				for (page, raw_solution, witness) in pages.clone() {
+					assert_ok!(MultiPhase::submit_page(
+						RuntimeOrigin::signed(who),
+						page,
+						Box::new(raw_solution),
+						witness
+					));
+				}
+			};
+			submit_all(99);
+			assert_eq!(balances(&99), (95, 5));
+
+			// an equally good solution is not accepted.
+			let (page, raw_solution, witness) = pages[0].clone();
+			assert_noop!(
+				MultiPhase::submit_page(
+					RuntimeOrigin::signed(999),
+					page,
+					Box::new(raw_solution),
+					witness
+				),
+				Error::<Runtime>::PreDispatchWeakSubmission,
+			);
+
+			// a better one replaces it, and the ejected submitter gets their deposit back.
+			QueuedPagedSolution::<Runtime>::mutate(|queued| {
+				queued.as_mut().unwrap().score.minimal_stake -= 1
+			});
+			submit_all(999);
+			assert_eq!(balances(&99), (100, 0));
+			assert_eq!(balances(&999), (95, 5));
+
+			assert!(MultiPhase::finalize_signed_phase());
+			assert_eq!(balances(&999), (100 + 7, 0));
+			assert!(multi_phase_events().contains(&Event::SolutionStored {
+				compute: ElectionCompute::Signed,
+				origin: Some(999),
+				prev_ejected: true
+			}));
+		})
+	}
+
 	#[test]
 	fn suppressed_solution_gets_bond_back() {
 		ExtBuilder::default().build_and_execute(|| {
@@ -18,11 +18,15 @@
 //! The unsigned phase, and its miner.
 
 use crate::{
-	helpers, Call, Config, ElectionCompute, Error, FeasibilityError, Pallet, RawSolution,
-	ReadySolution, RoundSnapshot, SolutionAccuracyOf, SolutionOf, SolutionOrSnapshotSize, Weight,
+	helpers, Call, Config, ElectionCompute, Error, FeasibilityError, PagedSolution, Pallet,
+	QueuedPagedSolution, RawSolution, ReadySolution, RoundSnapshot, SolutionAccuracyOf, SolutionOf,
+	SolutionOrSnapshotSize, StagedPagedSolution, StagedSolutionPages, Weight,
 };
 use codec::Encode;
-use frame_election_provider_support::{NposSolution, NposSolver, PerThing128, VoteWeight};
+use frame_election_provider_support::{
+	merge_supports, BoundedSupports, NposSolution, NposSolver, PageIndex, PerThing128, Supports,
+	VoteWeight,
+};
 use frame_support::{
 	dispatch::DispatchResult,
 	ensure,
@@ -273,6 +277,84 @@ impl<T: Config> Pallet<T> {
 		Ok(call)
 	}
 
+	/// Mine a new paged npos solution, one solution per page of the snapshot.
+	///
+	/// All pages claim the score of the entire solution.
+	pub fn mine_paged_solution() -> Result<
+		Vec<(PageIndex, RawSolution<SolutionOf<T::MinerConfig>>, SolutionOrSnapshotSize)>,
+		MinerError,
+	> {
+		let voter_pages = (0..T::Pages::get())
+			.map(|page| Self::snapshot_page(page).map(|snapshot| snapshot.voters))
+			.collect::<Option<Vec<_>>>()
+			.ok_or(MinerError::SnapshotUnAvailable)?;
+		let targets = Self::snapshot().ok_or(MinerError::SnapshotUnAvailable)?.targets;
+		let desired_targets = Self::desired_targets().ok_or(MinerError::SnapshotUnAvailable)?;
+
+		let (solutions, score) = Miner::<T::MinerConfig>::mine_paged_solution_with_snapshot::<
+			T::Solver,
+		>(voter_pages, targets, desired_targets)?;
+		let round = Self::round();
+		Ok(solutions
+			.into_iter()
+			.enumerate()
+			.map(|(page, (solution, size))| {
+				(page as PageIndex, RawSolution { solution, score, round }, size)
+			})
+			.collect())
+	}
+
+	/// Mine a new paged solution as one call per page. Performs all checks.
+	///
+	/// Nothing is submitted if the queued solution has the same score already, and pages that are
+	/// already staged with the same score are left out.
+	pub fn mine_checked_paged_calls() -> Result<Vec<Call<T>>, MinerError> {
+		let desired_targets = Self::desired_targets().ok_or(MinerError::SnapshotUnAvailable)?;
+		let pages = Self::mine_paged_solution()?;
+
+		// make sure that the pages together are accepted, the last one being checked against the
+		// claimed score.
+		let score =
+			pages.first().map(|(_, raw_solution, _)| raw_solution.score).unwrap_or_default();
+		let mut solution =
+			PagedSolution::<T::AccountId, T::MaxWinners> { score, ..Default::default() };
+		let queued = <QueuedPagedSolution<T>>::get().map_or(false, |queued| queued.score == score);
+		let staged = <StagedPagedSolution<T>>::get().map_or(false, |staged| staged.score == score);
+		let mut calls = Vec::with_capacity(pages.len());
+		for (page, raw_solution, witness) in pages {
+			let snapshot = Self::snapshot_page(page).ok_or(MinerError::SnapshotUnAvailable)?;
+			let supports = Self::feasibility_check_page(raw_solution.clone(), snapshot)?;
+			solution.add_page(&supports, desired_targets)?;
+
+			if queued || (staged && <StagedSolutionPages<T>>::contains_key(page)) {
+				continue
+			}
+
+			Self::unsigned_paged_pre_dispatch_checks(page, &raw_solution)
+				.map_err(MinerError::PreDispatchChecksFailed)?;
+			calls.push(Call::submit_unsigned_page {
+				page,
+				raw_solution: Box::new(raw_solution),
+				witness,
+			});
+		}
+		solution.check_score(desired_targets)?;
+
+		log!(debug, "mined a paged solution, submitting {} pages", calls.len());
+		Ok(calls)
+	}
+
+	/// Mine a new paged solution, and submit each of its pages back to the chain as an unsigned
+	/// transaction.
+	///
+	/// Unlike [`Pallet::mine_check_save_submit`], nothing is cached: the solution is mined again
+	/// for as long as the unsigned phase is open and it is not queued.
+	pub fn mine_check_submit_paged() -> Result<(), MinerError> {
+		log!(debug, "miner attempting to compute a paged unsigned solution.");
+
+		Self::mine_checked_paged_calls()?.into_iter().try_for_each(Self::submit_call)
+	}
+
 	fn submit_call(call: Call<T>) -> Result<(), MinerError> {
 		log!(debug, "miner submitting a solution as an unsigned transaction");
 
@@ -372,6 +454,9 @@ impl<T: Config> Pallet<T> {
 		// ensure solution is timely. Don't panic yet. This is a cheap check.
 		ensure!(Self::current_phase().is_unsigned_open(), Error::<T>::PreDispatchEarlySubmission);
 
+		// paged elections only accept paged solutions.
+		ensure!(T::Pages::get() <= 1, Error::<T>::PreDispatchWrongPage);
+
 		// ensure round is current
 		ensure!(Self::round() == raw_solution.round, Error::<T>::OcwCallWrongEra);
 
@@ -391,6 +476,64 @@ impl<T: Config> Pallet<T> {
 
 		Ok(())
 	}
+
+	/// Same as [`Pallet::unsigned_pre_dispatch_checks`], but for page `page` of a paged solution.
+	///
+	/// The claimed score must improve on the one of the queued paged solution. A page either
+	/// belongs to the staged paged solution, and must not be staged yet, or to a solution that
+	/// claims a better score than the staged one.
+	pub fn unsigned_paged_pre_dispatch_checks(
+		page: PageIndex,
+		raw_solution: &RawSolution<SolutionOf<T::MinerConfig>>,
+	) -> DispatchResult {
+		// ensure solution is timely. Don't panic yet. This is a cheap check.
+		ensure!(Self::current_phase().is_unsigned_open(), Error::<T>::PreDispatchEarlySubmission);
+
+		// ensure the page exists.
+		let pages = T::Pages::get();
+		ensure!(pages > 1 && page < pages, Error::<T>::PreDispatchWrongPage);
+
+		// ensure round is current
+		ensure!(Self::round() == raw_solution.round, Error::<T>::OcwCallWrongEra);
+
+		// a page can have less winners than the entire solution.
+		ensure!(
+			raw_solution.solution.unique_targets().len() as u32 <=
+				Self::desired_targets().unwrap_or_default(),
+			Error::<T>::PreDispatchWrongWinnerCount,
+		);
+
+		// ensure the score is being improved.
+		ensure!(
+			<QueuedPagedSolution<T>>::get()
+				.map_or(true, |queued| raw_solution.score > queued.score),
+			Error::<T>::PreDispatchWeakSubmission,
+		);
+
+		// ensure the page is missing, or the staged score is being improved. Panic henceforth.
+		match <StagedPagedSolution<T>>::get() {
+			Some(staged) if staged.score == raw_solution.score => ensure!(
+				!<StagedSolutionPages<T>>::contains_key(page),
+				Error::<T>::PreDispatchWrongPage
+			),
+			Some(staged) =>
+				ensure!(raw_solution.score > staged.score, Error::<T>::PreDispatchWeakSubmission),
+			None => {},
+		}
+
+		Ok(())
+	}
+
+	/// The unsigned checks of a solution, or of page `page` of a paged solution.
+	pub(crate) fn unsigned_checks_of(
+		raw_solution: &RawSolution<SolutionOf<T::MinerConfig>>,
+		maybe_page: Option<PageIndex>,
+	) -> DispatchResult {
+		match maybe_page {
+			None => Self::unsigned_pre_dispatch_checks(raw_solution),
+			Some(page) => Self::unsigned_paged_pre_dispatch_checks(page, raw_solution),
+		}
+	}
 }
 
 /// Configurations for a miner that comes with this pallet.
@@ -454,6 +597,79 @@ impl<T: MinerConfig> Miner<T> {
 			})
 	}
 
+	/// Same as [`Pallet::mine_paged_solution`], but the input snapshot data must be given.
+	///
+	/// The election is solved over the voters of all pages at once, and the result is split into
+	/// one solution per page, each of which is trimmed on its own. Returns the solution and the
+	/// witness of each page, and the score of all pages together.
+	pub fn mine_paged_solution_with_snapshot<S>(
+		voter_pages: Vec<Vec<MinerVoterOf<T>>>,
+		targets: Vec<T::AccountId>,
+		desired_targets: u32,
+	) -> Result<(Vec<(SolutionOf<T>, SolutionOrSnapshotSize)>, ElectionScore), MinerError>
+	where
+		S: NposSolver<AccountId = T::AccountId>,
+	{
+		let all_voters = voter_pages.iter().flatten().cloned().collect::<Vec<_>>();
+		let ElectionResult { assignments, winners: _ } =
+			S::solve(desired_targets as usize, targets.clone(), all_voters.clone()).map_err(
+				|e| {
+					log_no_system!(error, "solver error: {:?}", e);
+					MinerError::Solver
+				},
+			)?;
+
+		// reduce over all voters, each voter then belongs to exactly one page.
+		let cache = helpers::generate_voter_cache::<T>(&all_voters);
+		let stake_of = helpers::stake_of_fn::<T>(&all_voters, &cache);
+		let mut staked = assignment_ratio_to_staked_normalized(assignments, &stake_of)?;
+		sp_npos_elections::reduce(&mut staked);
+
+		let target_index = helpers::target_index_fn::<T>(&targets);
+		let mut solutions = Vec::with_capacity(voter_pages.len());
+		let mut supports: Supports<T::AccountId> = Default::default();
+		for voters in voter_pages {
+			let page_cache = helpers::generate_voter_cache::<T>(&voters);
+			let voter_index = helpers::voter_index_fn::<T>(&page_cache);
+
+			// Sort the assignments of the page by reversed voter stake, to truncate the
+			// lowest stakes first.
+			let (mut page, rest): (Vec<_>, Vec<_>) =
+				staked.into_iter().partition(|a| page_cache.contains_key(&a.who));
+			staked = rest;
+			page.sort_by_key(|a| sp_std::cmp::Reverse(stake_of(&a.who)));
+
+			let mut index_assignments = assignment_staked_to_ratio_normalized(page)?
+				.into_iter()
+				.map(|a| IndexAssignmentOf::<T>::new(&a, &voter_index, &target_index))
+				.collect::<Result<Vec<_>, _>>()?;
+
+			let size = SolutionOrSnapshotSize {
+				voters: voters.len() as u32,
+				targets: targets.len() as u32,
+			};
+			Self::trim_assignments_weight(
+				desired_targets,
+				size,
+				T::MaxWeight::get(),
+				&mut index_assignments,
+			);
+			Self::trim_assignments_length(
+				T::MaxLength::get(),
+				&mut index_assignments,
+				&|assignments: &[IndexAssignmentOf<T>]| {
+					SolutionOf::<T>::try_from(assignments).map(|s| s.encoded_size())
+				},
+			)?;
+
+			let solution = SolutionOf::<T>::try_from(&index_assignments)?;
+			merge_supports(&mut supports, Self::supports_of(solution.clone(), &voters, &targets)?);
+			solutions.push((solution, size));
+		}
+
+		Ok((solutions, supports.evaluate()))
+	}
+
 	/// Convert a raw solution from [`sp_npos_elections::ElectionResult`] to [`RawSolution`], which
 	/// is ready to be submitted to the chain.
 	///
@@ -762,10 +978,63 @@ impl<T: MinerConfig> Miner<T> {
 			FeasibilityError::UntrustedScoreTooLow
 		);
 
+		let supports = Self::supports_of(solution, &snapshot_voters, &snapshot_targets)?;
+
+		// Finally, check that the claimed score was indeed correct.
+		let known_score = supports.evaluate();
+		ensure!(known_score == score, FeasibilityError::InvalidScore);
+
+		// Size of winners in miner solution is equal to `desired_targets` <= `MaxWinners`.
+		let supports = supports
+			.try_into()
+			.defensive_map_err(|_| FeasibilityError::BoundedConversionFailed)?;
+
+		Ok(ReadySolution { supports, compute, score })
+	}
+
+	/// Checks the feasibility of a page of a paged solution, against its page of the snapshot.
+	///
+	/// Unlike [`Self::feasibility_check`], a page may back less than `desired_targets` winners,
+	/// and the claimed score can only be checked once all pages are there.
+	pub fn feasibility_check_page(
+		raw_solution: RawSolution<SolutionOf<T>>,
+		desired_targets: u32,
+		snapshot: RoundSnapshot<T::AccountId, MinerVoterOf<T>>,
+		current_round: u32,
+		minimum_untrusted_score: Option<ElectionScore>,
+	) -> Result<BoundedSupports<T::AccountId, T::MaxWinners>, FeasibilityError> {
+		let RawSolution { solution, score, round } = raw_solution;
+		let RoundSnapshot { voters: snapshot_voters, targets: snapshot_targets } = snapshot;
+
+		ensure!(current_round == round, FeasibilityError::InvalidRound);
+		ensure!(
+			solution.unique_targets().len() as u32 <= desired_targets,
+			FeasibilityError::WrongWinnerCount
+		);
+		ensure!(desired_targets <= T::MaxWinners::get(), FeasibilityError::TooManyDesiredTargets);
+		ensure!(
+			minimum_untrusted_score.map_or(true, |min_score| {
+				score.strict_threshold_better(min_score, sp_runtime::Perbill::zero())
+			}),
+			FeasibilityError::UntrustedScoreTooLow
+		);
+
+		Self::supports_of(solution, &snapshot_voters, &snapshot_targets)?
+			.try_into()
+			.defensive_map_err(|_| FeasibilityError::BoundedConversionFailed)
+	}
+
+	/// The supports of `solution`, after checking that all of its votes are part of the given
+	/// snapshot.
+	fn supports_of(
+		solution: SolutionOf<T>,
+		snapshot_voters: &Vec<MinerVoterOf<T>>,
+		snapshot_targets: &Vec<T::AccountId>,
+	) -> Result<Supports<T::AccountId>, FeasibilityError> {
 		// ----- Start building. First, we need some closures.
-		let cache = helpers::generate_voter_cache::<T>(&snapshot_voters);
-		let voter_at = helpers::voter_at_fn::<T>(&snapshot_voters);
-		let target_at = helpers::target_at_fn::<T>(&snapshot_targets);
+		let cache = helpers::generate_voter_cache::<T>(snapshot_voters);
+		let voter_at = helpers::voter_at_fn::<T>(snapshot_voters);
+		let target_at = helpers::target_at_fn::<T>(snapshot_targets);
 		let voter_index = helpers::voter_index_fn_usize::<T>(&cache);
 
 		// Then convert solution -> assignment. This will fail if any of the indices are gibberish,
@@ -797,23 +1066,12 @@ impl<T: MinerConfig> Miner<T> {
 		})?;
 
 		// ----- Start building support. First, we need one more closure.
-		let stake_of = helpers::stake_of_fn::<T>(&snapshot_voters, &cache);
+		let stake_of = helpers::stake_of_fn::<T>(snapshot_voters, &cache);
 
 		// This might fail if the normalization fails. Very unlikely. See `integrity_test`.
 		let staked_assignments = assignment_ratio_to_staked_normalized(assignments, stake_of)
 			.map_err::<FeasibilityError, _>(Into::into)?;
-		let supports = sp_npos_elections::to_supports(&staked_assignments);
-
-		// Finally, check that the claimed score was indeed correct.
-		let known_score = supports.evaluate();
-		ensure!(known_score == score, FeasibilityError::InvalidScore);
-
-		// Size of winners in miner solution is equal to `desired_targets` <= `MaxWinners`.
-		let supports = supports
-			.try_into()
-			.defensive_map_err(|_| FeasibilityError::BoundedConversionFailed)?;
-
-		Ok(ReadySolution { supports, compute, score })
+		Ok(sp_npos_elections::to_supports(&staked_assignments))
 	}
 }
 
@@ -1853,6 +2111,95 @@ mod tests {
 		})
 	}
 
+	#[test]
+	fn ocw_submits_every_page_of_a_paged_solution() {
+		let (mut ext, pool) = ExtBuilder::default().pages(2).phases(0, 5).build_offchainify(0);
+		ext.execute_with(|| {
+			roll_to_with_ocw(25);
+			assert_eq!(MultiPhase::current_phase(), Phase::Unsigned((true, 25)));
+
+			let pages = pool
+				.read()
+				.transactions
+				.iter()
+				.map(|encoded| match Extrinsic::decode(&mut &**encoded).unwrap().call {
+					RuntimeCall::MultiPhase(Call::submit_unsigned_page { page, .. }) => page,
+					_ => panic!("bad call: unexpected submission"),
+				})
+				.collect::<Vec<_>>();
+			assert_eq!(pages, vec![0, 1]);
+		})
+	}
+
+	#[test]
+	fn paged_pre_dispatch_checks_reject_wrong_pages() {
+		ExtBuilder::default().pages(2).phases(0, 5).build_and_execute(|| {
+			roll_to_unsigned();
+
+			let (page, raw_solution, witness) =
+				match MultiPhase::mine_checked_paged_calls().unwrap().remove(0) {
+					Call::submit_unsigned_page { page, raw_solution, witness } =>
+						(page, raw_solution, witness),
+					_ => unreachable!(),
+				};
+
+			// entire solutions and pages that do not exist are rejected.
+			assert_noop!(
+				MultiPhase::unsigned_pre_dispatch_checks(&raw_solution),
+				Error::<Runtime>::PreDispatchWrongPage
+			);
+			assert_noop!(
+				MultiPhase::unsigned_paged_pre_dispatch_checks(2, &raw_solution),
+				Error::<Runtime>::PreDispatchWrongPage
+			);
+
+			assert_ok!(MultiPhase::submit_unsigned_page(
+				RuntimeOrigin::none(),
+				page,
+				raw_solution.clone(),
+				witness
+			));
+
+			// a page cannot be queued twice, only the missing page is mined again.
+			assert_noop!(
+				MultiPhase::unsigned_paged_pre_dispatch_checks(page, &raw_solution),
+				Error::<Runtime>::PreDispatchWrongPage
+			);
+			assert_eq!(MultiPhase::mine_checked_paged_calls().unwrap().len(), 1);
+
+			// a weaker solution cannot replace the queued one.
+			let mut weaker = raw_solution.clone();
+			weaker.score.minimal_stake -= 1;
+			assert_noop!(
+				MultiPhase::unsigned_paged_pre_dispatch_checks(1, &weaker),
+				Error::<Runtime>::PreDispatchWeakSubmission
+			);
+		})
+	}
+
+	#[test]
+	#[should_panic(expected = "Invalid unsigned submission must produce invalid block and \
+	                           deprive validator from their authoring reward.: InvalidScore")]
+	fn paged_solution_with_wrong_score_panics_on_the_last_page() {
+		ExtBuilder::default().pages(2).phases(0, 5).build_and_execute(|| {
+			roll_to_unsigned();
+
+			for call in MultiPhase::mine_checked_paged_calls().unwrap() {
+				if let Call::submit_unsigned_page { page, mut raw_solution, witness } = call {
+					raw_solution.score.sum_stake += 1;
+					let _ = MultiPhase::submit_unsigned_page(
+						RuntimeOrigin::none(),
+						page,
+						raw_solution,
+						witness,
+					);
+					// the first page is checked on its own.
+					assert_eq!(StagedSolutionPages::<Runtime>::iter().count(), 1);
+				}
+			}
+		})
+	}
+
 	#[test]
 	fn trim_assignments_length_does_not_modify_when_short_enough() {
 		ExtBuilder::default().build_and_execute(|| {
@@ -387,3 +387,46 @@ fn ledger_consistency_active_balance_below_ed() {
 		assert!(Staking::ledger(11.into()).is_err());
 	});
 }
+
+#[test]
+/// Elects the validators of an era with a paged election: the voters are fetched, the solution is
+/// submitted and verified, and the result is served to staking, one page at a time.
+fn paged_election_works() {
+	use frame_election_provider_support::bounds::ElectionBoundsBuilder;
+	use pallet_election_provider_multi_phase::QueuedPagedSolution;
+
+	let (mut ext, pool_state, _) =
+		ExtBuilder::default().epm(EpmExtBuilder::default().pages(3)).build_offchainify();
+
+	ext.execute_with(|| {
+		// at most 4 of the 10 voters fit in a page.
+		ElectionBounds::set(
+			ElectionBoundsBuilder::default()
+				.voters_count(4.into())
+				.targets_count(1_000.into())
+				.build(),
+		);
+
+		while !ElectionProviderMultiPhase::current_phase().is_unsigned() {
+			roll_one(pool_state.clone(), false);
+		}
+		let voters = (0..3)
+			.map(|page| ElectionProviderMultiPhase::snapshot_page(page).unwrap().voters.len())
+			.collect::<Vec<_>>();
+		assert_eq!(voters, vec![2, 4, 4]);
+
+		// the offchain worker submitted all pages, each of them was verified on its own.
+		assert_eq!(QueuedPagedSolution::<Runtime>::get().unwrap().checked_pages, 3);
+
+		assert_ok!(start_next_active_era(pool_state.clone()));
+		assert!(QueuedPagedSolution::<Runtime>::get().is_none());
+
+		// the winners are exposed with the stake of the voters of all pages.
+		let validators = Session::validators();
+		assert_eq!(validators.len(), 2);
+		for validator in validators {
+			assert!(pallet_staking::ErasStakersOverview::<Runtime>::get(active_era(), validator)
+				.is_some());
+		}
+	});
+}
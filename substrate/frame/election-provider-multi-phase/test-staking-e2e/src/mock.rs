@@ -43,7 +43,7 @@ use std::collections::BTreeMap;
 
 use codec::Decode;
 use frame_election_provider_support::{
-	bounds::ElectionBoundsBuilder, onchain, ElectionDataProvider, ExtendedBalance,
+	bounds::ElectionBoundsBuilder, onchain, ElectionDataProvider, ExtendedBalance, PageIndex,
 	SequentialPhragmen, Weight,
 };
 use pallet_election_provider_multi_phase::{
@@ -166,6 +166,7 @@ frame_election_provider_support::generate_solution_type!(
 parameter_types! {
 	pub static SignedPhase: BlockNumber = 10;
 	pub static UnsignedPhase: BlockNumber = 10;
+	pub static Pages: PageIndex = 1;
 	// we expect a minimum of 3 blocks in signed phase and unsigned phases before trying
 	// enetering in emergency phase after the election failed.
 	pub static MinBlocksBeforeEmergency: BlockNumber = 3;
@@ -189,6 +190,7 @@ impl pallet_election_provider_multi_phase::Config for Runtime {
 	type EstimateCallFee = frame_support::traits::ConstU32<8>;
 	type SignedPhase = SignedPhase;
 	type UnsignedPhase = UnsignedPhase;
+	type Pages = Pages;
 	type BetterSignedThreshold = ();
 	type OffchainRepeat = OffchainRepeat;
 	type MinerTxPriority = TransactionPriority;
@@ -268,6 +270,7 @@ impl pallet_staking::Config for Runtime {
 	type EraPayout = ();
 	type NextNewSession = Session;
 	type MaxExposurePageSize = ConstU32<256>;
+	type MaxAutoPayoutsPerBlock = ConstU32<0>;
 	type OffendingValidatorsThreshold = OffendingValidatorsThreshold;
 	type ElectionProvider = ElectionProviderMultiPhase;
 	type GenesisElectionProvider = onchain::OnChainExecution<OnChainSeqPhragmen>;
@@ -416,6 +419,13 @@ impl EpmExtBuilder {
 		<UnsignedPhase>::set(unsigned);
 		self
 	}
+
+	/// Paged elections do not support the signed phase, which is disabled.
+	pub fn pages(self, pages: PageIndex) -> Self {
+		<Pages>::set(pages);
+		<SignedPhase>::set(0);
+		self
+	}
 }
 
 pub struct BalancesExtBuilder {
@@ -631,7 +641,8 @@ pub fn roll_to_with_ocw(n: BlockNumber, pool: Arc<RwLock<PoolState>>, delay_solu
 
 				let _ = match extrinsic.call {
 					RuntimeCall::ElectionProviderMultiPhase(
-						call @ Call::submit_unsigned { .. },
+						call @ Call::submit_unsigned { .. } |
+						call @ Call::submit_unsigned_page { .. },
 					) => {
 						// call submit_unsigned callable in OCW pool.
 						crate::assert_ok!(call.dispatch_bypass_filter(RuntimeOrigin::none()));
@@ -273,6 +273,12 @@ pub type IndexAssignmentOf<C> = IndexAssignment<
 	<C as NposSolution>::Accuracy,
 >;
 
+/// The index of a page of a paged election.
+///
+/// Pages are processed from the highest index, `pages - 1`, down to `0`, which is always the last
+/// page of an election.
+pub type PageIndex = u32;
+
 /// Types that are used by the data provider trait.
 pub mod data_provider {
 	/// Alias for the result type of the election data provider.
@@ -306,6 +312,32 @@ pub trait ElectionDataProvider {
 	/// appropriate weight at the end of execution with the system pallet directly.
 	fn electing_voters(bounds: DataProviderBounds) -> data_provider::Result<Vec<VoterOf<Self>>>;
 
+	/// A single page of the voters that participate in the election.
+	///
+	/// Pages are requested from the highest index down to `0`. Each page contains the voters that
+	/// follow the ones returned by the previously requested page, with `bounds` applying to each
+	/// page individually, such that all pages together can hold up to `pages` times `bounds`.
+	/// Requesting page `0` concludes the voters of the election, and the next page requested
+	/// afterwards starts over with the first voters.
+	///
+	/// A voter must not be part of more than one page of the same election, as its stake would
+	/// otherwise be counted more than once.
+	///
+	/// The default implementation returns all voters in page `0` and none in any other page.
+	///
+	/// This should be implemented as a self-weighing function. The implementor should register its
+	/// appropriate weight at the end of execution with the system pallet directly.
+	fn electing_voters_paged(
+		bounds: DataProviderBounds,
+		page: PageIndex,
+	) -> data_provider::Result<Vec<VoterOf<Self>>> {
+		if page.is_zero() {
+			Self::electing_voters(bounds)
+		} else {
+			Ok(Default::default())
+		}
+	}
+
 	/// The number of targets to elect.
 	///
 	/// This should be implemented as a self-weighing function. The implementor should register its
@@ -415,6 +447,30 @@ pub trait ElectionProvider: ElectionProviderBase {
 	/// implementor should register its appropriate weight at the end of execution with the
 	/// system pallet directly.
 	fn elect() -> Result<BoundedSupportsOf<Self>, Self::Error>;
+
+	/// The number of pages the result of an election is split into.
+	///
+	/// Election providers which are not paged return the entire result in a single page.
+	fn pages() -> PageIndex {
+		1
+	}
+
+	/// Return a single page of the result of the election.
+	///
+	/// Pages are requested from `Self::pages() - 1` down to `0`, and the result of the election
+	/// is the union of all pages, see [`merge_supports`]. Every voter backs winners in at most one
+	/// page, while a winner can be backed by voters of several pages. Requesting page `0`
+	/// concludes the election.
+	///
+	/// The default implementation performs the election in page `0`, and returns no winners in
+	/// any other page.
+	fn elect_paged(page: PageIndex) -> Result<BoundedSupportsOf<Self>, Self::Error> {
+		if page.is_zero() {
+			Self::elect()
+		} else {
+			Ok(Default::default())
+		}
+	}
 }
 
 /// A (almost) marker trait that signifies an election provider as working synchronously. i.e. being
@@ -671,6 +727,22 @@ pub type Voter<AccountId, Bound> = (AccountId, VoteWeight, BoundedVec<AccountId,
 pub type VoterOf<D> =
 	Voter<<D as ElectionDataProvider>::AccountId, <D as ElectionDataProvider>::MaxVotesPerVoter>;
 
+/// Merge a page of backings into the backings of the previous pages of an election.
+pub fn merge_supports<AccountId: PartialEq>(
+	supports: &mut Supports<AccountId>,
+	page: Supports<AccountId>,
+) {
+	for (winner, support) in page {
+		match supports.iter_mut().find(|(who, _)| who == &winner) {
+			Some((_, existing)) => {
+				existing.total = existing.total.saturating_add(support.total);
+				existing.voters.extend(support.voters);
+			},
+			None => supports.push((winner, support)),
+		}
+	}
+}
+
 /// Same as `BoundedSupports` but parameterized by a `ElectionProviderBase`.
 pub type BoundedSupportsOf<E> = BoundedSupports<
 	<E as ElectionProviderBase>::AccountId,
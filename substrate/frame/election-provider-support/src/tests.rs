@@ -452,3 +452,30 @@ fn index_assignments_generate_same_solution_as_plain_assignments() {
 
 	assert_eq!(solution, index_compact);
 }
+
+#[test]
+fn paged_supports_merge_into_original_supports() {
+	use crate::{merge_supports, Support, Supports};
+
+	// the backings of two pages, with winner `1` backed in both of them.
+	let page_1: Supports<u32> = vec![
+		(1, Support { total: 50, voters: vec![(11, 20), (12, 30)] }),
+		(3, Support { total: 0, voters: vec![] }),
+	];
+	let page_0: Supports<u32> = vec![
+		(1, Support { total: 10, voters: vec![(10, 10)] }),
+		(2, Support { total: 5, voters: vec![(13, 5)] }),
+	];
+
+	let mut merged = Supports::new();
+	merge_supports(&mut merged, page_1);
+	merge_supports(&mut merged, page_0);
+	assert_eq!(
+		merged,
+		vec![
+			(1, Support { total: 60, voters: vec![(11, 20), (12, 30), (10, 10)] }),
+			(3, Support { total: 0, voters: vec![] }),
+			(2, Support { total: 5, voters: vec![(13, 5)] }),
+		]
+	);
+}
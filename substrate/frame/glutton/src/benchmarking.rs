@@ -65,6 +65,42 @@ benchmarks! {
 		})
 	}
 
+	waste_proof_size_write {
+		let i in 0..5_000;
+
+		(0..5000).for_each(|i| TrashData::<T>::insert(i, [i as u8; 1024]));
+		let seed = System::<T>::block_number();
+	}: {
+		(0..i).for_each(|i| Glutton::<T>::overwrite_value(TrashTrie::Main, 0, i, &seed))
+	}
+
+	waste_child_proof_size_some {
+		let i in 0..5_000;
+
+		(0..5000).for_each(|i| Glutton::<T>::insert_child_value(i, 0));
+	}: {
+		(0..i).for_each(|i| Glutton::<T>::read_value(TrashTrie::Child, 0, i))
+	}
+
+	waste_child_proof_size_write {
+		let i in 0..5_000;
+
+		(0..5000).for_each(|i| Glutton::<T>::insert_child_value(i, 0));
+		let seed = System::<T>::block_number();
+	}: {
+		(0..i).for_each(|i| Glutton::<T>::overwrite_value(TrashTrie::Child, 0, i, &seed))
+	}
+
+	waste_child_trie_depth {
+		let d in 0 .. MAX_TRIE_DEPTH as u32;
+
+		(0..1000).for_each(|i| Glutton::<T>::insert_child_value(i, MAX_TRIE_DEPTH));
+	}: {
+		(0..d).for_each(|level| {
+			child::get_raw(&Glutton::<T>::child_trie(), &Glutton::<T>::child_key(0, level as u8));
+		})
+	}
+
 	// For manual verification only.
 	on_idle_high_proof_waste {
 		(0..5000).for_each(|i| TrashData::<T>::insert(i, [i as u8; 1024]));
@@ -95,5 +131,31 @@ benchmarks! {
 	set_storage {
 	}: _(SystemOrigin::Root, FixedU64::from_perbill(Perbill::from_percent(50)))
 
+	initialize_child_trie_grow {
+		let n in 0 .. 1_000;
+	}: {
+		Glutton::<T>::initialize_child_trie(SystemOrigin::Root.into(), n, 0, None).unwrap()
+	} verify {
+		assert_eq!(ChildTrashDataCount::<T>::get(), n);
+	}
+
+	initialize_child_trie_shrink {
+		let n in 0 .. 1_000;
+
+		Glutton::<T>::initialize_child_trie(SystemOrigin::Root.into(), n, 0, None).unwrap();
+	}: {
+		Glutton::<T>::initialize_child_trie(SystemOrigin::Root.into(), 0, 0, Some(n)).unwrap()
+	} verify {
+		assert_eq!(ChildTrashDataCount::<T>::get(), 0);
+	}
+
+	set_pov_pattern {
+		let pattern = PovPattern {
+			access: KeyAccess::Random,
+			trie: TrashTrie::Child,
+			writes: Perbill::from_percent(50),
+		};
+	}: _(SystemOrigin::Root, pattern)
+
 	impl_benchmark_test_suite!(Glutton, crate::mock::new_test_ext(), crate::mock::Test);
 }
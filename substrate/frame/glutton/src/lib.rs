@@ -23,6 +23,10 @@
 //!
 //! Pallet that consumes `ref_time` and `proof_size` of a block. Based on the `Compute` and
 //! `Storage` parameters the pallet consumes the adequate amount of weight.
+//!
+//! How the proof size is consumed is configured with a [`PovPattern`]: the trash data can be
+//! accessed sequentially or randomly, read or overwritten, and taken from the main trie or from a
+//! child trie in which every value sits below a configurable number of nested entries.
 
 #![deny(missing_docs)]
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -36,21 +40,72 @@ mod tests;
 pub mod weights;
 
 use blake2::{Blake2b512, Digest};
-use frame_support::{pallet_prelude::*, weights::WeightMeter, DefaultNoBound};
+use frame_support::{
+	pallet_prelude::*,
+	storage::child::{self, ChildInfo},
+	weights::WeightMeter,
+	DefaultNoBound,
+};
 use frame_system::pallet_prelude::*;
-use sp_io::hashing::twox_256;
-use sp_runtime::{traits::Zero, FixedPointNumber, FixedU64};
+use sp_io::hashing::{twox_256, twox_64};
+use sp_runtime::{traits::Zero, FixedPointNumber, FixedU64, PerThing, Perbill};
 use sp_std::{vec, vec::Vec};
 
 pub use pallet::*;
 pub use weights::WeightInfo;
 
+/// The log target of this pallet.
+pub const LOG_TARGET: &str = "runtime::glutton";
+
 /// The size of each value in the `TrashData` storage in bytes.
 pub const VALUE_SIZE: usize = 1024;
 /// Max number of entries for the `TrashData` map.
 pub const MAX_TRASH_DATA_ENTRIES: u32 = 65_000;
 /// Hard limit for any other resource limit (in units).
 pub const RESOURCE_HARD_LIMIT: FixedU64 = FixedU64::from_u32(10);
+/// Max number of nested entries above each value of the child trie.
+pub const MAX_TRIE_DEPTH: u8 = 16;
+/// The unprefixed id of the child trie holding the child trash data.
+pub const CHILD_TRIE_ID: &[u8] = b"glutton";
+
+/// The order in which `on_idle` accesses the trash data.
+#[derive(
+	Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Copy, PartialEq, Eq, RuntimeDebug, Default,
+)]
+pub enum KeyAccess {
+	/// The entries are accessed in the order of their index, starting with the first one.
+	#[default]
+	Sequential,
+	/// The entries are accessed in a pseudo-random order which changes with every block.
+	Random,
+}
+
+/// The trie `on_idle` takes the trash data from.
+#[derive(
+	Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Copy, PartialEq, Eq, RuntimeDebug, Default,
+)]
+pub enum TrashTrie {
+	/// The `TrashData` map of the main trie.
+	#[default]
+	Main,
+	/// The child trie set up with `initialize_child_trie`.
+	///
+	/// Reaching one of its values means reading all the entries nested above it.
+	Child,
+}
+
+/// How `on_idle` consumes proof size.
+#[derive(
+	Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Copy, PartialEq, Eq, RuntimeDebug, Default,
+)]
+pub struct PovPattern {
+	/// The order in which the trash data is accessed.
+	pub access: KeyAccess,
+	/// The trie the trash data is taken from.
+	pub trie: TrashTrie,
+	/// The proportion of the accesses which overwrite a value instead of reading it.
+	pub writes: Perbill,
+}
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -89,6 +144,31 @@ pub mod pallet {
 			/// The storage limit.
 			storage: FixedU64,
 		},
+		/// The child trie has been (re)initialized.
+		ChildTrieInitialized {
+			/// The number of values in the child trie.
+			count: u32,
+			/// The number of nested entries above each value.
+			depth: u8,
+		},
+		/// The pattern used to consume proof size has been updated.
+		PovPatternSet {
+			/// The pattern.
+			pattern: PovPattern,
+		},
+		/// Proof size has been consumed by accessing the trash data.
+		ProofSizeWasted {
+			/// The proof size which `on_idle` aimed to consume.
+			target: u64,
+			/// The proof size the accesses account for, according to the benchmarks. This is an
+			/// upper bound of what they added to the proof, not a measurement of it. The measured
+			/// proof size is logged next to it if the `measure-proof-size` feature is enabled.
+			estimated: u64,
+			/// The number of values which were read.
+			reads: u32,
+			/// The number of values which were overwritten.
+			writes: u32,
+		},
 	}
 
 	#[pallet::error]
@@ -100,6 +180,12 @@ pub mod pallet {
 
 		/// The limit was over [`crate::RESOURCE_HARD_LIMIT`].
 		InsaneLimit,
+
+		/// The depth was over [`crate::MAX_TRIE_DEPTH`].
+		InsaneDepth,
+
+		/// The depth of the child trie can only be changed while it is empty.
+		ChildTrieNotEmpty,
 	}
 
 	/// The proportion of the remaining `ref_time` to consume during `on_idle`.
@@ -137,6 +223,18 @@ pub mod pallet {
 	#[pallet::storage]
 	pub(crate) type TrashDataCount<T: Config> = StorageValue<_, u32, ValueQuery>;
 
+	/// The current number of values in the child trie.
+	#[pallet::storage]
+	pub(crate) type ChildTrashDataCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// The number of entries nested above each value of the child trie.
+	#[pallet::storage]
+	pub(crate) type ChildTrashDataDepth<T: Config> = StorageValue<_, u8, ValueQuery>;
+
+	/// The pattern `on_idle` uses to consume proof size.
+	#[pallet::storage]
+	pub(crate) type Pattern<T: Config> = StorageValue<_, PovPattern, ValueQuery>;
+
 	#[pallet::genesis_config]
 	#[derive(DefaultNoBound)]
 	pub struct GenesisConfig<T: Config> {
@@ -184,6 +282,10 @@ pub mod pallet {
 				!T::WeightInfo::waste_proof_size_some(1).proof_size().is_zero(),
 				"Weight zero; would get stuck in an infinite loop"
 			);
+			assert!(
+				!T::WeightInfo::waste_child_proof_size_some(1).proof_size().is_zero(),
+				"Weight zero; would get stuck in an infinite loop"
+			);
 		}
 
 		fn on_idle(_: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
@@ -201,7 +303,24 @@ pub mod pallet {
 				proof_size_limit,
 			));
 
-			Self::waste_at_most_proof_size(&mut meter);
+			let (reads, writes, achieved) = Self::waste_at_most_proof_size(&mut meter);
+			if reads > 0 || writes > 0 {
+				Self::deposit_event(Event::ProofSizeWasted {
+					target: proof_size_limit,
+					estimated: meter.consumed().proof_size(),
+					reads,
+					writes,
+				});
+				// The measurement stays out of the event, since it differs between the author, the
+				// importers and the validators of the block.
+				log::debug!(
+					target: LOG_TARGET,
+					"Wasted proof size: target {}, estimated {}, achieved {:?}",
+					proof_size_limit,
+					meter.consumed().proof_size(),
+					achieved,
+				);
+			}
 			Self::waste_at_most_ref_time(&mut meter);
 
 			meter.consumed()
@@ -277,26 +396,156 @@ pub mod pallet {
 			Self::deposit_event(Event::StorageLimitSet { storage });
 			Ok(())
 		}
+
+		/// Initialize the child trie with `new_count` values, each below `depth` nested entries.
+		///
+		/// `witness_count` is the current number of values in the child trie, like in
+		/// `initialize_pallet`. The depth can only be changed while the child trie is empty, so
+		/// shrink it to zero first to change it.
+		///
+		/// Only callable by Root or `AdminOrigin`.
+		#[pallet::call_index(3)]
+		#[pallet::weight({
+			let entries = u32::from(*depth).saturating_add(1);
+			T::WeightInfo::initialize_child_trie_grow(new_count.saturating_mul(entries)).max(
+				T::WeightInfo::initialize_child_trie_shrink(
+					witness_count.unwrap_or_default().saturating_mul(entries),
+				),
+			)
+		})]
+		pub fn initialize_child_trie(
+			origin: OriginFor<T>,
+			new_count: u32,
+			depth: u8,
+			witness_count: Option<u32>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin_or_root(origin)?;
+			ensure!(depth <= MAX_TRIE_DEPTH, Error::<T>::InsaneDepth);
+
+			let current_count = ChildTrashDataCount::<T>::get();
+			ensure!(
+				current_count == witness_count.unwrap_or_default(),
+				Error::<T>::AlreadyInitialized
+			);
+			ensure!(
+				current_count == 0 || depth == ChildTrashDataDepth::<T>::get(),
+				Error::<T>::ChildTrieNotEmpty
+			);
+
+			if new_count > current_count {
+				(current_count..new_count).for_each(|i| Self::insert_child_value(i, depth));
+			} else {
+				(new_count..current_count).for_each(|i| Self::remove_child_value(i, depth));
+			}
+
+			Self::deposit_event(Event::ChildTrieInitialized { count: new_count, depth });
+			ChildTrashDataCount::<T>::set(new_count);
+			ChildTrashDataDepth::<T>::set(depth);
+			Ok(())
+		}
+
+		/// Set the pattern `on_idle` uses to consume proof size.
+		///
+		/// Only callable by Root or `AdminOrigin`.
+		#[pallet::call_index(4)]
+		pub fn set_pov_pattern(origin: OriginFor<T>, pattern: PovPattern) -> DispatchResult {
+			T::AdminOrigin::ensure_origin_or_root(origin)?;
+
+			Pattern::<T>::set(pattern);
+
+			Self::deposit_event(Event::PovPatternSet { pattern });
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
-		/// Waste at most the remaining proof size of `meter`.
+		/// Waste at most the remaining proof size of `meter`, following the [`Pattern`].
 		///
-		/// Tries to come as close to the limit as possible.
-		pub(crate) fn waste_at_most_proof_size(meter: &mut WeightMeter) {
-			let Ok(n) = Self::calculate_proof_size_iters(&meter) else { return };
-
-			meter.consume(T::WeightInfo::waste_proof_size_some(n));
+		/// Tries to come as close to the limit as possible. Returns the number of values which were
+		/// read and overwritten, and the proof size the accesses added if a proof is recorded.
+		pub(crate) fn waste_at_most_proof_size(meter: &mut WeightMeter) -> (u32, u32, Option<u64>) {
+			let pattern = Pattern::<T>::get();
+			let (count, depth) = match pattern.trie {
+				TrashTrie::Main => (TrashDataCount::<T>::get(), 0),
+				TrashTrie::Child =>
+					(ChildTrashDataCount::<T>::get(), ChildTrashDataDepth::<T>::get()),
+			};
+			// Only existing values are overwritten, so that the trash data doesn't grow.
+			let write_ratio = if count.is_zero() { Perbill::zero() } else { pattern.writes };
+			let Ok(n) = Self::calculate_proof_size_iters(&meter, pattern.trie, depth, write_ratio)
+			else {
+				return (0, 0, None)
+			};
+			let writes = write_ratio.mul_floor(n);
+			let reads = n - writes;
+
+			let weight = Self::proof_size_weight(pattern.trie, depth, reads, writes);
+			if meter.try_consume(weight).is_err() {
+				return (0, 0, None)
+			}
 
-			(0..n).for_each(|i| {
-				TrashData::<T>::get(i);
+			let seed = frame_system::Pallet::<T>::block_number();
+			let before = Self::recorded_proof_size();
+			(0..n).for_each(|i| match pattern.access {
+				KeyAccess::Sequential if i < reads => Self::read_value(pattern.trie, depth, i),
+				KeyAccess::Sequential =>
+					Self::overwrite_value(pattern.trie, depth, i % count, &seed),
+				KeyAccess::Random => {
+					let index = Self::random_index(&seed, i, count);
+					if i < reads {
+						Self::read_value(pattern.trie, depth, index)
+					} else {
+						Self::overwrite_value(pattern.trie, depth, index, &seed)
+					}
+				},
 			});
+			let achieved = before
+				.zip(Self::recorded_proof_size())
+				.map(|(before, after)| after.saturating_sub(before));
+
+			(reads, writes, achieved)
 		}
 
-		/// Calculate how many times `waste_proof_size_some` should be called to fill up `meter`.
-		fn calculate_proof_size_iters(meter: &WeightMeter) -> Result<u32, ()> {
-			let base = T::WeightInfo::waste_proof_size_some(0);
-			let slope = T::WeightInfo::waste_proof_size_some(1).saturating_sub(base);
+		/// The size of the storage proof recorded so far.
+		///
+		/// Returns `None` if the host does not record a storage proof, or if the
+		/// `measure-proof-size` feature is disabled.
+		fn recorded_proof_size() -> Option<u64> {
+			#[cfg(feature = "measure-proof-size")]
+			{
+				use cumulus_primitives_proof_size_hostfunction::{
+					storage_proof_size, PROOF_RECORDING_DISABLED,
+				};
+
+				let size = storage_proof_size::storage_proof_size();
+				(size != PROOF_RECORDING_DISABLED).then_some(size)
+			}
+			#[cfg(not(feature = "measure-proof-size"))]
+			{
+				None
+			}
+		}
+
+		/// Calculate how many values should be accessed with a `write_ratio` to fill up `meter`.
+		fn calculate_proof_size_iters(
+			meter: &WeightMeter,
+			trie: TrashTrie,
+			depth: u8,
+			write_ratio: Perbill,
+		) -> Result<u32, ()> {
+			let base = Self::proof_size_weight(trie, depth, 0, 0);
+			let read = Self::proof_size_weight(trie, depth, 1, 0).saturating_sub(base);
+			let write = Self::proof_size_weight(trie, depth, 0, 1).saturating_sub(base);
+			let blend = |read: u64, write: u64| {
+				write_ratio
+					.left_from_one()
+					.mul_ceil(read)
+					.saturating_add(write_ratio.mul_ceil(write))
+			};
+			let slope = Weight::from_parts(
+				blend(read.ref_time(), write.ref_time()),
+				blend(read.proof_size(), write.proof_size()),
+			);
 
 			let remaining = meter.remaining().saturating_sub(base);
 			let iter_by_proof_size =
@@ -310,6 +559,89 @@ pub mod pallet {
 			}
 		}
 
+		/// The weight of reading `reads` and overwriting `writes` values of `trie`.
+		fn proof_size_weight(trie: TrashTrie, depth: u8, reads: u32, writes: u32) -> Weight {
+			match trie {
+				TrashTrie::Main => T::WeightInfo::waste_proof_size_some(reads)
+					.saturating_add(T::WeightInfo::waste_proof_size_write(writes)),
+				TrashTrie::Child => {
+					let nesting = T::WeightInfo::waste_child_trie_depth(depth.into())
+						.saturating_sub(T::WeightInfo::waste_child_trie_depth(0));
+					T::WeightInfo::waste_child_proof_size_some(reads)
+						.saturating_add(T::WeightInfo::waste_child_proof_size_write(writes))
+						.saturating_add(nesting.saturating_mul(reads.saturating_add(writes).into()))
+				},
+			}
+		}
+
+		/// Read the value `index` of `trie`, including the entries nested above it.
+		pub(crate) fn read_value(trie: TrashTrie, depth: u8, index: u32) {
+			match trie {
+				TrashTrie::Main => {
+					TrashData::<T>::get(index);
+				},
+				TrashTrie::Child => {
+					(0..=depth).for_each(|level| {
+						child::get_raw(&Self::child_trie(), &Self::child_key(index, level));
+					});
+				},
+			}
+		}
+
+		/// Overwrite the value `index` of `trie` with one derived from `seed`.
+		pub(crate) fn overwrite_value(trie: TrashTrie, depth: u8, index: u32, seed: impl Encode) {
+			let value = [(seed, index).using_encoded(twox_64)[0]; VALUE_SIZE];
+			match trie {
+				TrashTrie::Main => TrashData::<T>::insert(index, value),
+				TrashTrie::Child => {
+					(0..depth).for_each(|level| {
+						child::get_raw(&Self::child_trie(), &Self::child_key(index, level));
+					});
+					child::put_raw(&Self::child_trie(), &Self::child_key(index, depth), &value);
+				},
+			}
+		}
+
+		/// The pseudo-random index, derived from `seed`, of the `i`th value to access out of
+		/// `count` values.
+		pub(crate) fn random_index(seed: impl Encode, i: u32, count: u32) -> u32 {
+			if count.is_zero() {
+				return i
+			}
+			let hash = (seed, i).using_encoded(twox_64);
+			(u64::from_le_bytes(hash) % u64::from(count)) as u32
+		}
+
+		/// The child trie holding the child trash data.
+		pub(crate) fn child_trie() -> ChildInfo {
+			ChildInfo::new_default(CHILD_TRIE_ID)
+		}
+
+		/// The key of the entry at `level` above the value `index` of the child trie.
+		///
+		/// The keys of the entries above a value are prefixes of its key, so that each of them is a
+		/// node on the path to it. The value itself is at level `depth`.
+		pub(crate) fn child_key(index: u32, level: u8) -> Vec<u8> {
+			let mut key = index.using_encoded(twox_64).to_vec();
+			key.resize(key.len() + level as usize, 0);
+			key
+		}
+
+		/// Insert the value `index` into the child trie, below `depth` nested entries.
+		pub(crate) fn insert_child_value(index: u32, depth: u8) {
+			let trie = Self::child_trie();
+			(0..depth).for_each(|level| {
+				child::put_raw(&trie, &Self::child_key(index, level), &[level]);
+			});
+			child::put_raw(&trie, &Self::child_key(index, depth), &Self::gen_value(index));
+		}
+
+		/// Remove the value `index` and the entries nested above it from the child trie.
+		fn remove_child_value(index: u32, depth: u8) {
+			let trie = Self::child_trie();
+			(0..=depth).for_each(|level| child::kill(&trie, &Self::child_key(index, level)));
+		}
+
 		/// Waste at most the remaining ref time weight of `meter`.
 		///
 		/// Tries to come as close to the limit as possible.
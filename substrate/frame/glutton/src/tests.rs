@@ -86,6 +86,80 @@ fn expand_and_shrink_trash_data_works() {
 	});
 }
 
+#[test]
+fn initialize_child_trie_works() {
+	new_test_ext().execute_with(|| {
+		let trie = Glutton::child_trie();
+
+		assert_noop!(
+			Glutton::initialize_child_trie(RuntimeOrigin::signed(1), 3, 2, None),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			Glutton::initialize_child_trie(RuntimeOrigin::root(), 3, MAX_TRIE_DEPTH + 1, None),
+			Error::<Test>::InsaneDepth
+		);
+
+		assert_ok!(Glutton::initialize_child_trie(RuntimeOrigin::root(), 3, 2, None));
+		System::assert_last_event(Event::ChildTrieInitialized { count: 3, depth: 2 }.into());
+		assert_eq!(ChildTrashDataCount::<Test>::get(), 3);
+		assert_eq!(ChildTrashDataDepth::<Test>::get(), 2);
+
+		// Each value sits below the entries nested above it.
+		assert_eq!(child::get_raw(&trie, &Glutton::child_key(1, 0)), Some(vec![0]));
+		assert_eq!(child::get_raw(&trie, &Glutton::child_key(1, 1)), Some(vec![1]));
+		assert_eq!(
+			child::get_raw(&trie, &Glutton::child_key(1, 2)),
+			Some(Pallet::<Test>::gen_value(1).to_vec())
+		);
+		assert!(Glutton::child_key(1, 1).starts_with(&Glutton::child_key(1, 0)));
+		assert_eq!(child::get_raw(&trie, &Glutton::child_key(3, 0)), None);
+
+		assert_noop!(
+			Glutton::initialize_child_trie(RuntimeOrigin::root(), 5, 2, None),
+			Error::<Test>::AlreadyInitialized
+		);
+		// The depth can't be changed while there are values.
+		assert_noop!(
+			Glutton::initialize_child_trie(RuntimeOrigin::root(), 5, 3, Some(3)),
+			Error::<Test>::ChildTrieNotEmpty
+		);
+
+		assert_ok!(Glutton::initialize_child_trie(RuntimeOrigin::root(), 1, 2, Some(3)));
+		assert_eq!(ChildTrashDataCount::<Test>::get(), 1);
+		assert_eq!(child::get_raw(&trie, &Glutton::child_key(1, 0)), None);
+		assert_eq!(child::get_raw(&trie, &Glutton::child_key(1, 2)), None);
+		assert!(child::get_raw(&trie, &Glutton::child_key(0, 2)).is_some());
+
+		// Once empty, the depth can be changed.
+		assert_ok!(Glutton::initialize_child_trie(RuntimeOrigin::root(), 0, 2, Some(1)));
+		assert_ok!(Glutton::initialize_child_trie(RuntimeOrigin::root(), 2, 5, None));
+		assert_eq!(ChildTrashDataDepth::<Test>::get(), 5);
+		assert!(child::get_raw(&trie, &Glutton::child_key(1, 5)).is_some());
+	});
+}
+
+#[test]
+fn setting_pov_pattern_works() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(Pattern::<Test>::get(), PovPattern::default());
+
+		let pattern = PovPattern {
+			access: KeyAccess::Random,
+			trie: TrashTrie::Child,
+			writes: Perbill::from_percent(20),
+		};
+		assert_ok!(Glutton::set_pov_pattern(RuntimeOrigin::root(), pattern));
+		assert_eq!(Pattern::<Test>::get(), pattern);
+		System::assert_last_event(Event::PovPatternSet { pattern }.into());
+
+		assert_noop!(
+			Glutton::set_pov_pattern(RuntimeOrigin::signed(1), pattern),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
 #[test]
 fn setting_compute_works() {
 	new_test_ext().execute_with(|| {
@@ -282,6 +356,125 @@ fn waste_at_most_proof_size_weight_close_enough() {
 	});
 }
 
+#[test]
+fn on_idle_reports_wasted_proof_size() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Glutton::initialize_pallet(RuntimeOrigin::root(), 5000, None));
+		set_limits(One::one(), 0.5);
+
+		let should = Weight::from_parts(WEIGHT_REF_TIME_PER_SECOND, WEIGHT_PROOF_SIZE_PER_MB);
+		Glutton::on_idle(1, should);
+
+		let (target, estimated, reads, writes) = System::events()
+			.into_iter()
+			.find_map(|record| match record.event {
+				RuntimeEvent::Glutton(Event::ProofSizeWasted {
+					target,
+					estimated,
+					reads,
+					writes,
+				}) => Some((target, estimated, reads, writes)),
+				_ => None,
+			})
+			.expect("Proof size was wasted");
+		assert!(estimated <= target, "Consumed more than the target");
+		assert!(
+			Perbill::from_rational(estimated, target) >= Perbill::from_percent(99),
+			"Too few proof size consumed"
+		);
+		assert!(reads > 0);
+		assert_eq!(writes, 0);
+	});
+}
+
+#[test]
+fn waste_at_most_proof_size_with_writes_close_enough() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Glutton::initialize_pallet(RuntimeOrigin::root(), 5000, None));
+		let pattern = PovPattern {
+			access: KeyAccess::Random,
+			trie: TrashTrie::Main,
+			writes: Perbill::from_percent(25),
+		};
+		assert_ok!(Glutton::set_pov_pattern(RuntimeOrigin::root(), pattern));
+
+		let mut meter =
+			WeightMeter::with_limit(Weight::from_parts(u64::MAX, WEIGHT_PROOF_SIZE_PER_MB * 5));
+		let (reads, writes, achieved) = Glutton::waste_at_most_proof_size(&mut meter);
+
+		// No storage proof is recorded.
+		assert_eq!(achieved, None);
+		assert_eq!(writes, Perbill::from_percent(25).mul_floor(reads + writes));
+		assert!(
+			meter.consumed_ratio() >= Perbill::from_percent(99),
+			"{CALIBRATION_ERROR}\nConsumed too few: {:?}",
+			meter.consumed_ratio()
+		);
+		// Only existing values are overwritten.
+		assert_eq!(TrashData::<Test>::iter_keys().count(), 5000);
+	});
+}
+
+#[test]
+fn waste_at_most_proof_size_in_child_trie_close_enough() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Glutton::initialize_child_trie(RuntimeOrigin::root(), 5000, 4, None));
+		let pattern = PovPattern { trie: TrashTrie::Child, ..Default::default() };
+		assert_ok!(Glutton::set_pov_pattern(RuntimeOrigin::root(), pattern));
+
+		let mut meter =
+			WeightMeter::with_limit(Weight::from_parts(u64::MAX, WEIGHT_PROOF_SIZE_PER_MB * 5));
+		let (reads, writes, _) = Glutton::waste_at_most_proof_size(&mut meter);
+
+		assert!(reads > 0);
+		assert_eq!(writes, 0);
+		assert!(
+			meter.consumed_ratio() >= Perbill::from_percent(99),
+			"{CALIBRATION_ERROR}\nConsumed too few: {:?}",
+			meter.consumed_ratio()
+		);
+	});
+}
+
+#[test]
+#[cfg(feature = "measure-proof-size")]
+fn waste_at_most_proof_size_measures_the_recorded_proof() {
+	use sp_core::Blake2Hasher;
+	use sp_state_machine::TrieBackendBuilder;
+	use sp_trie::{proof_size_extension::ProofSizeExt, recorder::Recorder};
+
+	let mut ext = new_test_ext();
+	ext.execute_with(|| {
+		assert_ok!(Glutton::initialize_pallet(RuntimeOrigin::root(), 5000, None));
+	});
+	// Move the trash data into a backend which records the accesses to it.
+	ext.commit_all().unwrap();
+	let recorder = Recorder::<Blake2Hasher>::default();
+	let root = *ext.backend.root();
+	let storage = ext.backend.clone().into_storage();
+	ext.backend = TrieBackendBuilder::new(storage, root).with_recorder(recorder.clone()).build();
+	ext.register_extension(ProofSizeExt::new(recorder));
+
+	ext.execute_with(|| {
+		let mut meter =
+			WeightMeter::with_limit(Weight::from_parts(u64::MAX, WEIGHT_PROOF_SIZE_PER_MB));
+		let (reads, _, achieved) = Glutton::waste_at_most_proof_size(&mut meter);
+
+		assert!(reads > 0);
+		assert!(achieved.expect("The storage proof is recorded") > 0);
+	});
+}
+
+#[test]
+fn random_access_stays_within_trash_data() {
+	new_test_ext().execute_with(|| {
+		(0..100).for_each(|i| assert!(Glutton::random_index(1u64, i, 10) < 10));
+		assert_ne!(Glutton::random_index(1u64, 0, 1000), Glutton::random_index(2u64, 0, 1000));
+		// Without trash data there is nothing to pick from.
+		assert_eq!(Glutton::random_index(1u64, 7, 0), 7);
+	});
+}
+
 #[test]
 fn gen_value_works() {
 	let g0 = Pallet::<Test>::gen_value(0);
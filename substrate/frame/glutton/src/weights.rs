@@ -61,6 +61,13 @@ pub trait WeightInfo {
 	fn empty_on_idle() -> Weight;
 	fn set_compute() -> Weight;
 	fn set_storage() -> Weight;
+	fn initialize_child_trie_grow(n: u32, ) -> Weight;
+	fn initialize_child_trie_shrink(n: u32, ) -> Weight;
+	fn waste_proof_size_write(i: u32, ) -> Weight;
+	fn waste_child_proof_size_some(i: u32, ) -> Weight;
+	fn waste_child_proof_size_write(i: u32, ) -> Weight;
+	fn waste_child_trie_depth(d: u32, ) -> Weight;
+	fn set_pov_pattern() -> Weight;
 }
 
 /// Weights for pallet_glutton using the Substrate node and recommended hardware.
@@ -184,6 +191,85 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(8_213_000, 0)
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: Glutton ChildTrashDataCount (r:1 w:1)
+	/// Proof: Glutton ChildTrashDataCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Glutton ChildTrashDataDepth (r:1 w:1)
+	/// Proof: Glutton ChildTrashDataDepth (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: UNKNOWN KEY `0x3a6368696c645f73746f726167653a64656661756c743a676c7574746f6e` (r:1 w:1)
+	/// Proof: UNKNOWN KEY `0x3a6368696c645f73746f726167653a64656661756c743a676c7574746f6e` (r:1 w:1)
+	/// The range of component `n` is `[0, 1000]`.
+	fn initialize_child_trie_grow(n: u32, ) -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(12_718_000, 1489)
+			.saturating_add(Weight::from_parts(9_731_205, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	/// Storage: Glutton ChildTrashDataCount (r:1 w:1)
+	/// Proof: Glutton ChildTrashDataCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Glutton ChildTrashDataDepth (r:1 w:1)
+	/// Proof: Glutton ChildTrashDataDepth (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: UNKNOWN KEY `0x3a6368696c645f73746f726167653a64656661756c743a676c7574746f6e` (r:1 w:1)
+	/// Proof: UNKNOWN KEY `0x3a6368696c645f73746f726167653a64656661756c743a676c7574746f6e` (r:1 w:1)
+	/// The range of component `n` is `[0, 1000]`.
+	fn initialize_child_trie_shrink(n: u32, ) -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(12_452_330, 1489)
+			.saturating_add(Weight::from_parts(1_214_902, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 41).saturating_mul(n.into()))
+	}
+	/// Storage: Glutton TrashData (r:0 w:5000)
+	/// Proof: Glutton TrashData (max_values: Some(65000), max_size: Some(1036), added: 3016, mode: MaxEncodedLen)
+	/// The range of component `i` is `[0, 5000]`.
+	fn waste_proof_size_write(i: u32, ) -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(797_000, 990)
+			.saturating_add(Weight::from_parts(7_412_530, 0).saturating_mul(i.into()))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(i.into())))
+			.saturating_add(Weight::from_parts(0, 3016).saturating_mul(i.into()))
+	}
+	/// Storage: UNKNOWN KEY `0x3a6368696c645f73746f726167653a64656661756c743a676c7574746f6e` (r:1 w:0)
+	/// Proof: UNKNOWN KEY `0x3a6368696c645f73746f726167653a64656661756c743a676c7574746f6e` (r:1 w:0)
+	/// The range of component `i` is `[0, 5000]`.
+	fn waste_child_proof_size_some(i: u32, ) -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(712_000, 1163)
+			.saturating_add(Weight::from_parts(6_124_811, 0).saturating_mul(i.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(Weight::from_parts(0, 1061).saturating_mul(i.into()))
+	}
+	/// Storage: UNKNOWN KEY `0x3a6368696c645f73746f726167653a64656661756c743a676c7574746f6e` (r:1 w:1)
+	/// Proof: UNKNOWN KEY `0x3a6368696c645f73746f726167653a64656661756c743a676c7574746f6e` (r:1 w:1)
+	/// The range of component `i` is `[0, 5000]`.
+	fn waste_child_proof_size_write(i: u32, ) -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(804_000, 1163)
+			.saturating_add(Weight::from_parts(7_689_145, 0).saturating_mul(i.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 1061).saturating_mul(i.into()))
+	}
+	/// Storage: UNKNOWN KEY `0x3a6368696c645f73746f726167653a64656661756c743a676c7574746f6e` (r:1 w:0)
+	/// Proof: UNKNOWN KEY `0x3a6368696c645f73746f726167653a64656661756c743a676c7574746f6e` (r:1 w:0)
+	/// The range of component `d` is `[0, 16]`.
+	fn waste_child_trie_depth(d: u32, ) -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(4_395_120, 1196)
+			.saturating_add(Weight::from_parts(3_216_704, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(Weight::from_parts(0, 74).saturating_mul(d.into()))
+	}
+	/// Storage: Glutton Pattern (r:0 w:1)
+	/// Proof: Glutton Pattern (max_values: Some(1), max_size: Some(6), added: 501, mode: MaxEncodedLen)
+	fn set_pov_pattern() -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(8_402_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -306,4 +392,83 @@ impl WeightInfo for () {
 		Weight::from_parts(8_213_000, 0)
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: Glutton ChildTrashDataCount (r:1 w:1)
+	/// Proof: Glutton ChildTrashDataCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Glutton ChildTrashDataDepth (r:1 w:1)
+	/// Proof: Glutton ChildTrashDataDepth (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: UNKNOWN KEY `0x3a6368696c645f73746f726167653a64656661756c743a676c7574746f6e` (r:1 w:1)
+	/// Proof: UNKNOWN KEY `0x3a6368696c645f73746f726167653a64656661756c743a676c7574746f6e` (r:1 w:1)
+	/// The range of component `n` is `[0, 1000]`.
+	fn initialize_child_trie_grow(n: u32, ) -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(12_718_000, 1489)
+			.saturating_add(Weight::from_parts(9_731_205, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	/// Storage: Glutton ChildTrashDataCount (r:1 w:1)
+	/// Proof: Glutton ChildTrashDataCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Glutton ChildTrashDataDepth (r:1 w:1)
+	/// Proof: Glutton ChildTrashDataDepth (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: UNKNOWN KEY `0x3a6368696c645f73746f726167653a64656661756c743a676c7574746f6e` (r:1 w:1)
+	/// Proof: UNKNOWN KEY `0x3a6368696c645f73746f726167653a64656661756c743a676c7574746f6e` (r:1 w:1)
+	/// The range of component `n` is `[0, 1000]`.
+	fn initialize_child_trie_shrink(n: u32, ) -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(12_452_330, 1489)
+			.saturating_add(Weight::from_parts(1_214_902, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 41).saturating_mul(n.into()))
+	}
+	/// Storage: Glutton TrashData (r:0 w:5000)
+	/// Proof: Glutton TrashData (max_values: Some(65000), max_size: Some(1036), added: 3016, mode: MaxEncodedLen)
+	/// The range of component `i` is `[0, 5000]`.
+	fn waste_proof_size_write(i: u32, ) -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(797_000, 990)
+			.saturating_add(Weight::from_parts(7_412_530, 0).saturating_mul(i.into()))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(i.into())))
+			.saturating_add(Weight::from_parts(0, 3016).saturating_mul(i.into()))
+	}
+	/// Storage: UNKNOWN KEY `0x3a6368696c645f73746f726167653a64656661756c743a676c7574746f6e` (r:1 w:0)
+	/// Proof: UNKNOWN KEY `0x3a6368696c645f73746f726167653a64656661756c743a676c7574746f6e` (r:1 w:0)
+	/// The range of component `i` is `[0, 5000]`.
+	fn waste_child_proof_size_some(i: u32, ) -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(712_000, 1163)
+			.saturating_add(Weight::from_parts(6_124_811, 0).saturating_mul(i.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(Weight::from_parts(0, 1061).saturating_mul(i.into()))
+	}
+	/// Storage: UNKNOWN KEY `0x3a6368696c645f73746f726167653a64656661756c743a676c7574746f6e` (r:1 w:1)
+	/// Proof: UNKNOWN KEY `0x3a6368696c645f73746f726167653a64656661756c743a676c7574746f6e` (r:1 w:1)
+	/// The range of component `i` is `[0, 5000]`.
+	fn waste_child_proof_size_write(i: u32, ) -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(804_000, 1163)
+			.saturating_add(Weight::from_parts(7_689_145, 0).saturating_mul(i.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 1061).saturating_mul(i.into()))
+	}
+	/// Storage: UNKNOWN KEY `0x3a6368696c645f73746f726167653a64656661756c743a676c7574746f6e` (r:1 w:0)
+	/// Proof: UNKNOWN KEY `0x3a6368696c645f73746f726167653a64656661756c743a676c7574746f6e` (r:1 w:0)
+	/// The range of component `d` is `[0, 16]`.
+	fn waste_child_trie_depth(d: u32, ) -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(4_395_120, 1196)
+			.saturating_add(Weight::from_parts(3_216_704, 0).saturating_mul(d.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(Weight::from_parts(0, 74).saturating_mul(d.into()))
+	}
+	/// Storage: Glutton Pattern (r:0 w:1)
+	/// Proof: Glutton Pattern (max_values: Some(1), max_size: Some(6), added: 501, mode: MaxEncodedLen)
+	fn set_pov_pattern() -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(8_402_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
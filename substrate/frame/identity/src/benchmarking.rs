@@ -116,6 +116,41 @@ fn add_sub_accounts<T: Config>(
 	Ok(subs)
 }
 
+// Sets up an identity for a user with `a` attestations of other registrars and adds the whitelisted
+// caller as the registrar with index `0`. Returns the user, the hash of its identity information
+// and the attested field.
+fn setup_attestations<T: Config>(
+	a: u32,
+) -> Result<(T::AccountId, T::Hash, FieldsIdentifierOf<T>), &'static str> {
+	let user: T::AccountId = account("user", 0, SEED);
+	let _ = T::Currency::make_free_balance_be(&user, BalanceOf::<T>::max_value());
+	let info = T::IdentityInformation::create_identity_info();
+	let info_hash = T::Hashing::hash_of(&info);
+	Identity::<T>::set_identity(RawOrigin::Signed(user.clone()).into(), Box::new(info))?;
+
+	let caller: T::AccountId = whitelisted_caller();
+	let registrar_origin = T::RegistrarOrigin::try_successful_origin()
+		.expect("RegistrarOrigin has no successful origin required for the benchmark");
+	Identity::<T>::add_registrar(registrar_origin, T::Lookup::unlookup(caller))?;
+
+	let field = T::IdentityInformation::all_fields();
+	let mut attestations = BoundedVec::<AttestationOf<T>, T::MaxAttestations>::default();
+	for i in 0..a {
+		attestations
+			.try_push(Attestation {
+				registrar: i + 1,
+				field: field.clone(),
+				credential: info_hash,
+				identity: info_hash,
+				expiry: None,
+			})
+			.expect("benchmark should not pass more than T::MaxAttestations");
+	}
+	AttestationsOf::<T>::insert(&user, attestations);
+
+	Ok((user, info_hash, field))
+}
+
 fn bench_suffix() -> Vec<u8> {
 	b"bench".to_vec()
 }
@@ -726,5 +761,61 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn attest(a: Linear<0, { T::MaxAttestations::get() - 1 }>) -> Result<(), BenchmarkError> {
+		let (user, info_hash, field) = setup_attestations::<T>(a)?;
+		let user_lookup = T::Lookup::unlookup(user.clone());
+		let caller: T::AccountId = whitelisted_caller();
+		let credential = T::Hashing::hash_of(&b"credential".to_vec());
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller),
+			0,
+			user_lookup,
+			field.clone(),
+			credential,
+			info_hash,
+			Some(frame_system::Pallet::<T>::block_number() + One::one()),
+		);
+
+		assert_last_event::<T>(
+			Event::<T>::AttestationProvided { target: user, registrar_index: 0, field, credential }
+				.into(),
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn revoke_attestation(
+		a: Linear<1, { T::MaxAttestations::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let (user, info_hash, field) = setup_attestations::<T>(a - 1)?;
+		let user_lookup = T::Lookup::unlookup(user.clone());
+		let caller: T::AccountId = whitelisted_caller();
+		let caller_origin =
+			<T as frame_system::Config>::RuntimeOrigin::from(RawOrigin::Signed(caller.clone()));
+		let credential = T::Hashing::hash_of(&b"credential".to_vec());
+		Identity::<T>::attest(
+			caller_origin,
+			0,
+			user_lookup.clone(),
+			field.clone(),
+			credential,
+			info_hash,
+			None,
+		)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), 0, user_lookup, field.clone());
+
+		assert_last_event::<T>(
+			Event::<T>::AttestationRevoked { target: user, registrar_index: 0, field }.into(),
+		);
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(Identity, crate::tests::new_test_ext(), crate::tests::Test);
 }
@@ -52,6 +52,14 @@
 //! Users can have multiple usernames that map to the same `AccountId`, however one `AccountId` can
 //! only map to a single username, known as the _primary_.
 //!
+//! ### Attestations
+//!
+//! Besides judgements, registrars can attest to the content of individual identity fields by
+//! attaching the hash of an off-chain verifiable credential (e.g. a proof of control over an email
+//! address or a domain, or of a legal entity registration), optionally with an expiry. An
+//! attestation only applies to the identity information it was made for and lapses as soon as that
+//! information changes. Other pallets can query attestations through [`InspectAttestation`].
+//!
 //! ## Interface
 //!
 //! ### Dispatchable Functions
@@ -78,6 +86,8 @@
 //! * `set_fee` - Set the fee required to be paid for a judgement to be given by the registrar.
 //! * `set_fields` - Set the fields that a registrar cares about in their judgements.
 //! * `provide_judgement` - Provide a judgement to an identity.
+//! * `attest` - Attest to a single field of an identity with the hash of a verifiable credential.
+//! * `revoke_attestation` - Revoke a previously made attestation.
 //!
 //! #### For Username Authorities
 //! * `set_username_for` - Set a username for a given account. The account must approve it.
@@ -115,7 +125,8 @@ use sp_runtime::traits::{
 };
 use sp_std::prelude::*;
 pub use types::{
-	Data, IdentityInformationProvider, Judgement, RegistrarIndex, RegistrarInfo, Registration,
+	Attestation, Data, IdentityInformationProvider, InspectAttestation, Judgement, RegistrarIndex,
+	RegistrarInfo, Registration,
 };
 pub use weights::WeightInfo;
 
@@ -125,6 +136,13 @@ type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
 	<T as frame_system::Config>::AccountId,
 >>::NegativeImbalance;
 type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
+type FieldsIdentifierOf<T> =
+	<<T as Config>::IdentityInformation as IdentityInformationProvider>::FieldsIdentifier;
+type AttestationOf<T> = Attestation<
+	FieldsIdentifierOf<T>,
+	<T as frame_system::Config>::Hash,
+	frame_system::pallet_prelude::BlockNumberFor<T>,
+>;
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -166,6 +184,11 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxRegistrars: Get<u32>;
 
+		/// Maximum number of attestations that may be held by a single identity, across all
+		/// registrars and fields.
+		#[pallet::constant]
+		type MaxAttestations: Get<u32>;
+
 		/// What to do with slashed funds.
 		type Slashed: OnUnbalanced<NegativeImbalanceOf<Self>>;
 
@@ -297,6 +320,21 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// Attestations made by registrars on individual fields of an account's identity.
+	///
+	/// There may be only a single attestation per registrar and field.
+	///
+	/// TWOX-NOTE: OK ― `AccountId` is a secure hash.
+	#[pallet::storage]
+	#[pallet::getter(fn attestations)]
+	pub(super) type AttestationsOf<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		BoundedVec<AttestationOf<T>, T::MaxAttestations>,
+		ValueQuery,
+	>;
+
 	#[pallet::error]
 	pub enum Error<T> {
 		/// Too many subs-accounts.
@@ -351,6 +389,16 @@ pub mod pallet {
 		NoUsername,
 		/// The username cannot be forcefully removed because it can still be accepted.
 		NotExpired,
+		/// The provided attestation was for a different identity.
+		AttestationForDifferentIdentity,
+		/// The identity does not have the field that is attested to.
+		FieldNotSet,
+		/// The expiry of the attestation is not in the future.
+		InvalidExpiry,
+		/// Maximum amount of attestations reached for this identity. Cannot add any more.
+		TooManyAttestations,
+		/// The registrar has not attested to this field.
+		NoAttestation,
 	}
 
 	#[pallet::event]
@@ -392,6 +440,19 @@ pub mod pallet {
 		/// A dangling username (as in, a username corresponding to an account that has removed its
 		/// identity) has been removed.
 		DanglingUsernameRemoved { who: T::AccountId, username: Username<T> },
+		/// A registrar attested to a field of the identity of `target`.
+		AttestationProvided {
+			target: T::AccountId,
+			registrar_index: RegistrarIndex,
+			field: FieldsIdentifierOf<T>,
+			credential: T::Hash,
+		},
+		/// A registrar revoked its attestation on a field of the identity of `target`.
+		AttestationRevoked {
+			target: T::AccountId,
+			registrar_index: RegistrarIndex,
+			field: FieldsIdentifierOf<T>,
+		},
 	}
 
 	#[pallet::call]
@@ -576,6 +637,7 @@ pub mod pallet {
 			if let Some(username) = maybe_username {
 				AccountOfUsername::<T>::remove(username);
 			}
+			<AttestationsOf<T>>::remove(&sender);
 
 			let err_amount = T::Currency::unreserve(&sender, deposit);
 			debug_assert!(err_amount.is_zero());
@@ -888,6 +950,7 @@ pub mod pallet {
 			if let Some(username) = maybe_username {
 				AccountOfUsername::<T>::remove(username);
 			}
+			<AttestationsOf<T>>::remove(&target);
 			// Slash their deposit from them.
 			T::Slashed::on_unbalanced(T::Currency::slash_reserved(&target, deposit).0);
 
@@ -1192,6 +1255,130 @@ pub mod pallet {
 			Self::deposit_event(Event::DanglingUsernameRemoved { who: who.clone(), username });
 			Ok(Pays::No.into())
 		}
+
+		/// Attest to the content of a single field of an account's identity with the hash of an
+		/// off-chain verifiable credential.
+		///
+		/// The dispatch origin for this call must be _Signed_ and the sender must be the account
+		/// of the registrar whose index is `reg_index`.
+		///
+		/// - `reg_index`: the index of the registrar making the attestation.
+		/// - `target`: the account whose identity the attestation is upon. This must be an account
+		///   with a registered identity that has `field` set.
+		/// - `field`: the identity field that is attested to.
+		/// - `credential`: the hash of the verifiable credential backing the attestation.
+		/// - `identity`: The hash of the [`IdentityInformationProvider`] for that the attestation
+		///   is provided.
+		/// - `expiry`: the block number from which the attestation is no longer valid, if any.
+		///
+		/// A previous attestation of the same registrar on the same field is replaced. Expired
+		/// attestations and attestations on outdated identity information are pruned.
+		///
+		/// Emits `AttestationProvided` if successful.
+		#[pallet::call_index(22)]
+		#[pallet::weight(T::WeightInfo::attest(T::MaxAttestations::get()))]
+		pub fn attest(
+			origin: OriginFor<T>,
+			#[pallet::compact] reg_index: RegistrarIndex,
+			target: AccountIdLookupOf<T>,
+			field: FieldsIdentifierOf<T>,
+			credential: T::Hash,
+			identity: T::Hash,
+			expiry: Option<BlockNumberFor<T>>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			let target = T::Lookup::lookup(target)?;
+			Self::ensure_registrar(reg_index, &sender)?;
+			let (id, _) = <IdentityOf<T>>::get(&target).ok_or(Error::<T>::InvalidTarget)?;
+
+			ensure!(
+				T::Hashing::hash_of(&id.info) == identity,
+				Error::<T>::AttestationForDifferentIdentity
+			);
+			ensure!(id.info.has_identity(field.clone()), Error::<T>::FieldNotSet);
+			let now = frame_system::Pallet::<T>::block_number();
+			if let Some(expiry) = expiry {
+				ensure!(expiry > now, Error::<T>::InvalidExpiry);
+			}
+
+			let attestation = Attestation {
+				registrar: reg_index,
+				field: field.clone(),
+				credential,
+				identity,
+				expiry,
+			};
+			let attestations = <AttestationsOf<T>>::try_mutate(
+				&target,
+				|attestations| -> Result<usize, DispatchError> {
+					attestations.retain(|a| a.identity == identity && !a.is_expired(&now));
+					match attestations
+						.iter()
+						.position(|a| a.registrar == reg_index && a.field == field)
+					{
+						Some(i) => attestations[i] = attestation,
+						None => attestations
+							.try_push(attestation)
+							.map_err(|_| Error::<T>::TooManyAttestations)?,
+					}
+					Ok(attestations.len())
+				},
+			)?;
+
+			Self::deposit_event(Event::AttestationProvided {
+				target,
+				registrar_index: reg_index,
+				field,
+				credential,
+			});
+
+			Ok(Some(T::WeightInfo::attest(attestations as u32)).into())
+		}
+
+		/// Revoke an attestation previously made on a field of an account's identity.
+		///
+		/// The dispatch origin for this call must be _Signed_ and the sender must be the account
+		/// of the registrar whose index is `reg_index`.
+		///
+		/// - `reg_index`: the index of the registrar whose attestation is revoked.
+		/// - `target`: the account whose identity the attestation is upon.
+		/// - `field`: the identity field that the attestation is about.
+		///
+		/// Emits `AttestationRevoked` if successful.
+		#[pallet::call_index(23)]
+		#[pallet::weight(T::WeightInfo::revoke_attestation(T::MaxAttestations::get()))]
+		pub fn revoke_attestation(
+			origin: OriginFor<T>,
+			#[pallet::compact] reg_index: RegistrarIndex,
+			target: AccountIdLookupOf<T>,
+			field: FieldsIdentifierOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			let target = T::Lookup::lookup(target)?;
+			Self::ensure_registrar(reg_index, &sender)?;
+
+			let mut attestations = <AttestationsOf<T>>::get(&target);
+			let pos = attestations
+				.iter()
+				.position(|a| a.registrar == reg_index && a.field == field)
+				.ok_or(Error::<T>::NoAttestation)?;
+			attestations.remove(pos);
+
+			let remaining = attestations.len();
+			if attestations.is_empty() {
+				<AttestationsOf<T>>::remove(&target);
+			} else {
+				<AttestationsOf<T>>::insert(&target, attestations);
+			}
+
+			Self::deposit_event(Event::AttestationRevoked {
+				target,
+				registrar_index: reg_index,
+				field,
+			});
+
+			Ok(Some(T::WeightInfo::revoke_attestation(remaining.saturating_add(1) as u32)).into())
+		}
 	}
 }
 
@@ -1234,6 +1421,35 @@ impl<T: Config> Pallet<T> {
 			.map_or(false, |(registration, _username)| (registration.info.has_identity(fields)))
 	}
 
+	/// Check if some field of the account's identity carries a valid attestation by a registrar.
+	///
+	/// Attestations that have expired, or that were made for identity information other than the
+	/// current one, are ignored.
+	pub fn has_attested(who: &T::AccountId, field: FieldsIdentifierOf<T>) -> bool {
+		let attestations = AttestationsOf::<T>::get(who);
+		if attestations.is_empty() {
+			return false
+		}
+		let identity = match IdentityOf::<T>::get(who) {
+			Some((registration, _username)) => T::Hashing::hash_of(&registration.info),
+			None => return false,
+		};
+		let now = frame_system::Pallet::<T>::block_number();
+		attestations
+			.iter()
+			.any(|a| a.field == field && a.identity == identity && !a.is_expired(&now))
+	}
+
+	/// Ensure that `who` is the account of the registrar with index `reg_index`.
+	fn ensure_registrar(reg_index: RegistrarIndex, who: &T::AccountId) -> DispatchResult {
+		<Registrars<T>>::get()
+			.get(reg_index as usize)
+			.and_then(Option::as_ref)
+			.filter(|r| &r.account == who)
+			.ok_or(Error::<T>::InvalidIndex)?;
+		Ok(())
+	}
+
 	/// Calculate the deposit required for an identity.
 	fn calculate_identity_deposit(info: &T::IdentityInformation) -> BalanceOf<T> {
 		let bytes = info.encoded_size() as u32;
@@ -1357,6 +1573,9 @@ impl<T: Config> Pallet<T> {
 			<SuperOf<T>>::remove(sub);
 		}
 
+		// attestations
+		<AttestationsOf<T>>::remove(&who);
+
 		// unreserve any deposits
 		let deposit = id.total_deposit().saturating_add(subs_deposit);
 		let err_amount = T::Currency::unreserve(&who, deposit);
@@ -1455,3 +1674,9 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 }
+
+impl<T: Config> InspectAttestation<T::AccountId, FieldsIdentifierOf<T>> for Pallet<T> {
+	fn has_attested(who: &T::AccountId, field: FieldsIdentifierOf<T>) -> bool {
+		Self::has_attested(who, field)
+	}
+}
@@ -100,6 +100,7 @@ impl pallet_balances::Config for Test {
 parameter_types! {
 	pub const MaxAdditionalFields: u32 = 2;
 	pub const MaxRegistrars: u32 = 20;
+	pub const MaxAttestations: u32 = 3;
 }
 
 impl pallet_identity::Config for Test {
@@ -112,6 +113,7 @@ impl pallet_identity::Config for Test {
 	type MaxSubAccounts = ConstU32<2>;
 	type IdentityInformation = IdentityInfo<MaxAdditionalFields>;
 	type MaxRegistrars = MaxRegistrars;
+	type MaxAttestations = MaxAttestations;
 	type RegistrarOrigin = EnsureRoot<Self::AccountId>;
 	type ForceOrigin = EnsureRoot<Self::AccountId>;
 	type OffchainSignature = MultiSignature;
@@ -868,6 +870,261 @@ fn test_has_identity() {
 	});
 }
 
+#[test]
+fn attesting_should_work() {
+	new_test_ext().execute_with(|| {
+		let [_, _, three, _, ten, _, _, _] = accounts();
+		let display = IdentityField::Display as u64;
+		let credential = H256::repeat_byte(1);
+		let identity_hash = BlakeTwo256::hash_of(&infoof_ten());
+
+		assert_noop!(
+			Identity::attest(
+				RuntimeOrigin::signed(three.clone()),
+				0,
+				ten.clone(),
+				display,
+				credential,
+				identity_hash,
+				None
+			),
+			Error::<Test>::InvalidIndex
+		);
+		assert_ok!(Identity::add_registrar(RuntimeOrigin::root(), three.clone()));
+		assert_noop!(
+			Identity::attest(
+				RuntimeOrigin::signed(three.clone()),
+				0,
+				ten.clone(),
+				display,
+				credential,
+				identity_hash,
+				None
+			),
+			Error::<Test>::InvalidTarget
+		);
+
+		assert_ok!(Identity::set_identity(
+			RuntimeOrigin::signed(ten.clone()),
+			Box::new(infoof_ten())
+		));
+		assert_noop!(
+			Identity::attest(
+				RuntimeOrigin::signed(ten.clone()),
+				0,
+				ten.clone(),
+				display,
+				credential,
+				identity_hash,
+				None
+			),
+			Error::<Test>::InvalidIndex
+		);
+		assert_noop!(
+			Identity::attest(
+				RuntimeOrigin::signed(three.clone()),
+				0,
+				ten.clone(),
+				display,
+				credential,
+				H256::random(),
+				None
+			),
+			Error::<Test>::AttestationForDifferentIdentity
+		);
+		assert_noop!(
+			Identity::attest(
+				RuntimeOrigin::signed(three.clone()),
+				0,
+				ten.clone(),
+				IdentityField::Web as u64,
+				credential,
+				identity_hash,
+				None
+			),
+			Error::<Test>::FieldNotSet
+		);
+		assert_noop!(
+			Identity::attest(
+				RuntimeOrigin::signed(three.clone()),
+				0,
+				ten.clone(),
+				display,
+				credential,
+				identity_hash,
+				Some(1)
+			),
+			Error::<Test>::InvalidExpiry
+		);
+
+		assert_ok!(Identity::attest(
+			RuntimeOrigin::signed(three.clone()),
+			0,
+			ten.clone(),
+			display,
+			credential,
+			identity_hash,
+			None
+		));
+		System::assert_last_event(
+			Event::AttestationProvided {
+				target: ten.clone(),
+				registrar_index: 0,
+				field: display,
+				credential,
+			}
+			.into(),
+		);
+		assert!(Identity::has_attested(&ten, display));
+		assert!(!Identity::has_attested(&ten, IdentityField::Legal as u64));
+		assert!(<Identity as InspectAttestation<_, _>>::has_attested(&ten, display));
+
+		// attesting again replaces the previous attestation.
+		let new_credential = H256::repeat_byte(2);
+		assert_ok!(Identity::attest(
+			RuntimeOrigin::signed(three.clone()),
+			0,
+			ten.clone(),
+			display,
+			new_credential,
+			identity_hash,
+			None
+		));
+		assert_eq!(Identity::attestations(ten.clone()).len(), 1);
+		assert_eq!(Identity::attestations(ten.clone())[0].credential, new_credential);
+
+		// revoking
+		assert_noop!(
+			Identity::revoke_attestation(
+				RuntimeOrigin::signed(three.clone()),
+				0,
+				ten.clone(),
+				IdentityField::Legal as u64
+			),
+			Error::<Test>::NoAttestation
+		);
+		assert_ok!(Identity::revoke_attestation(
+			RuntimeOrigin::signed(three.clone()),
+			0,
+			ten.clone(),
+			display
+		));
+		System::assert_last_event(
+			Event::AttestationRevoked { target: ten.clone(), registrar_index: 0, field: display }
+				.into(),
+		);
+		assert!(!Identity::has_attested(&ten, display));
+		assert!(!AttestationsOf::<Test>::contains_key(&ten));
+	});
+}
+
+#[test]
+fn attestations_should_lapse() {
+	new_test_ext().execute_with(|| {
+		let [_, _, three, _, ten, _, _, _] = accounts();
+		let display = IdentityField::Display as u64;
+		let legal = IdentityField::Legal as u64;
+		assert_ok!(Identity::add_registrar(RuntimeOrigin::root(), three.clone()));
+		assert_ok!(Identity::set_identity(
+			RuntimeOrigin::signed(ten.clone()),
+			Box::new(infoof_ten())
+		));
+		let identity_hash = BlakeTwo256::hash_of(&infoof_ten());
+		assert_ok!(Identity::attest(
+			RuntimeOrigin::signed(three.clone()),
+			0,
+			ten.clone(),
+			display,
+			H256::repeat_byte(1),
+			identity_hash,
+			Some(10)
+		));
+		assert_ok!(Identity::attest(
+			RuntimeOrigin::signed(three.clone()),
+			0,
+			ten.clone(),
+			legal,
+			H256::repeat_byte(2),
+			identity_hash,
+			None
+		));
+
+		// expiry
+		run_to_block(9);
+		assert!(Identity::has_attested(&ten, display));
+		run_to_block(10);
+		assert!(!Identity::has_attested(&ten, display));
+		assert!(Identity::has_attested(&ten, legal));
+
+		// changing the identity information
+		let mut new_info = infoof_ten();
+		new_info.legal = Data::Raw(b"The Right Ordinal Ten, PhD".to_vec().try_into().unwrap());
+		assert_ok!(Identity::set_identity(
+			RuntimeOrigin::signed(ten.clone()),
+			Box::new(new_info.clone())
+		));
+		assert!(!Identity::has_attested(&ten, legal));
+
+		// stale attestations are pruned by a new attestation.
+		assert_ok!(Identity::attest(
+			RuntimeOrigin::signed(three.clone()),
+			0,
+			ten.clone(),
+			display,
+			H256::repeat_byte(3),
+			BlakeTwo256::hash_of(&new_info),
+			None
+		));
+		assert_eq!(Identity::attestations(ten.clone()).len(), 1);
+		assert!(Identity::has_attested(&ten, display));
+		assert!(!Identity::has_attested(&ten, legal));
+
+		// clearing the identity removes all attestations.
+		assert_ok!(Identity::clear_identity(RuntimeOrigin::signed(ten.clone())));
+		assert!(!AttestationsOf::<Test>::contains_key(&ten));
+		assert!(!Identity::has_attested(&ten, display));
+	});
+}
+
+#[test]
+fn amount_of_attestations_is_limited() {
+	new_test_ext().execute_with(|| {
+		let [_, _, three, _, ten, twenty, _, _] = accounts();
+		assert_ok!(Identity::add_registrar(RuntimeOrigin::root(), three.clone()));
+		assert_ok!(Identity::add_registrar(RuntimeOrigin::root(), twenty.clone()));
+		assert_ok!(Identity::set_identity(
+			RuntimeOrigin::signed(ten.clone()),
+			Box::new(infoof_ten())
+		));
+		let identity_hash = BlakeTwo256::hash_of(&infoof_ten());
+		let display = IdentityField::Display as u64;
+		let legal = IdentityField::Legal as u64;
+		for field in [display, legal, display | legal] {
+			assert_ok!(Identity::attest(
+				RuntimeOrigin::signed(three.clone()),
+				0,
+				ten.clone(),
+				field,
+				H256::repeat_byte(1),
+				identity_hash,
+				None
+			));
+		}
+		assert_noop!(
+			Identity::attest(
+				RuntimeOrigin::signed(twenty.clone()),
+				1,
+				ten.clone(),
+				display,
+				H256::repeat_byte(1),
+				identity_hash,
+				None
+			),
+			Error::<Test>::TooManyAttestations
+		);
+	});
+}
+
 #[test]
 fn reap_identity_works() {
 	new_test_ext().execute_with(|| {
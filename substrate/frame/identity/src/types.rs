@@ -319,6 +319,50 @@ pub struct RegistrarInfo<
 	pub fields: IdField,
 }
 
+/// An attestation by a registrar that the content of a single identity field has been verified
+/// against some off-chain credential (e.g. a signed email, domain or legal entity proof).
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct Attestation<FieldsIdentifier, Hash, BlockNumber> {
+	/// The registrar that made the attestation.
+	pub registrar: RegistrarIndex,
+
+	/// The identity field that the attestation is about.
+	pub field: FieldsIdentifier,
+
+	/// The hash of the verifiable credential that backs the attestation. The credential itself is
+	/// kept off-chain and may be retrieved through some hash-lookup service.
+	pub credential: Hash,
+
+	/// The hash of the identity information the attestation was made for. The attestation no
+	/// longer applies once the identity information changes.
+	pub identity: Hash,
+
+	/// The block number from which the attestation is no longer valid, if any.
+	pub expiry: Option<BlockNumber>,
+}
+
+impl<FieldsIdentifier, Hash, BlockNumber: PartialOrd>
+	Attestation<FieldsIdentifier, Hash, BlockNumber>
+{
+	/// Returns `true` if the attestation has expired at block `now`.
+	pub fn is_expired(&self, now: &BlockNumber) -> bool {
+		self.expiry.as_ref().map_or(false, |expiry| now >= expiry)
+	}
+}
+
+/// Something which can tell whether some field of an account's identity has been attested to by a
+/// registrar.
+pub trait InspectAttestation<AccountId, FieldsIdentifier> {
+	/// Returns `true` if `field` of the identity of `who` carries at least one valid attestation.
+	fn has_attested(who: &AccountId, field: FieldsIdentifier) -> bool;
+}
+
+impl<AccountId, FieldsIdentifier> InspectAttestation<AccountId, FieldsIdentifier> for () {
+	fn has_attested(_: &AccountId, _: FieldsIdentifier) -> bool {
+		false
+	}
+}
+
 /// Authority properties for a given pallet configuration.
 pub type AuthorityPropertiesOf<T> = AuthorityProperties<Suffix<T>>;
 
@@ -210,5 +210,38 @@ benchmarks! {
 		assert!(!Multisigs::<T>::contains_key(multi_account_id, call_hash));
 	}
 
+	poke_deposit {
+		// Signatories, need at least 2 people
+		let s in 2 .. T::MaxSignatories::get();
+		// Transaction Length, not a component
+		let z = 10_000;
+		let (mut signatories, call) = setup_multi::<T>(s, z)?;
+		let multi_account_id = Multisig::<T>::multi_account_id(&signatories, s.try_into().unwrap());
+		let caller = signatories.pop().ok_or("signatories should have len 2 or more")?;
+		let call_hash = call.using_encoded(blake2_256);
+		// Create the multi
+		let o = RawOrigin::Signed(caller.clone()).into();
+		Multisig::<T>::as_multi(o, s as u16, signatories.clone(), None, call, Weight::zero())?;
+		// Pretend that the deposit was taken before the deposit parameters were raised.
+		let deposit = Multisigs::<T>::get(&multi_account_id, call_hash)
+			.ok_or("multisig should have been created")?
+			.deposit;
+		Multisigs::<T>::mutate(&multi_account_id, call_hash, |m| {
+			if let Some(m) = m.as_mut() {
+				m.deposit = Zero::zero();
+			}
+		});
+		T::Currency::unreserve(&caller, deposit);
+		// Whitelist caller account from further DB operations.
+		let caller_key = frame_system::Account::<T>::hashed_key_for(&caller);
+		frame_benchmarking::benchmarking::add_to_whitelist(caller_key.into());
+	}: _(RawOrigin::Signed(caller), s as u16, signatories, call_hash)
+	verify {
+		assert_eq!(
+			Multisigs::<T>::get(&multi_account_id, call_hash).map(|m| m.deposit),
+			Some(deposit),
+		);
+	}
+
 	impl_benchmark_test_suite!(Multisig, crate::tests::new_test_ext(), crate::tests::Test);
 }
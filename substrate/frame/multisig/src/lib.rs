@@ -55,7 +55,7 @@ use frame_support::{
 		PostDispatchInfo,
 	},
 	ensure,
-	traits::{Currency, Get, ReservableCurrency},
+	traits::{Currency, Get, PokeDeposit, ReservableCurrency},
 	weights::Weight,
 	BoundedVec,
 };
@@ -245,6 +245,13 @@ pub mod pallet {
 			multisig: T::AccountId,
 			call_hash: CallHash,
 		},
+		/// The deposit of a multisig operation was updated.
+		DepositPoked {
+			who: T::AccountId,
+			call_hash: CallHash,
+			old_deposit: BalanceOf<T>,
+			new_deposit: BalanceOf<T>,
+		},
 	}
 
 	#[pallet::hooks]
@@ -493,6 +500,38 @@ pub mod pallet {
 			});
 			Ok(())
 		}
+
+		/// Poke the deposit reserved for a pre-existing, on-going multisig transaction.
+		///
+		/// The deposit is recalculated according to the current deposit parameters. Any increase
+		/// is reserved from the depositor and any excess is returned to them.
+		///
+		/// The dispatch origin for this call must be _Signed_ and the sender must be the depositor
+		/// of the multisig operation.
+		///
+		/// - `threshold`: The total number of approvals for this dispatch before it is executed.
+		/// - `other_signatories`: The accounts (other than the sender) who can approve this
+		/// dispatch. May not be empty.
+		/// - `call_hash`: The hash of the call to be executed.
+		///
+		/// The transaction fee is waived if the deposit was changed.
+		///
+		/// Emits `DepositPoked` if the deposit was changed.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::poke_deposit(other_signatories.len() as u32))]
+		pub fn poke_deposit(
+			origin: OriginFor<T>,
+			threshold: u16,
+			other_signatories: Vec<T::AccountId>,
+			call_hash: [u8; 32],
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let changed = <Self as PokeDeposit<_>>::poke_deposit(
+				&who,
+				(threshold, other_signatories, call_hash),
+			)?;
+			Ok(if changed { Pays::No } else { Pays::Yes }.into())
+		}
 	}
 }
 
@@ -610,7 +649,7 @@ impl<T: Config> Pallet<T> {
 			ensure!(maybe_timepoint.is_none(), Error::<T>::UnexpectedTimepoint);
 
 			// Just start the operation by recording it in storage.
-			let deposit = T::DepositBase::get() + T::DepositFactor::get() * threshold.into();
+			let deposit = Self::deposit(threshold);
 
 			T::Currency::reserve(&who, deposit)?;
 
@@ -636,6 +675,11 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// The deposit required for a multisig operation with the given `threshold`.
+	fn deposit(threshold: u16) -> BalanceOf<T> {
+		T::DepositBase::get() + T::DepositFactor::get() * threshold.into()
+	}
+
 	/// The current `Timepoint`.
 	pub fn timepoint() -> Timepoint<BlockNumberFor<T>> {
 		Timepoint {
@@ -667,6 +711,49 @@ impl<T: Config> Pallet<T> {
 	}
 }
 
+impl<T: Config> PokeDeposit<T::AccountId> for Pallet<T> {
+	type Key = (u16, Vec<T::AccountId>, CallHash);
+
+	fn poke_deposit(
+		who: &T::AccountId,
+		(threshold, other_signatories, call_hash): Self::Key,
+	) -> Result<bool, DispatchError> {
+		ensure!(threshold >= 2, Error::<T>::MinimumThreshold);
+		let max_sigs = T::MaxSignatories::get() as usize;
+		ensure!(!other_signatories.is_empty(), Error::<T>::TooFewSignatories);
+		ensure!(other_signatories.len() < max_sigs, Error::<T>::TooManySignatories);
+		let signatories = Self::ensure_sorted_and_insert(other_signatories, who.clone())?;
+
+		let id = Self::multi_account_id(&signatories, threshold);
+
+		<Multisigs<T>>::try_mutate(&id, call_hash, |maybe_multisig| {
+			let m = maybe_multisig.as_mut().ok_or(Error::<T>::NotFound)?;
+			ensure!(&m.depositor == who, Error::<T>::NotOwner);
+
+			let old_deposit = m.deposit;
+			let new_deposit = Self::deposit(threshold);
+			if new_deposit == old_deposit {
+				return Ok(false)
+			}
+			if new_deposit > old_deposit {
+				T::Currency::reserve(who, new_deposit - old_deposit)?;
+			} else {
+				let err_amount = T::Currency::unreserve(who, old_deposit - new_deposit);
+				debug_assert!(err_amount.is_zero());
+			}
+			m.deposit = new_deposit;
+
+			Self::deposit_event(Event::DepositPoked {
+				who: who.clone(),
+				call_hash,
+				old_deposit,
+				new_deposit,
+			});
+			Ok(true)
+		})
+	}
+}
+
 /// Return the weight of a dispatch call result as an `Option`.
 ///
 /// Will return the weight regardless of what the state of the result is.
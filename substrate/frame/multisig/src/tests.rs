@@ -24,6 +24,7 @@ use super::*;
 use crate as pallet_multisig;
 use frame_support::{
 	assert_noop, assert_ok, derive_impl,
+	dispatch::Pays,
 	traits::{ConstU32, ConstU64, Contains},
 };
 use sp_runtime::{BuildStorage, TokenError};
@@ -320,6 +321,55 @@ fn cancel_multisig_works() {
 	});
 }
 
+#[test]
+fn poke_deposit_works() {
+	new_test_ext().execute_with(|| {
+		let call = call_transfer(6, 15).encode();
+		let hash = blake2_256(&call);
+		let multi = Multisig::multi_account_id(&[1, 2, 3][..], 3);
+		assert_noop!(
+			Multisig::poke_deposit(RuntimeOrigin::signed(1), 3, vec![2, 3], hash),
+			Error::<Test>::NotFound,
+		);
+		assert_ok!(Multisig::approve_as_multi(
+			RuntimeOrigin::signed(1),
+			3,
+			vec![2, 3],
+			None,
+			hash,
+			Weight::zero()
+		));
+		assert_eq!(Balances::reserved_balance(1), 4);
+		assert_noop!(
+			Multisig::poke_deposit(RuntimeOrigin::signed(2), 3, vec![1, 3], hash),
+			Error::<Test>::NotOwner,
+		);
+
+		// Nothing changed, so the fee is paid.
+		let post_info = Multisig::poke_deposit(RuntimeOrigin::signed(1), 3, vec![2, 3], hash)
+			.expect("depositor can poke the deposit");
+		assert_eq!(post_info.pays_fee, Pays::Yes);
+
+		// The deposit was taken under higher deposit parameters; the excess is returned.
+		Multisigs::<Test>::mutate(multi, hash, |m| m.as_mut().unwrap().deposit = 6);
+		assert_ok!(Balances::reserve(&1, 2));
+		let post_info = Multisig::poke_deposit(RuntimeOrigin::signed(1), 3, vec![2, 3], hash)
+			.expect("depositor can poke the deposit");
+		assert_eq!(post_info.pays_fee, Pays::No);
+		System::assert_last_event(
+			pallet_multisig::Event::DepositPoked {
+				who: 1,
+				call_hash: hash,
+				old_deposit: 6,
+				new_deposit: 4,
+			}
+			.into(),
+		);
+		assert_eq!(Multisigs::<Test>::get(multi, hash).unwrap().deposit, 4);
+		assert_eq!(Balances::reserved_balance(1), 4);
+	});
+}
+
 #[test]
 fn multisig_2_of_3_as_multi_works() {
 	new_test_ext().execute_with(|| {
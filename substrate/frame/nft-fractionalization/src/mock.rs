@@ -112,6 +112,11 @@ impl pallet_assets::Config for Test {
 	type Freezer = ();
 	type Extra = ();
 	type CallbackHandle = ();
+	type TransferHook = ();
+	type RuntimeHoldReason = ();
+	type MaxHolds = ConstU32<0>;
+	type FreezeIdentifier = ();
+	type MaxFreezes = ConstU32<0>;
 	type WeightInfo = ();
 	pallet_assets::runtime_benchmarks_enabled! {
 		type BenchmarkHelper = ();
@@ -124,6 +129,7 @@ parameter_types! {
 
 impl pallet_nfts::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
 	type CollectionId = u32;
 	type ItemId = u32;
 	type Currency = Balances;
@@ -141,6 +147,8 @@ impl pallet_nfts::Config for Test {
 	type ApprovalsLimit = ConstU32<10>;
 	type ItemAttributesApprovalsLimit = ConstU32<2>;
 	type MaxTips = ConstU32<10>;
+	type MaxRoyaltyRecipients = ConstU32<10>;
+	type MaxNestingDepth = ConstU32<5>;
 	type MaxDeadlineDuration = ConstU64<10000>;
 	type MaxAttributesPerCall = ConstU32<2>;
 	type Features = Features;
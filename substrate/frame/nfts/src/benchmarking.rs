@@ -194,6 +194,23 @@ fn add_collection_attribute<T: Config<I>, I: 'static>(
 	(key, caller, caller_lookup)
 }
 
+fn nest_items<T: Config<I>, I: 'static>(depth: u32) -> (T::CollectionId, T::AccountId) {
+	let (collection, caller, _) = create_collection::<T, I>();
+	for i in 0..=T::MaxNestingDepth::get() {
+		mint_item::<T, I>(i as u16);
+	}
+	for i in 1..=depth {
+		assert_ok!(Nfts::<T, I>::nest(
+			SystemOrigin::Signed(caller.clone()).into(),
+			collection,
+			T::Helper::item(i as u16),
+			collection,
+			T::Helper::item(i as u16 - 1),
+		));
+	}
+	(collection, caller)
+}
+
 fn assert_last_event<T: Config<I>, I: 'static>(generic_event: <T as Config<I>>::RuntimeEvent) {
 	let events = frame_system::Pallet::<T>::events();
 	let system_event: <T as frame_system::Config>::RuntimeEvent = generic_event.into();
@@ -566,6 +583,26 @@ benchmarks_instance_pallet! {
 		assert_last_event::<T, I>(Event::ItemMetadataCleared { collection, item }.into());
 	}
 
+	poke_deposit {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+		add_item_metadata::<T, I>(item);
+		// Make the held deposits outdated, so that both need to be topped up.
+		Item::<T, I>::mutate(&collection, &item, |details| {
+			let deposit = &mut details.as_mut().unwrap().deposit;
+			T::Currency::unreserve(&caller, deposit.amount.saturating_sub(One::one()));
+			deposit.amount = One::one();
+		});
+		ItemMetadataOf::<T, I>::mutate(&collection, &item, |metadata| {
+			let deposit = &mut metadata.as_mut().unwrap().deposit;
+			T::Currency::unreserve(&caller, deposit.amount.saturating_sub(One::one()));
+			deposit.amount = One::one();
+		});
+	}: _(SystemOrigin::Signed(caller), collection, Some(item))
+	verify {
+		assert_eq!(Item::<T, I>::get(&collection, &item).unwrap().deposit.amount, T::ItemDeposit::get());
+	}
+
 	set_collection_metadata {
 		let data: BoundedVec<_, _> = vec![0u8; T::StringLimit::get() as usize].try_into().unwrap();
 
@@ -715,6 +752,121 @@ benchmarks_instance_pallet! {
 		}
 	}
 
+	set_royalties {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+		let n = T::MaxRoyaltyRecipients::get();
+		let royalties: RoyaltiesOf<T, I> = (0..n)
+			.map(|i| RoyaltyRecipient {
+				recipient: account("recipient", i, SEED),
+				share: Perbill::from_rational(1, n),
+			})
+			.collect::<Vec<_>>()
+			.try_into()
+			.unwrap();
+	}: _(SystemOrigin::Signed(caller.clone()), collection, Some(item), royalties.clone())
+	verify {
+		assert_last_event::<T, I>(Event::RoyaltiesSet {
+			collection,
+			item: Some(item),
+			royalties,
+		}.into());
+	}
+
+	pay_royalties {
+		let n in 0 .. T::MaxRoyaltyRecipients::get();
+		let (collection, seller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+		let royalties: RoyaltiesOf<T, I> = (0..n)
+			.map(|i| RoyaltyRecipient {
+				recipient: account("recipient", i, SEED),
+				share: Perbill::from_rational(1, n + 1),
+			})
+			.collect::<Vec<_>>()
+			.try_into()
+			.unwrap();
+		// The royalties of the collection are only found after looking up the item's own ones.
+		Nfts::<T, I>::set_royalties(
+			SystemOrigin::Signed(seller.clone()).into(),
+			collection,
+			None,
+			royalties,
+		)?;
+		let buyer: T::AccountId = account("buyer", 0, SEED);
+		T::Currency::make_free_balance_be(&buyer, DepositBalanceOf::<T, I>::max_value());
+		let price = T::Currency::minimum_balance().saturating_mul((100 * (n + 1)).into());
+	}: {
+		Nfts::<T, I>::pay_with_royalties(collection, item, &buyer, &seller, price)?;
+	}
+	verify {
+		if !n.is_zero() {
+			assert_last_event::<T, I>(Event::RoyaltyPaid {
+				collection,
+				item,
+				recipient: account("recipient", n - 1, SEED),
+				amount: Perbill::from_rational(1, n + 1).mul_floor(price),
+			}.into());
+		}
+	}
+
+	nest {
+		let d in 1 .. T::MaxNestingDepth::get();
+		let (collection, caller) = nest_items::<T, I>(d - 1);
+		let item = T::Helper::item(d as u16);
+		let parent_item = T::Helper::item(d as u16 - 1);
+	}: _(SystemOrigin::Signed(caller.clone()), collection, item, collection, parent_item)
+	verify {
+		assert_last_event::<T, I>(Event::ItemNested {
+			collection,
+			item,
+			parent_collection: collection,
+			parent_item,
+		}.into());
+	}
+
+	unnest {
+		let d in 1 .. T::MaxNestingDepth::get();
+		let (collection, caller) = nest_items::<T, I>(d);
+		let item = T::Helper::item(d as u16);
+		let parent_item = T::Helper::item(d as u16 - 1);
+		let caller_lookup = T::Lookup::unlookup(caller.clone());
+	}: _(SystemOrigin::Signed(caller.clone()), collection, item, caller_lookup)
+	verify {
+		assert_last_event::<T, I>(Event::Transferred {
+			collection,
+			item,
+			from: Nfts::<T, I>::item_account(&collection, &parent_item),
+			to: caller,
+		}.into());
+	}
+
+	as_item {
+		let d in 0 .. T::MaxNestingDepth::get();
+		let (collection, caller) = nest_items::<T, I>(d);
+		let item = T::Helper::item(d as u16);
+		let call: <T as Config<I>>::RuntimeCall =
+			frame_system::Call::<T>::remark { remark: vec![] }.into();
+	}: _(SystemOrigin::Signed(caller), collection, item, Box::new(call))
+
+	// The nesting of a new item minted to the account of an item `d - 1` deep, on top of `mint`.
+	nest_minted {
+		let d in 1 .. T::MaxNestingDepth::get();
+		let (collection, _) = nest_items::<T, I>(d - 1);
+		let parent = (collection, T::Helper::item(d as u16 - 1));
+		let (item, ..) = mint_item::<T, I>(T::MaxNestingDepth::get() as u16 + 1);
+	}: {
+		Nfts::<T, I>::ensure_can_nest((collection, item), 0, parent)?;
+		Nfts::<T, I>::link(collection, item, parent, 0)?;
+	}
+	verify {
+		assert_last_event::<T, I>(Event::ItemNested {
+			collection,
+			item,
+			parent_collection: parent.0,
+			parent_item: parent.1,
+		}.into());
+	}
+
 	create_swap {
 		let (collection, caller, _) = create_collection::<T, I>();
 		let (item1, ..) = mint_item::<T, I>(0);
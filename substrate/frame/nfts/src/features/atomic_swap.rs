@@ -21,10 +21,7 @@
 //! to have the functionality defined in this module.
 
 use crate::*;
-use frame_support::{
-	pallet_prelude::*,
-	traits::{Currency, ExistenceRequirement::KeepAlive},
-};
+use frame_support::pallet_prelude::*;
 
 impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	/// Creates a new swap offer for the specified item.
@@ -192,17 +189,19 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 		if let Some(ref price) = swap.price {
 			match price.direction {
-				PriceDirection::Send => T::Currency::transfer(
+				PriceDirection::Send => Self::pay_with_royalties(
+					send_collection_id,
+					send_item_id,
 					&receive_item.owner,
 					&send_item.owner,
 					price.amount,
-					KeepAlive,
 				)?,
-				PriceDirection::Receive => T::Currency::transfer(
+				PriceDirection::Receive => Self::pay_with_royalties(
+					receive_collection_id,
+					receive_item_id,
 					&send_item.owner,
 					&receive_item.owner,
 					price.amount,
-					KeepAlive,
 				)?,
 			};
 		}
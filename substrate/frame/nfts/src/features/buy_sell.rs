@@ -21,18 +21,16 @@
 //! to have the functionality defined in this module.
 
 use crate::*;
-use frame_support::{
-	pallet_prelude::*,
-	traits::{Currency, ExistenceRequirement, ExistenceRequirement::KeepAlive},
-};
+use frame_support::pallet_prelude::*;
 
 impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	/// Pays the specified tips to the corresponding receivers.
 	///
 	/// This function is used to pay tips from the `sender` account to multiple receivers. The tips
 	/// are specified as a `BoundedVec` of `ItemTipOf` with a maximum length of `T::MaxTips`. For
-	/// each tip, the function transfers the `amount` to the `receiver` account. The sender is
-	/// responsible for ensuring the validity of the provided tips.
+	/// each tip, the function transfers the `amount` to the `receiver` account, less any royalties
+	/// due on the tipped item which are paid to their recipients. The sender is responsible for
+	/// ensuring the validity of the provided tips.
 	///
 	/// - `sender`: The account that pays the tips.
 	/// - `tips`: A `BoundedVec` containing the tips to be paid, where each tip contains the
@@ -43,7 +41,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	) -> DispatchResult {
 		for tip in tips {
 			let ItemTip { collection, item, receiver, amount } = tip;
-			T::Currency::transfer(&sender, &receiver, amount, KeepAlive)?;
+			Self::pay_with_royalties(collection, item, &sender, &receiver, amount)?;
 			Self::deposit_event(Event::TipSent {
 				collection,
 				item,
@@ -119,7 +117,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	/// receive the bid price if it is equal to or higher than the item's set price. If
 	/// `whitelisted_buyer` is specified in the item's price information, only that account is
 	/// allowed to buy the item. If the item is not for sale, or the bid price is too low, the
-	/// function will return an error.
+	/// function will return an error. Any royalties due on the item are paid out of the price.
 	///
 	/// - `collection`: The identifier of the collection containing the item to be bought.
 	/// - `item`: The identifier of the item to be bought.
@@ -148,12 +146,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			ensure!(only_buyer == buyer, Error::<T, I>::NoPermission);
 		}
 
-		T::Currency::transfer(
-			&buyer,
-			&details.owner,
-			price_info.0,
-			ExistenceRequirement::KeepAlive,
-		)?;
+		Self::pay_with_royalties(collection, item, &buyer, &details.owner, price_info.0)?;
 
 		let old_owner = details.owner.clone();
 
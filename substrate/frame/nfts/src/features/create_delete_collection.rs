@@ -126,6 +126,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			}
 
 			CollectionMetadataOf::<T, I>::remove(&collection);
+			CollectionRoyaltiesOf::<T, I>::remove(&collection);
 			Self::clear_roles(&collection)?;
 
 			for (_, (_, deposit)) in Attribute::<T, I>::drain_prefix((&collection,)) {
@@ -28,7 +28,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	/// This function performs the minting of a new unique item. It checks if the item does not
 	/// already exist in the given collection, and if the max supply limit (if configured) is not
 	/// reached. It also reserves the required deposit for the item and sets the item details
-	/// accordingly.
+	/// accordingly. An item minted to the account of another item is nested within the latter.
 	///
 	/// # Errors
 	///
@@ -40,6 +40,9 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	///   ([`InconsistentItemConfig`](crate::Error::InconsistentItemConfig)).
 	/// - If the max supply limit (if configured) for the collection is reached
 	///   ([`MaxSupplyReached`](crate::Error::MaxSupplyReached)).
+	/// - If `mint_to` is the account of an item that doesn't exist
+	///   ([`UnknownItem`](crate::Error::UnknownItem)), or within which the item would be nested too
+	///   deep ([`NestingTooDeep`](crate::Error::NestingTooDeep)).
 	/// - If any error occurs in the `with_details_and_config` closure.
 	pub fn do_mint(
 		collection: T::CollectionId,
@@ -53,6 +56,11 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		) -> DispatchResult,
 	) -> DispatchResult {
 		ensure!(!Item::<T, I>::contains_key(collection, item), Error::<T, I>::AlreadyExists);
+		// Items only end up owned by another item by being nested within it.
+		let maybe_parent = Self::item_of_account(&mint_to);
+		if let Some(parent) = maybe_parent {
+			Self::ensure_can_nest((collection, item), 0, parent)?;
+		}
 
 		Collection::<T, I>::try_mutate(
 			&collection,
@@ -105,6 +113,9 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		)?;
 
 		Self::deposit_event(Event::Issued { collection, item, owner: mint_to });
+		if let Some(parent) = maybe_parent {
+			Self::link(collection, item, parent, 0)?;
+		}
 		Ok(())
 	}
 
@@ -205,6 +216,13 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	/// This function returns a dispatch error in the following cases:
 	/// - If the collection ID is invalid ([`UnknownCollection`](crate::Error::UnknownCollection)).
 	/// - If the item is locked ([`ItemLocked`](crate::Error::ItemLocked)).
+	/// - If the item is nested within another item ([`ItemNested`](crate::Error::ItemNested)).
+	/// - If other items are nested within the item or owned by its account
+	///   ([`HasNestedItems`](crate::Error::HasNestedItems)).
+	/// - If the account of the item holds a balance
+	///   ([`HasNestedBalance`](crate::Error::HasNestedBalance)). Only the balance of
+	///   [`Config::Currency`] is checked, so any other assets held by the account of the item
+	///   should be moved out of it beforehand.
 	pub fn do_burn(
 		collection: T::CollectionId,
 		item: T::ItemId,
@@ -215,6 +233,17 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			!Self::has_system_attribute(&collection, &item, PalletAttributes::TransferDisabled)?,
 			Error::<T, I>::ItemLocked
 		);
+		Self::ensure_not_nesting(&collection, &item)?;
+		// Nothing may be left behind in the account of the item, where it couldn't be reached.
+		let item_account = Self::item_account(&collection, &item);
+		ensure!(
+			Account::<T, I>::iter_key_prefix((&item_account,)).next().is_none(),
+			Error::<T, I>::HasNestedItems
+		);
+		ensure!(
+			T::Currency::total_balance(&item_account).is_zero(),
+			Error::<T, I>::HasNestedBalance
+		);
 		let item_config = Self::get_item_config(&collection, &item)?;
 		// NOTE: if item's settings are not empty (e.g. item's metadata is locked)
 		// then we keep the config record and don't remove it
@@ -262,6 +291,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		ItemPriceOf::<T, I>::remove(&collection, &item);
 		PendingSwapOf::<T, I>::remove(&collection, &item);
 		ItemAttributesApprovalsOf::<T, I>::remove(&collection, &item);
+		ItemRoyaltiesOf::<T, I>::remove(&collection, &item);
 
 		if remove_config {
 			ItemConfigOf::<T, I>::remove(&collection, &item);
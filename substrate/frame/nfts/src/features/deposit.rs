@@ -0,0 +1,156 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! This module contains helper methods to re-adjust the deposits held for collections and items
+//! to the currently configured requirements.
+
+use crate::*;
+use frame_support::{pallet_prelude::*, traits::PokeDeposit};
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Re-adjusts the deposits held by `who` for the `item` of `collection`, or for the
+	/// `collection` itself if no `item` is given, to the current requirements.
+	///
+	/// For an item, this covers the item deposit and the item metadata deposit. For a collection,
+	/// this covers the collection metadata deposit. The base collection deposit is not tracked
+	/// separately from `owner_deposit` and is therefore left untouched. Deposits that were waived
+	/// when the data was stored, e.g. by a privileged origin, remain waived.
+	///
+	/// - `who`: The account holding the deposits.
+	/// - `collection`: The collection whose deposits should be updated.
+	/// - `maybe_item`: The item whose deposits should be updated, if any.
+	///
+	/// Emits `DepositPoked` if any deposit was changed and returns whether that was the case.
+	/// Returns one of the following dispatch errors on failure:
+	/// - `UnknownCollection`: The specified collection does not exist.
+	/// - `UnknownItem`: The specified item does not exist within the collection.
+	/// - `NoDeposit`: `who` does not hold any deposit for the collection or item.
+	pub(crate) fn do_poke_deposit(
+		who: &T::AccountId,
+		collection: T::CollectionId,
+		maybe_item: Option<T::ItemId>,
+	) -> Result<bool, DispatchError> {
+		let mut collection_details =
+			Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
+		let collection_config = Self::get_collection_config(&collection)?;
+		let deposit_required =
+			collection_config.is_setting_enabled(CollectionSetting::DepositRequired);
+		let metadata_deposit = |len: usize| -> DepositBalanceOf<T, I> {
+			if deposit_required {
+				T::DepositPerByte::get()
+					.saturating_mul((len as u32).into())
+					.saturating_add(T::MetadataDepositBase::get())
+			} else {
+				Zero::zero()
+			}
+		};
+
+		let mut old_deposit = DepositBalanceOf::<T, I>::zero();
+		let mut new_deposit = DepositBalanceOf::<T, I>::zero();
+		match maybe_item {
+			Some(item) => {
+				let mut details =
+					Item::<T, I>::get(&collection, &item).ok_or(Error::<T, I>::UnknownItem)?;
+				if &details.deposit.account == who && !details.deposit.amount.is_zero() {
+					let deposit =
+						if deposit_required { T::ItemDeposit::get() } else { Zero::zero() };
+					Self::rejig_deposit(who, details.deposit.amount, deposit)?;
+					old_deposit.saturating_accrue(details.deposit.amount);
+					new_deposit.saturating_accrue(deposit);
+					details.deposit.amount = deposit;
+					Item::<T, I>::insert(&collection, &item, details);
+				}
+
+				if let Some(mut metadata) = ItemMetadataOf::<T, I>::get(&collection, &item) {
+					let depositor = metadata
+						.deposit
+						.account
+						.clone()
+						.unwrap_or(collection_details.owner.clone());
+					if &depositor == who && !metadata.deposit.amount.is_zero() {
+						let deposit = metadata_deposit(metadata.data.len());
+						Self::rejig_deposit(who, metadata.deposit.amount, deposit)?;
+						if metadata.deposit.account.is_none() {
+							collection_details
+								.owner_deposit
+								.saturating_reduce(metadata.deposit.amount);
+							collection_details.owner_deposit.saturating_accrue(deposit);
+						}
+						old_deposit.saturating_accrue(metadata.deposit.amount);
+						new_deposit.saturating_accrue(deposit);
+						metadata.deposit.amount = deposit;
+						ItemMetadataOf::<T, I>::insert(&collection, &item, metadata);
+					}
+				}
+			},
+			None => {
+				ensure!(&collection_details.owner == who, Error::<T, I>::NoDeposit);
+				if let Some(mut metadata) = CollectionMetadataOf::<T, I>::get(&collection) {
+					if !metadata.deposit.is_zero() {
+						let deposit = metadata_deposit(metadata.data.len());
+						Self::rejig_deposit(who, metadata.deposit, deposit)?;
+						collection_details.owner_deposit.saturating_reduce(metadata.deposit);
+						collection_details.owner_deposit.saturating_accrue(deposit);
+						old_deposit.saturating_accrue(metadata.deposit);
+						new_deposit.saturating_accrue(deposit);
+						metadata.deposit = deposit;
+						CollectionMetadataOf::<T, I>::insert(&collection, metadata);
+					}
+				}
+			},
+		}
+		ensure!(!old_deposit.is_zero(), Error::<T, I>::NoDeposit);
+		Collection::<T, I>::insert(&collection, collection_details);
+
+		let changed = old_deposit != new_deposit;
+		if changed {
+			Self::deposit_event(Event::DepositPoked {
+				who: who.clone(),
+				collection,
+				item: maybe_item,
+				old_deposit,
+				new_deposit,
+			});
+		}
+		Ok(changed)
+	}
+
+	/// Reserves or unreserves the difference between `old` and `new` deposits of `who`.
+	fn rejig_deposit(
+		who: &T::AccountId,
+		old: DepositBalanceOf<T, I>,
+		new: DepositBalanceOf<T, I>,
+	) -> DispatchResult {
+		if new > old {
+			T::Currency::reserve(who, new - old)?;
+		} else if new < old {
+			T::Currency::unreserve(who, old - new);
+		}
+		Ok(())
+	}
+}
+
+impl<T: Config<I>, I: 'static> PokeDeposit<T::AccountId> for Pallet<T, I> {
+	type Key = (T::CollectionId, Option<T::ItemId>);
+
+	fn poke_deposit(
+		who: &T::AccountId,
+		(collection, maybe_item): Self::Key,
+	) -> Result<bool, DispatchError> {
+		Self::do_poke_deposit(who, collection, maybe_item)
+	}
+}
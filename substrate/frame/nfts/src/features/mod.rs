@@ -21,8 +21,11 @@ pub mod attributes;
 pub mod buy_sell;
 pub mod create_delete_collection;
 pub mod create_delete_item;
+pub mod deposit;
 pub mod lock;
 pub mod metadata;
+pub mod nesting;
 pub mod roles;
+pub mod royalties;
 pub mod settings;
 pub mod transfer;
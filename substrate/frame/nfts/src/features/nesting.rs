@@ -0,0 +1,253 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! This module contains helper methods to nest items within other items, so that an item owns
+//! other items and balances through its own account.
+
+use crate::*;
+use frame_support::{pallet_prelude::*, traits::PalletInfoAccess};
+use sp_runtime::traits::TrailingZeroInput;
+
+/// The prefix of the accounts of items, which tells them apart from any other account.
+const ITEM_ACCOUNT_PREFIX: [u8; 8] = *b"modlnfts";
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// The account of an item, which owns everything nested within that item.
+	///
+	/// The item is encoded into its account rather than hashed, so that [`Self::item_of_account`]
+	/// can tell which item an account belongs to.
+	pub fn item_account(collection: &T::CollectionId, item: &T::ItemId) -> T::AccountId {
+		(ITEM_ACCOUNT_PREFIX, Self::index() as u32, collection, item)
+			.using_encoded(|entropy| Decode::decode(&mut TrailingZeroInput::new(entropy)))
+			.expect("infinite length input; no invalid inputs for type; qed")
+	}
+
+	/// The item whose account `who` is, if any.
+	pub fn item_of_account(who: &T::AccountId) -> Option<(T::CollectionId, T::ItemId)> {
+		let (prefix, index, collection, item) = who
+			.using_encoded(|encoded| {
+				<([u8; 8], u32, T::CollectionId, T::ItemId)>::decode(&mut TrailingZeroInput::new(
+					encoded,
+				))
+			})
+			.ok()?;
+		(prefix == ITEM_ACCOUNT_PREFIX &&
+			index == Self::index() as u32 &&
+			Self::item_account(&collection, &item) == *who)
+			.then_some((collection, item))
+	}
+
+	/// The owner of the topmost item an item is nested within, or of the item itself if it's not
+	/// nested.
+	pub fn root_owner(
+		collection: &T::CollectionId,
+		item: &T::ItemId,
+	) -> Result<T::AccountId, DispatchError> {
+		let (collection, item) =
+			Self::ancestors(collection, item)?.pop().unwrap_or((*collection, *item));
+		let details = Item::<T, I>::get(&collection, &item).ok_or(Error::<T, I>::UnknownItem)?;
+		Ok(details.owner)
+	}
+
+	/// The items an item is nested within, from its parent up to the topmost one.
+	pub(crate) fn ancestors(
+		collection: &T::CollectionId,
+		item: &T::ItemId,
+	) -> Result<Vec<(T::CollectionId, T::ItemId)>, DispatchError> {
+		let mut ancestors = Vec::new();
+		let mut next = NestingOf::<T, I>::get(collection, item).and_then(|n| n.parent);
+		while let Some((collection, item)) = next {
+			ensure!(
+				ancestors.len() < T::MaxNestingDepth::get() as usize,
+				Error::<T, I>::NestingTooDeep
+			);
+			ancestors.push((collection, item));
+			next = NestingOf::<T, I>::get(&collection, &item).and_then(|n| n.parent);
+		}
+		Ok(ancestors)
+	}
+
+	/// How deep the nesting within an item goes, given the counts of its `children` by height.
+	fn nesting_height(children: &[u32]) -> u32 {
+		children.iter().rposition(|count| !count.is_zero()).map_or(0, |h| h as u32 + 1)
+	}
+
+	/// Ensures that an item is neither nested within another item nor has items nested within it.
+	pub(crate) fn ensure_not_nesting(
+		collection: &T::CollectionId,
+		item: &T::ItemId,
+	) -> DispatchResult {
+		if let Some(nesting) = NestingOf::<T, I>::get(collection, item) {
+			ensure!(nesting.parent.is_none(), Error::<T, I>::ItemNested);
+			ensure!(nesting.children.is_empty(), Error::<T, I>::HasNestedItems);
+		}
+		Ok(())
+	}
+
+	/// Nests an item within another item, taking it out of the item it was nested within, if any.
+	///
+	/// - `origin`: The account which must own the item.
+	/// - `collection`: The collection of the item to be nested.
+	/// - `item`: The item to be nested.
+	/// - `parent_collection`: The collection of the item to nest within.
+	/// - `parent_item`: The item to nest within.
+	pub(crate) fn do_nest(
+		origin: T::AccountId,
+		collection: T::CollectionId,
+		item: T::ItemId,
+		parent_collection: T::CollectionId,
+		parent_item: T::ItemId,
+	) -> DispatchResult {
+		let details = Item::<T, I>::get(&collection, &item).ok_or(Error::<T, I>::UnknownItem)?;
+		ensure!(details.owner == origin, Error::<T, I>::NoPermission);
+
+		let parent = (parent_collection, parent_item);
+		let height = NestingOf::<T, I>::get(&collection, &item)
+			.map_or(0, |n| Self::nesting_height(&n.children));
+		Self::ensure_can_nest((collection, item), height, parent)?;
+
+		Self::unlink(collection, item)?;
+		Self::transfer_item(
+			collection,
+			item,
+			Self::item_account(&parent_collection, &parent_item),
+			|_, _| Ok(()),
+		)?;
+		Self::link(collection, item, parent, height)
+	}
+
+	/// Ensures that an item, whose own nesting goes `height` deep, can be nested within the
+	/// `parent` item without creating a cycle or going deeper than [`Config::MaxNestingDepth`].
+	pub(crate) fn ensure_can_nest(
+		item: (T::CollectionId, T::ItemId),
+		height: u32,
+		parent: (T::CollectionId, T::ItemId),
+	) -> DispatchResult {
+		ensure!(Item::<T, I>::contains_key(&parent.0, &parent.1), Error::<T, I>::UnknownItem);
+		let mut lineage = Self::ancestors(&parent.0, &parent.1)?;
+		lineage.push(parent);
+		ensure!(!lineage.contains(&item), Error::<T, I>::NestingCycle);
+		ensure!(
+			lineage.len() as u32 + height <= T::MaxNestingDepth::get(),
+			Error::<T, I>::NestingTooDeep
+		);
+		Ok(())
+	}
+
+	/// Records that an item owned by the account of the `parent` item, and whose own nesting goes
+	/// `height` deep, is nested within it.
+	pub(crate) fn link(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		parent: (T::CollectionId, T::ItemId),
+		height: u32,
+	) -> DispatchResult {
+		let mut nesting = NestingOf::<T, I>::get(&collection, &item).unwrap_or_default();
+		nesting.parent = Some(parent);
+		NestingOf::<T, I>::insert(&collection, &item, nesting);
+		Self::note_child_height(parent, None, Some(height))?;
+
+		let (parent_collection, parent_item) = parent;
+		Self::deposit_event(Event::ItemNested { collection, item, parent_collection, parent_item });
+		Ok(())
+	}
+
+	/// Takes a nested item out of the item it's nested within and transfers it to `dest`.
+	///
+	/// - `origin`: The account which must own the topmost item the item is nested within.
+	/// - `collection`: The collection of the nested item.
+	/// - `item`: The nested item.
+	/// - `dest`: The account to receive ownership of the item.
+	pub(crate) fn do_unnest(
+		origin: T::AccountId,
+		collection: T::CollectionId,
+		item: T::ItemId,
+		dest: T::AccountId,
+	) -> DispatchResult {
+		ensure!(Self::root_owner(&collection, &item)? == origin, Error::<T, I>::NoPermission);
+		ensure!(Self::unlink(collection, item)?, Error::<T, I>::NotNested);
+		Self::do_transfer(collection, item, dest, |_, _| Ok(()))
+	}
+
+	/// Removes an item from the item it's nested within, returning whether it was nested.
+	///
+	/// The item keeps being owned by the account of its former parent.
+	fn unlink(collection: T::CollectionId, item: T::ItemId) -> Result<bool, DispatchError> {
+		let Some(mut nesting) = NestingOf::<T, I>::get(&collection, &item) else {
+			return Ok(false)
+		};
+		let Some((parent_collection, parent_item)) = nesting.parent.take() else {
+			return Ok(false)
+		};
+		let height = Self::nesting_height(&nesting.children);
+		if nesting.children.is_empty() {
+			NestingOf::<T, I>::remove(&collection, &item);
+		} else {
+			NestingOf::<T, I>::insert(&collection, &item, nesting);
+		}
+		Self::note_child_height((parent_collection, parent_item), Some(height), None)?;
+
+		Self::deposit_event(Event::ItemUnnested {
+			collection,
+			item,
+			parent_collection,
+			parent_item,
+		});
+		Ok(true)
+	}
+
+	/// Records that a child of the `parent` item, whose own nesting went `removed` deep, now goes
+	/// `added` deep, and updates the height of every item above accordingly.
+	fn note_child_height(
+		parent: (T::CollectionId, T::ItemId),
+		mut removed: Option<u32>,
+		mut added: Option<u32>,
+	) -> DispatchResult {
+		let (mut collection, mut item) = parent;
+		loop {
+			let mut nesting = NestingOf::<T, I>::get(&collection, &item).unwrap_or_default();
+			let old_height = Self::nesting_height(&nesting.children);
+			if let Some(count) = removed.and_then(|h| nesting.children.get_mut(h as usize)) {
+				count.saturating_dec();
+			}
+			if let Some(height) = added {
+				while nesting.children.len() <= height as usize {
+					nesting.children.try_push(0).map_err(|_| Error::<T, I>::NestingTooDeep)?;
+				}
+				nesting.children[height as usize].saturating_inc();
+			}
+			while nesting.children.last() == Some(&0) {
+				nesting.children.pop();
+			}
+			let new_height = Self::nesting_height(&nesting.children);
+
+			let next = nesting.parent;
+			if next.is_none() && nesting.children.is_empty() {
+				NestingOf::<T, I>::remove(&collection, &item);
+			} else {
+				NestingOf::<T, I>::insert(&collection, &item, nesting);
+			}
+			match next {
+				Some(parent) if new_height != old_height => {
+					(removed, added) = (Some(old_height), Some(new_height));
+					(collection, item) = parent;
+				},
+				_ => return Ok(()),
+			}
+		}
+	}
+}
@@ -0,0 +1,116 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! This module contains helper methods to configure the royalties of collections and items, and
+//! to pay them out of the price of a sale.
+
+use crate::*;
+use frame_support::{
+	pallet_prelude::*,
+	traits::{Currency, ExistenceRequirement::KeepAlive},
+};
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Sets the royalties of a collection, or of an item within it.
+	///
+	/// The total share of the royalties can't exceed the whole price. Empty `royalties` clear the
+	/// ones which were set before.
+	///
+	/// - `maybe_check_origin`: The account which must be the owner of the collection, if any.
+	/// - `collection`: The collection whose royalties are set.
+	/// - `maybe_item`: The item whose royalties are set, if any.
+	/// - `royalties`: The recipients and their share of the price.
+	pub(crate) fn do_set_royalties(
+		maybe_check_origin: Option<T::AccountId>,
+		collection: T::CollectionId,
+		maybe_item: Option<T::ItemId>,
+		royalties: RoyaltiesOf<T, I>,
+	) -> DispatchResult {
+		if let Some(check_origin) = &maybe_check_origin {
+			ensure!(
+				Self::collection_owner(collection).as_ref() == Some(check_origin),
+				Error::<T, I>::NoPermission
+			);
+		}
+
+		let is_root = maybe_check_origin.is_none();
+		let collection_config = Self::get_collection_config(&collection)?;
+		ensure!(
+			is_root || collection_config.is_setting_enabled(CollectionSetting::UnlockedRoyalties),
+			Error::<T, I>::LockedRoyalties
+		);
+
+		let total: u64 = royalties.iter().map(|r| r.share.deconstruct() as u64).sum();
+		ensure!(total <= Perbill::one().deconstruct() as u64, Error::<T, I>::RoyaltiesTooHigh);
+
+		match maybe_item {
+			Some(item) => {
+				ensure!(Item::<T, I>::contains_key(&collection, &item), Error::<T, I>::UnknownItem);
+				if royalties.is_empty() {
+					ItemRoyaltiesOf::<T, I>::remove(&collection, &item);
+				} else {
+					ItemRoyaltiesOf::<T, I>::insert(&collection, &item, &royalties);
+				}
+			},
+			None =>
+				if royalties.is_empty() {
+					CollectionRoyaltiesOf::<T, I>::remove(&collection);
+				} else {
+					CollectionRoyaltiesOf::<T, I>::insert(&collection, &royalties);
+				},
+		}
+
+		Self::deposit_event(Event::RoyaltiesSet { collection, item: maybe_item, royalties });
+		Ok(())
+	}
+
+	/// The royalties due on the sale of an item: its own ones if set, or otherwise the ones of its
+	/// collection.
+	pub fn royalties(collection: &T::CollectionId, item: &T::ItemId) -> RoyaltiesOf<T, I> {
+		ItemRoyaltiesOf::<T, I>::get(collection, item)
+			.or_else(|| CollectionRoyaltiesOf::<T, I>::get(collection))
+			.unwrap_or_default()
+	}
+
+	/// Pays the price of an item from the `buyer` to the `seller`, less the royalties due on it
+	/// which are paid to their recipients.
+	///
+	/// - `collection`: The collection of the item sold.
+	/// - `item`: The item sold.
+	/// - `buyer`: The account paying the price.
+	/// - `seller`: The account receiving the price, less the royalties.
+	/// - `price`: The price of the item.
+	pub(crate) fn pay_with_royalties(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		buyer: &T::AccountId,
+		seller: &T::AccountId,
+		price: ItemPrice<T, I>,
+	) -> DispatchResult {
+		let mut remainder = price;
+		for RoyaltyRecipient { recipient, share } in Self::royalties(&collection, &item) {
+			let amount = share.mul_floor(price);
+			if amount.is_zero() {
+				continue
+			}
+			T::Currency::transfer(buyer, &recipient, amount, KeepAlive)?;
+			remainder.saturating_reduce(amount);
+			Self::deposit_event(Event::RoyaltyPaid { collection, item, recipient, amount });
+		}
+		T::Currency::transfer(buyer, seller, remainder, KeepAlive)
+	}
+}
@@ -41,6 +41,9 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	/// - If the item ID is invalid ([`UnknownItem`](crate::Error::UnknownItem)).
 	/// - If the item is locked or transferring it is disabled
 	///   ([`ItemLocked`](crate::Error::ItemLocked)).
+	/// - If the item is nested within another item ([`ItemNested`](crate::Error::ItemNested)).
+	/// - If the destination is the account of an item
+	///   ([`NestingRequired`](crate::Error::NestingRequired)).
 	/// - If the collection or item is non-transferable
 	///   ([`ItemsNonTransferable`](crate::Error::ItemsNonTransferable)).
 	pub fn do_transfer(
@@ -51,6 +54,24 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			&CollectionDetailsFor<T, I>,
 			&mut ItemDetailsFor<T, I>,
 		) -> DispatchResult,
+	) -> DispatchResult {
+		// Ensure items only end up owned by another item by being nested within it.
+		ensure!(Self::item_of_account(&dest).is_none(), Error::<T, I>::NestingRequired);
+		Self::transfer_item(collection, item, dest, with_details)
+	}
+
+	/// Transfer an NFT to the specified destination account, which may be the account of an item.
+	///
+	/// This performs the same checks as [`Self::do_transfer`] otherwise, and is meant to be used
+	/// when nesting the NFT within the item.
+	pub(crate) fn transfer_item(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		dest: T::AccountId,
+		with_details: impl FnOnce(
+			&CollectionDetailsFor<T, I>,
+			&mut ItemDetailsFor<T, I>,
+		) -> DispatchResult,
 	) -> DispatchResult {
 		// Retrieve collection details.
 		let collection_details =
@@ -59,6 +80,12 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		// Ensure the item is not locked.
 		ensure!(!T::Locker::is_locked(collection, item), Error::<T, I>::ItemLocked);
 
+		// Ensure the item is not nested within another item.
+		ensure!(
+			NestingOf::<T, I>::get(&collection, &item).map_or(true, |n| n.parent.is_none()),
+			Error::<T, I>::ItemNested
+		);
+
 		// Ensure the item is not transfer disabled on the system level attribute.
 		ensure!(
 			!Self::has_system_attribute(&collection, &item, PalletAttributes::TransferDisabled)?,
@@ -49,14 +49,17 @@ pub mod macros;
 pub mod weights;
 
 use codec::{Decode, Encode};
-use frame_support::traits::{
-	tokens::Locker, BalanceStatus::Reserved, Currency, EnsureOriginWithArg, Incrementable,
-	ReservableCurrency,
+use frame_support::{
+	dispatch::{extract_actual_weight, GetDispatchInfo, PostDispatchInfo},
+	traits::{
+		tokens::Locker, BalanceStatus::Reserved, Currency, EnsureOriginWithArg, Incrementable,
+		OriginTrait, ReservableCurrency,
+	},
 };
 use frame_system::Config as SystemConfig;
 use sp_runtime::{
-	traits::{IdentifyAccount, Saturating, StaticLookup, Verify, Zero},
-	RuntimeDebug,
+	traits::{Dispatchable, IdentifyAccount, Saturating, StaticLookup, Verify, Zero},
+	Perbill, RuntimeDebug,
 };
 use sp_std::prelude::*;
 
@@ -77,7 +80,7 @@ pub mod pallet {
 	use frame_system::pallet_prelude::*;
 
 	/// The current storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -105,6 +108,13 @@ pub mod pallet {
 		type RuntimeEvent: From<Event<Self, I>>
 			+ IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
+		/// The overarching call type, dispatched on behalf of an item by `as_item`.
+		type RuntimeCall: Parameter
+			+ Dispatchable<RuntimeOrigin = Self::RuntimeOrigin, PostInfo = PostDispatchInfo>
+			+ GetDispatchInfo
+			+ From<frame_system::Call<Self>>
+			+ IsType<<Self as frame_system::Config>::RuntimeCall>;
+
 		/// Identifier for the collection of item.
 		///
 		/// SAFETY: The functions in the `Incrementable` trait are fallible. If the functions
@@ -182,6 +192,14 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxTips: Get<u32>;
 
+		/// The max number of accounts which could receive royalties on the sale of an item.
+		#[pallet::constant]
+		type MaxRoyaltyRecipients: Get<u32>;
+
+		/// The max number of items an item could be nested within, one inside the other.
+		#[pallet::constant]
+		type MaxNestingDepth: Get<u32>;
+
 		/// The max duration in blocks for deadlines.
 		#[pallet::constant]
 		type MaxDeadlineDuration: Get<BlockNumberFor<Self>>;
@@ -344,6 +362,35 @@ pub mod pallet {
 	pub type NextCollectionId<T: Config<I>, I: 'static = ()> =
 		StorageValue<_, T::CollectionId, OptionQuery>;
 
+	/// The royalties paid on the sale of any item of a collection.
+	#[pallet::storage]
+	pub type CollectionRoyaltiesOf<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::CollectionId, RoyaltiesOf<T, I>, OptionQuery>;
+
+	/// The royalties paid on the sale of an item, in place of the ones of its collection.
+	#[pallet::storage]
+	pub type ItemRoyaltiesOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::ItemId,
+		RoyaltiesOf<T, I>,
+		OptionQuery,
+	>;
+
+	/// The nesting of items within other items.
+	#[pallet::storage]
+	pub type NestingOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::ItemId,
+		NestingDetailsFor<T, I>,
+		OptionQuery,
+	>;
+
 	/// Handles all the pending swaps.
 	#[pallet::storage]
 	pub type PendingSwapOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
@@ -558,6 +605,43 @@ pub mod pallet {
 			attribute: PalletAttributes<T::CollectionId>,
 			value: BoundedVec<u8, T::ValueLimit>,
 		},
+		/// The deposits held by `who` for the `collection` or an `item` within that `collection`
+		/// have been updated to the current requirements.
+		DepositPoked {
+			who: T::AccountId,
+			collection: T::CollectionId,
+			item: Option<T::ItemId>,
+			old_deposit: DepositBalanceOf<T, I>,
+			new_deposit: DepositBalanceOf<T, I>,
+		},
+		/// The royalties of the `collection`, or of an `item` within that `collection`, have been
+		/// set. Empty `royalties` means that they have been cleared.
+		RoyaltiesSet {
+			collection: T::CollectionId,
+			item: Option<T::ItemId>,
+			royalties: RoyaltiesOf<T, I>,
+		},
+		/// A royalty was paid on the sale of an item.
+		RoyaltyPaid {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			recipient: T::AccountId,
+			amount: ItemPrice<T, I>,
+		},
+		/// An `item` was nested within the `parent_item`, whose account now owns it.
+		ItemNested {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			parent_collection: T::CollectionId,
+			parent_item: T::ItemId,
+		},
+		/// An `item` was taken out of the `parent_item` it was nested within.
+		ItemUnnested {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			parent_collection: T::CollectionId,
+			parent_item: T::ItemId,
+		},
 	}
 
 	#[pallet::error]
@@ -652,6 +736,38 @@ pub mod pallet {
 		CollectionNotEmpty,
 		/// The witness data should be provided.
 		WitnessRequired,
+		/// The account does not hold any deposit for the collection or item.
+		NoDeposit,
+		/// The royalties of the collection and its items are locked.
+		LockedRoyalties,
+		/// The royalties add up to more than the whole price.
+		RoyaltiesTooHigh,
+		/// The item is nested within another item and must be unnested first.
+		ItemNested,
+		/// The item is not nested within another item.
+		NotNested,
+		/// The item can't be nested within itself or within an item nested within it.
+		NestingCycle,
+		/// The nesting would go deeper than allowed.
+		NestingTooDeep,
+		/// The item has other items nested within it.
+		HasNestedItems,
+		/// Items can only be moved into the account of an item by nesting them within it.
+		NestingRequired,
+		/// The account of the item still holds a balance.
+		HasNestedBalance,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
+		fn integrity_test() {
+			// Otherwise the accounts of items are truncated, and may be shared by several items.
+			assert!(
+				<([u8; 8], u32, T::CollectionId, T::ItemId)>::max_encoded_len() <=
+					T::AccountId::max_encoded_len(),
+				"The account of an item must be able to encode its collection and item ids",
+			);
+		}
 	}
 
 	#[pallet::call]
@@ -804,7 +920,10 @@ pub mod pallet {
 		///
 		/// Weight: `O(1)`
 		#[pallet::call_index(3)]
-		#[pallet::weight(T::WeightInfo::mint())]
+		#[pallet::weight(
+			T::WeightInfo::mint()
+				.saturating_add(T::WeightInfo::nest_minted(T::MaxNestingDepth::get()))
+		)]
 		pub fn mint(
 			origin: OriginFor<T>,
 			collection: T::CollectionId,
@@ -915,7 +1034,10 @@ pub mod pallet {
 		///
 		/// Weight: `O(1)`
 		#[pallet::call_index(4)]
-		#[pallet::weight(T::WeightInfo::force_mint())]
+		#[pallet::weight(
+			T::WeightInfo::force_mint()
+				.saturating_add(T::WeightInfo::nest_minted(T::MaxNestingDepth::get()))
+		)]
 		pub fn force_mint(
 			origin: OriginFor<T>,
 			collection: T::CollectionId,
@@ -1722,9 +1844,13 @@ pub mod pallet {
 		/// - `item`: The item the sender wants to buy.
 		/// - `bid_price`: The price the sender is willing to pay.
 		///
+		/// Any royalties due on the item are paid out of the price.
+		///
+		/// Emits `RoyaltyPaid` for each royalty paid.
 		/// Emits `ItemBought` on success.
 		#[pallet::call_index(32)]
-		#[pallet::weight(T::WeightInfo::buy_item())]
+		#[pallet::weight(T::WeightInfo::buy_item()
+			.saturating_add(T::WeightInfo::pay_royalties(T::MaxRoyaltyRecipients::get())))]
 		pub fn buy_item(
 			origin: OriginFor<T>,
 			collection: T::CollectionId,
@@ -1741,9 +1867,16 @@ pub mod pallet {
 		///
 		/// - `tips`: Tips array.
 		///
+		/// Any royalties due on a tipped item are paid out of its tip, so that tips can't be used
+		/// to pay for an item while avoiding its royalties.
+		///
+		/// Emits `RoyaltyPaid` for each royalty paid.
 		/// Emits `TipSent` on every tip transfer.
 		#[pallet::call_index(33)]
-		#[pallet::weight(T::WeightInfo::pay_tips(tips.len() as u32))]
+		#[pallet::weight(T::WeightInfo::pay_tips(tips.len() as u32).saturating_add(
+			T::WeightInfo::pay_royalties(T::MaxRoyaltyRecipients::get())
+				.saturating_mul(tips.len() as u64)
+		))]
 		pub fn pay_tips(
 			origin: OriginFor<T>,
 			tips: BoundedVec<ItemTipOf<T, I>, T::MaxTips>,
@@ -1822,9 +1955,13 @@ pub mod pallet {
 		/// - `receive_item`: The item to be received.
 		/// - `witness_price`: A price that was previously agreed on.
 		///
+		/// Any royalties due on the item paid for are paid out of the price.
+		///
+		/// Emits `RoyaltyPaid` for each royalty paid.
 		/// Emits `SwapClaimed` on success.
 		#[pallet::call_index(36)]
-		#[pallet::weight(T::WeightInfo::claim_swap())]
+		#[pallet::weight(T::WeightInfo::claim_swap()
+			.saturating_add(T::WeightInfo::pay_royalties(T::MaxRoyaltyRecipients::get())))]
 		pub fn claim_swap(
 			origin: OriginFor<T>,
 			send_collection: T::CollectionId,
@@ -1858,7 +1995,10 @@ pub mod pallet {
 		/// Emits `AttributeSet` if the attributes were provided.
 		/// Emits `ItemMetadataSet` if the metadata was not empty.
 		#[pallet::call_index(37)]
-		#[pallet::weight(T::WeightInfo::mint_pre_signed(mint_data.attributes.len() as u32))]
+		#[pallet::weight(
+			T::WeightInfo::mint_pre_signed(mint_data.attributes.len() as u32)
+				.saturating_add(T::WeightInfo::nest_minted(T::MaxNestingDepth::get()))
+		)]
 		pub fn mint_pre_signed(
 			origin: OriginFor<T>,
 			mint_data: Box<PreSignedMintOf<T, I>>,
@@ -1895,6 +2035,161 @@ pub mod pallet {
 			Self::validate_signature(&Encode::encode(&data), &signature, &signer)?;
 			Self::do_set_attributes_pre_signed(origin, data, signer)
 		}
+
+		/// Re-adjust the deposits held by the sender for an item, or for the collection's metadata
+		/// if no item is given, to the current requirements.
+		///
+		/// Origin must be Signed and must hold a deposit for the `collection` or `maybe_item`.
+		///
+		/// - `collection`: The collection whose deposits should be updated.
+		/// - `maybe_item`: The item whose deposits should be updated, if any.
+		///
+		/// The sender pays no fee if any deposit was updated.
+		///
+		/// Emits `DepositPoked` if any deposit was updated.
+		#[pallet::call_index(39)]
+		#[pallet::weight(T::WeightInfo::poke_deposit())]
+		pub fn poke_deposit(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			maybe_item: Option<T::ItemId>,
+		) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
+			let changed = Self::do_poke_deposit(&origin, collection, maybe_item)?;
+			Ok(if changed { Pays::No } else { Pays::Yes }.into())
+		}
+
+		/// Set the royalties paid on every sale of the items of a collection, or of a single item.
+		///
+		/// Royalties set for an item take the place of the ones of its collection. They are paid
+		/// out of the price of `buy_item` and of `claim_swap`, the seller receiving the rest.
+		///
+		/// Origin must be either `ForceOrigin` or Signed and the sender should be the Owner of the
+		/// `collection`.
+		///
+		/// - `collection`: The collection whose royalties should be set.
+		/// - `maybe_item`: The item whose royalties should be set, if any.
+		/// - `royalties`: The recipients and their share of the price. Empty to clear them.
+		///
+		/// Emits `RoyaltiesSet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(40)]
+		#[pallet::weight(T::WeightInfo::set_royalties())]
+		pub fn set_royalties(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			maybe_item: Option<T::ItemId>,
+			royalties: RoyaltiesOf<T, I>,
+		) -> DispatchResult {
+			let maybe_check_origin = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some).map_err(DispatchError::from))?;
+			Self::do_set_royalties(maybe_check_origin, collection, maybe_item, royalties)
+		}
+
+		/// Nest an item within another item.
+		///
+		/// The item is transferred to the account of the parent item, so that it follows the
+		/// parent wherever the latter is transferred. Fungible balances can be nested the same way
+		/// by transferring them to [`Pallet::item_account`].
+		///
+		/// Origin must be Signed and the sender should be the Owner of the `item`. Nesting an item
+		/// which is already nested within another one takes it out of the latter, so the call can
+		/// be dispatched through `as_item` to move nested items around.
+		///
+		/// - `collection`: The collection of the item to be nested.
+		/// - `item`: The item to be nested.
+		/// - `parent_collection`: The collection of the item to nest within.
+		/// - `parent_item`: The item to nest within.
+		///
+		/// Emits `ItemNested`.
+		///
+		/// Weight: `O(MaxNestingDepth)`
+		#[pallet::call_index(41)]
+		#[pallet::weight(T::WeightInfo::nest(T::MaxNestingDepth::get()))]
+		pub fn nest(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			parent_collection: T::CollectionId,
+			parent_item: T::ItemId,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_nest(origin, collection, item, parent_collection, parent_item)
+		}
+
+		/// Take a nested item out of the item it's nested within.
+		///
+		/// Origin must be Signed and the sender should be the Owner of the topmost item the `item`
+		/// is nested within.
+		///
+		/// - `collection`: The collection of the nested item.
+		/// - `item`: The nested item.
+		/// - `dest`: The account to receive ownership of the item.
+		///
+		/// Emits `ItemUnnested`.
+		///
+		/// Weight: `O(MaxNestingDepth)`
+		#[pallet::call_index(42)]
+		#[pallet::weight(T::WeightInfo::unnest(T::MaxNestingDepth::get()))]
+		pub fn unnest(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			dest: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let dest = T::Lookup::lookup(dest)?;
+			Self::do_unnest(origin, collection, item, dest)
+		}
+
+		/// Dispatch a call on behalf of an item, from the account of the item.
+		///
+		/// This allows the items and the balances nested within an item to be managed by the owner
+		/// of that item. The call is dispatched with the filters of the origin of this call.
+		///
+		/// Origin must be Signed and the sender should be the Owner of the topmost item the `item`
+		/// is nested within, or of the `item` itself if it's not nested.
+		///
+		/// - `collection`: The collection of the item.
+		/// - `item`: The item to dispatch the call on behalf of.
+		/// - `call`: The call to dispatch.
+		///
+		/// Weight: `O(MaxNestingDepth)` plus the weight of the `call`.
+		#[pallet::call_index(43)]
+		#[pallet::weight({
+			let dispatch_info = call.get_dispatch_info();
+			(
+				T::WeightInfo::as_item(T::MaxNestingDepth::get()).saturating_add(dispatch_info.weight),
+				dispatch_info.class,
+			)
+		})]
+		pub fn as_item(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			call: Box<<T as Config<I>>::RuntimeCall>,
+		) -> DispatchResultWithPostInfo {
+			let mut origin = origin;
+			let who = ensure_signed(origin.clone())?;
+			ensure!(Self::root_owner(&collection, &item)? == who, Error::<T, I>::NoPermission);
+			origin.set_caller_from(frame_system::RawOrigin::Signed(Self::item_account(
+				&collection,
+				&item,
+			)));
+			let info = call.get_dispatch_info();
+			let result = call.dispatch(origin);
+			// Always take into account the base weight of this call.
+			let weight = T::WeightInfo::as_item(T::MaxNestingDepth::get())
+				.saturating_add(extract_actual_weight(&result, &info));
+			result
+				.map_err(|mut err| {
+					err.post_info = Some(weight).into();
+					err
+				})
+				.map(|_| Some(weight).into())
+		}
 	}
 }
 
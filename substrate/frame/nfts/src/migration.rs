@@ -64,7 +64,7 @@ pub mod v1 {
 				onchain_version
 			);
 
-			if onchain_version == 0 && current_version == 1 {
+			if onchain_version == 0 {
 				let mut translated = 0u64;
 				let mut configs_iterated = 0u64;
 				Collection::<T>::translate::<
@@ -77,13 +77,13 @@ pub mod v1 {
 					Some(old_value.migrate_to_v1(item_configs))
 				});
 
-				current_version.put::<Pallet<T>>();
+				StorageVersion::new(1).put::<Pallet<T>>();
 
 				log::info!(
 					target: LOG_TARGET,
 					"Upgraded {} records, storage to version {:?}",
 					translated,
-					current_version
+					StorageVersion::new(1)
 				);
 				T::DbWeight::get().reads_writes(translated + configs_iterated + 1, translated + 1)
 			} else {
@@ -118,3 +118,84 @@ pub mod v1 {
 		}
 	}
 }
+
+pub mod v2 {
+	use frame_support::{pallet_prelude::*, weights::Weight};
+
+	use super::*;
+
+	/// A migration utility to update the storage version from v1 to v2 for the pallet.
+	///
+	/// The royalties of a collection can be changed by its owner as long as
+	/// [`CollectionSetting::UnlockedRoyalties`] isn't disabled, which it isn't for any collection
+	/// created before the royalties were introduced. This disables it for all the existing
+	/// collections, so that royalties can't be imposed on their items without the force origin.
+	pub struct MigrateToV2<T>(sp_std::marker::PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let current_version = Pallet::<T>::current_storage_version();
+			let onchain_version = Pallet::<T>::on_chain_storage_version();
+
+			log::info!(
+				target: LOG_TARGET,
+				"Running migration with current storage version {:?} / onchain {:?}",
+				current_version,
+				onchain_version
+			);
+
+			if onchain_version == 1 && current_version == 2 {
+				let mut translated = 0u64;
+				CollectionConfigOf::<T>::translate_values::<CollectionConfigFor<T>, _>(
+					|mut config| {
+						config.disable_setting(CollectionSetting::UnlockedRoyalties);
+						translated.saturating_inc();
+						Some(config)
+					},
+				);
+
+				current_version.put::<Pallet<T>>();
+
+				log::info!(
+					target: LOG_TARGET,
+					"Upgraded {} records, storage to version {:?}",
+					translated,
+					current_version
+				);
+				T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+			} else {
+				log::info!(
+					target: LOG_TARGET,
+					"Migration did not execute. This probably should be removed"
+				);
+				T::DbWeight::get().reads(1)
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			let prev_count = CollectionConfigOf::<T>::iter().count();
+			Ok((prev_count as u32).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(prev_count: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let prev_count: u32 = Decode::decode(&mut prev_count.as_slice()).expect(
+				"the state parameter should be something that was generated by pre_upgrade",
+			);
+			let post_count = CollectionConfigOf::<T>::iter().count() as u32;
+			ensure!(
+				prev_count == post_count,
+				"the records count before and after the migration should be the same"
+			);
+			ensure!(
+				CollectionConfigOf::<T>::iter_values()
+					.all(|config| !config.is_setting_enabled(CollectionSetting::UnlockedRoyalties)),
+				"the royalties of all the existing collections should be locked"
+			);
+
+			ensure!(Pallet::<T>::on_chain_storage_version() >= 2, "wrong storage version");
+
+			Ok(())
+		}
+	}
+}
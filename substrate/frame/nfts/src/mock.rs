@@ -96,6 +96,7 @@ parameter_types! {
 
 impl Config for Test {
 	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
 	type CollectionId = u32;
 	type ItemId = u32;
 	type Currency = Balances;
@@ -113,6 +114,8 @@ impl Config for Test {
 	type ApprovalsLimit = ConstU32<10>;
 	type ItemAttributesApprovalsLimit = ConstU32<2>;
 	type MaxTips = ConstU32<10>;
+	type MaxRoyaltyRecipients = ConstU32<10>;
+	type MaxNestingDepth = ConstU32<3>;
 	type MaxDeadlineDuration = ConstU64<10000>;
 	type MaxAttributesPerCall = ConstU32<2>;
 	type Features = Features;
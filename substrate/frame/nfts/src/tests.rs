@@ -20,7 +20,8 @@
 use crate::{mock::*, Event, SystemConfig, *};
 use enumflags2::BitFlags;
 use frame_support::{
-	assert_noop, assert_ok,
+	assert_err_ignore_postinfo, assert_noop, assert_ok,
+	dispatch::Pays,
 	traits::{
 		tokens::nonfungibles_v2::{Create, Destroy, Inspect, Mutate},
 		Currency, Get,
@@ -30,7 +31,7 @@ use pallet_balances::Error as BalancesError;
 use sp_core::{bounded::BoundedVec, Pair};
 use sp_runtime::{
 	traits::{Dispatchable, IdentifyAccount},
-	MultiSignature, MultiSigner,
+	MultiSignature, MultiSigner, Perbill,
 };
 use sp_std::prelude::*;
 
@@ -897,6 +898,82 @@ fn set_item_metadata_should_work() {
 	});
 }
 
+#[test]
+fn poke_deposit_works() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 42, account(1), None));
+		assert_ok!(Nfts::set_metadata(RuntimeOrigin::signed(account(1)), 0, 42, bvec![0u8; 20]));
+		assert_ok!(Nfts::set_collection_metadata(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			bvec![0u8; 10]
+		));
+		assert_eq!(Balances::reserved_balance(&account(1)), 1 + 21 + 11);
+
+		// Nothing changes while the deposits are up to date.
+		let res = Nfts::poke_deposit(RuntimeOrigin::signed(account(1)), 0, Some(42)).unwrap();
+		assert_eq!(res.pays_fee, Pays::Yes);
+		let res = Nfts::poke_deposit(RuntimeOrigin::signed(account(1)), 0, None).unwrap();
+		assert_eq!(res.pays_fee, Pays::Yes);
+
+		assert_noop!(
+			Nfts::poke_deposit(RuntimeOrigin::signed(account(2)), 0, Some(42)),
+			Error::<Test>::NoDeposit
+		);
+		assert_noop!(
+			Nfts::poke_deposit(RuntimeOrigin::signed(account(2)), 0, None),
+			Error::<Test>::NoDeposit
+		);
+		assert_noop!(
+			Nfts::poke_deposit(RuntimeOrigin::signed(account(1)), 0, Some(43)),
+			Error::<Test>::UnknownItem
+		);
+		assert_noop!(
+			Nfts::poke_deposit(RuntimeOrigin::signed(account(1)), 1, None),
+			Error::<Test>::UnknownCollection
+		);
+
+		// Simulate deposits taken under an older pricing.
+		Item::<Test>::mutate(0, 42, |details| details.as_mut().unwrap().deposit.amount = 3);
+		assert_ok!(Balances::reserve(&account(1), 2));
+		ItemMetadataOf::<Test>::mutate(0, 42, |metadata| {
+			metadata.as_mut().unwrap().deposit.amount = 11
+		});
+		Balances::unreserve(&account(1), 10);
+		Collection::<Test>::mutate(0, |details| details.as_mut().unwrap().owner_deposit -= 10);
+
+		let res = Nfts::poke_deposit(RuntimeOrigin::signed(account(1)), 0, Some(42)).unwrap();
+		assert_eq!(res.pays_fee, Pays::No);
+		assert!(events().contains(&Event::<Test>::DepositPoked {
+			who: account(1),
+			collection: 0,
+			item: Some(42),
+			old_deposit: 14,
+			new_deposit: 22,
+		}));
+		assert_eq!(Balances::reserved_balance(&account(1)), 1 + 21 + 11);
+		assert_eq!(Item::<Test>::get(0, 42).unwrap().deposit.amount, 1);
+		assert_eq!(ItemMetadataOf::<Test>::get(0, 42).unwrap().deposit.amount, 21);
+		assert_eq!(Collection::<Test>::get(0).unwrap().owner_deposit, 21 + 11);
+
+		// Deposits waived by the force origin stay waived.
+		assert_ok!(Nfts::set_metadata(RuntimeOrigin::root(), 0, 42, bvec![0u8; 20]));
+		assert_ok!(Nfts::set_collection_metadata(RuntimeOrigin::root(), 0, bvec![0u8; 10]));
+		assert_ok!(Nfts::poke_deposit(RuntimeOrigin::signed(account(1)), 0, Some(42)));
+		assert_eq!(ItemMetadataOf::<Test>::get(0, 42).unwrap().deposit.amount, 0);
+		assert_noop!(
+			Nfts::poke_deposit(RuntimeOrigin::signed(account(1)), 0, None),
+			Error::<Test>::NoDeposit
+		);
+	});
+}
+
 #[test]
 fn set_collection_owner_attributes_should_work() {
 	new_test_ext().execute_with(|| {
@@ -2671,6 +2748,718 @@ fn pay_tips_should_work() {
 	});
 }
 
+#[test]
+fn set_royalties_should_work() {
+	new_test_ext().execute_with(|| {
+		let user_1 = account(1);
+		let user_2 = account(2);
+		let collection_id = 0;
+		let item_id = 1;
+		let royalty = |recipient, percent| RoyaltyRecipient {
+			recipient,
+			share: Perbill::from_percent(percent),
+		};
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			user_1.clone(),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::mint(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_id,
+			user_1.clone(),
+			None
+		));
+
+		assert_noop!(
+			Nfts::set_royalties(
+				RuntimeOrigin::signed(user_2.clone()),
+				collection_id,
+				None,
+				bvec![royalty(user_2.clone(), 10)],
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Nfts::set_royalties(
+				RuntimeOrigin::signed(user_1.clone()),
+				collection_id,
+				Some(2),
+				bvec![royalty(user_2.clone(), 10)],
+			),
+			Error::<Test>::UnknownItem
+		);
+		assert_noop!(
+			Nfts::set_royalties(
+				RuntimeOrigin::signed(user_1.clone()),
+				collection_id,
+				None,
+				bvec![royalty(user_1.clone(), 60), royalty(user_2.clone(), 50)],
+			),
+			Error::<Test>::RoyaltiesTooHigh
+		);
+
+		// the royalties of the item override the ones of the collection
+		assert_ok!(Nfts::set_royalties(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			None,
+			bvec![royalty(user_2.clone(), 10)],
+		));
+		assert_eq!(
+			Nfts::royalties(&collection_id, &item_id).to_vec(),
+			vec![royalty(user_2.clone(), 10)]
+		);
+		assert_ok!(Nfts::set_royalties(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			Some(item_id),
+			bvec![royalty(user_1.clone(), 5)],
+		));
+		assert!(events().contains(&Event::<Test>::RoyaltiesSet {
+			collection: collection_id,
+			item: Some(item_id),
+			royalties: bvec![royalty(user_1.clone(), 5)],
+		}));
+		assert_eq!(
+			Nfts::royalties(&collection_id, &item_id).to_vec(),
+			vec![royalty(user_1.clone(), 5)]
+		);
+
+		// empty royalties clear the item's ones
+		assert_ok!(Nfts::set_royalties(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			Some(item_id),
+			bvec![],
+		));
+		assert!(!ItemRoyaltiesOf::<Test>::contains_key(collection_id, item_id));
+		assert_eq!(
+			Nfts::royalties(&collection_id, &item_id).to_vec(),
+			vec![royalty(user_2.clone(), 10)]
+		);
+
+		// once locked, only the force origin can change the royalties
+		assert_ok!(Nfts::lock_collection(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			CollectionSettings::from_disabled(CollectionSetting::UnlockedRoyalties.into()),
+		));
+		assert_noop!(
+			Nfts::set_royalties(
+				RuntimeOrigin::signed(user_1.clone()),
+				collection_id,
+				None,
+				bvec![],
+			),
+			Error::<Test>::LockedRoyalties
+		);
+		assert_ok!(Nfts::set_royalties(RuntimeOrigin::root(), collection_id, None, bvec![]));
+		assert!(Nfts::royalties(&collection_id, &item_id).is_empty());
+
+		// the royalties are removed with the item and the collection
+		assert_ok!(Nfts::set_royalties(
+			RuntimeOrigin::root(),
+			collection_id,
+			Some(item_id),
+			bvec![royalty(user_2.clone(), 10)],
+		));
+		assert_ok!(Nfts::burn(RuntimeOrigin::signed(user_1.clone()), collection_id, item_id));
+		assert!(!ItemRoyaltiesOf::<Test>::contains_key(collection_id, item_id));
+	});
+}
+
+#[test]
+fn royalties_should_be_paid_on_sales() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let user_1 = account(1);
+		let user_2 = account(2);
+		let user_3 = account(3);
+		let user_4 = account(4);
+		let collection_id = 0;
+		let item_1 = 1;
+		let item_2 = 2;
+		let item_3 = 3;
+		let price = 100;
+		let initial_balance = 1000;
+
+		Balances::make_free_balance_be(&user_1, initial_balance);
+		Balances::make_free_balance_be(&user_2, initial_balance);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			user_1.clone(),
+			default_collection_config()
+		));
+		for item in [item_1, item_2] {
+			assert_ok!(Nfts::mint(
+				RuntimeOrigin::signed(user_1.clone()),
+				collection_id,
+				item,
+				user_1.clone(),
+				None
+			));
+		}
+		assert_ok!(Nfts::force_mint(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_3,
+			user_2.clone(),
+			default_item_config(),
+		));
+		assert_ok!(Nfts::set_royalties(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			None,
+			bvec![RoyaltyRecipient { recipient: user_3.clone(), share: Perbill::from_percent(10) }],
+		));
+		assert_ok!(Nfts::set_royalties(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			Some(item_2),
+			bvec![
+				RoyaltyRecipient { recipient: user_3.clone(), share: Perbill::from_percent(5) },
+				RoyaltyRecipient { recipient: user_4.clone(), share: Perbill::from_percent(20) },
+			],
+		));
+
+		// the collection's royalties are paid on a sale
+		assert_ok!(Nfts::set_price(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_1,
+			Some(price),
+			None,
+		));
+		assert_ok!(Nfts::buy_item(
+			RuntimeOrigin::signed(user_2.clone()),
+			collection_id,
+			item_1,
+			price,
+		));
+		assert_eq!(Balances::total_balance(&user_1), initial_balance + 90);
+		assert_eq!(Balances::total_balance(&user_2), initial_balance - price);
+		assert_eq!(Balances::total_balance(&user_3), 10);
+		assert!(events().contains(&Event::<Test>::RoyaltyPaid {
+			collection: collection_id,
+			item: item_1,
+			recipient: user_3.clone(),
+			amount: 10,
+		}));
+
+		// the item's own royalties are paid on a priced swap
+		assert_ok!(Nfts::create_swap(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_2,
+			collection_id,
+			Some(item_3),
+			Some(PriceWithDirection { amount: price, direction: PriceDirection::Receive }),
+			2,
+		));
+		assert_ok!(Nfts::claim_swap(
+			RuntimeOrigin::signed(user_2.clone()),
+			collection_id,
+			item_3,
+			collection_id,
+			item_2,
+			Some(PriceWithDirection { amount: price, direction: PriceDirection::Receive }),
+		));
+		assert_eq!(Balances::total_balance(&user_1), initial_balance + 90 + 75);
+		assert_eq!(Balances::total_balance(&user_2), initial_balance - price * 2);
+		assert_eq!(Balances::total_balance(&user_3), 10 + 5);
+		assert_eq!(Balances::total_balance(&user_4), 20);
+		assert_eq!(Item::<Test>::get(collection_id, item_2).unwrap().owner, user_2);
+		assert_eq!(Item::<Test>::get(collection_id, item_3).unwrap().owner, user_1);
+	});
+}
+
+#[test]
+fn royalties_should_be_paid_on_tips() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let user_1 = account(1);
+		let user_2 = account(2);
+		let user_3 = account(3);
+		let collection_id = 0;
+		let item_id = 1;
+		let tip = 100;
+		let initial_balance = 1000;
+
+		Balances::make_free_balance_be(&user_1, initial_balance);
+		Balances::make_free_balance_be(&user_2, initial_balance);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			user_1.clone(),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::set_royalties(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			None,
+			bvec![RoyaltyRecipient { recipient: user_3.clone(), share: Perbill::from_percent(10) }],
+		));
+
+		assert_ok!(Nfts::pay_tips(
+			RuntimeOrigin::signed(user_2.clone()),
+			bvec![ItemTip {
+				collection: collection_id,
+				item: item_id,
+				receiver: user_1.clone(),
+				amount: tip
+			}]
+		));
+		assert_eq!(Balances::total_balance(&user_1), initial_balance + 90);
+		assert_eq!(Balances::total_balance(&user_2), initial_balance - tip);
+		assert_eq!(Balances::total_balance(&user_3), 10);
+		let events = events();
+		assert!(events.contains(&Event::<Test>::RoyaltyPaid {
+			collection: collection_id,
+			item: item_id,
+			recipient: user_3.clone(),
+			amount: 10,
+		}));
+		assert!(events.contains(&Event::<Test>::TipSent {
+			collection: collection_id,
+			item: item_id,
+			sender: user_2.clone(),
+			receiver: user_1.clone(),
+			amount: tip,
+		}));
+	});
+}
+
+#[test]
+fn migration_to_v2_locks_royalties_of_existing_collections() {
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		let user_1 = account(1);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			user_1.clone(),
+			default_collection_config()
+		));
+		StorageVersion::new(1).put::<Nfts>();
+
+		migration::v2::MigrateToV2::<Test>::on_runtime_upgrade();
+
+		assert_eq!(Nfts::on_chain_storage_version(), StorageVersion::new(2));
+		assert_noop!(
+			Nfts::set_royalties(RuntimeOrigin::signed(user_1.clone()), 0, None, bvec![]),
+			Error::<Test>::LockedRoyalties
+		);
+		assert_ok!(Nfts::set_royalties(RuntimeOrigin::root(), 0, None, bvec![]));
+	});
+}
+
+#[test]
+fn nesting_should_work() {
+	new_test_ext().execute_with(|| {
+		let user_1 = account(1);
+		let user_2 = account(2);
+		let user_3 = account(3);
+		let collection_id = 0;
+		let parent = 1;
+		let child = 2;
+		let other = 3;
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			user_1.clone(),
+			default_collection_config()
+		));
+		for item in [parent, child, other] {
+			assert_ok!(Nfts::mint(
+				RuntimeOrigin::signed(user_1.clone()),
+				collection_id,
+				item,
+				user_1.clone(),
+				None
+			));
+		}
+
+		assert_noop!(
+			Nfts::nest(
+				RuntimeOrigin::signed(user_2.clone()),
+				collection_id,
+				child,
+				collection_id,
+				parent
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Nfts::nest(
+				RuntimeOrigin::signed(user_1.clone()),
+				collection_id,
+				child,
+				collection_id,
+				42
+			),
+			Error::<Test>::UnknownItem
+		);
+		assert_ok!(Nfts::nest(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			child,
+			collection_id,
+			parent
+		));
+		let parent_account = Nfts::item_account(&collection_id, &parent);
+		assert_eq!(Item::<Test>::get(collection_id, child).unwrap().owner, parent_account);
+		assert!(events().contains(&Event::<Test>::ItemNested {
+			collection: collection_id,
+			item: child,
+			parent_collection: collection_id,
+			parent_item: parent,
+		}));
+
+		// a nested item can't be transferred or burned, nor its parent burned
+		let transfer: RuntimeCall = crate::Call::<Test>::transfer {
+			collection: collection_id,
+			item: child,
+			dest: user_3.clone(),
+		}
+		.into();
+		assert_err_ignore_postinfo!(
+			Nfts::as_item(
+				RuntimeOrigin::signed(user_1.clone()),
+				collection_id,
+				parent,
+				Box::new(transfer)
+			),
+			Error::<Test>::ItemNested
+		);
+		assert_noop!(
+			Nfts::burn(RuntimeOrigin::signed(user_1.clone()), collection_id, parent),
+			Error::<Test>::HasNestedItems
+		);
+
+		// balances are nested through the account of the item as well
+		Balances::make_free_balance_be(&parent_account, 100);
+
+		// the nested item and balances follow the parent
+		assert_ok!(Nfts::transfer(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			parent,
+			user_2.clone()
+		));
+		assert_eq!(Nfts::root_owner(&collection_id, &child), Ok(user_2.clone()));
+		let withdraw: RuntimeCall =
+			pallet_balances::Call::<Test>::transfer_allow_death { dest: user_3.clone(), value: 30 }
+				.into();
+		assert_noop!(
+			Nfts::as_item(
+				RuntimeOrigin::signed(user_1.clone()),
+				collection_id,
+				parent,
+				Box::new(withdraw.clone())
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Nfts::as_item(
+			RuntimeOrigin::signed(user_2.clone()),
+			collection_id,
+			parent,
+			Box::new(withdraw)
+		));
+		assert_eq!(Balances::total_balance(&parent_account), 70);
+		assert_eq!(Balances::total_balance(&user_3), 30);
+
+		// nested items can be moved around through the item they're nested within
+		let move_child: RuntimeCall = crate::Call::<Test>::nest {
+			collection: collection_id,
+			item: child,
+			parent_collection: collection_id,
+			parent_item: other,
+		}
+		.into();
+		assert_ok!(Nfts::as_item(
+			RuntimeOrigin::signed(user_2.clone()),
+			collection_id,
+			parent,
+			Box::new(move_child)
+		));
+		assert_eq!(
+			Item::<Test>::get(collection_id, child).unwrap().owner,
+			Nfts::item_account(&collection_id, &other)
+		);
+		assert_eq!(Nfts::root_owner(&collection_id, &child), Ok(user_1.clone()));
+		assert!(!NestingOf::<Test>::contains_key(collection_id, parent));
+
+		assert_noop!(
+			Nfts::unnest(
+				RuntimeOrigin::signed(user_2.clone()),
+				collection_id,
+				child,
+				user_2.clone()
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Nfts::unnest(
+				RuntimeOrigin::signed(user_1.clone()),
+				collection_id,
+				other,
+				user_2.clone()
+			),
+			Error::<Test>::NotNested
+		);
+		assert_ok!(Nfts::unnest(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			child,
+			user_2.clone()
+		));
+		assert!(events().contains(&Event::<Test>::ItemUnnested {
+			collection: collection_id,
+			item: child,
+			parent_collection: collection_id,
+			parent_item: other,
+		}));
+		assert_eq!(Item::<Test>::get(collection_id, child).unwrap().owner, user_2);
+		assert_eq!(NestingOf::<Test>::iter().count(), 0);
+		assert_ok!(Nfts::burn(RuntimeOrigin::signed(user_1.clone()), collection_id, other));
+	});
+}
+
+#[test]
+fn item_accounts_should_be_checked() {
+	new_test_ext().execute_with(|| {
+		let user_1 = account(1);
+		let collection_id = 0;
+		let parent = 1;
+		let child = 2;
+		let other = 3;
+		let parent_account = Nfts::item_account(&collection_id, &parent);
+		let other_account = Nfts::item_account(&collection_id, &other);
+
+		assert_eq!(Nfts::item_of_account(&parent_account), Some((collection_id, parent)));
+		assert_eq!(Nfts::item_of_account(&user_1), None);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			user_1.clone(),
+			default_collection_config()
+		));
+		for item in [parent, child, other] {
+			assert_ok!(Nfts::mint(
+				RuntimeOrigin::signed(user_1.clone()),
+				collection_id,
+				item,
+				user_1.clone(),
+				None
+			));
+		}
+
+		// items only end up within other items by being nested
+		assert_noop!(
+			Nfts::transfer(
+				RuntimeOrigin::signed(user_1.clone()),
+				collection_id,
+				child,
+				parent_account.clone()
+			),
+			Error::<Test>::NestingRequired
+		);
+		assert_ok!(Nfts::nest(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			child,
+			collection_id,
+			parent
+		));
+		assert_noop!(
+			Nfts::unnest(
+				RuntimeOrigin::signed(user_1.clone()),
+				collection_id,
+				child,
+				other_account.clone()
+			),
+			Error::<Test>::NestingRequired
+		);
+
+		// an item can't be burned while its account holds anything
+		Balances::make_free_balance_be(&other_account, 10);
+		assert_noop!(
+			Nfts::burn(RuntimeOrigin::signed(user_1.clone()), collection_id, other),
+			Error::<Test>::HasNestedBalance
+		);
+		let withdraw: RuntimeCall =
+			pallet_balances::Call::<Test>::transfer_all { dest: user_1.clone(), keep_alive: false }
+				.into();
+		assert_ok!(Nfts::as_item(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			other,
+			Box::new(withdraw)
+		));
+		assert_ok!(Nfts::mint(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			4,
+			other_account.clone(),
+			None
+		));
+		assert_noop!(
+			Nfts::burn(RuntimeOrigin::signed(user_1.clone()), collection_id, other),
+			Error::<Test>::HasNestedItems
+		);
+		let burn: RuntimeCall =
+			crate::Call::<Test>::burn { collection: collection_id, item: 4 }.into();
+		assert_ok!(Nfts::as_item(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			other,
+			Box::new(burn)
+		));
+		assert_ok!(Nfts::burn(RuntimeOrigin::signed(user_1.clone()), collection_id, other));
+	});
+}
+
+#[test]
+fn nesting_depth_and_cycles_should_be_checked() {
+	new_test_ext().execute_with(|| {
+		let user_1 = account(1);
+		let collection_id = 0;
+		let nest = |item, parent_item| {
+			Nfts::nest(
+				RuntimeOrigin::signed(account(1)),
+				collection_id,
+				item,
+				collection_id,
+				parent_item,
+			)
+		};
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			user_1.clone(),
+			default_collection_config()
+		));
+		for item in 1..=7 {
+			assert_ok!(Nfts::mint(
+				RuntimeOrigin::signed(user_1.clone()),
+				collection_id,
+				item,
+				user_1.clone(),
+				None
+			));
+		}
+
+		assert_noop!(nest(1, 1), Error::<Test>::NestingCycle);
+
+		// 4 in 3 in 2 in 1 reaches the max depth of 3
+		assert_ok!(nest(2, 1));
+		assert_ok!(nest(3, 2));
+		assert_ok!(nest(4, 3));
+		assert_eq!(Nfts::ancestors(&collection_id, &4), Ok(vec![(0, 3), (0, 2), (0, 1)]));
+		assert_noop!(nest(1, 4), Error::<Test>::NestingCycle);
+		assert_noop!(nest(5, 4), Error::<Test>::NestingTooDeep);
+
+		// the items nested within an item count towards the depth of wherever it's nested
+		assert_ok!(nest(7, 6));
+		assert_noop!(nest(6, 3), Error::<Test>::NestingTooDeep);
+		assert_ok!(nest(6, 2));
+		assert_eq!(Nfts::root_owner(&collection_id, &7), Ok(user_1.clone()));
+
+		assert_noop!(nest(5, 7), Error::<Test>::NestingTooDeep);
+
+		// taking items out lowers how deep the nesting within the items above them goes
+		assert_noop!(nest(1, 5), Error::<Test>::NestingTooDeep);
+		for item in [6, 4, 3] {
+			assert_ok!(Nfts::unnest(
+				RuntimeOrigin::signed(user_1.clone()),
+				collection_id,
+				item,
+				user_1.clone()
+			));
+		}
+		assert_eq!(
+			NestingOf::<Test>::get(collection_id, 1).unwrap().children.into_inner(),
+			vec![1]
+		);
+		assert_ok!(nest(1, 5));
+		assert_eq!(
+			NestingOf::<Test>::get(collection_id, 5).unwrap().children.into_inner(),
+			vec![0, 1]
+		);
+	});
+}
+
+#[test]
+fn minting_to_an_item_account_should_nest() {
+	new_test_ext().execute_with(|| {
+		let user_1 = account(1);
+		let collection_id = 0;
+		let mint_to = |item, parent_item| {
+			Nfts::mint(
+				RuntimeOrigin::signed(account(1)),
+				collection_id,
+				item,
+				Nfts::item_account(&collection_id, &parent_item),
+				None,
+			)
+		};
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			user_1.clone(),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::mint(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			1,
+			user_1.clone(),
+			None
+		));
+
+		// the account of an item which doesn't exist
+		assert_noop!(mint_to(2, 5), Error::<Test>::UnknownItem);
+
+		// 4 in 3 in 2 in 1 reaches the max depth of 3
+		assert_ok!(mint_to(2, 1));
+		assert!(events().contains(&Event::<Test>::ItemNested {
+			collection: collection_id,
+			item: 2,
+			parent_collection: collection_id,
+			parent_item: 1,
+		}));
+		assert_ok!(mint_to(3, 2));
+		assert_ok!(Nfts::force_mint(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			4,
+			Nfts::item_account(&collection_id, &3),
+			default_item_config()
+		));
+		assert_eq!(Nfts::ancestors(&collection_id, &4), Ok(vec![(0, 3), (0, 2), (0, 1)]));
+		assert_eq!(Nfts::root_owner(&collection_id, &4), Ok(user_1.clone()));
+		assert_eq!(
+			NestingOf::<Test>::get(collection_id, 1).unwrap().children.into_inner(),
+			vec![0, 0, 1]
+		);
+		assert_noop!(mint_to(5, 4), Error::<Test>::NestingTooDeep);
+
+		// minted items are taken out like any other nested item
+		assert_ok!(Nfts::unnest(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			4,
+			user_1.clone()
+		));
+		assert_eq!(Item::<Test>::get(collection_id, 4).unwrap().owner, user_1);
+	});
+}
+
 #[test]
 fn create_cancel_swap_should_work() {
 	new_test_ext().execute_with(|| {
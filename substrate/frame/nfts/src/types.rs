@@ -68,6 +68,17 @@ pub(super) type ItemTipOf<T, I = ()> = ItemTip<
 	<T as SystemConfig>::AccountId,
 	BalanceOf<T, I>,
 >;
+/// A type alias for the royalties due on the sale of an item.
+pub(super) type RoyaltiesOf<T, I = ()> = BoundedVec<
+	RoyaltyRecipient<<T as SystemConfig>::AccountId>,
+	<T as Config<I>>::MaxRoyaltyRecipients,
+>;
+/// A type alias for the nesting details of an item.
+pub(super) type NestingDetailsFor<T, I = ()> = NestingDetails<
+	<T as Config<I>>::CollectionId,
+	<T as Config<I>>::ItemId,
+	BoundedVec<u32, <T as Config<I>>::MaxNestingDepth>,
+>;
 /// A type alias for the settings configuration of a collection.
 pub(super) type CollectionConfigFor<T, I = ()> =
 	CollectionConfig<BalanceOf<T, I>, BlockNumberFor<T>, <T as Config<I>>::CollectionId>;
@@ -250,6 +261,25 @@ pub struct PriceWithDirection<Amount> {
 	pub(super) direction: PriceDirection,
 }
 
+/// An account entitled to a share of the price whenever an item is sold.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct RoyaltyRecipient<AccountId> {
+	/// The account receiving the royalty.
+	pub recipient: AccountId,
+	/// The share of the price paid to the `recipient`.
+	pub share: Perbill,
+}
+
+/// Information about the nesting of an item within other items.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, Default, TypeInfo, MaxEncodedLen)]
+pub struct NestingDetails<CollectionId, ItemId, Heights> {
+	/// The collection and the item which own this item, if it's nested.
+	pub(super) parent: Option<(CollectionId, ItemId)>,
+	/// The number of items nested directly within this item, indexed by how deep their own
+	/// nesting goes.
+	pub(super) children: Heights,
+}
+
 /// Support for up to 64 user-enabled features on a collection.
 #[bitflags]
 #[repr(u64)]
@@ -265,6 +295,9 @@ pub enum CollectionSetting {
 	UnlockedMaxSupply,
 	/// When this isn't set then the deposit is required to hold the items of this collection.
 	DepositRequired,
+	/// The royalties of this collection and its items can be modified. This is disabled for the
+	/// collections created before royalties were supported by [`crate::migration::v2`].
+	UnlockedRoyalties,
 }
 
 /// Wrapper type for `BitFlags<CollectionSetting>` that implements `Codec`.
@@ -90,6 +90,13 @@ pub trait WeightInfo {
 	fn claim_swap() -> Weight;
 	fn mint_pre_signed(n: u32, ) -> Weight;
 	fn set_attributes_pre_signed(n: u32, ) -> Weight;
+	fn poke_deposit() -> Weight;
+	fn set_royalties() -> Weight;
+	fn pay_royalties(n: u32, ) -> Weight;
+	fn nest(d: u32, ) -> Weight;
+	fn unnest(d: u32, ) -> Weight;
+	fn as_item(d: u32, ) -> Weight;
+	fn nest_minted(d: u32, ) -> Weight;
 }
 
 /// Weights for pallet_nfts using the Substrate node and recommended hardware.
@@ -216,8 +223,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
 	/// Storage: Nfts ItemMetadataOf (r:1 w:0)
 	/// Proof: Nfts ItemMetadataOf (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
-	/// Storage: Nfts Account (r:0 w:1)
+	/// Storage: Nfts NestingOf (r:1 w:0)
+	/// Proof: Nfts NestingOf (max_values: None, max_size: Some(70), added: 2545, mode: MaxEncodedLen)
+	/// Storage: Nfts Account (r:1 w:1)
 	/// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	/// Storage: Nfts ItemPriceOf (r:0 w:1)
 	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
 	/// Storage: Nfts ItemAttributesApprovalsOf (r:0 w:1)
@@ -225,12 +236,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: Nfts PendingSwapOf (r:0 w:1)
 	/// Proof: Nfts PendingSwapOf (max_values: None, max_size: Some(71), added: 2546, mode: MaxEncodedLen)
 	fn burn() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `564`
-		//  Estimated: `4326`
-		// Minimum execution time: 50_738_000 picoseconds.
+		// TODO: run benchmarks
 		Weight::from_parts(51_850_000, 4326)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
 			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
 	/// Storage: Nfts Collection (r:1 w:0)
@@ -774,6 +782,136 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
 			.saturating_add(Weight::from_parts(0, 2921).saturating_mul(n.into()))
 	}
+	/// Storage: Nfts Collection (r:1 w:1)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: Nfts Item (r:1 w:1)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemMetadataOf (r:1 w:1)
+	/// Proof: Nfts ItemMetadataOf (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	fn poke_deposit() -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(41_203_000, 4326)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: Nfts Collection (r:1 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: Nfts Item (r:1 w:0)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemRoyaltiesOf (r:0 w:1)
+	/// Proof: Nfts ItemRoyaltiesOf (max_values: None, max_size: Some(371), added: 2846, mode: MaxEncodedLen)
+	fn set_royalties() -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(23_081_000, 4326)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Nfts ItemRoyaltiesOf (r:1 w:0)
+	/// Proof: Nfts ItemRoyaltiesOf (max_values: None, max_size: Some(371), added: 2846, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionRoyaltiesOf (r:1 w:0)
+	/// Proof: Nfts CollectionRoyaltiesOf (max_values: None, max_size: Some(355), added: 2830, mode: MaxEncodedLen)
+	/// Storage: System Account (r:10 w:10)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 10]`.
+	fn pay_royalties(n: u32, ) -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(10_180_337, 3836)
+			.saturating_add(Weight::from_parts(27_654_812, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(n.into()))
+	}
+	/// Storage: Nfts Item (r:2 w:1)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// Storage: Nfts NestingOf (r:6 w:6)
+	/// Proof: Nfts NestingOf (max_values: None, max_size: Some(70), added: 2545, mode: MaxEncodedLen)
+	/// Storage: Nfts Collection (r:1 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Nfts Attribute (r:1 w:0)
+	/// Proof: Nfts Attribute (max_values: None, max_size: Some(479), added: 2954, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemConfigOf (r:1 w:0)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts Account (r:0 w:2)
+	/// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemPriceOf (r:0 w:1)
+	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
+	/// Storage: Nfts PendingSwapOf (r:0 w:1)
+	/// Proof: Nfts PendingSwapOf (max_values: None, max_size: Some(71), added: 2546, mode: MaxEncodedLen)
+	/// The range of component `d` is `[1, 5]`.
+	fn nest(d: u32, ) -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(49_806_112, 4326)
+			.saturating_add(Weight::from_parts(4_862_370, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(d.into())))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 2545).saturating_mul(d.into()))
+	}
+	/// Storage: Nfts NestingOf (r:6 w:6)
+	/// Proof: Nfts NestingOf (max_values: None, max_size: Some(70), added: 2545, mode: MaxEncodedLen)
+	/// Storage: Nfts Item (r:2 w:1)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// Storage: Nfts Collection (r:1 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Nfts Attribute (r:1 w:0)
+	/// Proof: Nfts Attribute (max_values: None, max_size: Some(479), added: 2954, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemConfigOf (r:1 w:0)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts Account (r:0 w:2)
+	/// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemPriceOf (r:0 w:1)
+	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
+	/// Storage: Nfts PendingSwapOf (r:0 w:1)
+	/// Proof: Nfts PendingSwapOf (max_values: None, max_size: Some(71), added: 2546, mode: MaxEncodedLen)
+	/// The range of component `d` is `[1, 5]`.
+	fn unnest(d: u32, ) -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(47_209_590, 4326)
+			.saturating_add(Weight::from_parts(4_858_482, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(d.into())))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 2545).saturating_mul(d.into()))
+	}
+	/// Storage: Nfts NestingOf (r:6 w:0)
+	/// Proof: Nfts NestingOf (max_values: None, max_size: Some(70), added: 2545, mode: MaxEncodedLen)
+	/// Storage: Nfts Item (r:1 w:0)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// The range of component `d` is `[0, 5]`.
+	fn as_item(d: u32, ) -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(11_213_045, 4326)
+			.saturating_add(Weight::from_parts(2_855_191, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 2545).saturating_mul(d.into()))
+	}
+	/// Storage: Nfts Item (r:1 w:0)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// Storage: Nfts NestingOf (r:6 w:6)
+	/// Proof: Nfts NestingOf (max_values: None, max_size: Some(70), added: 2545, mode: MaxEncodedLen)
+	/// The range of component `d` is `[1, 5]`.
+	fn nest_minted(d: u32, ) -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(11_213_045, 4326)
+			.saturating_add(Weight::from_parts(4_862_370, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(d.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 2545).saturating_mul(d.into()))
+	}
 }
 
 // For backwards compatibility and tests
@@ -899,8 +1037,12 @@ impl WeightInfo for () {
 	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
 	/// Storage: Nfts ItemMetadataOf (r:1 w:0)
 	/// Proof: Nfts ItemMetadataOf (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
-	/// Storage: Nfts Account (r:0 w:1)
+	/// Storage: Nfts NestingOf (r:1 w:0)
+	/// Proof: Nfts NestingOf (max_values: None, max_size: Some(70), added: 2545, mode: MaxEncodedLen)
+	/// Storage: Nfts Account (r:1 w:1)
 	/// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	/// Storage: Nfts ItemPriceOf (r:0 w:1)
 	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
 	/// Storage: Nfts ItemAttributesApprovalsOf (r:0 w:1)
@@ -908,12 +1050,9 @@ impl WeightInfo for () {
 	/// Storage: Nfts PendingSwapOf (r:0 w:1)
 	/// Proof: Nfts PendingSwapOf (max_values: None, max_size: Some(71), added: 2546, mode: MaxEncodedLen)
 	fn burn() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `564`
-		//  Estimated: `4326`
-		// Minimum execution time: 50_738_000 picoseconds.
+		// TODO: run benchmarks
 		Weight::from_parts(51_850_000, 4326)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
 			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 	/// Storage: Nfts Collection (r:1 w:0)
@@ -1457,4 +1596,134 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
 			.saturating_add(Weight::from_parts(0, 2921).saturating_mul(n.into()))
 	}
+	/// Storage: Nfts Collection (r:1 w:1)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: Nfts Item (r:1 w:1)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemMetadataOf (r:1 w:1)
+	/// Proof: Nfts ItemMetadataOf (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	fn poke_deposit() -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(41_203_000, 4326)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: Nfts Collection (r:1 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: Nfts Item (r:1 w:0)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemRoyaltiesOf (r:0 w:1)
+	/// Proof: Nfts ItemRoyaltiesOf (max_values: None, max_size: Some(371), added: 2846, mode: MaxEncodedLen)
+	fn set_royalties() -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(23_081_000, 4326)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Nfts ItemRoyaltiesOf (r:1 w:0)
+	/// Proof: Nfts ItemRoyaltiesOf (max_values: None, max_size: Some(371), added: 2846, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionRoyaltiesOf (r:1 w:0)
+	/// Proof: Nfts CollectionRoyaltiesOf (max_values: None, max_size: Some(355), added: 2830, mode: MaxEncodedLen)
+	/// Storage: System Account (r:10 w:10)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 10]`.
+	fn pay_royalties(n: u32, ) -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(10_180_337, 3836)
+			.saturating_add(Weight::from_parts(27_654_812, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(n.into()))
+	}
+	/// Storage: Nfts Item (r:2 w:1)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// Storage: Nfts NestingOf (r:6 w:6)
+	/// Proof: Nfts NestingOf (max_values: None, max_size: Some(70), added: 2545, mode: MaxEncodedLen)
+	/// Storage: Nfts Collection (r:1 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Nfts Attribute (r:1 w:0)
+	/// Proof: Nfts Attribute (max_values: None, max_size: Some(479), added: 2954, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemConfigOf (r:1 w:0)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts Account (r:0 w:2)
+	/// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemPriceOf (r:0 w:1)
+	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
+	/// Storage: Nfts PendingSwapOf (r:0 w:1)
+	/// Proof: Nfts PendingSwapOf (max_values: None, max_size: Some(71), added: 2546, mode: MaxEncodedLen)
+	/// The range of component `d` is `[1, 5]`.
+	fn nest(d: u32, ) -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(49_806_112, 4326)
+			.saturating_add(Weight::from_parts(4_862_370, 0).saturating_mul(d.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(d.into())))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 2545).saturating_mul(d.into()))
+	}
+	/// Storage: Nfts NestingOf (r:6 w:6)
+	/// Proof: Nfts NestingOf (max_values: None, max_size: Some(70), added: 2545, mode: MaxEncodedLen)
+	/// Storage: Nfts Item (r:2 w:1)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// Storage: Nfts Collection (r:1 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Nfts Attribute (r:1 w:0)
+	/// Proof: Nfts Attribute (max_values: None, max_size: Some(479), added: 2954, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemConfigOf (r:1 w:0)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts Account (r:0 w:2)
+	/// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemPriceOf (r:0 w:1)
+	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
+	/// Storage: Nfts PendingSwapOf (r:0 w:1)
+	/// Proof: Nfts PendingSwapOf (max_values: None, max_size: Some(71), added: 2546, mode: MaxEncodedLen)
+	/// The range of component `d` is `[1, 5]`.
+	fn unnest(d: u32, ) -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(47_209_590, 4326)
+			.saturating_add(Weight::from_parts(4_858_482, 0).saturating_mul(d.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(d.into())))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 2545).saturating_mul(d.into()))
+	}
+	/// Storage: Nfts NestingOf (r:6 w:0)
+	/// Proof: Nfts NestingOf (max_values: None, max_size: Some(70), added: 2545, mode: MaxEncodedLen)
+	/// Storage: Nfts Item (r:1 w:0)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// The range of component `d` is `[0, 5]`.
+	fn as_item(d: u32, ) -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(11_213_045, 4326)
+			.saturating_add(Weight::from_parts(2_855_191, 0).saturating_mul(d.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 2545).saturating_mul(d.into()))
+	}
+	/// Storage: Nfts Item (r:1 w:0)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// Storage: Nfts NestingOf (r:6 w:6)
+	/// Proof: Nfts NestingOf (max_values: None, max_size: Some(70), added: 2545, mode: MaxEncodedLen)
+	/// The range of component `d` is `[1, 5]`.
+	fn nest_minted(d: u32, ) -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(11_213_045, 4326)
+			.saturating_add(Weight::from_parts(4_862_370, 0).saturating_mul(d.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(d.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 2545).saturating_mul(d.into()))
+	}
 }
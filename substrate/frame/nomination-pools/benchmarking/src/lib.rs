@@ -34,6 +34,7 @@ use frame_support::{
 };
 use frame_system::RawOrigin as RuntimeOrigin;
 use pallet_nomination_pools::{
+	adapter::{StakeStrategy, StakeStrategyType},
 	BalanceOf, BondExtra, BondedPoolInner, BondedPools, ClaimPermission, ClaimPermissions,
 	Commission, CommissionChangeRate, CommissionClaimPermission, ConfigOp, GlobalMaxCommission,
 	MaxPoolMembers, MaxPoolMembersPerPool, MaxPools, Metadata, MinCreateBond, MinJoinBond,
@@ -41,7 +42,7 @@ use pallet_nomination_pools::{
 };
 use pallet_staking::MaxNominationsOf;
 use sp_runtime::{
-	traits::{Bounded, StaticLookup, Zero},
+	traits::{Bounded, SaturatedConversion, StaticLookup, Zero},
 	Perbill,
 };
 use sp_staking::{EraIndex, StakingInterface};
@@ -844,6 +845,36 @@ frame_benchmarking::benchmarks! {
 		assert!(&Pools::<T>::check_ed_imbalance().is_ok());
 	}
 
+	apply_slash {
+		// a member of a pool that is slashed by half.
+		let min_join_bond = MinJoinBond::<T>::get().max(CurrencyOf::<T>::minimum_balance());
+		let (_, pool_account) = create_pool_account::<T>(0, Pools::<T>::depositor_min_bond() * 2u32.into(), None);
+		let joiner: T::AccountId = create_funded_user_with_balance::<T>("joiner", 0, min_join_bond * 2u32.into());
+		let joiner_lookup = T::Lookup::unlookup(joiner.clone());
+		Pools::<T>::join(RuntimeOrigin::Signed(joiner.clone()).into(), min_join_bond, 1).unwrap();
+
+		let slash: u128 = (T::Staking::active_stake(&pool_account).unwrap() / 2u32.into()).saturated_into();
+		let _ = pallet_staking::slashing::do_slash::<T>(
+			&pool_account,
+			slash.saturated_into(),
+			&mut Default::default(),
+			&mut Default::default(),
+			0,
+		);
+		let delegated = T::StakeAdapter::strategy_type() == StakeStrategyType::Delegate;
+		whitelist_account!(joiner);
+	}: {
+		// with transferred stake, the slash is already applied to the pool and the call fails.
+		let result = Pools::<T>::apply_slash(RuntimeOrigin::Signed(joiner.clone()).into(), joiner_lookup);
+		assert_eq!(result.is_ok(), delegated);
+	}
+	verify {
+		if delegated {
+			let member = PoolMembers::<T>::get(&joiner).unwrap();
+			assert!(Pools::<T>::member_pending_slash(&joiner, &member).is_zero());
+		}
+	}
+
 	impl_benchmark_test_suite!(
 		Pallet,
 		crate::mock::new_test_ext(),
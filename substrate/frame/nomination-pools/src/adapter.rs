@@ -0,0 +1,222 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The strategies a pool can use to stake the funds of its members.
+//!
+//! - [`TransferStake`]: the funds of the members are transferred to the bonded account of the pool,
+//!   which bonds them.
+//! - [`DelegateStake`]: the funds of the members stay in their own accounts, delegated to the
+//!   bonded account of the pool. Slashes of the pool are applied to each member individually, see
+//!   [`Call::apply_slash`](crate::Call::apply_slash).
+
+use crate::*;
+use sp_staking::DelegatedStakingInterface;
+
+/// The way the funds of the members of a pool are staked.
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebugNoBound)]
+pub enum StakeStrategyType {
+	/// The funds are transferred to the pool.
+	Transfer,
+	/// The funds are delegated to the pool.
+	Delegate,
+}
+
+/// An adapter between the pools and the staking system, for the movement of the funds of the
+/// members.
+///
+/// Everything else, such as nominating or unbonding, goes through [`Config::Staking`], which
+/// should be the same staking system as the one used by the strategy.
+pub trait StakeStrategy {
+	/// The balance type of the staking system.
+	type Balance: frame_support::traits::tokens::Balance;
+	/// The account id type of the staking system.
+	type AccountId: Clone + sp_std::fmt::Debug;
+
+	/// The way the funds are staked.
+	fn strategy_type() -> StakeStrategyType;
+
+	/// The balance of the pool that is not actively bonded, and can be paid out to members.
+	fn transferable_balance(pool_account: &Self::AccountId) -> Self::Balance;
+
+	/// The total balance of the pool, bonded or not.
+	fn total_balance(pool_account: &Self::AccountId) -> Self::Balance;
+
+	/// The funds `member` has put into the pools, if they are tracked by the staking system.
+	fn member_delegation_balance(member: &Self::AccountId) -> Option<Self::Balance>;
+
+	/// Put `amount` of the funds of `who` into the pool and bond them.
+	fn pledge_bond(
+		who: &Self::AccountId,
+		pool_account: &Self::AccountId,
+		reward_account: &Self::AccountId,
+		amount: Self::Balance,
+		bond_type: BondType,
+	) -> DispatchResult;
+
+	/// Pay `amount` of the unbonded funds of the pool out to `who`.
+	fn member_withdraw(
+		who: &Self::AccountId,
+		pool_account: &Self::AccountId,
+		amount: Self::Balance,
+	) -> DispatchResult;
+
+	/// The slashes of the pool that have not been applied to its members yet.
+	fn pending_slash(pool_account: &Self::AccountId) -> Self::Balance;
+
+	/// Apply `amount` of the pending slash of the pool to `member`.
+	fn member_slash(
+		pool_account: &Self::AccountId,
+		member: &Self::AccountId,
+		amount: Self::Balance,
+	) -> DispatchResult;
+}
+
+/// Stake the funds of the members by transferring them to the pool.
+///
+/// Slashes are applied to the pool account directly by the staking system.
+pub struct TransferStake<T: Config, Staking: StakingInterface>(
+	sp_std::marker::PhantomData<(T, Staking)>,
+);
+
+impl<T: Config, Staking: StakingInterface<Balance = BalanceOf<T>, AccountId = T::AccountId>>
+	StakeStrategy for TransferStake<T, Staking>
+{
+	type Balance = BalanceOf<T>;
+	type AccountId = T::AccountId;
+
+	fn strategy_type() -> StakeStrategyType {
+		StakeStrategyType::Transfer
+	}
+
+	fn transferable_balance(pool_account: &Self::AccountId) -> Self::Balance {
+		T::Currency::balance(pool_account)
+			.saturating_sub(Staking::active_stake(pool_account).unwrap_or_default())
+	}
+
+	fn total_balance(pool_account: &Self::AccountId) -> Self::Balance {
+		T::Currency::total_balance(pool_account)
+	}
+
+	fn member_delegation_balance(_member: &Self::AccountId) -> Option<Self::Balance> {
+		None
+	}
+
+	fn pledge_bond(
+		who: &Self::AccountId,
+		pool_account: &Self::AccountId,
+		reward_account: &Self::AccountId,
+		amount: Self::Balance,
+		bond_type: BondType,
+	) -> DispatchResult {
+		match bond_type {
+			BondType::Create => {
+				T::Currency::transfer(who, pool_account, amount, Preservation::Expendable)?;
+				Staking::bond(pool_account, amount, reward_account)
+			},
+			BondType::Later => {
+				T::Currency::transfer(who, pool_account, amount, Preservation::Preserve)?;
+				Staking::bond_extra(pool_account, amount)
+			},
+		}
+	}
+
+	fn member_withdraw(
+		who: &Self::AccountId,
+		pool_account: &Self::AccountId,
+		amount: Self::Balance,
+	) -> DispatchResult {
+		T::Currency::transfer(pool_account, who, amount, Preservation::Expendable)?;
+		Ok(())
+	}
+
+	fn pending_slash(_pool_account: &Self::AccountId) -> Self::Balance {
+		// slashes are applied to the pool account right away.
+		Zero::zero()
+	}
+
+	fn member_slash(
+		_pool_account: &Self::AccountId,
+		_member: &Self::AccountId,
+		_amount: Self::Balance,
+	) -> DispatchResult {
+		Err(Error::<T>::NotSupported.into())
+	}
+}
+
+/// Stake the funds of the members by delegating them to the pool.
+///
+/// The funds stay in the accounts of the members, which can still use them, e.g. for voting in
+/// governance.
+pub struct DelegateStake<T: Config, Delegation: DelegatedStakingInterface>(
+	sp_std::marker::PhantomData<(T, Delegation)>,
+);
+
+impl<
+		T: Config,
+		Delegation: DelegatedStakingInterface<Balance = BalanceOf<T>, AccountId = T::AccountId>,
+	> StakeStrategy for DelegateStake<T, Delegation>
+{
+	type Balance = BalanceOf<T>;
+	type AccountId = T::AccountId;
+
+	fn strategy_type() -> StakeStrategyType {
+		StakeStrategyType::Delegate
+	}
+
+	fn transferable_balance(pool_account: &Self::AccountId) -> Self::Balance {
+		Delegation::agent_balance(pool_account)
+			.saturating_sub(Delegation::active_stake(pool_account).unwrap_or_default())
+	}
+
+	fn total_balance(pool_account: &Self::AccountId) -> Self::Balance {
+		Delegation::agent_balance(pool_account)
+	}
+
+	fn member_delegation_balance(member: &Self::AccountId) -> Option<Self::Balance> {
+		Some(Delegation::delegator_balance(member))
+	}
+
+	fn pledge_bond(
+		who: &Self::AccountId,
+		pool_account: &Self::AccountId,
+		reward_account: &Self::AccountId,
+		amount: Self::Balance,
+		_bond_type: BondType,
+	) -> DispatchResult {
+		Delegation::delegate(who, pool_account, reward_account, amount)
+	}
+
+	fn member_withdraw(
+		who: &Self::AccountId,
+		pool_account: &Self::AccountId,
+		amount: Self::Balance,
+	) -> DispatchResult {
+		Delegation::withdraw_delegation(who, pool_account, amount)
+	}
+
+	fn pending_slash(pool_account: &Self::AccountId) -> Self::Balance {
+		Delegation::pending_slash(pool_account)
+	}
+
+	fn member_slash(
+		pool_account: &Self::AccountId,
+		member: &Self::AccountId,
+		amount: Self::Balance,
+	) -> DispatchResult {
+		Delegation::delegator_slash(pool_account, member, amount)
+	}
+}
@@ -397,9 +397,11 @@ pub mod mock;
 #[cfg(test)]
 mod tests;
 
+pub mod adapter;
 pub mod migration;
 pub mod weights;
 
+use adapter::{StakeStrategy, StakeStrategyType};
 pub use pallet::*;
 pub use weights::WeightInfo;
 
@@ -425,7 +427,7 @@ pub enum ConfigOp<T: Codec + Debug> {
 }
 
 /// The type of bonding that can happen to a pool.
-enum BondType {
+pub enum BondType {
 	/// Someone is bonding into the pool upon creation.
 	Create,
 	/// Someone is adding more funds later to this pool.
@@ -537,9 +539,10 @@ impl<T: Config> PoolMember<T> {
 
 	/// Total balance of the member, both active and unbonding.
 	/// Doesn't mutate state.
-	#[cfg(any(feature = "try-runtime", feature = "fuzzing", test, debug_assertions))]
 	fn total_balance(&self) -> BalanceOf<T> {
-		let pool = BondedPool::<T>::get(self.pool_id).unwrap();
+		let Some(pool) = BondedPool::<T>::get(self.pool_id).defensive() else {
+			return Zero::zero()
+		};
 		let active_balance = pool.points_to_balance(self.active_points());
 
 		let sub_pools = match SubPoolsStorage::<T>::get(self.pool_id) {
@@ -553,7 +556,7 @@ impl<T: Config> PoolMember<T> {
 				// if the `SubPools::with_era` has already been merged into the
 				// `SubPools::no_era` use this pool instead.
 				let era_pool = sub_pools.with_era.get(era).unwrap_or(&sub_pools.no_era);
-				accumulator + (era_pool.point_to_balance(*unlocked_points))
+				accumulator.saturating_add(era_pool.point_to_balance(*unlocked_points))
 			},
 		);
 
@@ -1048,8 +1051,7 @@ impl<T: Config> BondedPool<T> {
 		// `pallet-nomination-pool`. This means reducible balance always returns balance preserving
 		// ED in the account. What we want though is transferable balance given the account can be
 		// dusted.
-		T::Currency::balance(&account)
-			.saturating_sub(T::Staking::active_stake(&account).unwrap_or_default())
+		T::StakeAdapter::transferable_balance(&account)
 	}
 
 	fn is_root(&self, who: &T::AccountId) -> bool {
@@ -1235,8 +1237,8 @@ impl<T: Config> BondedPool<T> {
 	/// Bond exactly `amount` from `who`'s funds into this pool. Increases the [`TotalValueLocked`]
 	/// by `amount`.
 	///
-	/// If the bond is [`BondType::Create`], [`Staking::bond`] is called, and `who` is allowed to be
-	/// killed. Otherwise, [`Staking::bond_extra`] is called and `who` cannot be killed.
+	/// The funds are put into the pool by [`Config::StakeAdapter`]. If the funds are transferred,
+	/// `who` is allowed to be killed if the bond is [`BondType::Create`].
 	///
 	/// Returns `Ok(points_issues)`, `Err` otherwise.
 	fn try_bond_funds(
@@ -1245,28 +1247,20 @@ impl<T: Config> BondedPool<T> {
 		amount: BalanceOf<T>,
 		ty: BondType,
 	) -> Result<BalanceOf<T>, DispatchError> {
-		// Cache the value
-		let bonded_account = self.bonded_account();
-		T::Currency::transfer(
-			who,
-			&bonded_account,
-			amount,
-			match ty {
-				BondType::Create => Preservation::Expendable,
-				BondType::Later => Preservation::Preserve,
-			},
-		)?;
 		// We must calculate the points issued *before* we bond who's funds, else points:balance
 		// ratio will be wrong.
 		let points_issued = self.issue(amount);
 
-		match ty {
-			BondType::Create => T::Staking::bond(&bonded_account, amount, &self.reward_account())?,
-			// The pool should always be created in such a way its in a state to bond extra, but if
-			// the active balance is slashed below the minimum bonded or the account cannot be
-			// found, we exit early.
-			BondType::Later => T::Staking::bond_extra(&bonded_account, amount)?,
-		}
+		// The pool should always be created in such a way its in a state to bond extra, but if
+		// the active balance is slashed below the minimum bonded or the account cannot be found,
+		// we exit early.
+		T::StakeAdapter::pledge_bond(
+			who,
+			&self.bonded_account(),
+			&self.reward_account(),
+			amount,
+			ty,
+		)?;
 		TotalValueLocked::<T>::mutate(|tvl| {
 			tvl.saturating_accrue(amount);
 		});
@@ -1657,6 +1651,12 @@ pub mod pallet {
 		/// The interface for nominating.
 		type Staking: StakingInterface<Balance = BalanceOf<Self>, AccountId = Self::AccountId>;
 
+		/// The way the funds of the members are staked, see [`adapter`].
+		///
+		/// Must use the same staking system as [`Config::Staking`]. Changing the strategy of a
+		/// runtime with existing pools requires a migration of their funds.
+		type StakeAdapter: StakeStrategy<Balance = BalanceOf<Self>, AccountId = Self::AccountId>;
+
 		/// The amount of eras a `SubPools::with_era` pool can exist before it gets merged into the
 		/// `SubPools::no_era` pool. In other words, this is the amount of eras a member will be
 		/// able to withdraw from an unbonding pool which is guaranteed to have the correct ratio of
@@ -1879,6 +1879,8 @@ pub mod pallet {
 		MinBalanceDeficitAdjusted { pool_id: PoolId, amount: BalanceOf<T> },
 		/// Claimed excess frozen ED of af the reward pool.
 		MinBalanceExcessAdjusted { pool_id: PoolId, amount: BalanceOf<T> },
+		/// A pending slash of the pool was applied to one of its members.
+		MemberSlashed { pool_id: PoolId, member: T::AccountId, amount: BalanceOf<T> },
 	}
 
 	#[pallet::error]
@@ -1956,6 +1958,10 @@ pub mod pallet {
 		BondExtraRestricted,
 		/// No imbalance in the ED deposit for the pool.
 		NothingToAdjust,
+		/// The operation is not supported by the [`Config::StakeAdapter`].
+		NotSupported,
+		/// The member has no pending slash to apply.
+		NothingToSlash,
 	}
 
 	#[derive(Encode, Decode, PartialEq, TypeInfo, PalletError, RuntimeDebug)]
@@ -2256,6 +2262,9 @@ pub mod pallet {
 
 			bonded_pool.ok_to_withdraw_unbonded_with(&caller, &member_account)?;
 
+			// The pending slash of the member must be applied before any funds are released.
+			Self::do_apply_slash(&member_account, &member)?;
+
 			// NOTE: must do this after we have done the `ok_to_withdraw_unbonded_other_with` check.
 			let withdrawn_points = member.withdraw_unlocked(current_era);
 			ensure!(!withdrawn_points.is_empty(), Error::<T>::CannotWithdrawAny);
@@ -2297,11 +2306,10 @@ pub mod pallet {
 				// order to ensure members can leave the pool and it can be destroyed.
 				.min(bonded_pool.transferable_balance());
 
-			T::Currency::transfer(
-				&bonded_pool.bonded_account(),
+			T::StakeAdapter::member_withdraw(
 				&member_account,
+				&bonded_pool.bonded_account(),
 				balance_to_unbond,
-				Preservation::Expendable,
 			)
 			.defensive()?;
 
@@ -2791,6 +2799,34 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Apply the pending slash of the pool of `member_account` to it.
+		///
+		/// Only pools whose members delegate their funds have pending slashes, see
+		/// [`adapter::DelegateStake`]. A slash of such a pool is applied to each member
+		/// individually, pro rata to its share of the pool.
+		///
+		/// The dispatch origin of this call can be any signed account.
+		#[pallet::call_index(23)]
+		#[pallet::weight(T::WeightInfo::apply_slash())]
+		pub fn apply_slash(
+			origin: OriginFor<T>,
+			member_account: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let _ = ensure_signed(origin)?;
+			ensure!(
+				T::StakeAdapter::strategy_type() == StakeStrategyType::Delegate,
+				Error::<T>::NotSupported
+			);
+
+			let member_account = T::Lookup::lookup(member_account)?;
+			let member =
+				PoolMembers::<T>::get(&member_account).ok_or(Error::<T>::PoolMemberNotFound)?;
+			let slashed = Self::do_apply_slash(&member_account, &member)?;
+			ensure!(!slashed.is_zero(), Error::<T>::NothingToSlash);
+
+			Ok(())
+		}
 	}
 
 	#[pallet::hooks]
@@ -2897,6 +2933,52 @@ impl<T: Config> Pallet<T> {
 		bonded_pool.remove();
 	}
 
+	/// The part of the pending slash of the pool of `member` that applies to it.
+	///
+	/// This is the difference between the funds the member put into the pool and its current
+	/// balance in the pool, capped by the pending slash of the pool.
+	pub fn member_pending_slash(
+		member_account: &T::AccountId,
+		member: &PoolMember<T>,
+	) -> BalanceOf<T> {
+		let pool_account = Self::create_bonded_account(member.pool_id);
+		let pool_pending_slash = T::StakeAdapter::pending_slash(&pool_account);
+		if pool_pending_slash.is_zero() {
+			return Zero::zero()
+		}
+
+		T::StakeAdapter::member_delegation_balance(member_account)
+			.map_or(Zero::zero(), |delegated| {
+				delegated.saturating_sub(member.total_balance()).min(pool_pending_slash)
+			})
+	}
+
+	/// Apply the part of the pending slash of the pool of `member` that applies to it, if any.
+	///
+	/// Returns the slashed amount.
+	fn do_apply_slash(
+		member_account: &T::AccountId,
+		member: &PoolMember<T>,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		let pending_slash = Self::member_pending_slash(member_account, member);
+		if pending_slash.is_zero() {
+			return Ok(Zero::zero())
+		}
+
+		T::StakeAdapter::member_slash(
+			&Self::create_bonded_account(member.pool_id),
+			member_account,
+			pending_slash,
+		)?;
+		Self::deposit_event(Event::<T>::MemberSlashed {
+			pool_id: member.pool_id,
+			member: member_account.clone(),
+			amount: pending_slash,
+		});
+
+		Ok(pending_slash)
+	}
+
 	/// Create the main, bonded account of a pool with the given id.
 	pub fn create_bonded_account(id: PoolId) -> T::AccountId {
 		T::PalletId::get().into_sub_account_truncating((AccountType::Bonded, id))
@@ -3444,7 +3526,7 @@ impl<T: Config> Pallet<T> {
 
 			let sum_unbonding_balance = subs.sum_unbonding_balance();
 			let bonded_balance = T::Staking::active_stake(&pool_account).unwrap_or_default();
-			let total_balance = T::Currency::total_balance(&pool_account);
+			let total_balance = T::StakeAdapter::total_balance(&pool_account);
 
 			assert!(
 				total_balance >= bonded_balance + sum_unbonding_balance,
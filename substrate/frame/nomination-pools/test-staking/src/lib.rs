@@ -27,6 +27,7 @@ use pallet_nomination_pools::{
 };
 use pallet_staking::{CurrentEra, Event as StakingEvent, Payee, RewardDestination};
 use sp_runtime::{bounded_btree_map, traits::Zero};
+use sp_staking::DelegatedStakingInterface;
 
 #[test]
 fn pool_lifecycle_e2e() {
@@ -691,3 +692,103 @@ fn pool_slash_non_proportional_bonded_pool_and_chunks() {
 		);
 	});
 }
+
+#[test]
+fn pool_slash_is_applied_to_delegating_members() {
+	new_test_ext().execute_with(|| {
+		DelegateStakeEnabled::set(true);
+		ExistentialDeposit::set(1);
+		CurrentEra::<Runtime>::set(Some(0));
+
+		// create the pool and have two members join it.
+		assert_ok!(Pools::create(RuntimeOrigin::signed(10), 40, 10, 10, 10));
+		assert_ok!(Pools::join(RuntimeOrigin::signed(20), 20, 1));
+		assert_ok!(Pools::join(RuntimeOrigin::signed(21), 20, 1));
+
+		// the funds stay with the members, delegated to the pool.
+		assert_eq!(Balances::free_balance(POOL1_BONDED), 0);
+		assert_eq!(Balances::free_balance(20), 100);
+		assert_eq!(pallet_staking::Pallet::<Runtime>::delegator_balance(&20), 20);
+		assert_eq!(pallet_staking::Pallet::<Runtime>::agent_balance(&POOL1_BONDED), 80);
+
+		assert_eq!(
+			staking_events_since_last_call(),
+			vec![
+				StakingEvent::Delegated { agent: POOL1_BONDED, delegator: 10, amount: 40 },
+				StakingEvent::Bonded { stash: POOL1_BONDED, amount: 40 },
+				StakingEvent::Delegated { agent: POOL1_BONDED, delegator: 20, amount: 20 },
+				StakingEvent::Bonded { stash: POOL1_BONDED, amount: 20 },
+				StakingEvent::Delegated { agent: POOL1_BONDED, delegator: 21, amount: 20 },
+				StakingEvent::Bonded { stash: POOL1_BONDED, amount: 20 },
+			]
+		);
+		assert_eq!(
+			pool_events_since_last_call(),
+			vec![
+				PoolsEvent::Created { depositor: 10, pool_id: 1 },
+				PoolsEvent::Bonded { member: 10, pool_id: 1, bonded: 40, joined: true },
+				PoolsEvent::Bonded { member: 20, pool_id: 1, bonded: 20, joined: true },
+				PoolsEvent::Bonded { member: 21, pool_id: 1, bonded: 20, joined: true },
+			]
+		);
+
+		// the pool is slashed. Nothing is taken from the members yet.
+		pallet_staking::slashing::do_slash::<Runtime>(
+			&POOL1_BONDED,
+			30,
+			&mut Default::default(),
+			&mut Default::default(),
+			0,
+		);
+		assert_eq!(
+			pool_events_since_last_call(),
+			vec![PoolsEvent::PoolSlashed { pool_id: 1, balance: 50 }]
+		);
+		assert_eq!(pallet_staking::Pallet::<Runtime>::pending_slash(&POOL1_BONDED), 30);
+		assert_eq!(Balances::free_balance(20), 100);
+
+		// 20 owns a quarter of the pool, which is now worth 12 instead of 20.
+		let member = PoolMembers::<Runtime>::get(20).unwrap();
+		assert_eq!(Pools::member_pending_slash(&20, &member), 8);
+
+		// anyone can apply it.
+		assert_ok!(Pools::apply_slash(RuntimeOrigin::signed(10), 20));
+		assert_eq!(
+			pool_events_since_last_call(),
+			vec![PoolsEvent::MemberSlashed { pool_id: 1, member: 20, amount: 8 }]
+		);
+		assert_eq!(
+			staking_events_since_last_call(),
+			vec![StakingEvent::DelegatorSlashed { agent: POOL1_BONDED, delegator: 20, amount: 8 }]
+		);
+		assert_eq!(Balances::free_balance(20), 92);
+		assert_eq!(pallet_staking::Pallet::<Runtime>::delegator_balance(&20), 12);
+		assert_eq!(pallet_staking::Pallet::<Runtime>::pending_slash(&POOL1_BONDED), 22);
+
+		// but only once.
+		assert_noop!(
+			Pools::apply_slash(RuntimeOrigin::signed(10), 20),
+			PoolsError::<Runtime>::NothingToSlash
+		);
+
+		// the slash of 21 is applied when it withdraws.
+		assert_ok!(Pools::unbond(RuntimeOrigin::signed(21), 21, 20));
+		CurrentEra::<Runtime>::set(Some(3));
+		assert_ok!(Pools::withdraw_unbonded(RuntimeOrigin::signed(21), 21, 0));
+		assert_eq!(Balances::free_balance(21), 92);
+		assert_eq!(pallet_staking::Pallet::<Runtime>::delegator_balance(&21), 0);
+		assert_eq!(pallet_staking::Pallet::<Runtime>::pending_slash(&POOL1_BONDED), 14);
+	});
+}
+
+#[test]
+fn apply_slash_is_not_supported_for_transferred_stake() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Pools::create(RuntimeOrigin::signed(10), 40, 10, 10, 10));
+
+		assert_noop!(
+			Pools::apply_slash(RuntimeOrigin::signed(10), 10),
+			PoolsError::<Runtime>::NotSupported
+		);
+	});
+}
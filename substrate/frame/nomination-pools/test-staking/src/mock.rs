@@ -23,9 +23,13 @@ use frame_support::{
 	traits::{ConstU64, ConstU8},
 	PalletId,
 };
+use pallet_nomination_pools::{
+	adapter::{DelegateStake, StakeStrategy, StakeStrategyType, TransferStake},
+	BondType,
+};
 use sp_runtime::{
 	traits::{Convert, IdentityLookup},
-	BuildStorage, FixedU128, Perbill,
+	BuildStorage, DispatchResult, FixedU128, Perbill,
 };
 
 type AccountId = u128;
@@ -126,6 +130,7 @@ impl pallet_staking::Config for Runtime {
 	type EraPayout = pallet_staking::ConvertCurve<RewardCurve>;
 	type NextNewSession = ();
 	type MaxExposurePageSize = ConstU32<64>;
+	type MaxAutoPayoutsPerBlock = MaxAutoPayoutsPerBlock;
 	type OffendingValidatorsThreshold = ();
 	type ElectionProvider =
 		frame_election_provider_support::NoElection<(AccountId, BlockNumber, Staking, ())>;
@@ -171,6 +176,96 @@ impl Convert<sp_core::U256, Balance> for U256ToBalance {
 parameter_types! {
 	pub const PostUnbondingPoolsWindow: u32 = 10;
 	pub const PoolsPalletId: PalletId = PalletId(*b"py/nopls");
+	pub static DelegateStakeEnabled: bool = false;
+}
+
+type Transfer = TransferStake<Runtime, Staking>;
+type Delegate = DelegateStake<Runtime, Staking>;
+
+/// Stakes the funds of pool members either by transfer or by delegation, depending on
+/// [`DelegateStakeEnabled`].
+pub struct MockStakeAdapter;
+impl StakeStrategy for MockStakeAdapter {
+	type Balance = Balance;
+	type AccountId = AccountId;
+
+	fn strategy_type() -> StakeStrategyType {
+		if DelegateStakeEnabled::get() {
+			Delegate::strategy_type()
+		} else {
+			Transfer::strategy_type()
+		}
+	}
+
+	fn transferable_balance(pool_account: &AccountId) -> Balance {
+		if DelegateStakeEnabled::get() {
+			Delegate::transferable_balance(pool_account)
+		} else {
+			Transfer::transferable_balance(pool_account)
+		}
+	}
+
+	fn total_balance(pool_account: &AccountId) -> Balance {
+		if DelegateStakeEnabled::get() {
+			Delegate::total_balance(pool_account)
+		} else {
+			Transfer::total_balance(pool_account)
+		}
+	}
+
+	fn member_delegation_balance(member: &AccountId) -> Option<Balance> {
+		if DelegateStakeEnabled::get() {
+			Delegate::member_delegation_balance(member)
+		} else {
+			Transfer::member_delegation_balance(member)
+		}
+	}
+
+	fn pledge_bond(
+		who: &AccountId,
+		pool_account: &AccountId,
+		reward_account: &AccountId,
+		amount: Balance,
+		bond_type: BondType,
+	) -> DispatchResult {
+		if DelegateStakeEnabled::get() {
+			Delegate::pledge_bond(who, pool_account, reward_account, amount, bond_type)
+		} else {
+			Transfer::pledge_bond(who, pool_account, reward_account, amount, bond_type)
+		}
+	}
+
+	fn member_withdraw(
+		who: &AccountId,
+		pool_account: &AccountId,
+		amount: Balance,
+	) -> DispatchResult {
+		if DelegateStakeEnabled::get() {
+			Delegate::member_withdraw(who, pool_account, amount)
+		} else {
+			Transfer::member_withdraw(who, pool_account, amount)
+		}
+	}
+
+	fn pending_slash(pool_account: &AccountId) -> Balance {
+		if DelegateStakeEnabled::get() {
+			Delegate::pending_slash(pool_account)
+		} else {
+			Transfer::pending_slash(pool_account)
+		}
+	}
+
+	fn member_slash(
+		pool_account: &AccountId,
+		member: &AccountId,
+		amount: Balance,
+	) -> DispatchResult {
+		if DelegateStakeEnabled::get() {
+			Delegate::member_slash(pool_account, member, amount)
+		} else {
+			Transfer::member_slash(pool_account, member, amount)
+		}
+	}
 }
 
 impl pallet_nomination_pools::Config for Runtime {
@@ -182,6 +277,7 @@ impl pallet_nomination_pools::Config for Runtime {
 	type BalanceToU256 = BalanceToU256;
 	type U256ToBalance = U256ToBalance;
 	type Staking = Staking;
+	type StakeAdapter = MockStakeAdapter;
 	type PostUnbondingPoolsWindow = PostUnbondingPoolsWindow;
 	type MaxMetadataLen = ConstU32<256>;
 	type MaxUnbonding = ConstU32<8>;
@@ -215,6 +215,15 @@ benchmarks! {
 		assert_eq!(RequestStatusFor::<T>::get(&hash), Some(s));
 	}
 
+	poke_deposit {
+		let caller = funded_account::<T>();
+		let (preimage, hash) = preimage_and_hash::<T>();
+		assert_ok!(Preimage::<T>::note_preimage(RawOrigin::Signed(caller.clone()).into(), preimage));
+	}: _(RawOrigin::Signed(caller), hash)
+	verify {
+		assert!(Preimage::<T>::have_preimage(&hash));
+	}
+
 	ensure_updated {
 		let n in 1..MAX_HASH_UPGRADE_BULK_COUNT;
 
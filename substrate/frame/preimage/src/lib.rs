@@ -49,7 +49,7 @@ use frame_support::{
 	ensure,
 	pallet_prelude::Get,
 	traits::{
-		Consideration, Currency, Defensive, FetchResult, Footprint, PreimageProvider,
+		Consideration, Currency, Defensive, FetchResult, Footprint, PokeDeposit, PreimageProvider,
 		PreimageRecipient, QueryPreimage, ReservableCurrency, StorePreimage,
 	},
 	BoundedSlice, BoundedVec,
@@ -138,6 +138,8 @@ pub mod pallet {
 		Requested { hash: T::Hash },
 		/// A preimage has ben cleared.
 		Cleared { hash: T::Hash },
+		/// The deposit held for a preimage has been updated to the current requirement.
+		DepositPoked { who: T::AccountId, hash: T::Hash },
 	}
 
 	#[pallet::error]
@@ -158,6 +160,8 @@ pub mod pallet {
 		TooMany,
 		/// Too few hashes were requested to be upgraded (i.e. zero).
 		TooFew,
+		/// The preimage does not have a deposit held for it.
+		NoDeposit,
 	}
 
 	/// A reason for this pallet placing a hold on funds.
@@ -253,10 +257,38 @@ pub mod pallet {
 			let pays: Pays = (ratio < Perbill::from_percent(90)).into();
 			Ok(pays.into())
 		}
+
+		/// Re-adjust the deposit held for a preimage noted by the caller to the current
+		/// requirement.
+		///
+		/// The caller pays no fee if the deposit was updated.
+		///
+		/// - `hash`: The hash of the preimage whose deposit should be updated.
+		#[pallet::call_index(5)]
+		pub fn poke_deposit(origin: OriginFor<T>, hash: T::Hash) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let changed = <Self as PokeDeposit<_>>::poke_deposit(&who, hash)?;
+			Ok(if changed { Pays::No } else { Pays::Yes }.into())
+		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
+	/// Update `ticket` held by `owner` for a preimage of `len` bytes to the current requirement,
+	/// returning the new ticket and whether it changed.
+	fn poke_ticket(
+		who: &T::AccountId,
+		owner: &T::AccountId,
+		ticket: TicketOf<T>,
+		len: u32,
+	) -> Result<(TicketOf<T>, bool), DispatchError> {
+		ensure!(owner == who, Error::<T>::NotAuthorized);
+		let old_ticket = ticket.encode();
+		let ticket = ticket.update(owner, Footprint::from_parts(1, len as usize))?;
+		let changed = ticket.encode() != old_ticket;
+		Ok((ticket, changed))
+	}
+
 	fn do_ensure_updated(h: &T::Hash) -> bool {
 		#[allow(deprecated)]
 		let r = match StatusFor::<T>::take(h) {
@@ -491,6 +523,36 @@ impl<T: Config> Pallet<T> {
 	}
 }
 
+impl<T: Config> PokeDeposit<T::AccountId> for Pallet<T> {
+	type Key = T::Hash;
+
+	fn poke_deposit(who: &T::AccountId, hash: T::Hash) -> Result<bool, DispatchError> {
+		Self::do_ensure_updated(&hash);
+		let status = RequestStatusFor::<T>::get(hash).ok_or(Error::<T>::NotNoted)?;
+		let (status, changed) = match status {
+			RequestStatus::Unrequested { ticket: (owner, ticket), len } => {
+				let (ticket, changed) = Self::poke_ticket(who, &owner, ticket, len)?;
+				(RequestStatus::Unrequested { ticket: (owner, ticket), len }, changed)
+			},
+			RequestStatus::Requested {
+				maybe_ticket: Some((owner, ticket)),
+				count,
+				maybe_len: Some(len),
+			} => {
+				let (ticket, changed) = Self::poke_ticket(who, &owner, ticket, len)?;
+				let maybe_ticket = Some((owner, ticket));
+				(RequestStatus::Requested { maybe_ticket, count, maybe_len: Some(len) }, changed)
+			},
+			RequestStatus::Requested { .. } => return Err(Error::<T>::NoDeposit.into()),
+		};
+		RequestStatusFor::<T>::insert(hash, status);
+		if changed {
+			Self::deposit_event(Event::DepositPoked { who: who.clone(), hash });
+		}
+		Ok(changed)
+	}
+}
+
 impl<T: Config> PreimageProvider<T::Hash> for Pallet<T> {
 	fn have_preimage(hash: &T::Hash) -> bool {
 		Self::have(hash)
@@ -21,7 +21,7 @@ use super::*;
 
 use crate as pallet_preimage;
 use frame_support::{
-	derive_impl, ord_parameter_types,
+	derive_impl, ord_parameter_types, parameter_types,
 	traits::{fungible::HoldConsideration, ConstU32, ConstU64, Everything},
 	weights::constants::RocksDbWeight,
 };
@@ -91,10 +91,14 @@ ord_parameter_types! {
 	pub const One: u64 = 1;
 }
 
+parameter_types! {
+	pub static ByteDeposit: u64 = 1;
+}
+
 pub struct ConvertDeposit;
 impl Convert<Footprint, u64> for ConvertDeposit {
 	fn convert(a: Footprint) -> u64 {
-		a.count * 2 + a.size
+		a.count * 2 + a.size * ByteDeposit::get()
 	}
 }
 
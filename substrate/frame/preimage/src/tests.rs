@@ -116,6 +116,48 @@ fn user_unnote_preimage_works() {
 	});
 }
 
+#[test]
+fn poke_deposit_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Preimage::note_preimage(RuntimeOrigin::signed(2), vec![1]));
+		assert_eq!(Balances::balance_on_hold(&(), &2), 3);
+
+		// Nothing to update while the requirement stays the same.
+		let res = Preimage::poke_deposit(RuntimeOrigin::signed(2), hashed([1])).unwrap();
+		assert_eq!(res.pays_fee, Pays::Yes);
+		assert_eq!(Balances::balance_on_hold(&(), &2), 3);
+
+		assert_noop!(
+			Preimage::poke_deposit(RuntimeOrigin::signed(3), hashed([1])),
+			Error::<Test>::NotAuthorized
+		);
+		assert_noop!(
+			Preimage::poke_deposit(RuntimeOrigin::signed(2), hashed([2])),
+			Error::<Test>::NotNoted
+		);
+
+		ByteDeposit::set(3);
+		let res = Preimage::poke_deposit(RuntimeOrigin::signed(2), hashed([1])).unwrap();
+		assert_eq!(res.pays_fee, Pays::No);
+		assert_eq!(Balances::balance_on_hold(&(), &2), 5);
+		System::assert_last_event(Event::<Test>::DepositPoked { who: 2, hash: hashed([1]) }.into());
+
+		// A requested preimage keeps its deposit until it is unnoted, so it can still be poked.
+		assert_ok!(Preimage::request_preimage(RuntimeOrigin::signed(1), hashed([1])));
+		ByteDeposit::set(1);
+		assert_ok!(Preimage::poke_deposit(RuntimeOrigin::signed(2), hashed([1])));
+		assert_eq!(Balances::balance_on_hold(&(), &2), 3);
+
+		// Preimages noted by the manager hold no deposit.
+		assert_ok!(Preimage::note_preimage(RuntimeOrigin::signed(1), vec![2]));
+		assert_noop!(
+			Preimage::poke_deposit(RuntimeOrigin::signed(1), hashed([2])),
+			Error::<Test>::NoDeposit
+		);
+	});
+}
+
 #[test]
 fn manager_unnote_preimage_works() {
 	new_test_ext().execute_with(|| {
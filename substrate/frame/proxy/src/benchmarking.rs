@@ -260,5 +260,26 @@ benchmarks! {
 		assert!(!Proxies::<T>::contains_key(&pure_account));
 	}
 
+	poke_deposit {
+		let caller: T::AccountId = whitelisted_caller();
+		add_proxies::<T>(T::MaxProxies::get(), Some(caller.clone()))?;
+		add_announcements::<T>(T::MaxPending::get(), Some(caller.clone()), None)?;
+		// Pretend that both deposits were taken before the deposit parameters were raised.
+		let (_, proxy_deposit) = Proxies::<T>::get(&caller);
+		let (_, announcement_deposit) = Announcements::<T>::get(&caller);
+		Proxies::<T>::mutate(&caller, |(_, deposit)| *deposit = Zero::zero());
+		Announcements::<T>::mutate(&caller, |(_, deposit)| *deposit = Zero::zero());
+		T::Currency::unreserve(&caller, proxy_deposit + announcement_deposit);
+	}: _(RawOrigin::Signed(caller.clone()))
+	verify {
+		assert_eq!(Proxies::<T>::get(&caller).1, proxy_deposit);
+		assert_last_event::<T>(Event::DepositPoked {
+			who: caller,
+			kind: DepositKind::Announcements,
+			old_deposit: Zero::zero(),
+			new_deposit: announcement_deposit,
+		}.into());
+	}
+
 	impl_benchmark_test_suite!(Proxy, crate::tests::new_test_ext(), crate::tests::Test);
 }
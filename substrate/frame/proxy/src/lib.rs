@@ -37,7 +37,11 @@ use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::{
 	dispatch::GetDispatchInfo,
 	ensure,
-	traits::{Currency, Get, InstanceFilter, IsSubType, IsType, OriginTrait, ReservableCurrency},
+	traits::{
+		Currency, Get, InstanceFilter, IsSubType, IsType, OriginTrait, PokeDeposit,
+		ReservableCurrency,
+	},
+	BoundedVec,
 };
 use frame_system::{self as system, ensure_signed, pallet_prelude::BlockNumberFor};
 pub use pallet::*;
@@ -93,6 +97,15 @@ pub struct Announcement<AccountId, Hash, BlockNumber> {
 	height: BlockNumber,
 }
 
+/// The kind of deposit held by an account.
+#[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum DepositKind {
+	/// The deposit held for the proxies of an account.
+	Proxies,
+	/// The deposit held for the pending announcements of a proxy.
+	Announcements,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::{DispatchResult, *};
@@ -512,6 +525,24 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Poke the deposits held for the proxies and announcements of the sender.
+		///
+		/// The deposits are recalculated according to the current deposit parameters. Any
+		/// increase is reserved from the sender and any excess is returned to them.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// The transaction fee is waived if any deposit was changed.
+		///
+		/// Emits `DepositPoked` for every deposit that was changed.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::poke_deposit())]
+		pub fn poke_deposit(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let changed = <Self as PokeDeposit<_>>::poke_deposit(&who, ())?;
+			Ok(if changed { Pays::No } else { Pays::Yes }.into())
+		}
 	}
 
 	#[pallet::event]
@@ -543,6 +574,13 @@ pub mod pallet {
 			proxy_type: T::ProxyType,
 			delay: BlockNumberFor<T>,
 		},
+		/// A deposit held by an account was updated.
+		DepositPoked {
+			who: T::AccountId,
+			kind: DepositKind,
+			old_deposit: BalanceOf<T>,
+			new_deposit: BalanceOf<T>,
+		},
 	}
 
 	#[pallet::error]
@@ -735,6 +773,34 @@ impl<T: Config> Pallet<T> {
 		Ok(if len == 0 { None } else { Some(new_deposit) })
 	}
 
+	/// Recalculate the deposit held in `entry`, a list of items together with their deposit, and
+	/// update it in place.
+	///
+	/// Returns `None` if there is no entry and otherwise whether the deposit has changed.
+	fn poke_deposit_of<Item, MaxItems: Get<u32>>(
+		who: &T::AccountId,
+		kind: DepositKind,
+		entry: &mut Option<(BoundedVec<Item, MaxItems>, BalanceOf<T>)>,
+		base: BalanceOf<T>,
+		factor: BalanceOf<T>,
+	) -> Result<Option<bool>, DispatchError> {
+		let Some((items, old_deposit)) = entry.take() else { return Ok(None) };
+		let new_deposit = Self::rejig_deposit(who, old_deposit, base, factor, items.len())?;
+		*entry = new_deposit.map(|deposit| (items, deposit));
+
+		let new_deposit = new_deposit.unwrap_or_else(Zero::zero);
+		if new_deposit == old_deposit {
+			return Ok(Some(false))
+		}
+		Self::deposit_event(Event::<T>::DepositPoked {
+			who: who.clone(),
+			kind,
+			old_deposit,
+			new_deposit,
+		});
+		Ok(Some(true))
+	}
+
 	fn edit_announcements<
 		F: FnMut(&Announcement<T::AccountId, CallHashOf<T>, BlockNumberFor<T>>) -> bool,
 	>(
@@ -808,3 +874,30 @@ impl<T: Config> Pallet<T> {
 		T::Currency::unreserve(&delegator, old_deposit);
 	}
 }
+
+impl<T: Config> PokeDeposit<T::AccountId> for Pallet<T> {
+	type Key = ();
+
+	fn poke_deposit(who: &T::AccountId, _: ()) -> Result<bool, DispatchError> {
+		let proxies = Proxies::<T>::try_mutate_exists(who, |entry| {
+			Self::poke_deposit_of(
+				who,
+				DepositKind::Proxies,
+				entry,
+				T::ProxyDepositBase::get(),
+				T::ProxyDepositFactor::get(),
+			)
+		})?;
+		let announcements = Announcements::<T>::try_mutate_exists(who, |entry| {
+			Self::poke_deposit_of(
+				who,
+				DepositKind::Announcements,
+				entry,
+				T::AnnouncementDepositBase::get(),
+				T::AnnouncementDepositFactor::get(),
+			)
+		})?;
+		ensure!(proxies.is_some() || announcements.is_some(), Error::<T>::NotFound);
+		Ok(proxies.unwrap_or(false) || announcements.unwrap_or(false))
+	}
+}
@@ -25,6 +25,7 @@ use crate as proxy;
 use codec::{Decode, Encode};
 use frame_support::{
 	assert_noop, assert_ok, derive_impl,
+	dispatch::Pays,
 	traits::{ConstU32, ConstU64, Contains},
 };
 use sp_core::H256;
@@ -590,3 +591,56 @@ fn pure_works() {
 		);
 	});
 }
+
+#[test]
+fn poke_deposit_works() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(Proxy::poke_deposit(RuntimeOrigin::signed(1)), Error::<Test>::NotFound);
+
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 2, ProxyType::Any, 0));
+		assert_eq!(Balances::reserved_balance(1), 2);
+
+		// Nothing changed, so the fee is paid.
+		let post_info = Proxy::poke_deposit(RuntimeOrigin::signed(1)).unwrap();
+		assert_eq!(post_info.pays_fee, Pays::Yes);
+		assert_eq!(Balances::reserved_balance(1), 2);
+
+		// The proxy deposit was taken under higher deposit parameters; the excess is returned.
+		Proxies::<Test>::mutate(1, |(_, deposit)| *deposit = 5);
+		assert_ok!(Balances::reserve(&1, 3));
+		let post_info = Proxy::poke_deposit(RuntimeOrigin::signed(1)).unwrap();
+		assert_eq!(post_info.pays_fee, Pays::No);
+		System::assert_last_event(
+			ProxyEvent::DepositPoked {
+				who: 1,
+				kind: DepositKind::Proxies,
+				old_deposit: 5,
+				new_deposit: 2,
+			}
+			.into(),
+		);
+		assert_eq!(Proxies::<Test>::get(1).1, 2);
+		assert_eq!(Balances::reserved_balance(1), 2);
+
+		// The announcement deposit was taken under lower deposit parameters; the difference is
+		// reserved.
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(3), 1, ProxyType::Any, 0));
+		assert_ok!(Proxy::announce(RuntimeOrigin::signed(1), 3, [1; 32].into()));
+		assert_eq!(Balances::reserved_balance(1), 4);
+		Announcements::<Test>::mutate(1, |(_, deposit)| *deposit = 0);
+		Balances::unreserve(&1, 2);
+		let post_info = Proxy::poke_deposit(RuntimeOrigin::signed(1)).unwrap();
+		assert_eq!(post_info.pays_fee, Pays::No);
+		System::assert_last_event(
+			ProxyEvent::DepositPoked {
+				who: 1,
+				kind: DepositKind::Announcements,
+				old_deposit: 0,
+				new_deposit: 2,
+			}
+			.into(),
+		);
+		assert_eq!(Announcements::<Test>::get(1).1, 2);
+		assert_eq!(Balances::reserved_balance(1), 4);
+	});
+}
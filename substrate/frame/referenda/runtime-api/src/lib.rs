@@ -0,0 +1,40 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the referenda pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use pallet_referenda::{ReferendumIndex, ReferendumPreview};
+
+sp_api::decl_runtime_apis! {
+	/// Runtime api for previewing referenda.
+	pub trait ReferendaApi<TrackId, BlockNumber, Hash>
+		where
+			TrackId: Codec,
+			BlockNumber: Codec,
+			Hash: Codec,
+	{
+		/// Returns a preview of the ongoing referendum `index`: its decoded proposal call tree,
+		/// its track's curves evaluated at the current block and the projected blocks at which
+		/// it meets them and would be confirmed.
+		fn referendum_preview(
+			index: ReferendumIndex,
+		) -> Option<ReferendumPreview<TrackId, BlockNumber, Hash>>;
+	}
+}
@@ -64,7 +64,7 @@
 #![recursion_limit = "256"]
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use codec::{Codec, Encode};
+use codec::{Codec, Decode, Encode};
 use frame_support::{
 	dispatch::DispatchResult,
 	ensure,
@@ -73,8 +73,8 @@ use frame_support::{
 			v3::{Anon as ScheduleAnon, Named as ScheduleNamed},
 			DispatchTime,
 		},
-		Currency, LockIdentifier, OnUnbalanced, OriginTrait, PollStatus, Polling, QueryPreimage,
-		ReservableCurrency, StorePreimage, VoteTally,
+		CallMetadata, Currency, GetCallMetadata, LockIdentifier, OnUnbalanced, OriginTrait,
+		PollStatus, Polling, QueryPreimage, ReservableCurrency, StorePreimage, VoteTally,
 	},
 	BoundedVec,
 };
@@ -95,9 +95,10 @@ use self::branch::{BeginDecidingBranch, OneFewerDecidingBranch, ServiceBranch};
 pub use self::{
 	pallet::*,
 	types::{
-		BalanceOf, BoundedCallOf, CallOf, Curve, DecidingStatus, DecidingStatusOf, Deposit,
-		InsertSorted, NegativeImbalanceOf, PalletsOriginOf, ReferendumIndex, ReferendumInfo,
-		ReferendumInfoOf, ReferendumStatus, ReferendumStatusOf, ScheduleAddressOf, TallyOf,
+		BalanceOf, BoundedCallOf, CallOf, CallPreview, CallPreviewOf, CallWrappers, Curve,
+		DecidingStatus, DecidingStatusOf, Deposit, InnerCall, InsertSorted, NegativeImbalanceOf,
+		PalletsOriginOf, ReferendumIndex, ReferendumInfo, ReferendumInfoOf, ReferendumPreview,
+		ReferendumPreviewOf, ReferendumStatus, ReferendumStatusOf, ScheduleAddressOf, TallyOf,
 		TrackIdOf, TrackInfo, TrackInfoOf, TracksInfo, VotesOf,
 	},
 	weights::WeightInfo,
@@ -137,6 +138,12 @@ macro_rules! impl_tracksinfo_get {
 
 const ASSEMBLY_ID: LockIdentifier = *b"assembly";
 
+/// The depth of wrapped calls up to which [`Pallet::referendum_preview`] decodes a proposal.
+pub const MAX_PREVIEW_DEPTH: u32 = 8;
+
+/// The number of calls up to which [`Pallet::referendum_preview`] decodes a proposal.
+pub const MAX_PREVIEW_CALLS: u32 = 128;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -865,6 +872,107 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		}
 	}
 
+	/// Return a preview of the ongoing referendum `index`, or `None` if it is not ongoing.
+	///
+	/// The proposal is decoded into a call tree, following the calls exposed by `W` and fetching
+	/// their preimages as needed. The track's curves are evaluated at the current block, and the
+	/// blocks at which the current tally meets them are projected.
+	pub fn referendum_preview<W: CallWrappers<CallOf<T, I>, T::Hash>>(
+		index: ReferendumIndex,
+	) -> Option<ReferendumPreviewOf<T, I>>
+	where
+		CallOf<T, I>: GetCallMetadata,
+	{
+		let status = Self::ensure_ongoing(index).ok()?;
+		let track = Self::track(status.track)?;
+		let now = frame_system::Pallet::<T>::block_number();
+
+		let proposal = match T::Preimages::peek(&status.proposal) {
+			Ok((call, _)) => Self::preview_call::<W>(call, 0, &mut 0),
+			Err(DispatchError::Corruption) =>
+				CallPreview::Undecodable { hash: status.proposal.hash() },
+			Err(_) => CallPreview::Unavailable { hash: status.proposal.hash() },
+		};
+
+		let (deciding_since, elapsed) = match status.deciding {
+			Some(ref deciding) => (deciding.since, now.saturating_sub(deciding.since)),
+			None => (now.max(status.submitted.saturating_add(track.prepare_period)), Zero::zero()),
+		};
+		let x = Perbill::from_rational(elapsed.min(track.decision_period), track.decision_period);
+		let approval = status.tally.approval(status.track);
+		let support = status.tally.support(status.track);
+
+		let met_at = |curve: &Curve, y: Perbill| {
+			curve.passing(Perbill::one(), y).then(|| {
+				deciding_since.saturating_add(curve.delay(y).mul_ceil(track.decision_period))
+			})
+		};
+		let approval_met_at = met_at(&track.min_approval, approval);
+		let support_met_at = met_at(&track.min_support, support);
+		let confirmed_at = match status.deciding.and_then(|d| d.confirming) {
+			Some(confirming) => Some(confirming),
+			None => approval_met_at
+				.zip(support_met_at)
+				.map(|(a, s)| a.max(s).max(now).saturating_add(track.confirm_period)),
+		};
+
+		Some(ReferendumPreview {
+			track: status.track,
+			proposal,
+			metadata: MetadataOf::<T, I>::get(index),
+			approval,
+			support,
+			min_approval: track.min_approval.threshold(x),
+			min_support: track.min_support.threshold(x),
+			deciding_since,
+			approval_met_at,
+			support_met_at,
+			confirmed_at,
+		})
+	}
+
+	/// Decode `call` and the calls it wraps, `depth` levels deep into a proposal of which `calls`
+	/// have been decoded so far.
+	fn preview_call<W: CallWrappers<CallOf<T, I>, T::Hash>>(
+		call: CallOf<T, I>,
+		depth: u32,
+		calls: &mut u32,
+	) -> CallPreviewOf<T>
+	where
+		CallOf<T, I>: GetCallMetadata,
+	{
+		if depth > MAX_PREVIEW_DEPTH {
+			return CallPreview::TooDeep
+		}
+		calls.saturating_inc();
+		let inner = W::inner_calls(&call)
+			.into_iter()
+			.map(|inner| {
+				// Checked before fetching any preimage.
+				if *calls >= MAX_PREVIEW_CALLS {
+					return CallPreview::TooMany
+				}
+				match inner {
+					InnerCall::Inline(call) => Self::preview_call::<W>(call, depth + 1, calls),
+					InnerCall::Lookup { hash, len } => match T::Preimages::fetch(&hash, len) {
+						Ok(data) => match CallOf::<T, I>::decode(&mut &data[..]) {
+							Ok(call) => Self::preview_call::<W>(call, depth + 1, calls),
+							Err(_) => CallPreview::Undecodable { hash },
+						},
+						Err(_) => CallPreview::Unavailable { hash },
+					},
+				}
+			})
+			.collect();
+		let CallMetadata { pallet_name, function_name } = call.get_call_metadata();
+		CallPreview::Call {
+			pallet: pallet_name.as_bytes().to_vec(),
+			function: function_name.as_bytes().to_vec(),
+			encoded: call.encode(),
+			inner,
+		}
+	}
+
 	// Enqueue a proposal from a referendum which has presumably passed.
 	fn schedule_enactment(
 		index: ReferendumIndex,
@@ -666,3 +666,189 @@ fn clear_metadata_works() {
 		}));
 	});
 }
+
+#[test]
+fn referendum_preview_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		let index = Failing.create();
+		let since = deciding_since(index);
+		run_to(since + 2);
+		set_tally(index, 50, 50);
+
+		let preview = Referenda::referendum_preview::<()>(index).unwrap();
+		assert_eq!(
+			preview.proposal,
+			CallPreview::Call {
+				pallet: b"Balances".to_vec(),
+				function: b"force_set_balance".to_vec(),
+				encoded: set_balance_proposal(1),
+				inner: vec![],
+			}
+		);
+		assert_eq!(preview.track, 0);
+		assert_eq!(preview.metadata, None);
+		assert_eq!(preview.approval, Perbill::from_percent(50));
+		assert_eq!(preview.support, Perbill::from_percent(50));
+		// Half-way through the decision period.
+		assert_eq!(preview.min_approval, Perbill::from_percent(75));
+		assert_eq!(preview.min_support, Perbill::from_percent(50));
+		assert_eq!(preview.deciding_since, since);
+		assert_eq!(preview.approval_met_at, Some(since + 4));
+		assert_eq!(preview.support_met_at, Some(since + 2));
+		assert_eq!(preview.confirmed_at, Some(since + 6));
+
+		// Approval never meets the curve.
+		set_tally(index, 40, 60);
+		let preview = Referenda::referendum_preview::<()>(index).unwrap();
+		assert_eq!(preview.approval_met_at, None);
+		assert_eq!(preview.support_met_at, Some(since + 3));
+		assert_eq!(preview.confirmed_at, None);
+
+		// Finished referenda have no preview.
+		assert_ok!(Referenda::kill(RuntimeOrigin::root(), index));
+		assert_eq!(Referenda::referendum_preview::<()>(index), None);
+	});
+}
+
+#[test]
+fn referendum_preview_follows_wrapped_calls() {
+	use sp_core::H256;
+	use sp_runtime::traits::{BlakeTwo256, Hash};
+
+	// Follows scheduled calls, and treats `request_preimage` as a stand-in for a call which
+	// refers to another call by the hash of its preimage.
+	struct TestWrappers;
+	impl CallWrappers<RuntimeCall, H256> for TestWrappers {
+		fn inner_calls(call: &RuntimeCall) -> Vec<InnerCall<RuntimeCall, H256>> {
+			match call {
+				RuntimeCall::Scheduler(pallet_scheduler::Call::schedule { call, .. }) =>
+					vec![InnerCall::Inline((**call).clone())],
+				RuntimeCall::Preimage(pallet_preimage::Call::request_preimage { hash }) =>
+					vec![InnerCall::Lookup { hash: *hash, len: None }],
+				_ => vec![],
+			}
+		}
+	}
+
+	ExtBuilder::default().build_and_execute(|| {
+		let set_balance = set_balance_proposal(2);
+		assert_ok!(Preimage::note_preimage(RuntimeOrigin::signed(1), set_balance.clone()));
+		let set_balance_hash = BlakeTwo256::hash(&set_balance);
+		let missing_hash = H256::repeat_byte(1);
+		let undecodable = note_preimage(1);
+
+		let schedule = |hash| {
+			RuntimeCall::Scheduler(pallet_scheduler::Call::schedule {
+				when: 10,
+				maybe_periodic: None,
+				priority: 0,
+				call: Box::new(RuntimeCall::Preimage(pallet_preimage::Call::request_preimage {
+					hash,
+				})),
+			})
+		};
+		let request =
+			|hash| RuntimeCall::Preimage(pallet_preimage::Call::request_preimage { hash }).encode();
+		for hash in [set_balance_hash, missing_hash, undecodable] {
+			assert_ok!(Referenda::submit(
+				RuntimeOrigin::signed(1),
+				Box::new(RawOrigin::Root.into()),
+				<Preimage as StorePreimage>::bound(schedule(hash)).unwrap(),
+				DispatchTime::At(10),
+			));
+		}
+
+		let preview = |index, inner| {
+			assert_eq!(
+				Referenda::referendum_preview::<TestWrappers>(index).unwrap().proposal,
+				CallPreview::Call {
+					pallet: b"Scheduler".to_vec(),
+					function: b"schedule".to_vec(),
+					encoded: schedule(match index {
+						0 => set_balance_hash,
+						1 => missing_hash,
+						_ => undecodable,
+					})
+					.encode(),
+					inner: vec![inner],
+				}
+			);
+		};
+		preview(
+			0,
+			CallPreview::Call {
+				pallet: b"Preimage".to_vec(),
+				function: b"request_preimage".to_vec(),
+				encoded: request(set_balance_hash),
+				inner: vec![CallPreview::Call {
+					pallet: b"Balances".to_vec(),
+					function: b"force_set_balance".to_vec(),
+					encoded: set_balance,
+					inner: vec![],
+				}],
+			},
+		);
+		preview(
+			1,
+			CallPreview::Call {
+				pallet: b"Preimage".to_vec(),
+				function: b"request_preimage".to_vec(),
+				encoded: request(missing_hash),
+				inner: vec![CallPreview::Unavailable { hash: missing_hash }],
+			},
+		);
+		preview(
+			2,
+			CallPreview::Call {
+				pallet: b"Preimage".to_vec(),
+				function: b"request_preimage".to_vec(),
+				encoded: request(undecodable),
+				inner: vec![CallPreview::Undecodable { hash: undecodable }],
+			},
+		);
+	});
+}
+
+#[test]
+fn referendum_preview_decodes_a_bounded_number_of_calls() {
+	use sp_core::H256;
+
+	// A remark wraps as many empty remarks as it has bytes.
+	struct TestWrappers;
+	impl CallWrappers<RuntimeCall, H256> for TestWrappers {
+		fn inner_calls(call: &RuntimeCall) -> Vec<InnerCall<RuntimeCall, H256>> {
+			let RuntimeCall::System(frame_system::Call::remark { remark }) = call else {
+				return vec![]
+			};
+			let empty = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+			remark.iter().map(|_| InnerCall::Inline(empty.clone())).collect()
+		}
+	}
+
+	ExtBuilder::default().build_and_execute(|| {
+		let remark =
+			|len| RuntimeCall::System(frame_system::Call::remark { remark: vec![0; len as usize] });
+		assert_ok!(Referenda::submit(
+			RuntimeOrigin::signed(1),
+			Box::new(RawOrigin::Root.into()),
+			<Preimage as StorePreimage>::bound(remark(MAX_PREVIEW_CALLS)).unwrap(),
+			DispatchTime::At(10),
+		));
+
+		let CallPreview::Call { inner, .. } =
+			Referenda::referendum_preview::<TestWrappers>(0).unwrap().proposal
+		else {
+			panic!("the proposal is decoded")
+		};
+		let empty_remark = CallPreview::Call {
+			pallet: b"System".to_vec(),
+			function: b"remark".to_vec(),
+			encoded: remark(0).encode(),
+			inner: vec![],
+		};
+		// The proposal itself is one of the calls.
+		let mut expected = vec![empty_remark; MAX_PREVIEW_CALLS as usize - 1];
+		expected.push(CallPreview::TooMany);
+		assert_eq!(inner, expected);
+	});
+}
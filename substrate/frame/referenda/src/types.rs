@@ -70,6 +70,10 @@ pub type ScheduleAddressOf<T, I> = <<T as Config<I>>::Scheduler as Anon<
 	PalletsOriginOf<T>,
 >>::Address;
 
+pub type CallPreviewOf<T> = CallPreview<<T as frame_system::Config>::Hash>;
+pub type ReferendumPreviewOf<T, I> =
+	ReferendumPreview<TrackIdOf<T, I>, BlockNumberFor<T>, <T as frame_system::Config>::Hash>;
+
 /// A referendum index.
 pub type ReferendumIndex = u32;
 
@@ -269,6 +273,85 @@ impl<
 	}
 }
 
+/// A call wrapped by another call, as exposed by [`CallWrappers`].
+#[derive(Clone, PartialEq, Eq, RuntimeDebug)]
+pub enum InnerCall<Call, Hash> {
+	/// The wrapped call is given inline.
+	Inline(Call),
+	/// The wrapped call is referred to by the hash of its preimage, and possibly its length.
+	Lookup { hash: Hash, len: Option<u32> },
+}
+
+/// Something which exposes the calls wrapped by a call, such as the calls of a batch, the task
+/// of a scheduling call or the call of a whitelisted dispatch.
+///
+/// Used by [`Pallet::referendum_preview`] to decode a proposal into a call tree.
+pub trait CallWrappers<Call, Hash> {
+	/// Return the calls wrapped by `call`, in order. Empty if `call` wraps no calls.
+	fn inner_calls(call: &Call) -> Vec<InnerCall<Call, Hash>>;
+}
+
+impl<Call, Hash> CallWrappers<Call, Hash> for () {
+	fn inner_calls(_: &Call) -> Vec<InnerCall<Call, Hash>> {
+		Vec::new()
+	}
+}
+
+/// A node of the call tree of a referendum proposal.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum CallPreview<Hash> {
+	/// A decoded call.
+	Call {
+		/// The name of the pallet of the call.
+		pallet: Vec<u8>,
+		/// The name of the call within its pallet.
+		function: Vec<u8>,
+		/// The SCALE encoded call.
+		encoded: Vec<u8>,
+		/// The calls wrapped by this call.
+		inner: Vec<CallPreview<Hash>>,
+	},
+	/// A call whose preimage is not available.
+	Unavailable { hash: Hash },
+	/// A call whose preimage cannot be decoded.
+	Undecodable { hash: Hash },
+	/// A call nested deeper than [`MAX_PREVIEW_DEPTH`], which is not decoded.
+	TooDeep,
+	/// A call past the first [`MAX_PREVIEW_CALLS`] of the proposal, which is not decoded.
+	TooMany,
+}
+
+/// A preview of an ongoing referendum, as returned by [`Pallet::referendum_preview`].
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ReferendumPreview<TrackId, Moment, Hash> {
+	/// The track of the referendum.
+	pub track: TrackId,
+	/// The call tree of the proposal.
+	pub proposal: CallPreview<Hash>,
+	/// The hash of the metadata preimage, if any.
+	pub metadata: Option<Hash>,
+	/// The current approval of the referendum.
+	pub approval: Perbill,
+	/// The current support of the referendum.
+	pub support: Perbill,
+	/// The approval currently needed to pass, according to the track's `min_approval` curve.
+	pub min_approval: Perbill,
+	/// The support currently needed to pass, according to the track's `min_support` curve.
+	pub min_support: Perbill,
+	/// When the referendum began being decided or, if it is not yet being decided, the earliest
+	/// block at which it may begin.
+	pub deciding_since: Moment,
+	/// The block at which the current approval meets the `min_approval` curve, or `None` if it
+	/// does not within the decision period.
+	pub approval_met_at: Option<Moment>,
+	/// The block at which the current support meets the `min_support` curve, or `None` if it
+	/// does not within the decision period.
+	pub support_met_at: Option<Moment>,
+	/// The block at which the referendum would be confirmed given the current tally, or `None`
+	/// if it would not be.
+	pub confirmed_at: Option<Moment>,
+}
+
 /// Type for describing a curve over the 2-dimensional space of axes between 0-1, as represented
 /// by `(Perbill, Perbill)`.
 #[derive(Clone, Eq, PartialEq, Encode, Decode, TypeInfo, MaxEncodedLen)]
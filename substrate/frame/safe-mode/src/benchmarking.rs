@@ -234,6 +234,59 @@ mod benchmarks {
 		Ok(())
 	}
 
+	/// Exempting a call with the maximum number of exemptions.
+	#[benchmark]
+	fn exempt() -> Result<(), BenchmarkError> {
+		let origin =
+			T::ExemptionOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let full_name = call_name::<T>();
+		for i in 1..T::MaxExemptionsPerCall::get() {
+			SafeMode::<T>::do_exempt(full_name.clone(), args_prefix::<T>(i))
+				.map_err(DispatchError::from)?;
+		}
+		let prefix = args_prefix::<T>(0);
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, full_name.clone(), prefix.clone());
+
+		assert!(Exemptions::<T>::get(full_name).contains(&prefix));
+		Ok(())
+	}
+
+	/// Revoking the last of the maximum number of exemptions of a call.
+	#[benchmark]
+	fn revoke_exemption() -> Result<(), BenchmarkError> {
+		let origin =
+			T::ExemptionOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let full_name = call_name::<T>();
+		for i in 0..T::MaxExemptionsPerCall::get() {
+			SafeMode::<T>::do_exempt(full_name.clone(), args_prefix::<T>(i))
+				.map_err(DispatchError::from)?;
+		}
+		let prefix = args_prefix::<T>(T::MaxExemptionsPerCall::get().saturating_sub(1));
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, full_name.clone(), prefix.clone());
+
+		assert!(!Exemptions::<T>::get(full_name).contains(&prefix));
+		Ok(())
+	}
+
+	/// Longest possible call name.
+	fn call_name<T: Config>() -> CallNameOf<T> {
+		let max_len = T::MaxNameLen::get() as usize;
+		(vec![1; max_len].try_into().unwrap(), vec![1; max_len].try_into().unwrap())
+	}
+
+	/// Longest possible arguments prefix, distinguished by its first bytes.
+	fn args_prefix<T: Config>(index: u32) -> ArgsPrefixOf<T> {
+		let max_len = T::MaxArgsPrefixLen::get() as usize;
+		let mut prefix = index.encode();
+		prefix.resize(max_len.max(prefix.len()), 1);
+		prefix.truncate(max_len);
+		prefix.try_into().unwrap()
+	}
+
 	fn init_bal<T: Config>() -> BalanceOf<T> {
 		BalanceOf::<T>::max_value() / 10u32.into()
 	}
@@ -41,6 +41,8 @@
 //! - Origin configuration items are separated for privileged entering and exiting safe mode.
 //! - A configurable duration sets the number of blocks after which the system will exit safe mode.
 //! - Safe mode may be extended beyond the configured exit by additional calls.
+//! - Governance can exempt calls with some arguments from the safe-mode, e.g. the transfers of one
+//!   specific asset, without changing the `WhitelistedCalls` of the runtime.
 //!
 //! ### Example
 //!
@@ -70,6 +72,17 @@
 //! A storage value (`EnteredUntil`) is used to store the block safe mode will be exited on.
 //! Using the call filter will require a db read of that storage on the first extrinsic.
 //! The storage will be added to the overlay and incur low cost for all additional calls.
+//!
+//! While the safe-mode is entered, the exemptions of calls which are not whitelisted are read
+//! from a storage map (`Exemptions`). Only calls with exemptions are SCALE encoded to match their
+//! arguments.
+//!
+//! ### Exemptions
+//!
+//! An exemption allows a call while the safe-mode is entered if its SCALE encoded arguments start
+//! with the exemption's prefix. The call filter of the runtime does not know the origin of a call,
+//! so exemptions apply to all callers. Calls can be paused for some kinds of callers only with the
+//! pause rules of the tx-pause pallet.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![deny(rustdoc::broken_intra_doc_links)]
@@ -104,6 +117,13 @@ pub use weights::*;
 type BalanceOf<T> =
 	<<T as Config>::Currency as fungible::Inspect<<T as frame_system::Config>::AccountId>>::Balance;
 
+/// The stringy names of a pallet and a call in it from [`GetCallMetadata`].
+pub type CallNameOf<T> =
+	(BoundedVec<u8, <T as Config>::MaxNameLen>, BoundedVec<u8, <T as Config>::MaxNameLen>);
+
+/// The SCALE encoded prefix of the arguments of the calls allowed by an exemption.
+pub type ArgsPrefixOf<T> = BoundedVec<u8, <T as Config>::MaxArgsPrefixLen>;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -182,6 +202,21 @@ pub mod pallet {
 		#[pallet::constant]
 		type ReleaseDelay: Get<Option<BlockNumberFor<Self>>>;
 
+		/// The origin that may exempt calls from the safe-mode and revoke exemptions.
+		type ExemptionOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Maximum length of the pallet and call names of an exempted call.
+		#[pallet::constant]
+		type MaxNameLen: Get<u32>;
+
+		/// Maximum number of exemptions per call.
+		#[pallet::constant]
+		type MaxExemptionsPerCall: Get<u32>;
+
+		/// Maximum length of the encoded arguments prefix of an exemption.
+		#[pallet::constant]
+		type MaxArgsPrefixLen: Get<u32>;
+
 		// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -208,6 +243,15 @@ pub mod pallet {
 
 		/// An error from the underlying `Currency`.
 		CurrencyError,
+
+		/// The call is already exempted with this arguments prefix.
+		AlreadyExempted,
+
+		/// The call is not exempted with this arguments prefix.
+		NotExempted,
+
+		/// The call has too many exemptions.
+		TooManyExemptions,
 	}
 
 	#[pallet::event]
@@ -240,6 +284,12 @@ pub mod pallet {
 		///
 		/// This error comes from the underlying `Currency`.
 		CannotRelease,
+
+		/// Calls with the given arguments prefix are allowed while the safe-mode is entered.
+		CallExempted { full_name: CallNameOf<T>, args_prefix: ArgsPrefixOf<T> },
+
+		/// An exemption of a call was revoked.
+		ExemptionRevoked { full_name: CallNameOf<T>, args_prefix: ArgsPrefixOf<T> },
 	}
 
 	/// The reason why the safe-mode was deactivated.
@@ -275,6 +325,17 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// The arguments prefixes of the calls which are allowed while the safe-mode is entered, in
+	/// addition to the [`Config::WhitelistedCalls`].
+	#[pallet::storage]
+	pub type Exemptions<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		CallNameOf<T>,
+		BoundedVec<ArgsPrefixOf<T>, T::MaxExemptionsPerCall>,
+		ValueQuery,
+	>;
+
 	/// Configure the initial state of this pallet in the genesis block.
 	#[pallet::genesis_config]
 	#[derive(DefaultNoBound)]
@@ -446,6 +507,45 @@ pub mod pallet {
 
 			Self::do_release(true, account, block).map_err(Into::into)
 		}
+
+		/// Allow a call while the safe-mode is entered if its SCALE encoded arguments start with
+		/// `args_prefix`.
+		///
+		/// An empty prefix allows the call with any arguments.
+		/// Emits an [`Event::CallExempted`] event on success.
+		/// Errors with [`Error::AlreadyExempted`] if the call is already exempted with this
+		/// prefix.
+		///
+		/// Can only be called by the [`Config::ExemptionOrigin`] origin.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::exempt())]
+		pub fn exempt(
+			origin: OriginFor<T>,
+			full_name: CallNameOf<T>,
+			args_prefix: ArgsPrefixOf<T>,
+		) -> DispatchResult {
+			T::ExemptionOrigin::ensure_origin(origin)?;
+
+			Self::do_exempt(full_name, args_prefix).map_err(Into::into)
+		}
+
+		/// Revoke an exemption of a call.
+		///
+		/// Emits an [`Event::ExemptionRevoked`] event on success.
+		/// Errors with [`Error::NotExempted`] if the call is not exempted with this prefix.
+		///
+		/// Can only be called by the [`Config::ExemptionOrigin`] origin.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::revoke_exemption())]
+		pub fn revoke_exemption(
+			origin: OriginFor<T>,
+			full_name: CallNameOf<T>,
+			args_prefix: ArgsPrefixOf<T>,
+		) -> DispatchResult {
+			T::ExemptionOrigin::ensure_origin(origin)?;
+
+			Self::do_revoke_exemption(full_name, args_prefix).map_err(Into::into)
+		}
 	}
 
 	#[pallet::hooks]
@@ -580,6 +680,40 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	pub(crate) fn do_exempt(
+		full_name: CallNameOf<T>,
+		args_prefix: ArgsPrefixOf<T>,
+	) -> Result<(), Error<T>> {
+		Exemptions::<T>::try_mutate(&full_name, |prefixes| {
+			ensure!(!prefixes.contains(&args_prefix), Error::<T>::AlreadyExempted);
+			prefixes
+				.try_push(args_prefix.clone())
+				.map_err(|_| Error::<T>::TooManyExemptions)
+		})?;
+		Self::deposit_event(Event::<T>::CallExempted { full_name, args_prefix });
+
+		Ok(())
+	}
+
+	pub(crate) fn do_revoke_exemption(
+		full_name: CallNameOf<T>,
+		args_prefix: ArgsPrefixOf<T>,
+	) -> Result<(), Error<T>> {
+		Exemptions::<T>::try_mutate_exists(&full_name, |maybe_prefixes| {
+			let prefixes = maybe_prefixes.as_mut().ok_or(Error::<T>::NotExempted)?;
+			let index =
+				prefixes.iter().position(|p| p == &args_prefix).ok_or(Error::<T>::NotExempted)?;
+			prefixes.remove(index);
+			if prefixes.is_empty() {
+				*maybe_prefixes = None;
+			}
+			Ok::<_, Error<T>>(())
+		})?;
+		Self::deposit_event(Event::<T>::ExemptionRevoked { full_name, args_prefix });
+
+		Ok(())
+	}
+
 	/// Return whether `safe-mode` is entered.
 	pub fn is_entered() -> bool {
 		EnteredUntil::<T>::exists()
@@ -590,18 +724,38 @@ impl<T: Config> Pallet<T> {
 	where
 		T::RuntimeCall: GetCallMetadata,
 	{
-		let CallMetadata { pallet_name, .. } = call.get_call_metadata();
+		let CallMetadata { pallet_name, function_name } = call.get_call_metadata();
 		// SAFETY: The `SafeMode` pallet is always allowed.
 		if pallet_name == <Pallet<T> as PalletInfoAccess>::name() {
 			return true
 		}
 
 		if Self::is_entered() {
-			T::WhitelistedCalls::contains(call)
+			T::WhitelistedCalls::contains(call) ||
+				Self::is_exempted(call, pallet_name, function_name)
 		} else {
 			true
 		}
 	}
+
+	/// Return whether the given call is allowed by an exemption.
+	fn is_exempted(call: &T::RuntimeCall, pallet_name: &str, function_name: &str) -> bool {
+		let pallet = BoundedVec::try_from(pallet_name.as_bytes().to_vec());
+		let function = BoundedVec::try_from(function_name.as_bytes().to_vec());
+		let full_name = match (pallet, function) {
+			(Ok(pallet), Ok(function)) => (pallet, function),
+			_ => return false,
+		};
+		let prefixes = Exemptions::<T>::get(&full_name);
+		if prefixes.is_empty() {
+			return false
+		}
+
+		// The first two bytes are the indices of the pallet and the call.
+		let encoded = call.encode();
+		let args = encoded.get(2..).unwrap_or_default();
+		prefixes.iter().any(|prefix| args.starts_with(prefix))
+	}
 }
 
 impl<T: Config> Contains<T::RuntimeCall> for Pallet<T>
@@ -202,6 +202,7 @@ impl SafeModeNotify for MockedNotify {
 frame_support::ord_parameter_types! {
 	pub const ForceExitOrigin: u64 = 100;
 	pub const ForceDepositOrigin: u64 = 200;
+	pub const ExemptionOrigin: u64 = 300;
 }
 
 impl Config for Test {
@@ -219,6 +220,10 @@ impl Config for Test {
 	type ForceDepositOrigin = EnsureSignedBy<ForceDepositOrigin, Self::AccountId>;
 	type ReleaseDelay = ReleaseDelay;
 	type Notify = MockedNotify;
+	type ExemptionOrigin = EnsureSignedBy<ExemptionOrigin, Self::AccountId>;
+	type MaxNameLen = ConstU32<50>;
+	type MaxExemptionsPerCall = ConstU32<2>;
+	type MaxArgsPrefixLen = ConstU32<32>;
 	type WeightInfo = ();
 }
 
@@ -148,6 +148,74 @@ fn can_filter_balance_calls_when_activated() {
 	});
 }
 
+#[test]
+fn can_exempt_calls_by_arguments() {
+	new_test_ext().execute_with(|| {
+		let full_name = call_name(b"Balances", b"transfer_allow_death");
+		let prefix: ArgsPrefixOf<Test> = 1u64.encode().try_into().unwrap();
+		assert_ok!(SafeMode::exempt(
+			RuntimeOrigin::signed(mock::ExemptionOrigin::get()),
+			full_name.clone(),
+			prefix.clone(),
+		));
+		System::assert_last_event(
+			Event::CallExempted { full_name: full_name.clone(), args_prefix: prefix.clone() }
+				.into(),
+		);
+		assert_ok!(SafeMode::enter(RuntimeOrigin::signed(0)));
+
+		// Only transfers to `1` are allowed.
+		assert_ok!(call_transfer().dispatch(RuntimeOrigin::signed(0)));
+		assert_err!(
+			RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death {
+				dest: 2,
+				value: 1
+			})
+			.dispatch(RuntimeOrigin::signed(0)),
+			frame_system::Error::<Test>::CallFiltered
+		);
+
+		assert_ok!(SafeMode::revoke_exemption(
+			RuntimeOrigin::signed(mock::ExemptionOrigin::get()),
+			full_name.clone(),
+			prefix,
+		));
+		assert!(!Exemptions::<Test>::contains_key(&full_name));
+		assert_err!(
+			call_transfer().dispatch(RuntimeOrigin::signed(0)),
+			frame_system::Error::<Test>::CallFiltered
+		);
+	});
+}
+
+#[test]
+fn fails_to_add_invalid_exemptions() {
+	new_test_ext().execute_with(|| {
+		let full_name = call_name(b"Balances", b"transfer_allow_death");
+		let prefix = |dest: u64| -> ArgsPrefixOf<Test> { dest.encode().try_into().unwrap() };
+		let origin = RuntimeOrigin::signed(mock::ExemptionOrigin::get());
+
+		assert_noop!(
+			SafeMode::exempt(signed(0), full_name.clone(), prefix(1)),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(SafeMode::exempt(origin.clone(), full_name.clone(), prefix(1)));
+		assert_noop!(
+			SafeMode::exempt(origin.clone(), full_name.clone(), prefix(1)),
+			Error::<Test>::AlreadyExempted
+		);
+		assert_ok!(SafeMode::exempt(origin.clone(), full_name.clone(), prefix(2)));
+		assert_noop!(
+			SafeMode::exempt(origin.clone(), full_name.clone(), prefix(3)),
+			Error::<Test>::TooManyExemptions
+		);
+		assert_noop!(
+			SafeMode::revoke_exemption(origin, full_name, prefix(3)),
+			Error::<Test>::NotExempted
+		);
+	});
+}
+
 #[test]
 fn can_filter_balance_in_batch_when_activated() {
 	new_test_ext().execute_with(|| {
@@ -590,3 +658,7 @@ fn call_transfer() -> RuntimeCall {
 fn signed(who: u64) -> RuntimeOrigin {
 	RuntimeOrigin::signed(who)
 }
+
+fn call_name(pallet_name: &[u8], call_name: &[u8]) -> CallNameOf<Test> {
+	(pallet_name.to_vec().try_into().unwrap(), call_name.to_vec().try_into().unwrap())
+}
@@ -59,6 +59,8 @@ pub trait WeightInfo {
 	fn release_deposit() -> Weight;
 	fn force_release_deposit() -> Weight;
 	fn force_slash_deposit() -> Weight;
+	fn exempt() -> Weight;
+	fn revoke_exemption() -> Weight;
 }
 
 /// Weights for `pallet_safe_mode` using the Substrate node and recommended hardware.
@@ -189,6 +191,22 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	/// Storage: `SafeMode::Exemptions` (r:1 w:1)
+	/// Proof: `SafeMode::Exemptions` (`max_values`: None, `max_size`: Some(1573), added: 4048, mode: `MaxEncodedLen`)
+	fn exempt() -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(22_514_000, 5038)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `SafeMode::Exemptions` (r:1 w:1)
+	/// Proof: `SafeMode::Exemptions` (`max_values`: None, `max_size`: Some(1573), added: 4048, mode: `MaxEncodedLen`)
+	fn revoke_exemption() -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(21_742_000, 5038)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -318,4 +336,20 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	/// Storage: `SafeMode::Exemptions` (r:1 w:1)
+	/// Proof: `SafeMode::Exemptions` (`max_values`: None, `max_size`: Some(1573), added: 4048, mode: `MaxEncodedLen`)
+	fn exempt() -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(22_514_000, 5038)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `SafeMode::Exemptions` (r:1 w:1)
+	/// Proof: `SafeMode::Exemptions` (`max_values`: None, `max_size`: Some(1573), added: 4048, mode: `MaxEncodedLen`)
+	fn revoke_exemption() -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(21_742_000, 5038)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
@@ -107,7 +107,7 @@ mod benchmarks {
 		System::<T>::set_block_number(System::<T>::block_number() + T::RegistrationPeriod::get());
 
 		let salary = T::Salary::get_salary(T::Members::rank_of(&caller).unwrap(), &caller);
-		T::Paymaster::ensure_successful(&caller, (), salary);
+		T::Paymaster::ensure_successful(&caller, T::DefaultAsset::get(), salary);
 
 		#[extrinsic_call]
 		_(RawOrigin::Signed(caller.clone()));
@@ -134,7 +134,7 @@ mod benchmarks {
 
 		let salary = T::Salary::get_salary(T::Members::rank_of(&caller).unwrap(), &caller);
 		let recipient: T::AccountId = account("recipient", 0, SEED);
-		T::Paymaster::ensure_successful(&recipient, (), salary);
+		T::Paymaster::ensure_successful(&recipient, T::DefaultAsset::get(), salary);
 
 		#[extrinsic_call]
 		_(RawOrigin::Signed(caller.clone()), recipient.clone());
@@ -161,7 +161,7 @@ mod benchmarks {
 
 		let salary = T::Salary::get_salary(T::Members::rank_of(&caller).unwrap(), &caller);
 		let recipient: T::AccountId = account("recipient", 0, SEED);
-		T::Paymaster::ensure_successful(&recipient, (), salary);
+		T::Paymaster::ensure_successful(&recipient, T::DefaultAsset::get(), salary);
 		Salary::<T, I>::payout(RawOrigin::Signed(caller.clone()).into()).unwrap();
 		let id = match Claimant::<T, I>::get(&caller).unwrap().status {
 			Attempted { id, .. } => id,
@@ -175,6 +175,19 @@ mod benchmarks {
 		assert!(!matches!(Claimant::<T, I>::get(&caller).unwrap().status, Attempted { .. }));
 	}
 
+	#[benchmark]
+	fn set_payout_asset() {
+		let caller = whitelisted_caller();
+		ensure_member_with_salary::<T, I>(&caller);
+		Salary::<T, I>::init(RawOrigin::Signed(caller.clone()).into()).unwrap();
+		Salary::<T, I>::induct(RawOrigin::Signed(caller.clone()).into()).unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), None);
+
+		assert!(Salary::<T, I>::payout_asset(&caller).is_none());
+	}
+
 	impl_benchmark_test_suite! {
 		Salary,
 		crate::tests::new_test_ext(),
@@ -30,8 +30,8 @@ use frame_support::{
 	dispatch::DispatchResultWithPostInfo,
 	ensure,
 	traits::{
-		tokens::{GetSalary, Pay, PaymentStatus},
-		RankedMembers,
+		tokens::{ConversionToAssetBalance, GetSalary, Pay, PaymentStatus},
+		Contains, RankedMembers,
 	},
 };
 
@@ -103,9 +103,30 @@ pub mod pallet {
 		type RuntimeEvent: From<Event<Self, I>>
 			+ IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
+		/// The type for the kinds of asset in which payments can be made.
+		type AssetKind: Parameter + MaxEncodedLen;
+
 		/// Means by which we can make payments to accounts. This also defines the currency and the
 		/// balance which we use to denote that currency.
-		type Paymaster: Pay<Beneficiary = <Self as frame_system::Config>::AccountId, AssetKind = ()>;
+		type Paymaster: Pay<
+			Beneficiary = <Self as frame_system::Config>::AccountId,
+			AssetKind = Self::AssetKind,
+		>;
+
+		/// The asset in which salaries and the budget are denominated and in which payments are
+		/// made unless a member chose another payout asset.
+		type DefaultAsset: Get<Self::AssetKind>;
+
+		/// The assets which members may choose to be paid in instead of the `DefaultAsset`.
+		type PayoutAssets: Contains<Self::AssetKind>;
+
+		/// Type for converting an amount of the `DefaultAsset` into the equivalent amount of a
+		/// chosen payout asset.
+		type BalanceConverter: ConversionToAssetBalance<
+			BalanceOf<Self, I>,
+			Self::AssetKind,
+			BalanceOf<Self, I>,
+		>;
 
 		/// The current membership of payees.
 		type Members: RankedMembers<AccountId = <Self as frame_system::Config>::AccountId>;
@@ -157,6 +178,11 @@ pub mod pallet {
 	pub(super) type Claimant<T: Config<I>, I: 'static = ()> =
 		StorageMap<_, Twox64Concat, T::AccountId, ClaimantStatusOf<T, I>, OptionQuery>;
 
+	/// The asset a claimant chose to be paid in, if not the `DefaultAsset`.
+	#[pallet::storage]
+	pub(super) type PayoutAsset<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, T::AccountId, T::AssetKind, OptionQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config<I>, I: 'static = ()> {
@@ -173,6 +199,8 @@ pub mod pallet {
 		},
 		/// The next cycle begins.
 		CycleStarted { index: CycleIndexOf<T> },
+		/// A member changed the asset in which they are paid; `None` means the `DefaultAsset`.
+		PayoutAssetSet { who: T::AccountId, asset_kind: Option<T::AssetKind> },
 	}
 
 	#[pallet::error]
@@ -205,6 +233,10 @@ pub mod pallet {
 		Inconclusive,
 		/// The cycle is after that in which the payment was made.
 		NotCurrent,
+		/// The asset is not among the allowed payout assets.
+		AssetNotAllowed,
+		/// The payout could not be converted into the chosen asset.
+		ConversionFailed,
 	}
 
 	#[pallet::call]
@@ -378,6 +410,37 @@ pub mod pallet {
 
 			Ok(Pays::No.into())
 		}
+
+		/// Choose the asset in which future payouts are made.
+		///
+		/// The salary is converted from the `DefaultAsset` into the chosen asset at the time of
+		/// payout. Should the asset no longer be allowed by then, the payout is made in the
+		/// `DefaultAsset`.
+		///
+		/// - `origin`: A `Signed` origin of an inducted account.
+		/// - `asset_kind`: The asset to be paid in, or `None` for the `DefaultAsset`; must be one
+		///   of the `PayoutAssets`.
+		#[pallet::weight(T::WeightInfo::set_payout_asset())]
+		#[pallet::call_index(7)]
+		pub fn set_payout_asset(
+			origin: OriginFor<T>,
+			asset_kind: Option<Box<T::AssetKind>>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			ensure!(Claimant::<T, I>::contains_key(&who), Error::<T, I>::NotInducted);
+
+			let asset_kind = asset_kind.map(|a| *a).filter(|a| *a != T::DefaultAsset::get());
+			match asset_kind {
+				Some(ref asset_kind) => {
+					ensure!(T::PayoutAssets::contains(asset_kind), Error::<T, I>::AssetNotAllowed);
+					PayoutAsset::<T, I>::insert(&who, asset_kind);
+				},
+				None => PayoutAsset::<T, I>::remove(&who),
+			}
+
+			Self::deposit_event(Event::<T, I>::PayoutAssetSet { who, asset_kind });
+			Ok(Pays::No.into())
+		}
 	}
 
 	impl<T: Config<I>, I: 'static> Pallet<T, I> {
@@ -390,6 +453,9 @@ pub mod pallet {
 		pub fn cycle_period() -> BlockNumberFor<T> {
 			T::RegistrationPeriod::get() + T::PayoutPeriod::get()
 		}
+		pub fn payout_asset(who: &T::AccountId) -> Option<T::AssetKind> {
+			PayoutAsset::<T, I>::get(who)
+		}
 		fn do_payout(who: T::AccountId, beneficiary: T::AccountId) -> DispatchResult {
 			let mut status = Status::<T, I>::get().ok_or(Error::<T, I>::NotStarted)?;
 			let mut claimant = Claimant::<T, I>::get(&who).ok_or(Error::<T, I>::NotInducted)?;
@@ -434,8 +500,16 @@ pub mod pallet {
 
 			claimant.last_active = status.cycle_index;
 
-			let id =
-				T::Paymaster::pay(&beneficiary, (), payout).map_err(|_| Error::<T, I>::PayError)?;
+			let (asset_kind, amount) = match PayoutAsset::<T, I>::get(&who) {
+				Some(asset_kind) if T::PayoutAssets::contains(&asset_kind) => {
+					let amount = T::BalanceConverter::to_asset_balance(payout, asset_kind.clone())
+						.map_err(|_| Error::<T, I>::ConversionFailed)?;
+					(asset_kind, amount)
+				},
+				_ => (T::DefaultAsset::get(), payout),
+			};
+			let id = T::Paymaster::pay(&beneficiary, asset_kind, amount)
+				.map_err(|_| Error::<T, I>::PayError)?;
 
 			claimant.status = Attempted { registered, id, amount: payout };
 
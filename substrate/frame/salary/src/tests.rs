@@ -23,7 +23,7 @@ use frame_support::{
 	assert_noop, assert_ok, derive_impl,
 	pallet_prelude::Weight,
 	parameter_types,
-	traits::{tokens::ConvertRank, ConstU32, ConstU64, Everything},
+	traits::{tokens::ConvertRank, ConstU32, ConstU64, Everything, IsInVec},
 };
 use sp_core::H256;
 use sp_runtime::{
@@ -78,16 +78,19 @@ impl frame_system::Config for Test {
 }
 
 thread_local! {
-	pub static PAID: RefCell<BTreeMap<u64, u64>> = RefCell::new(BTreeMap::new());
+	pub static PAID: RefCell<BTreeMap<(u64, u32), u64>> = RefCell::new(BTreeMap::new());
 	pub static STATUS: RefCell<BTreeMap<u64, PaymentStatus>> = RefCell::new(BTreeMap::new());
 	pub static LAST_ID: RefCell<u64> = RefCell::new(0u64);
 }
 
 fn paid(who: u64) -> u64 {
-	PAID.with(|p| p.borrow().get(&who).cloned().unwrap_or(0))
+	paid_in(who, 0)
+}
+fn paid_in(who: u64, asset_kind: u32) -> u64 {
+	PAID.with(|p| p.borrow().get(&(who, asset_kind)).cloned().unwrap_or(0))
 }
 fn unpay(who: u64, amount: u64) {
-	PAID.with(|p| p.borrow_mut().entry(who).or_default().saturating_reduce(amount))
+	PAID.with(|p| p.borrow_mut().entry((who, 0)).or_default().saturating_reduce(amount))
 }
 fn set_status(id: u64, s: PaymentStatus) {
 	STATUS.with(|m| m.borrow_mut().insert(id, s));
@@ -98,15 +101,15 @@ impl Pay for TestPay {
 	type Beneficiary = u64;
 	type Balance = u64;
 	type Id = u64;
-	type AssetKind = ();
+	type AssetKind = u32;
 	type Error = ();
 
 	fn pay(
 		who: &Self::Beneficiary,
-		_: Self::AssetKind,
+		asset_kind: Self::AssetKind,
 		amount: Self::Balance,
 	) -> Result<Self::Id, Self::Error> {
-		PAID.with(|paid| *paid.borrow_mut().entry(*who).or_default() += amount);
+		PAID.with(|paid| *paid.borrow_mut().entry((*who, asset_kind)).or_default() += amount);
 		Ok(LAST_ID.with(|lid| {
 			let x = *lid.borrow();
 			lid.replace(x + 1);
@@ -169,12 +172,30 @@ fn set_rank(who: u64, rank: u64) {
 
 parameter_types! {
 	pub static Budget: u64 = 10;
+	pub static PayoutAssets: Vec<u32> = vec![1, 2];
+}
+
+/// Asset `1` is worth half of the default asset `0`; there is no rate for any other asset.
+pub struct TestConverter;
+impl ConversionToAssetBalance<u64, u32, u64> for TestConverter {
+	type Error = ();
+	fn to_asset_balance(balance: u64, asset_kind: u32) -> Result<u64, Self::Error> {
+		match asset_kind {
+			0 => Ok(balance),
+			1 => Ok(balance * 2),
+			_ => Err(()),
+		}
+	}
 }
 
 impl Config for Test {
 	type WeightInfo = ();
 	type RuntimeEvent = RuntimeEvent;
+	type AssetKind = u32;
 	type Paymaster = TestPay;
+	type DefaultAsset = ConstU32<0>;
+	type PayoutAssets = IsInVec<PayoutAssets>;
+	type BalanceConverter = TestConverter;
 	type Members = TestClub;
 	type Salary = ConvertRank<Identity>;
 	type RegistrationPeriod = ConstU64<2>;
@@ -311,6 +332,56 @@ fn unregistered_payment_works() {
 	});
 }
 
+#[test]
+fn payout_in_chosen_asset_works() {
+	new_test_ext().execute_with(|| {
+		set_rank(1, 2);
+		assert_ok!(Salary::init(RuntimeOrigin::signed(1)));
+		assert_noop!(
+			Salary::set_payout_asset(RuntimeOrigin::signed(1), Some(Box::new(1))),
+			Error::<Test>::NotInducted
+		);
+		assert_ok!(Salary::induct(RuntimeOrigin::signed(1)));
+		assert_noop!(
+			Salary::set_payout_asset(RuntimeOrigin::signed(1), Some(Box::new(3))),
+			Error::<Test>::AssetNotAllowed
+		);
+		// Asset `2` is allowed but cannot be converted into.
+		assert_ok!(Salary::set_payout_asset(RuntimeOrigin::signed(1), Some(Box::new(2))));
+		run_to(5);
+		assert_ok!(Salary::bump(RuntimeOrigin::signed(1)));
+		run_to(7);
+		assert_noop!(Salary::payout(RuntimeOrigin::signed(1)), Error::<Test>::ConversionFailed);
+
+		assert_ok!(Salary::set_payout_asset(RuntimeOrigin::signed(1), Some(Box::new(1))));
+		System::assert_last_event(
+			Event::<Test>::PayoutAssetSet { who: 1, asset_kind: Some(1) }.into(),
+		);
+		assert_ok!(Salary::payout(RuntimeOrigin::signed(1)));
+		assert_eq!(paid(1), 0);
+		assert_eq!(paid_in(1, 1), 4);
+		// The budget is accounted for in the default asset.
+		assert_eq!(Salary::status().unwrap().total_unregistered_paid, 2);
+
+		// Once the asset is no longer allowed, the default asset is paid instead.
+		PayoutAssets::set(vec![2]);
+		run_to(9);
+		assert_ok!(Salary::bump(RuntimeOrigin::signed(1)));
+		run_to(11);
+		assert_ok!(Salary::payout(RuntimeOrigin::signed(1)));
+		assert_eq!(paid(1), 2);
+		assert_eq!(paid_in(1, 1), 4);
+		assert_eq!(Salary::payout_asset(&1), Some(1));
+
+		// Choosing the default asset clears the choice.
+		assert_ok!(Salary::set_payout_asset(RuntimeOrigin::signed(1), Some(Box::new(0))));
+		System::assert_last_event(
+			Event::<Test>::PayoutAssetSet { who: 1, asset_kind: None }.into(),
+		);
+		assert_eq!(Salary::payout_asset(&1), None);
+	});
+}
+
 #[test]
 fn retry_payment_works() {
 	new_test_ext().execute_with(|| {
@@ -59,6 +59,7 @@ pub trait WeightInfo {
 	fn payout() -> Weight;
 	fn payout_other() -> Weight;
 	fn check_payment() -> Weight;
+	fn set_payout_asset() -> Weight;
 }
 
 /// Weights for pallet_salary using the Substrate node and recommended hardware.
@@ -122,13 +123,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: Salary Claimant (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
 	/// Storage: RankedCollective Members (r:1 w:0)
 	/// Proof: RankedCollective Members (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: Salary PayoutAsset (r:1 w:0)
+	/// Proof: Salary PayoutAsset (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
 	fn payout() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `462`
-		//  Estimated: `3543`
-		// Minimum execution time: 63_660_000 picoseconds.
+		// TODO: run benchmarks
 		Weight::from_parts(65_006_000, 3543)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: Salary Status (r:1 w:1)
@@ -139,13 +139,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: RankedCollective Members (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Salary PayoutAsset (r:1 w:0)
+	/// Proof: Salary PayoutAsset (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
 	fn payout_other() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `462`
-		//  Estimated: `3593`
-		// Minimum execution time: 64_706_000 picoseconds.
+		// TODO: run benchmarks
 		Weight::from_parts(65_763_000, 3593)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: Salary Status (r:1 w:1)
@@ -161,6 +160,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	/// Storage: Salary Claimant (r:1 w:0)
+	/// Proof: Salary Claimant (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
+	/// Storage: Salary PayoutAsset (r:0 w:1)
+	/// Proof: Salary PayoutAsset (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	fn set_payout_asset() -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(10_312_000, 3543)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -223,13 +232,12 @@ impl WeightInfo for () {
 	/// Proof: Salary Claimant (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
 	/// Storage: RankedCollective Members (r:1 w:0)
 	/// Proof: RankedCollective Members (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: Salary PayoutAsset (r:1 w:0)
+	/// Proof: Salary PayoutAsset (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
 	fn payout() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `462`
-		//  Estimated: `3543`
-		// Minimum execution time: 63_660_000 picoseconds.
+		// TODO: run benchmarks
 		Weight::from_parts(65_006_000, 3543)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: Salary Status (r:1 w:1)
@@ -240,13 +248,12 @@ impl WeightInfo for () {
 	/// Proof: RankedCollective Members (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Salary PayoutAsset (r:1 w:0)
+	/// Proof: Salary PayoutAsset (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
 	fn payout_other() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `462`
-		//  Estimated: `3593`
-		// Minimum execution time: 64_706_000 picoseconds.
+		// TODO: run benchmarks
 		Weight::from_parts(65_763_000, 3593)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: Salary Status (r:1 w:1)
@@ -262,4 +269,14 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	/// Storage: Salary Claimant (r:1 w:0)
+	/// Proof: Salary Claimant (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
+	/// Storage: Salary PayoutAsset (r:0 w:1)
+	/// Proof: Salary PayoutAsset (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	fn set_payout_asset() -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(10_312_000, 3543)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
@@ -305,7 +305,7 @@ use frame_support::{
 		ConstU32, Currency, Defensive, DefensiveMax, DefensiveSaturating, Get, LockIdentifier,
 	},
 	weights::Weight,
-	BoundedVec, CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+	BoundedVec, CloneNoBound, DefaultNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
 };
 use scale_info::TypeInfo;
 use sp_runtime::{
@@ -325,6 +325,7 @@ pub use weights::WeightInfo;
 pub use pallet::{pallet::*, UseNominatorsAndValidatorsMap, UseValidatorsMap};
 
 pub(crate) const STAKING_ID: LockIdentifier = *b"staking ";
+pub(crate) const DELEGATION_ID: LockIdentifier = *b"delegate";
 pub(crate) const LOG_TARGET: &str = "runtime::staking";
 
 // syntactic sugar for logging.
@@ -956,6 +957,78 @@ impl Default for Forcing {
 	}
 }
 
+/// The progress of providing the voters of a paged election, page by page.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, Default)]
+pub enum SnapshotStatus<AccountId> {
+	/// The voters up to and including this account have been provided.
+	Ongoing(AccountId),
+	/// All voters have been provided.
+	Consumed,
+	/// No voters have been provided yet.
+	#[default]
+	Waiting,
+}
+
+/// The progress of paying out the rewards of an era automatically.
+///
+/// See [`Config::MaxAutoPayoutsPerBlock`].
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PayoutCursor<AccountId> {
+	/// The era whose rewards are paid out.
+	pub era: EraIndex,
+	/// The validator whose pages are paid out, if the first one has been reached yet.
+	pub validator: Option<AccountId>,
+}
+
+/// Funds of a delegator that are delegated to an agent.
+///
+/// See [`sp_staking::DelegatedStakingInterface`].
+#[derive(
+	PartialEqNoBound,
+	EqNoBound,
+	CloneNoBound,
+	Encode,
+	Decode,
+	RuntimeDebugNoBound,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(T))]
+pub struct Delegation<T: Config> {
+	/// The agent the funds are delegated to.
+	pub agent: T::AccountId,
+	/// The delegated funds, locked in the account of the delegator.
+	pub amount: BalanceOf<T>,
+}
+
+/// The funds that are delegated to an agent.
+#[derive(
+	PartialEqNoBound,
+	EqNoBound,
+	CloneNoBound,
+	Encode,
+	Decode,
+	RuntimeDebugNoBound,
+	TypeInfo,
+	MaxEncodedLen,
+	DefaultNoBound,
+)]
+#[scale_info(skip_type_params(T))]
+pub struct AgentLedger<T: Config> {
+	/// The sum of the funds of all delegations to the agent.
+	pub total_delegated: BalanceOf<T>,
+	/// The part of `total_delegated` that was slashed, but has not been applied to the
+	/// delegators yet.
+	pub pending_slash: BalanceOf<T>,
+}
+
+impl<T: Config> AgentLedger<T> {
+	/// The delegated funds, without the ones that are pending to be slashed.
+	pub fn effective_balance(&self) -> BalanceOf<T> {
+		self.total_delegated.saturating_sub(self.pending_slash)
+	}
+}
+
 /// A `Convert` implementation that finds the stash of the given controller account,
 /// if any.
 pub struct StashOf<T>(sp_std::marker::PhantomData<T>);
@@ -1198,6 +1271,53 @@ impl<T: Config> EraInfo<T> {
 		});
 	}
 
+	/// Store a part of the exposure of an elected validator, on top of what is already stored for
+	/// the era.
+	///
+	/// Used for paged election results, where a validator can be backed by the voters of several
+	/// pages. The nominators are appended to the last exposure page, which is filled up before
+	/// new pages are added.
+	pub(crate) fn upsert_exposure(
+		era: EraIndex,
+		validator: &T::AccountId,
+		exposure: Exposure<T::AccountId, BalanceOf<T>>,
+	) {
+		let Some(mut exposure_metadata) = <ErasStakersOverview<T>>::get(era, &validator) else {
+			return Self::set_exposure(era, validator, exposure)
+		};
+		let page_size = T::MaxExposurePageSize::get().defensive_max(1);
+
+		exposure_metadata.total.saturating_accrue(exposure.total);
+		exposure_metadata.own.saturating_accrue(exposure.own);
+		exposure_metadata
+			.nominator_count
+			.saturating_accrue(exposure.others.len() as u32);
+
+		let mut others = exposure.others.into_iter();
+		if let Some(last_page) = exposure_metadata.page_count.checked_sub(1) {
+			let mut exposure_page =
+				<ErasStakersPaged<T>>::get((era, &validator, last_page)).unwrap_or_default();
+			let free = (page_size as usize).saturating_sub(exposure_page.others.len());
+			if free > 0 && !others.as_slice().is_empty() {
+				others.by_ref().take(free).for_each(|individual| {
+					exposure_page.page_total.saturating_accrue(individual.value);
+					exposure_page.others.push(individual);
+				});
+				<ErasStakersPaged<T>>::insert((era, &validator, last_page), &exposure_page);
+			}
+		}
+
+		let rest = Exposure { total: Zero::zero(), own: Zero::zero(), others: others.collect() };
+		let (_, exposure_pages) = rest.into_pages(page_size);
+		exposure_pages.into_iter().for_each(|paged_exposure| {
+			let page = exposure_metadata.page_count;
+			<ErasStakersPaged<T>>::insert((era, &validator, page), &paged_exposure);
+			exposure_metadata.page_count.saturating_inc();
+		});
+
+		<ErasStakersOverview<T>>::insert(era, &validator, &exposure_metadata);
+	}
+
 	/// Store total exposure for all the elected validators in the era.
 	pub(crate) fn set_total_stake(era: EraIndex, total_stake: BalanceOf<T>) {
 		<ErasTotalStake<T>>::insert(era, total_stake);
@@ -20,28 +20,31 @@
 use frame_election_provider_support::{
 	bounds::{CountBound, SizeBound},
 	data_provider, BoundedSupportsOf, DataProviderBounds, ElectionDataProvider, ElectionProvider,
-	ScoreProvider, SortedListProvider, VoteWeight, VoterOf,
+	PageIndex, ScoreProvider, SortedListProvider, VoteWeight, VoterOf,
 };
 use frame_support::{
 	defensive,
 	dispatch::WithPostDispatchInfo,
 	pallet_prelude::*,
+	storage::with_storage_layer,
 	traits::{
 		Currency, Defensive, DefensiveSaturating, EstimateNextNewSession, Get, Imbalance, Len,
-		OnUnbalanced, TryCollect, UnixTime,
+		LockableCurrency, OnUnbalanced, TryCollect, UnixTime, WithdrawReasons,
 	},
 	weights::Weight,
 };
 use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
 use pallet_session::historical;
 use sp_runtime::{
-	traits::{Bounded, Convert, One, SaturatedConversion, Saturating, StaticLookup, Zero},
+	traits::{
+		Bounded, CheckedAdd, Convert, One, SaturatedConversion, Saturating, StaticLookup, Zero,
+	},
 	Perbill,
 };
 use sp_staking::{
 	currency_to_vote::CurrencyToVote,
 	offence::{DisableStrategy, OffenceDetails, OnOffenceHandler},
-	EraIndex, Page, SessionIndex, Stake,
+	DelegatedStakingInterface, EraIndex, Page, SessionIndex, Stake,
 	StakingAccount::{self, Controller, Stash},
 	StakingInterface,
 };
@@ -49,9 +52,10 @@ use sp_std::prelude::*;
 
 use crate::{
 	election_size_tracker::StaticTracker, log, slashing, weights::WeightInfo, ActiveEraInfo,
-	BalanceOf, EraInfo, EraPayout, Exposure, ExposureOf, Forcing, IndividualExposure,
-	MaxNominationsOf, MaxWinnersOf, Nominations, NominationsQuota, PositiveImbalanceOf,
-	RewardDestination, SessionInterface, StakingLedger, ValidatorPrefs,
+	AgentLedger, BalanceOf, Delegation, EraInfo, EraPayout, Exposure, ExposureOf, Forcing,
+	IndividualExposure, MaxNominationsOf, MaxWinnersOf, Nominations, NominationsQuota,
+	PayoutCursor, PositiveImbalanceOf, RewardDestination, SessionInterface, SnapshotStatus,
+	StakingLedger, ValidatorPrefs, DELEGATION_ID,
 };
 
 use super::pallet::*;
@@ -317,6 +321,100 @@ impl<T: Config> Pallet<T> {
 		Ok(Some(T::WeightInfo::payout_stakers_alive_staked(nominator_payout_count)).into())
 	}
 
+	/// Start the automatic payout of `era`, or queue it in [`AutoPayoutQueue`] if the payout of a
+	/// previous era is still in progress.
+	fn queue_auto_payout(era: EraIndex) {
+		if !AutoPayoutCursor::<T>::exists() {
+			AutoPayoutCursor::<T>::put(PayoutCursor { era, validator: None });
+			Self::deposit_event(Event::<T>::AutoPayoutStarted { era_index: era });
+			return
+		}
+
+		AutoPayoutQueue::<T>::mutate(|queue| {
+			if queue.is_full() {
+				// the oldest era is out of the history depth by now.
+				queue.remove(0);
+			}
+			queue.force_push(era);
+		});
+	}
+
+	/// Pay out the reward pages of the era in [`AutoPayoutCursor`], one page at a time, for as
+	/// long as `remaining_weight` and [`Config::MaxAutoPayoutsPerBlock`] allow.
+	///
+	/// Once all pages of an era are paid out, the next era of [`AutoPayoutQueue`] follows. Pages
+	/// that have already been claimed are skipped. Returns the consumed weight.
+	pub(crate) fn do_auto_payout(remaining_weight: Weight) -> Weight {
+		// paying out a page, or finding the next validator to pay out.
+		let step_weight = T::WeightInfo::payout_stakers_alive_staked(T::MaxExposurePageSize::get())
+			.saturating_add(T::DbWeight::get().reads(1));
+		// reading and writing the cursor.
+		let mut consumed = T::DbWeight::get().reads_writes(1, 1);
+
+		let max_payouts = T::MaxAutoPayoutsPerBlock::get();
+		if max_payouts.is_zero() || !consumed.saturating_add(step_weight).all_lte(remaining_weight)
+		{
+			return Weight::zero()
+		}
+		let Some(mut cursor) = AutoPayoutCursor::<T>::get() else {
+			return T::DbWeight::get().reads(1)
+		};
+
+		let mut payouts = 0;
+		while payouts < max_payouts &&
+			consumed.saturating_add(step_weight).all_lte(remaining_weight)
+		{
+			consumed.saturating_accrue(step_weight);
+
+			if let Some(validator) = cursor.validator.clone() {
+				let paid = with_storage_layer(|| Self::do_payout_stakers(validator, cursor.era));
+				if paid.is_ok() {
+					payouts += 1;
+					continue
+				}
+			}
+
+			// the current validator has no pages left, move on to the next one.
+			if let Some(next) =
+				Self::next_auto_payout_validator(cursor.era, cursor.validator.as_ref())
+			{
+				cursor.validator = Some(next);
+				continue
+			}
+
+			// the era is done, move on to the next queued one.
+			Self::deposit_event(Event::<T>::AutoPayoutCompleted { era_index: cursor.era });
+			consumed.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+			let mut queue = AutoPayoutQueue::<T>::get();
+			if queue.is_empty() {
+				AutoPayoutCursor::<T>::kill();
+				return consumed
+			}
+			cursor = PayoutCursor { era: queue.remove(0), validator: None };
+			AutoPayoutQueue::<T>::put(queue);
+			Self::deposit_event(Event::<T>::AutoPayoutStarted { era_index: cursor.era });
+		}
+
+		AutoPayoutCursor::<T>::put(cursor);
+		consumed
+	}
+
+	/// The validator of `era` that comes after `validator`, or the first one if `validator` is
+	/// `None`.
+	fn next_auto_payout_validator(
+		era: EraIndex,
+		validator: Option<&T::AccountId>,
+	) -> Option<T::AccountId> {
+		match validator {
+			Some(validator) => ErasStakersOverview::<T>::iter_key_prefix_from(
+				era,
+				ErasStakersOverview::<T>::hashed_key_for(era, validator),
+			)
+			.next(),
+			None => ErasStakersOverview::<T>::iter_key_prefix(era).next(),
+		}
+	}
+
 	/// Chill a stash account.
 	pub(crate) fn chill_stash(stash: &T::AccountId) {
 		let chilled_as_validator = Self::do_remove_validator(stash);
@@ -519,6 +617,11 @@ impl<T: Config> Pallet<T> {
 			<ErasValidatorReward<T>>::insert(&active_era.index, validator_payout);
 			T::RewardRemainder::on_unbalanced(T::Currency::issue(remainder));
 
+			// Pay out the rewards of the era in the coming blocks, once the previous eras are done.
+			if !T::MaxAutoPayoutsPerBlock::get().is_zero() {
+				Self::queue_auto_payout(active_era.index);
+			}
+
 			// Clear offending validators.
 			<OffendingValidators<T>>::kill();
 		}
@@ -539,6 +642,16 @@ impl<T: Config> Pallet<T> {
 			MaxWinnersOf<T>,
 		>,
 	) -> BoundedVec<T::AccountId, MaxWinnersOf<T>> {
+		let new_planned_era = Self::plan_new_era(start_session_index);
+
+		// Set staking information for the new era.
+		Self::store_stakers_info(exposures, new_planned_era)
+	}
+
+	/// Bump the current era, store its start session index and clean old era information.
+	///
+	/// Returns the new planned era.
+	fn plan_new_era(start_session_index: SessionIndex) -> EraIndex {
 		// Increment or set current era.
 		let new_planned_era = CurrentEra::<T>::mutate(|s| {
 			*s = Some(s.map(|s| s + 1).unwrap_or(0));
@@ -551,8 +664,98 @@ impl<T: Config> Pallet<T> {
 			Self::clear_era_information(old_era);
 		}
 
-		// Set staking information for the new era.
-		Self::store_stakers_info(exposures, new_planned_era)
+		new_planned_era
+	}
+
+	/// Fetch a page of a paged election result ahead of the new era.
+	///
+	/// Pages `pages - 1` down to `1` are fetched, one per block, in the blocks right before the
+	/// next election is predicted. The exposures of each page are stored right away for the era
+	/// that is planned next, such that the complete result is never held at once. Page `0`, and
+	/// any page that was missed, is fetched once the new era is planned, see
+	/// [`Self::try_trigger_new_era`]. Does nothing if the election provider is not paged.
+	///
+	/// Returns the consumed weight.
+	pub(crate) fn fetch_next_election_page(now: BlockNumberFor<T>) -> Weight {
+		let pages = <T::ElectionProvider as ElectionProvider>::pages();
+		if pages <= 1 {
+			return Weight::zero()
+		}
+
+		let remaining =
+			<Self as ElectionDataProvider>::next_election_prediction(now).saturating_sub(now);
+		let prediction_weight = T::DbWeight::get().reads(5);
+		if remaining.is_zero() || remaining >= pages.into() {
+			return prediction_weight
+		}
+
+		// the page that is due in this block. If some blocks were missed, catch up one page at a
+		// time, the remaining ones are fetched when the new era is planned.
+		let page: PageIndex = remaining.saturated_into();
+		let next_page = NextElectionPage::<T>::get().unwrap_or(pages - 1);
+		if page > next_page || next_page.is_zero() {
+			return prediction_weight.saturating_add(T::DbWeight::get().reads(1))
+		}
+
+		let page_weight = match Self::store_election_page(next_page) {
+			Some(weight) => {
+				NextElectionPage::<T>::put(next_page - 1);
+				weight
+			},
+			None => {
+				// start over, all pages are fetched again when the new era is planned.
+				Self::clear_election_pages();
+				Weight::zero()
+			},
+		};
+
+		prediction_weight
+			.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+			.saturating_add(page_weight)
+	}
+
+	/// Fetch a page of a paged election result and store the exposures of its winners for the era
+	/// that is planned next, on top of the ones of the previous pages.
+	///
+	/// Returns the consumed weight, or `None` if the page could not be fetched or the result has
+	/// more winners than allowed. In the latter case, the exposures of the page may be partially
+	/// stored, see [`Self::clear_election_pages`].
+	fn store_election_page(page: PageIndex) -> Option<Weight> {
+		let supports = <T::ElectionProvider>::elect_paged(page)
+			.map_err(|e| {
+				log!(warn, "election provider failed to provide page {} due to {:?}", page, e)
+			})
+			.ok()?;
+
+		let planned_era = CurrentEra::<T>::get().map_or(0, |era| era.saturating_add(1));
+		let page_size = T::MaxExposurePageSize::get().max(1) as u64;
+		let mut elected = ElectableStashes::<T>::get();
+		let mut total_stake = ErasTotalStake::<T>::get(planned_era);
+		let mut weight = T::DbWeight::get().reads_writes(3, 2);
+
+		for (stash, exposure) in Self::collect_exposures(supports) {
+			if elected.try_insert(stash.clone()).is_err() {
+				log!(warn, "paged election result has more winners than allowed");
+				return None
+			}
+			total_stake = total_stake.saturating_add(exposure.total);
+			let exposure_pages =
+				(exposure.others.len() as u64).saturating_add(page_size - 1) / page_size;
+			weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2 + exposure_pages));
+			EraInfo::<T>::upsert_exposure(planned_era, &stash, exposure);
+		}
+
+		ElectableStashes::<T>::put(elected);
+		EraInfo::<T>::set_total_stake(planned_era, total_stake);
+		Some(weight)
+	}
+
+	/// Discard the pages of a paged election result that were stored so far.
+	fn clear_election_pages() {
+		NextElectionPage::<T>::kill();
+		ElectableStashes::<T>::kill();
+		let planned_era = CurrentEra::<T>::get().map_or(0, |era| era.saturating_add(1));
+		Self::clear_era_information(planned_era);
 	}
 
 	/// Potentially plan a new era.
@@ -577,6 +780,8 @@ impl<T: Config> Pallet<T> {
 				.try_into()
 				// both bounds checked in integrity test to be equal
 				.defensive_unwrap_or_default()
+		} else if <T::ElectionProvider as ElectionProvider>::pages() > 1 {
+			return Self::try_trigger_new_paged_era(start_session_index)
 		} else {
 			let result = <T::ElectionProvider>::elect().map_err(|e| {
 				log!(warn, "election provider failed due to {:?}", e);
@@ -586,7 +791,52 @@ impl<T: Config> Pallet<T> {
 		};
 
 		let exposures = Self::collect_exposures(election_result);
-		if (exposures.len() as u32) < Self::minimum_validator_count().max(1) {
+		if !Self::has_enough_validators(start_session_index, exposures.len()) {
+			return None
+		}
+
+		Self::deposit_event(Event::StakersElected);
+		Some(Self::trigger_new_era(start_session_index, exposures))
+	}
+
+	/// Same as [`Self::try_trigger_new_era`], for a paged election result.
+	///
+	/// The pages that were not fetched ahead of time are fetched now. Their exposures are already
+	/// stored for the planned era, and are discarded again if no new era can be planned.
+	fn try_trigger_new_paged_era(
+		start_session_index: SessionIndex,
+	) -> Option<BoundedVec<T::AccountId, MaxWinnersOf<T>>> {
+		let pages = <T::ElectionProvider as ElectionProvider>::pages();
+		let next_page = NextElectionPage::<T>::take().unwrap_or(pages - 1);
+		for page in (0..=next_page).rev() {
+			if Self::store_election_page(page).is_none() {
+				Self::clear_election_pages();
+				Self::deposit_event(Event::StakingElectionFailed);
+				return None
+			}
+		}
+
+		let elected_stashes: BoundedVec<_, MaxWinnersOf<T>> = ElectableStashes::<T>::take()
+			.into_iter()
+			.collect::<Vec<_>>()
+			.try_into()
+			.expect("elected stashes are bounded by the same bound; qed");
+		if !Self::has_enough_validators(start_session_index, elected_stashes.len()) {
+			Self::clear_election_pages();
+			return None
+		}
+
+		Self::deposit_event(Event::StakersElected);
+		let new_planned_era = Self::plan_new_era(start_session_index);
+		Self::store_validator_prefs(&elected_stashes, new_planned_era);
+		Some(elected_stashes)
+	}
+
+	/// Whether `elected` validators are enough to plan a new era.
+	///
+	/// If not, a [`Event::StakingElectionFailed`] is emitted. The initial era is planned anyhow.
+	fn has_enough_validators(start_session_index: SessionIndex, elected: usize) -> bool {
+		if (elected as u32) < Self::minimum_validator_count().max(1) {
 			// Session will panic if we ever return an empty validator set, thus max(1) ^^.
 			match CurrentEra::<T>::get() {
 				Some(current_era) if current_era > 0 => log!(
@@ -594,7 +844,7 @@ impl<T: Config> Pallet<T> {
 					"chain does not have enough staking candidates to operate for era {:?} ({} \
 					elected, minimum is {})",
 					CurrentEra::<T>::get().unwrap_or(0),
-					exposures.len(),
+					elected,
 					Self::minimum_validator_count(),
 				),
 				None => {
@@ -609,11 +859,10 @@ impl<T: Config> Pallet<T> {
 			}
 
 			Self::deposit_event(Event::StakingElectionFailed);
-			return None
+			return false
 		}
 
-		Self::deposit_event(Event::StakersElected);
-		Some(Self::trigger_new_era(start_session_index, exposures))
+		true
 	}
 
 	/// Process the output of the election.
@@ -644,9 +893,15 @@ impl<T: Config> Pallet<T> {
 			.expect("elected_stashes.len() always equal to exposures.len(); qed");
 
 		EraInfo::<T>::set_total_stake(new_planned_era, total_stake);
+		Self::store_validator_prefs(&elected_stashes, new_planned_era);
 
+		elected_stashes
+	}
+
+	/// Store the preferences of the elected validators for the new planned era.
+	fn store_validator_prefs(elected_stashes: &[T::AccountId], new_planned_era: EraIndex) {
 		// Collect the pref of all winners.
-		for stash in &elected_stashes {
+		for stash in elected_stashes {
 			let pref = Self::validators(stash);
 			<ErasValidatorPrefs<T>>::insert(&new_planned_era, stash, pref);
 		}
@@ -659,8 +914,6 @@ impl<T: Config> Pallet<T> {
 				new_planned_era,
 			);
 		}
-
-		elected_stashes
 	}
 
 	/// Consume a set of [`BoundedSupports`] from [`sp_npos_elections`] and collect them into a
@@ -820,6 +1073,18 @@ impl<T: Config> Pallet<T> {
 	///
 	/// This function is self-weighing as [`DispatchClass::Mandatory`].
 	pub fn get_npos_voters(bounds: DataProviderBounds) -> Vec<VoterOf<Self>> {
+		Self::get_npos_voters_from(bounds, None).0
+	}
+
+	/// Same as [`Self::get_npos_voters`], but starts iterating the voter list right after `start`,
+	/// if given.
+	///
+	/// Also returns the status of the iteration, which can be used to continue with the next
+	/// page of voters.
+	pub(crate) fn get_npos_voters_from(
+		bounds: DataProviderBounds,
+		start: Option<T::AccountId>,
+	) -> (Vec<VoterOf<Self>>, SnapshotStatus<T::AccountId>) {
 		let mut voters_size_tracker: StaticTracker<Self> = StaticTracker::default();
 
 		let final_predicted_len = {
@@ -837,7 +1102,17 @@ impl<T: Config> Pallet<T> {
 		let mut nominators_taken = 0u32;
 		let mut min_active_stake = u64::MAX;
 
-		let mut sorted_voters = T::VoterList::iter();
+		let is_first_page = start.is_none();
+		let mut sorted_voters = match start {
+			Some(ref last) => T::VoterList::iter_from(last).unwrap_or_else(|_| {
+				defensive!("DEFENSIVE: last voter of the previous page is not in `VoterList`");
+				Box::new(sp_std::iter::empty())
+			}),
+			None => T::VoterList::iter(),
+		};
+		let mut last_voter = start;
+		let mut exhausted = false;
+
 		while all_voters.len() < final_predicted_len as usize &&
 			voters_seen < (NPOS_MAX_ITERATIONS_COEFFICIENT * final_predicted_len as u32)
 		{
@@ -846,13 +1121,17 @@ impl<T: Config> Pallet<T> {
 					voters_seen.saturating_inc();
 					voter
 				},
-				None => break,
+				None => {
+					exhausted = true;
+					break
+				},
 			};
 
 			let voter_weight = weight_of(&voter);
 			// if voter weight is zero, do not consider this voter for the snapshot.
 			if voter_weight.is_zero() {
 				log!(debug, "voter's active balance is 0. skip this voter.");
+				last_voter = Some(voter);
 				continue
 			}
 
@@ -862,7 +1141,7 @@ impl<T: Config> Pallet<T> {
 					// voter at this point and accept all the current nominations. The nomination
 					// quota is only enforced at `nominate` time.
 
-					let voter = (voter, voter_weight, targets);
+					let voter = (voter.clone(), voter_weight, targets);
 					if voters_size_tracker.try_register_voter(&voter, &bounds).is_err() {
 						// no more space left for the election result, stop iterating.
 						Self::deposit_event(Event::<T>::SnapshotVotersSizeExceeded {
@@ -878,6 +1157,7 @@ impl<T: Config> Pallet<T> {
 				}
 				min_active_stake =
 					if voter_weight < min_active_stake { voter_weight } else { min_active_stake };
+				last_voter = Some(voter);
 			} else if Validators::<T>::contains_key(&voter) {
 				// if this voter is a validator:
 				let self_vote = (
@@ -897,6 +1177,7 @@ impl<T: Config> Pallet<T> {
 				}
 				all_voters.push(self_vote);
 				validators_taken.saturating_inc();
+				last_voter = Some(voter);
 			} else {
 				// this can only happen if: 1. there a bug in the bags-list (or whatever is the
 				// sorted list) logic and the state of the two pallets is no longer compatible, or
@@ -907,6 +1188,7 @@ impl<T: Config> Pallet<T> {
 				    "DEFENSIVE: invalid item in `VoterList`: {:?}, this nominator probably has too many nominations now",
                     voter,
                 );
+				last_voter = Some(voter);
 			}
 		}
 
@@ -918,7 +1200,11 @@ impl<T: Config> Pallet<T> {
 		let min_active_stake: T::CurrencyBalance =
 			if all_voters.is_empty() { Zero::zero() } else { min_active_stake.into() };
 
-		MinimumActiveStake::<T>::put(min_active_stake);
+		// later pages contain voters with less stake, the minimum is that of the last non-empty
+		// page.
+		if is_first_page || !all_voters.is_empty() {
+			MinimumActiveStake::<T>::put(min_active_stake);
+		}
 
 		log!(
 			info,
@@ -928,7 +1214,13 @@ impl<T: Config> Pallet<T> {
 			nominators_taken
 		);
 
-		all_voters
+		let status = match (exhausted, last_voter) {
+			(true, _) => SnapshotStatus::Consumed,
+			(false, Some(last)) => SnapshotStatus::Ongoing(last),
+			(false, None) => SnapshotStatus::Waiting,
+		};
+
+		(all_voters, status)
 	}
 
 	/// Get the targets for an upcoming npos election.
@@ -1133,6 +1425,34 @@ impl<T: Config> ElectionDataProvider for Pallet<T> {
 		Ok(voters)
 	}
 
+	fn electing_voters_paged(
+		bounds: DataProviderBounds,
+		page: PageIndex,
+	) -> data_provider::Result<Vec<VoterOf<Self>>> {
+		// the most significant page always starts a new snapshot.
+		let pages = <T::ElectionProvider as ElectionProvider>::pages();
+		let status = if page == pages.saturating_sub(1) {
+			SnapshotStatus::Waiting
+		} else {
+			VoterSnapshotStatus::<T>::get()
+		};
+
+		let (voters, status) = match status {
+			SnapshotStatus::Waiting => Self::get_npos_voters_from(bounds, None),
+			SnapshotStatus::Ongoing(last) => Self::get_npos_voters_from(bounds, Some(last)),
+			SnapshotStatus::Consumed => (Vec::new(), SnapshotStatus::Consumed),
+		};
+
+		// the last page finishes the snapshot.
+		if page.is_zero() {
+			VoterSnapshotStatus::<T>::kill();
+		} else {
+			VoterSnapshotStatus::<T>::put(status);
+		}
+
+		Ok(voters)
+	}
+
 	fn electable_targets(bounds: DataProviderBounds) -> data_provider::Result<Vec<T::AccountId>> {
 		let targets = Self::get_npos_targets(bounds);
 
@@ -1817,6 +2137,207 @@ impl<T: Config> StakingInterface for Pallet<T> {
 	}
 }
 
+impl<T: Config> Pallet<T> {
+	/// The balance backing the stake of `stash`.
+	///
+	/// For agents, these are the funds delegated to them, otherwise the balance of the stash.
+	pub(crate) fn stash_balance(stash: &T::AccountId) -> BalanceOf<T> {
+		Agents::<T>::get(stash)
+			.map(|agent| agent.effective_balance())
+			.unwrap_or_else(|| T::Currency::total_balance(stash))
+	}
+
+	/// The funds delegated to `agent` that are neither bonded nor pending to be slashed, and can
+	/// be released to the delegators.
+	fn withdrawable_delegated_funds(agent: &T::AccountId, ledger: &AgentLedger<T>) -> BalanceOf<T> {
+		let bonded = Self::ledger(Stash(agent.clone())).map(|l| l.total).unwrap_or_default();
+		ledger.effective_balance().saturating_sub(bonded)
+	}
+
+	/// Store the delegation of `delegator` and lock its funds, or remove both if nothing is
+	/// delegated anymore.
+	fn update_delegation(delegator: &T::AccountId, delegation: Delegation<T>) {
+		if delegation.amount.is_zero() {
+			T::Currency::remove_lock(DELEGATION_ID, delegator);
+			Delegators::<T>::remove(delegator);
+			frame_system::Pallet::<T>::dec_consumers(delegator);
+		} else {
+			T::Currency::set_lock(
+				DELEGATION_ID,
+				delegator,
+				delegation.amount,
+				WithdrawReasons::all(),
+			);
+			Delegators::<T>::insert(delegator, delegation);
+		}
+	}
+
+	/// Store the ledger of `agent`, or remove it once it is neither bonded nor delegated to.
+	fn update_agent(agent: &T::AccountId, ledger: AgentLedger<T>) {
+		if ledger.total_delegated.is_zero() && !Bonded::<T>::contains_key(agent) {
+			Agents::<T>::remove(agent);
+			let _ = frame_system::Pallet::<T>::dec_providers(agent).defensive();
+		} else {
+			Agents::<T>::insert(agent, ledger);
+		}
+	}
+}
+
+impl<T: Config> DelegatedStakingInterface for Pallet<T> {
+	fn delegate(
+		delegator: &Self::AccountId,
+		agent: &Self::AccountId,
+		reward_account: &Self::AccountId,
+		amount: Self::Balance,
+	) -> DispatchResult {
+		ensure!(delegator != agent, Error::<T>::InvalidDelegation);
+		// funds can only be at stake once.
+		ensure!(
+			!Bonded::<T>::contains_key(delegator) && !Agents::<T>::contains_key(delegator),
+			Error::<T>::InvalidDelegation
+		);
+
+		let maybe_delegation = Delegators::<T>::get(delegator);
+		let mut delegation = match maybe_delegation {
+			Some(ref delegation) => {
+				ensure!(&delegation.agent == agent, Error::<T>::InvalidDelegation);
+				delegation.clone()
+			},
+			None => Delegation { agent: agent.clone(), amount: Zero::zero() },
+		};
+		delegation.amount = delegation.amount.checked_add(&amount).ok_or(Error::<T>::BadState)?;
+		ensure!(
+			T::Currency::free_balance(delegator) >= delegation.amount,
+			Error::<T>::NotEnoughDelegatedFunds
+		);
+		if maybe_delegation.is_none() {
+			frame_system::Pallet::<T>::inc_consumers(delegator)
+				.map_err(|_| Error::<T>::BadState)?;
+		}
+
+		let mut agent_ledger = match Agents::<T>::get(agent) {
+			Some(agent_ledger) => agent_ledger,
+			None => {
+				ensure!(
+					!Bonded::<T>::contains_key(agent) && !Delegators::<T>::contains_key(agent),
+					Error::<T>::InvalidDelegation
+				);
+				// the agent is keyless, it exists as long as funds are delegated to it.
+				frame_system::Pallet::<T>::inc_providers(agent);
+				AgentLedger::default()
+			},
+		};
+		agent_ledger.total_delegated.saturating_accrue(amount);
+		Agents::<T>::insert(agent, agent_ledger);
+		Self::update_delegation(delegator, delegation);
+
+		match Self::ledger(Stash(agent.clone())) {
+			Ok(mut ledger) => {
+				ledger.total.saturating_accrue(amount);
+				ledger.active.saturating_accrue(amount);
+				ensure!(
+					ledger.active >= T::Currency::minimum_balance(),
+					Error::<T>::InsufficientBond
+				);
+				ledger.update()?;
+				if T::VoterList::contains(agent) {
+					let _ = T::VoterList::on_update(agent, Self::weight_of(agent)).defensive();
+				}
+			},
+			Err(_) => {
+				ensure!(amount >= T::Currency::minimum_balance(), Error::<T>::InsufficientBond);
+				frame_system::Pallet::<T>::inc_consumers(agent)
+					.map_err(|_| Error::<T>::BadState)?;
+				StakingLedger::<T>::new(agent.clone(), amount)
+					.bond(RewardDestination::Account(reward_account.clone()))?;
+			},
+		}
+
+		Self::deposit_event(Event::<T>::Delegated {
+			agent: agent.clone(),
+			delegator: delegator.clone(),
+			amount,
+		});
+		Self::deposit_event(Event::<T>::Bonded { stash: agent.clone(), amount });
+		Ok(())
+	}
+
+	fn withdraw_delegation(
+		delegator: &Self::AccountId,
+		agent: &Self::AccountId,
+		amount: Self::Balance,
+	) -> DispatchResult {
+		let mut delegation = Delegators::<T>::get(delegator)
+			.filter(|delegation| &delegation.agent == agent)
+			.ok_or(Error::<T>::NotDelegator)?;
+		let mut agent_ledger = Agents::<T>::get(agent).ok_or(Error::<T>::NotAgent)?;
+		ensure!(
+			delegation.amount >= amount &&
+				Self::withdrawable_delegated_funds(agent, &agent_ledger) >= amount,
+			Error::<T>::NotEnoughDelegatedFunds
+		);
+
+		delegation.amount.saturating_reduce(amount);
+		agent_ledger.total_delegated.saturating_reduce(amount);
+		Self::update_delegation(delegator, delegation);
+		Self::update_agent(agent, agent_ledger);
+
+		Self::deposit_event(Event::<T>::DelegationReleased {
+			agent: agent.clone(),
+			delegator: delegator.clone(),
+			amount,
+		});
+		Ok(())
+	}
+
+	fn agent_balance(agent: &Self::AccountId) -> Self::Balance {
+		Agents::<T>::get(agent)
+			.map(|agent| agent.effective_balance())
+			.unwrap_or_default()
+	}
+
+	fn delegator_balance(delegator: &Self::AccountId) -> Self::Balance {
+		Delegators::<T>::get(delegator)
+			.map(|delegation| delegation.amount)
+			.unwrap_or_default()
+	}
+
+	fn pending_slash(agent: &Self::AccountId) -> Self::Balance {
+		Agents::<T>::get(agent).map(|agent| agent.pending_slash).unwrap_or_default()
+	}
+
+	fn delegator_slash(
+		agent: &Self::AccountId,
+		delegator: &Self::AccountId,
+		value: Self::Balance,
+	) -> DispatchResult {
+		let mut delegation = Delegators::<T>::get(delegator)
+			.filter(|delegation| &delegation.agent == agent)
+			.ok_or(Error::<T>::NotDelegator)?;
+		let mut agent_ledger = Agents::<T>::get(agent).ok_or(Error::<T>::NotAgent)?;
+		ensure!(
+			delegation.amount >= value && agent_ledger.pending_slash >= value,
+			Error::<T>::NotEnoughDelegatedFunds
+		);
+
+		let (imbalance, _missing) = T::Currency::slash(delegator, value);
+		T::Slash::on_unbalanced(imbalance);
+
+		delegation.amount.saturating_reduce(value);
+		agent_ledger.total_delegated.saturating_reduce(value);
+		agent_ledger.pending_slash.saturating_reduce(value);
+		Self::update_delegation(delegator, delegation);
+		Self::update_agent(agent, agent_ledger);
+
+		Self::deposit_event(Event::<T>::DelegatorSlashed {
+			agent: agent.clone(),
+			delegator: delegator.clone(),
+			amount: value,
+		});
+		Ok(())
+	}
+}
+
 #[cfg(any(test, feature = "try-runtime"))]
 impl<T: Config> Pallet<T> {
 	pub(crate) fn do_try_state(_: BlockNumberFor<T>) -> Result<(), TryRuntimeError> {
@@ -19,7 +19,7 @@
 
 use codec::Codec;
 use frame_election_provider_support::{
-	ElectionProvider, ElectionProviderBase, SortedListProvider, VoteWeight,
+	ElectionProvider, ElectionProviderBase, PageIndex, SortedListProvider, VoteWeight,
 };
 use frame_support::{
 	pallet_prelude::*,
@@ -47,10 +47,11 @@ mod impls;
 pub use impls::*;
 
 use crate::{
-	slashing, weights::WeightInfo, AccountIdLookupOf, ActiveEraInfo, BalanceOf, EraPayout,
-	EraRewardPoints, Exposure, ExposurePage, Forcing, MaxNominationsOf, NegativeImbalanceOf,
-	Nominations, NominationsQuota, PositiveImbalanceOf, RewardDestination, SessionInterface,
-	StakingLedger, UnappliedSlash, UnlockChunk, ValidatorPrefs,
+	slashing, weights::WeightInfo, AccountIdLookupOf, ActiveEraInfo, AgentLedger, BalanceOf,
+	Delegation, EraPayout, EraRewardPoints, Exposure, ExposurePage, Forcing, MaxNominationsOf,
+	MaxWinnersOf, NegativeImbalanceOf, Nominations, NominationsQuota, PayoutCursor,
+	PositiveImbalanceOf, RewardDestination, SessionInterface, SnapshotStatus, StakingLedger,
+	UnappliedSlash, UnlockChunk, ValidatorPrefs,
 };
 
 // The speculative number of spans are used as an input of the weight annotation of
@@ -217,6 +218,14 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxExposurePageSize: Get<u32>;
 
+		/// The maximum number of reward pages paid out automatically per block.
+		///
+		/// When an era ends, the reward pages of all of its validators that have not been claimed
+		/// are paid out in the following blocks, using the weight left over in `on_idle`. Set to
+		/// zero to disable automatic payouts.
+		#[pallet::constant]
+		type MaxAutoPayoutsPerBlock: Get<u32>;
+
 		/// The fraction of the validator set that is safe to be offending.
 		/// After the threshold is reached a new era will be forced.
 		type OffendingValidatorsThreshold: Get<Perbill>;
@@ -659,6 +668,52 @@ pub mod pallet {
 	#[pallet::storage]
 	pub(crate) type ChillThreshold<T: Config> = StorageValue<_, Percent, OptionQuery>;
 
+	/// The progress of providing the voters of a paged election to the election provider.
+	///
+	/// Only used if [`ElectionProvider::pages`] of the [`Config::ElectionProvider`] is greater
+	/// than one.
+	#[pallet::storage]
+	#[pallet::unbounded]
+	pub(crate) type VoterSnapshotStatus<T: Config> =
+		StorageValue<_, SnapshotStatus<T::AccountId>, ValueQuery>;
+
+	/// The next page of a paged election result to fetch from the election provider.
+	///
+	/// Only set while the pages of an election result are being fetched ahead of the new era.
+	#[pallet::storage]
+	pub(crate) type NextElectionPage<T: Config> = StorageValue<_, PageIndex, OptionQuery>;
+
+	/// The validators elected by the pages of the election result fetched so far.
+	///
+	/// Their exposures are stored for the era that is planned next as each page is fetched.
+	#[pallet::storage]
+	pub(crate) type ElectableStashes<T: Config> =
+		StorageValue<_, BoundedBTreeSet<T::AccountId, MaxWinnersOf<T>>, ValueQuery>;
+
+	/// The progress of the automatic payout of the rewards of the last ended era.
+	///
+	/// Only set while there are pages left to pay out, see [`Config::MaxAutoPayoutsPerBlock`].
+	#[pallet::storage]
+	pub type AutoPayoutCursor<T: Config> = StorageValue<_, PayoutCursor<T::AccountId>, OptionQuery>;
+
+	/// The ended eras whose automatic payout starts once the one in [`AutoPayoutCursor`] is
+	/// completed, oldest first.
+	///
+	/// Bounded by [`Config::HistoryDepth`], beyond which the rewards can no longer be paid out.
+	#[pallet::storage]
+	pub type AutoPayoutQueue<T: Config> =
+		StorageValue<_, BoundedVec<EraIndex, T::HistoryDepth>, ValueQuery>;
+
+	/// The delegations of funds to agents, keyed by the delegator.
+	///
+	/// See [`sp_staking::DelegatedStakingInterface`].
+	#[pallet::storage]
+	pub type Delegators<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, Delegation<T>>;
+
+	/// The funds delegated to agents, keyed by the agent.
+	#[pallet::storage]
+	pub type Agents<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, AgentLedger<T>>;
+
 	#[pallet::genesis_config]
 	#[derive(frame_support::DefaultNoBound)]
 	pub struct GenesisConfig<T: Config> {
@@ -785,6 +840,16 @@ pub mod pallet {
 		SnapshotTargetsSizeExceeded { size: u32 },
 		/// A new force era mode was set.
 		ForceEra { mode: Forcing },
+		/// Funds of a delegator were delegated to an agent.
+		Delegated { agent: T::AccountId, delegator: T::AccountId, amount: BalanceOf<T> },
+		/// Funds delegated to an agent were released back to the delegator.
+		DelegationReleased { agent: T::AccountId, delegator: T::AccountId, amount: BalanceOf<T> },
+		/// A pending slash of an agent was applied to one of its delegators.
+		DelegatorSlashed { agent: T::AccountId, delegator: T::AccountId, amount: BalanceOf<T> },
+		/// The rewards of an era are being paid out automatically.
+		AutoPayoutStarted { era_index: EraIndex },
+		/// All the rewards of an era have been paid out automatically.
+		AutoPayoutCompleted { era_index: EraIndex },
 	}
 
 	#[pallet::error]
@@ -847,13 +912,22 @@ pub mod pallet {
 		BoundNotMet,
 		/// Used when attempting to use deprecated controller account logic.
 		ControllerDeprecated,
+		/// The account is not an agent that funds are delegated to.
+		NotAgent,
+		/// The account has not delegated funds to the given agent.
+		NotDelegator,
+		/// The account cannot take part in the delegation, e.g. because it is already bonded or
+		/// delegates to a different agent.
+		InvalidDelegation,
+		/// Not enough delegated funds are available for the operation.
+		NotEnoughDelegatedFunds,
 	}
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-		fn on_initialize(_now: BlockNumberFor<T>) -> Weight {
-			// just return the weight of the on_finalize.
-			T::DbWeight::get().reads(1)
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			// the weight of the on_finalize, and of fetching the pages of a paged election result.
+			T::DbWeight::get().reads(1).saturating_add(Self::fetch_next_election_page(now))
 		}
 
 		fn on_finalize(_n: BlockNumberFor<T>) {
@@ -870,6 +944,10 @@ pub mod pallet {
 			// `on_finalize` weight is tracked in `on_initialize`
 		}
 
+		fn on_idle(_now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::do_auto_payout(remaining_weight)
+		}
+
 		fn integrity_test() {
 			// ensure that we funnel the correct value to the `DataProvider::MaxVotesPerVoter`;
 			assert_eq!(
@@ -929,6 +1007,11 @@ pub mod pallet {
 				return Err(Error::<T>::AlreadyBonded.into())
 			}
 
+			// Delegated funds can not be bonded a second time.
+			if Delegators::<T>::contains_key(&stash) || Agents::<T>::contains_key(&stash) {
+				return Err(Error::<T>::InvalidDelegation.into())
+			}
+
 			// Reject a bond which is considered to be _dust_.
 			if value < T::Currency::minimum_balance() {
 				return Err(Error::<T>::InsufficientBond.into())
@@ -1635,7 +1718,7 @@ pub mod pallet {
 			let _ = ensure_signed(origin)?;
 
 			let ed = T::Currency::minimum_balance();
-			let reapable = T::Currency::total_balance(&stash) < ed ||
+			let reapable = Self::stash_balance(&stash) < ed ||
 				Self::ledger(Stash(stash.clone())).map(|l| l.total).unwrap_or_default() < ed;
 			ensure!(reapable, Error::<T>::FundedTarget);
 
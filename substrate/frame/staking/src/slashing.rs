@@ -50,7 +50,7 @@
 //! Based on research at <https://research.web3.foundation/en/latest/polkadot/slashing/npos.html>
 
 use crate::{
-	BalanceOf, Config, Error, Exposure, NegativeImbalanceOf, NominatorSlashInEra,
+	Agents, BalanceOf, Config, Error, Exposure, NegativeImbalanceOf, NominatorSlashInEra,
 	OffendingValidators, Pallet, Perbill, SessionInterface, SpanSlash, UnappliedSlash,
 	ValidatorSlashInEra,
 };
@@ -595,28 +595,38 @@ pub(crate) fn clear_stash_metadata<T: Config>(
 // apply the slash to a stash account, deducting any missing funds from the reward
 // payout, saturating at 0. this is mildly unfair but also an edge-case that
 // can only occur when overlapping locked funds have been slashed.
+//
+// the slash of an agent is only recorded, to be applied to its delegators later on. returns the
+// value recorded this way, the caller is expected to deduct its share from the reward payout.
 pub fn do_slash<T: Config>(
 	stash: &T::AccountId,
 	value: BalanceOf<T>,
 	reward_payout: &mut BalanceOf<T>,
 	slashed_imbalance: &mut NegativeImbalanceOf<T>,
 	slash_era: EraIndex,
-) {
+) -> BalanceOf<T> {
 	let mut ledger =
 		match Pallet::<T>::ledger(sp_staking::StakingAccount::Stash(stash.clone())).defensive() {
 			Ok(ledger) => ledger,
-			Err(_) => return, // nothing to do.
+			Err(_) => return Zero::zero(), // nothing to do.
 		};
 
 	let value = ledger.slash(value, T::Currency::minimum_balance(), slash_era);
+	let mut deferred = Zero::zero();
 
 	if !value.is_zero() {
-		let (imbalance, missing) = T::Currency::slash(stash, value);
-		slashed_imbalance.subsume(imbalance);
+		if let Some(mut agent) = Agents::<T>::get(stash) {
+			agent.pending_slash.saturating_accrue(value);
+			Agents::<T>::insert(stash, agent);
+			deferred = value;
+		} else {
+			let (imbalance, missing) = T::Currency::slash(stash, value);
+			slashed_imbalance.subsume(imbalance);
 
-		if !missing.is_zero() {
-			// deduct overslash from the reward payout
-			*reward_payout = reward_payout.saturating_sub(missing);
+			if !missing.is_zero() {
+				// deduct overslash from the reward payout
+				*reward_payout = reward_payout.saturating_sub(missing);
+			}
 		}
 
 		let _ = ledger
@@ -629,6 +639,8 @@ pub fn do_slash<T: Config>(
 			amount: value,
 		});
 	}
+
+	deferred
 }
 
 /// Apply a previously-unapplied slash.
@@ -638,8 +650,9 @@ pub(crate) fn apply_slash<T: Config>(
 ) {
 	let mut slashed_imbalance = NegativeImbalanceOf::<T>::zero();
 	let mut reward_payout = unapplied_slash.payout;
+	let mut total_slash = unapplied_slash.own;
 
-	do_slash::<T>(
+	let mut deferred = do_slash::<T>(
 		&unapplied_slash.validator,
 		unapplied_slash.own,
 		&mut reward_payout,
@@ -648,15 +661,22 @@ pub(crate) fn apply_slash<T: Config>(
 	);
 
 	for &(ref nominator, nominator_slash) in &unapplied_slash.others {
-		do_slash::<T>(
+		total_slash.saturating_accrue(nominator_slash);
+		deferred.saturating_accrue(do_slash::<T>(
 			nominator,
 			nominator_slash,
 			&mut reward_payout,
 			&mut slashed_imbalance,
 			slash_era,
-		);
+		));
 	}
 
+	// the slashes of agents are applied to their delegators later on, their share of the reward
+	// cannot be paid out of the slashed imbalance.
+	let deferred_payout =
+		Perbill::from_rational(deferred, total_slash).mul_floor(unapplied_slash.payout);
+	reward_payout = reward_payout.saturating_sub(deferred_payout);
+
 	pay_reporters::<T>(reward_payout, slashed_imbalance, &unapplied_slash.reporters);
 }
 
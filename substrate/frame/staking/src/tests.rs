@@ -5152,6 +5152,31 @@ mod election_data_provider {
 			});
 	}
 
+	#[test]
+	fn voters_can_be_fetched_in_pages() {
+		ExtBuilder::default()
+			.set_status(41, StakerStatus::Validator)
+			.build_and_execute(|| {
+				assert_eq!(<Test as Config>::VoterList::count(), 5);
+				let all_voters = Staking::electing_voters(DataProviderBounds::default()).unwrap();
+				let bounds = ElectionBoundsBuilder::default().voters_count(2.into()).build().voters;
+
+				// each page continues right after the last voter of the previous one.
+				let (first, status) = Staking::get_npos_voters_from(bounds, None);
+				assert_eq!(first, all_voters[..2].to_vec());
+				assert_eq!(status, SnapshotStatus::Ongoing(all_voters[1].0));
+
+				let (second, status) = Staking::get_npos_voters_from(bounds, Some(all_voters[1].0));
+				assert_eq!(second, all_voters[2..4].to_vec());
+				assert_eq!(status, SnapshotStatus::Ongoing(all_voters[3].0));
+
+				// the list is exhausted with the last page.
+				let (last, status) = Staking::get_npos_voters_from(bounds, Some(all_voters[3].0));
+				assert_eq!(last, all_voters[4..].to_vec());
+				assert_eq!(status, SnapshotStatus::Consumed);
+			});
+	}
+
 	#[test]
 	fn respects_snapshot_count_limits() {
 		ExtBuilder::default()
@@ -6408,6 +6433,47 @@ fn can_page_exposure() {
 	assert_eq!(exposure_metadata.nominator_count, 19);
 }
 
+#[test]
+fn exposures_of_a_paged_election_are_upserted() {
+	ExtBuilder::default().build_and_execute(|| {
+		MaxExposurePageSize::set(2);
+		let individual = |who, value| IndividualExposure { who, value };
+
+		// validator 11 is backed by the voters of two pages of an election result.
+		EraInfo::<Test>::upsert_exposure(
+			5,
+			&11,
+			Exposure { total: 1_100, own: 1_000, others: vec![individual(101, 100)] },
+		);
+		EraInfo::<Test>::upsert_exposure(
+			5,
+			&11,
+			Exposure {
+				total: 700,
+				own: 0,
+				others: vec![individual(103, 300), individual(104, 400)],
+			},
+		);
+
+		// the last exposure page is filled up before a new one is added.
+		assert_eq!(
+			ErasStakersOverview::<Test>::get(5, &11).unwrap(),
+			PagedExposureMetadata { total: 1_800, own: 1_000, nominator_count: 3, page_count: 2 }
+		);
+		assert_eq!(
+			ErasStakersPaged::<Test>::get((5, &11, 0)).unwrap(),
+			ExposurePage {
+				page_total: 400,
+				others: vec![individual(101, 100), individual(103, 300)]
+			}
+		);
+		assert_eq!(
+			ErasStakersPaged::<Test>::get((5, &11, 1)).unwrap(),
+			ExposurePage { page_total: 400, others: vec![individual(104, 400)] }
+		);
+	});
+}
+
 #[test]
 fn should_retain_era_info_only_upto_history_depth() {
 	ExtBuilder::default().build_and_execute(|| {
@@ -7059,3 +7125,294 @@ mod ledger {
 		})
 	}
 }
+
+mod delegated_staking {
+	use super::*;
+	use frame_support::traits::Imbalance;
+	use sp_staking::{DelegatedStakingInterface, StakingInterface};
+
+	const AGENT: AccountId = 200;
+	const REWARD_ACCOUNT: AccountId = 201;
+
+	#[test]
+	fn delegated_funds_are_bonded_and_released() {
+		ExtBuilder::default().build_and_execute(|| {
+			assert_ok!(<Staking as DelegatedStakingInterface>::delegate(
+				&61,
+				&AGENT,
+				&REWARD_ACCOUNT,
+				500
+			));
+			assert_ok!(<Staking as DelegatedStakingInterface>::delegate(
+				&71,
+				&AGENT,
+				&REWARD_ACCOUNT,
+				300
+			));
+
+			// the agent is bonded with all delegated funds, which stay with the delegators.
+			assert_eq!(Staking::ledger(AGENT.into()).unwrap().active, 800);
+			assert_eq!(Staking::payee(AGENT.into()), RewardDestination::Account(REWARD_ACCOUNT));
+			assert_eq!(Balances::free_balance(AGENT), 0);
+			assert_eq!(Balances::free_balance(61), 2000);
+			assert_eq!(Balances::locks(61)[0].amount, 500);
+			assert_eq!(<Staking as DelegatedStakingInterface>::agent_balance(&AGENT), 800);
+
+			// a delegator can not delegate to another agent, nor bond the funds itself.
+			assert_noop!(
+				<Staking as DelegatedStakingInterface>::delegate(&61, &202, &REWARD_ACCOUNT, 100),
+				Error::<Test>::InvalidDelegation
+			);
+			assert_noop!(
+				Staking::bond(RuntimeOrigin::signed(61), 100, RewardDestination::Staked),
+				Error::<Test>::InvalidDelegation
+			);
+
+			// bonded funds can not be released.
+			assert_noop!(
+				<Staking as DelegatedStakingInterface>::withdraw_delegation(&61, &AGENT, 100),
+				Error::<Test>::NotEnoughDelegatedFunds
+			);
+
+			assert_ok!(<Staking as StakingInterface>::unbond(&AGENT, 500));
+			start_active_era(BondingDuration::get());
+			assert_ok!(<Staking as StakingInterface>::withdraw_unbonded(AGENT, 0));
+
+			assert_ok!(<Staking as DelegatedStakingInterface>::withdraw_delegation(
+				&61, &AGENT, 500
+			));
+			assert!(Delegators::<Test>::get(61).is_none());
+			assert!(Balances::locks(61).is_empty());
+			assert_eq!(Agents::<Test>::get(AGENT).unwrap().total_delegated, 300);
+		});
+	}
+
+	#[test]
+	fn agent_slashes_are_applied_to_delegators() {
+		ExtBuilder::default().build_and_execute(|| {
+			assert_ok!(<Staking as DelegatedStakingInterface>::delegate(
+				&61,
+				&AGENT,
+				&REWARD_ACCOUNT,
+				500
+			));
+			assert_ok!(<Staking as DelegatedStakingInterface>::delegate(
+				&71,
+				&AGENT,
+				&REWARD_ACCOUNT,
+				500
+			));
+
+			let mut reward_payout = 0;
+			let mut slashed_imbalance = NegativeImbalanceOf::<Test>::zero();
+			assert_eq!(
+				slashing::do_slash::<Test>(
+					&AGENT,
+					100,
+					&mut reward_payout,
+					&mut slashed_imbalance,
+					0
+				),
+				100
+			);
+
+			// the slash is only recorded, the funds of the delegators are untouched.
+			assert_eq!(Staking::ledger(AGENT.into()).unwrap().active, 900);
+			assert_eq!(slashed_imbalance.peek(), 0);
+			assert_eq!(<Staking as DelegatedStakingInterface>::pending_slash(&AGENT), 100);
+			assert_eq!(<Staking as DelegatedStakingInterface>::agent_balance(&AGENT), 900);
+			assert_eq!(Balances::free_balance(61), 2000);
+
+			assert_ok!(<Staking as DelegatedStakingInterface>::delegator_slash(&AGENT, &61, 50));
+			assert_eq!(Balances::free_balance(61), 1950);
+			assert_eq!(<Staking as DelegatedStakingInterface>::delegator_balance(&61), 450);
+			assert_eq!(<Staking as DelegatedStakingInterface>::pending_slash(&AGENT), 50);
+
+			// a delegator can not be slashed more than the pending slash.
+			assert_noop!(
+				<Staking as DelegatedStakingInterface>::delegator_slash(&AGENT, &71, 60),
+				Error::<Test>::NotEnoughDelegatedFunds
+			);
+			assert_ok!(<Staking as DelegatedStakingInterface>::delegator_slash(&AGENT, &71, 50));
+			assert_eq!(<Staking as DelegatedStakingInterface>::pending_slash(&AGENT), 0);
+			assert_eq!(<Staking as DelegatedStakingInterface>::agent_balance(&AGENT), 900);
+		});
+	}
+
+	#[test]
+	fn only_the_agent_share_of_the_reward_is_withheld() {
+		ExtBuilder::default().build_and_execute(|| {
+			assert_ok!(<Staking as DelegatedStakingInterface>::delegate(
+				&61,
+				&AGENT,
+				&REWARD_ACCOUNT,
+				1000
+			));
+			let reporter_balance = Balances::free_balance(1);
+
+			// half of the slash is recorded for the agent, the other half slashed from 11.
+			slashing::apply_slash::<Test>(
+				UnappliedSlash {
+					validator: 11,
+					own: 100,
+					others: vec![(AGENT, 100)],
+					reporters: vec![1],
+					payout: 20,
+				},
+				0,
+			);
+			assert_eq!(<Staking as DelegatedStakingInterface>::pending_slash(&AGENT), 100);
+
+			// the reporter is paid the share of the slash of 11.
+			assert_eq!(Balances::free_balance(1), reporter_balance + 10);
+		});
+	}
+}
+
+mod auto_payout {
+	use super::*;
+
+	fn claimed_validators(era: EraIndex) -> usize {
+		ClaimedRewards::<Test>::iter_prefix(era).count()
+	}
+
+	fn on_idle(remaining_weight: Weight) -> Weight {
+		<Staking as Hooks<u64>>::on_idle(System::block_number(), remaining_weight)
+	}
+
+	#[test]
+	fn rewards_are_paid_out_in_on_idle() {
+		ExtBuilder::default().build_and_execute(|| {
+			MaxAutoPayoutsPerBlock::set(1);
+			mock::start_active_era(1);
+			reward_all_elected();
+			mock::start_active_era(2);
+
+			assert_eq!(
+				AutoPayoutCursor::<Test>::get(),
+				Some(PayoutCursor { era: 1, validator: None })
+			);
+			assert!(staking_events_since_last_call()
+				.contains(&Event::AutoPayoutStarted { era_index: 1 }));
+			assert_eq!(claimed_validators(1), 0);
+
+			// the first validator is found, and its only page paid out.
+			let consumed = on_idle(Weight::MAX);
+			assert!(!consumed.is_zero());
+			assert_eq!(claimed_validators(1), 1);
+			let first = AutoPayoutCursor::<Test>::get().unwrap().validator.unwrap();
+			assert!(matches!(
+				staking_events_since_last_call().as_slice(),
+				&[Event::PayoutStarted { era_index: 1, .. }, .., Event::Rewarded { .. }]
+			));
+
+			// the second one is paid out in the next block.
+			on_idle(Weight::MAX);
+			assert_eq!(claimed_validators(1), 2);
+			assert_ne!(AutoPayoutCursor::<Test>::get().unwrap().validator.unwrap(), first);
+
+			// there is nothing left to pay out.
+			on_idle(Weight::MAX);
+			assert_eq!(AutoPayoutCursor::<Test>::get(), None);
+			assert_eq!(
+				staking_events_since_last_call(),
+				vec![Event::AutoPayoutCompleted { era_index: 1 }]
+			);
+			let err_weight = <Test as Config>::WeightInfo::payout_stakers_alive_staked(0);
+			assert_noop!(
+				Staking::payout_stakers(RuntimeOrigin::signed(1337), 11, 1),
+				Error::<Test>::AlreadyClaimed.with_weight(err_weight)
+			);
+
+			// nothing happens until the next era ends.
+			assert_eq!(
+				on_idle(Weight::MAX),
+				<Test as frame_system::Config>::DbWeight::get().reads(1)
+			);
+		});
+	}
+
+	#[test]
+	fn claimed_pages_are_skipped() {
+		ExtBuilder::default().build_and_execute(|| {
+			MaxAutoPayoutsPerBlock::set(10);
+			mock::start_active_era(1);
+			reward_all_elected();
+			mock::start_active_era(2);
+
+			assert_ok!(Staking::payout_stakers(RuntimeOrigin::signed(1337), 11, 1));
+			let _ = staking_events_since_last_call();
+
+			// only 21 is left to be paid out, which is done in a single block.
+			on_idle(Weight::MAX);
+			let events = staking_events_since_last_call();
+			assert!(events.contains(&Event::PayoutStarted { era_index: 1, validator_stash: 21 }));
+			assert!(!events.contains(&Event::PayoutStarted { era_index: 1, validator_stash: 11 }));
+			assert_eq!(events.last(), Some(&Event::AutoPayoutCompleted { era_index: 1 }));
+			assert_eq!(claimed_validators(1), 2);
+		});
+	}
+
+	#[test]
+	fn unfinished_payout_is_not_dropped_by_the_next_era() {
+		ExtBuilder::default().build_and_execute(|| {
+			MaxAutoPayoutsPerBlock::set(10);
+			mock::start_active_era(1);
+			reward_all_elected();
+			mock::start_active_era(2);
+			reward_all_elected();
+			mock::start_active_era(3);
+
+			// the payout of era 1 is kept, era 2 waits for it.
+			assert_eq!(
+				AutoPayoutCursor::<Test>::get(),
+				Some(PayoutCursor { era: 1, validator: None })
+			);
+			assert_eq!(AutoPayoutQueue::<Test>::get().into_inner(), vec![2]);
+			let events = staking_events_since_last_call();
+			assert!(!events.contains(&Event::AutoPayoutStarted { era_index: 2 }));
+
+			// both eras are paid out, one after the other.
+			on_idle(Weight::MAX);
+			assert_eq!(claimed_validators(1), 2);
+			assert_eq!(claimed_validators(2), 2);
+			assert_eq!(AutoPayoutCursor::<Test>::get(), None);
+			assert!(AutoPayoutQueue::<Test>::get().is_empty());
+			let events = staking_events_since_last_call();
+			assert!(events.contains(&Event::AutoPayoutCompleted { era_index: 1 }));
+			assert!(events.contains(&Event::AutoPayoutStarted { era_index: 2 }));
+			assert_eq!(events.last(), Some(&Event::AutoPayoutCompleted { era_index: 2 }));
+		});
+	}
+
+	#[test]
+	fn auto_payout_respects_limits() {
+		ExtBuilder::default().build_and_execute(|| {
+			mock::start_active_era(1);
+			reward_all_elected();
+			mock::start_active_era(2);
+
+			// disabled by default.
+			assert_eq!(AutoPayoutCursor::<Test>::get(), None);
+			assert_eq!(on_idle(Weight::MAX), Weight::zero());
+
+			MaxAutoPayoutsPerBlock::set(10);
+			mock::start_active_era(3);
+			assert_eq!(
+				AutoPayoutCursor::<Test>::get(),
+				Some(PayoutCursor { era: 2, validator: None })
+			);
+
+			// not enough weight left for a single page.
+			let page_weight = <Test as Config>::WeightInfo::payout_stakers_alive_staked(
+				MaxExposurePageSize::get(),
+			);
+			assert_eq!(on_idle(page_weight), Weight::zero());
+			assert_eq!(claimed_validators(2), 0);
+			assert_eq!(
+				AutoPayoutCursor::<Test>::get(),
+				Some(PayoutCursor { era: 2, validator: None })
+			);
+		});
+	}
+}
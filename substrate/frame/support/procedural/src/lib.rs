@@ -30,6 +30,7 @@ mod no_bound;
 mod pallet;
 mod pallet_error;
 mod storage_alias;
+mod storage_migration;
 mod transactional;
 mod tt_macro;
 
@@ -553,6 +554,13 @@ pub fn storage_alias(attributes: TokenStream, input: TokenStream) -> TokenStream
 		.into()
 }
 
+#[proc_macro_attribute]
+pub fn storage_migration(attributes: TokenStream, input: TokenStream) -> TokenStream {
+	storage_migration::storage_migration(attributes.into(), input.into())
+		.unwrap_or_else(|r| r.into_compile_error())
+		.into()
+}
+
 /// This attribute can be used to derive a full implementation of a trait based on a local partial
 /// impl and an external impl containing defaults that can be overriden in the local impl.
 ///
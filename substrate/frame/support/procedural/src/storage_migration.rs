@@ -0,0 +1,140 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Implementation of the `storage_migration` attribute macro.
+
+use frame_support_procedural_tools::generate_access_from_frame_or_crate;
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{
+	parse::{Parse, ParseStream},
+	spanned::Spanned,
+	Error, GenericParam, Ident, ItemType, Result, Token, Type,
+};
+
+mod keyword {
+	syn::custom_keyword!(to);
+	syn::custom_keyword!(db_weight);
+	syn::custom_keyword!(name);
+}
+
+/// The arguments of the attribute: `to = $type, db_weight = $type` and optionally
+/// `name = $ident`.
+struct Args {
+	to: Type,
+	db_weight: Type,
+	name: Option<Ident>,
+}
+
+impl Parse for Args {
+	fn parse(input: ParseStream) -> Result<Self> {
+		let (mut to, mut db_weight, mut name) = (None, None, None);
+
+		while !input.is_empty() {
+			let lookahead = input.lookahead1();
+			let (span, duplicate) = if lookahead.peek(keyword::to) {
+				let keyword = input.parse::<keyword::to>()?;
+				input.parse::<Token![=]>()?;
+				(keyword.span, to.replace(input.parse()?).is_some())
+			} else if lookahead.peek(keyword::db_weight) {
+				let keyword = input.parse::<keyword::db_weight>()?;
+				input.parse::<Token![=]>()?;
+				(keyword.span, db_weight.replace(input.parse()?).is_some())
+			} else if lookahead.peek(keyword::name) {
+				let keyword = input.parse::<keyword::name>()?;
+				input.parse::<Token![=]>()?;
+				(keyword.span, name.replace(input.parse()?).is_some())
+			} else {
+				return Err(lookahead.error())
+			};
+			if duplicate {
+				return Err(Error::new(span, "Argument given twice"))
+			}
+
+			if !input.is_empty() {
+				input.parse::<Token![,]>()?;
+			}
+		}
+
+		let missing = |arg| Error::new(Span::call_site(), format!("Missing `{} = ...`", arg));
+		Ok(Self {
+			to: to.ok_or_else(|| missing("to"))?,
+			db_weight: db_weight.ok_or_else(|| missing("db_weight"))?,
+			name,
+		})
+	}
+}
+
+/// Implementation of the `storage_migration` attribute macro.
+///
+/// The input is kept as it is, and a type alias of the migration from the annotated storage alias
+/// to the `to` storage type is declared next to it.
+pub fn storage_migration(attributes: TokenStream, input: TokenStream) -> Result<TokenStream> {
+	let args = syn::parse2::<Args>(attributes)?;
+	let item = syn::parse2::<ItemType>(input.clone()).map_err(|e| {
+		Error::new(
+			e.span(),
+			"`storage_migration` must be put on the type alias of the old storage item, above \
+			`storage_alias`",
+		)
+	})?;
+	let crate_ = generate_access_from_frame_or_crate("frame-support")?;
+
+	let storage = match &*item.ty {
+		Type::Path(path) => path.path.segments.last().map(|segment| segment.ident.to_string()),
+		_ => None,
+	};
+	let migration = match storage.as_deref() {
+		Some("StorageDoubleMap") => quote!(MigrateStorageDoubleMap),
+		Some("StorageNMap") => quote!(MigrateStorageNMap),
+		_ =>
+			return Err(Error::new(
+				item.ty.span(),
+				"Only `StorageDoubleMap` and `StorageNMap` can be migrated",
+			)),
+	};
+
+	let vis = &item.vis;
+	let ident = &item.ident;
+	let name = args.name.unwrap_or_else(|| format_ident!("Migrate{}", ident));
+	// Bounds are not enforced in type aliases, so only the parameters are declared.
+	let params = item.generics.params.iter().map(|param| match param {
+		GenericParam::Type(param) => {
+			let ident = &param.ident;
+			quote!(#ident)
+		},
+		GenericParam::Lifetime(param) => {
+			let lifetime = &param.lifetime;
+			quote!(#lifetime)
+		},
+		GenericParam::Const(param) => {
+			let (ident, ty) = (&param.ident, &param.ty);
+			quote!(const #ident: #ty)
+		},
+	});
+	let (_, type_generics, _) = item.generics.split_for_impl();
+	let (to, db_weight) = (&args.to, &args.db_weight);
+	let doc = format!(" Migrates all entries of [`{}`] to their new storage layout.", ident);
+
+	Ok(quote! {
+		#input
+
+		#[doc = #doc]
+		#vis type #name<#( #params ),*> =
+			#crate_::migrations::#migration<#ident #type_generics, #to, #db_weight>;
+	})
+}
@@ -173,6 +173,53 @@ impl TypeId for PalletId {
 #[doc = docify::embed!("src/tests/storage_alias.rs", storage_alias_guess)]
 pub use frame_support_procedural::storage_alias;
 
+/// Generate a migration of all entries of a storage item to a new layout.
+///
+/// The attribute is put on a [`storage_alias`] describing the layout of a
+/// [`StorageDoubleMap`](storage::types::StorageDoubleMap) or
+/// [`StorageNMap`](storage::types::StorageNMap) as currently found on chain, above the
+/// `storage_alias` attribute. It declares, next to the alias, a type alias of the
+/// [`MigrateStorageDoubleMap`](migrations::MigrateStorageDoubleMap) or
+/// [`MigrateStorageNMap`](migrations::MigrateStorageNMap) migration to the storage type given
+/// as `to`, which can then be wrapped in a
+/// [`VersionedMigration`](migrations::VersionedMigration).
+///
+/// The migration can change the hashers and key types, and the value type through [`From`].
+/// With the `try-runtime` feature, it checks that the number of entries is the same before and
+/// after.
+///
+/// The attribute takes the following arguments:
+/// - `to`: The storage type to migrate the entries to, usually the one declared by the pallet.
+/// - `db_weight`: The [`RuntimeDbWeight`](weights::RuntimeDbWeight) used to weigh the
+///   migration.
+/// - `name` (optional): The name of the migration, `Migrate` followed by the name of the alias
+///   by default.
+///
+/// # Example
+///
+/// ```ignore
+/// mod v0 {
+/// 	use super::*;
+///
+/// 	#[frame_support::storage_migration(
+/// 		to = crate::Approvals<T>,
+/// 		db_weight = <T as frame_system::Config>::DbWeight,
+/// 	)]
+/// 	#[frame_support::storage_alias]
+/// 	pub type Approvals<T: Config> =
+/// 		StorageDoubleMap<Pallet<T>, Twox64Concat, AccountIdOf<T>, Twox64Concat, u32, OldApproval>;
+/// }
+///
+/// pub type MigrateV0ToV1<T> = VersionedMigration<
+/// 	0,
+/// 	1,
+/// 	v0::MigrateApprovals<T>,
+/// 	crate::pallet::Pallet<T>,
+/// 	<T as frame_system::Config>::DbWeight,
+/// >;
+/// ```
+pub use frame_support_procedural::storage_migration;
+
 pub use frame_support_procedural::derive_impl;
 
 /// Create new implementations of the [`Get`](crate::traits::Get) trait.
@@ -16,13 +16,21 @@
 // limitations under the License.
 
 use crate::{
-	traits::{GetStorageVersion, NoStorageVersionSet, PalletInfoAccess, StorageVersion},
+	hash::{ReversibleStorageHasher, StorageHasher},
+	storage::types::{
+		EncodeLikeTuple, KeyGenerator, QueryKindTrait, ReversibleKeyGenerator, StorageDoubleMap,
+		StorageNMap, TupleToEncodedIter,
+	},
+	traits::{
+		GetStorageVersion, NoStorageVersionSet, PalletInfoAccess, StorageInstance, StorageVersion,
+	},
 	weights::{RuntimeDbWeight, Weight},
 };
+use codec::{EncodeLike, FullCodec};
 use impl_trait_for_tuples::impl_for_tuples;
 use sp_core::Get;
 use sp_io::{hashing::twox_128, storage::clear_prefix, KillStorageResult};
-use sp_std::marker::PhantomData;
+use sp_std::{marker::PhantomData, vec::Vec};
 
 /// Handles storage migration pallet versioning.
 ///
@@ -344,3 +352,264 @@ impl<P: Get<&'static str>, DbWeight: Get<RuntimeDbWeight>> frame_support::traits
 		Ok(())
 	}
 }
+
+/// Migrates all entries of a [`StorageDoubleMap`] from the `Old` storage type to the `New` one.
+///
+/// `Old` and `New` are typically two aliases of the same storage item, where `Old` describes the
+/// layout currently found on chain and `New` the one declared by the pallet. The migration
+/// supports:
+/// - Changing the hashers of the keys. The hashers of `Old` must be reversible, so that the keys
+///   can be decoded from the storage.
+/// - Changing the key types, as long as the old keys encode like the new ones.
+/// - Changing the value type, with the new value being created from the old one using [`From`].
+/// - Moving the entries to a different storage prefix.
+///
+/// All entries are read into memory and written back in a single block, so this migration is only
+/// suitable for maps with a bounded number of entries.
+///
+/// With the `try-runtime` feature enabled, the migration checks that the number of entries is the
+/// same before and after the migration.
+///
+/// The migration is usually declared with the [`storage_migration`](crate::storage_migration)
+/// attribute on the alias of the old storage item, rather than spelled out as below.
+///
+/// ### Examples
+/// ```ignore
+/// #[frame_support::storage_alias]
+/// type Approvals<T: Config> =
+/// 	StorageDoubleMap<Pallet<T>, Twox64Concat, AccountIdOf<T>, Twox64Concat, u32, OldApproval>;
+///
+/// pub type MigrateV1ToV2<T> = VersionedMigration<
+/// 	1,
+/// 	2,
+/// 	MigrateStorageDoubleMap<Approvals<T>, crate::Approvals<T>, <T as frame_system::Config>::DbWeight>,
+/// 	crate::pallet::Pallet<T>,
+/// 	<T as frame_system::Config>::DbWeight,
+/// >;
+/// ```
+pub struct MigrateStorageDoubleMap<Old, New, DbWeight>(PhantomData<(Old, New, DbWeight)>);
+
+impl<
+		OldPrefix: StorageInstance,
+		OldHasher1: StorageHasher + ReversibleStorageHasher,
+		OldKey1: FullCodec + EncodeLike<NewKey1>,
+		OldHasher2: StorageHasher + ReversibleStorageHasher,
+		OldKey2: FullCodec + EncodeLike<NewKey2>,
+		OldValue: FullCodec,
+		OldQueryKind: QueryKindTrait<OldValue, OldOnEmpty>,
+		OldOnEmpty: Get<OldQueryKind::Query> + 'static,
+		OldMaxValues: Get<Option<u32>>,
+		NewPrefix: StorageInstance,
+		NewHasher1: StorageHasher,
+		NewKey1: FullCodec,
+		NewHasher2: StorageHasher,
+		NewKey2: FullCodec,
+		NewValue: FullCodec + From<OldValue>,
+		NewQueryKind: QueryKindTrait<NewValue, NewOnEmpty>,
+		NewOnEmpty: Get<NewQueryKind::Query> + 'static,
+		NewMaxValues: Get<Option<u32>>,
+		DbWeight: Get<RuntimeDbWeight>,
+	> crate::traits::OnRuntimeUpgrade
+	for MigrateStorageDoubleMap<
+		StorageDoubleMap<
+			OldPrefix,
+			OldHasher1,
+			OldKey1,
+			OldHasher2,
+			OldKey2,
+			OldValue,
+			OldQueryKind,
+			OldOnEmpty,
+			OldMaxValues,
+		>,
+		StorageDoubleMap<
+			NewPrefix,
+			NewHasher1,
+			NewKey1,
+			NewHasher2,
+			NewKey2,
+			NewValue,
+			NewQueryKind,
+			NewOnEmpty,
+			NewMaxValues,
+		>,
+		DbWeight,
+	>
+{
+	fn on_runtime_upgrade() -> Weight {
+		let entries = StorageDoubleMap::<
+			OldPrefix,
+			OldHasher1,
+			OldKey1,
+			OldHasher2,
+			OldKey2,
+			OldValue,
+			OldQueryKind,
+			OldOnEmpty,
+			OldMaxValues,
+		>::drain()
+		.collect::<Vec<_>>();
+		let migrated = entries.len() as u64;
+
+		for (key1, key2, value) in entries {
+			StorageDoubleMap::<
+				NewPrefix,
+				NewHasher1,
+				NewKey1,
+				NewHasher2,
+				NewKey2,
+				NewValue,
+				NewQueryKind,
+				NewOnEmpty,
+				NewMaxValues,
+			>::insert(key1, key2, NewValue::from(value));
+		}
+
+		log::info!(
+			"🚚 Migrated {} entries of {}::{}",
+			migrated,
+			NewPrefix::pallet_prefix(),
+			NewPrefix::STORAGE_PREFIX
+		);
+
+		DbWeight::get().reads_writes(migrated, migrated.saturating_mul(2))
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+		pre_upgrade_entry_count::<OldPrefix>()
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+		post_upgrade_entry_count::<OldPrefix, NewPrefix>(state)
+	}
+}
+
+/// Migrates all entries of a [`StorageNMap`] from the `Old` storage type to the `New` one.
+///
+/// This is the [`StorageNMap`] equivalent of [`MigrateStorageDoubleMap`], refer to its
+/// documentation for the supported changes and the caveats. The decoded old key tuple must encode
+/// like the key arguments of `New`.
+///
+/// ### Examples
+/// ```ignore
+/// #[frame_support::storage_alias]
+/// type Votes<T: Config> = StorageNMap<
+/// 	Pallet<T>,
+/// 	(NMapKey<Twox64Concat, u32>, NMapKey<Twox64Concat, AccountIdOf<T>>),
+/// 	u32,
+/// >;
+///
+/// pub type MigrateV1ToV2<T> = VersionedMigration<
+/// 	1,
+/// 	2,
+/// 	MigrateStorageNMap<Votes<T>, crate::Votes<T>, <T as frame_system::Config>::DbWeight>,
+/// 	crate::pallet::Pallet<T>,
+/// 	<T as frame_system::Config>::DbWeight,
+/// >;
+/// ```
+pub struct MigrateStorageNMap<Old, New, DbWeight>(PhantomData<(Old, New, DbWeight)>);
+
+impl<
+		OldPrefix: StorageInstance,
+		OldKey: ReversibleKeyGenerator,
+		OldValue: FullCodec,
+		OldQueryKind: QueryKindTrait<OldValue, OldOnEmpty>,
+		OldOnEmpty: Get<OldQueryKind::Query> + 'static,
+		OldMaxValues: Get<Option<u32>>,
+		NewPrefix: StorageInstance,
+		NewKey: KeyGenerator,
+		NewValue: FullCodec + From<OldValue>,
+		NewQueryKind: QueryKindTrait<NewValue, NewOnEmpty>,
+		NewOnEmpty: Get<NewQueryKind::Query> + 'static,
+		NewMaxValues: Get<Option<u32>>,
+		DbWeight: Get<RuntimeDbWeight>,
+	> crate::traits::OnRuntimeUpgrade
+	for MigrateStorageNMap<
+		StorageNMap<OldPrefix, OldKey, OldValue, OldQueryKind, OldOnEmpty, OldMaxValues>,
+		StorageNMap<NewPrefix, NewKey, NewValue, NewQueryKind, NewOnEmpty, NewMaxValues>,
+		DbWeight,
+	>
+where
+	OldKey::Key: EncodeLikeTuple<NewKey::KArg> + TupleToEncodedIter,
+{
+	fn on_runtime_upgrade() -> Weight {
+		let entries = StorageNMap::<
+			OldPrefix,
+			OldKey,
+			OldValue,
+			OldQueryKind,
+			OldOnEmpty,
+			OldMaxValues,
+		>::drain()
+		.collect::<Vec<_>>();
+		let migrated = entries.len() as u64;
+
+		for (key, value) in entries {
+			StorageNMap::<
+				NewPrefix,
+				NewKey,
+				NewValue,
+				NewQueryKind,
+				NewOnEmpty,
+				NewMaxValues,
+			>::insert(key, NewValue::from(value));
+		}
+
+		log::info!(
+			"🚚 Migrated {} entries of {}::{}",
+			migrated,
+			NewPrefix::pallet_prefix(),
+			NewPrefix::STORAGE_PREFIX
+		);
+
+		DbWeight::get().reads_writes(migrated, migrated.saturating_mul(2))
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+		pre_upgrade_entry_count::<OldPrefix>()
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+		post_upgrade_entry_count::<OldPrefix, NewPrefix>(state)
+	}
+}
+
+/// Count the number of keys stored under the storage item `Prefix`.
+#[cfg(feature = "try-runtime")]
+fn entry_count<Prefix: StorageInstance>() -> u32 {
+	let prefix = Prefix::prefix_hash().to_vec();
+	crate::storage::KeyPrefixIterator::new(prefix.clone(), prefix, |_| Ok(())).count() as u32
+}
+
+/// Encode the number of entries of `OldPrefix` to be checked after the migration.
+#[cfg(feature = "try-runtime")]
+fn pre_upgrade_entry_count<OldPrefix: StorageInstance>(
+) -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+	use codec::Encode;
+	Ok(entry_count::<OldPrefix>().encode())
+}
+
+/// Check that `NewPrefix` holds as many entries as `OldPrefix` did before the migration, and that
+/// no entries are left behind if the entries were moved to a different prefix.
+#[cfg(feature = "try-runtime")]
+fn post_upgrade_entry_count<OldPrefix: StorageInstance, NewPrefix: StorageInstance>(
+	state: Vec<u8>,
+) -> Result<(), sp_runtime::TryRuntimeError> {
+	use codec::Decode;
+	let expected = u32::decode(&mut &state[..])
+		.map_err(|_| "Failed to decode the number of entries before the migration")?;
+
+	if OldPrefix::prefix_hash() != NewPrefix::prefix_hash() && entry_count::<OldPrefix>() != 0 {
+		return Err("Entries of the old storage item remain after the migration".into())
+	}
+	let migrated = entry_count::<NewPrefix>();
+	if migrated != expected {
+		log::error!("Expected {} entries after the migration, found {}", expected, migrated);
+		return Err("The number of entries changed during the migration".into())
+	}
+	Ok(())
+}
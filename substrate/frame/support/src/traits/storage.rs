@@ -242,6 +242,23 @@ impl<A> Consideration<A> for () {
 	}
 }
 
+/// Something which holds a deposit from an account for some item in storage and which is able to
+/// recalculate it according to the current deposit parameters.
+///
+/// Deposits are generally calculated once, when the item is created. Should the parameters of the
+/// calculation change later on (e.g. through governance), existing deposits stay at their
+/// historical value until they are poked.
+pub trait PokeDeposit<AccountId> {
+	/// Identifies the item for which the deposit is held.
+	type Key;
+
+	/// Recalculate the deposit held from `who` for the item identified by `key`.
+	///
+	/// Any increase of the deposit is taken from `who` and any excess is returned to them. Returns
+	/// `true` if the deposit has changed.
+	fn poke_deposit(who: &AccountId, key: Self::Key) -> Result<bool, DispatchError>;
+}
+
 macro_rules! impl_incrementable {
 	($($type:ty),+) => {
 		$(
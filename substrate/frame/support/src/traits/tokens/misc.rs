@@ -283,8 +283,8 @@ pub trait ConversionFromAssetBalance<AssetBalance, AssetId, OutBalance> {
 	fn ensure_successful(asset_id: AssetId);
 }
 
-/// Implements [`ConversionFromAssetBalance`], enabling a 1:1 conversion of the asset balance
-/// value to the balance.
+/// Implements [`ConversionFromAssetBalance`] and [`ConversionToAssetBalance`], enabling a 1:1
+/// conversion between the asset balance value and the balance.
 pub struct UnityAssetBalanceConversion;
 impl<AssetBalance, AssetId, OutBalance>
 	ConversionFromAssetBalance<AssetBalance, AssetId, OutBalance> for UnityAssetBalanceConversion
@@ -298,6 +298,16 @@ where
 	#[cfg(feature = "runtime-benchmarks")]
 	fn ensure_successful(_: AssetId) {}
 }
+impl<InBalance, AssetId, AssetBalance> ConversionToAssetBalance<InBalance, AssetId, AssetBalance>
+	for UnityAssetBalanceConversion
+where
+	InBalance: Into<AssetBalance>,
+{
+	type Error = ();
+	fn to_asset_balance(balance: InBalance, _: AssetId) -> Result<AssetBalance, Self::Error> {
+		Ok(balance.into())
+	}
+}
 
 /// Trait to handle NFT locking mechanism to ensure interactions with the asset can be implemented
 /// downstream to extend logic of Uniques/Nfts current functionality.
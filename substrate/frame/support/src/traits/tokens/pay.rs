@@ -21,7 +21,7 @@ use codec::{Decode, Encode, FullCodec, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_core::{RuntimeDebug, TypedGet};
 use sp_runtime::DispatchError;
-use sp_std::fmt::Debug;
+use sp_std::{fmt::Debug, vec::Vec};
 
 use super::{fungible, fungibles, Balance, Preservation::Expendable};
 
@@ -53,6 +53,22 @@ pub trait Pay {
 	/// `InProgress` for some `id` it must return `Unknown` rather than the actual result
 	/// value.
 	fn check_payment(id: Self::Id) -> PaymentStatus;
+	/// Make several `payments` to `who` at once and return a single identifier for all of them,
+	/// whose status is then that of every one of the payments.
+	///
+	/// Returns `None`, having paid nothing, if the payments cannot be made at once. By default
+	/// only a single payment can. If `Some` error is returned, some of the payments might have
+	/// been made and it is up to the caller to revert the changes.
+	fn pay_batch(
+		who: &Self::Beneficiary,
+		payments: Vec<(Self::AssetKind, Self::Balance)>,
+	) -> Option<Result<Self::Id, Self::Error>> {
+		let mut payments = payments.into_iter();
+		match (payments.next(), payments.next()) {
+			(Some((asset_kind, amount)), None) => Some(Self::pay(who, asset_kind, amount)),
+			_ => None,
+		}
+	}
 	/// Ensure that a call to pay with the given parameters will be successful if done immediately
 	/// after this call. Used in benchmarking code.
 	#[cfg(feature = "runtime-benchmarks")]
@@ -105,6 +121,16 @@ where
 	fn check_payment(_: ()) -> PaymentStatus {
 		PaymentStatus::Success
 	}
+	fn pay_batch(
+		who: &Self::Beneficiary,
+		payments: Vec<(Self::AssetKind, Self::Balance)>,
+	) -> Option<Result<Self::Id, Self::Error>> {
+		Some(
+			payments
+				.into_iter()
+				.try_for_each(|(asset_kind, amount)| Self::pay(who, asset_kind, amount)),
+		)
+	}
 	#[cfg(feature = "runtime-benchmarks")]
 	fn ensure_successful(_: &Self::Beneficiary, _: Self::AssetKind, amount: Self::Balance) {
 		<F as fungible::Mutate<_>>::mint_into(&A::get(), amount).unwrap();
@@ -138,6 +164,16 @@ where
 	fn check_payment(_: ()) -> PaymentStatus {
 		PaymentStatus::Success
 	}
+	fn pay_batch(
+		who: &Self::Beneficiary,
+		payments: Vec<(Self::AssetKind, Self::Balance)>,
+	) -> Option<Result<Self::Id, Self::Error>> {
+		Some(
+			payments
+				.into_iter()
+				.try_for_each(|(asset, amount)| Self::pay(who, asset, amount)),
+		)
+	}
 	#[cfg(feature = "runtime-benchmarks")]
 	fn ensure_successful(_: &Self::Beneficiary, asset: Self::AssetKind, amount: Self::Balance) {
 		<F as fungibles::Create<_>>::create(asset.clone(), A::get(), true, amount).unwrap();
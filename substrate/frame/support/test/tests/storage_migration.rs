@@ -0,0 +1,208 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for [`MigrateStorageDoubleMap`] and [`MigrateStorageNMap`], and the `storage_migration`
+//! attribute generating them.
+
+use frame_support::{
+	construct_runtime, derive_impl,
+	migrations::{MigrateStorageDoubleMap, MigrateStorageNMap, VersionedMigration},
+	storage::types::{Key as NMapKey, StorageDoubleMap, StorageNMap},
+	traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+	weights::constants::RocksDbWeight,
+	Identity, Twox64Concat,
+};
+use sp_core::ConstU64;
+use sp_runtime::BuildStorage;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+#[frame_support::pallet]
+mod dummy_pallet {
+	use frame_support::pallet_prelude::*;
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {}
+
+	#[pallet::storage]
+	pub type Approvals<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, u32, Blake2_128Concat, u64, u64>;
+
+	#[pallet::storage]
+	pub type Votes<T: Config> = StorageNMap<
+		_,
+		(NMapKey<Blake2_128Concat, u32>, NMapKey<Twox64Concat, u64>, NMapKey<Identity, u8>),
+		u64,
+	>;
+}
+
+impl dummy_pallet::Config for Test {}
+
+construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system = 0,
+		DummyPallet: dummy_pallet = 1,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
+impl frame_system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type Block = Block;
+	type BlockHashCount = ConstU64<10>;
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type RuntimeEvent = RuntimeEvent;
+	type PalletInfo = PalletInfo;
+	type OnSetCode = ();
+}
+
+/// The storage layout of the pallet before the migrations.
+mod v0 {
+	use super::*;
+
+	#[frame_support::storage_migration(
+		to = dummy_pallet::Approvals<Test>,
+		db_weight = RocksDbWeight,
+	)]
+	#[frame_support::storage_alias]
+	pub type Approvals = StorageDoubleMap<DummyPallet, Identity, u32, Twox64Concat, u64, u32>;
+
+	#[frame_support::storage_migration(to = dummy_pallet::Votes<Test>, db_weight = RocksDbWeight)]
+	#[frame_support::storage_alias]
+	pub type Votes = StorageNMap<
+		DummyPallet,
+		(NMapKey<Identity, u32>, NMapKey<Identity, u64>, NMapKey<Identity, u8>),
+		u32,
+	>;
+}
+
+/// A generic storage alias whose migration is given a custom name.
+mod v1 {
+	use super::*;
+
+	#[frame_support::storage_migration(
+		to = dummy_pallet::Approvals<Test>,
+		db_weight = RocksDbWeight,
+		name = ApprovalsFromV1,
+	)]
+	#[frame_support::storage_alias]
+	pub type Approvals<T: dummy_pallet::Config> =
+		StorageDoubleMap<dummy_pallet::Pallet<T>, Identity, u32, Twox64Concat, u64, u32>;
+}
+
+use v0::{MigrateApprovals, MigrateVotes};
+
+type VersionedMigrationV0ToV1 =
+	VersionedMigration<0, 1, (MigrateApprovals, MigrateVotes), DummyPallet, RocksDbWeight>;
+
+fn new_test_ext() -> sp_io::TestExternalities {
+	let storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	let mut ext: sp_io::TestExternalities = sp_io::TestExternalities::from(storage);
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		for i in 0..3 {
+			v0::Approvals::insert(i, i as u64 + 10, i + 100);
+			v0::Votes::insert((i, i as u64 + 10, i as u8), i + 100);
+		}
+	});
+	ext
+}
+
+#[test]
+fn double_map_is_migrated() {
+	new_test_ext().execute_with(|| {
+		let weight = MigrateApprovals::on_runtime_upgrade();
+		assert_eq!(weight, RocksDbWeight::get().reads_writes(3, 6));
+
+		assert_eq!(v0::Approvals::iter().count(), 0);
+		let mut approvals = dummy_pallet::Approvals::<Test>::iter().collect::<Vec<_>>();
+		approvals.sort();
+		assert_eq!(approvals, vec![(0, 10, 100), (1, 11, 101), (2, 12, 102)]);
+	});
+}
+
+#[test]
+fn nmap_is_migrated() {
+	new_test_ext().execute_with(|| {
+		let weight = MigrateVotes::on_runtime_upgrade();
+		assert_eq!(weight, RocksDbWeight::get().reads_writes(3, 6));
+
+		assert_eq!(v0::Votes::iter().count(), 0);
+		let mut votes = dummy_pallet::Votes::<Test>::iter().collect::<Vec<_>>();
+		votes.sort();
+		assert_eq!(votes, vec![((0, 10, 0), 100), ((1, 11, 1), 101), ((2, 12, 2), 102)]);
+	});
+}
+
+#[test]
+fn migrations_compose_with_versioned_migration() {
+	new_test_ext().execute_with(|| {
+		VersionedMigrationV0ToV1::on_runtime_upgrade();
+		assert_eq!(DummyPallet::on_chain_storage_version(), StorageVersion::new(1));
+		assert_eq!(dummy_pallet::Approvals::<Test>::get(1, 11), Some(101));
+		assert_eq!(dummy_pallet::Votes::<Test>::get((2, 12, 2)), Some(102));
+
+		// Running it again is a noop.
+		dummy_pallet::Approvals::<Test>::insert(1, 11, 1000);
+		VersionedMigrationV0ToV1::on_runtime_upgrade();
+		assert_eq!(dummy_pallet::Approvals::<Test>::get(1, 11), Some(1000));
+	});
+}
+
+#[cfg(feature = "try-runtime")]
+#[test]
+fn entry_counts_are_checked() {
+	new_test_ext().execute_with(|| {
+		MigrateApprovals::try_on_runtime_upgrade(true).unwrap();
+		MigrateVotes::try_on_runtime_upgrade(true).unwrap();
+	});
+
+	new_test_ext().execute_with(|| {
+		let state = MigrateApprovals::pre_upgrade().unwrap();
+		MigrateApprovals::on_runtime_upgrade();
+		dummy_pallet::Approvals::<Test>::remove(0, 10);
+		assert!(MigrateApprovals::post_upgrade(state).is_err());
+	});
+}
+
+#[test]
+fn generated_migrations_are_the_typed_migrations() {
+	fn same_type<A: 'static, B: 'static>() -> bool {
+		core::any::TypeId::of::<A>() == core::any::TypeId::of::<B>()
+	}
+
+	assert!(same_type::<
+		MigrateApprovals,
+		MigrateStorageDoubleMap<v0::Approvals, dummy_pallet::Approvals<Test>, RocksDbWeight>,
+	>());
+	assert!(same_type::<
+		MigrateVotes,
+		MigrateStorageNMap<v0::Votes, dummy_pallet::Votes<Test>, RocksDbWeight>,
+	>());
+	assert!(same_type::<
+		v1::ApprovalsFromV1<Test>,
+		MigrateStorageDoubleMap<v1::Approvals<Test>, dummy_pallet::Approvals<Test>, RocksDbWeight>,
+	>());
+}
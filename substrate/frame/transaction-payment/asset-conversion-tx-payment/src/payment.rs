@@ -126,6 +126,11 @@ where
 		.map_err(|_| TransactionValidityError::from(InvalidTransaction::Payment))?;
 
 		ensure!(asset_consumed > Zero::zero(), InvalidTransaction::Payment);
+		// the swap is not part of the weight of the call, account for what it adds to the block.
+		frame_system::Pallet::<T>::register_extra_weight_unchecked(
+			CON::swap_overhead_weight(2),
+			info.class,
+		);
 
 		// charge the fee in native currency
 		<T::OnChargeTransaction>::withdraw_fee(who, call, info, fee, tip)
@@ -166,6 +171,10 @@ where
 			// If this fails, the account might have dropped below the existential balance or there
 			// is not enough liquidity left in the pool. In that case we don't throw an error and
 			// the account will keep the native currency.
+			frame_system::Pallet::<T>::register_extra_weight_unchecked(
+				CON::swap_overhead_weight(2),
+				dispatch_info.class,
+			);
 			match CON::swap_exact_tokens_for_tokens(
 				who.clone(), // we already deposited the native to `who`
 				vec![
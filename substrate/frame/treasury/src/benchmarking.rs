@@ -31,6 +31,7 @@ use frame_support::{
 		tokens::{ConversionFromAssetBalance, PaymentStatus},
 		EnsureOrigin, OnInitialize,
 	},
+	BoundedVec,
 };
 use frame_system::RawOrigin;
 use sp_core::crypto::FromEntropy;
@@ -104,6 +105,26 @@ fn create_spend_arguments<T: Config<I>, I: 'static>(
 	(asset_kind, 100u32.into(), beneficiary, beneficiary_lookup)
 }
 
+// Create `n` approved spends of `amount` of `asset_kind` to the same beneficiary.
+fn create_spends<T: Config<I>, I: 'static>(
+	n: u32,
+	asset_kind: &T::AssetKind,
+	amount: AssetBalanceOf<T, I>,
+	beneficiary_lookup: &BeneficiaryLookupOf<T, I>,
+) -> Result<(), BenchmarkError> {
+	for _ in 0..n {
+		let origin = T::SpendOrigin::try_successful_origin().map_err(|_| "No origin")?;
+		Treasury::<T, I>::spend(
+			origin,
+			Box::new(asset_kind.clone()),
+			amount,
+			Box::new(beneficiary_lookup.clone()),
+			None,
+		)?;
+	}
+	Ok(())
+}
+
 #[instance_benchmarks]
 mod benchmarks {
 	use super::*;
@@ -334,6 +355,54 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn payout_batch(n: Linear<1, { T::MaxPaymentBatch::get() }>) -> Result<(), BenchmarkError> {
+		let (asset_kind, amount, beneficiary, beneficiary_lookup) =
+			create_spend_arguments::<T, _>(SEED);
+		T::BalanceConverter::ensure_successful(asset_kind.clone());
+		create_spends::<T, I>(n, &asset_kind, amount, &beneficiary_lookup)?;
+		T::Paymaster::ensure_successful(&beneficiary, asset_kind, amount.saturating_mul(n.into()));
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let indices: BoundedVec<_, _> = (0..n).collect::<Vec<_>>().try_into().unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), indices);
+
+		let id = match Spends::<T, I>::get(0).unwrap().status {
+			PaymentState::Attempted { id, .. } => id,
+			_ => panic!("No payout attempt made"),
+		};
+		assert_eq!(PaymentSpends::<T, I>::iter_prefix(id).count(), n as usize);
+		assert_last_event::<T, I>(Event::Paid { index: n - 1, payment_id: id }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn poll_payment(n: Linear<1, { T::MaxPaymentBatch::get() }>) -> Result<(), BenchmarkError> {
+		let (asset_kind, amount, beneficiary, beneficiary_lookup) =
+			create_spend_arguments::<T, _>(SEED);
+		T::BalanceConverter::ensure_successful(asset_kind.clone());
+		create_spends::<T, I>(n, &asset_kind, amount, &beneficiary_lookup)?;
+		T::Paymaster::ensure_successful(&beneficiary, asset_kind, amount.saturating_mul(n.into()));
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let indices: BoundedVec<_, _> = (0..n).collect::<Vec<_>>().try_into().unwrap();
+		Treasury::<T, _>::payout_batch(RawOrigin::Signed(caller).into(), indices)?;
+		match Spends::<T, I>::get(0).unwrap().status {
+			PaymentState::Attempted { id, .. } => {
+				T::Paymaster::ensure_concluded(id);
+			},
+			_ => panic!("No payout attempt made"),
+		};
+
+		#[block]
+		{
+			Treasury::<T, I>::poll_payments(&mut WeightMeter::new());
+		}
+
+		assert_eq!(PaymentSpends::<T, I>::iter().count(), 0);
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(
 		Treasury,
 		crate::tests::ExtBuilder::default().build(),
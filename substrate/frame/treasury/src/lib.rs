@@ -68,11 +68,16 @@
 //! any asset kind managed by the treasury, with payment facilitated by a designated
 //! [`pallet::Config::Paymaster`]. To claim these spends, the `payout` dispatchable should be called
 //! within some temporal bounds, starting from the moment they become valid and within one
-//! [`pallet::Config::PayoutPeriod`].
+//! [`pallet::Config::PayoutPeriod`]. Several spends to the same beneficiary can be claimed at once
+//! with the `payout_batch` dispatchable, e.g. with a single XCM message if the paymaster pays
+//! remote assets over XCM. The status of attempted payments is polled whenever a block has some
+//! weight left, so that spends are removed once paid and can be claimed again once their payment
+//! failed, without the `check_status` dispatchable having to be called.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 mod benchmarking;
+pub mod migration;
 #[cfg(test)]
 mod tests;
 pub mod weights;
@@ -92,10 +97,12 @@ use frame_support::{
 	dispatch::{DispatchResult, DispatchResultWithPostInfo},
 	ensure, print,
 	traits::{
-		tokens::Pay, Currency, ExistenceRequirement::KeepAlive, Get, Imbalance, OnUnbalanced,
-		ReservableCurrency, WithdrawReasons,
+		tokens::{Pay, PaymentStatus},
+		Currency,
+		ExistenceRequirement::KeepAlive,
+		Get, Imbalance, OnUnbalanced, ReservableCurrency, WithdrawReasons,
 	},
-	weights::Weight,
+	weights::{Weight, WeightMeter},
 	PalletId,
 };
 
@@ -189,13 +196,16 @@ pub type SpendIndex = u32;
 pub mod pallet {
 	use super::*;
 	use frame_support::{
-		dispatch_context::with_context,
-		pallet_prelude::*,
-		traits::tokens::{ConversionFromAssetBalance, PaymentStatus},
+		dispatch_context::with_context, pallet_prelude::*,
+		traits::tokens::ConversionFromAssetBalance,
 	};
 	use frame_system::pallet_prelude::*;
 
+	/// The in-code storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T, I = ()>(PhantomData<(T, I)>);
 
 	#[pallet::config]
@@ -286,6 +296,10 @@ pub mod pallet {
 		#[pallet::constant]
 		type PayoutPeriod: Get<BlockNumberFor<Self>>;
 
+		/// The maximum number of spends that can be claimed with a single payment.
+		#[pallet::constant]
+		type MaxPaymentBatch: Get<u32>;
+
 		/// Helper type for benchmarks.
 		#[cfg(feature = "runtime-benchmarks")]
 		type BenchmarkHelper: ArgumentsFactory<Self::AssetKind, Self::Beneficiary>;
@@ -339,6 +353,24 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// The spends paid by each attempted payment, whose status is yet to be concluded.
+	#[pallet::storage]
+	pub type PaymentSpends<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		<T::Paymaster as Pay>::Id,
+		Twox64Concat,
+		SpendIndex,
+		(),
+		OptionQuery,
+	>;
+
+	/// The key in [`PaymentSpends`] up to which the attempted payments were last polled, the
+	/// polling resuming after it.
+	#[pallet::storage]
+	pub type PollCursor<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, (<T::Paymaster as Pay>::Id, SpendIndex), OptionQuery>;
+
 	#[pallet::genesis_config]
 	#[derive(frame_support::DefaultNoBound)]
 	pub struct GenesisConfig<T: Config<I>, I: 'static = ()> {
@@ -431,6 +463,14 @@ pub mod pallet {
 		NotAttempted,
 		/// The payment has neither failed nor succeeded yet.
 		Inconclusive,
+		/// No spends were given to be claimed.
+		EmptyBatch,
+		/// The same spend was given more than once.
+		DuplicateSpend,
+		/// The spends are not all to the same beneficiary.
+		BeneficiaryMismatch,
+		/// The spends cannot be paid with a single payment.
+		BatchNotSupported,
 	}
 
 	#[pallet::hooks]
@@ -458,6 +498,13 @@ pub mod pallet {
 			}
 		}
 
+		/// Poll the status of attempted payments with the weight left in the block.
+		fn on_idle(_: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let mut meter = WeightMeter::with_limit(remaining_weight);
+			Self::poll_payments(&mut meter);
+			meter.consumed()
+		}
+
 		#[cfg(feature = "try-runtime")]
 		fn try_state(
 			_: frame_system::pallet_prelude::BlockNumberFor<T>,
@@ -798,8 +845,9 @@ pub mod pallet {
 		///
 		/// Spends must be claimed within some temporal bounds. A spend may be claimed within one
 		/// [`Config::PayoutPeriod`] from the `valid_from` block.
-		/// In case of a payout failure, the spend status must be updated with the `check_status`
-		/// dispatchable before retrying with the current function.
+		/// In case of a payout failure, the spend status must be updated, either automatically once
+		/// the failure is known or with the `check_status` dispatchable, before retrying with the
+		/// current function.
 		///
 		/// ### Parameters
 		/// - `index`: The spend index.
@@ -825,6 +873,7 @@ pub mod pallet {
 
 			spend.status = PaymentState::Attempted { id };
 			Spends::<T, I>::insert(index, spend);
+			PaymentSpends::<T, I>::insert(id, index, ());
 
 			Self::deposit_event(Event::<T, I>::Paid { index, payment_id: id });
 
@@ -839,9 +888,11 @@ pub mod pallet {
 		///
 		/// ## Details
 		///
-		/// The status check is a prerequisite for retrying a failed payout.
+		/// The status check is a prerequisite for retrying a failed payout, although it also
+		/// happens automatically once the status of the payment is known.
 		/// If a spend has either succeeded or expired, it is removed from the storage by this
-		/// function. In such instances, transaction fees are refunded.
+		/// function. In such instances, transaction fees are refunded. The status of the other
+		/// spends claimed with the same payment is updated alike.
 		///
 		/// ### Parameters
 		/// - `index`: The spend index.
@@ -851,13 +902,16 @@ pub mod pallet {
 		/// Emits [`Event::PaymentFailed`] if the spend payout has failed.
 		/// Emits [`Event::SpendProcessed`] if the spend payout has succeed.
 		#[pallet::call_index(7)]
-		#[pallet::weight(T::WeightInfo::check_status())]
+		#[pallet::weight(
+			T::WeightInfo::check_status()
+				.max(T::WeightInfo::poll_payment(T::MaxPaymentBatch::get()))
+		)]
 		pub fn check_status(origin: OriginFor<T>, index: SpendIndex) -> DispatchResultWithPostInfo {
 			use PaymentState as State;
 			use PaymentStatus as Status;
 
 			ensure_signed(origin)?;
-			let mut spend = Spends::<T, I>::get(index).ok_or(Error::<T, I>::InvalidIndex)?;
+			let spend = Spends::<T, I>::get(index).ok_or(Error::<T, I>::InvalidIndex)?;
 			let now = frame_system::Pallet::<T>::block_number();
 
 			if now > spend.expire_at && !matches!(spend.status, State::Attempted { .. }) {
@@ -872,20 +926,18 @@ pub mod pallet {
 				_ => return Err(Error::<T, I>::NotAttempted.into()),
 			};
 
-			match T::Paymaster::check_payment(payment_id) {
-				Status::Failure => {
-					spend.status = PaymentState::Failed;
-					Spends::<T, I>::insert(index, spend);
-					Self::deposit_event(Event::<T, I>::PaymentFailed { index, payment_id });
-				},
-				Status::Success | Status::Unknown => {
-					Spends::<T, I>::remove(index);
-					Self::deposit_event(Event::<T, I>::SpendProcessed { index });
-					return Ok(Pays::No.into())
-				},
-				Status::InProgress => return Err(Error::<T, I>::Inconclusive.into()),
+			let status = T::Paymaster::check_payment(payment_id);
+			if status == Status::InProgress {
+				return Err(Error::<T, I>::Inconclusive.into())
+			}
+			PaymentSpends::<T, I>::remove(payment_id, index);
+			Self::conclude_spend(index, payment_id, &status);
+			Self::conclude_payment(payment_id, &status);
+
+			match status {
+				Status::Failure => Ok(Pays::Yes.into()),
+				_ => Ok(Pays::No.into()),
 			}
-			return Ok(Pays::Yes.into())
 		}
 
 		/// Void previously approved spend.
@@ -918,6 +970,74 @@ pub mod pallet {
 			Self::deposit_event(Event::<T, I>::AssetSpendVoided { index });
 			Ok(())
 		}
+
+		/// Claim several spends to the same beneficiary with a single payment.
+		///
+		/// ## Dispatch Origin
+		///
+		/// Must be signed.
+		///
+		/// ## Details
+		///
+		/// Each of the spends must be claimable with the `payout` dispatchable. They are paid at
+		/// once if the [`Config::Paymaster`] supports it, e.g. with a single XCM message for
+		/// assets residing at the same location, and share the status of that payment.
+		///
+		/// ### Parameters
+		/// - `indices`: The spend indices.
+		///
+		/// ## Events
+		///
+		/// Emits [`Event::Paid`] for each of the spends if successful.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::payout_batch(indices.len() as u32))]
+		pub fn payout_batch(
+			origin: OriginFor<T>,
+			indices: BoundedVec<SpendIndex, T::MaxPaymentBatch>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let now = frame_system::Pallet::<T>::block_number();
+
+			let mut spends = Vec::with_capacity(indices.len());
+			for index in indices {
+				ensure!(!spends.iter().any(|(i, _)| *i == index), Error::<T, I>::DuplicateSpend);
+				let spend = Spends::<T, I>::get(index).ok_or(Error::<T, I>::InvalidIndex)?;
+				ensure!(now >= spend.valid_from, Error::<T, I>::EarlyPayout);
+				ensure!(spend.expire_at > now, Error::<T, I>::SpendExpired);
+				ensure!(
+					matches!(spend.status, PaymentState::Pending | PaymentState::Failed),
+					Error::<T, I>::AlreadyAttempted
+				);
+				if let Some((_, first)) = spends.first() {
+					ensure!(
+						first.beneficiary == spend.beneficiary,
+						Error::<T, I>::BeneficiaryMismatch
+					);
+				}
+				spends.push((index, spend));
+			}
+			let beneficiary = match spends.first() {
+				Some((_, first)) => first.beneficiary.clone(),
+				None => return Err(Error::<T, I>::EmptyBatch.into()),
+			};
+
+			let payments = spends
+				.iter()
+				.map(|(_, spend)| (spend.asset_kind.clone(), spend.amount))
+				.collect();
+			let id = T::Paymaster::pay_batch(&beneficiary, payments)
+				.ok_or(Error::<T, I>::BatchNotSupported)?
+				.map_err(|_| Error::<T, I>::PayoutError)?;
+
+			for (index, mut spend) in spends {
+				spend.status = PaymentState::Attempted { id };
+				Spends::<T, I>::insert(index, spend);
+				PaymentSpends::<T, I>::insert(id, index, ());
+				Self::deposit_event(Event::<T, I>::Paid { index, payment_id: id });
+			}
+
+			Ok(())
+		}
 	}
 }
 
@@ -1030,6 +1150,100 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			.saturating_sub(T::Currency::minimum_balance())
 	}
 
+	/// Check the status of the attempted payments, as far as `meter` allows, and conclude the
+	/// spends of those which either succeeded or failed.
+	///
+	/// Payments are polled in storage order, resuming after the [`PollCursor`] and starting over
+	/// once all of them were polled, so that those still in progress do not hold back the others.
+	/// Only the cursor and the first payment are read if no payment is attempted.
+	pub(crate) fn poll_payments(meter: &mut WeightMeter) {
+		let read_weight = T::DbWeight::get().reads(1);
+		// The cursor and the first payment are read.
+		if meter.try_consume(T::DbWeight::get().reads(2)).is_err() {
+			return
+		}
+		let mut cursor = PollCursor::<T, I>::get();
+		if cursor.is_none() && PaymentSpends::<T, I>::iter_keys().next().is_none() {
+			// No payment is attempted.
+			return
+		}
+		// The cursor is updated, and the end of the payments read.
+		if meter.try_consume(T::DbWeight::get().reads_writes(1, 1)).is_err() {
+			return
+		}
+		let poll_weight = T::WeightInfo::poll_payment(T::MaxPaymentBatch::get());
+
+		let mut keys = match cursor {
+			Some((payment_id, index)) => PaymentSpends::<T, I>::iter_keys_from(
+				PaymentSpends::<T, I>::hashed_key_for(payment_id, index),
+			),
+			None => PaymentSpends::<T, I>::iter_keys(),
+		};
+		// Collected first since the storage must not be altered while iterating over it.
+		let mut payment_ids = Vec::new();
+		loop {
+			let Some((payment_id, index)) = keys.next() else {
+				// All the payments were polled, the next polling starts over.
+				cursor = None;
+				break
+			};
+			// The spends of a payment are stored next to each other.
+			let polled = payment_ids.last() == Some(&payment_id);
+			if meter.try_consume(if polled { read_weight } else { poll_weight }).is_err() {
+				break
+			}
+			if !polled {
+				payment_ids.push(payment_id);
+			}
+			cursor = Some((payment_id, index));
+		}
+		PollCursor::<T, I>::set(cursor);
+
+		for payment_id in payment_ids {
+			let status = T::Paymaster::check_payment(payment_id);
+			if status != PaymentStatus::InProgress {
+				Self::conclude_payment(payment_id, &status);
+			}
+		}
+	}
+
+	/// Conclude the spends paid by the payment `payment_id`, given its final `status`.
+	fn conclude_payment(payment_id: <T::Paymaster as Pay>::Id, status: &PaymentStatus) {
+		for (index, ()) in
+			PaymentSpends::<T, I>::drain_prefix(payment_id).take(T::MaxPaymentBatch::get() as usize)
+		{
+			Self::conclude_spend(index, payment_id, status);
+		}
+	}
+
+	/// Conclude the spend at `index`, if it is still attempted with the payment `payment_id`.
+	///
+	/// A spend whose payment failed can be claimed again, while a spend whose payment succeeded,
+	/// or will never be known to have, is removed.
+	fn conclude_spend(
+		index: SpendIndex,
+		payment_id: <T::Paymaster as Pay>::Id,
+		status: &PaymentStatus,
+	) {
+		let Some(mut spend) = Spends::<T, I>::get(index) else { return };
+		if spend.status != (PaymentState::Attempted { id: payment_id }) {
+			return
+		}
+
+		match status {
+			PaymentStatus::Failure => {
+				spend.status = PaymentState::Failed;
+				Spends::<T, I>::insert(index, spend);
+				Self::deposit_event(Event::<T, I>::PaymentFailed { index, payment_id });
+			},
+			PaymentStatus::Success | PaymentStatus::Unknown => {
+				Spends::<T, I>::remove(index);
+				Self::deposit_event(Event::<T, I>::SpendProcessed { index });
+			},
+			PaymentStatus::InProgress => {},
+		}
+	}
+
 	/// Ensure the correctness of the state of this pallet.
 	#[cfg(any(feature = "try-runtime", test))]
 	fn do_try_state() -> Result<(), sp_runtime::TryRuntimeError> {
@@ -1082,6 +1296,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	/// [`SpendCount`].
 	/// 3. For each spend entry contained in [`Spends`] we should have spend.expire_at
 	/// > spend.valid_from.
+	/// 4. Each spend in [`PaymentSpends`] should be contained in [`Spends`] and attempted with
+	/// the payment it is saved under.
 	#[cfg(any(feature = "try-runtime", test))]
 	fn try_state_spends() -> Result<(), sp_runtime::TryRuntimeError> {
 		let current_spend_count = SpendCount::<T, I>::get();
@@ -1106,6 +1322,15 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			Ok(())
 		})?;
 
+		PaymentSpends::<T, I>::iter_keys().try_for_each(|(id, index)| -> DispatchResult {
+			ensure!(
+				Spends::<T, I>::get(index).map(|spend| spend.status) ==
+					Some(PaymentState::Attempted { id }),
+				"Spends in `PaymentSpends` must be attempted with the payment they are saved under."
+			);
+			Ok(())
+		})?;
+
 		Ok(())
 	}
 }
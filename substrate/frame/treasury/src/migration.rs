@@ -0,0 +1,72 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Storage migrations for the treasury pallet.
+
+use super::*;
+use frame_support::traits::{Get, OnRuntimeUpgrade};
+use sp_std::marker::PhantomData;
+
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+mod v1 {
+	use super::*;
+
+	/// Actual implementation of the storage migration.
+	///
+	/// The status of the payments attempted before [`PaymentSpends`] was introduced is not polled
+	/// automatically, so their spends are indexed by payment here.
+	pub struct MigrateToV1Impl<T, I>(PhantomData<(T, I)>);
+
+	impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV1Impl<T, I> {
+		fn on_runtime_upgrade() -> frame_support::weights::Weight {
+			let (mut reads, mut writes) = (0u64, 0u64);
+			for (index, spend) in Spends::<T, I>::iter() {
+				reads.saturating_inc();
+				if let PaymentState::Attempted { id } = spend.status {
+					PaymentSpends::<T, I>::insert(id, index, ());
+					writes.saturating_inc();
+				}
+			}
+
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_: Vec<u8>) -> Result<(), TryRuntimeError> {
+			for (index, spend) in Spends::<T, I>::iter() {
+				if let PaymentState::Attempted { id } = spend.status {
+					ensure!(
+						PaymentSpends::<T, I>::contains_key(id, index),
+						"the spends of all the attempted payments should be indexed by payment"
+					);
+				}
+			}
+			Ok(())
+		}
+	}
+}
+
+/// Migrate the pallet storage from `0` to `1`.
+pub type MigrateV0ToV1<T, I> = frame_support::migrations::VersionedMigration<
+	0,
+	1,
+	v1::MigrateToV1Impl<T, I>,
+	Pallet<T, I>,
+	<T as frame_system::Config>::DbWeight,
+>;
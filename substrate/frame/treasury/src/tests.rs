@@ -32,7 +32,7 @@ use frame_support::{
 	parameter_types,
 	traits::{
 		tokens::{ConversionFromAssetBalance, PaymentStatus},
-		ConstU32, ConstU64, OnInitialize,
+		ConstU32, ConstU64, OnIdle, OnInitialize,
 	},
 	PalletId,
 };
@@ -148,6 +148,19 @@ impl Pay for TestPay {
 	fn check_payment(id: Self::Id) -> PaymentStatus {
 		STATUS.with(|s| s.borrow().get(&id).cloned().unwrap_or(PaymentStatus::Unknown))
 	}
+	fn pay_batch(
+		who: &Self::Beneficiary,
+		payments: Vec<(Self::AssetKind, Self::Balance)>,
+	) -> Option<Result<Self::Id, Self::Error>> {
+		for (asset_kind, amount) in payments {
+			PAID.with(|paid| *paid.borrow_mut().entry((*who, asset_kind)).or_default() += amount);
+		}
+		Some(Ok(LAST_ID.with(|lid| {
+			let x = *lid.borrow();
+			lid.replace(x + 1);
+			x
+		})))
+	}
 	#[cfg(feature = "runtime-benchmarks")]
 	fn ensure_successful(_: &Self::Beneficiary, _: Self::AssetKind, _: Self::Balance) {}
 	#[cfg(feature = "runtime-benchmarks")]
@@ -215,6 +228,7 @@ impl Config for Test {
 	type Paymaster = TestPay;
 	type BalanceConverter = MulBy<ConstU64<2>>;
 	type PayoutPeriod = SpendPayoutPeriod;
+	type MaxPaymentBatch = ConstU32<3>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
 }
@@ -999,6 +1013,204 @@ fn check_status_works() {
 	});
 }
 
+#[test]
+fn payout_batch_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		// spends `0` and `1` of assets `1` and `2` to `6`, spend `2` to `7`.
+		assert_ok!(Treasury::spend(RuntimeOrigin::signed(10), Box::new(1), 2, Box::new(6), None));
+		assert_ok!(Treasury::spend(RuntimeOrigin::signed(10), Box::new(2), 1, Box::new(6), None));
+		assert_ok!(Treasury::spend(RuntimeOrigin::signed(10), Box::new(1), 2, Box::new(7), None));
+		assert_ok!(Treasury::spend(
+			RuntimeOrigin::signed(10),
+			Box::new(1),
+			2,
+			Box::new(6),
+			Some(10)
+		));
+
+		let batch = |indices: Vec<SpendIndex>| {
+			Treasury::payout_batch(RuntimeOrigin::signed(1), indices.try_into().unwrap())
+		};
+		assert_noop!(batch(vec![]), Error::<Test, _>::EmptyBatch);
+		assert_noop!(batch(vec![0, 4]), Error::<Test, _>::InvalidIndex);
+		assert_noop!(batch(vec![0, 0]), Error::<Test, _>::DuplicateSpend);
+		assert_noop!(batch(vec![0, 2]), Error::<Test, _>::BeneficiaryMismatch);
+		assert_noop!(batch(vec![0, 3]), Error::<Test, _>::EarlyPayout);
+
+		// both spends to `6` are paid with a single payment.
+		assert_ok!(batch(vec![0, 1]));
+		assert_eq!(paid(6, 1), 2);
+		assert_eq!(paid(6, 2), 1);
+		let payment_id = get_payment_id(0).expect("no payment attempt");
+		assert_eq!(get_payment_id(1), Some(payment_id));
+		System::assert_has_event(Event::<Test, _>::Paid { index: 0, payment_id }.into());
+		System::assert_last_event(Event::<Test, _>::Paid { index: 1, payment_id }.into());
+		assert_noop!(batch(vec![1]), Error::<Test, _>::AlreadyAttempted);
+
+		// checking the status of one of the spends concludes both.
+		set_status(payment_id, PaymentStatus::Failure);
+		unpay(6, 1, 2);
+		unpay(6, 2, 1);
+		assert_ok!(Treasury::check_status(RuntimeOrigin::signed(1), 0));
+		System::assert_has_event(Event::<Test, _>::PaymentFailed { index: 0, payment_id }.into());
+		System::assert_has_event(Event::<Test, _>::PaymentFailed { index: 1, payment_id }.into());
+		assert_eq!(PaymentSpends::<Test, _>::iter().count(), 0);
+
+		// both failed spends can be paid again.
+		assert_ok!(batch(vec![1, 0]));
+		assert_eq!(paid(6, 1), 2);
+		assert_eq!(paid(6, 2), 1);
+		let payment_id = get_payment_id(1).expect("no payment attempt");
+		set_status(payment_id, PaymentStatus::Success);
+		assert_ok!(Treasury::check_status(RuntimeOrigin::signed(1), 1));
+		assert_eq!(Spends::<Test, _>::get(0), None);
+		assert_eq!(Spends::<Test, _>::get(1), None);
+	});
+}
+
+#[test]
+fn payments_are_polled_on_idle() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		// only the cursor and the first payment are read while no payment is attempted.
+		assert_eq!(
+			<Treasury as OnIdle<u64>>::on_idle(1, Weight::MAX),
+			<Test as frame_system::Config>::DbWeight::get().reads(2)
+		);
+
+		for _ in 0..4 {
+			assert_ok!(Treasury::spend(
+				RuntimeOrigin::signed(10),
+				Box::new(1),
+				2,
+				Box::new(6),
+				None
+			));
+		}
+		assert_ok!(Treasury::payout(RuntimeOrigin::signed(1), 0));
+		assert_ok!(Treasury::payout(RuntimeOrigin::signed(1), 1));
+		assert_ok!(Treasury::payout_batch(
+			RuntimeOrigin::signed(1),
+			vec![2, 3].try_into().unwrap()
+		));
+		let (succeeded, in_progress, failed) =
+			(get_payment_id(0).unwrap(), get_payment_id(1).unwrap(), get_payment_id(2).unwrap());
+		set_status(succeeded, PaymentStatus::Success);
+		set_status(in_progress, PaymentStatus::InProgress);
+		set_status(failed, PaymentStatus::Failure);
+
+		// nothing is polled without any weight left.
+		assert_eq!(<Treasury as OnIdle<u64>>::on_idle(1, Weight::zero()), Weight::zero());
+		assert_eq!(PaymentSpends::<Test, _>::iter().count(), 4);
+
+		let consumed = <Treasury as OnIdle<u64>>::on_idle(1, Weight::MAX);
+		assert!(consumed.all_gte(<Test as Config>::WeightInfo::poll_payment(3).saturating_mul(3)));
+		System::assert_has_event(Event::<Test, _>::SpendProcessed { index: 0 }.into());
+		System::assert_has_event(
+			Event::<Test, _>::PaymentFailed { index: 2, payment_id: failed }.into(),
+		);
+		System::assert_has_event(
+			Event::<Test, _>::PaymentFailed { index: 3, payment_id: failed }.into(),
+		);
+		assert_eq!(Spends::<Test, _>::get(0), None);
+		assert_eq!(get_payment_id(1), Some(in_progress));
+		assert_eq!(get_payment_id(2), None);
+		assert_eq!(get_payment_id(3), None);
+		assert_eq!(
+			PaymentSpends::<Test, _>::iter_keys().collect::<Vec<_>>(),
+			vec![(in_progress, 1)]
+		);
+
+		// the payment in progress is polled again until it concludes.
+		set_status(in_progress, PaymentStatus::Success);
+		<Treasury as OnIdle<u64>>::on_idle(2, Weight::MAX);
+		System::assert_last_event(Event::<Test, _>::SpendProcessed { index: 1 }.into());
+		assert_eq!(PaymentSpends::<Test, _>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn payment_polling_resumes_where_it_stopped() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		for index in 0..3 {
+			assert_ok!(Treasury::spend(
+				RuntimeOrigin::signed(10),
+				Box::new(1),
+				2,
+				Box::new(6),
+				None
+			));
+			assert_ok!(Treasury::payout(RuntimeOrigin::signed(1), index));
+		}
+		let payments = PaymentSpends::<Test, _>::iter_keys().collect::<Vec<_>>();
+		for (payment_id, _) in &payments {
+			set_status(*payment_id, PaymentStatus::InProgress);
+		}
+		let (last_id, last_index) = payments[2];
+		set_status(last_id, PaymentStatus::Success);
+
+		// only a single payment can be polled each time.
+		let weight = <Test as frame_system::Config>::DbWeight::get()
+			.reads_writes(3, 1)
+			.saturating_add(<Test as Config>::WeightInfo::poll_payment(3));
+		<Treasury as OnIdle<u64>>::on_idle(1, weight);
+		assert_eq!(PollCursor::<Test, _>::get(), Some(payments[0]));
+		<Treasury as OnIdle<u64>>::on_idle(2, weight);
+		assert_eq!(PollCursor::<Test, _>::get(), Some(payments[1]));
+		assert_eq!(PaymentSpends::<Test, _>::iter().count(), 3);
+
+		// the payments in progress do not keep the last one from being polled.
+		<Treasury as OnIdle<u64>>::on_idle(3, weight);
+		System::assert_last_event(Event::<Test, _>::SpendProcessed { index: last_index }.into());
+		assert_eq!(PollCursor::<Test, _>::get(), None);
+
+		// the polling then starts over.
+		let (first_id, first_index) = payments[0];
+		set_status(first_id, PaymentStatus::Success);
+		<Treasury as OnIdle<u64>>::on_idle(4, weight);
+		System::assert_last_event(Event::<Test, _>::SpendProcessed { index: first_index }.into());
+		assert_eq!(PollCursor::<Test, _>::get(), Some(payments[0]));
+	});
+}
+
+#[test]
+fn migration_to_v1_indexes_attempted_payments() {
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		for _ in 0..2 {
+			assert_ok!(Treasury::spend(
+				RuntimeOrigin::signed(10),
+				Box::new(1),
+				2,
+				Box::new(6),
+				None
+			));
+		}
+		assert_ok!(Treasury::payout(RuntimeOrigin::signed(1), 0));
+		let payment_id = get_payment_id(0).expect("no payment attempt");
+		// the payment was attempted before the spends were indexed by payment.
+		PaymentSpends::<Test, _>::remove(payment_id, 0);
+		StorageVersion::new(0).put::<Treasury>();
+
+		crate::migration::MigrateV0ToV1::<Test, ()>::on_runtime_upgrade();
+
+		assert_eq!(Treasury::on_chain_storage_version(), 1);
+		assert_eq!(
+			PaymentSpends::<Test, _>::iter_keys().collect::<Vec<_>>(),
+			vec![(payment_id, 0)]
+		);
+
+		// the payment is then polled like any other.
+		set_status(payment_id, PaymentStatus::Success);
+		<Treasury as OnIdle<u64>>::on_idle(1, Weight::MAX);
+		System::assert_last_event(Event::<Test, _>::SpendProcessed { index: 0 }.into());
+	});
+}
+
 #[test]
 fn try_state_proposals_invariant_1_works() {
 	ExtBuilder::default().build().execute_with(|| {
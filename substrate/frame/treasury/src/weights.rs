@@ -57,6 +57,8 @@ pub trait WeightInfo {
 	fn payout() -> Weight;
 	fn check_status() -> Weight;
 	fn void_spend() -> Weight;
+	fn payout_batch(n: u32, ) -> Weight;
+	fn poll_payment(n: u32, ) -> Weight;
 }
 
 /// Weights for pallet_treasury using the Substrate node and recommended hardware.
@@ -178,25 +180,23 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Treasury PaymentSpends (r:0 w:1)
+	/// Proof: Treasury PaymentSpends (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
 	fn payout() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `705`
-		//  Estimated: `6208`
-		// Minimum execution time: 760_000_000 picoseconds.
+		// TODO: run benchmarks
 		Weight::from_parts(822_000_000, 6208)
 			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: Treasury Spends (r:1 w:1)
 	/// Proof: Treasury Spends (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Treasury PaymentSpends (r:1 w:1)
+	/// Proof: Treasury PaymentSpends (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
 	fn check_status() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `194`
-		//  Estimated: `3534`
-		// Minimum execution time: 153_000_000 picoseconds.
+		// TODO: run benchmarks
 		Weight::from_parts(160_000_000, 3534)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: Treasury Spends (r:1 w:1)
 	/// Proof: Treasury Spends (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
@@ -209,6 +209,44 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: Treasury Spends (r:100 w:100)
+	/// Proof: Treasury Spends (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:2 w:2)
+	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Treasury PaymentSpends (r:0 w:100)
+	/// Proof: Treasury PaymentSpends (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 100]`.
+	fn payout_batch(n: u32, ) -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(797_000_000, 6208)
+			.saturating_add(Weight::from_parts(118_260_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2544).saturating_mul(n.into()))
+	}
+	/// Storage: Treasury PollCursor (r:1 w:1)
+	/// Proof: Treasury PollCursor (max_values: Some(1), max_size: Some(12), added: 507, mode: MaxEncodedLen)
+	/// Storage: Treasury PaymentSpends (r:101 w:100)
+	/// Proof: Treasury PaymentSpends (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Treasury Spends (r:100 w:100)
+	/// Proof: Treasury Spends (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 100]`.
+	fn poll_payment(n: u32, ) -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(104_000_000, 4008)
+			.saturating_add(Weight::from_parts(131_540_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2544).saturating_mul(n.into()))
+	}
 }
 
 // For backwards compatibility and tests
@@ -329,25 +367,23 @@ impl WeightInfo for () {
 	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Treasury PaymentSpends (r:0 w:1)
+	/// Proof: Treasury PaymentSpends (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
 	fn payout() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `705`
-		//  Estimated: `6208`
-		// Minimum execution time: 760_000_000 picoseconds.
+		// TODO: run benchmarks
 		Weight::from_parts(822_000_000, 6208)
 			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	/// Storage: Treasury Spends (r:1 w:1)
 	/// Proof: Treasury Spends (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Treasury PaymentSpends (r:1 w:1)
+	/// Proof: Treasury PaymentSpends (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
 	fn check_status() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `194`
-		//  Estimated: `3534`
-		// Minimum execution time: 153_000_000 picoseconds.
+		// TODO: run benchmarks
 		Weight::from_parts(160_000_000, 3534)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: Treasury Spends (r:1 w:1)
 	/// Proof: Treasury Spends (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
@@ -360,4 +396,42 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: Treasury Spends (r:100 w:100)
+	/// Proof: Treasury Spends (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:2 w:2)
+	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Treasury PaymentSpends (r:0 w:100)
+	/// Proof: Treasury PaymentSpends (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 100]`.
+	fn payout_batch(n: u32, ) -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(797_000_000, 6208)
+			.saturating_add(Weight::from_parts(118_260_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2544).saturating_mul(n.into()))
+	}
+	/// Storage: Treasury PollCursor (r:1 w:1)
+	/// Proof: Treasury PollCursor (max_values: Some(1), max_size: Some(12), added: 507, mode: MaxEncodedLen)
+	/// Storage: Treasury PaymentSpends (r:101 w:100)
+	/// Proof: Treasury PaymentSpends (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Treasury Spends (r:100 w:100)
+	/// Proof: Treasury Spends (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 100]`.
+	fn poll_payment(n: u32, ) -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(104_000_000, 4008)
+			.saturating_add(Weight::from_parts(131_540_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2544).saturating_mul(n.into()))
+	}
 }
@@ -49,6 +49,38 @@ mod benchmarks {
 		assert!(PausedCalls::<T>::get(full_name).is_none());
 	}
 
+	#[benchmark]
+	fn pause_with_rule() {
+		let origin = T::PauseOrigin::try_successful_origin()
+			.expect("Tx-pause pallet is not usable without pause origin");
+		let full_name = name::<T>();
+		for i in 1..T::MaxRulesPerCall::get() {
+			TxPause::<T>::do_pause_with_rule(full_name.clone(), rule::<T>(i as u8)).unwrap();
+		}
+		let rule = rule::<T>(0);
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, full_name.clone(), rule.clone());
+
+		assert!(PauseRules::<T>::get(full_name).contains(&rule));
+	}
+
+	#[benchmark]
+	fn unpause_rule() {
+		let unpause_origin = T::UnpauseOrigin::try_successful_origin()
+			.expect("Tx-pause pallet is not usable without pause origin");
+		let full_name = name::<T>();
+		for i in 0..T::MaxRulesPerCall::get() {
+			TxPause::<T>::do_pause_with_rule(full_name.clone(), rule::<T>(i as u8)).unwrap();
+		}
+		let rule = rule::<T>(0);
+
+		#[extrinsic_call]
+		_(unpause_origin as T::RuntimeOrigin, full_name.clone(), rule.clone());
+
+		assert!(!PauseRules::<T>::get(full_name).contains(&rule));
+	}
+
 	impl_benchmark_test_suite!(TxPause, crate::mock::new_test_ext(), crate::mock::Test);
 }
 
@@ -57,3 +89,13 @@ fn name<T: Config>() -> RuntimeCallNameOf<T> {
 	let max_len = T::MaxNameLen::get() as usize;
 	(vec![1; max_len].try_into().unwrap(), vec![1; max_len].try_into().unwrap())
 }
+
+/// Longest possible rule, distinguished by its first byte.
+fn rule<T: Config>(first: u8) -> PauseRuleOf<T> {
+	let max_len = T::MaxArgsPrefixLen::get() as usize;
+	let mut args_prefix = vec![1; max_len];
+	if let Some(byte) = args_prefix.first_mut() {
+		*byte = first;
+	}
+	PauseRule { caller: CallerKind::Signed, args_prefix: args_prefix.try_into().unwrap() }
+}
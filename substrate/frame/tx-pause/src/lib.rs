@@ -42,6 +42,7 @@
 //! - Pausing is triggered using the string representation of the call.
 //! - Pauses can target a single extrinsic or an entire pallet.
 //! - Pauses can target future extrinsics or pallets.
+//! - Pauses can be restricted to some callers or arguments of an extrinsic with a [`PauseRule`].
 //!
 //! ### Example
 //!
@@ -70,6 +71,28 @@
 //!
 //! A storage map (`PausedCalls`) is used to store currently paused calls.
 //! Using the call filter will require a db read of that storage on each extrinsic.
+//!
+//! The rules of partially paused calls are stored in a second storage map (`PauseRules`), which
+//! is read on each extrinsic as well. Only calls with rules are SCALE encoded to match their
+//! arguments against the rules. The [`CheckPauseRules`] signed extension reads both maps once
+//! for each distinct call of a transaction and adds these reads to the weight of the block.
+//!
+//! ### Pause Rules
+//!
+//! A [`PauseRule`] pauses a call only if it was made by the rule's [`CallerKind`] and its SCALE
+//! encoded arguments start with the rule's `args_prefix`. For example, transfers of one specific
+//! asset can be paused by a rule matching the encoded asset ID, which is the first argument of
+//! `transfer`.
+//!
+//! The call filter of the runtime does not know the origin of a call, so it only applies rules for
+//! [`CallerKind::Any`]. Rules for signed or unsigned transactions are applied by the
+//! [`CheckPauseRules`] signed extension to the call of a transaction and to all calls nested in it
+//! by the wrappers of [`Config::InnerCalls`], e.g. batches or proxies. This allows to pause calls
+//! of users while governance, e.g. root or a technical committee, can still make them.
+//!
+//! Calls which are dispatched later or with another origin than the one of the transaction, like
+//! scheduled calls or the proposals of a collective, are not nested calls of the transaction and
+//! are only subject to the rules for [`CallerKind::Any`].
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![deny(rustdoc::broken_intra_doc_links)]
@@ -80,14 +103,20 @@ mod tests;
 pub mod weights;
 
 use frame_support::{
-	dispatch::GetDispatchInfo,
+	dispatch::{DispatchInfo, GetDispatchInfo},
 	pallet_prelude::*,
 	traits::{CallMetadata, Contains, GetCallMetadata, IsSubType, IsType},
-	DefaultNoBound,
+	CloneNoBound, DefaultNoBound, EqNoBound, PartialEqNoBound,
 };
 use frame_system::pallet_prelude::*;
-use sp_runtime::{traits::Dispatchable, DispatchResult};
-use sp_std::{convert::TryInto, prelude::*};
+use sp_runtime::{
+	traits::{DispatchInfoOf, Dispatchable, SignedExtension},
+	transaction_validity::{
+		InvalidTransaction, TransactionValidity, TransactionValidityError, ValidTransaction,
+	},
+	DispatchResult,
+};
+use sp_std::{collections::btree_map::BTreeMap, convert::TryInto, fmt, prelude::*};
 
 pub use pallet::*;
 pub use weights::*;
@@ -103,6 +132,66 @@ pub type PalletCallNameOf<T> = BoundedVec<u8, <T as Config>::MaxNameLen>;
 /// to partially or fully specify an item a variant of a  [`Config::RuntimeCall`].
 pub type RuntimeCallNameOf<T> = (PalletNameOf<T>, PalletCallNameOf<T>);
 
+/// The SCALE encoded prefix of the arguments of a call matched by a [`PauseRule`].
+pub type ArgsPrefixOf<T> = BoundedVec<u8, <T as Config>::MaxArgsPrefixLen>;
+
+/// A [`PauseRule`] for the [`Config::RuntimeCall`] of a runtime.
+pub type PauseRuleOf<T> = PauseRule<ArgsPrefixOf<T>>;
+
+/// The maximum depth of the calls nested in a transaction which are checked by [`CheckPauseRules`].
+///
+/// Transactions nesting calls deeper than this are rejected.
+pub const MAX_NESTING_DEPTH: u32 = 8;
+
+/// Provides the calls which a call dispatches on behalf of its caller.
+///
+/// This is implemented by the runtime for the wrappers of calls like `Utility::batch` or
+/// `Proxy::proxy`, so that [`CheckPauseRules`] can apply the [`PauseRule`]s of signed and unsigned
+/// callers to the wrapped calls as well.
+pub trait InnerCalls<Call> {
+	/// The calls directly nested in `call`.
+	fn inner_calls(call: &Call) -> Vec<&Call>;
+}
+
+impl<Call> InnerCalls<Call> for () {
+	fn inner_calls(_: &Call) -> Vec<&Call> {
+		Vec::new()
+	}
+}
+
+/// The kind of caller a [`PauseRule`] applies to.
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum CallerKind {
+	/// Calls made by any origin.
+	Any,
+	/// Calls of transactions signed by an account.
+	Signed,
+	/// Calls of unsigned transactions.
+	Unsigned,
+}
+
+/// Pauses a call only for some callers and arguments.
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct PauseRule<ArgsPrefix> {
+	/// The kind of caller that is paused.
+	pub caller: CallerKind,
+	/// The prefix of the SCALE encoded arguments of the paused calls.
+	///
+	/// An empty prefix matches all arguments.
+	pub args_prefix: ArgsPrefix,
+}
+
+impl<ArgsPrefix: AsRef<[u8]>> PauseRule<ArgsPrefix> {
+	/// Whether a call with the encoded `args` made by `caller` is paused by this rule.
+	///
+	/// Rules for a specific kind of caller never match a call of an unknown caller, given as
+	/// [`CallerKind::Any`].
+	pub fn matches(&self, caller: CallerKind, args: &[u8]) -> bool {
+		(self.caller == CallerKind::Any || self.caller == caller) &&
+			args.starts_with(self.args_prefix.as_ref())
+	}
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -142,6 +231,20 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxNameLen: Get<u32>;
 
+		/// Maximum number of [`PauseRule`]s per call.
+		#[pallet::constant]
+		type MaxRulesPerCall: Get<u32>;
+
+		/// Maximum length of the encoded arguments prefix of a [`PauseRule`].
+		#[pallet::constant]
+		type MaxArgsPrefixLen: Get<u32>;
+
+		/// The calls which are dispatched on behalf of the caller of a call.
+		///
+		/// The [`PauseRule`]s of signed and unsigned callers are applied to these as well. Only
+		/// wrappers which keep the kind of the caller, e.g. batches and proxies, should be listed.
+		type InnerCalls: InnerCalls<<Self as frame_system::Config>::RuntimeCall>;
+
 		// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -151,6 +254,16 @@ pub mod pallet {
 	pub type PausedCalls<T: Config> =
 		StorageMap<_, Blake2_128Concat, RuntimeCallNameOf<T>, (), OptionQuery>;
 
+	/// The rules of the calls that are paused only for some callers or arguments.
+	#[pallet::storage]
+	pub type PauseRules<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		RuntimeCallNameOf<T>,
+		BoundedVec<PauseRuleOf<T>, T::MaxRulesPerCall>,
+		ValueQuery,
+	>;
+
 	#[pallet::error]
 	pub enum Error<T> {
 		/// The call is paused.
@@ -164,6 +277,9 @@ pub mod pallet {
 
 		// The pallet or call does not exist in the runtime.
 		NotFound,
+
+		/// The call has too many pause rules.
+		TooManyRules,
 	}
 
 	#[pallet::event]
@@ -173,6 +289,10 @@ pub mod pallet {
 		CallPaused { full_name: RuntimeCallNameOf<T> },
 		/// This pallet, or a specific call is now unpaused.
 		CallUnpaused { full_name: RuntimeCallNameOf<T> },
+		/// A call is now paused for the callers and arguments matching a rule.
+		CallPausedWithRule { full_name: RuntimeCallNameOf<T>, rule: PauseRuleOf<T> },
+		/// A rule pausing a call was removed.
+		CallRuleUnpaused { full_name: RuntimeCallNameOf<T>, rule: PauseRuleOf<T> },
 	}
 
 	/// Configure the initial state of this pallet in the genesis block.
@@ -218,6 +338,38 @@ pub mod pallet {
 
 			Self::do_unpause(ident).map_err(Into::into)
 		}
+
+		/// Pause a call for the callers and arguments matching `rule`.
+		///
+		/// Can only be called by [`Config::PauseOrigin`].
+		/// Emits an [`Event::CallPausedWithRule`] event on success.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::pause_with_rule())]
+		pub fn pause_with_rule(
+			origin: OriginFor<T>,
+			full_name: RuntimeCallNameOf<T>,
+			rule: PauseRuleOf<T>,
+		) -> DispatchResult {
+			T::PauseOrigin::ensure_origin(origin)?;
+
+			Self::do_pause_with_rule(full_name, rule).map_err(Into::into)
+		}
+
+		/// Remove a rule pausing a call.
+		///
+		/// Can only be called by [`Config::UnpauseOrigin`].
+		/// Emits an [`Event::CallRuleUnpaused`] event on success.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::unpause_rule())]
+		pub fn unpause_rule(
+			origin: OriginFor<T>,
+			ident: RuntimeCallNameOf<T>,
+			rule: PauseRuleOf<T>,
+		) -> DispatchResult {
+			T::UnpauseOrigin::ensure_origin(origin)?;
+
+			Self::do_unpause_rule(ident, rule).map_err(Into::into)
+		}
 	}
 }
 
@@ -238,6 +390,40 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	pub(crate) fn do_pause_with_rule(
+		ident: RuntimeCallNameOf<T>,
+		rule: PauseRuleOf<T>,
+	) -> Result<(), Error<T>> {
+		Self::ensure_can_pause(&ident)?;
+		PauseRules::<T>::try_mutate(&ident, |rules| {
+			if rules.contains(&rule) {
+				return Err(Error::<T>::IsPaused)
+			}
+			rules.try_push(rule.clone()).map_err(|_| Error::<T>::TooManyRules)
+		})?;
+		Self::deposit_event(Event::CallPausedWithRule { full_name: ident, rule });
+
+		Ok(())
+	}
+
+	pub(crate) fn do_unpause_rule(
+		ident: RuntimeCallNameOf<T>,
+		rule: PauseRuleOf<T>,
+	) -> Result<(), Error<T>> {
+		PauseRules::<T>::try_mutate_exists(&ident, |maybe_rules| {
+			let rules = maybe_rules.as_mut().ok_or(Error::<T>::IsUnpaused)?;
+			let index = rules.iter().position(|r| r == &rule).ok_or(Error::<T>::IsUnpaused)?;
+			rules.remove(index);
+			if rules.is_empty() {
+				*maybe_rules = None;
+			}
+			Ok::<_, Error<T>>(())
+		})?;
+		Self::deposit_event(Event::CallRuleUnpaused { full_name: ident, rule });
+
+		Ok(())
+	}
+
 	/// Return whether this call is paused.
 	pub fn is_paused(full_name: &RuntimeCallNameOf<T>) -> bool {
 		if T::WhitelistedCalls::contains(full_name) {
@@ -258,6 +444,85 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Return whether `call` made by `caller` is paused, either entirely or by a [`PauseRule`].
+	///
+	/// Pass [`CallerKind::Any`] if the caller is unknown.
+	pub fn is_call_paused(
+		call: &<T as frame_system::Config>::RuntimeCall,
+		caller: CallerKind,
+	) -> bool
+	where
+		<T as frame_system::Config>::RuntimeCall: GetCallMetadata,
+	{
+		match Self::full_name_of(call) {
+			Some(full_name) => Self::pause_state(&full_name).pauses(call, caller),
+			None => true,
+		}
+	}
+
+	/// Return whether `call` made by `caller` or any call nested in it is paused, along with the
+	/// number of distinct calls whose pause state was read from storage.
+	///
+	/// The nested calls are given by [`Config::InnerCalls`]. Calls nested deeper than
+	/// [`MAX_NESTING_DEPTH`] are treated as paused. The pause state of a call is read once, however
+	/// often it is nested.
+	pub fn is_nested_call_paused(
+		call: &<T as frame_system::Config>::RuntimeCall,
+		caller: CallerKind,
+	) -> (bool, u32)
+	where
+		<T as frame_system::Config>::RuntimeCall: GetCallMetadata,
+	{
+		let mut states = BTreeMap::new();
+		let paused = Self::is_nested_call_paused_at(call, caller, 0, &mut states);
+		(paused, states.len() as u32)
+	}
+
+	fn is_nested_call_paused_at(
+		call: &<T as frame_system::Config>::RuntimeCall,
+		caller: CallerKind,
+		depth: u32,
+		states: &mut BTreeMap<RuntimeCallNameOf<T>, PauseState<T>>,
+	) -> bool
+	where
+		<T as frame_system::Config>::RuntimeCall: GetCallMetadata,
+	{
+		if depth > MAX_NESTING_DEPTH {
+			return true
+		}
+		let Some(full_name) = Self::full_name_of(call) else { return true };
+		let paused = states
+			.entry(full_name)
+			.or_insert_with_key(|full_name| Self::pause_state(full_name))
+			.pauses(call, caller);
+		paused ||
+			T::InnerCalls::inner_calls(call)
+				.into_iter()
+				.any(|inner| Self::is_nested_call_paused_at(inner, caller, depth + 1, states))
+	}
+
+	/// The full name of `call`, or `None` if its names are too long to be paused.
+	fn full_name_of(call: &<T as frame_system::Config>::RuntimeCall) -> Option<RuntimeCallNameOf<T>>
+	where
+		<T as frame_system::Config>::RuntimeCall: GetCallMetadata,
+	{
+		let CallMetadata { pallet_name, function_name } = call.get_call_metadata();
+		let pallet = PalletNameOf::<T>::try_from(pallet_name.as_bytes().to_vec()).ok()?;
+		let function = PalletCallNameOf::<T>::try_from(function_name.as_bytes().to_vec()).ok()?;
+		Some((pallet, function))
+	}
+
+	/// Read whether the call `full_name` is paused entirely, and its [`PauseRule`]s otherwise.
+	fn pause_state(full_name: &RuntimeCallNameOf<T>) -> PauseState<T> {
+		if Self::is_paused(full_name) {
+			PauseState::Paused
+		} else if T::WhitelistedCalls::contains(full_name) {
+			PauseState::Ruled(Default::default())
+		} else {
+			PauseState::Ruled(PauseRules::<T>::get(full_name))
+		}
+	}
+
 	/// Ensure that this call can be paused.
 	pub fn ensure_can_pause(full_name: &RuntimeCallNameOf<T>) -> Result<(), Error<T>> {
 		// SAFETY: The `TxPause` pallet can never pause itself.
@@ -291,8 +556,7 @@ where
 {
 	/// Return whether the call is allowed to be dispatched.
 	fn contains(call: &<T as frame_system::Config>::RuntimeCall) -> bool {
-		let CallMetadata { pallet_name, function_name } = call.get_call_metadata();
-		!Pallet::<T>::is_paused_unbound(pallet_name.into(), function_name.into())
+		!Pallet::<T>::is_call_paused(call, CallerKind::Any)
 	}
 }
 
@@ -331,3 +595,138 @@ impl<T: Config> From<Error<T>> for frame_support::traits::TransactionPauseError
 		}
 	}
 }
+
+/// Whether a call is paused entirely, or the [`PauseRule`]s it is paused by otherwise.
+enum PauseState<T: Config> {
+	Paused,
+	Ruled(BoundedVec<PauseRuleOf<T>, T::MaxRulesPerCall>),
+}
+
+impl<T: Config> PauseState<T> {
+	/// Return whether `call` made by `caller` is paused.
+	fn pauses(&self, call: &<T as frame_system::Config>::RuntimeCall, caller: CallerKind) -> bool {
+		match self {
+			Self::Paused => true,
+			Self::Ruled(rules) if rules.is_empty() => false,
+			Self::Ruled(rules) => {
+				// The first two bytes are the indices of the pallet and the call.
+				let encoded = call.encode();
+				let args = encoded.get(2..).unwrap_or_default();
+				rules.iter().any(|rule| rule.matches(caller, args))
+			},
+		}
+	}
+}
+
+/// Rejects transactions whose call is paused by a [`PauseRule`] for signed or unsigned callers.
+///
+/// The call of a transaction and all calls nested in it, as given by [`Config::InnerCalls`], are
+/// checked.
+///
+/// The pause state of each distinct call is read once per transaction. As this is not part of the
+/// weight of the call, the reads are added to the weight of the block when the transaction is
+/// dispatched.
+#[derive(Encode, Decode, CloneNoBound, EqNoBound, PartialEqNoBound, DefaultNoBound, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct CheckPauseRules<T: Config + Send + Sync>(PhantomData<T>);
+
+impl<T: Config + Send + Sync> CheckPauseRules<T>
+where
+	<T as frame_system::Config>::RuntimeCall: GetCallMetadata,
+{
+	/// Create new `SignedExtension` to check pause rules.
+	pub fn new() -> Self {
+		Self(PhantomData)
+	}
+
+	/// Check the calls of a transaction, returning the weight of the storage reads.
+	fn check(
+		call: &<T as frame_system::Config>::RuntimeCall,
+		caller: CallerKind,
+	) -> Result<Weight, TransactionValidityError> {
+		let (paused, read) = Pallet::<T>::is_nested_call_paused(call, caller);
+		if paused {
+			return Err(InvalidTransaction::Call.into())
+		}
+		// `PausedCalls` and `PauseRules` are read for each distinct call.
+		Ok(T::DbWeight::get().reads(2 * read as u64))
+	}
+
+	/// Check the calls of a transaction about to be dispatched, and add the weight of the storage
+	/// reads to the block.
+	fn check_pre_dispatch(
+		call: &<T as frame_system::Config>::RuntimeCall,
+		info: &DispatchInfoOf<<T as frame_system::Config>::RuntimeCall>,
+		caller: CallerKind,
+	) -> Result<(), TransactionValidityError>
+	where
+		<T as frame_system::Config>::RuntimeCall: Dispatchable<Info = DispatchInfo>,
+	{
+		let weight = Self::check(call, caller)?;
+		frame_system::Pallet::<T>::register_extra_weight_unchecked(weight, info.class);
+		Ok(())
+	}
+}
+
+impl<T: Config + Send + Sync> fmt::Debug for CheckPauseRules<T> {
+	#[cfg(feature = "std")]
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "CheckPauseRules")
+	}
+
+	#[cfg(not(feature = "std"))]
+	fn fmt(&self, _: &mut fmt::Formatter) -> fmt::Result {
+		Ok(())
+	}
+}
+
+impl<T: Config + Send + Sync> SignedExtension for CheckPauseRules<T>
+where
+	<T as frame_system::Config>::RuntimeCall: Dispatchable<Info = DispatchInfo> + GetCallMetadata,
+{
+	const IDENTIFIER: &'static str = "CheckPauseRules";
+	type AccountId = T::AccountId;
+	type Call = <T as frame_system::Config>::RuntimeCall;
+	type AdditionalSigned = ();
+	type Pre = ();
+
+	fn additional_signed(&self) -> Result<(), TransactionValidityError> {
+		Ok(())
+	}
+
+	fn validate(
+		&self,
+		_who: &Self::AccountId,
+		call: &Self::Call,
+		_info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> TransactionValidity {
+		Self::check(call, CallerKind::Signed).map(|_| ValidTransaction::default())
+	}
+
+	fn pre_dispatch(
+		self,
+		_who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> Result<Self::Pre, TransactionValidityError> {
+		Self::check_pre_dispatch(call, info, CallerKind::Signed)
+	}
+
+	fn validate_unsigned(
+		call: &Self::Call,
+		_info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> TransactionValidity {
+		Self::check(call, CallerKind::Unsigned).map(|_| ValidTransaction::default())
+	}
+
+	fn pre_dispatch_unsigned(
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> Result<(), TransactionValidityError> {
+		Self::check_pre_dispatch(call, info, CallerKind::Unsigned)
+	}
+}
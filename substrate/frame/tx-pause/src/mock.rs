@@ -51,7 +51,7 @@ impl frame_system::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Block = Block;
 	type BlockHashCount = BlockHashCount;
-	type DbWeight = ();
+	type DbWeight = frame_support::weights::constants::RocksDbWeight;
 	type Version = ();
 	type PalletInfo = PalletInfo;
 	type AccountData = pallet_balances::AccountData<u64>;
@@ -170,6 +170,24 @@ impl Contains<RuntimeCallNameOf<Test>> for WhitelistedCalls {
 	}
 }
 
+/// The calls nested in batches and proxy calls.
+pub struct NestedCalls;
+impl pallet_tx_pause::InnerCalls<RuntimeCall> for NestedCalls {
+	fn inner_calls(call: &RuntimeCall) -> Vec<&RuntimeCall> {
+		match call {
+			RuntimeCall::Utility(pallet_utility::Call::batch { calls }) |
+			RuntimeCall::Utility(pallet_utility::Call::batch_all { calls }) |
+			RuntimeCall::Utility(pallet_utility::Call::force_batch { calls }) =>
+				calls.iter().collect(),
+			RuntimeCall::Utility(pallet_utility::Call::as_derivative { call, .. }) |
+			RuntimeCall::Utility(pallet_utility::Call::with_weight { call, .. }) |
+			RuntimeCall::Proxy(pallet_proxy::Call::proxy { call, .. }) |
+			RuntimeCall::Proxy(pallet_proxy::Call::proxy_announced { call, .. }) => vec![call],
+			_ => Vec::new(),
+		}
+	}
+}
+
 impl Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
@@ -177,6 +195,9 @@ impl Config for Test {
 	type UnpauseOrigin = EnsureSignedBy<UnpauseOrigin, Self::AccountId>;
 	type WhitelistedCalls = WhitelistedCalls;
 	type MaxNameLen = MaxNameLen;
+	type MaxRulesPerCall = ConstU32<2>;
+	type MaxArgsPrefixLen = ConstU32<32>;
+	type InnerCalls = NestedCalls;
 	type WeightInfo = ();
 }
 
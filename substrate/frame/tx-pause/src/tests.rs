@@ -21,7 +21,7 @@ use super::*;
 use crate::mock::{RuntimeCall, *};
 
 use frame_support::{assert_err, assert_noop, assert_ok};
-use sp_runtime::DispatchError;
+use sp_runtime::{transaction_validity::InvalidTransaction, DispatchError};
 
 // GENERAL SUCCESS/POSITIVE TESTS ---------------------
 
@@ -129,6 +129,127 @@ fn can_filter_balance_in_proxy_when_paused() {
 	});
 }
 
+#[test]
+fn can_pause_call_with_argument_rule() {
+	new_test_ext().execute_with(|| {
+		let rule = PauseRule { caller: CallerKind::Any, args_prefix: args_prefix(2u64) };
+		assert_ok!(TxPause::pause_with_rule(
+			RuntimeOrigin::signed(mock::PauseOrigin::get()),
+			full_name::<Test>(b"Balances", b"transfer_allow_death"),
+			rule.clone(),
+		));
+		System::assert_last_event(
+			Event::CallPausedWithRule {
+				full_name: full_name::<Test>(b"Balances", b"transfer_allow_death"),
+				rule: rule.clone(),
+			}
+			.into(),
+		);
+
+		// Only transfers to `2` are paused.
+		assert_err!(
+			call_transfer(2, 1).dispatch(RuntimeOrigin::signed(0)),
+			frame_system::Error::<Test>::CallFiltered
+		);
+		assert_ok!(call_transfer(3, 1).dispatch(RuntimeOrigin::signed(0)));
+
+		assert_ok!(TxPause::unpause_rule(
+			RuntimeOrigin::signed(mock::UnpauseOrigin::get()),
+			full_name::<Test>(b"Balances", b"transfer_allow_death"),
+			rule,
+		));
+		assert!(PauseRules::<Test>::get(full_name::<Test>(b"Balances", b"transfer_allow_death"))
+			.is_empty());
+		assert_ok!(call_transfer(2, 1).dispatch(RuntimeOrigin::signed(0)));
+	});
+}
+
+#[test]
+fn caller_rules_are_checked_by_signed_extension() {
+	new_test_ext().execute_with(|| {
+		let rule = PauseRule { caller: CallerKind::Signed, args_prefix: Default::default() };
+		assert_ok!(TxPause::pause_with_rule(
+			RuntimeOrigin::signed(mock::PauseOrigin::get()),
+			full_name::<Test>(b"Balances", b"transfer_allow_death"),
+			rule,
+		));
+
+		let call = call_transfer(2, 1);
+		let info = call.get_dispatch_info();
+		assert_eq!(
+			CheckPauseRules::<Test>::new().validate(&0, &call, &info, 0),
+			Err(InvalidTransaction::Call.into())
+		);
+		assert_ok!(CheckPauseRules::<Test>::validate_unsigned(&call, &info, 0));
+		let keep_alive = call_transfer_keep_alive(2, 1);
+		assert_ok!(CheckPauseRules::<Test>::new().validate(&0, &keep_alive, &info, 0));
+
+		// The call filter does not know the caller and only applies rules for any caller.
+		assert_ok!(call.dispatch(RuntimeOrigin::signed(0)));
+	});
+}
+
+#[test]
+fn caller_rules_apply_to_nested_calls() {
+	new_test_ext().execute_with(|| {
+		let rule = PauseRule { caller: CallerKind::Signed, args_prefix: args_prefix(2u64) };
+		assert_ok!(TxPause::pause_with_rule(
+			RuntimeOrigin::signed(mock::PauseOrigin::get()),
+			full_name::<Test>(b"Balances", b"transfer_allow_death"),
+			rule,
+		));
+		let check = |call: RuntimeCall| {
+			let info = call.get_dispatch_info();
+			CheckPauseRules::<Test>::new().validate(&0, &call, &info, 0)
+		};
+
+		let batch = |calls| RuntimeCall::Utility(pallet_utility::Call::batch { calls });
+		let proxy = |call| {
+			RuntimeCall::Proxy(pallet_proxy::Call::proxy {
+				real: 1,
+				force_proxy_type: None,
+				call: Box::new(call),
+			})
+		};
+
+		assert_eq!(
+			check(batch(vec![call_transfer(3, 1), call_transfer(2, 1)])),
+			Err(InvalidTransaction::Call.into())
+		);
+		assert_eq!(
+			check(proxy(batch(vec![call_transfer(2, 1)]))),
+			Err(InvalidTransaction::Call.into())
+		);
+		assert_ok!(check(proxy(batch(vec![call_transfer(3, 1)]))));
+
+		// Nesting beyond the maximum depth is rejected.
+		let mut call = call_transfer(3, 1);
+		for _ in 0..=MAX_NESTING_DEPTH {
+			call = batch(vec![call]);
+		}
+		assert_eq!(check(call), Err(InvalidTransaction::Call.into()));
+	});
+}
+
+#[test]
+fn pause_state_is_read_once_per_call_and_weighed() {
+	new_test_ext().execute_with(|| {
+		let batch = RuntimeCall::Utility(pallet_utility::Call::batch {
+			calls: vec![call_transfer(2, 1), call_transfer(3, 1), call_transfer_keep_alive(2, 1)],
+		});
+
+		// The batch and the two distinct transfer calls are read.
+		assert_eq!(TxPause::is_nested_call_paused(&batch, CallerKind::Signed), (false, 3));
+
+		let info = batch.get_dispatch_info();
+		let before = System::block_weight().total();
+		assert_ok!(CheckPauseRules::<Test>::new().pre_dispatch(&0, &batch, &info, 0));
+		let reads = <Test as frame_system::Config>::DbWeight::get().reads(6);
+		assert_ne!(reads, Weight::zero());
+		assert_eq!(System::block_weight().total(), before.saturating_add(reads));
+	});
+}
+
 // GENERAL FAIL/NEGATIVE TESTS ---------------------
 
 #[test]
@@ -210,6 +331,41 @@ fn fails_to_unpause_not_paused_pallet() {
 	});
 }
 
+#[test]
+fn fails_to_add_invalid_rules() {
+	new_test_ext().execute_with(|| {
+		let rule =
+			|dest: u64| PauseRule { caller: CallerKind::Any, args_prefix: args_prefix(dest) };
+		let name = full_name::<Test>(b"Balances", b"transfer_allow_death");
+		let origin = RuntimeOrigin::signed(mock::PauseOrigin::get());
+
+		assert_noop!(
+			TxPause::pause_with_rule(
+				origin.clone(),
+				full_name::<Test>(b"Balances", b"transfer_keep_alive"),
+				rule(1),
+			),
+			Error::<Test>::Unpausable
+		);
+
+		assert_ok!(TxPause::pause_with_rule(origin.clone(), name.clone(), rule(1)));
+		assert_noop!(
+			TxPause::pause_with_rule(origin.clone(), name.clone(), rule(1)),
+			Error::<Test>::IsPaused
+		);
+		assert_ok!(TxPause::pause_with_rule(origin.clone(), name.clone(), rule(2)));
+		assert_noop!(
+			TxPause::pause_with_rule(origin, name.clone(), rule(3)),
+			Error::<Test>::TooManyRules
+		);
+
+		assert_noop!(
+			TxPause::unpause_rule(RuntimeOrigin::signed(mock::UnpauseOrigin::get()), name, rule(3)),
+			Error::<Test>::IsUnpaused
+		);
+	});
+}
+
 pub fn call_transfer(dest: u64, value: u64) -> RuntimeCall {
 	RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death { dest, value })
 }
@@ -218,6 +374,10 @@ pub fn call_transfer_keep_alive(dest: u64, value: u64) -> RuntimeCall {
 	RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive { dest, value })
 }
 
+pub fn args_prefix(dest: u64) -> ArgsPrefixOf<Test> {
+	dest.encode().try_into().unwrap()
+}
+
 pub fn full_name<T: Config>(pallet_name: &[u8], call_name: &[u8]) -> RuntimeCallNameOf<T> {
 	<RuntimeCallNameOf<T>>::from((
 		pallet_name.to_vec().try_into().unwrap(),
@@ -51,6 +51,8 @@ use core::marker::PhantomData;
 pub trait WeightInfo {
 	fn pause() -> Weight;
 	fn unpause() -> Weight;
+	fn pause_with_rule() -> Weight;
+	fn unpause_rule() -> Weight;
 }
 
 /// Weights for `pallet_tx_pause` using the Substrate node and recommended hardware.
@@ -78,6 +80,24 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `TxPause::PausedCalls` (r:1 w:0)
+	/// Proof: `TxPause::PausedCalls` (`max_values`: None, `max_size`: Some(532), added: 3007, mode: `MaxEncodedLen`)
+	/// Storage: `TxPause::PauseRules` (r:1 w:1)
+	/// Proof: `TxPause::PauseRules` (`max_values`: None, `max_size`: Some(1589), added: 4064, mode: `MaxEncodedLen`)
+	fn pause_with_rule() -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(25_029_000, 5054)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TxPause::PauseRules` (r:1 w:1)
+	/// Proof: `TxPause::PauseRules` (`max_values`: None, `max_size`: Some(1589), added: 4064, mode: `MaxEncodedLen`)
+	fn unpause_rule() -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(24_512_000, 5054)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -104,4 +124,22 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `TxPause::PausedCalls` (r:1 w:0)
+	/// Proof: `TxPause::PausedCalls` (`max_values`: None, `max_size`: Some(532), added: 3007, mode: `MaxEncodedLen`)
+	/// Storage: `TxPause::PauseRules` (r:1 w:1)
+	/// Proof: `TxPause::PauseRules` (`max_values`: None, `max_size`: Some(1589), added: 4064, mode: `MaxEncodedLen`)
+	fn pause_with_rule() -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(25_029_000, 5054)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TxPause::PauseRules` (r:1 w:1)
+	/// Proof: `TxPause::PauseRules` (`max_values`: None, `max_size`: Some(1589), added: 4064, mode: `MaxEncodedLen`)
+	fn unpause_rule() -> Weight {
+		// TODO: run benchmarks
+		Weight::from_parts(24_512_000, 5054)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
@@ -99,3 +99,71 @@ pub enum TraceBlockResponse {
 	/// Successful block tracing response
 	BlockTrace(BlockTrace),
 }
+
+/// Storage accesses recorded while re-executing a block, grouped by execution phase.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockStorageAccess {
+	/// Hash of the block being traced
+	pub block_hash: String,
+	/// Parent hash
+	pub parent_hash: String,
+	/// Estimated encoded size of the storage proof recorded for the whole block.
+	pub proof_size: u64,
+	/// Storage accesses of each execution phase, in execution order.
+	pub phases: Vec<PhaseStorageAccess>,
+}
+
+/// Phase of the block execution, mirroring the phase tracked by `frame_system`.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum ExecutionPhase {
+	/// Block initialization, including all `on_initialize` hooks.
+	Initialization,
+	/// Application of the extrinsic with the given index.
+	ApplyExtrinsic(u32),
+	/// Block finalization, including all `on_finalize` hooks and the storage root calculation.
+	Finalization,
+}
+
+/// Storage accesses made during a single execution phase.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct PhaseStorageAccess {
+	/// The execution phase.
+	pub phase: ExecutionPhase,
+	/// Number of bytes this phase added to the storage proof.
+	pub proof_size: u64,
+	/// Keys read during this phase, in order of first access.
+	pub reads: Vec<StorageAccess>,
+	/// Keys written during this phase, in order of first access.
+	pub writes: Vec<StorageAccess>,
+}
+
+/// Accesses to a single storage key.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageAccess {
+	/// Hex encoded storage key.
+	pub key: String,
+	/// Hex encoded storage key of the child trie, if the key belongs to one.
+	pub child_info: Option<String>,
+	/// Name of the pallet owning the storage item, if it is known to the runtime metadata.
+	pub pallet: Option<String>,
+	/// Name of the storage item, if it is known to the runtime metadata.
+	pub storage_item: Option<String>,
+	/// Number of times the key was accessed.
+	pub count: u32,
+	/// Number of bytes the accesses added to the storage proof.
+	pub proof_size: u64,
+}
+
+/// Response for the `state_traceBlockStorageAccess` RPC.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub enum TraceStorageAccessResponse {
+	/// Error block tracing response
+	TraceError(TraceError),
+	/// Successful storage access tracing response
+	StorageAccess(BlockStorageAccess),
+}
@@ -298,6 +298,50 @@ pub trait StakingInterface {
 	fn set_current_era(era: EraIndex);
 }
 
+/// An extension of [`StakingInterface`] for stakers, called agents, whose stake is delegated to
+/// them by other accounts, the delegators.
+///
+/// Delegated funds never leave the accounts of the delegators. They stay locked there while the
+/// agent bonds them. Slashes of an agent are not applied right away, they are recorded as pending
+/// and applied to the individual delegators through [`Self::delegator_slash`].
+pub trait DelegatedStakingInterface: StakingInterface {
+	/// Delegate `amount` of the funds of `delegator` to `agent`, and bond them.
+	///
+	/// If `agent` is not bonded yet, it is bonded with `reward_account` as its reward destination.
+	/// A delegator can only delegate to a single agent.
+	fn delegate(
+		delegator: &Self::AccountId,
+		agent: &Self::AccountId,
+		reward_account: &Self::AccountId,
+		amount: Self::Balance,
+	) -> DispatchResult;
+
+	/// Release `amount` of the funds `delegator` delegated to `agent`.
+	///
+	/// Only funds that `agent` has unbonded and withdrawn can be released.
+	fn withdraw_delegation(
+		delegator: &Self::AccountId,
+		agent: &Self::AccountId,
+		amount: Self::Balance,
+	) -> DispatchResult;
+
+	/// The funds delegated to `agent`, without the ones that are pending to be slashed.
+	fn agent_balance(agent: &Self::AccountId) -> Self::Balance;
+
+	/// The funds `delegator` delegated, including the ones that are pending to be slashed.
+	fn delegator_balance(delegator: &Self::AccountId) -> Self::Balance;
+
+	/// The slashes of `agent` that have not been applied to its delegators yet.
+	fn pending_slash(agent: &Self::AccountId) -> Self::Balance;
+
+	/// Apply `value` of the pending slash of `agent` to `delegator`.
+	fn delegator_slash(
+		agent: &Self::AccountId,
+		delegator: &Self::AccountId,
+		value: Self::Balance,
+	) -> DispatchResult;
+}
+
 /// The amount of exposure for an era that an individual nominator has (susceptible to slashing).
 #[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
 pub struct IndividualExposure<AccountId, Balance: HasCompact> {
@@ -0,0 +1,218 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Replay of Bulk Coretime sales under the price adapters of [`pallet_broker`].
+//!
+//! Given the outcome of a series of sales, [`simulate`] works out the regular price which each of
+//! them would have had, following the same rules as the pallet does when rotating sales, together
+//! with the price of a core which is renewed in every sale. This allows the parameters of an
+//! [`Adapter`] and of the renewal pricing to be evaluated against real or made-up demand.
+
+use pallet_broker::{AdaptPrice, CoreIndex, Linear, MovingAverage};
+use serde::{Deserialize, Serialize};
+use sp_arithmetic::{traits::Zero, FixedPointNumber, FixedU64, Perbill};
+use std::fmt;
+
+/// The balance type of the simulation.
+pub type Balance = u128;
+
+frame_support::parameter_types! {
+	static Smoothing: Perbill = Perbill::zero();
+}
+
+/// The outcome of a single sale.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SaleRecord {
+	/// The number of cores offered for sale.
+	pub cores_offered: CoreIndex,
+	/// The number of cores which should be sold for the price to remain the same.
+	pub ideal_cores_sold: CoreIndex,
+	/// The number of cores sold, including renewals.
+	pub cores_sold: CoreIndex,
+	/// The price of the last core sold up to the ideal amount. Defaults to the regular price.
+	#[serde(default)]
+	pub sellout_price: Option<Balance>,
+}
+
+/// The price adapter to simulate.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Adapter {
+	/// [`Linear`], which only reacts to the last sale.
+	Linear,
+	/// [`MovingAverage`] with the given weight of the latest sale.
+	MovingAverage(Perbill),
+}
+
+/// The parameters of a simulation.
+#[derive(Clone, Debug)]
+pub struct Params {
+	/// The price adapter.
+	pub adapter: Adapter,
+	/// The regular price of the first sale.
+	pub initial_price: Balance,
+	/// The amount by which the renewal price increases each sale, as in the pallet configuration.
+	pub renewal_bump: Perbill,
+	/// The most a renewal may cost as a multiple of the regular price, as the pallet's
+	/// `RenewalPriceCap`.
+	pub renewal_cap: Option<FixedU64>,
+}
+
+/// The simulated prices of a single sale.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Outcome {
+	/// The sale which was replayed.
+	pub sale: SaleRecord,
+	/// The regular price of the sale, that is after its leadin period.
+	pub price: Balance,
+	/// The price paid in this sale for a core bought in the first sale and renewed ever since,
+	/// assuming renewals are made after the leadin period.
+	pub renewal_price: Balance,
+	/// The record of demand kept by the adapter after this sale, if any.
+	pub demand: Option<f64>,
+}
+
+/// The outcomes of all replayed sales.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct Report(pub Vec<Outcome>);
+
+impl fmt::Display for Report {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		writeln!(
+			f,
+			"{:>5} {:>8} {:>6} {:>5} {:>20} {:>20} {:>8}",
+			"sale", "offered", "ideal", "sold", "price", "renewal", "demand"
+		)?;
+		for (i, outcome) in self.0.iter().enumerate() {
+			let demand = outcome.demand.map_or("-".into(), |d| format!("{:.3}", d));
+			writeln!(
+				f,
+				"{:>5} {:>8} {:>6} {:>5} {:>20} {:>20} {:>8}",
+				i,
+				outcome.sale.cores_offered,
+				outcome.sale.ideal_cores_sold,
+				outcome.sale.cores_sold,
+				outcome.price,
+				outcome.renewal_price,
+				demand,
+			)?;
+		}
+		Ok(())
+	}
+}
+
+fn adapt(
+	adapter: Adapter,
+	sold: CoreIndex,
+	target: CoreIndex,
+	limit: CoreIndex,
+	demand: &mut Option<FixedU64>,
+) -> FixedU64 {
+	match adapter {
+		Adapter::Linear => Linear::adapt_price_with_demand(sold, target, limit, demand),
+		Adapter::MovingAverage(smoothing) => {
+			Smoothing::set(smoothing);
+			MovingAverage::<Smoothing>::adapt_price_with_demand(sold, target, limit, demand)
+		},
+	}
+}
+
+/// Replay `history`, one sale after the other, under `params`.
+pub fn simulate(history: &[SaleRecord], params: &Params) -> Report {
+	let capped = |renewal: Balance, price: Balance| match params.renewal_cap {
+		Some(cap) => renewal.min(cap.saturating_mul_int(price)),
+		None => renewal,
+	};
+
+	let mut price = params.initial_price;
+	let mut renewal = params.initial_price;
+	let mut demand = None;
+	let mut outcomes = Vec::with_capacity(history.len());
+	for (i, sale) in history.iter().enumerate() {
+		if i > 0 {
+			renewal = capped(renewal, price);
+		}
+		// The price recorded for the next renewal, as the pallet does when renewing.
+		let next_renewal = price.min(renewal.saturating_add(params.renewal_bump * renewal));
+
+		let offered = sale.cores_offered;
+		let ideal = sale.ideal_cores_sold;
+		let sold = sale.cores_sold;
+		let next_price = if offered == 0 {
+			price
+		} else {
+			let purchase_price =
+				if sold >= ideal { sale.sellout_price.unwrap_or(price) } else { price };
+			adapt(params.adapter, sold.min(offered), ideal, offered, &mut demand)
+				.saturating_mul_int(purchase_price)
+		};
+
+		outcomes.push(Outcome {
+			sale: sale.clone(),
+			price,
+			renewal_price: renewal,
+			demand: demand.map(|d: FixedU64| d.to_float()),
+		});
+		price = next_price;
+		renewal = next_renewal;
+	}
+	Report(outcomes)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn sale(
+		cores_offered: CoreIndex,
+		ideal_cores_sold: CoreIndex,
+		cores_sold: CoreIndex,
+	) -> SaleRecord {
+		SaleRecord { cores_offered, ideal_cores_sold, cores_sold, sellout_price: None }
+	}
+
+	fn prices(report: &Report) -> Vec<(Balance, Balance)> {
+		report.0.iter().map(|o| (o.price, o.renewal_price)).collect()
+	}
+
+	#[test]
+	fn linear_follows_the_last_sale() {
+		let params = Params {
+			adapter: Adapter::Linear,
+			initial_price: 100,
+			renewal_bump: Perbill::from_percent(10),
+			renewal_cap: None,
+		};
+		let history = [sale(10, 5, 5), sale(10, 5, 10), sale(10, 5, 0), sale(10, 5, 5)];
+		let report = simulate(&history, &params);
+		assert_eq!(prices(&report), vec![(100, 100), (100, 100), (200, 100), (0, 110)]);
+		assert!(report.0.iter().all(|o| o.demand.is_none()));
+	}
+
+	#[test]
+	fn moving_average_and_cap_smooth_prices() {
+		let params = Params {
+			adapter: Adapter::MovingAverage(Perbill::from_percent(50)),
+			initial_price: 100,
+			renewal_bump: Perbill::from_percent(10),
+			renewal_cap: Some(FixedU64::from_rational(1, 2)),
+		};
+		let history = [sale(10, 5, 5), sale(10, 5, 10), sale(10, 5, 0), sale(10, 5, 5)];
+		let report = simulate(&history, &params);
+		assert_eq!(prices(&report), vec![(100, 100), (100, 50), (150, 55), (112, 56)]);
+		assert_eq!(report.0[3].demand, Some(0.875));
+	}
+}
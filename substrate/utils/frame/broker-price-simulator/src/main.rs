@@ -0,0 +1,95 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Replay a JSON list of Bulk Coretime sales under a price adapter of `pallet-broker`.
+//!
+//! ```sh,notrust
+//! $ broker-price-simulator --initial-price 1000000000000 sales.json
+//! $ broker-price-simulator --adapter moving-average --smoothing 0.2 --renewal-cap 2 sales.json
+//! ```
+//!
+//! Each sale of the list is an object such as
+//! `{ "cores_offered": 10, "ideal_cores_sold": 6, "cores_sold": 8, "sellout_price": 1000 }`, where
+//! `sellout_price` may be omitted.
+
+use broker_price_simulator::{simulate, Adapter, Params, SaleRecord};
+use clap::{Parser, ValueEnum};
+use sp_arithmetic::{FixedU64, Perbill};
+use std::path::PathBuf;
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum AdapterArg {
+	Linear,
+	MovingAverage,
+}
+
+#[derive(Debug, Parser)]
+struct Opt {
+	/// The price adapter to simulate.
+	#[arg(long, value_enum, default_value_t = AdapterArg::Linear)]
+	adapter: AdapterArg,
+
+	/// The weight of the latest sale in the moving average, between zero and one.
+	#[arg(long, default_value_t = 0.2)]
+	smoothing: f64,
+
+	/// The regular price of the first sale.
+	#[arg(long, default_value_t = 1_000_000_000_000)]
+	initial_price: u128,
+
+	/// The amount by which the renewal price increases each sale, between zero and one.
+	#[arg(long, default_value_t = 0.03)]
+	renewal_bump: f64,
+
+	/// The most a renewal may cost as a multiple of the regular price, uncapped if not given.
+	#[arg(long)]
+	renewal_cap: Option<f64>,
+
+	/// Output the report as JSON.
+	#[arg(long)]
+	json: bool,
+
+	/// The path to the JSON list of sales to replay.
+	history: PathBuf,
+}
+
+type BoxError = Box<dyn std::error::Error>;
+
+fn main() -> Result<(), BoxError> {
+	let Opt { adapter, smoothing, initial_price, renewal_bump, renewal_cap, json, history } =
+		Opt::parse();
+
+	let history: Vec<SaleRecord> = serde_json::from_reader(std::fs::File::open(history)?)?;
+	let adapter = match adapter {
+		AdapterArg::Linear => Adapter::Linear,
+		AdapterArg::MovingAverage => Adapter::MovingAverage(Perbill::from_float(smoothing)),
+	};
+	let params = Params {
+		adapter,
+		initial_price,
+		renewal_bump: Perbill::from_float(renewal_bump),
+		renewal_cap: renewal_cap.map(FixedU64::from_float),
+	};
+	let report = simulate(&history, &params);
+
+	if json {
+		println!("{}", serde_json::to_string_pretty(&report)?);
+	} else {
+		print!("{}", report);
+	}
+	Ok(())
+}
@@ -0,0 +1,447 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Off-chain verification of NPoS election results.
+//!
+//! Runs the election algorithms of [`sp_npos_elections`] over the snapshot of an election, and
+//! reports the score and the PJR compliance of each outcome, next to the ones of the solution
+//! queued on-chain, if any.
+//!
+//! A snapshot is read either from the storage of `pallet-election-provider-multi-phase`, see
+//! [`ElectionSnapshot::from_storage`], or from a JSON dump of an [`ElectionSnapshot`].
+
+use codec::Decode;
+use serde::{Deserialize, Serialize};
+use sp_npos_elections::{
+	assignment_ratio_to_staked_normalized, phragmms, pjr_check, reduce, seq_phragmen,
+	BalancingConfig, ElectionResult, ElectionScore, EvaluateSupport, ExtendedBalance,
+	StakedAssignment, Supports, VoteWeight,
+};
+use sp_runtime::{AccountId32, Perbill};
+use std::{collections::BTreeMap, fmt};
+
+/// The account id used by the relay chains and the kitchensink runtime.
+pub type AccountId = AccountId32;
+
+/// A voter of an election, with its stake and the targets it votes for.
+pub type Voter = (AccountId, VoteWeight, Vec<AccountId>);
+
+/// The errors of the verifier.
+#[derive(Debug)]
+pub enum Error {
+	/// A storage item needed for the snapshot is missing.
+	MissingStorage(&'static str),
+	/// A storage item could not be decoded.
+	Codec(&'static str, codec::Error),
+	/// An election algorithm failed.
+	Election(sp_npos_elections::Error),
+}
+
+impl fmt::Display for Error {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Error::MissingStorage(item) => write!(f, "storage item `{}` not found", item),
+			Error::Codec(item, e) => write!(f, "failed to decode `{}`: {}", item, e),
+			Error::Election(e) => write!(f, "election failed: {:?}", e),
+		}
+	}
+}
+
+impl std::error::Error for Error {}
+
+impl From<sp_npos_elections::Error> for Error {
+	fn from(e: sp_npos_elections::Error) -> Self {
+		Error::Election(e)
+	}
+}
+
+/// A solution of an election, as queued on-chain.
+#[derive(Clone, Debug, PartialEq, Eq, Decode, Serialize, Deserialize)]
+pub struct QueuedSolution {
+	/// The backings of the winners.
+	pub supports: Supports<AccountId>,
+	/// The score claimed for the solution.
+	pub score: ElectionScore,
+}
+
+/// The input of an election, and the solution queued on-chain for it, if any.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ElectionSnapshot {
+	/// All the voters of the election.
+	pub voters: Vec<Voter>,
+	/// All the electable targets.
+	pub targets: Vec<AccountId>,
+	/// The number of targets to elect.
+	pub desired_targets: u32,
+	/// The solution queued on-chain, if any.
+	#[serde(default)]
+	pub queued_solution: Option<QueuedSolution>,
+}
+
+/// The storage key of `item` in the pallet named `pallet`.
+pub fn storage_key(pallet: &str, item: &str) -> Vec<u8> {
+	[sp_crypto_hashing::twox_128(pallet.as_bytes()), sp_crypto_hashing::twox_128(item.as_bytes())]
+		.concat()
+}
+
+fn decode_item<T: Decode>(item: &'static str, value: Option<Vec<u8>>) -> Result<Option<T>, Error> {
+	// items are decoded leniently, so that the unused trailing fields of on-chain types can be
+	// ignored.
+	value
+		.map(|v| T::decode(&mut &v[..]).map_err(|e| Error::Codec(item, e)))
+		.transpose()
+}
+
+impl ElectionSnapshot {
+	/// The storage items read by [`Self::from_storage`].
+	pub const STORAGE_ITEMS: [&'static str; 3] = ["Snapshot", "DesiredTargets", "QueuedSolution"];
+
+	/// Read the snapshot from the storage of the election provider multi-phase pallet named
+	/// `pallet`, using `get` to read a storage key.
+	///
+	/// The snapshot only exists while the election provider is in its signed or unsigned phase.
+	pub fn from_storage(
+		pallet: &str,
+		get: impl Fn(&[u8]) -> Option<Vec<u8>>,
+	) -> Result<Self, Error> {
+		let read = |item| get(&storage_key(pallet, item));
+
+		// `RoundSnapshot { voters, targets }`.
+		let (voters, targets) =
+			decode_item::<(Vec<Voter>, Vec<AccountId>)>("Snapshot", read("Snapshot"))?
+				.ok_or(Error::MissingStorage("Snapshot"))?;
+		let desired_targets = decode_item::<u32>("DesiredTargets", read("DesiredTargets"))?
+			.ok_or(Error::MissingStorage("DesiredTargets"))?;
+		// `ReadySolution { supports, score, compute }`.
+		let queued_solution =
+			decode_item::<QueuedSolution>("QueuedSolution", read("QueuedSolution"))?;
+
+		Ok(Self { voters, targets, desired_targets, queued_solution })
+	}
+
+	fn stake_of(&self) -> BTreeMap<AccountId, VoteWeight> {
+		self.voters.iter().map(|(who, stake, _)| (who.clone(), *stake)).collect()
+	}
+}
+
+/// An election algorithm of [`sp_npos_elections`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Algorithm {
+	/// [`sp_npos_elections::seq_phragmen`].
+	SeqPhragmen,
+	/// [`sp_npos_elections::phragmms`].
+	Phragmms,
+}
+
+impl fmt::Display for Algorithm {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Algorithm::SeqPhragmen => write!(f, "seq-phragmen"),
+			Algorithm::Phragmms => write!(f, "phragmms"),
+		}
+	}
+}
+
+/// The configuration of a run of an election algorithm.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RunConfig {
+	/// The number of iterations of the balancing algorithm, none if zero.
+	pub balancing_iterations: usize,
+	/// The tolerance of the balancing algorithm.
+	pub balancing_tolerance: ExtendedBalance,
+	/// Whether to reduce the edges of the solution, as the off-chain miner does.
+	pub reduce: bool,
+}
+
+impl RunConfig {
+	fn balancing(&self) -> Option<BalancingConfig> {
+		(self.balancing_iterations > 0).then_some(BalancingConfig {
+			iterations: self.balancing_iterations,
+			tolerance: self.balancing_tolerance,
+		})
+	}
+}
+
+/// The outcome of an election, as verified against its snapshot.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Outcome {
+	/// Where the outcome comes from, an algorithm or the chain.
+	pub source: String,
+	/// The score of the outcome, computed from its supports.
+	pub score: ElectionScore,
+	/// The number of winners.
+	pub winners: usize,
+	/// The number of edges between voters and winners.
+	pub edges: usize,
+	/// A target that violates PJR, if the outcome is not PJR compliant.
+	pub pjr_counter_example: Option<AccountId>,
+}
+
+/// Verify the `supports` of an election against its `snapshot`.
+pub fn verify(
+	source: impl ToString,
+	snapshot: &ElectionSnapshot,
+	supports: &Supports<AccountId>,
+) -> Outcome {
+	Outcome {
+		source: source.to_string(),
+		score: supports.evaluate(),
+		winners: supports.len(),
+		edges: supports.iter().map(|(_, support)| support.voters.len()).sum(),
+		pjr_counter_example: pjr_check(supports, snapshot.targets.clone(), snapshot.voters.clone())
+			.err(),
+	}
+}
+
+/// Run `algorithm` over `snapshot`, and verify its outcome.
+pub fn run(
+	snapshot: &ElectionSnapshot,
+	algorithm: Algorithm,
+	config: RunConfig,
+) -> Result<Outcome, Error> {
+	let to_elect = snapshot.desired_targets as usize;
+	let targets = snapshot.targets.clone();
+	let voters = snapshot.voters.clone();
+
+	let ElectionResult { assignments, .. } = match algorithm {
+		Algorithm::SeqPhragmen =>
+			seq_phragmen::<_, Perbill>(to_elect, targets, voters, config.balancing())?,
+		Algorithm::Phragmms =>
+			phragmms::<_, Perbill>(to_elect, targets, voters, config.balancing())?,
+	};
+
+	let stake_of = snapshot.stake_of();
+	let mut staked: Vec<StakedAssignment<AccountId>> =
+		assignment_ratio_to_staked_normalized(assignments, |who| {
+			stake_of.get(who).copied().unwrap_or_default()
+		})?;
+	if config.reduce {
+		reduce(&mut staked);
+	}
+
+	let supports = sp_npos_elections::to_supports(&staked);
+	Ok(verify(algorithm, snapshot, &supports))
+}
+
+/// The report of the verification of an election.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Report {
+	/// The number of voters in the snapshot.
+	pub voters: usize,
+	/// The number of targets in the snapshot.
+	pub targets: usize,
+	/// The number of targets to elect.
+	pub desired_targets: u32,
+	/// The outcome of the solution queued on-chain, if any.
+	pub on_chain: Option<Outcome>,
+	/// Whether the score claimed by the queued solution matches the one of its supports.
+	pub on_chain_score_matches: Option<bool>,
+	/// The outcomes of the algorithms, best score first.
+	pub outcomes: Vec<Outcome>,
+}
+
+/// Run each of `algorithms` over `snapshot`, and verify their outcomes and the one queued
+/// on-chain.
+pub fn analyze(
+	snapshot: &ElectionSnapshot,
+	algorithms: &[Algorithm],
+	config: RunConfig,
+) -> Result<Report, Error> {
+	let mut outcomes = algorithms
+		.iter()
+		.map(|algorithm| {
+			log::info!(target: "npos-elections-verifier", "running {}", algorithm);
+			run(snapshot, *algorithm, config)
+		})
+		.collect::<Result<Vec<_>, _>>()?;
+	outcomes.sort_by(|a, b| b.score.cmp(&a.score));
+
+	let on_chain = snapshot
+		.queued_solution
+		.as_ref()
+		.map(|queued| verify("on-chain", snapshot, &queued.supports));
+	let on_chain_score_matches = snapshot
+		.queued_solution
+		.as_ref()
+		.zip(on_chain.as_ref())
+		.map(|(queued, outcome)| queued.score == outcome.score);
+
+	Ok(Report {
+		voters: snapshot.voters.len(),
+		targets: snapshot.targets.len(),
+		desired_targets: snapshot.desired_targets,
+		on_chain,
+		on_chain_score_matches,
+		outcomes,
+	})
+}
+
+fn write_outcome(
+	f: &mut fmt::Formatter<'_>,
+	outcome: &Outcome,
+	on_chain: Option<&Outcome>,
+) -> fmt::Result {
+	let pjr = match &outcome.pjr_counter_example {
+		None => "yes".to_string(),
+		Some(counter_example) => format!("no ({})", counter_example),
+	};
+	let versus_on_chain = match on_chain {
+		Some(on_chain) if outcome.score == on_chain.score => "equal",
+		Some(on_chain)
+			if outcome.score.strict_threshold_better(on_chain.score, Perbill::zero()) =>
+			"better",
+		Some(_) => "worse",
+		None => "-",
+	};
+	writeln!(
+		f,
+		"{:<14} {:>40} {:>40} {:>40} {:>8} {:>8} {:>8}  {}",
+		outcome.source,
+		outcome.score.minimal_stake,
+		outcome.score.sum_stake,
+		outcome.score.sum_stake_squared,
+		outcome.winners,
+		outcome.edges,
+		versus_on_chain,
+		pjr,
+	)
+}
+
+impl fmt::Display for Report {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		writeln!(
+			f,
+			"{} voters, {} targets, {} to elect",
+			self.voters, self.targets, self.desired_targets
+		)?;
+		if let Some(false) = self.on_chain_score_matches {
+			writeln!(f, "WARNING: the score claimed on-chain does not match the queued supports")?;
+		}
+		writeln!(
+			f,
+			"{:<14} {:>40} {:>40} {:>40} {:>8} {:>8} {:>8}  PJR",
+			"source",
+			"minimal stake",
+			"sum stake",
+			"sum stake squared",
+			"winners",
+			"edges",
+			"on-chain"
+		)?;
+		if let Some(on_chain) = &self.on_chain {
+			write_outcome(f, on_chain, None)?;
+		}
+		for outcome in &self.outcomes {
+			write_outcome(f, outcome, self.on_chain.as_ref())?;
+		}
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use codec::Encode;
+
+	fn account(i: u8) -> AccountId {
+		AccountId::new([i; 32])
+	}
+
+	fn snapshot() -> ElectionSnapshot {
+		ElectionSnapshot {
+			voters: vec![
+				(account(10), 10, vec![account(1), account(2)]),
+				(account(20), 20, vec![account(1), account(3)]),
+				(account(30), 30, vec![account(2), account(3)]),
+				(account(1), 5, vec![account(1)]),
+			],
+			targets: vec![account(1), account(2), account(3)],
+			desired_targets: 2,
+			queued_solution: None,
+		}
+	}
+
+	#[test]
+	fn algorithms_are_compared() {
+		let snapshot = snapshot();
+		let config = RunConfig { balancing_iterations: 10, ..Default::default() };
+		let report =
+			analyze(&snapshot, &[Algorithm::SeqPhragmen, Algorithm::Phragmms], config).unwrap();
+
+		assert_eq!(report.voters, 4);
+		assert_eq!(report.outcomes.len(), 2);
+		assert!(report.outcomes[0].score >= report.outcomes[1].score);
+		for outcome in &report.outcomes {
+			assert_eq!(outcome.winners, 2);
+			assert_eq!(outcome.pjr_counter_example, None);
+		}
+		assert_eq!(report.on_chain, None);
+	}
+
+	#[test]
+	fn on_chain_solution_is_verified() {
+		let mut snapshot = snapshot();
+		let phragmen = run(&snapshot, Algorithm::SeqPhragmen, Default::default()).unwrap();
+
+		// a solution that elects the least backed target, and claims a wrong score.
+		let supports = vec![
+			(account(1), sp_npos_elections::Support { total: 5, voters: vec![(account(1), 5)] }),
+			(
+				account(3),
+				sp_npos_elections::Support {
+					total: 50,
+					voters: vec![(account(20), 20), (account(30), 30)],
+				},
+			),
+		];
+		snapshot.queued_solution = Some(QueuedSolution { supports, score: phragmen.score });
+
+		let report = analyze(&snapshot, &[Algorithm::SeqPhragmen], Default::default()).unwrap();
+		let on_chain = report.on_chain.unwrap();
+		assert_eq!(on_chain.score.minimal_stake, 5);
+		assert_eq!(report.on_chain_score_matches, Some(false));
+		assert!(report.outcomes[0]
+			.score
+			.strict_threshold_better(on_chain.score, Perbill::zero()));
+	}
+
+	#[test]
+	fn snapshot_is_read_from_storage() {
+		let snapshot = snapshot();
+		let storage: BTreeMap<Vec<u8>, Vec<u8>> = [
+			(
+				storage_key("ElectionProviderMultiPhase", "Snapshot"),
+				(snapshot.voters.clone(), snapshot.targets.clone()).encode(),
+			),
+			(storage_key("ElectionProviderMultiPhase", "DesiredTargets"), 2u32.encode()),
+		]
+		.into_iter()
+		.collect();
+
+		let read = ElectionSnapshot::from_storage("ElectionProviderMultiPhase", |key| {
+			storage.get(key).cloned()
+		})
+		.unwrap();
+		assert_eq!(read, snapshot);
+
+		assert!(matches!(
+			ElectionSnapshot::from_storage("Other", |key| storage.get(key).cloned()),
+			Err(Error::MissingStorage("Snapshot"))
+		));
+	}
+}
@@ -0,0 +1,172 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Verify the election of a chain, or of a JSON dump of it, against the NPoS election algorithms.
+//!
+//! ```sh,notrust
+//! $ npos-elections-verifier --balancing-iterations 10 remote --uri wss://rpc.polkadot.io:443
+//! $ npos-elections-verifier --json file snapshot.json
+//! ```
+
+use clap::{Parser, Subcommand, ValueEnum};
+use frame_remote_externalities::{Builder, Mode, OnlineConfig};
+use npos_elections_verifier::{analyze, storage_key, Algorithm, ElectionSnapshot, RunConfig};
+use sp_core::H256;
+use sp_runtime::{
+	generic::{Block, Header},
+	traits::BlakeTwo256,
+	OpaqueExtrinsic,
+};
+use std::path::{Path, PathBuf};
+
+/// Any block type of the relay chains and the kitchensink runtime, only used to fetch storage.
+type AnyBlock = Block<Header<u32, BlakeTwo256>, OpaqueExtrinsic>;
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum AlgorithmArg {
+	SeqPhragmen,
+	Phragmms,
+}
+
+impl From<AlgorithmArg> for Algorithm {
+	fn from(arg: AlgorithmArg) -> Self {
+		match arg {
+			AlgorithmArg::SeqPhragmen => Algorithm::SeqPhragmen,
+			AlgorithmArg::Phragmms => Algorithm::Phragmms,
+		}
+	}
+}
+
+#[derive(Debug, Subcommand)]
+enum Source {
+	/// Read the snapshot from the storage of a live chain.
+	Remote {
+		/// The RPC endpoint of the chain.
+		#[arg(long, default_value = "ws://localhost:9944")]
+		uri: String,
+
+		/// The hash of the block to read the snapshot at, the latest finalized one if not given.
+		#[arg(long, value_parser = parse_hash)]
+		at: Option<H256>,
+
+		/// The name of the election provider multi-phase pallet in the runtime.
+		#[arg(long, default_value = "ElectionProviderMultiPhase")]
+		pallet: String,
+
+		/// Also write the snapshot as JSON to this file, to be used with `file` later on.
+		#[arg(long)]
+		dump: Option<PathBuf>,
+	},
+
+	/// Read the snapshot from a JSON dump.
+	File {
+		/// The path to the JSON dump.
+		path: PathBuf,
+	},
+}
+
+#[derive(Debug, Parser)]
+struct Opt {
+	/// The algorithms to run.
+	#[arg(long, value_enum, value_delimiter = ',', default_values_t = vec![AlgorithmArg::SeqPhragmen, AlgorithmArg::Phragmms])]
+	algorithms: Vec<AlgorithmArg>,
+
+	/// The number of iterations of the balancing algorithm, none if zero.
+	#[arg(long, default_value_t = 0)]
+	balancing_iterations: usize,
+
+	/// The tolerance of the balancing algorithm.
+	#[arg(long, default_value_t = 0)]
+	balancing_tolerance: u128,
+
+	/// Reduce the edges of the solutions, as the off-chain miner does.
+	#[arg(long)]
+	reduce: bool,
+
+	/// Output the report as JSON.
+	#[arg(long)]
+	json: bool,
+
+	#[command(subcommand)]
+	source: Source,
+}
+
+type BoxError = Box<dyn std::error::Error>;
+
+fn parse_hash(hash: &str) -> Result<H256, String> {
+	hash.strip_prefix("0x").unwrap_or(hash).parse().map_err(|e| format!("{:?}", e))
+}
+
+async fn remote_snapshot(
+	uri: String,
+	at: Option<H256>,
+	pallet: &str,
+) -> Result<ElectionSnapshot, BoxError> {
+	let hashed_keys = ElectionSnapshot::STORAGE_ITEMS
+		.iter()
+		.map(|item| storage_key(pallet, item))
+		.collect();
+
+	let mut ext = Builder::<AnyBlock>::new()
+		.mode(Mode::Online(OnlineConfig {
+			transport: uri.into(),
+			at,
+			hashed_keys,
+			child_trie: false,
+			..Default::default()
+		}))
+		.build()
+		.await?;
+
+	let snapshot = ext.execute_with(|| {
+		ElectionSnapshot::from_storage(pallet, |key| sp_io::storage::get(key).map(|v| v.to_vec()))
+	})?;
+	Ok(snapshot)
+}
+
+fn file_snapshot(path: &Path) -> Result<ElectionSnapshot, BoxError> {
+	Ok(serde_json::from_reader(std::fs::File::open(path)?)?)
+}
+
+#[tokio::main]
+async fn main() -> Result<(), BoxError> {
+	sp_tracing::try_init_simple();
+	let Opt { algorithms, balancing_iterations, balancing_tolerance, reduce, json, source } =
+		Opt::parse();
+
+	let snapshot = match source {
+		Source::Remote { uri, at, pallet, dump } => {
+			let snapshot = remote_snapshot(uri, at, &pallet).await?;
+			if let Some(dump) = dump {
+				serde_json::to_writer_pretty(std::fs::File::create(dump)?, &snapshot)?;
+			}
+			snapshot
+		},
+		Source::File { path } => file_snapshot(&path)?,
+	};
+
+	let algorithms = algorithms.into_iter().map(Into::into).collect::<Vec<_>>();
+	let config = RunConfig { balancing_iterations, balancing_tolerance, reduce };
+	let report = analyze(&snapshot, &algorithms, config)?;
+
+	if json {
+		println!("{}", serde_json::to_string_pretty(&report)?);
+	} else {
+		print!("{}", report);
+	}
+	Ok(())
+}